@@ -0,0 +1,40 @@
+#![allow(non_snake_case)]
+
+// Renders how two exported plan manifests differ:
+//
+//     cargo run --example Manifest -- Before.json After.json
+//
+// Lines start with `-` for an action After lacks, `+` for one it adds and
+// `~` for a changed field. The exit code is 1 if After is not compatible.
+
+fn Read(Path:&str) -> Manifest {
+	let Content = std::fs::read_to_string(Path)
+		.unwrap_or_else(|_Error| panic!("Cannot read {}: {}", Path, _Error));
+
+	serde_json::from_str(&Content)
+		.unwrap_or_else(|_Error| panic!("Cannot parse {}: {}", Path, _Error))
+}
+
+fn main() {
+	let Path = std::env::args().skip(1).collect::<Vec<_>>();
+
+	let [Before, After] = &Path[..] else {
+		eprintln!("Usage: Manifest <Before.json> <After.json>");
+
+		std::process::exit(2);
+	};
+
+	let Diff = Read(Before).Diff(&Read(After));
+
+	if Diff.Empty() {
+		println!("The manifests are the same");
+	} else {
+		print!("{}", Diff);
+	}
+
+	if !Diff.Compatible() {
+		std::process::exit(1);
+	}
+}
+
+use Echo::Struct::Sequence::Plan::Manifest::Struct as Manifest;
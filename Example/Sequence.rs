@@ -7,7 +7,7 @@ struct SimpleSite;
 impl Site for SimpleSite {
 	async fn Receive(
 		&self,
-		Action:Box<dyn Echo::Trait::Sequence::Action::Trait>,
+		Action:Arc<dyn Echo::Trait::Sequence::Action::Trait>,
 		Context:&Life,
 	) -> Result<(), Error> {
 		Action.Execute(Context).await
@@ -18,11 +18,8 @@ impl Site for SimpleSite {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	// Create a plan with file reading and writing actions
 	let Plan = Arc::new(
-		Echo::Struct::Sequence::Plan::Struct::New()
-			.WithSignature(Signature { Name:"Read".to_string() })
-			.WithSignature(Signature { Name:"Write".to_string() })
-			.WithFunction("Read", Common::Read::Fn)?
-			.WithFunction("Write", Common::Write::Fn)?
+		Echo::Builtin::Fs::Struct::New(".")
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())?
 			.Build(),
 	);
 
@@ -30,12 +27,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let Production = Arc::new(Echo::Struct::Sequence::Production::Struct::New());
 
 	// Create a life context
-	let Life = Life {
-		Span:Arc::new(DashMap::new()),
-		Fate:Arc::new(config::Config::default()),
-		Cache:Arc::new(tokio::sync::Mutex::new(DashMap::new())),
-		Karma:Arc::new(DashMap::new()),
-	};
+	let Life = Life::Builder().Build();
 
 	// Create a site
 	let Site = Arc::new(SimpleSite);
@@ -44,27 +36,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let Sequence = Echo::Struct::Sequence::Struct::New(Site, Production.clone(), Life);
 
 	// Add actions to the production line
-	// Create actions for reading and writing files
+	// Create actions for writing and reading files
 	Production
-		.Assign(Box::new(Common::New("Read", json!(["input.txt"]), Plan.clone()).clone()))
-		.await;
+		.Enqueue(Box::new(
+			Action::New("Write", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!(["output.txt", "Hello, World!"])),
+		))
+		.await?;
 
 	Production
-		.Assign(Box::new(
-			Common::New("Write", json!(["output.txt", "Hello, World!"]), Plan.clone()).clone(),
+		.Enqueue(Box::new(
+			Action::New("Read", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!(["output.txt"])),
 		))
-		.await;
+		.await?;
 
 	let CloneSequence = Sequence.clone();
 
 	// Run the sequence
 	tokio::spawn(async move {
-		// TODO: thread 'tokio-runtime-worker' has overflowed its stack
-		// CloneSequence.Run().await;
+		CloneSequence.Run().await;
 	});
 
 	// Wait for a moment to allow actions to complete
-	tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+	tokio::time::sleep(std::time::Duration::from_secs(1)).await;
 
 	// Shutdown the sequence
 	Sequence.Shutdown().await;
@@ -74,20 +69,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
-use dashmap::DashMap;
 use serde_json::{json, Value};
-use tokio::{
-	fs::{File, OpenOptions},
-	io::{AsyncReadExt, AsyncWriteExt},
-};
 use Echo::{
 	Enum::Sequence::Action::Error::Enum as Error,
-	Struct::Sequence::{
-		Action::{Signature::Struct as Signature, Struct as Action},
-		Arc,
-		Life::Struct as Life,
-	},
+	Struct::Sequence::{Action::Struct as Action, Arc, Life::Struct as Life},
 	Trait::Sequence::Site::Trait as Site,
 };
-
-pub mod Common;
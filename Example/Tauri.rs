@@ -2,11 +2,19 @@
 
 struct SimpleSite;
 
+struct Webview(tauri::AppHandle);
+
+impl EventSink for Webview {
+	fn Emit(&self, Name:&str, Payload:Value) -> Result<(), String> {
+		self.0.emit_all(Name, Payload).map_err(|_Error| _Error.to_string())
+	}
+}
+
 #[async_trait::async_trait]
 impl Site for SimpleSite {
 	async fn Receive(
 		&self,
-		Action:Box<dyn Sequence::Action::Trait>,
+		Action:Arc<dyn Echo::Trait::Sequence::Action::Trait>,
 		Context:&Life,
 	) -> Result<(), Error> {
 		Action.Execute(Context).await
@@ -16,42 +24,31 @@ impl Site for SimpleSite {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let Plan = Arc::new(
-		Echo::Struct::Sequence::Plan::Struct::New()
-			.WithSignature(Action::Signature::Struct { Name:"Read".to_string() })
-			.WithSignature(Action::Signature::Struct { Name:"Write".to_string() })
-			.WithFunction("Read", Common::Read::Fn)?
-			.WithFunction("Write", Common::Write::Fn)?
+		Echo::Builtin::Fs::Struct::New(".")
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())?
 			.Build(),
 	);
 
 	let Production = Arc::new(Echo::Struct::Sequence::Production::Struct::New());
 
-	let Life = Life {
-		Span:Arc::new(dashmap::DashMap::new()),
-		Fate:Arc::new(config::Config::default()),
-		Cache:Arc::new(tokio::sync::Mutex::new(dashmap::DashMap::new())),
-		Karma:Arc::new(dashmap::DashMap::new()),
-	};
+	let Life = Life::Builder().Build();
 
 	let Site = Arc::new(SimpleSite);
 	let Sequence = Arc::new(Sequence::Struct::New(Site, Production.clone(), Life));
 
-	// Channel for sending action results
-	let (Allow, mut Mark) = mpsc::unbounded_channel();
-
 	// Spawn worker tasks
 	let mut Force = JoinSet::new();
 
 	for _ in 0..4 {
 		let Sequence = Sequence.clone();
 
-		let tx = Allow.clone();
-
 		Force.spawn(async move {
-			while !Sequence.Time.Get().await {
-				if let Some(action) = Sequence.Production.Do().await {
-					let result = Sequence.Site.Receive(action, &Sequence.Life).await;
-					tx.send(result).unwrap();
+			let Shutdown = Sequence.ShutdownToken();
+
+			while !Shutdown.Get().await {
+				// One dequeue and execution, with the retries of `Run`
+				if Sequence.ConsumeOne().await.is_none() {
+					tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 				}
 			}
 		});
@@ -60,43 +57,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	// Set up Tauri application
 	tauri::Builder::default()
 		.setup(|app| {
-			let Handle = app.handle();
+			// Bridge results to the webview, coalesced and batched
+			tokio::spawn(
+				ResultBridge::Struct::New(Webview(app.handle()))
+					.WithRate(5)
+					.WithBatch(10)
+					.WithRuntime(Sequence.Context().Runtime())
+					.Run(Sequence.Context().Subscribe()),
+			);
 
 			// Add actions to the production line
 			tokio::spawn(async move {
-				Production
-					.Assign(Box::new(
-						Action::Struct::New(
-							"Write",
-							json!(["output.txt", "Hello, World!"]),
-							Arc::new(Plan.clone()),
-						)
-						.clone(),
+				let _ = Production
+					.Enqueue(Box::new(
+						Action::Struct::New("Write", Value::Null, Plan.clone())
+							.WithMetadata("Argument", json!(["output.txt", "Hello, World!"])),
 					))
 					.await;
 
-				Production
-					.Assign(Box::new(
-						Action::Struct::New("Read", json!(["input.txt"]), Arc::new(Plan.clone()))
-							.clone(),
+				let _ = Production
+					.Enqueue(Box::new(
+						Action::Struct::New("Read", Value::Null, Plan.clone())
+							.WithMetadata("Argument", json!(["output.txt"])),
 					))
 					.await;
-
-				// Process action results
-				while let Some(result) = Mark.recv().await {
-					match result {
-						Ok(_) => {
-							Handle
-								.emit_all("ActionResult", "Action completed successfully")
-								.unwrap()
-						},
-						Err(e) => {
-							Handle
-								.emit_all("ActionResult", format!("Action failed: {}", e))
-								.unwrap()
-						},
-					}
-				}
 			});
 
 			Ok(())
@@ -122,16 +106,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 use std::sync::Arc;
 
 use serde_json::{json, Value};
-use tokio::{
-	fs::{File, OpenOptions},
-	io::{AsyncReadExt, AsyncWriteExt},
-	sync::mpsc,
-	task::JoinSet,
-};
+use tauri::Manager;
+use tokio::task::JoinSet;
 use Echo::{
 	Enum::Sequence::Action::Error::Enum as Error,
-	Struct::Sequence::{self, Action, Life::Struct as Life, Plan},
-	Trait::Sequence::Site,
+	Struct::{
+		Integration::Tauri::ResultBridge,
+		Sequence::{self, Action, Life::Struct as Life},
+	},
+	Trait::{Integration::EventSink::Trait as EventSink, Sequence::Site::Trait as Site},
 };
-
-pub mod Common;
@@ -1,8 +1,11 @@
 #![allow(non_snake_case)]
 
+// An action shared between the queues
+type Shared = Arc<dyn Echo::Trait::Sequence::Action::Trait>;
+
 // Define a worker-stealing queue
 struct WorkerStealingQueue {
-	Queues:Vec<Arc<Mutex<Vec<Box<dyn Echo::Trait::Sequence::Action::Trait>>>>>,
+	Queues:Vec<Arc<Mutex<Vec<Shared>>>>,
 }
 
 impl WorkerStealingQueue {
@@ -12,11 +15,11 @@ impl WorkerStealingQueue {
 		}
 	}
 
-	async fn Assign(&self, Identifier:usize, Action:Box<dyn Echo::Trait::Sequence::Action::Trait>) {
+	async fn Assign(&self, Identifier:usize, Action:Shared) {
 		self.Queues[Identifier].lock().await.push(Action);
 	}
 
-	async fn Do(&self, Worker:usize) -> Option<Box<dyn Echo::Trait::Sequence::Action::Trait>> {
+	async fn Do(&self, Worker:usize, Life:&Life) -> Option<Shared> {
 		let mut Queue = self.Queues[Worker].lock().await;
 
 		if let Some(Action) = Queue.pop() {
@@ -28,7 +31,7 @@ impl WorkerStealingQueue {
 			let mut QueuesOther:Vec<usize> =
 				(0..self.Queues.len()).filter(|&i| i != Worker).collect();
 
-			QueuesOther.shuffle(&mut rand::thread_rng());
+			Life.Randomness.Shuffle(&mut QueuesOther);
 
 			for IdOther in QueuesOther {
 				let mut QueueOther = self.Queues[IdOther].lock().await;
@@ -53,8 +56,8 @@ struct StealingWorker {
 impl Worker for StealingWorker {
 	async fn Receive(
 		&self,
-		Action:Box<dyn Echo::Trait::Sequence::Action::Trait>,
-		Context:&Life,
+		Action:Arc<dyn Echo::Trait::Sequence::Action::Trait>,
+		_Context:&Life,
 	) -> Result<(), Error> {
 		self.Queue.Assign(self.Id, Action).await;
 
@@ -64,7 +67,7 @@ impl Worker for StealingWorker {
 
 async fn worker_loop(Worker:Arc<StealingWorker>, Life:Arc<Life>, Running:Arc<Mutex<bool>>) {
 	while *Running.lock().await {
-		if let Some(Action) = Worker.Queue.Do(Worker.Id).await {
+		if let Some(Action) = Worker.Queue.Do(Worker.Id, &Life).await {
 			if let Err(_Error) = Action.Execute(&Life).await {
 				eprintln!("Error executing action: {:?}", _Error);
 			}
@@ -78,11 +81,8 @@ async fn worker_loop(Worker:Arc<StealingWorker>, Life:Arc<Life>, Running:Arc<Mut
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	// Create a plan with file reading and writing actions
 	let Plan = Arc::new(
-		Echo::Struct::Sequence::Plan::Struct::New()
-			.WithSignature(Signature { Name:"Read".to_string() })
-			.WithSignature(Signature { Name:"Write".to_string() })
-			.WithFunction("Read", Common::Read::Fn)?
-			.WithFunction("Write", Common::Write::Fn)?
+		Echo::Builtin::Fs::Struct::New(".")
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())?
 			.Build(),
 	);
 
@@ -92,12 +92,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let Queue = Arc::new(WorkerStealingQueue::New(Force));
 
 	// Create a life context
-	let Life = Arc::new(Life {
-		Span:Arc::new(dashmap::DashMap::new()),
-		Fate:Arc::new(config::Config::default()),
-		Cache:Arc::new(tokio::sync::Mutex::new(dashmap::DashMap::new())),
-		Karma:Arc::new(dashmap::DashMap::new()),
-	});
+	let Life = Arc::new(Life::Builder().Build());
 
 	// Create workers
 	let Workers:Vec<Arc<StealingWorker>> = (0..Force)
@@ -124,19 +119,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 		.collect();
 
 	// Add actions to the queue
-	Action::New("Write", json!([format!("output.txt"), "Hello, World!"]), Plan.clone());
-
 	for i in 0..4 {
 		let Action = if i % 2 == 0 {
+			Action::New("Write", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!(["output.txt", "Hello, World!"]))
 		} else {
-			Action::New("Read", json!(["input.txt"]), Plan.clone())
+			Action::New("Read", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!(["output.txt"]))
 		};
-	}
 
-	Queue.Assign(i % Force, Box::new(Action)).await;
+		Queue.Assign(i % Force, Arc::new(Action)).await;
+	}
 
 	// Wait for a moment to allow actions to complete
-	sleep(Duration::from_secs(10)).await;
+	sleep(Duration::from_secs(1)).await;
 
 	// Signal workers to stop
 	*Running.lock().await = false;
@@ -151,25 +147,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
-use std::sync::Arc;
-
 use async_trait::async_trait;
-use rand::seq::SliceRandom;
 use serde_json::{json, Value};
 use tokio::{
-	fs::{File, OpenOptions},
-	io::{AsyncReadExt, AsyncWriteExt},
 	sync::Mutex,
 	time::{sleep, Duration},
 };
 use Echo::{
 	Enum::Sequence::Action::Error::Enum as Error,
-	Struct::Sequence::{
-		Action::{Signature::Struct as Signature, Struct as Action},
-		Arc,
-		Life::Struct as Life,
-	},
+	Struct::Sequence::{Action::Struct as Action, Arc, Life::Struct as Life},
 	Trait::Sequence::Site::Trait as Worker,
 };
-
-pub mod Common;
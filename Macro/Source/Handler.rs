@@ -0,0 +1,146 @@
+/// Builds the `Handler::Trait` implementation for an inherent `impl` block.
+///
+/// # Arguments
+///
+/// * `Attribute` - The attribute arguments, optionally `Kind = "Name"`.
+/// * `Item` - The inherent `impl` block carrying the `Run` method.
+///
+/// # Returns
+///
+/// The generated implementation, or a `syn::Error` pointing at the part of
+/// the input that does not fit the expected shape.
+pub fn Fn(Attribute:TokenStream, Item:&ItemImpl) -> Result<TokenStream> {
+	if let Some((_, Path, _)) = &Item.trait_ {
+		return Err(Error::new_spanned(Path, "Handler expects an inherent impl block"));
+	}
+
+	let Run = Item
+		.items
+		.iter()
+		.find_map(|Item| match Item {
+			ImplItem::Fn(Function) if Function.sig.ident == "Run" => Some(Function),
+			_ => None,
+		})
+		.ok_or_else(|| Error::new_spanned(&Item.self_ty, "Handler expects a `Run` method"))?;
+
+	if Run.sig.asyncness.is_none() {
+		return Err(Error::new_spanned(&Run.sig, "`Run` must be an async method"));
+	}
+
+	let Request = match Run.sig.inputs.iter().collect::<Vec<_>>().as_slice() {
+		[FnArg::Receiver(_), FnArg::Typed(Request)] => Request.ty.clone(),
+		_ => {
+			return Err(Error::new_spanned(
+				&Run.sig.inputs,
+				"`Run` must take `&self` and exactly one request argument",
+			));
+		},
+	};
+
+	let Response = Response(&Run.sig.output)?;
+
+	let Kind = match Kind(Attribute)? {
+		Some(Kind) => Kind,
+		None => Name(&Item.self_ty)?,
+	};
+
+	let Type = &Item.self_ty;
+
+	let (Generic, _, Where) = Item.generics.split_for_impl();
+
+	Ok(quote! {
+		impl #Generic ::Echo::Trait::Sequence::Handler::Trait for #Type #Where {
+			type Request = #Request;
+
+			type Response = #Response;
+
+			fn Kind() -> &'static str { #Kind }
+
+			fn Run(
+				&self,
+				Request:Self::Request,
+			) -> impl ::std::future::Future<
+				Output = ::std::result::Result<
+					Self::Response,
+					::Echo::Enum::Sequence::Action::Error::Enum,
+				>,
+			> + Send {
+				<#Type>::Run(self, Request)
+			}
+		}
+	})
+}
+
+/// Extracts the success type out of a `Result<Response, Error>` return type.
+fn Response(Output:&ReturnType) -> Result<Type> {
+	let ReturnType::Type(_, Type) = Output else {
+		return Err(Error::new_spanned(Output, "`Run` must return `Result<Response, Error>`"));
+	};
+
+	if let Type::Path(Path) = Type.as_ref() {
+		if let Some(Segment) = Path.path.segments.last() {
+			if let PathArguments::AngleBracketed(Argument) = &Segment.arguments {
+				if let Some(GenericArgument::Type(Response)) = Argument.args.first() {
+					if Segment.ident == "Result" {
+						return Ok(Response.clone());
+					}
+				}
+			}
+		}
+	}
+
+	Err(Error::new_spanned(Type, "`Run` must return `Result<Response, Error>`"))
+}
+
+/// Parses the optional `Kind = "Name"` attribute argument.
+fn Kind(Attribute:TokenStream) -> Result<Option<LitStr>> {
+	if Attribute.is_empty() {
+		return Ok(None);
+	}
+
+	let Argument:MetaNameValue = syn::parse2(Attribute)?;
+
+	if !Argument.path.is_ident("Kind") {
+		return Err(Error::new_spanned(
+			&Argument.path,
+			"Unknown Handler argument, expected `Kind`",
+		));
+	}
+
+	match Argument.value {
+		Expr::Lit(ExprLit { lit: Lit::Str(Kind), .. }) => Ok(Some(Kind)),
+		Value => Err(Error::new_spanned(Value, "`Kind` must be a string literal")),
+	}
+}
+
+/// Derives the default kind from the last segment of the handler's type.
+fn Name(Type:&Type) -> Result<LitStr> {
+	match Type {
+		Type::Path(Path) => Path
+			.path
+			.segments
+			.last()
+			.map(|Segment| LitStr::new(&Segment.ident.to_string(), Segment.ident.span()))
+			.ok_or_else(|| Error::new_spanned(Type, "Handler type has no name")),
+		_ => Err(Error::new_spanned(Type, "Handler expects a named type, or an explicit `Kind`")),
+	}
+}
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	Error,
+	Expr,
+	ExprLit,
+	FnArg,
+	GenericArgument,
+	ImplItem,
+	ItemImpl,
+	Lit,
+	LitStr,
+	MetaNameValue,
+	PathArguments,
+	Result,
+	ReturnType,
+	Type,
+};
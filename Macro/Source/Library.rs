@@ -0,0 +1,52 @@
+#![allow(non_snake_case)]
+
+/// Turns an inherent `impl` block with an async `Run` method into a typed
+/// action handler.
+///
+/// The block must contain
+/// `async fn Run(&self, Request:Request) -> Result<Response, Error>`. The
+/// attribute keeps the block as written and adds an implementation of
+/// `Echo::Trait::Sequence::Handler::Trait`, taking the request and response
+/// types from `Run`. The handler's kind defaults to the name of the type and
+/// can be overridden with `#[Handler(Kind = "Name")]`.
+///
+/// The generated handler is registered with `Plan::WithHandler`, which also
+/// takes care of the serde glue.
+#[proc_macro_attribute]
+pub fn Handler(Attribute:TokenStream, Item:TokenStream) -> TokenStream {
+	let Item = parse_macro_input!(Item as ItemImpl);
+
+	match Handler::Fn(Attribute.into(), &Item) {
+		Ok(Expansion) => quote!(#Item #Expansion).into(),
+		Err(_Error) => {
+			let Error = _Error.to_compile_error();
+
+			quote!(#Item #Error).into()
+		},
+	}
+}
+
+/// Derives `Echo::Trait::Sequence::Set::Trait` for an enum of actions.
+///
+/// Every variant is an action named after the variant, either a unit variant
+/// or a tuple variant carrying exactly one typed request. The derive also
+/// generates a `<Enum>Handler` trait with one async method per variant;
+/// implementing it for a handler type and building an
+/// `Echo::Struct::Sequence::Plan::Typed::Struct` makes a missing arm a
+/// compile error.
+#[proc_macro_derive(Set)]
+pub fn Set(Item:TokenStream) -> TokenStream {
+	let Item = parse_macro_input!(Item as DeriveInput);
+
+	match Set::Fn(&Item) {
+		Ok(Expansion) => Expansion.into(),
+		Err(_Error) => _Error.to_compile_error().into(),
+	}
+}
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, ItemImpl};
+
+mod Handler;
+mod Set;
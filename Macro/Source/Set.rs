@@ -0,0 +1,142 @@
+/// Builds the `Set::Trait` implementation and handler trait for an enum.
+///
+/// # Arguments
+///
+/// * `Item` - The enum whose variants form the action set.
+///
+/// # Returns
+///
+/// The generated handler trait and implementation, or a `syn::Error` pointing
+/// at the variant that does not fit the expected shape.
+pub fn Fn(Item:&DeriveInput) -> Result<TokenStream> {
+	let Data::Enum(Enum) = &Item.data else {
+		return Err(Error::new_spanned(&Item.ident, "Set can only be derived for enums"));
+	};
+
+	if !Item.generics.params.is_empty() {
+		return Err(Error::new_spanned(&Item.generics, "Set cannot be derived for generic enums"));
+	}
+
+	let Type = &Item.ident;
+
+	let Visibility = &Item.vis;
+
+	let Handler = format_ident!("{}Handler", Type);
+
+	let mut Name = Vec::new();
+
+	let mut Method = Vec::new();
+
+	let mut Decode = Vec::new();
+
+	let mut Pattern = Vec::new();
+
+	let mut Dispatch = Vec::new();
+
+	for Variant in &Enum.variants {
+		let Identifier = &Variant.ident;
+
+		let Literal = LitStr::new(&Identifier.to_string(), Identifier.span());
+
+		match &Variant.fields {
+			Fields::Unit => {
+				Method.push(quote! {
+					fn #Identifier(&self) -> impl ::std::future::Future<
+						Output = ::std::result::Result<
+							::Echo::serde_json::Value,
+							::Echo::Enum::Sequence::Action::Error::Enum,
+						>,
+					> + Send;
+				});
+
+				Decode.push(quote!(#Literal => ::std::result::Result::Ok(#Type::#Identifier)));
+
+				Pattern.push(quote!(#Type::#Identifier));
+
+				Dispatch.push(quote!(#Type::#Identifier => Handler.#Identifier().await));
+			},
+			Fields::Unnamed(Field) if Field.unnamed.len() == 1 => {
+				let Request = &Field.unnamed[0].ty;
+
+				Method.push(quote! {
+					fn #Identifier(&self, Request:#Request) -> impl ::std::future::Future<
+						Output = ::std::result::Result<
+							::Echo::serde_json::Value,
+							::Echo::Enum::Sequence::Action::Error::Enum,
+						>,
+					> + Send;
+				});
+
+				Decode.push(quote! {
+					#Literal => ::std::result::Result::Ok(#Type::#Identifier(
+						::Echo::Fn::Argument::Decode::Fn(Name, Argument)?,
+					))
+				});
+
+				Pattern.push(quote!(#Type::#Identifier(..)));
+
+				Dispatch.push(
+					quote!(#Type::#Identifier(Request) => Handler.#Identifier(Request).await),
+				);
+			},
+			_ => {
+				return Err(Error::new_spanned(
+					Variant,
+					"Set variants must be unit variants or carry exactly one request",
+				));
+			},
+		}
+
+		Name.push(Literal);
+	}
+
+	Ok(quote! {
+		/// The handler answering every action of the set, one method per variant.
+		#Visibility trait #Handler: Send + Sync + 'static {
+			#(#Method)*
+		}
+
+		impl<H:#Handler> ::Echo::Trait::Sequence::Set::Trait<H> for #Type {
+			fn Names() -> &'static [&'static str] { &[#(#Name),*] }
+
+			fn Name(&self) -> &'static str {
+				match self {
+					#(#Pattern => #Name,)*
+				}
+			}
+
+			fn Decode(
+				Name:&str,
+				Argument: ::std::vec::Vec<::Echo::serde_json::Value>,
+			) -> ::std::result::Result<Self, ::Echo::Enum::Sequence::Action::Error::Enum> {
+				let _ = &Argument;
+
+				match Name {
+					#(#Decode,)*
+					_ => ::std::result::Result::Err(
+						::Echo::Enum::Sequence::Action::Error::Enum::Execution(
+							::std::format!("No action {} in {}", Name, ::std::stringify!(#Type)),
+						),
+					),
+				}
+			}
+
+			fn Dispatch(self, Handler:&H) -> impl ::std::future::Future<
+				Output = ::std::result::Result<
+					::Echo::serde_json::Value,
+					::Echo::Enum::Sequence::Action::Error::Enum,
+				>,
+			> + Send + '_ {
+				async move {
+					match self {
+						#(#Dispatch,)*
+					}
+				}
+			}
+		}
+	})
+}
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
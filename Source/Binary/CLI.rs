@@ -0,0 +1,98 @@
+#![allow(non_snake_case)]
+
+//! `echo-cli`, the command line of an Echo server.
+//!
+//! `echo-cli import <FILE> --address <HOST:PORT>` imports a JSONL or CSV
+//! file of submissions through `Echo::Import`, resuming from its checkpoint,
+//! and prints the report as JSON. The options are:
+//!
+//! - `--token <TOKEN>`: authenticates the connection;
+//! - `--manifest <FILE>`: refuses lines naming an action a JSON manifest,
+//!   as written by `Formality::ExportManifest`, lacks;
+//! - `--batch <N>`, `--concurrency <N>` and `--rate <PER SECOND>`: pace the
+//!   submissions;
+//! - `--depth <N>`: waits while the queue of a line holds `N` actions.
+
+const USAGE:&str = "Usage: echo-cli import <FILE> --address <HOST:PORT> [--token <TOKEN>] \
+                    [--manifest <FILE>] [--batch <N>] [--concurrency <N>] [--rate <PER SECOND>] \
+                    [--depth <N>]";
+
+#[tokio::main]
+async fn main() -> ExitCode {
+	env_logger::init();
+
+	match Command(env::args().skip(1).collect()).await {
+		Ok(Report) => {
+			println!("{}", serde_json::to_string_pretty(&Report).unwrap_or_default());
+
+			if Report.Complete { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+		},
+		Err(_Error) => {
+			eprintln!("{}", _Error);
+
+			ExitCode::from(2)
+		},
+	}
+}
+
+/// Runs the command the arguments name.
+async fn Command(Argument:Vec<String>) -> Result<Report, String> {
+	let mut Argument = Argument.into_iter();
+
+	if Argument.next().as_deref() != Some("import") {
+		return Err(USAGE.to_string());
+	}
+
+	let (mut File, mut Address, mut Config, mut Options) =
+		(None, None, Config::New(), Options::New());
+
+	while let Some(Flag) = Argument.next() {
+		if !Flag.starts_with("--") {
+			File = Some(Flag);
+
+			continue;
+		}
+
+		let Value = Argument.next().ok_or_else(|| format!("{} needs a value\n{}", Flag, USAGE))?;
+
+		match Flag.as_str() {
+			"--address" => Address = Some(Value),
+			"--token" => Config = Config.WithToken(&Value),
+			"--manifest" => {
+				let Content = read(&Value).await.map_err(|_Error| _Error.to_string())?;
+
+				Options = Options.WithManifest(
+					serde_json::from_slice(&Content).map_err(|_Error| _Error.to_string())?,
+				);
+			},
+			"--batch" => Options = Options.WithBatch(Number(&Flag, &Value)?),
+			"--concurrency" => Options = Options.WithConcurrency(Number(&Flag, &Value)?),
+			"--rate" => Options = Options.WithRate(Number(&Flag, &Value)?),
+			"--depth" => {
+				Options = Options.WithDepth(Number(&Flag, &Value)?, Duration::from_millis(200))
+			},
+			_ => return Err(format!("Unknown option {}\n{}", Flag, USAGE)),
+		}
+	}
+
+	let (Some(File), Some(Address)) = (File, Address) else {
+		return Err(USAGE.to_string());
+	};
+
+	let Client = Client::Connect(&Address, Config).await.map_err(|_Error| _Error.to_string())?;
+
+	Import::Run(&File, &Client, &Options).await.map_err(|_Error| _Error.to_string())
+}
+
+/// Parses the value of a numeric option.
+fn Number<T:FromStr>(Flag:&str, Value:&str) -> Result<T, String> {
+	Value.parse().map_err(|_| format!("{} expects a number, not {}", Flag, Value))
+}
+
+use std::{env, process::ExitCode, str::FromStr, time::Duration};
+
+use tokio::fs::read;
+use Echo::{
+	Import::{self, Options::Struct as Options, Report::Struct as Report},
+	Struct::Client::{Config::Struct as Config, Struct as Client},
+};
@@ -0,0 +1,353 @@
+/// Configuration of the built-in file-operation actions.
+///
+/// `Register` adds `Read`, `Write`, `Append`, `Copy`, `Move`, `Delete`,
+/// `List` and `Exists` to a plan. Their arguments are positional:
+///
+/// - `Read`, `Delete`, `Exists`: `[path]`
+/// - `Write`, `Append`: `[path, content]`, the content being text or bytes,
+///   such as the payload of a binary frame, written without being copied
+/// - `Copy`, `Move`: `[from, to]`
+/// - `List`: `[glob]`
+///
+/// Every path is resolved under `Root` and verified to stay there, with
+/// symbolic links resolved before the check, so neither `..` nor a link can
+/// escape the jail. `Delete` and `Move` act on a link itself rather than on
+/// its target. Results are objects naming paths relative to `Root`; the
+/// content `Read` returns is text, or follows the `Fn::Binary::Encode`
+/// convention if the file is not UTF-8.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The directory all paths are resolved under.
+	pub Root:PathBuf,
+
+	/// The maximum size in bytes of a file returned by `Read`.
+	pub Read:u64,
+
+	/// The maximum number of entries returned by `List`.
+	pub List:usize,
+}
+
+impl Struct {
+	/// Creates a new configuration jailed under the given directory.
+	///
+	/// # Arguments
+	///
+	/// * `Root` - The directory all paths are resolved under.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` with a 16 MiB read cap and a 10,000 entry list cap.
+	pub fn New(Root:impl Into<PathBuf>) -> Self {
+		Struct { Root:Root.into(), Read:16 * 1024 * 1024, List:10_000 }
+	}
+
+	/// Registers every file-operation action into a plan.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan to register the actions into.
+	///
+	/// # Returns
+	///
+	/// A `Result` containing the extended plan, or an error message if the
+	/// actions could not be registered.
+	pub fn Register(self, mut Plan:Plan) -> Result<Plan, String> {
+		let Config = Arc::new(self);
+
+		for Name in ["Read", "Write", "Append", "Copy", "Move", "Delete", "List", "Exists"] {
+			let Config = Config.clone();
+
+			Plan = Plan.WithSignature(Signature::New(Name)).WithArgFunction(
+				Name,
+				move |Argument:Vec<Arg>| {
+					let Config = Config.clone();
+
+					async move { Config.Run(Name, Argument).await }
+				},
+			)?;
+		}
+
+		Ok(Plan)
+	}
+
+	/// Runs one file operation.
+	async fn Run(&self, Name:&str, Argument:Vec<Arg>) -> Result<Value, Error> {
+		match Name {
+			"Read" => {
+				let Path:String = Decode(Name, Argument)?;
+
+				let Resolved = self.Resolve(&Path).await?;
+
+				let Size = fs::metadata(&Resolved).await.map_err(Failure)?.len();
+
+				if Size > self.Read {
+					return Err(Error::Execution(format!(
+						"File {} is {} bytes, over the read limit of {}",
+						Path, Size, self.Read
+					)));
+				}
+
+				let Content = match String::from_utf8(fs::read(&Resolved).await.map_err(Failure)?) {
+					Ok(Text) => Value::String(Text),
+					Err(_Error) => crate::Fn::Binary::Encode::Fn(_Error.as_bytes()),
+				};
+
+				Ok(json!({ "path": Path, "content": Content, "bytes": Size }))
+			},
+			"Write" | "Append" => {
+				let [Path, Content]:[Arg; 2] = Argument.try_into().map_err(|_| {
+					Error::Execution(format!("Invalid request for {}: expected two arguments", Name))
+				})?;
+
+				let Path:String = Decode(Name, vec![Path])?;
+
+				let Content = Content.IntoBytes().map_err(|_| {
+					Error::Execution(format!("Invalid request for {}: content is not text", Name))
+				})?;
+
+				let Resolved = self.Resolve(&Path).await?;
+
+				let (Append, Size) = (Name == "Append", Content.len());
+
+				let (Sender, Receiver) = oneshot::channel();
+
+				// Written by a blocking task from the payload itself, with no
+				// buffer in between
+				let Task = Invocation::Runtime().SpawnBlocking(Box::pin(async move {
+					let Write = || {
+						let mut File = std::fs::OpenOptions::new()
+							.create(true)
+							.write(true)
+							.append(Append)
+							.truncate(!Append)
+							.open(&Resolved)?;
+
+						File.write_all(&Content)?;
+
+						File.flush()
+					};
+
+					let _ = Sender.send(Write());
+				}));
+
+				match Receiver.await {
+					Ok(Written) => Written.map_err(Failure)?,
+					Err(_) => {
+						let Exit = Task.await.err().unwrap_or(Exit::Cancelled);
+
+						return Err(Error::Execution(Exit.to_string()));
+					},
+				}
+
+				Ok(json!({ "path": Path, "bytes": Size }))
+			},
+			"Copy" => {
+				let (From, To):(String, String) = Decode(Name, Argument)?;
+
+				let Bytes = fs::copy(self.Resolve(&From).await?, self.Resolve(&To).await?)
+					.await
+					.map_err(Failure)?;
+
+				Ok(json!({ "from": From, "to": To, "bytes": Bytes }))
+			},
+			"Move" => {
+				let (From, To):(String, String) = Decode(Name, Argument)?;
+
+				fs::rename(self.Leaf(&From).await?, self.Leaf(&To).await?)
+					.await
+					.map_err(Failure)?;
+
+				Ok(json!({ "from": From, "to": To }))
+			},
+			"Delete" => {
+				let Path:String = Decode(Name, Argument)?;
+
+				let Resolved = self.Leaf(&Path).await?;
+
+				let Deleted = match fs::symlink_metadata(&Resolved).await {
+					Ok(Metadata) if Metadata.is_dir() => {
+						fs::remove_dir_all(&Resolved).await.map_err(Failure)?;
+
+						true
+					},
+					Ok(_) => {
+						fs::remove_file(&Resolved).await.map_err(Failure)?;
+
+						true
+					},
+					Err(_Error) if _Error.kind() == ErrorKind::NotFound => false,
+					Err(_Error) => return Err(Failure(_Error)),
+				};
+
+				Ok(json!({ "path": Path, "deleted": Deleted }))
+			},
+			"List" => {
+				let Pattern:String = Decode(Name, Argument)?;
+
+				Ok(json!({ "pattern": Pattern, "entries": self.List(&Pattern).await? }))
+			},
+			"Exists" => {
+				let Path:String = Decode(Name, Argument)?;
+
+				let Kind = match fs::metadata(self.Resolve(&Path).await?).await {
+					Ok(Metadata) if Metadata.is_dir() => json!("directory"),
+					Ok(_) => json!("file"),
+					Err(_) => Value::Null,
+				};
+
+				Ok(json!({ "path": Path, "exists": !Kind.is_null(), "kind": Kind }))
+			},
+			_ => Err(Error::Execution(format!("Unknown file operation: {}", Name))),
+		}
+	}
+
+	/// Resolves a path under the root and verifies it stays there.
+	///
+	/// The path is normalised lexically first, then the deepest existing
+	/// ancestor is canonicalised so that symbolic links are followed before
+	/// the containment check.
+	pub(crate) async fn Resolve(&self, Path:&str) -> Result<PathBuf, Error> {
+		let Root = fs::canonicalize(&self.Root).await.map_err(Failure)?;
+
+		let mut Resolved = Root.clone();
+
+		for Component in std::path::Path::new(Path).components() {
+			match Component {
+				Component::Normal(Part) => Resolved.push(Part),
+				Component::CurDir | Component::RootDir | Component::Prefix(_) => {},
+				Component::ParentDir => {
+					if !Resolved.pop() || !Resolved.starts_with(&Root) {
+						return Err(Escape(Path));
+					}
+				},
+			}
+		}
+
+		let mut Existing = Resolved.as_path();
+
+		let mut Rest = Vec::new();
+
+		let Canonical = loop {
+			match fs::canonicalize(Existing).await {
+				Ok(Canonical) => break Canonical,
+				Err(_) => {
+					Rest.push(Existing.file_name().ok_or_else(|| Escape(Path))?.to_owned());
+
+					Existing = Existing.parent().ok_or_else(|| Escape(Path))?;
+				},
+			}
+		};
+
+		if !Canonical.starts_with(&Root) {
+			return Err(Escape(Path));
+		}
+
+		Ok(Rest.into_iter().rev().fold(Canonical, |Path, Part| Path.join(Part)))
+	}
+
+	/// Resolves a path under the root without following its last component.
+	///
+	/// Only the parent is resolved, so a symbolic link names the link itself
+	/// and destructive operations never reach through it. The root itself is
+	/// refused.
+	async fn Leaf(&self, Path:&str) -> Result<PathBuf, Error> {
+		let mut Normal = PathBuf::new();
+
+		for Component in std::path::Path::new(Path).components() {
+			match Component {
+				Component::Normal(Part) => Normal.push(Part),
+				Component::CurDir | Component::RootDir | Component::Prefix(_) => {},
+				Component::ParentDir => {
+					if !Normal.pop() {
+						return Err(Escape(Path));
+					}
+				},
+			}
+		}
+
+		let Some(Name) = Normal.file_name().map(ToOwned::to_owned) else {
+			return Err(Error::Execution(format!("Path refers to the root itself: {}", Path)));
+		};
+
+		Normal.pop();
+
+		Ok(self.Resolve(&Normal.to_string_lossy()).await?.join(Name))
+	}
+
+	/// Lists the paths under the root matching a glob, relative to the root.
+	///
+	/// Symbolic links to directories are not descended into.
+	async fn List(&self, Pattern:&str) -> Result<Vec<String>, Error> {
+		let Root = fs::canonicalize(&self.Root).await.map_err(Failure)?;
+
+		let mut Pending = vec![Root.clone()];
+
+		let mut Entries = Vec::new();
+
+		while let Some(Directory) = Pending.pop() {
+			let mut Read = fs::read_dir(&Directory).await.map_err(Failure)?;
+
+			while let Some(Entry) = Read.next_entry().await.map_err(Failure)? {
+				let Path = Entry.path();
+
+				let Relative = Path
+					.strip_prefix(&Root)
+					.map_err(|_Error| Error::Execution(_Error.to_string()))?
+					.to_string_lossy()
+					.replace('\\', "/");
+
+				if Entry.file_type().await.map_err(Failure)?.is_dir() {
+					Pending.push(Path);
+				}
+
+				if crate::Fn::Glob::Fn(Pattern, &Relative) {
+					if Entries.len() >= self.List {
+						return Err(Error::Execution(format!(
+							"List {} matches more than {} entries",
+							Pattern, self.List
+						)));
+					}
+
+					Entries.push(Relative);
+				}
+			}
+		}
+
+		Entries.sort();
+
+		Ok(Entries)
+	}
+}
+
+/// Decodes the positional arguments of a file operation.
+fn Decode<T:DeserializeOwned>(Name:&str, Argument:Vec<Arg>) -> Result<T, Error> {
+	crate::Fn::Argument::Decode::Fn(Name, Argument)
+}
+
+/// Maps an I/O error into an execution error.
+fn Failure(_Error:std::io::Error) -> Error { Error::Execution(_Error.to_string()) }
+
+/// Builds the error reported for a path leaving the root.
+fn Escape(Path:&str) -> Error { Error::Execution(format!("Path escapes the root: {}", Path)) }
+
+use std::{
+	io::{ErrorKind, Write},
+	path::{Component, PathBuf},
+	sync::Arc,
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::{fs, sync::oneshot};
+
+use crate::{
+	Enum::{
+		Runtime::Exit::Enum as Exit,
+		Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Invocation::Struct as Invocation,
+		Plan::Struct as Plan,
+	},
+};
@@ -0,0 +1,282 @@
+/// Configuration of the built-in `HttpRequest` action.
+///
+/// The action's first argument is an object with `method`, `url`, optional
+/// `headers`, `body` (a string, or any other JSON value sent as JSON) and
+/// `timeout_ms` fields. A second argument, such as the payload of a binary
+/// frame, is sent as the body as it is, without being copied. It returns
+/// `{"status", "headers", "body", "truncated"}`, keeping only the allowed
+/// response headers and at most `Capture` bytes of body.
+///
+/// Retries are not performed here; a failed request fails the action and the
+/// Sequence decides whether to try again.
+#[derive(Clone)]
+pub struct Struct {
+	/// The client performing the requests.
+	pub Client:Arc<dyn Client::Trait>,
+
+	/// The URL prefixes requests and redirects may target, or `None` to allow
+	/// any URL.
+	pub Allow:Option<Vec<String>>,
+
+	/// The maximum number of redirects followed per request.
+	pub Redirect:usize,
+
+	/// The maximum number of response body bytes returned.
+	pub Capture:usize,
+
+	/// The timeout applied when an action does not specify its own.
+	pub Timeout:Duration,
+
+	/// Whether non-2xx responses fail the action instead of being returned as
+	/// data.
+	pub Reject:bool,
+
+	/// The response headers included in the result, in lowercase.
+	pub Header:Vec<String>,
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Struct {
+			Client:Arc::new(Plain::Struct),
+			Allow:None,
+			Redirect:5,
+			Capture:1024 * 1024,
+			Timeout:Duration::from_secs(30),
+			Reject:true,
+			Header:["content-type", "content-length", "etag", "last-modified", "location"]
+				.map(String::from)
+				.to_vec(),
+		}
+	}
+}
+
+impl Struct {
+	/// Registers the `HttpRequest` action into a plan.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan to register the action into.
+	///
+	/// # Returns
+	///
+	/// A `Result` containing the extended plan, or an error message if the
+	/// action could not be registered.
+	pub fn Register(self, Plan:Plan) -> Result<Plan, String> {
+		let Config = Arc::new(self);
+
+		Plan.WithSignature(Signature::New("HttpRequest")).WithArgFunction(
+			"HttpRequest",
+			move |Argument:Vec<Arg>| {
+				let Config = Config.clone();
+
+				async move {
+					let mut Argument = Argument.into_iter();
+
+					let Request:Request = crate::Fn::Argument::Decode::Fn(
+						"HttpRequest",
+						Argument.next().into_iter().collect(),
+					)?;
+
+					let Payload = match Argument.next().map(Arg::IntoBytes) {
+						Some(Ok(Payload)) => Some(Payload),
+						Some(Err(_)) => {
+							return Err(Error::Execution(
+								"Invalid request for HttpRequest: the body is not text".to_string(),
+							));
+						},
+						None => None,
+					};
+
+					let Timeout = Invocation::Bound(
+						Request
+							.timeout_ms
+							.map(Duration::from_millis)
+							.unwrap_or(Config.Timeout),
+					);
+
+					Invocation::Runtime()
+						.Timeout(Timeout, Config.Run(Request, Payload))
+						.await
+						.ok_or_else(|| {
+							Error::Execution(format!("HTTP request timed out after {:?}", Timeout))
+						})?
+				}
+			},
+		)
+	}
+
+	/// Runs a single request, following redirects, with `Payload` as its
+	/// body if given.
+	async fn Run(&self, Request:Request, Payload:Option<Bytes>) -> Result<Value, Error> {
+		let mut Method = Method::from_bytes(Request.method.to_ascii_uppercase().as_bytes())
+			.map_err(|_Error| Error::Execution(format!("Invalid HTTP method: {}", _Error)))?;
+
+		let mut Header = Request.headers;
+
+		let mut Body = match (Payload, Request.body) {
+			(Some(Payload), _) => Payload,
+			(None, None | Some(Value::Null)) => Bytes::new(),
+			(None, Some(Value::String(Body))) => Bytes::from(Body),
+			(None, Some(Body)) => {
+				if !Header.keys().any(|Name| Name.eq_ignore_ascii_case("content-type")) {
+					Header.insert("content-type".to_string(), "application/json".to_string());
+				}
+
+				Bytes::from(Body.to_string())
+			},
+		};
+
+		let mut Url = Request.url;
+
+		let mut Hop = 0;
+
+		loop {
+			self.Check(&Url)?;
+
+			let mut Builder = http::Request::builder().method(Method.clone()).uri(&Url);
+
+			for (Name, Value) in &Header {
+				Builder = Builder.header(Name, Value);
+			}
+
+			let Response = self
+				.Client
+				.Send(
+					Builder
+						.body(Body.clone())
+						.map_err(|_Error| Error::Execution(_Error.to_string()))?,
+					self.Capture,
+				)
+				.await?;
+
+			let Status = Response.status();
+
+			if Status.is_redirection() {
+				if let Some(Location) = Response.headers().get(LOCATION) {
+					Hop += 1;
+
+					if Hop > self.Redirect {
+						return Err(Error::Execution(format!(
+							"Too many redirects, the limit is {}",
+							self.Redirect
+						)));
+					}
+
+					let Location = Location
+						.to_str()
+						.map_err(|_Error| Error::Execution(_Error.to_string()))?;
+
+					Url = Join(&Url, Location);
+
+					if Status == StatusCode::SEE_OTHER {
+						Method = Method::GET;
+
+						Body = Bytes::new();
+					}
+
+					continue;
+				}
+			}
+
+			if self.Reject && !Status.is_success() {
+				return Err(Error::Execution(format!(
+					"HTTP request to {} failed with {}",
+					Url, Status
+				)));
+			}
+
+			let Header = Response
+				.headers()
+				.iter()
+				.filter(|(Name, _)| self.Header.iter().any(|Allow| Allow == Name.as_str()))
+				.map(|(Name, Value)| {
+					(Name.to_string(), json!(String::from_utf8_lossy(Value.as_bytes())))
+				})
+				.collect::<serde_json::Map<_, _>>();
+
+			let mut Body = Response.into_body();
+
+			let Truncated = Body.len() > self.Capture;
+
+			Body.truncate(self.Capture);
+
+			return Ok(json!({
+				"status": Status.as_u16(),
+				"headers": Header,
+				"body": String::from_utf8_lossy(&Body),
+				"truncated": Truncated,
+			}));
+		}
+	}
+
+	/// Checks a URL against the allow-list.
+	fn Check(&self, Url:&str) -> Result<(), Error> {
+		match &self.Allow {
+			Some(Allow) if !Allow.iter().any(|Prefix| Url.starts_with(Prefix.as_str())) => {
+				Err(Error::Execution(format!("URL is not allowed: {}", Url)))
+			},
+			_ => Ok(()),
+		}
+	}
+}
+
+/// The argument of an `HttpRequest` action.
+#[derive(Deserialize)]
+struct Request {
+	#[serde(default = "Get")]
+	method:String,
+
+	url:String,
+
+	#[serde(default)]
+	headers:HashMap<String, String>,
+
+	#[serde(default)]
+	body:Option<Value>,
+
+	#[serde(default)]
+	timeout_ms:Option<u64>,
+}
+
+/// The default method of a request.
+fn Get() -> String { "GET".to_string() }
+
+/// Resolves a redirect location against the URL it was returned for.
+fn Join(Base:&str, Location:&str) -> String {
+	if Location.contains("://") {
+		return Location.to_string();
+	}
+
+	let Origin = Base.find("://").map(|Scheme| {
+		let Rest = &Base[Scheme + 3..];
+
+		&Base[..Scheme + 3 + Rest.find('/').unwrap_or(Rest.len())]
+	});
+
+	match (Origin, Location.starts_with('/')) {
+		(Some(Origin), true) => format!("{}{}", Origin, Location),
+		_ => {
+			format!("{}/{}", Base.rsplit_once('/').map(|(Base, _)| Base).unwrap_or(Base), Location)
+		},
+	}
+}
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use bytes::Bytes;
+use http::{header::LOCATION, Method, StatusCode};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Invocation::Struct as Invocation,
+		Plan::Struct as Plan,
+	},
+};
+
+pub mod Client;
+pub mod Plain;
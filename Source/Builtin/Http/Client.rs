@@ -0,0 +1,25 @@
+/// Trait for the HTTP clients used by the built-in `HttpRequest` action.
+///
+/// A client performs exactly one request: redirects, allow-listing and
+/// status handling are applied by `Builtin::Http::Struct` around it, and
+/// retries are left to the Sequence.
+#[async_trait::async_trait]
+pub trait Trait: Send + Sync {
+	/// Sends a single request.
+	///
+	/// # Arguments
+	///
+	/// * `Request` - The request to send, its body shared rather than owned so
+	///   that redirects resend it without a copy.
+	/// * `Limit` - The maximum number of body bytes the caller keeps; clients
+	///   may stop reading once they have one byte more than that.
+	///
+	/// # Returns
+	///
+	/// Returns the response, or an `Error::Execution` describing the failure.
+	async fn Send(
+		&self,
+		Request:http::Request<bytes::Bytes>,
+		Limit:usize,
+	) -> Result<http::Response<Vec<u8>>, crate::Enum::Sequence::Action::Error::Enum>;
+}
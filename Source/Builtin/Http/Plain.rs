@@ -0,0 +1,154 @@
+/// A minimal HTTP/1.1 client over plain TCP.
+///
+/// This is the default client of the built-in `HttpRequest` action. It opens
+/// one connection per request, supports `Content-Length`, chunked and
+/// read-to-end bodies, and does not speak TLS; deployments that need `https`
+/// plug in their own `Client::Trait`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+#[async_trait::async_trait]
+impl Client for Struct {
+	async fn Send(
+		&self,
+		Request:Request<Bytes>,
+		Limit:usize,
+	) -> Result<Response<Vec<u8>>, Error> {
+		let Uri = Request.uri();
+
+		if Uri.scheme_str() != Some("http") {
+			return Err(Error::Execution(format!("Unsupported URL scheme: {}", Uri)));
+		}
+
+		let Host = Uri
+			.host()
+			.ok_or_else(|| Error::Execution(format!("URL has no host: {}", Uri)))?;
+
+		let Port = Uri.port_u16().unwrap_or(80);
+
+		let mut Stream = BufReader::new(TcpStream::connect((Host, Port)).await.map_err(Failure)?);
+
+		let mut Head = format!(
+			"{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\nContent-Length: {}\r\n",
+			Request.method(),
+			Uri.path_and_query().map(|Path| Path.as_str()).unwrap_or("/"),
+			Uri.authority().map(|Authority| Authority.as_str()).unwrap_or(Host),
+			Request.body().len()
+		);
+
+		for (Name, Value) in Request.headers() {
+			if Name != HOST && Name != CONNECTION && Name != CONTENT_LENGTH {
+				Head.push_str(&format!(
+					"{}: {}\r\n",
+					Name,
+					Value
+						.to_str()
+						.map_err(|_Error| Error::Execution(_Error.to_string()))?
+				));
+			}
+		}
+
+		Head.push_str("\r\n");
+
+		Stream.get_mut().write_all(Head.as_bytes()).await.map_err(Failure)?;
+
+		Stream.get_mut().write_all(Request.body()).await.map_err(Failure)?;
+
+		let mut Raw = Vec::new();
+
+		let (Status, Header) = loop {
+			let mut Line = Vec::new();
+
+			if Stream.read_until(b'\n', &mut Line).await.map_err(Failure)? == 0 {
+				return Err(Error::Execution("Connection closed before the response head".into()));
+			}
+
+			Raw.extend_from_slice(&Line);
+
+			if Line == b"\r\n" || Line == b"\n" {
+				let mut Header = [httparse::EMPTY_HEADER; 64];
+
+				let mut Head = httparse::Response::new(&mut Header);
+
+				Head.parse(&Raw)
+					.map_err(|_Error| Error::Execution(_Error.to_string()))?;
+
+				let Header = Head
+					.headers
+					.iter()
+					.map(|Header| (Header.name.to_string(), Header.value.to_vec()))
+					.collect::<Vec<_>>();
+
+				break (Head.code.unwrap_or(0), Header);
+			}
+		};
+
+		let Find = |Name:&str| {
+			Header
+				.iter()
+				.find(|(Key, _)| Key.eq_ignore_ascii_case(Name))
+				.map(|(_, Value)| String::from_utf8_lossy(Value).trim().to_ascii_lowercase())
+		};
+
+		let mut Body = Vec::new();
+
+		if Find("transfer-encoding").is_some_and(|Encoding| Encoding.contains("chunked")) {
+			loop {
+				let mut Line = String::new();
+
+				Stream.read_line(&mut Line).await.map_err(Failure)?;
+
+				let Size = usize::from_str_radix(Line.trim().split(';').next().unwrap_or(""), 16)
+					.map_err(|_Error| {
+					Error::Execution(format!("Invalid chunk size: {}", _Error))
+				})?;
+
+				if Size == 0 || Body.len() > Limit {
+					break;
+				}
+
+				let mut Chunk = vec![0; Size + 2];
+
+				Stream.read_exact(&mut Chunk).await.map_err(Failure)?;
+
+				Body.extend_from_slice(&Chunk[..Size]);
+			}
+		} else {
+			let Length = Find("content-length").and_then(|Length| Length.parse::<usize>().ok());
+
+			let Cap = (Limit + 1).min(Length.unwrap_or(usize::MAX));
+
+			(&mut Stream)
+				.take(Cap as u64)
+				.read_to_end(&mut Body)
+				.await
+				.map_err(Failure)?;
+		}
+
+		let mut Response = Response::builder().status(Status);
+
+		for (Name, Value) in Header {
+			Response = Response.header(Name, Value);
+		}
+
+		Response
+			.body(Body)
+			.map_err(|_Error| Error::Execution(_Error.to_string()))
+	}
+}
+
+/// Maps an I/O error into an execution error.
+fn Failure(_Error:std::io::Error) -> Error { Error::Execution(_Error.to_string()) }
+
+use bytes::Bytes;
+use http::{
+	header::{CONNECTION, CONTENT_LENGTH, HOST},
+	Request,
+	Response,
+};
+use tokio::{
+	io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+	net::TcpStream,
+};
+
+use crate::{Builtin::Http::Client::Trait as Client, Enum::Sequence::Action::Error::Enum as Error};
@@ -0,0 +1,271 @@
+/// Configuration of the built-in `Process` action.
+///
+/// The action runs a program through `tokio::process` and returns
+/// `{"status", "stdout", "stderr", "duration_ms"}`. Its single argument is an
+/// object with `program`, optional `args`, `stdin`, `cwd`, `env`, `shell` and
+/// `timeout_ms` fields.
+///
+/// The defaults are conservative: programs are executed directly without a
+/// shell, no environment variables may be set, and output is capped.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The programs that may be executed, or `None` to allow any program.
+	///
+	/// Shell command lines are checked as `sh`.
+	pub Program:Option<Vec<String>>,
+
+	/// The environment variables an action may set.
+	pub Environment:Vec<String>,
+
+	/// Whether an action may ask for its program to be run through `sh -c`.
+	pub Shell:bool,
+
+	/// The maximum number of bytes captured from each of stdout and stderr.
+	pub Capture:usize,
+
+	/// The timeout applied when an action does not specify its own.
+	pub Timeout:Duration,
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Struct {
+			Program:None,
+			Environment:Vec::new(),
+			Shell:false,
+			Capture:1024 * 1024,
+			Timeout:Duration::from_secs(60),
+		}
+	}
+}
+
+impl Struct {
+	/// Registers the `Process` action into a plan.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan to register the action into.
+	///
+	/// # Returns
+	///
+	/// A `Result` containing the extended plan, or an error message if the
+	/// action could not be registered.
+	pub fn Register(self, Plan:Plan) -> Result<Plan, String> {
+		let Config = Arc::new(self);
+
+		Plan.WithSignature(Signature::New("Process")).WithFunction(
+			"Process",
+			move |Argument:Vec<Value>| {
+				let Config = Config.clone();
+
+				async move {
+					Config
+						.Run(crate::Fn::Argument::Decode::Fn("Process", Argument)?)
+						.await
+				}
+			},
+		)
+	}
+
+	/// Runs a single process request.
+	async fn Run(&self, Request:Request) -> Result<Value, Error> {
+		// A shell command line is checked as the shell itself
+		let Program = if Request.shell { "sh" } else { Request.program.as_str() };
+
+		if let Some(Allow) = &self.Program {
+			if !Allow.iter().any(|Allow| Allow == Program) {
+				return Err(Error::Execution(format!("Program is not allowed: {}", Program)));
+			}
+		}
+
+		if let Some(Name) = Request.env.keys().find(|Name| !self.Environment.contains(Name)) {
+			return Err(Error::Execution(format!("Environment variable is not allowed: {}", Name)));
+		}
+
+		let mut Command = if Request.shell {
+			if !self.Shell {
+				return Err(Error::Execution("Shell interpretation is not enabled".to_string()));
+			}
+
+			let mut Command = Command::new("sh");
+
+			Command.arg("-c").arg(&Request.program).args(&Request.args);
+
+			Command
+		} else {
+			let mut Command = Command::new(&Request.program);
+
+			Command.args(&Request.args);
+
+			Command
+		};
+
+		if let Some(Directory) = &Request.cwd {
+			Command.current_dir(Directory);
+		}
+
+		Command
+			.envs(&Request.env)
+			.stdin(if Request.stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.kill_on_drop(true);
+
+		#[cfg(unix)]
+		Command.process_group(0);
+
+		let Start = Instant::now();
+
+		let mut Child = Command.spawn().map_err(|_Error| {
+			Error::Execution(format!("Failed to start {}: {}", Request.program, _Error))
+		})?;
+
+		// Kills the whole process tree if the action times out or is dropped
+		let mut Tree = Group(Child.id());
+
+		let Runtime = Invocation::Runtime();
+
+		if let (Some(Input), Some(mut Stdin)) = (Request.stdin, Child.stdin.take()) {
+			Runtime.Spawn(Box::pin(async move {
+				let _ = Stdin.write_all(Input.as_bytes()).await;
+			}));
+		}
+
+		let Stdout = Spawn(&*Runtime, Capture(Child.stdout.take(), self.Capture));
+
+		let Stderr = Spawn(&*Runtime, Capture(Child.stderr.take(), self.Capture));
+
+		let Timeout = Invocation::Bound(
+			Request.timeout_ms.map(Duration::from_millis).unwrap_or(self.Timeout),
+		);
+
+		let Status = match Runtime.Timeout(Timeout, Child.wait()).await {
+			Some(Status) => Status.map_err(|_Error| Error::Execution(_Error.to_string()))?,
+			None => {
+				drop(Tree);
+
+				return Err(Error::Execution(format!(
+					"Process {} timed out after {:?}",
+					Request.program, Timeout
+				)));
+			},
+		};
+
+		// The process exited on its own, leave its group alone
+		Tree.0 = None;
+
+		let Stdout = Stdout.await.map_err(|_Error| Error::Execution(_Error.to_string()))?;
+
+		let Stderr = Stderr.await.map_err(|_Error| Error::Execution(_Error.to_string()))?;
+
+		Ok(json!({
+			"status": Status.code(),
+			"stdout": String::from_utf8_lossy(&Stdout),
+			"stderr": String::from_utf8_lossy(&Stderr),
+			"duration_ms": Start.elapsed().as_millis() as u64,
+		}))
+	}
+}
+
+/// The argument of a `Process` action.
+#[derive(Deserialize)]
+struct Request {
+	program:String,
+
+	#[serde(default)]
+	args:Vec<String>,
+
+	#[serde(default)]
+	stdin:Option<String>,
+
+	#[serde(default)]
+	cwd:Option<String>,
+
+	#[serde(default)]
+	env:HashMap<String, String>,
+
+	#[serde(default)]
+	shell:bool,
+
+	#[serde(default)]
+	timeout_ms:Option<u64>,
+}
+
+/// Kills the process group of a child when dropped.
+struct Group(Option<u32>);
+
+impl Drop for Group {
+	fn drop(&mut self) {
+		#[cfg(unix)]
+		if let Some(Identifier) = self.0 {
+			// SAFETY: `kill` has no memory-safety preconditions; a negative id
+			// addresses the process group created with `process_group(0)`.
+			unsafe {
+				libc::kill(-(Identifier as i32), libc::SIGKILL);
+			}
+		}
+	}
+}
+
+/// Spawns the capture of a child's output on a runtime.
+///
+/// # Returns
+///
+/// Receives the output once the child closed it.
+fn Spawn(
+	Runtime:&dyn Runtime,
+	Capture:impl Future<Output = Vec<u8>> + Send + 'static,
+) -> oneshot::Receiver<Vec<u8>> {
+	let (Sender, Receiver) = oneshot::channel();
+
+	Runtime.Spawn(Box::pin(async move {
+		let _ = Sender.send(Capture.await);
+	}));
+
+	Receiver
+}
+
+/// Reads a child's output up to `Limit` bytes, draining the rest so the child
+/// never blocks on a full pipe.
+async fn Capture(Output:Option<impl AsyncRead + Unpin>, Limit:usize) -> Vec<u8> {
+	let mut Captured = Vec::new();
+
+	let Some(mut Output) = Output else {
+		return Captured;
+	};
+
+	let mut Buffer = [0u8; 8192];
+
+	while let Ok(Read) = Output.read(&mut Buffer).await {
+		if Read == 0 {
+			break;
+		}
+
+		let Room = Limit.saturating_sub(Captured.len());
+
+		Captured.extend_from_slice(&Buffer[..Read.min(Room)]);
+	}
+
+	Captured
+}
+
+use std::{collections::HashMap, future::Future, process::Stdio, sync::Arc, time::Duration};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::{
+	io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+	process::Command,
+	sync::oneshot,
+	time::Instant,
+};
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Invocation::Struct as Invocation,
+		Plan::Struct as Plan,
+	},
+	Trait::Runtime::Trait as Runtime,
+};
@@ -0,0 +1,7 @@
+pub mod Fs;
+
+#[cfg(feature = "Http")]
+pub mod Http;
+
+#[cfg(feature = "Process")]
+pub mod Process;
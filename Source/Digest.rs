@@ -0,0 +1,199 @@
+//! Stable digests of JSON values, shared by every feature that needs to
+//! recognize equal actions or arguments, so that they agree with each other
+//! and across platforms and builds.
+//!
+//! `Encode` writes a value in its canonical form, and `Canonical` hashes
+//! that form with SHA-256. The canonical form is compact JSON where:
+//!
+//! - object keys are sorted by Unicode scalar value, which is the order of
+//!   their UTF-8 bytes;
+//! - there is no whitespace between tokens;
+//! - strings escape only `"`, `\` and the control characters U+0000 to U+001F,
+//!   as `\b`, `\t`, `\n`, `\f`, `\r` or `\u00xx` in lowercase hex; every other
+//!   character is written as its UTF-8 bytes;
+//! - integers, and floats whose value is an integer of magnitude below 2^53,
+//!   are written in decimal without a fraction or exponent, so `1` and `1.0`
+//!   agree; `-0.0` is written `0`; 64-bit integers keep every digit, even
+//!   beyond the precision of floats;
+//! - other floats are written as the shortest digits that read back to the same
+//!   value, in exponential notation without a `+` or leading zeros, e.g. `5e-1`
+//!   or `1.25e300`.
+//!
+//! A `serde_json::Value` cannot hold NaN or an infinity: serializing one
+//! into a value yields `null`, which is what the canonical form contains.
+//!
+//! `Sha256::Stream` hashes bytes as they come, such as the chunks of a
+//! result, to the same digest `Raw` gives them whole.
+
+/// Writes a value in its canonical form.
+///
+/// # Arguments
+///
+/// * `Value` - The value to write.
+///
+/// # Returns
+///
+/// The canonical JSON text.
+pub fn Encode(Value:&Value) -> String {
+	let mut Text = String::new();
+
+	Write(&mut Text, Value);
+
+	Text
+}
+
+/// Hashes a value in its canonical form with SHA-256.
+///
+/// # Arguments
+///
+/// * `Value` - The value to hash.
+///
+/// # Returns
+///
+/// The 32-byte digest.
+pub fn Canonical(Value:&Value) -> [u8; 32] { Sha256::Fn(Encode(Value).as_bytes()) }
+
+/// Hashes bytes as they are with SHA-256, without reading them as JSON.
+///
+/// # Arguments
+///
+/// * `Data` - The bytes to hash.
+///
+/// # Returns
+///
+/// The 32-byte digest.
+pub fn Raw(Data:&[u8]) -> [u8; 32] { Sha256::Fn(Data) }
+
+/// Digests an action: its type, its arguments and the metadata that
+/// distinguishes otherwise equal actions for the caller.
+///
+/// The digest is that of the canonical form of
+/// `{"Action": Action, "Argument": Argument, "Metadata": Metadata}`.
+///
+/// # Arguments
+///
+/// * `Action` - The action type.
+/// * `Argument` - The arguments of the action.
+/// * `Metadata` - The selected metadata, empty if none matters.
+///
+/// # Returns
+///
+/// The 32-byte digest.
+pub fn ActionDigest(Action:&str, Argument:&[Value], Metadata:&Map<String, Value>) -> [u8; 32] {
+	Canonical(&json!({ "Action": Action, "Argument": Argument, "Metadata": Metadata }))
+}
+
+/// Writes a digest as lowercase hexadecimal digits.
+///
+/// # Arguments
+///
+/// * `Digest` - The digest.
+///
+/// # Returns
+///
+/// Two digits per byte.
+pub fn Hex(Digest:&[u8]) -> String {
+	Digest
+		.iter()
+		.fold(String::with_capacity(Digest.len() * 2), |mut Hex, Byte| {
+			let _ = write!(Hex, "{:02x}", Byte);
+
+			Hex
+		})
+}
+
+/// Appends the canonical form of a value.
+fn Write(Text:&mut String, Value:&Value) {
+	match Value {
+		Value::Null => Text.push_str("null"),
+		Value::Bool(Bool) => Text.push_str(if *Bool { "true" } else { "false" }),
+		Value::Number(Number) => Text.push_str(&self::Number(Number)),
+		Value::String(String) => Quote(Text, String),
+		Value::Array(Array) => {
+			Text.push('[');
+
+			for (Index, Item) in Array.iter().enumerate() {
+				if Index > 0 {
+					Text.push(',');
+				}
+
+				Write(Text, Item);
+			}
+
+			Text.push(']');
+		},
+		Value::Object(Object) => {
+			let mut Entry = Object.iter().collect::<Vec<_>>();
+
+			Entry.sort_by(|(Left, _), (Right, _)| Left.as_bytes().cmp(Right.as_bytes()));
+
+			Text.push('{');
+
+			for (Index, (Key, Item)) in Entry.into_iter().enumerate() {
+				if Index > 0 {
+					Text.push(',');
+				}
+
+				Quote(Text, Key);
+
+				Text.push(':');
+
+				Write(Text, Item);
+			}
+
+			Text.push('}');
+		},
+	}
+}
+
+/// Writes a number in its canonical form.
+fn Number(Number:&Number) -> String {
+	if let Some(Integer) = Number.as_u64() {
+		return Integer.to_string();
+	}
+
+	if let Some(Integer) = Number.as_i64() {
+		return Integer.to_string();
+	}
+
+	let Float = Number.as_f64().unwrap_or_default();
+
+	if Float == 0.0 {
+		return "0".to_string();
+	}
+
+	if Float.fract() == 0.0 && Float.abs() < 9_007_199_254_740_992.0 {
+		return (Float as i64).to_string();
+	}
+
+	format!("{:e}", Float)
+}
+
+/// Appends a string as a canonical JSON string.
+fn Quote(Text:&mut String, String:&str) {
+	Text.push('"');
+
+	for Character in String.chars() {
+		match Character {
+			'"' => Text.push_str("\\\""),
+			'\\' => Text.push_str("\\\\"),
+			'\u{8}' => Text.push_str("\\b"),
+			'\t' => Text.push_str("\\t"),
+			'\n' => Text.push_str("\\n"),
+			'\u{c}' => Text.push_str("\\f"),
+			'\r' => Text.push_str("\\r"),
+			Character if (Character as u32) < 0x20 => {
+				let _ = write!(Text, "\\u{:04x}", Character as u32);
+			},
+			Character => Text.push(Character),
+		}
+	}
+
+	Text.push('"');
+}
+
+use std::fmt::Write as _;
+
+use serde_json::{json, Map, Number, Value};
+
+pub mod Sha256;
@@ -0,0 +1,179 @@
+/// The SHA-256 round constants.
+const K:[u32; 64] = [
+	0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+	0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+	0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+	0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+	0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+	0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+	0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+	0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Hashes bytes with SHA-256 (FIPS 180-4).
+///
+/// # Arguments
+///
+/// * `Data` - The bytes to hash.
+///
+/// # Returns
+///
+/// The 32-byte digest.
+pub fn Fn(Data:&[u8]) -> [u8; 32] {
+	let mut Stream = Stream::New();
+
+	Stream.Update(Data);
+
+	Stream.Finish()
+}
+
+/// Hashes bytes with SHA-256 as they come, such as the chunks of a
+/// transfer, without holding more than one block of them.
+#[derive(Clone)]
+pub struct Stream {
+	/// The hash state.
+	State:[u32; 8],
+
+	/// The bytes short of a whole block.
+	Tail:Vec<u8>,
+
+	/// The number of bytes hashed.
+	Length:u64,
+}
+
+impl Stream {
+	/// Starts a new hash.
+	pub fn New() -> Self {
+		Stream {
+			State:[
+				0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+				0x5be0cd19,
+			],
+			Tail:Vec::with_capacity(64),
+			Length:0,
+		}
+	}
+
+	/// Hashes the next bytes.
+	///
+	/// # Arguments
+	///
+	/// * `Data` - The bytes following those hashed so far.
+	pub fn Update(&mut self, mut Data:&[u8]) {
+		self.Length += Data.len() as u64;
+
+		if !self.Tail.is_empty() {
+			let Taken = (64 - self.Tail.len()).min(Data.len());
+
+			self.Tail.extend_from_slice(&Data[..Taken]);
+
+			Data = &Data[Taken..];
+
+			if self.Tail.len() < 64 {
+				return;
+			}
+
+			Compress(&mut self.State, &self.Tail);
+
+			self.Tail.clear();
+		}
+
+		let Whole = Data.len() - Data.len() % 64;
+
+		// Whole blocks are hashed in place, only the tail is copied
+		for Block in Data[..Whole].chunks_exact(64) {
+			Compress(&mut self.State, Block);
+		}
+
+		self.Tail.extend_from_slice(&Data[Whole..]);
+	}
+
+	/// Ends the hash.
+	///
+	/// # Returns
+	///
+	/// The 32-byte digest of every byte hashed.
+	pub fn Finish(mut self) -> [u8; 32] {
+		// The tail, a one bit, zeros and the bit length fill one or two blocks
+		self.Tail.push(0x80);
+
+		while self.Tail.len() % 64 != 56 {
+			self.Tail.push(0);
+		}
+
+		self.Tail.extend_from_slice(&self.Length.wrapping_mul(8).to_be_bytes());
+
+		for Block in self.Tail.chunks_exact(64) {
+			Compress(&mut self.State, Block);
+		}
+
+		let mut Digest = [0u8; 32];
+
+		for (Chunk, Word) in Digest.chunks_exact_mut(4).zip(self.State) {
+			Chunk.copy_from_slice(&Word.to_be_bytes());
+		}
+
+		Digest
+	}
+}
+
+impl Default for Stream {
+	fn default() -> Self { Self::New() }
+}
+
+/// Mixes one 64-byte block into the hash state.
+fn Compress(State:&mut [u32; 8], Block:&[u8]) {
+	let mut W = [0u32; 64];
+
+	for (Index, Word) in Block.chunks_exact(4).enumerate() {
+		W[Index] = u32::from_be_bytes([Word[0], Word[1], Word[2], Word[3]]);
+	}
+
+	for Index in 16..64 {
+		let S0 = W[Index - 15].rotate_right(7)
+			^ W[Index - 15].rotate_right(18)
+			^ (W[Index - 15] >> 3);
+
+		let S1 = W[Index - 2].rotate_right(17)
+			^ W[Index - 2].rotate_right(19)
+			^ (W[Index - 2] >> 10);
+
+		W[Index] = W[Index - 16]
+			.wrapping_add(S0)
+			.wrapping_add(W[Index - 7])
+			.wrapping_add(S1);
+	}
+
+	let [mut A, mut B, mut C, mut D, mut E, mut F, mut G, mut H] = *State;
+
+	for Index in 0..64 {
+		let S1 = E.rotate_right(6) ^ E.rotate_right(11) ^ E.rotate_right(25);
+
+		let Choice = (E & F) ^ (!E & G);
+
+		let T1 = H
+			.wrapping_add(S1)
+			.wrapping_add(Choice)
+			.wrapping_add(K[Index])
+			.wrapping_add(W[Index]);
+
+		let S0 = A.rotate_right(2) ^ A.rotate_right(13) ^ A.rotate_right(22);
+
+		let Majority = (A & B) ^ (A & C) ^ (B & C);
+
+		let T2 = S0.wrapping_add(Majority);
+
+		H = G;
+		G = F;
+		F = E;
+		E = D.wrapping_add(T1);
+		D = C;
+		C = B;
+		B = A;
+		A = T1.wrapping_add(T2);
+	}
+
+	for (State, Value) in State.iter_mut().zip([A, B, C, D, E, F, G, H]) {
+		*State = State.wrapping_add(Value);
+	}
+}
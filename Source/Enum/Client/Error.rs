@@ -0,0 +1,63 @@
+/// Represents the errors a client can report.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Enum {
+	/// The connection could not be established.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the I/O error.
+	#[error("Connection error: {0}")]
+	Connection(String),
+
+	/// The connection was lost before the answer arrived.
+	#[error("Connection lost")]
+	ConnectionLost,
+
+	/// No answer arrived within the configured timeout.
+	#[error("Timed out")]
+	Timeout,
+
+	/// No result arrived within the timeout or before the deadline of the
+	/// submission itself.
+	#[error("Timed out waiting for the result")]
+	ClientTimeout,
+
+	/// The server rejected the message or the action failed.
+	///
+	/// # Arguments
+	///
+	/// * `String` - The message of the server's `Error` reply.
+	#[error("Failed: {0}")]
+	Failed(String),
+
+	/// The server refused a `Control` message.
+	///
+	/// # Arguments
+	///
+	/// * `String` - The message of the server's `Denied` reply.
+	#[error("Denied: {0}")]
+	Denied(String),
+
+	/// The result was sent in chunks and its transfer was aborted, by the
+	/// server or because the chunks failed a check.
+	///
+	/// # Arguments
+	///
+	/// * `Code` - Why the transfer was aborted, such as `DigestMismatch`.
+	/// * `String` - A description of the failure.
+	#[error("Transfer aborted: {1}")]
+	Transfer(Code, String),
+
+	/// A frame could not be encoded or decoded, or an answer did not match
+	/// its request.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the protocol error.
+	#[error("Protocol error: {0}")]
+	Protocol(String),
+}
+
+use thiserror::Error;
+
+use crate::Enum::Transport::Code::Enum as Code;
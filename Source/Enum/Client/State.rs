@@ -0,0 +1,15 @@
+/// The connection state of a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// The connection is open and authenticated.
+	Connected,
+
+	/// The connection dropped and the client is trying to open a new one.
+	Reconnecting,
+
+	/// The connection dropped for good; every request fails with
+	/// `ConnectionLost`.
+	Disconnected,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,220 @@
+/// A lifecycle event published on the event bus.
+///
+/// Actions are identified by the sequence number of the production line
+/// they were enqueued into (see `Production::Stamp`). Events are tagged by
+/// their `Type` field when serialized.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Event"))]
+#[serde(tag = "Type")]
+pub enum Enum {
+	/// An action entered a production line.
+	Enqueued {
+		/// The sequence number of the action.
+		Sequence:u64,
+
+		/// The name of the action, if it has one.
+		Action:Option<String>,
+	},
+
+	/// A sequence dequeued an action and started executing it.
+	Started {
+		/// The sequence number of the action.
+		Sequence:u64,
+	},
+
+	/// An attempt failed and the action will be retried.
+	Retried {
+		/// The sequence number of the action.
+		Sequence:u64,
+
+		/// The number of the attempt that failed, starting at 1.
+		Attempt:u32,
+
+		/// The error of the failed attempt.
+		Error:String,
+	},
+
+	/// The action completed successfully.
+	Completed {
+		/// The sequence number of the action.
+		Sequence:u64,
+
+		/// The attempts it took, the last one successful.
+		#[serde(default)]
+		History:History,
+
+		/// The annotations its attempts wrote; omitted when empty.
+		#[serde(default, skip_serializing_if = "Map::is_empty")]
+		Annotation:Map<String, Value>,
+	},
+
+	/// The action failed its final attempt.
+	Failed {
+		/// The sequence number of the action.
+		Sequence:u64,
+
+		/// The error of the final attempt.
+		Error:String,
+
+		/// The attempts it took, all failed.
+		#[serde(default)]
+		History:History,
+	},
+
+	/// An action outlived its `Ttl` or `ExpiresAt` and was not executed.
+	Expired {
+		/// The sequence number of the action.
+		Sequence:u64,
+	},
+
+	/// An internal task panicked; its `Overseer` restarts it as its policy
+	/// says.
+	TaskFailed {
+		/// The name of the task.
+		Name:String,
+
+		/// The panic message.
+		Error:String,
+	},
+
+	/// A transport connection was opened.
+	Opened {
+		/// The identifier of the connection, unique per pump.
+		Connection:u64,
+	},
+
+	/// A transport connection was closed after draining.
+	Closed {
+		/// The identifier of the connection, unique per pump.
+		Connection:u64,
+	},
+
+	/// An administrative operation ran, e.g. a dead-letter requeue.
+	Audit {
+		/// The operation, e.g. `DeadLetter.Requeue`.
+		Operation:String,
+
+		/// The queue operated on.
+		Queue:String,
+
+		/// The sequence numbers of the entries affected.
+		Sequence:Vec<u64>,
+
+		/// The queue entries were moved into, if any.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		Target:Option<String>,
+	},
+
+	/// An action ran longer than the stuck threshold of the `Watchdog`;
+	/// reported once per execution.
+	StuckAction {
+		/// The sequence number of the action.
+		Sequence:u64,
+
+		/// The type of the action.
+		Action:String,
+
+		/// The worker slot running the action.
+		Worker:usize,
+
+		/// How long the action had been running, in milliseconds.
+		ElapsedMs:u64,
+
+		/// The attempt running.
+		Attempt:u32,
+
+		/// Whether the running attempt was cancelled.
+		Cancelled:bool,
+	},
+
+	/// An action referenced a hook matching no registered hook under the
+	/// `Warn` policy of `MissingHook`.
+	MissingHook {
+		/// The sequence number of the action, if it runs in a sequence.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		Sequence:Option<u64>,
+
+		/// The name or pattern referenced.
+		Hook:String,
+	},
+
+	/// The background refresh of a stale result of a `Cacheable` action
+	/// failed; the stale result is kept.
+	RefreshFailed {
+		/// The type of the action.
+		Action:String,
+
+		/// The error of the refresh.
+		Error:String,
+	},
+
+	/// An action naming a graph in its `Graph` metadata changed state, see
+	/// `Graph::Dag`.
+	Node {
+		/// The name of the graph.
+		Graph:String,
+
+		/// The identifier of the node within the graph.
+		Node:String,
+
+		/// The state the node entered.
+		State:State,
+
+		/// How long the action ran, in milliseconds, once it ended.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		DurationMs:Option<u64>,
+	},
+
+	/// The admission policies of a `Life` decided about a submission, see
+	/// `Life::Admit`.
+	Admission {
+		/// The name of the submitter.
+		Identity:String,
+
+		/// The plan function the action calls.
+		Action:String,
+
+		/// What was decided: `Deny` by the first policy refusing, else
+		/// `Modify` with the patches of every policy, else `Allow`.
+		Decision:Decision,
+
+		/// The policies that did not simply allow, in evaluation order.
+		#[serde(default, skip_serializing_if = "Vec::is_empty")]
+		Policy:Vec<String>,
+
+		/// Whether the decision was only previewed, see `Life::DryRun`.
+		#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+		DryRun:bool,
+	},
+}
+
+impl Enum {
+	/// Returns the `Type` tag of the event, e.g. `Completed`.
+	pub fn Type(&self) -> &'static str {
+		match self {
+			Enum::Enqueued { .. } => "Enqueued",
+			Enum::Started { .. } => "Started",
+			Enum::Retried { .. } => "Retried",
+			Enum::Completed { .. } => "Completed",
+			Enum::Failed { .. } => "Failed",
+			Enum::Expired { .. } => "Expired",
+			Enum::TaskFailed { .. } => "TaskFailed",
+			Enum::Opened { .. } => "Opened",
+			Enum::Closed { .. } => "Closed",
+			Enum::Audit { .. } => "Audit",
+			Enum::StuckAction { .. } => "StuckAction",
+			Enum::MissingHook { .. } => "MissingHook",
+			Enum::RefreshFailed { .. } => "RefreshFailed",
+			Enum::Node { .. } => "Node",
+			Enum::Admission { .. } => "Admission",
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::Sequence::{Decision::Enum as Decision, Graph::State::Enum as State},
+	Struct::Sequence::Attempt::History::Struct as History,
+};
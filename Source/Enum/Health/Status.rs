@@ -0,0 +1,15 @@
+/// The health of a component, ordered from best to worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Status"))]
+pub enum Enum {
+	/// Working as intended.
+	Healthy,
+
+	/// Working, but needs attention.
+	Degraded,
+
+	/// Not working; the process should not receive work.
+	Unhealthy,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,22 @@
+/// How a task spawned by a `Runtime` ended other than by returning.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Enum {
+	/// The task was aborted, or dropped by its executor before it ended.
+	Cancelled,
+
+	/// The task panicked, with the panic message.
+	Panicked(String),
+}
+
+impl Display for Enum {
+	fn fmt(&self, Formatter:&mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Enum::Cancelled => write!(Formatter, "task was cancelled"),
+			Enum::Panicked(Message) => write!(Formatter, "task panicked: {}", Message),
+		}
+	}
+}
+
+impl std::error::Error for Enum {}
+
+use std::fmt::{self, Display, Formatter};
@@ -0,0 +1,181 @@
+/// An argument handed to a plan function.
+///
+/// Most arguments are JSON values. Large payloads, such as the body of a
+/// binary frame, are kept as shared bytes or text instead, so passing them
+/// from the transport to the function clones a reference count rather than
+/// the payload. Functions registered with `Plan::WithArgFunction` receive
+/// them as they are; the others receive `Value`, which converts them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Enum {
+	/// A JSON value.
+	Json(Value),
+
+	/// Shared text.
+	Str(Arc<str>),
+
+	/// Shared bytes.
+	Bytes(Bytes),
+}
+
+impl Enum {
+	/// Converts the argument into a JSON value, for functions that take
+	/// `serde_json::Value` arguments.
+	///
+	/// Text becomes a string. Bytes become a string when they are valid
+	/// UTF-8, and the `Fn::Binary::Encode` convention otherwise. Both copy
+	/// the payload.
+	///
+	/// # Returns
+	///
+	/// The argument as a JSON value.
+	pub fn Value(self) -> Value {
+		match self {
+			Enum::Json(Value) => Value,
+			Enum::Str(Text) => Value::String(Text.to_string()),
+			Enum::Bytes(Data) => {
+				match std::str::from_utf8(&Data) {
+					Ok(Text) => Value::String(Text.to_string()),
+					Err(_) => crate::Fn::Binary::Encode::Fn(&Data),
+				}
+			},
+		}
+	}
+
+	/// Borrows the payload of the argument as bytes, without copying it.
+	///
+	/// # Returns
+	///
+	/// The bytes of text, of shared bytes or of a JSON string, or `None` for
+	/// any other JSON value.
+	pub fn Bytes(&self) -> Option<&[u8]> {
+		match self {
+			Enum::Json(Value::String(Text)) => Some(Text.as_bytes()),
+			Enum::Json(_) => None,
+			Enum::Str(Text) => Some(Text.as_bytes()),
+			Enum::Bytes(Data) => Some(Data),
+		}
+	}
+
+	/// Borrows the argument as text, without copying it.
+	///
+	/// # Returns
+	///
+	/// The text of a JSON string, of shared text or of bytes that are valid
+	/// UTF-8, or `None` otherwise.
+	pub fn Str(&self) -> Option<&str> {
+		match self {
+			Enum::Json(Value::String(Text)) => Some(Text),
+			Enum::Json(_) => None,
+			Enum::Str(Text) => Some(Text),
+			Enum::Bytes(Data) => std::str::from_utf8(Data).ok(),
+		}
+	}
+
+	/// Takes the payload of the argument as shared bytes.
+	///
+	/// Shared bytes are returned as they are and a JSON string gives up its
+	/// buffer; only shared text is copied.
+	///
+	/// # Returns
+	///
+	/// The bytes, or the argument back if it is a JSON value other than a
+	/// string.
+	pub fn IntoBytes(self) -> Result<Bytes, Self> {
+		match self {
+			Enum::Json(Value::String(Text)) => Ok(Bytes::from(Text)),
+			Enum::Json(Value) => Err(Enum::Json(Value)),
+			Enum::Str(Text) => Ok(Bytes::copy_from_slice(Text.as_bytes())),
+			Enum::Bytes(Data) => Ok(Data),
+		}
+	}
+
+	/// Returns the size of the argument, as its JSON encoding for a JSON
+	/// value and as its payload otherwise, without encoding it into memory.
+	pub fn Size(&self) -> u64 {
+		match self {
+			Enum::Json(Value) => {
+				let mut Written = Counter(0);
+
+				let _ = serde_json::to_writer(&mut Written, Value);
+
+				Written.0
+			},
+			Enum::Str(Text) => Text.len() as u64,
+			Enum::Bytes(Data) => Data.len() as u64,
+		}
+	}
+}
+
+/// Counts the bytes written into it.
+struct Counter(u64);
+
+impl io::Write for Counter {
+	fn write(&mut self, Buffer:&[u8]) -> io::Result<usize> {
+		self.0 += Buffer.len() as u64;
+
+		Ok(Buffer.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+impl From<Value> for Enum {
+	fn from(Value:Value) -> Self { Enum::Json(Value) }
+}
+
+impl From<Bytes> for Enum {
+	fn from(Data:Bytes) -> Self { Enum::Bytes(Data) }
+}
+
+impl From<Arc<str>> for Enum {
+	fn from(Text:Arc<str>) -> Self { Enum::Str(Text) }
+}
+
+impl From<Enum> for Value {
+	fn from(Argument:Enum) -> Self { Argument.Value() }
+}
+
+impl Serialize for Enum {
+	/// Writes a JSON value as it is, text as a string and bytes as `Value`
+	/// converts them.
+	fn serialize<S>(&self, Serializer:S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer, {
+		match self {
+			Enum::Json(Value) => Value.serialize(Serializer),
+			Enum::Str(Text) => Serializer.serialize_str(Text),
+			Enum::Bytes(Data) => {
+				match std::str::from_utf8(Data) {
+					Ok(Text) => Serializer.serialize_str(Text),
+					Err(_) => crate::Fn::Binary::Encode::Fn(Data).serialize(Serializer),
+				}
+			},
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Enum {
+	/// Reads any JSON value, as `Json`.
+	fn deserialize<D>(Deserializer:D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>, {
+		Value::deserialize(Deserializer).map(Enum::Json)
+	}
+}
+
+#[cfg(feature = "Schema")]
+impl schemars::JsonSchema for Enum {
+	fn is_referenceable() -> bool { false }
+
+	fn schema_name() -> String { Value::schema_name() }
+
+	fn json_schema(Generator:&mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		Value::json_schema(Generator)
+	}
+}
+
+use std::{io, sync::Arc};
+
+use bytes::Bytes;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
@@ -1,5 +1,5 @@
 /// Represents various error types that can occur during sequence actions.
-#[derive(Debug, Error)]
+#[derive(Clone, Debug, PartialEq, Error, Serialize, Deserialize)]
 pub enum Enum {
 	/// Indicates an error related to an invalid license.
 	///
@@ -9,6 +9,15 @@ pub enum Enum {
 	#[error("Invalid License: {0}")]
 	License(String),
 
+	/// Indicates that the `LicenseValidator` of the context refused the
+	/// license of an action, e.g. because it expired.
+	///
+	/// # Arguments
+	///
+	/// * `String` - Why the license was refused.
+	#[error("Invalid license: {0}")]
+	InvalidLicense(String),
+
 	/// Represents an error that occurred during execution of an action.
 	///
 	/// # Arguments
@@ -32,6 +41,148 @@ pub enum Enum {
 	/// * `String` - A description of the specific cancellation error.
 	#[error("Cancellation error: {0}")]
 	Cancellation(String),
+
+	/// Indicates that a copy of an action was requested but the action does
+	/// not support being copied.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the action that could not be copied.
+	#[error("Non-cloneable action: {0}")]
+	NonCloneable(String),
+
+	/// Indicates that an action expired before it could run.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the expired deadline.
+	#[error("Deadline exceeded: {0}")]
+	DeadlineExceeded(String),
+
+	/// Indicates that the function of an action ran past the `Timeout` of
+	/// the action.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the timeout.
+	#[error("Timed out: {0}")]
+	Timeout(String),
+
+	/// Indicates that a result was evicted from its bounded store before it
+	/// was delivered.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the evicted result.
+	#[error("Result evicted: {0}")]
+	ResultEvicted(String),
+
+	/// Indicates that a template placeholder could not be resolved.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description naming the placeholder.
+	#[error("Template error: {0}")]
+	Template(String),
+
+	/// Indicates that an action disappeared from its production line before
+	/// it finished, e.g. because the line was cleared.
+	///
+	/// # Arguments
+	///
+	/// * `String` - A description of the abandoned action.
+	#[error("Action abandoned: {0}")]
+	Abandoned(String),
+
+	/// Indicates that no function of the plan handles an action type.
+	#[error("Unknown action type {Action}; known types: {}", Known.join(", "))]
+	UnknownAction {
+		/// The action type without a function.
+		Action:String,
+
+		/// The action types the plan handles, capped so that a large plan
+		/// does not flood the error.
+		Known:Vec<String>,
+	},
+
+	/// Indicates that the arguments of an action could not be decoded into
+	/// the typed request of its handler.
+	#[error("Invalid request for {Kind} at {Path}: {Message}")]
+	InvalidRequest {
+		/// The kind of the handler, the name of the action.
+		Kind:String,
+
+		/// The path to the value that failed, e.g. `.Step[1].Name`, or `.`
+		/// for the request itself.
+		Path:String,
+
+		/// Serde's description of the problem.
+		Message:String,
+	},
+
+	/// Indicates that the follow-up chain of an action exceeds a limit of
+	/// the production line it was submitted to.
+	#[error("Chain exceeds its {Limit} limit of {Maximum}")]
+	ChainLimit {
+		/// The limit exceeded: `depth`, `nodes` or `bytes`.
+		Limit:String,
+
+		/// The value of the limit.
+		Maximum:usize,
+	},
+
+	/// Indicates that a hook an action references matches no registered
+	/// hook, under the `Fail` policy of `MissingHook`.
+	#[error("Unknown hook: {0}")]
+	UnknownHook(String),
+
+	/// Indicates that the hooks an action references expand to more hooks
+	/// than `hooks.expansion` allows.
+	#[error("Hooks expand beyond the limit of {Maximum}")]
+	HookLimit {
+		/// The value of the limit.
+		Maximum:usize,
+	},
+
+	/// Indicates that an admission policy of the context refused the
+	/// submission of an action.
+	#[error("Admission denied: {Reason}")]
+	Admission {
+		/// Why the submission was refused, naming the policy.
+		Reason:String,
+
+		/// The code the refusal is reported with.
+		Code:Code,
+	},
 }
 
+impl Enum {
+	/// Returns the class of the error, the name of its variant, e.g.
+	/// `DeadlineExceeded`.
+	pub fn Class(&self) -> &'static str {
+		match self {
+			Enum::License(_) => "License",
+			Enum::InvalidLicense(_) => "InvalidLicense",
+			Enum::Execution(_) => "Execution",
+			Enum::Routing(_) => "Routing",
+			Enum::Cancellation(_) => "Cancellation",
+			Enum::NonCloneable(_) => "NonCloneable",
+			Enum::DeadlineExceeded(_) => "DeadlineExceeded",
+			Enum::Timeout(_) => "Timeout",
+			Enum::ResultEvicted(_) => "ResultEvicted",
+			Enum::Template(_) => "Template",
+			Enum::Abandoned(_) => "Abandoned",
+			Enum::UnknownAction { .. } => "UnknownAction",
+			Enum::InvalidRequest { .. } => "InvalidRequest",
+			Enum::ChainLimit { .. } => "ChainLimit",
+			Enum::UnknownHook(_) => "UnknownHook",
+			Enum::HookLimit { .. } => "HookLimit",
+			Enum::Admission { .. } => "Admission",
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+
+use crate::Enum::Transport::Code::Enum as Code;
@@ -0,0 +1,16 @@
+/// How one attempt of an action ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Outcome"))]
+pub enum Enum {
+	/// The attempt succeeded.
+	Succeeded,
+
+	/// The attempt failed.
+	Failed,
+
+	/// The attempt succeeded after the result of another attempt was
+	/// delivered; see `Transport::Terminal`.
+	Duplicate,
+}
+
+use serde::{Deserialize, Serialize};
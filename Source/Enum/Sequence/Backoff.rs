@@ -0,0 +1,46 @@
+/// How long a retry waits after a failed attempt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Enum {
+	/// Every retry waits the same delay.
+	Fixed(Duration),
+
+	/// The retry after attempt `n` waits `Base` times 2^`n`, plus a random
+	/// whole number of milliseconds below `Jitter`.
+	Exponential {
+		/// The unit of the delay.
+		Base:Duration,
+
+		/// The bound of the random addition; zero adds none.
+		Jitter:Duration,
+	},
+}
+
+impl Enum {
+	/// Computes the delay before the retry following an attempt.
+	///
+	/// # Arguments
+	///
+	/// * `Attempt` - The number of the failed attempt, starting at 1.
+	/// * `Randomness` - The source of the jitter.
+	///
+	/// # Returns
+	///
+	/// The delay before the next attempt.
+	pub fn Delay(&self, Attempt:u32, Randomness:&Randomness) -> Duration {
+		match *self {
+			Enum::Fixed(Delay) => Delay,
+			Enum::Exponential { Base, Jitter } => {
+				let Jitter = Jitter.as_millis() as u64;
+
+				let Jitter = if Jitter > 0 { Randomness.Range(0..Jitter) } else { 0 };
+
+				Base.saturating_mul(2u32.saturating_pow(Attempt))
+					.saturating_add(Duration::from_millis(Jitter))
+			},
+		}
+	}
+}
+
+use std::time::Duration;
+
+use crate::Struct::Sequence::Randomness::Struct as Randomness;
@@ -0,0 +1,48 @@
+/// What `DeadLetter::Requeue` did with one entry of a dead-letter queue.
+///
+/// Outcomes are tagged by their `Outcome` field when serialized.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Requeue"))]
+#[serde(tag = "Outcome")]
+pub enum Enum {
+	/// The entry left the dead-letter queue and was enqueued into `Target`.
+	Requeued {
+		/// The sequence number of the entry in the dead-letter queue.
+		Sequence:u64,
+
+		/// The queue the action was enqueued into.
+		Target:String,
+
+		/// The sequence number the action received in `Target`.
+		Requeued:u64,
+	},
+
+	/// An earlier call requeued the entry already; nothing was done.
+	AlreadyRequeued {
+		/// The sequence number of the entry in the dead-letter queue.
+		Sequence:u64,
+
+		/// The queue the action was enqueued into.
+		Target:String,
+
+		/// The sequence number the action received in `Target`.
+		Requeued:u64,
+	},
+
+	/// The dead-letter queue holds no such entry.
+	Missing {
+		/// The sequence number asked for.
+		Sequence:u64,
+	},
+
+	/// `Target` refused the action, which stays in the dead-letter queue.
+	Refused {
+		/// The sequence number of the entry in the dead-letter queue.
+		Sequence:u64,
+
+		/// Why the action was refused.
+		Error:String,
+	},
+}
+
+use serde::{Deserialize, Serialize};
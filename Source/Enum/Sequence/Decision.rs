@@ -0,0 +1,58 @@
+/// What an admission policy decides about a submission.
+///
+/// Serialized tagged by its `Decision` field, e.g.
+/// `{"Decision": "Deny", "Reason": "...", "Code": "ECHO_FORBIDDEN"}`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Decision"))]
+#[serde(tag = "Decision")]
+pub enum Enum {
+	/// The submission goes on unchanged.
+	Allow,
+
+	/// The submission is refused.
+	Deny {
+		/// Why the submission is refused.
+		Reason:String,
+
+		/// The code the refusal is reported with, `ECHO_FORBIDDEN` for the
+		/// built-in policies.
+		Code:Code,
+	},
+
+	/// The submission goes on with its metadata patched: every entry is set
+	/// on the action, an option when named after a `Reserved` key, e.g.
+	/// `Queue` or `Priority`.
+	Modify {
+		/// The metadata set, by key.
+		Patch:Map<String, Value>,
+	},
+}
+
+impl Enum {
+	/// Refuses a submission with `Code::Forbidden`.
+	///
+	/// # Arguments
+	///
+	/// * `Reason` - Why the submission is refused.
+	///
+	/// # Returns
+	///
+	/// A `Deny` decision.
+	pub fn Forbid(Reason:impl Into<String>) -> Self {
+		Enum::Deny { Reason:Reason.into(), Code:Code::Forbidden }
+	}
+
+	/// Returns the name of the decision, e.g. `Deny`.
+	pub fn Name(&self) -> &'static str {
+		match self {
+			Enum::Allow => "Allow",
+			Enum::Deny { .. } => "Deny",
+			Enum::Modify { .. } => "Modify",
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::Enum::Transport::Code::Enum as Code;
@@ -0,0 +1,16 @@
+/// Where an action streams its output, declared in its `OutputTo` metadata.
+///
+/// In JSON a file is `{"File": "exports/log.txt"}` and the submitting
+/// connection is `"Partial"`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// A file, resolved under the jail named by `output.root` in the
+	/// configuration and truncated at every attempt.
+	File(String),
+
+	/// The connection that submitted the action, as `Partial` replies
+	/// carrying base64 chunks ahead of the final `Result`.
+	Partial,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,35 @@
+/// Where a node of a `Graph` is in its execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "NodeState"))]
+pub enum Enum {
+	/// The action has not started.
+	Pending,
+
+	/// The action runs.
+	Running,
+
+	/// The action succeeded.
+	Done,
+
+	/// The action failed or was cancelled.
+	Failed,
+
+	/// The action will not run, as the outcome of the one before it leads
+	/// elsewhere.
+	Skipped,
+}
+
+impl Enum {
+	/// Returns the name of the state, e.g. `Running`.
+	pub fn Name(&self) -> &'static str {
+		match self {
+			Enum::Pending => "Pending",
+			Enum::Running => "Running",
+			Enum::Done => "Done",
+			Enum::Failed => "Failed",
+			Enum::Skipped => "Skipped",
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
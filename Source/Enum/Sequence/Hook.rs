@@ -0,0 +1,66 @@
+/// A hook an action runs in a phase of its `Hooks` metadata, as resolved by
+/// `Life::Expand`.
+#[derive(Clone)]
+pub enum Enum {
+	/// A hook registered under exactly this name.
+	Exact {
+		/// The name of the hook.
+		Name:String,
+
+		/// The hook.
+		Hook:Cycle,
+	},
+
+	/// A hook registered under a pattern matching the name.
+	Pattern {
+		/// The pattern the hook was registered under.
+		Pattern:String,
+
+		/// The name the pattern matched.
+		Name:String,
+
+		/// The hook, called with `Name`.
+		Hook:Pattern,
+	},
+}
+
+impl Enum {
+	/// Returns the name the hook runs for.
+	pub fn Name(&self) -> &str {
+		match self {
+			Enum::Exact { Name, .. } | Enum::Pattern { Name, .. } => Name,
+		}
+	}
+
+	/// Runs the hook.
+	///
+	/// # Returns
+	///
+	/// The result of the hook.
+	pub fn Call(&self) -> Result<(), Error> {
+		match self {
+			Enum::Exact { Hook, .. } => Hook(),
+			Enum::Pattern { Name, Hook, .. } => Hook(Name),
+		}
+	}
+}
+
+impl std::fmt::Debug for Enum {
+	fn fmt(&self, Formatter:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Enum::Exact { Name, .. } => {
+				Formatter.debug_struct("Exact").field("Name", Name).finish()
+			},
+			Enum::Pattern { Pattern, Name, .. } => Formatter
+				.debug_struct("Pattern")
+				.field("Pattern", Pattern)
+				.field("Name", Name)
+				.finish(),
+		}
+	}
+}
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Type::Sequence::Action::{Cycle::Type as Cycle, Pattern::Type as Pattern},
+};
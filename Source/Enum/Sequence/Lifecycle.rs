@@ -0,0 +1,23 @@
+/// Where an action is in its lifecycle, as tracked by `ActionRegistry`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// The action waits in its production line.
+	Queued,
+
+	/// A sequence dequeued the action; it runs or waits in the timer service.
+	Running,
+
+	/// The action finished and its outcome was reported.
+	Completed,
+
+	/// The action disappeared from its production line without finishing;
+	/// its completion resolved with `Error::Abandoned`.
+	Abandoned,
+}
+
+impl Enum {
+	/// Returns whether the action is done with, successfully or not.
+	pub fn Terminal(&self) -> bool { matches!(self, Enum::Completed | Enum::Abandoned) }
+}
+
+use serde::{Deserialize, Serialize};
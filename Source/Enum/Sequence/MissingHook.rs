@@ -0,0 +1,38 @@
+/// What an action does with a hook reference in its `Hooks` metadata that
+/// matches no registered hook.
+///
+/// The policy is read from the action's `MissingHooks` metadata, then from
+/// `Life::Builder::WithMissingHook`, then from `hooks.missing`, and is
+/// `Ignore` unless any of them sets it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// Skip the reference.
+	#[default]
+	Ignore,
+
+	/// Skip the reference, logging a warning, publishing a `MissingHook`
+	/// event and counting it in `echo_hooks_missing_total`.
+	Warn,
+
+	/// Fail with `Error::UnknownHook` before the function runs.
+	Fail,
+}
+
+impl Enum {
+	/// Reads a policy from its name: `ignore`, `warn` or `fail`, in any
+	/// case.
+	///
+	/// # Returns
+	///
+	/// The policy, or `None` for any other name.
+	pub fn Parse(Name:&str) -> Option<Self> {
+		match Name.to_ascii_lowercase().as_str() {
+			"ignore" => Some(Enum::Ignore),
+			"warn" => Some(Enum::Warn),
+			"fail" => Some(Enum::Fail),
+			_ => None,
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,152 @@
+/// The stage of an action's lifecycle at which it runs the hooks of its
+/// `Hooks` metadata.
+///
+/// `Hooks` is either an array of references, all run `BeforeFunction`, or an
+/// object of such arrays by stage, e.g. `{"Accepted": ["Audit"],
+/// "BeforeFunction": ["LogStart"], "AfterFunction": ["LogEnd"], "Failed":
+/// ["Alert"]}`. The keys of the earlier phases stay accepted: `Before` and
+/// `AfterDelay` for `BeforeFunction`, `After` for `AfterFunction` and
+/// `OnError` for `Failed`.
+///
+/// The stages run in the order of `ALL`: `Accepted` when the action is
+/// submitted, `BeforeDelay` when a worker starts holding it for its `Delay`,
+/// `BeforeFunction` once it is due, then `AfterFunction` once the function
+/// succeeded or `Failed` once anything from `BeforeFunction` on failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Enum {
+	/// When the action is submitted through `Life::Submit` or to a source
+	/// delivering into a `Life`, before it is enqueued; a failing hook refuses
+	/// the action.
+	Accepted,
+
+	/// When a worker hands the action to the timer service to wait out its
+	/// `Delay`, so only for an action not yet due; a failing hook is logged
+	/// and the action still waits.
+	BeforeDelay,
+
+	/// Once the action is due, before the function runs; a failing hook fails
+	/// the action.
+	BeforeFunction,
+
+	/// Once the function succeeded, before the `NextAction`; a failing hook
+	/// fails the action.
+	AfterFunction,
+
+	/// Once the hooks before or after, or the function, failed; a failing
+	/// hook is logged and the action keeps its original error.
+	Failed,
+}
+
+impl Enum {
+	/// Every stage, in the order an action may run them.
+	pub const ALL:[Enum; 5] =
+		[Enum::Accepted, Enum::BeforeDelay, Enum::BeforeFunction, Enum::AfterFunction, Enum::Failed];
+
+	/// Returns the key of the stage in the object form of `Hooks`.
+	pub fn Key(self) -> &'static str {
+		match self {
+			Enum::Accepted => "Accepted",
+			Enum::BeforeDelay => "BeforeDelay",
+			Enum::BeforeFunction => "BeforeFunction",
+			Enum::AfterFunction => "AfterFunction",
+			Enum::Failed => "Failed",
+		}
+	}
+
+	/// Reads a key of the object form of `Hooks`.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key, that of a stage or of an earlier phase.
+	///
+	/// # Returns
+	///
+	/// The stage, or `None` for an unknown key.
+	pub fn Parse(Key:&str) -> Option<Self> {
+		match Key {
+			"Before" | "AfterDelay" => Some(Enum::BeforeFunction),
+			"After" => Some(Enum::AfterFunction),
+			"OnError" => Some(Enum::Failed),
+			Key => Self::ALL.into_iter().find(|Stage| Stage.Key() == Key),
+		}
+	}
+
+	/// Reads the references of the stage from `Hooks` metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Hooks` - The `Hooks` metadata.
+	///
+	/// # Returns
+	///
+	/// The references of the stage, under its own key before those under an
+	/// earlier one, or `Error::Execution` if `Hooks` is neither an array nor
+	/// an object of arrays by stage.
+	pub fn References(self, Hooks:&Value) -> Result<Vec<&str>, Error> {
+		let Listed = match Hooks {
+			Value::Array(_) if self == Enum::BeforeFunction => vec![(self.Key(), Hooks)],
+			Value::Array(_) => Vec::new(),
+			Value::Object(Stage) => {
+				let mut Listed = Vec::new();
+
+				for (Key, Reference) in Stage {
+					match Self::Parse(Key) {
+						Some(Stage) if Stage == self => Listed.push((Key.as_str(), Reference)),
+						Some(_) => {},
+						None => {
+							return Err(Error::Execution(format!("Unknown hook stage: {}", Key)));
+						},
+					}
+				}
+
+				// The key of the stage itself first
+				Listed.sort_by_key(|(Key, _)| *Key != self.Key());
+
+				Listed
+			},
+			_ => {
+				return Err(Error::Execution(format!(
+					"Hooks is neither an array nor an object: {}",
+					Hooks
+				)));
+			},
+		};
+
+		let mut Reference = Vec::new();
+
+		for (Key, Listed) in Listed {
+			match Listed {
+				Value::Array(Listed) => Reference.extend(Listed.iter().filter_map(Value::as_str)),
+				Listed => {
+					return Err(Error::Execution(format!(
+						"{} hooks are not an array: {}",
+						Key, Listed
+					)));
+				},
+			}
+		}
+
+		Ok(Reference)
+	}
+
+	/// Reads the references of every stage from `Hooks` metadata, skipping
+	/// whatever is malformed.
+	///
+	/// # Arguments
+	///
+	/// * `Hooks` - The `Hooks` metadata.
+	///
+	/// # Returns
+	///
+	/// The references, `Accepted` first.
+	pub fn Every(Hooks:&Value) -> Vec<&str> {
+		Self::ALL
+			.iter()
+			.flat_map(|Stage| Stage.References(Hooks).unwrap_or_default())
+			.collect()
+	}
+}
+
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
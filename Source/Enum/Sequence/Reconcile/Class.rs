@@ -0,0 +1,32 @@
+/// What `Life::Reconcile` found a queued action to be, against a plan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Enum {
+	/// The plan has its function and `Life` every hook it references, or
+	/// the action does not name a plan function at all.
+	Runnable,
+
+	/// The plan has no function for its action type.
+	MissingFunction,
+
+	/// A hook it references in its `Hooks` metadata matches no registered
+	/// hook.
+	MissingHook,
+
+	/// Its `Action` metadata is not a string or its `Argument` metadata not
+	/// an array, so it cannot be read as a call of a plan function.
+	Undeserializable,
+}
+
+impl Enum {
+	/// Returns the `Reason` an action of this class is dead-lettered with.
+	pub fn Reason(&self) -> &'static str {
+		match self {
+			Enum::Runnable => "runnable",
+			Enum::MissingFunction => "unknown",
+			Enum::MissingHook => "missing-hook",
+			Enum::Undeserializable => "undeserializable",
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,33 @@
+/// What `Life::Reconcile` does with a queued action that cannot run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// Leave the action queued; it fails when it is dequeued.
+	Keep,
+
+	/// Move the action to the dead-letter queue of its queue, annotated
+	/// with why. An action whose queue has none is kept.
+	DeadLetter,
+
+	/// Remove the action, resolving its completion with the error it would
+	/// have failed with, and publish an `Audit` event.
+	Drop,
+}
+
+impl Enum {
+	/// Reads a policy from its configuration name: `keep`, `dead_letter` or
+	/// `drop`.
+	///
+	/// # Returns
+	///
+	/// The policy, or `None` for any other name.
+	pub fn Parse(Name:&str) -> Option<Self> {
+		match Name.to_ascii_lowercase().as_str() {
+			"keep" => Some(Enum::Keep),
+			"dead_letter" | "deadletter" => Some(Enum::DeadLetter),
+			"drop" => Some(Enum::Drop),
+			_ => None,
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
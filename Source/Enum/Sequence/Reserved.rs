@@ -0,0 +1,181 @@
+/// A metadata key the engine reads to decide how an action runs.
+///
+/// Reserved keys live under the `__echo.` prefix, apart from the metadata
+/// of users, so a business field that happens to be named `Delay` delays
+/// nothing. They are set through the typed accessors of `Action::Struct`,
+/// such as `WithOption` and `WithDelay`, the object of a follow-up, or the
+/// `Options` of a wire submission.
+///
+/// For one release, a flat key of the same name is still read when the
+/// prefixed one is missing, with a deprecation warning, and options are
+/// written in both forms; a plan built `WithLegacyKeys(false)` drops this
+/// shim.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Enum {
+	/// The name of the plan function to run.
+	Action,
+
+	/// The arguments passed to the function.
+	Argument,
+
+	/// The license of the action.
+	License,
+
+	/// The Karma queue the action is routed to.
+	Queue,
+
+	/// The priority of the action in its production line.
+	Priority,
+
+	/// How long the action waits before it runs.
+	Delay,
+
+	/// How long each attempt may run.
+	Timeout,
+
+	/// When the action must be finished.
+	Deadline,
+
+	/// How long the action may wait in its queue.
+	Ttl,
+
+	/// When the waiting action expires.
+	ExpiresAt,
+
+	/// The hooks the action runs, by stage.
+	Hooks,
+
+	/// What happens when a hook is not registered.
+	MissingHooks,
+
+	/// The follow-up run after a success.
+	NextAction,
+
+	/// The follow-up run after a success, before `NextAction`.
+	OnSuccess,
+
+	/// The follow-up run after a failure.
+	OnFailure,
+
+	/// Whether a failure compensated by `OnFailure` counts as a success.
+	AbsorbFailure,
+
+	/// Where the output of the function is streamed.
+	OutputTo,
+
+	/// Whether placeholders in the arguments are resolved.
+	Template,
+
+	/// The graph the action is a node of.
+	Graph,
+
+	/// The node of the graph the action is.
+	Node,
+
+	/// The key a completed action is remembered by, so the same action
+	/// submitted again is not executed twice.
+	IdempotencyKey,
+}
+
+/// The prefix of every reserved key.
+pub const PREFIX:&str = "__echo.";
+
+impl Enum {
+	/// Every reserved key.
+	pub const ALL:[Enum; 21] = [
+		Enum::Action,
+		Enum::Argument,
+		Enum::License,
+		Enum::Queue,
+		Enum::Priority,
+		Enum::Delay,
+		Enum::Timeout,
+		Enum::Deadline,
+		Enum::Ttl,
+		Enum::ExpiresAt,
+		Enum::Hooks,
+		Enum::MissingHooks,
+		Enum::NextAction,
+		Enum::OnSuccess,
+		Enum::OnFailure,
+		Enum::AbsorbFailure,
+		Enum::OutputTo,
+		Enum::Template,
+		Enum::Graph,
+		Enum::Node,
+		Enum::IdempotencyKey,
+	];
+
+	/// Returns the name of the key without its prefix, as in `Options` and
+	/// the legacy flat form.
+	pub fn Name(self) -> &'static str {
+		match self {
+			Enum::Action => "Action",
+			Enum::Argument => "Argument",
+			Enum::License => "License",
+			Enum::Queue => "Queue",
+			Enum::Priority => "Priority",
+			Enum::Delay => "Delay",
+			Enum::Timeout => "Timeout",
+			Enum::Deadline => "Deadline",
+			Enum::Ttl => "Ttl",
+			Enum::ExpiresAt => "ExpiresAt",
+			Enum::Hooks => "Hooks",
+			Enum::MissingHooks => "MissingHooks",
+			Enum::NextAction => "NextAction",
+			Enum::OnSuccess => "OnSuccess",
+			Enum::OnFailure => "OnFailure",
+			Enum::AbsorbFailure => "AbsorbFailure",
+			Enum::OutputTo => "OutputTo",
+			Enum::Template => "Template",
+			Enum::Graph => "Graph",
+			Enum::Node => "Node",
+			Enum::IdempotencyKey => "IdempotencyKey",
+		}
+	}
+
+	/// Returns the metadata key the value is stored under, e.g.
+	/// `__echo.Delay`.
+	pub fn Key(self) -> String { format!("{}{}", PREFIX, self.Name()) }
+
+	/// Reads a reserved key.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key, with or without its prefix.
+	///
+	/// # Returns
+	///
+	/// The reserved key, or `None` for a key of the user.
+	pub fn Parse(Key:&str) -> Option<Self> {
+		let Name = Key.strip_prefix(PREFIX).unwrap_or(Key);
+
+		Self::ALL.into_iter().find(|Reserved| Reserved.Name() == Name)
+	}
+
+	/// Warns, once per key and process, that the key was read in its legacy
+	/// flat form.
+	pub fn Deprecated(self) {
+		let First = Warned
+			.lock()
+			.unwrap_or_else(|Poison| Poison.into_inner())
+			.insert(self.Name());
+
+		if First {
+			warn!(
+				"Metadata key {} is read in its deprecated flat form; set it as {} or through \
+				 Options instead",
+				self.Name(),
+				self.Key()
+			);
+		}
+	}
+}
+
+/// The keys whose flat form was read already.
+#[allow(non_upper_case_globals)]
+static Warned:Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+use std::{collections::BTreeSet, sync::Mutex};
+
+use log::warn;
@@ -0,0 +1,25 @@
+/// What a `Overseer` does when one of its tasks panics.
+///
+/// A task that returns is finished under every policy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Enum {
+	/// The task stays down.
+	Never,
+
+	/// The task is restarted at most `Restarts` times, each after `Backoff`.
+	Limited {
+		/// How many restarts are allowed.
+		Restarts:u32,
+
+		/// How long to wait before each restart.
+		Backoff:Duration,
+	},
+
+	/// The task is restarted after every panic, each time after `Backoff`.
+	Always {
+		/// How long to wait before each restart.
+		Backoff:Duration,
+	},
+}
+
+use std::time::Duration;
@@ -0,0 +1,109 @@
+/// Where a source delivers the actions it produces.
+#[derive(Clone)]
+pub enum Enum {
+	/// Every action goes into this production line.
+	Production(Arc<Production>),
+
+	/// Every action is routed by its `Queue` metadata to a Karma queue of
+	/// this lifecycle.
+	Life(Life),
+}
+
+impl Enum {
+	/// Delivers an action to the target.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to deliver.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the action is enqueued, `Error::Admission` if an
+	/// admission policy of the `Life` refused it, `Error::Routing` if it
+	/// could not be routed, the error of an `Accepted` hook refusing it, or
+	/// `Error::ChainLimit` if its line refused its chain.
+	pub async fn Deliver(&self, Action:Box<dyn Action>) -> Result<(), Error> {
+		if let Enum::Life(Life) = self {
+			Life.Screen(&Identity::Local(), Action.as_ref()).await?;
+		}
+
+		let Production = self.Resolve(Action.as_ref()).await?;
+
+		// Only a `Life` has hooks to run
+		if let Enum::Life(Life) = self {
+			Action.Stage(Life, Phase::Accepted).await?;
+		}
+
+		Production.Enqueue(Action).await?;
+
+		Ok(())
+	}
+
+	/// Resolves the production line an action would be delivered to.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to route.
+	///
+	/// # Returns
+	///
+	/// The production line, or `Error::Routing` if the action cannot be
+	/// routed.
+	pub async fn Resolve(&self, Action:&dyn Action) -> Result<Arc<Production>, Error> {
+		match self {
+			Enum::Production(Production) => Ok(Production.clone()),
+			Enum::Life(Life) => Route::Fn(Life, Action).await,
+		}
+	}
+}
+
+impl Enum {
+	/// Returns the event bus of the target, if it has one.
+	///
+	/// # Returns
+	///
+	/// The bus of the `Life`, or the bus attached to the `Production`.
+	pub fn Bus(&self) -> Option<Bus> {
+		match self {
+			Enum::Production(Production) => Production.Bus().cloned(),
+			Enum::Life(Life) => Some(Life.Bus.clone()),
+		}
+	}
+
+	/// Returns the runtime serving the target.
+	///
+	/// # Returns
+	///
+	/// The runtime of the `Life`, or tokio's for a lone `Production`.
+	pub fn Runtime(&self) -> Arc<dyn Runtime> {
+		match self {
+			Enum::Production(_) => Arc::new(Tokio::Struct),
+			Enum::Life(Life) => Life.Runtime(),
+		}
+	}
+}
+
+impl From<Arc<Production>> for Enum {
+	fn from(Production:Arc<Production>) -> Self { Enum::Production(Production) }
+}
+
+impl From<Life> for Enum {
+	fn from(Life:Life) -> Self { Enum::Life(Life) }
+}
+
+use std::sync::Arc;
+
+use crate::{
+	Enum::Sequence::{Action::Error::Enum as Error, Phase::Enum as Phase},
+	Fn::Route,
+	Struct::{
+		Event::Bus::Struct as Bus,
+		Runtime::Tokio,
+		Sequence::{
+			Admission::Identity::Struct as Identity,
+			Life::Struct as Life,
+			Production::Struct as Production,
+		},
+	},
+	Trait::{Runtime::Trait as Runtime, Sequence::Action::Trait as Action},
+};
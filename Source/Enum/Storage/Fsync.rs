@@ -0,0 +1,20 @@
+/// When storage files are flushed to disk.
+///
+/// Read from configuration as `always`, `onclose` or `never`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Enum {
+	/// After every write and append; the slowest and safest.
+	#[serde(rename = "always")]
+	Always,
+
+	/// When a file is written whole or a log is closed.
+	#[default]
+	#[serde(rename = "onclose")]
+	OnClose,
+
+	/// Never; the operating system decides.
+	#[serde(rename = "never")]
+	Never,
+}
+
+use serde::{Deserialize, Serialize};
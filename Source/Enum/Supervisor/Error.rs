@@ -0,0 +1,21 @@
+/// Represents the errors a `Supervisor` can report.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Enum {
+	/// A component failed to start; those started before it were shut down
+	/// again.
+	#[error("Component {Component} failed to start: {Reason}")]
+	Start {
+		/// The name of the component that failed to start.
+		Component:String,
+
+		/// Why the component failed to start.
+		Reason:String,
+
+		/// The shutdown of the components started before it.
+		Rollback:Report,
+	},
+}
+
+use thiserror::Error;
+
+use crate::Struct::Supervisor::Report::Struct as Report;
@@ -0,0 +1,16 @@
+/// How one component of a `Supervisor` ended its shutdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Enum {
+	/// The component stopped in time.
+	Stopped,
+
+	/// The component stopped, but reported an error.
+	///
+	/// # Arguments
+	///
+	/// * `String` - The error the component reported.
+	Failed(String),
+
+	/// The component was still stopping when its deadline passed.
+	Expired,
+}
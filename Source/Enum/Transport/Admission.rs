@@ -0,0 +1,12 @@
+/// Whether a transport accepted a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Admission"))]
+pub enum Enum {
+	/// The submission was acknowledged and queued.
+	Accepted,
+
+	/// The submission was refused; its record says why.
+	Rejected,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,273 @@
+/// The stable code of an error a client can receive, carried by `Error`
+/// replies, e.g. `ECHO_TIMEOUT`.
+///
+/// Codes never change once published; `Catalogue` lists them with whether
+/// retrying can help and what they mean.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Code"))]
+pub enum Enum {
+	/// The token was refused, or a message came before `Auth`.
+	#[serde(rename = "ECHO_UNAUTHORIZED")]
+	Unauthorized,
+
+	/// The message could not be decoded.
+	#[serde(rename = "ECHO_MALFORMED")]
+	Malformed,
+
+	/// The transport is paused or draining.
+	#[serde(rename = "ECHO_NOT_ACCEPTING")]
+	NotAccepting,
+
+	/// The pump is not set up for the message.
+	#[serde(rename = "ECHO_UNSUPPORTED")]
+	Unsupported,
+
+	/// The stored result asked for is gone.
+	#[serde(rename = "ECHO_NOT_FOUND")]
+	NotFound,
+
+	/// The reply could not be encoded.
+	#[serde(rename = "ECHO_ENCODING")]
+	Encoding,
+
+	/// The client cancelled the submission.
+	#[serde(rename = "ECHO_CANCELLED")]
+	Cancelled,
+
+	/// The action was dropped before it ran, e.g. by a shutdown.
+	#[serde(rename = "ECHO_DROPPED")]
+	Dropped,
+
+	/// The license check failed.
+	#[serde(rename = "ECHO_LICENSE")]
+	License,
+
+	/// The function of the action failed.
+	#[serde(rename = "ECHO_EXECUTION")]
+	Execution,
+
+	/// The action could not be routed to a queue.
+	#[serde(rename = "ECHO_ROUTING")]
+	Routing,
+
+	/// The action was cancelled on the server.
+	#[serde(rename = "ECHO_INTERRUPTED")]
+	Interrupted,
+
+	/// The action cannot be copied where a copy is needed.
+	#[serde(rename = "ECHO_NON_CLONEABLE")]
+	NonCloneable,
+
+	/// The action ran out of time.
+	#[serde(rename = "ECHO_TIMEOUT")]
+	Timeout,
+
+	/// The result was evicted before it was delivered.
+	#[serde(rename = "ECHO_RESULT_EVICTED")]
+	ResultEvicted,
+
+	/// A template placeholder of the action could not be resolved.
+	#[serde(rename = "ECHO_TEMPLATE")]
+	Template,
+
+	/// The action disappeared from its queue before it finished.
+	#[serde(rename = "ECHO_ABANDONED")]
+	Abandoned,
+
+	/// No function of the plan handles the action type.
+	#[serde(rename = "ECHO_UNKNOWN_ACTION")]
+	UnknownAction,
+
+	/// The follow-up chain of the action exceeds a limit of its queue.
+	#[serde(rename = "ECHO_CHAIN_LIMIT")]
+	ChainLimit,
+
+	/// A hook the action references is not registered.
+	#[serde(rename = "ECHO_UNKNOWN_HOOK")]
+	UnknownHook,
+
+	/// The hooks the action references expand beyond the limit.
+	#[serde(rename = "ECHO_HOOK_LIMIT")]
+	HookLimit,
+
+	/// Another connection of the identity is open and the pump refuses a
+	/// second one.
+	#[serde(rename = "ECHO_DUPLICATE_CONNECTION")]
+	DuplicateConnection,
+
+	/// A chunk of a result arrived out of order.
+	#[serde(rename = "ECHO_CHUNK_ORDER")]
+	ChunkOrder,
+
+	/// The chunks of a result do not match the digest of its `ResultEnd`.
+	#[serde(rename = "ECHO_DIGEST_MISMATCH")]
+	DigestMismatch,
+
+	/// The transfer of a result in chunks was given up.
+	#[serde(rename = "ECHO_TRANSFER_ABORTED")]
+	TransferAborted,
+
+	/// An admission policy refused the submission.
+	#[serde(rename = "ECHO_FORBIDDEN")]
+	Forbidden,
+}
+
+impl Enum {
+	/// Every code, in catalogue order.
+	pub const ALL:[Enum; 26] = [
+		Enum::Unauthorized,
+		Enum::Malformed,
+		Enum::NotAccepting,
+		Enum::Unsupported,
+		Enum::NotFound,
+		Enum::Encoding,
+		Enum::Cancelled,
+		Enum::Dropped,
+		Enum::License,
+		Enum::Execution,
+		Enum::Routing,
+		Enum::Interrupted,
+		Enum::NonCloneable,
+		Enum::Timeout,
+		Enum::ResultEvicted,
+		Enum::Template,
+		Enum::Abandoned,
+		Enum::UnknownAction,
+		Enum::ChainLimit,
+		Enum::UnknownHook,
+		Enum::HookLimit,
+		Enum::DuplicateConnection,
+		Enum::ChunkOrder,
+		Enum::DigestMismatch,
+		Enum::TransferAborted,
+		Enum::Forbidden,
+	];
+
+	/// Returns the code as sent on the wire, e.g. `ECHO_TIMEOUT`.
+	pub fn Name(&self) -> &'static str {
+		match self {
+			Enum::Unauthorized => "ECHO_UNAUTHORIZED",
+			Enum::Malformed => "ECHO_MALFORMED",
+			Enum::NotAccepting => "ECHO_NOT_ACCEPTING",
+			Enum::Unsupported => "ECHO_UNSUPPORTED",
+			Enum::NotFound => "ECHO_NOT_FOUND",
+			Enum::Encoding => "ECHO_ENCODING",
+			Enum::Cancelled => "ECHO_CANCELLED",
+			Enum::Dropped => "ECHO_DROPPED",
+			Enum::License => "ECHO_LICENSE",
+			Enum::Execution => "ECHO_EXECUTION",
+			Enum::Routing => "ECHO_ROUTING",
+			Enum::Interrupted => "ECHO_INTERRUPTED",
+			Enum::NonCloneable => "ECHO_NON_CLONEABLE",
+			Enum::Timeout => "ECHO_TIMEOUT",
+			Enum::ResultEvicted => "ECHO_RESULT_EVICTED",
+			Enum::Template => "ECHO_TEMPLATE",
+			Enum::Abandoned => "ECHO_ABANDONED",
+			Enum::UnknownAction => "ECHO_UNKNOWN_ACTION",
+			Enum::ChainLimit => "ECHO_CHAIN_LIMIT",
+			Enum::UnknownHook => "ECHO_UNKNOWN_HOOK",
+			Enum::HookLimit => "ECHO_HOOK_LIMIT",
+			Enum::DuplicateConnection => "ECHO_DUPLICATE_CONNECTION",
+			Enum::ChunkOrder => "ECHO_CHUNK_ORDER",
+			Enum::DigestMismatch => "ECHO_DIGEST_MISMATCH",
+			Enum::TransferAborted => "ECHO_TRANSFER_ABORTED",
+			Enum::Forbidden => "ECHO_FORBIDDEN",
+		}
+	}
+
+	/// Returns whether submitting the action again may succeed.
+	pub fn Retryable(&self) -> bool {
+		matches!(
+			self,
+			Enum::NotAccepting
+				| Enum::Dropped
+				| Enum::Execution
+				| Enum::Timeout
+				| Enum::ResultEvicted
+				| Enum::Abandoned
+				| Enum::ChunkOrder
+				| Enum::DigestMismatch
+		)
+	}
+
+	/// Returns what the code means, for client developers.
+	pub fn Description(&self) -> &'static str {
+		match self {
+			Enum::Unauthorized => "The token was refused, or a message came before Auth.",
+			Enum::Malformed => "The message could not be decoded.",
+			Enum::NotAccepting => "The transport is paused or draining; try again later.",
+			Enum::Unsupported => "The server is not set up for the message.",
+			Enum::NotFound => "The stored result asked for is gone.",
+			Enum::Encoding => "The reply could not be encoded.",
+			Enum::Cancelled => "The submission was cancelled by the client.",
+			Enum::Dropped => "The action was dropped before it ran, e.g. by a shutdown.",
+			Enum::License => "The license check failed.",
+			Enum::Execution => "The function of the action failed.",
+			Enum::Routing => "The action could not be routed to a queue.",
+			Enum::Interrupted => "The action was cancelled on the server.",
+			Enum::NonCloneable => "The action cannot be copied where a copy is needed.",
+			Enum::Timeout => "The action ran out of time.",
+			Enum::ResultEvicted => "The result was evicted before it was delivered.",
+			Enum::Template => "A template placeholder of the action could not be resolved.",
+			Enum::Abandoned => "The action disappeared from its queue before it finished.",
+			Enum::UnknownAction => "No function of the plan handles the action type.",
+			Enum::ChainLimit => "The follow-up chain of the action exceeds a limit of its queue.",
+			Enum::UnknownHook => "A hook the action references is not registered.",
+			Enum::HookLimit => "The hooks the action references expand beyond the limit.",
+			Enum::DuplicateConnection => {
+				"Another connection of the identity is open and a second one is refused."
+			},
+			Enum::ChunkOrder => "A chunk of a result arrived out of order.",
+			Enum::DigestMismatch => {
+				"The chunks of a result do not match the digest of its ResultEnd."
+			},
+			Enum::TransferAborted => "The transfer of a result in chunks was given up.",
+			Enum::Forbidden => "An admission policy refused the submission.",
+		}
+	}
+
+	/// Lists every code with whether it is retryable and what it means.
+	///
+	/// # Returns
+	///
+	/// The catalogue, in a stable order.
+	pub fn Catalogue() -> Vec<Catalogue> {
+		Self::ALL
+			.iter()
+			.map(|Code| Catalogue {
+				Code:*Code,
+				Retryable:Code.Retryable(),
+				Description:Code.Description().to_string(),
+			})
+			.collect()
+	}
+}
+
+impl From<&Error> for Enum {
+	// Every variant is listed, so a new one needs a code to compile
+	fn from(Error:&Error) -> Self {
+		match Error {
+			Error::License(_) | Error::InvalidLicense(_) => Enum::License,
+			Error::Execution(_) | Error::InvalidRequest { .. } => Enum::Execution,
+			Error::Routing(_) => Enum::Routing,
+			Error::Cancellation(_) => Enum::Interrupted,
+			Error::NonCloneable(_) => Enum::NonCloneable,
+			Error::DeadlineExceeded(_) | Error::Timeout(_) => Enum::Timeout,
+			Error::ResultEvicted(_) => Enum::ResultEvicted,
+			Error::Template(_) => Enum::Template,
+			Error::Abandoned(_) => Enum::Abandoned,
+			Error::UnknownAction { .. } => Enum::UnknownAction,
+			Error::ChainLimit { .. } => Enum::ChainLimit,
+			Error::UnknownHook(_) => Enum::UnknownHook,
+			Error::HookLimit { .. } => Enum::HookLimit,
+			Error::Admission { Code, .. } => *Code,
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Transport::Catalogue::Struct as Catalogue,
+};
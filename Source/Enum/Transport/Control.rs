@@ -0,0 +1,19 @@
+/// An operator command carried by a `Control` message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Control"))]
+pub enum Enum {
+	/// Stops accepting submissions for good, waits for queued and running
+	/// actions to finish and reports.
+	Drain,
+
+	/// Stops accepting submissions until `Resume`.
+	Pause,
+
+	/// Accepts submissions again after `Pause`.
+	Resume,
+
+	/// Reports the current state.
+	Status,
+}
+
+use serde::{Deserialize, Serialize};
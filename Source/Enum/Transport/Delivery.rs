@@ -0,0 +1,17 @@
+/// The order in which a connection receives the results of its submissions.
+///
+/// Chosen by the client with the `Delivery` field of `Auth`, which pumps
+/// without a token accept as well, and echoed in `Authenticated`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Delivery"))]
+pub enum Enum {
+	/// Results are sent as actions complete.
+	#[default]
+	Unordered,
+
+	/// Results are sent in the order the submissions were received; results
+	/// that complete early are held until every earlier one was sent.
+	SubmissionOrder,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,219 @@
+/// A message sent by a client to a transport.
+///
+/// Messages are tagged by their `Type` field, e.g.
+/// `{"Type":"Submit","Id":"1","Action":"Read","Argument":["a.txt"]}`.
+/// Unknown fields are ignored, so clients may send fields of later wire
+/// versions. `Wire` lists every type carried on the wire.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Message"))]
+#[serde(tag = "Type")]
+pub enum Enum {
+	/// Submits an action for execution.
+	Submit {
+		/// The client-chosen identifier echoed in every reply about this
+		/// action.
+		Id:String,
+
+		/// The name of the plan function to run.
+		Action:String,
+
+		/// The arguments passed to the function; none when absent. A binary
+		/// frame appends its payload as shared bytes, see `Codec::Json`.
+		#[serde(default)]
+		Argument:Vec<Arg>,
+
+		/// Metadata attached to the action; none when absent. Flat reserved
+		/// keys such as `Queue` are still read here for one release.
+		#[serde(default)]
+		Metadata:Map<String, Value>,
+
+		/// The reserved options of the action by name, e.g.
+		/// `{"Queue":"io","Delay":"5s"}`, kept apart from its metadata; none
+		/// when absent. An unknown name is refused as `Malformed`.
+		#[serde(default, alias = "options")]
+		Options:Map<String, Value>,
+	},
+
+	/// Authenticates the connection; must be the first message when the
+	/// pump requires a token.
+	Auth {
+		/// The shared secret configured on the pump.
+		Token:String,
+
+		/// The order in which results are sent to the connection;
+		/// `Unordered` when absent.
+		#[serde(default)]
+		Delivery:Delivery,
+	},
+
+	/// Withdraws a submission of this connection that has not completed;
+	/// answered with `Cancelled`.
+	Cancel {
+		/// The identifier of the submission.
+		Id:String,
+	},
+
+	/// Lists the actions of the plan; answered with `Description`.
+	Describe,
+
+	/// Streams lifecycle events to this connection as `Event` replies,
+	/// replacing any earlier subscription; answered with `Subscribed`.
+	Subscribe {
+		/// The event types to stream, such as `Completed`, or every type
+		/// when empty.
+		#[serde(default)]
+		Filter:Vec<String>,
+	},
+
+	/// Checks that the transport is alive; answered with `Pong`.
+	Ping,
+
+	/// Controls the pump; requires the `Admin` role.
+	Control {
+		/// The command to run.
+		Control:Control,
+
+		/// How long a `Drain` may wait for remaining work, a `Time::Duration`
+		/// such as `5000` milliseconds or `"5s"`; unlimited when absent.
+		#[serde(default)]
+		DeadlineMs:Option<Span>,
+
+		/// The Karma queue a `Pause` or `Resume` applies to, leaving the
+		/// pump and the other queues as they are; the pump when absent.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		Queue:Option<String>,
+	},
+
+	/// Requests a `Life::Stats` snapshot; answered with `Stats`.
+	Stats,
+
+	/// Requests a `Life::Summary` of the Karma queues; answered with
+	/// `Summary`.
+	Summary,
+
+	/// Requests a `Life::Health` report; answered with `Health`.
+	Health,
+
+	/// Lists the actions executing right now; answered with `InFlight`.
+	InFlight,
+
+	/// Reads a result offloaded into the pump's result store; answered with
+	/// `Fetched`.
+	Fetch {
+		/// The reference carried by the `$stored` marker of the result.
+		Stored:Stored,
+	},
+
+	/// Gives up the transfer of a result sent in chunks, e.g. after a chunk
+	/// came out of order; no more chunks of it are sent.
+	ResultAbort {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// Why the transfer is given up.
+		Code:Code,
+
+		/// A description of the failure.
+		Message:String,
+	},
+
+	/// Lists the entries of a dead-letter queue; requires the `Admin` role
+	/// and answered with `DeadLetterListed`.
+	DeadLetterList {
+		/// The name of the dead-letter queue.
+		Queue:String,
+
+		/// The entries to list; every entry when absent.
+		#[serde(default)]
+		Filter:Filter,
+	},
+
+	/// Sends entries of a dead-letter queue back for another try; requires
+	/// the `Admin` role and answered with `DeadLetterRequeued`.
+	DeadLetterRequeue {
+		/// The name of the dead-letter queue.
+		Queue:String,
+
+		/// The sequence numbers of the entries to requeue.
+		Sequence:Vec<u64>,
+
+		/// The name of the queue to enqueue them into.
+		Target:String,
+	},
+
+	/// Deletes entries of a dead-letter queue; requires the `Admin` role and
+	/// answered with `DeadLetterPurged`.
+	DeadLetterPurge {
+		/// The name of the dead-letter queue.
+		Queue:String,
+
+		/// The entries to delete; every entry when absent.
+		#[serde(default)]
+		Filter:Filter,
+
+		/// Whether to only report what would be deleted; `false` when absent.
+		#[serde(default)]
+		DryRun:bool,
+	},
+
+	/// Selects a page of the submissions the pump recorded; requires the
+	/// `Admin` role and answered with `Submissions`.
+	QuerySubmissions {
+		/// The submissions to select and the page to return; the first 100
+		/// submissions when absent.
+		#[serde(default)]
+		Query:Query,
+	},
+
+	/// Hands the queued actions off to the connecting process; requires the
+	/// `Admin` role and answered with a `HandoffChunk`, or with
+	/// `HandoffDone` once nothing is left.
+	Handoff {
+		/// The most actions per chunk; 100 when absent.
+		#[serde(default)]
+		Chunk:Option<usize>,
+	},
+
+	/// Confirms that the actions of a `HandoffChunk` were enqueued; requires
+	/// the `Admin` role and answered like `Handoff`.
+	HandoffAck {
+		/// The number of the chunk.
+		Chunk:u64,
+	},
+
+	/// Stops reading from the connection; pending actions still reply before
+	/// the transport closes it.
+	Close,
+}
+
+impl Enum {
+	/// Returns whether the message requires the `Admin` role.
+	pub fn Admin(&self) -> bool {
+		matches!(
+			self,
+			Enum::Control { .. }
+				| Enum::DeadLetterList { .. }
+				| Enum::DeadLetterRequeue { .. }
+				| Enum::DeadLetterPurge { .. }
+				| Enum::QuerySubmissions { .. }
+				| Enum::Handoff { .. }
+				| Enum::HandoffAck { .. }
+		)
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::{
+		Sequence::Action::Arg::Enum as Arg,
+		Transport::{Code::Enum as Code, Control::Enum as Control, Delivery::Enum as Delivery},
+	},
+	Struct::{
+		Sequence::DeadLetter::Filter::Struct as Filter,
+		Storage::Stored::Struct as Stored,
+		Transport::History::Query::Struct as Query,
+	},
+	Time::Duration::Struct as Span,
+};
@@ -0,0 +1,332 @@
+/// A message sent by a transport back to its client.
+///
+/// Replies are tagged by their `Type` field, like `Message`, and unknown
+/// fields are ignored. `Wire` lists every type carried on the wire.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Reply"))]
+#[serde(tag = "Type")]
+pub enum Enum {
+	/// The submission was accepted and enqueued.
+	Ack {
+		/// The identifier of the submission.
+		Id:String,
+	},
+
+	/// The action completed with a value.
+	Result {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// The value returned by the function.
+		Value:Value,
+
+		/// The attempts it took, the last one successful; empty when absent.
+		#[serde(default)]
+		History:History,
+
+		/// The annotations the action wrote while running; omitted when
+		/// empty.
+		#[serde(default, skip_serializing_if = "Map::is_empty")]
+		Annotation:Map<String, Value>,
+
+		/// Whether the result was sent ahead of earlier submissions because
+		/// the reordering buffer of a `SubmissionOrder` connection was full;
+		/// omitted when `false`.
+		#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+		OutOfOrder:bool,
+	},
+
+	/// A chunk of the output an action streams to the `Partial`
+	/// destination, sent before its `Result`.
+	Partial {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// The chunk, base64-encoded.
+		Data:String,
+	},
+
+	/// A piece of a result too large for one reply, sent by a pump built
+	/// with `WithChunking` instead of the `Result`; the chunks of different
+	/// results may interleave, those of one come in order.
+	ResultChunk {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// The position of the chunk, from 0.
+		Index:u64,
+
+		/// The number of chunks of the result.
+		Total:u64,
+
+		/// The bytes of the chunk, base64-encoded; the chunks of a result
+		/// concatenated are its JSON encoding.
+		Data:String,
+	},
+
+	/// The end of a result sent in chunks.
+	ResultEnd {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// The SHA-256 of the concatenated chunks, in hexadecimal.
+		Digest:String,
+
+		/// The attempts it took, the last one successful; empty when absent.
+		#[serde(default)]
+		History:History,
+
+		/// The annotations the action wrote while running; omitted when
+		/// empty.
+		#[serde(default, skip_serializing_if = "Map::is_empty")]
+		Annotation:Map<String, Value>,
+	},
+
+	/// The transfer of a result in chunks was given up by the pump, or by
+	/// the client with `ResultAbort`; no more chunks of it follow.
+	ResultAbort {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// Why the transfer was given up.
+		Code:Code,
+
+		/// A description of the failure.
+		Message:String,
+	},
+
+	/// A message was rejected or an action failed.
+	Error {
+		/// The identifier of the submission, or `None` when the message
+		/// could not be parsed.
+		Id:Option<String>,
+
+		/// A description of the failure.
+		Message:String,
+
+		/// The stable code of the failure, listed in the catalogue;
+		/// omitted by servers predating codes.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		Code:Option<Code>,
+
+		/// Whether the error was sent ahead of earlier submissions because
+		/// the reordering buffer of a `SubmissionOrder` connection was full;
+		/// omitted when `false`.
+		#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+		OutOfOrder:bool,
+	},
+
+	/// The answer to `Cancel`.
+	Cancelled {
+		/// The identifier of the submission.
+		Id:String,
+
+		/// Whether the submission was withdrawn; `false` when it had
+		/// already completed or is unknown.
+		Cancelled:bool,
+	},
+
+	/// The answer to `Describe`.
+	Description {
+		/// The names of the actions of the plan.
+		Action:Vec<String>,
+
+		/// The `WIRE_VERSION` of the server; 0 when absent.
+		#[serde(default)]
+		Version:u32,
+
+		/// The codes `Error` replies may carry; empty when absent.
+		#[serde(default, skip_serializing_if = "Vec::is_empty")]
+		Catalogue:Vec<Catalogue>,
+	},
+
+	/// The answer to `Subscribe`.
+	Subscribed,
+
+	/// A lifecycle event matching the connection's subscription.
+	Event {
+		/// The event.
+		Event:Event,
+	},
+
+	/// The connection was authenticated.
+	Authenticated {
+		/// What the connection is allowed to do.
+		Role:Role,
+
+		/// The order in which results are sent to the connection;
+		/// `Unordered` when absent.
+		#[serde(default)]
+		Delivery:Delivery,
+
+		/// The `WIRE_VERSION` of the server; 0 when absent.
+		#[serde(default)]
+		Version:u32,
+	},
+
+	/// The answer to `Control`.
+	Control {
+		/// The command that ran.
+		Control:Control,
+
+		/// The state of the pump after the command.
+		State:State,
+
+		/// What happened during a `Drain`; omitted for other commands.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		Drain:Option<Drain>,
+
+		/// The paused Karma queues of a pump serving a `Life`; omitted when
+		/// none is.
+		#[serde(default, skip_serializing_if = "Vec::is_empty")]
+		Paused:Vec<String>,
+	},
+
+	/// A `Control` message was refused.
+	Denied {
+		/// Why the message was refused.
+		Message:String,
+	},
+
+	/// The answer to `Ping`.
+	Pong,
+
+	/// The answer to `Stats`.
+	Stats {
+		/// The snapshot of the pump's `Life`.
+		Stats:Snapshot,
+	},
+
+	/// The answer to `Summary`.
+	Summary {
+		/// The summary of every Karma queue of the pump's `Life`, by name.
+		Queue:BTreeMap<String, Summary>,
+	},
+
+	/// The answer to `Health`.
+	Health {
+		/// The health report of the pump's `Life`.
+		Health:Report,
+	},
+
+	/// The answer to `InFlight`.
+	InFlight {
+		/// The actions executing, the longest running first.
+		Entry:Vec<InFlight>,
+	},
+
+	/// The answer to `Fetch`.
+	Fetched {
+		/// The result read from the store.
+		Value:Value,
+	},
+
+	/// The answer to `DeadLetterList`.
+	DeadLetterListed {
+		/// The name of the dead-letter queue.
+		Queue:String,
+
+		/// The entries selected, in dequeue order.
+		Entry:Vec<Entry>,
+	},
+
+	/// The answer to `DeadLetterRequeue`.
+	DeadLetterRequeued {
+		/// The name of the dead-letter queue.
+		Queue:String,
+
+		/// What happened to every entry, in the order asked for.
+		Outcome:Vec<Requeue>,
+	},
+
+	/// The answer to `DeadLetterPurge`.
+	DeadLetterPurged {
+		/// The name of the dead-letter queue.
+		Queue:String,
+
+		/// The entries deleted, or that would be deleted during a dry run.
+		Entry:Vec<Entry>,
+
+		/// Whether nothing was deleted.
+		DryRun:bool,
+	},
+
+	/// The answer to `QuerySubmissions`.
+	Submissions {
+		/// The submissions selected and the cursor of the next page.
+		Page:Page,
+	},
+
+	/// Queued actions handed off, in answer to `Handoff` or `HandoffAck`.
+	HandoffChunk {
+		/// The number of the chunk, acknowledged with `HandoffAck`.
+		Chunk:u64,
+
+		/// The actions, in dequeue order.
+		Action:Vec<Transfer>,
+	},
+
+	/// The end of a handoff, in answer to `Handoff` or `HandoffAck`.
+	HandoffDone {
+		/// What the handoff moved.
+		Handoff:Handoff,
+	},
+
+	/// The last reply of a connection taken over by a newer one of the same
+	/// identity; the results it still owed are sent to that one.
+	Superseded {
+		/// The identifier of the connection that took over.
+		Connection:u64,
+	},
+}
+
+impl Enum {
+	/// Returns the identifier of the submission the reply is about, if any.
+	pub fn Id(&self) -> Option<&str> {
+		match self {
+			Enum::Ack { Id }
+			| Enum::Result { Id, .. }
+			| Enum::Partial { Id, .. }
+			| Enum::ResultChunk { Id, .. }
+			| Enum::ResultEnd { Id, .. }
+			| Enum::ResultAbort { Id, .. }
+			| Enum::Cancelled { Id, .. } => Some(Id),
+			Enum::Error { Id, .. } => Id.as_deref(),
+			_ => None,
+		}
+	}
+}
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::DeadLetter::Requeue::Enum as Requeue,
+		Transport::{
+			Code::Enum as Code,
+			Control::Enum as Control,
+			Delivery::Enum as Delivery,
+			Role::Enum as Role,
+			State::Enum as State,
+		},
+	},
+	Struct::{
+		Health::Report::Struct as Report,
+		Sequence::{
+			Attempt::History::Struct as History,
+			DeadLetter::Entry::Struct as Entry,
+			Watchdog::InFlight::Struct as InFlight,
+		},
+		Stats::{Snapshot::Struct as Snapshot, Summary::Struct as Summary},
+		Transport::{
+			Catalogue::Struct as Catalogue,
+			Drain::Struct as Drain,
+			Handoff::{Struct as Handoff, Transfer::Struct as Transfer},
+			History::Page::Struct as Page,
+		},
+	},
+};
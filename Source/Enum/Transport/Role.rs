@@ -0,0 +1,12 @@
+/// What an authenticated connection is allowed to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Role"))]
+pub enum Enum {
+	/// Submits actions and queries the process.
+	Client,
+
+	/// Additionally controls the process with `Control` messages.
+	Admin,
+}
+
+use serde::{Deserialize, Serialize};
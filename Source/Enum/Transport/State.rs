@@ -0,0 +1,19 @@
+/// Whether a pump accepts submissions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "State"))]
+pub enum Enum {
+	/// Submissions are accepted.
+	Open,
+
+	/// Submissions are rejected until resumed.
+	Paused,
+
+	/// Submissions are rejected while remaining work finishes.
+	Draining,
+
+	/// Submissions are rejected and remaining work has finished or the
+	/// drain deadline passed.
+	Drained,
+}
+
+use serde::{Deserialize, Serialize};
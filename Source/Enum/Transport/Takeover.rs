@@ -0,0 +1,18 @@
+/// What a pump does when a connection authenticates as an identity that
+/// already has a connection open, set per identity class with
+/// `Pump::WithTakeover`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Enum {
+	/// Refuse the new connection with `ECHO_DUPLICATE_CONNECTION`.
+	Reject,
+
+	/// Keep both, each receiving the results of its own submissions.
+	#[default]
+	Allow,
+
+	/// Close the old connection with a `Superseded` reply and move to the
+	/// new one the results the old one still owes, in the order its
+	/// reordering buffer releases them, the submissions it may cancel and
+	/// its event subscription.
+	TakeOver,
+}
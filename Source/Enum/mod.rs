@@ -1,5 +1,94 @@
+#[cfg(feature = "Client")]
+pub mod Client {
+	pub mod Error;
+
+	pub mod State;
+}
+
+pub mod Event;
+
+pub mod Health {
+	pub mod Status;
+}
+
+pub mod Runtime {
+	pub mod Exit;
+}
+
 pub mod Sequence {
 	pub mod Action {
+		pub mod Arg;
+
 		pub mod Error;
 	}
+
+	pub mod Attempt {
+		pub mod Outcome;
+	}
+
+	pub mod Backoff;
+
+	pub mod DeadLetter {
+		pub mod Requeue;
+	}
+
+	pub mod Decision;
+
+	pub mod Destination;
+
+	pub mod Graph {
+		pub mod State;
+	}
+
+	pub mod Hook;
+
+	pub mod Lifecycle;
+
+	pub mod MissingHook;
+
+	pub mod Phase;
+
+	pub mod Reconcile {
+		pub mod Class;
+
+		pub mod Policy;
+	}
+
+	pub mod Reserved;
+
+	pub mod Restart;
+}
+
+pub mod Source {
+	pub mod Target;
+}
+
+pub mod Storage {
+	pub mod Fsync;
+}
+
+pub mod Supervisor {
+	pub mod Error;
+
+	pub mod Outcome;
+}
+
+pub mod Transport {
+	pub mod Admission;
+
+	pub mod Code;
+
+	pub mod Control;
+
+	pub mod Delivery;
+
+	pub mod Message;
+
+	pub mod Reply;
+
+	pub mod Role;
+
+	pub mod State;
+
+	pub mod Takeover;
 }
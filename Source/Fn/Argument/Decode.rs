@@ -0,0 +1,307 @@
+/// Decodes an action's argument vector into a typed request.
+///
+/// A single argument is decoded on its own, any other number of arguments is
+/// decoded as a JSON array, so both `["input.txt"]` and
+/// `["output.txt", "Hello"]` map naturally onto a string and a tuple.
+///
+/// # Arguments
+///
+/// * `Kind` - The name of the action, used in error messages.
+/// * `Argument` - The argument vector handed to the plan function, as JSON
+///   values or as `Arg`, converted with `Arg::Value`.
+///
+/// # Returns
+///
+/// The decoded request, or an `Error::InvalidRequest` carrying the kind, the
+/// path to the offending value, e.g. `.Step[1].Name`, and serde's
+/// description of the problem (unknown fields, wrong types, missing values).
+pub fn Fn<T:DeserializeOwned, A:Into<Value>>(Kind:&str, Argument:Vec<A>) -> Result<T, Error> {
+	let Argument:Vec<Value> = Argument.into_iter().map(Into::into).collect();
+
+	let Argument = match <[Value; 1]>::try_from(Argument) {
+		Ok([Argument]) => Argument,
+		Err(Argument) => Value::Array(Argument),
+	};
+
+	let Path = RefCell::new(Vec::new());
+
+	T::deserialize(Track { Inner:Argument, Path:&Path, Capture:false }).map_err(|_Error| {
+		let Path = Path.take();
+
+		Error::InvalidRequest {
+			Kind:Kind.to_string(),
+			Path:if Path.is_empty() { ".".to_string() } else { Path.concat() },
+			Message:_Error.to_string(),
+		}
+	})
+}
+
+/// A deserializer recording the path it descends, one segment per map key
+/// or sequence index, and leaving the segments of a failure in place.
+struct Track<'a, D> {
+	/// The deserializer being tracked.
+	Inner:D,
+
+	/// The segments from the root to the value being decoded.
+	Path:&'a RefCell<Vec<String>>,
+
+	/// Whether the value is a map key, recorded as a segment once visited.
+	Capture:bool,
+}
+
+/// The visitor of a `Track`, tracking the maps and sequences it visits.
+struct Visit<'a, V> {
+	Inner:V,
+
+	Path:&'a RefCell<Vec<String>>,
+
+	Capture:bool,
+}
+
+/// A seed decoding an element, key or value through a `Track`.
+struct Seed<'a, S> {
+	Inner:S,
+
+	Path:&'a RefCell<Vec<String>>,
+
+	Capture:bool,
+}
+
+/// A sequence whose elements are tracked by index.
+struct Sequence<'a, A> {
+	Inner:A,
+
+	Path:&'a RefCell<Vec<String>>,
+
+	/// The index of the next element.
+	Index:usize,
+}
+
+/// A map whose values are tracked by key.
+struct Map<'a, A> {
+	Inner:A,
+
+	Path:&'a RefCell<Vec<String>>,
+}
+
+/// Forwards `deserialize_*` methods to the inner deserializer.
+macro_rules! Forward {
+	($($Method:ident($($Name:ident:$Type:ty),*)),* $(,)?) => {
+		$(
+			fn $Method<V:Visitor<'de>>(
+				self,
+				$($Name:$Type,)*
+				Inner:V,
+			) -> Result<V::Value, D::Error> {
+				self.Inner.$Method(
+					$($Name,)*
+					Visit { Inner, Path:self.Path, Capture:self.Capture },
+				)
+			}
+		)*
+	};
+}
+
+impl<'de, D:Deserializer<'de>> Deserializer<'de> for Track<'_, D> {
+	type Error = D::Error;
+
+	Forward! {
+		deserialize_any(),
+		deserialize_bool(),
+		deserialize_i8(),
+		deserialize_i16(),
+		deserialize_i32(),
+		deserialize_i64(),
+		deserialize_i128(),
+		deserialize_u8(),
+		deserialize_u16(),
+		deserialize_u32(),
+		deserialize_u64(),
+		deserialize_u128(),
+		deserialize_f32(),
+		deserialize_f64(),
+		deserialize_char(),
+		deserialize_str(),
+		deserialize_string(),
+		deserialize_bytes(),
+		deserialize_byte_buf(),
+		deserialize_option(),
+		deserialize_unit(),
+		deserialize_unit_struct(Name:&'static str),
+		deserialize_newtype_struct(Name:&'static str),
+		deserialize_seq(),
+		deserialize_tuple(Length:usize),
+		deserialize_tuple_struct(Name:&'static str, Length:usize),
+		deserialize_map(),
+		deserialize_struct(Name:&'static str, Field:&'static [&'static str]),
+		deserialize_enum(Name:&'static str, Variant:&'static [&'static str]),
+		deserialize_identifier(),
+		deserialize_ignored_any(),
+	}
+
+	fn is_human_readable(&self) -> bool { self.Inner.is_human_readable() }
+}
+
+impl<V> Visit<'_, V> {
+	/// Records a visited map key as a segment.
+	fn Note(&self, Key:&dyn Display) {
+		if self.Capture {
+			self.Path.borrow_mut().push(format!(".{}", Key));
+		}
+	}
+}
+
+/// Forwards `visit_*` methods of primitives, recording map keys.
+macro_rules! Primitive {
+	($($Method:ident($Type:ty)),* $(,)?) => {
+		$(
+			fn $Method<E:de::Error>(self, Value:$Type) -> Result<Self::Value, E> {
+				self.Note(&Value);
+
+				self.Inner.$Method(Value)
+			}
+		)*
+	};
+}
+
+impl<'de, V:Visitor<'de>> Visitor<'de> for Visit<'_, V> {
+	type Value = V::Value;
+
+	fn expecting(&self, Formatter:&mut fmt::Formatter) -> fmt::Result {
+		self.Inner.expecting(Formatter)
+	}
+
+	Primitive! {
+		visit_bool(bool),
+		visit_i8(i8),
+		visit_i16(i16),
+		visit_i32(i32),
+		visit_i64(i64),
+		visit_i128(i128),
+		visit_u8(u8),
+		visit_u16(u16),
+		visit_u32(u32),
+		visit_u64(u64),
+		visit_u128(u128),
+		visit_f32(f32),
+		visit_f64(f64),
+		visit_char(char),
+		visit_str(&str),
+		visit_borrowed_str(&'de str),
+		visit_string(String),
+	}
+
+	fn visit_bytes<E:de::Error>(self, Value:&[u8]) -> Result<Self::Value, E> {
+		self.Inner.visit_bytes(Value)
+	}
+
+	fn visit_borrowed_bytes<E:de::Error>(self, Value:&'de [u8]) -> Result<Self::Value, E> {
+		self.Inner.visit_borrowed_bytes(Value)
+	}
+
+	fn visit_byte_buf<E:de::Error>(self, Value:Vec<u8>) -> Result<Self::Value, E> {
+		self.Inner.visit_byte_buf(Value)
+	}
+
+	fn visit_none<E:de::Error>(self) -> Result<Self::Value, E> { self.Inner.visit_none() }
+
+	fn visit_unit<E:de::Error>(self) -> Result<Self::Value, E> { self.Inner.visit_unit() }
+
+	fn visit_some<D:Deserializer<'de>>(self, Inner:D) -> Result<Self::Value, D::Error> {
+		self.Inner.visit_some(Track { Inner, Path:self.Path, Capture:false })
+	}
+
+	fn visit_newtype_struct<D:Deserializer<'de>>(self, Inner:D) -> Result<Self::Value, D::Error> {
+		self.Inner.visit_newtype_struct(Track { Inner, Path:self.Path, Capture:false })
+	}
+
+	fn visit_seq<A:SeqAccess<'de>>(self, Inner:A) -> Result<Self::Value, A::Error> {
+		self.Inner.visit_seq(Sequence { Inner, Path:self.Path, Index:0 })
+	}
+
+	fn visit_map<A:MapAccess<'de>>(self, Inner:A) -> Result<Self::Value, A::Error> {
+		self.Inner.visit_map(Map { Inner, Path:self.Path })
+	}
+
+	fn visit_enum<A:EnumAccess<'de>>(self, Inner:A) -> Result<Self::Value, A::Error> {
+		self.Inner.visit_enum(Inner)
+	}
+}
+
+impl<'de, S:DeserializeSeed<'de>> DeserializeSeed<'de> for Seed<'_, S> {
+	type Value = S::Value;
+
+	fn deserialize<D:Deserializer<'de>>(self, Inner:D) -> Result<Self::Value, D::Error> {
+		self.Inner.deserialize(Track { Inner, Path:self.Path, Capture:self.Capture })
+	}
+}
+
+impl<'de, A:SeqAccess<'de>> SeqAccess<'de> for Sequence<'_, A> {
+	type Error = A::Error;
+
+	fn next_element_seed<S:DeserializeSeed<'de>>(
+		&mut self,
+		Inner:S,
+	) -> Result<Option<S::Value>, A::Error> {
+		self.Path.borrow_mut().push(format!("[{}]", self.Index));
+
+		let Element = self.Inner.next_element_seed(Seed { Inner, Path:self.Path, Capture:false })?;
+
+		self.Path.borrow_mut().pop();
+
+		self.Index += 1;
+
+		Ok(Element)
+	}
+
+	fn size_hint(&self) -> Option<usize> { self.Inner.size_hint() }
+}
+
+impl<'de, A:MapAccess<'de>> MapAccess<'de> for Map<'_, A> {
+	type Error = A::Error;
+
+	fn next_key_seed<S:DeserializeSeed<'de>>(
+		&mut self,
+		Inner:S,
+	) -> Result<Option<S::Value>, A::Error> {
+		let Depth = self.Path.borrow().len();
+
+		let Key = self.Inner.next_key_seed(Seed { Inner, Path:self.Path, Capture:true })?;
+
+		// A key visited as anything but a primitive still takes a segment
+		if Key.is_some() && self.Path.borrow().len() == Depth {
+			self.Path.borrow_mut().push(".?".to_string());
+		}
+
+		Ok(Key)
+	}
+
+	fn next_value_seed<S:DeserializeSeed<'de>>(&mut self, Inner:S) -> Result<S::Value, A::Error> {
+		let Value = self.Inner.next_value_seed(Seed { Inner, Path:self.Path, Capture:false })?;
+
+		self.Path.borrow_mut().pop();
+
+		Ok(Value)
+	}
+
+	fn size_hint(&self) -> Option<usize> { self.Inner.size_hint() }
+}
+
+use std::{
+	cell::RefCell,
+	fmt::{self, Display},
+};
+
+use serde::de::{
+	self,
+	DeserializeOwned,
+	DeserializeSeed,
+	Deserializer,
+	EnumAccess,
+	MapAccess,
+	SeqAccess,
+	Visitor,
+};
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
@@ -0,0 +1,36 @@
+/// Unwraps binary data produced with `Fn::Binary::Encode`.
+///
+/// # Arguments
+///
+/// * `Value` - A value following the `{"encoding": "base64", "data": ...}`
+///   convention.
+///
+/// # Returns
+///
+/// `Ok(Some(bytes))` if the value follows the convention, `Ok(None)` if it is
+/// any other value, or an `Error::Execution` if it claims to be base64 but the
+/// data cannot be decoded.
+pub fn Fn(Value:&Value) -> Result<Option<Vec<u8>>, Error> {
+	let Some(Object) = Value.as_object() else {
+		return Ok(None);
+	};
+
+	if Object.get("encoding").and_then(Value::as_str) != Some("base64") {
+		return Ok(None);
+	}
+
+	let Data = Object
+		.get("data")
+		.and_then(Value::as_str)
+		.ok_or_else(|| Error::Execution("Binary result is missing its data".to_string()))?;
+
+	STANDARD
+		.decode(Data)
+		.map(Some)
+		.map_err(|_Error| Error::Execution(format!("Invalid base64 result: {}", _Error)))
+}
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
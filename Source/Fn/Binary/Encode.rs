@@ -0,0 +1,19 @@
+/// Wraps binary data in the documented result convention for plan functions.
+///
+/// Plan functions return a `serde_json::Value`, which cannot carry raw bytes.
+/// Functions that produce binary data should return the value built here,
+/// which has the shape `{"encoding": "base64", "data": "<base64>"}`, so that
+/// consumers can tell it apart from plain strings and decode it with
+/// `Fn::Binary::Decode`.
+///
+/// # Arguments
+///
+/// * `Data` - The bytes to wrap.
+///
+/// # Returns
+///
+/// A `serde_json::Value` object holding the base64-encoded bytes.
+pub fn Fn(Data:&[u8]) -> Value { json!({ "encoding": "base64", "data": STANDARD.encode(Data) }) }
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
@@ -0,0 +1,61 @@
+/// Matches a candidate string against a glob-style pattern.
+///
+/// Supported syntax:
+/// - `?` matches any single character except `/`
+/// - `*` matches any run of characters except `/`
+/// - `**` matches any run of characters, including `/`
+///
+/// Every other character matches itself.
+///
+/// # Arguments
+///
+/// * `Pattern` - The glob pattern.
+/// * `Candidate` - The string to match.
+///
+/// # Returns
+///
+/// `true` if the whole candidate matches the pattern.
+pub fn Fn(Pattern:&str, Candidate:&str) -> bool {
+	let Pattern = Pattern.chars().collect::<Vec<_>>();
+
+	let Candidate = Candidate.chars().collect::<Vec<_>>();
+
+	// Match[j] is true when the pattern processed so far matches Candidate[..j]
+	let mut Match = vec![false; Candidate.len() + 1];
+
+	Match[0] = true;
+
+	let mut Index = 0;
+
+	while Index < Pattern.len() {
+		let Deep = Pattern[Index] == '*' && Pattern.get(Index + 1) == Some(&'*');
+
+		let mut Next = vec![false; Candidate.len() + 1];
+
+		match Pattern[Index] {
+			'*' => {
+				for Position in 0..=Candidate.len() {
+					Next[Position] = Match[Position]
+						|| (Position > 0
+							&& Next[Position - 1] && (Deep || Candidate[Position - 1] != '/'));
+				}
+			},
+			'?' => {
+				for Position in 1..=Candidate.len() {
+					Next[Position] = Match[Position - 1] && Candidate[Position - 1] != '/';
+				}
+			},
+			Character => {
+				for Position in 1..=Candidate.len() {
+					Next[Position] = Match[Position - 1] && Candidate[Position - 1] == Character;
+				}
+			},
+		}
+
+		Match = Next;
+
+		Index += if Deep { 2 } else { 1 };
+	}
+
+	Match[Candidate.len()]
+}
@@ -0,0 +1,17 @@
+/// Reports an error raised by a source task without stopping it.
+///
+/// The error is logged and counted in the `echo_source_errors_total` counter,
+/// labelled with the source that raised it, so failing sources stay visible.
+///
+/// # Arguments
+///
+/// * `Source` - The kind of source reporting the error (e.g. `"watch"`).
+/// * `Message` - A description of the error.
+pub fn Fn(Source:&str, Message:&str) {
+	error!("Source {} failed: {}", Source, Message);
+
+	counter!("echo_source_errors_total", "source" => Source.to_string()).increment(1);
+}
+
+use log::error;
+use metrics::counter;
@@ -0,0 +1,64 @@
+/// Runs an operation until it succeeds, following a retry policy.
+///
+/// Every attempt is recorded into the returned history. A failed attempt is
+/// retried after the backoff of the policy, unless it was the last attempt
+/// allowed, its error is not retryable or the policy's cancellation token
+/// was cancelled. The backoff sleeps on the clock of the runtime, so a
+/// paused clock drives it in tests.
+///
+/// # Arguments
+///
+/// * `Retry` - The retry policy.
+/// * `Operation` - Starts one attempt of the operation.
+///
+/// # Returns
+///
+/// The value of the first successful attempt, or the error of the last one,
+/// together with the history of all attempts.
+pub async fn Fn<T, E, O, F>(Retry:&Retry<E>, mut Operation:O) -> (Result<T, E>, History)
+where
+	O: FnMut() -> F,
+	F: Future<Output = Result<T, E>>,
+	E: Display, {
+	let mut History = History::New(Retry.Keep);
+
+	let mut Number = 0;
+
+	loop {
+		Number += 1;
+
+		let Started = Attempt::Now();
+
+		let Result = Operation().await;
+
+		History.Push(Attempt::New(Number, Started, &Result));
+
+		let _Error = match Result {
+			Ok(Value) => return (Ok(Value), History),
+			Err(_Error) => _Error,
+		};
+
+		if Number >= Retry.Attempts || !Retry.Retryable(&_Error) {
+			return (Err(_Error), History);
+		}
+
+		let Backoff = Retry.Delay(Number);
+
+		if let Some(Last) = History.LastMut() {
+			*Last = Last.clone().WithBackoff(Backoff);
+		}
+
+		Retry.Notify(&History, &_Error);
+
+		if !Retry.Wait(Backoff).await {
+			return (Err(_Error), History);
+		}
+	}
+}
+
+use std::{fmt::Display, future::Future};
+
+use crate::Struct::Sequence::{
+	Attempt::{History::Struct as History, Struct as Attempt},
+	Retry::Struct as Retry,
+};
@@ -0,0 +1,42 @@
+/// Resolves the production line an action is routed to.
+///
+/// The action's `Queue` metadata names a Karma queue of the lifecycle. A
+/// missing queue is created if the lifecycle has a queue factory.
+///
+/// # Arguments
+///
+/// * `Life` - The lifecycle whose Karma queues are searched.
+/// * `Action` - The action to route.
+///
+/// # Returns
+///
+/// The production line named by the action, or `Error::Routing` if the
+/// action names no queue or an unknown one that cannot be created.
+pub async fn Fn(Life:&Life, Action:&dyn Action) -> Result<Arc<Production>, Error> {
+	let Queue = match Action.Metadata("Queue").await {
+		Some(Value::String(Queue)) => Queue,
+		Some(Other) => {
+			return Err(Error::Routing(format!("Queue metadata is not a string: {}", Other)))
+		},
+		None => return Err(Error::Routing("Action has no Queue metadata".to_string())),
+	};
+
+	match Life.Karma.get(&Queue).map(|Production| Production.clone()) {
+		Some(Production) => {
+			Life.Dynamic.Touch(&Queue);
+
+			Ok(Production)
+		},
+		None => Life.Dynamic.Create(Life, &Queue),
+	}
+}
+
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Life::Struct as Life, Production::Struct as Production},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,206 @@
+/// Resolves the `{{...}}` placeholders in the strings of a JSON value.
+///
+/// Strings anywhere in `Value`, including nested arrays and objects, are
+/// scanned for tokens of the form `{{provider}}` or `{{provider:argument}}`:
+///
+/// - `now` and `date` - the current UTC time, formatted by an optional spec of
+///   `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%s` (Unix seconds) and `%%`; `now`
+///   defaults to `%Y-%m-%dT%H:%M:%SZ`, `date` to `%Y-%m-%d`.
+/// - `config:<key>` - a key of the `Life` configuration.
+/// - `cache:<key>` - a key of the `Life` cache.
+/// - `metadata:<key>` - a metadata entry of the action, or a value inside it
+///   with `metadata:<key>/<pointer>`, e.g. `metadata:Previous/content`.
+///
+/// Resolved strings are inserted as they are, other values as JSON. `\{\{`
+/// stands for a literal `{{`.
+///
+/// # Arguments
+///
+/// * `Life` - The lifecycle whose configuration and cache are read.
+/// * `Metadata` - The metadata of the action.
+/// * `Value` - The value to resolve.
+///
+/// # Returns
+///
+/// The value with every token replaced, or `Error::Template` naming the
+/// first token that could not be resolved.
+pub async fn Fn(Life:&Life, Metadata:&Vector, Value:Value) -> Result<Value, Error> {
+	Walk(Life, Metadata, Value).await
+}
+
+/// Resolves the tokens of a value and everything nested in it.
+fn Walk<'a>(
+	Life:&'a Life,
+	Metadata:&'a Vector,
+	Value:Value,
+) -> BoxFuture<'a, Result<Value, Error>> {
+	async move {
+		Ok(match Value {
+			Value::String(Text) => Value::String(Render(Life, Metadata, &Text).await?),
+			Value::Array(Item) => {
+				let mut Resolved = Vec::with_capacity(Item.len());
+
+				for Item in Item {
+					Resolved.push(Walk(Life, Metadata, Item).await?);
+				}
+
+				Value::Array(Resolved)
+			},
+			Value::Object(Entry) => {
+				let mut Resolved = Map::new();
+
+				for (Key, Item) in Entry {
+					Resolved.insert(Key, Walk(Life, Metadata, Item).await?);
+				}
+
+				Value::Object(Resolved)
+			},
+			Other => Other,
+		})
+	}
+	.boxed()
+}
+
+/// Replaces the tokens of one string.
+async fn Render(Life:&Life, Metadata:&Vector, Text:&str) -> Result<String, Error> {
+	let mut Rendered = String::with_capacity(Text.len());
+
+	let mut Rest = Text;
+
+	while let Some(Start) = Rest.find(['\\', '{']) {
+		Rendered.push_str(&Rest[..Start]);
+
+		Rest = &Rest[Start..];
+
+		if let Some(After) = Rest.strip_prefix("\\{\\{") {
+			Rendered.push_str("{{");
+
+			Rest = After;
+		} else if let Some(After) = Rest.strip_prefix("{{") {
+			let End = After
+				.find("}}")
+				.ok_or_else(|| Error::Template(format!("Unclosed token in {}", Text)))?;
+
+			Rendered.push_str(&Resolve(Life, Metadata, After[..End].trim()).await?);
+
+			Rest = &After[End + 2..];
+		} else {
+			Rendered.push_str(&Rest[..1]);
+
+			Rest = &Rest[1..];
+		}
+	}
+
+	Rendered.push_str(Rest);
+
+	Ok(Rendered)
+}
+
+/// Resolves one token through its provider.
+async fn Resolve(Life:&Life, Metadata:&Vector, Token:&str) -> Result<String, Error> {
+	let Unresolved = || Error::Template(format!("Unresolved token {{{{{}}}}}", Token));
+
+	let (Provider, Argument) = match Token.split_once(':') {
+		Some((Provider, Argument)) => (Provider, Some(Argument)),
+		None => (Token, None),
+	};
+
+	let Value = match (Provider, Argument) {
+		("now", Format) => Value::String(Time(Format.unwrap_or("%Y-%m-%dT%H:%M:%SZ"))?),
+		("date", Format) => Value::String(Time(Format.unwrap_or("%Y-%m-%d"))?),
+		("config", Some(Key)) => Life.Fate.get::<Value>(Key).map_err(|_| Unresolved())?,
+		("cache", Some(Key)) => Life.Cache.Get(&Key.to_string()).ok_or_else(Unresolved)?,
+		("metadata", Some(Key)) => {
+			let (Key, Pointer) = match Key.split_once('/') {
+				Some((Key, Pointer)) => (Key, Some(Pointer)),
+				None => (Key, None),
+			};
+
+			let Entry = Metadata.Get(Key).await.ok_or_else(Unresolved)?;
+
+			match Pointer {
+				Some(Pointer) => {
+					Entry.pointer(&format!("/{}", Pointer)).cloned().ok_or_else(Unresolved)?
+				},
+				None => Entry,
+			}
+		},
+		_ => return Err(Unresolved()),
+	};
+
+	Ok(match Value {
+		Value::String(Text) => Text,
+		Other => Other.to_string(),
+	})
+}
+
+/// Formats the current UTC time.
+fn Time(Format:&str) -> Result<String, Error> {
+	let Now = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|Now| Now.as_secs())
+		.unwrap_or_default();
+
+	let (Days, Second) = ((Now / 86_400) as i64, Now % 86_400);
+
+	// Civil date from days since the epoch, after Howard Hinnant
+	let Shifted = Days + 719_468;
+
+	let Era = Shifted.div_euclid(146_097);
+
+	let DayOfEra = Shifted - Era * 146_097;
+
+	let YearOfEra = (DayOfEra - DayOfEra / 1_460 + DayOfEra / 36_524 - DayOfEra / 146_096) / 365;
+
+	let DayOfYear = DayOfEra - (365 * YearOfEra + YearOfEra / 4 - YearOfEra / 100);
+
+	let Month = (5 * DayOfYear + 2) / 153;
+
+	let Day = DayOfYear - (153 * Month + 2) / 5 + 1;
+
+	let Month = if Month < 10 { Month + 3 } else { Month - 9 };
+
+	let Year = YearOfEra + Era * 400 + i64::from(Month <= 2);
+
+	let mut Formatted = String::new();
+
+	let mut Spec = Format.chars();
+
+	while let Some(Character) = Spec.next() {
+		if Character != '%' {
+			Formatted.push(Character);
+
+			continue;
+		}
+
+		match Spec.next() {
+			Some('Y') => Formatted.push_str(&format!("{:04}", Year)),
+			Some('m') => Formatted.push_str(&format!("{:02}", Month)),
+			Some('d') => Formatted.push_str(&format!("{:02}", Day)),
+			Some('H') => Formatted.push_str(&format!("{:02}", Second / 3_600)),
+			Some('M') => Formatted.push_str(&format!("{:02}", Second / 60 % 60)),
+			Some('S') => Formatted.push_str(&format!("{:02}", Second % 60)),
+			Some('s') => Formatted.push_str(&Now.to_string()),
+			Some('%') => Formatted.push('%'),
+			Other => {
+				return Err(Error::Template(format!(
+					"Unknown time format %{} in {}",
+					Other.map(String::from).unwrap_or_default(),
+					Format
+				)));
+			},
+		}
+	}
+
+	Ok(Formatted)
+}
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures::future::{BoxFuture, FutureExt};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Life::Struct as Life, Vector::Struct as Vector},
+};
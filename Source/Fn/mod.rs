@@ -0,0 +1,19 @@
+pub mod Argument {
+	pub mod Decode;
+}
+
+pub mod Binary {
+	pub mod Decode;
+
+	pub mod Encode;
+}
+
+pub mod Glob;
+
+pub mod Report;
+
+pub mod Retry;
+
+pub mod Route;
+
+pub mod Template;
@@ -0,0 +1,255 @@
+//! Bulk import of action submissions from a file, such as the backlog of a
+//! job system being migrated, paced so the ingest path is never flooded.
+//!
+//! A file whose name ends in `.csv` is CSV: its first line names the
+//! columns, `Action`, and optionally `Argument` and `Metadata` holding JSON,
+//! every other column becoming a metadata entry; each record is one line.
+//! Any other file is JSONL, each line an object such as
+//! `{"Action":"Write","Argument":["a.txt"],"Metadata":{"Queue":"io"}}`.
+//! Blank lines are skipped.
+//!
+//! Lines are read as they are submitted, never the whole file at once. Each
+//! is checked against the `Options` manifest, its action and the
+//! `InputTypes` of its arguments, then submitted to a `Destination` in
+//! batches, up to `Concurrency` at a time, at most `Rate` per second, each
+//! waiting while the queue it goes to holds `Depth` actions or more. After
+//! every batch the position and report are written to the `Checkpoint`
+//! sidecar, so a run interrupted by its `Cancellation`, or killed, resumes
+//! after the last batch it finished.
+//!
+//! Every submission carries `IdempotencyKey` metadata made of its line
+//! number and digest, the same on every run, so a destination can drop a
+//! line of the batch a killed run was submitting when it is submitted again.
+
+/// Imports the lines of a file, resuming after its checkpoint if it has
+/// one.
+///
+/// # Arguments
+///
+/// * `Path` - The path of the JSONL or CSV file.
+/// * `Destination` - Where the lines are submitted.
+/// * `Options` - How the lines are paced and checked.
+///
+/// # Returns
+///
+/// The report of every line handled, in this run or an earlier one, or the
+/// error reading the file or writing its checkpoint. A line refused is
+/// reported, not an error.
+pub async fn Run(
+	Path:impl AsRef<Path>,
+	Destination:&dyn Destination,
+	Options:&Options::Struct,
+) -> io::Result<Report::Struct> {
+	let Path = Path.as_ref();
+
+	let Sidecar = Checkpoint::Struct::Sidecar(Path);
+
+	let mut Checkpoint = Checkpoint::Struct::Read(&Sidecar).await?.unwrap_or_default();
+
+	let Csv = Path.extension().is_some_and(|Extension| Extension.eq_ignore_ascii_case("csv"));
+
+	let mut Reader = BufReader::new(File::open(Path).await?).lines();
+
+	let (mut Header, mut Number, mut Batch) = (None, 0, Vec::new());
+
+	let mut Pace = Pace::New(Options.Rate);
+
+	while let Some(Text) = Reader.next_line().await? {
+		Number += 1;
+
+		if Csv && Header.is_none() {
+			Header = Some(
+				Line::Struct::Header(&Text)
+					.map_err(|_Error| io::Error::new(io::ErrorKind::InvalidData, _Error))?,
+			);
+
+			continue;
+		}
+
+		if Number <= Checkpoint.Line || Text.trim().is_empty() {
+			continue;
+		}
+
+		let Parsed = match &Header {
+			Some(Header) => Line::Struct::Csv(Number, &Text, Header),
+			None => Line::Struct::Json(Number, &Text),
+		};
+
+		Batch.push((Number, Parsed.and_then(|Line| Validate(Line, Options))));
+
+		if Batch.len() < Options.Batch {
+			continue;
+		}
+
+		let Batch = std::mem::take(&mut Batch);
+
+		Submit(Destination, Options, &mut Pace, Batch, &mut Checkpoint.Report).await;
+
+		Checkpoint.Line = Number;
+
+		Checkpoint.Write(&Sidecar).await?;
+
+		if Options.Cancellation.as_ref().is_some_and(Cancellation::IsCancelled) {
+			info!("Import of {} interrupted after line {}", Path.display(), Number);
+
+			return Ok(Checkpoint.Report);
+		}
+	}
+
+	Submit(Destination, Options, &mut Pace, Batch, &mut Checkpoint.Report).await;
+
+	Checkpoint.Line = Number;
+
+	Checkpoint.Report.Complete = true;
+
+	Checkpoint.Write(&Sidecar).await?;
+
+	info!(
+		"Imported {}: {} accepted, {} rejected",
+		Path.display(),
+		Checkpoint.Report.Accepted,
+		Checkpoint.Report.Rejected.len()
+	);
+
+	Ok(Checkpoint.Report)
+}
+
+/// Checks a line against the manifest of the options, if any: it must name
+/// one of its actions, with arguments of its `InputTypes`.
+fn Validate(Line:Line::Struct, Options:&Options::Struct) -> Result<Line::Struct, String> {
+	let Some(Manifest) = &Options.Manifest else {
+		return Ok(Line);
+	};
+
+	let Signature = Manifest
+		.Action
+		.iter()
+		.find(|Signature| Signature.Name == Line.Action)
+		.ok_or_else(|| format!("Unknown action {}", Line.Action))?;
+
+	let Argument = Line.Argument.iter().cloned().map(Arg::Json).collect::<Vec<_>>();
+
+	Signature.CheckInput(&Argument).map_err(|_Error| _Error.to_string())?;
+
+	Ok(Line)
+}
+
+/// Submits a batch, adding the outcome of every line to the report in file
+/// order.
+async fn Submit(
+	Destination:&dyn Destination,
+	Options:&Options::Struct,
+	Pace:&mut Pace,
+	Batch:Vec<(u64, Result<Line::Struct, String>)>,
+	Report:&mut Report::Struct,
+) {
+	let Runtime = Destination.Runtime();
+
+	let Outcome = stream::iter(Batch.into_iter().map(|(Number, Line)| {
+		let Slot = Line.is_ok().then(|| Pace.Next(Runtime.Now()));
+
+		let Runtime = &Runtime;
+
+		async move {
+			let Line = Line?;
+
+			if let Some(Slot) = Slot {
+				Runtime.SleepUntil(Slot).await;
+			}
+
+			Drain(Destination, &**Runtime, &Line, Options).await;
+
+			Destination.Submit(&Line).await
+		}
+		.map(move |Outcome| (Number, Outcome))
+	}))
+	.buffered(Options.Concurrency)
+	.collect::<Vec<_>>()
+	.await;
+
+	for (Number, Outcome) in Outcome {
+		match Outcome {
+			Ok(()) => Report.Accepted += 1,
+			Err(Reason) => {
+				counter!("echo_import_rejected_total").increment(1);
+
+				Report.Rejected.push(Rejection::Struct { Line:Number, Reason });
+			},
+		}
+	}
+}
+
+/// Waits while the queue of a line holds `Depth` actions or more.
+async fn Drain(
+	Destination:&dyn Destination,
+	Runtime:&dyn Runtime,
+	Line:&Line::Struct,
+	Options:&Options::Struct,
+) {
+	let Some(Maximum) = Options.Depth else {
+		return;
+	};
+
+	while Destination.Depth(Line).await.is_some_and(|Depth| Depth >= Maximum) {
+		Runtime.Sleep(Options.Poll).await;
+	}
+}
+
+/// Spaces submissions at most `Rate` per second apart.
+struct Pace {
+	/// The time between two submissions, or `None` for no limit.
+	Interval:Option<Duration>,
+
+	/// When the previous submission was allowed.
+	Last:Option<Instant>,
+}
+
+impl Pace {
+	/// Creates a pace allowing `Rate` submissions per second.
+	fn New(Rate:Option<f64>) -> Self {
+		let Interval =
+			Rate.filter(|Rate| *Rate > 0.0).map(|Rate| Duration::from_secs_f64(1.0 / Rate));
+
+		Pace { Interval, Last:None }
+	}
+
+	/// Returns when the next submission may start, given the time now.
+	fn Next(&mut self, Now:Instant) -> Instant {
+		let Next = match (self.Interval, self.Last) {
+			(Some(Interval), Some(Last)) => (Last + Interval).max(Now),
+			_ => Now,
+		};
+
+		self.Last = Some(Next);
+
+		Next
+	}
+}
+
+use std::{io, path::Path, time::Duration};
+
+use futures::{stream, FutureExt, StreamExt};
+use log::info;
+use metrics::counter;
+use tokio::{
+	fs::File,
+	io::{AsyncBufReadExt, BufReader},
+	time::Instant,
+};
+
+use crate::{
+	Enum::Sequence::Action::Arg::Enum as Arg,
+	Import::Report::Rejection,
+	Struct::Sequence::Cancellation::Struct as Cancellation,
+	Trait::{Import::Destination::Trait as Destination, Runtime::Trait as Runtime},
+};
+
+pub mod Checkpoint;
+
+pub mod Line;
+
+pub mod Local;
+
+pub mod Options;
+
+pub mod Report;
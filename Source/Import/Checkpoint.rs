@@ -0,0 +1,78 @@
+/// How far an import got, kept in a sidecar file next to its input so an
+/// interrupted import resumes after the last batch it finished.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Struct {
+	/// The last line of the input handled, counting from 1.
+	pub Line:u64,
+
+	/// The report of the lines up to `Line`.
+	pub Report:Report::Struct,
+}
+
+impl Struct {
+	/// Returns the path of the sidecar file of an input: the path of the
+	/// input with `.checkpoint` appended.
+	///
+	/// # Arguments
+	///
+	/// * `Input` - The path of the imported file.
+	///
+	/// # Returns
+	///
+	/// The path of its checkpoint.
+	pub fn Sidecar(Input:&Path) -> PathBuf {
+		let mut Sidecar = Input.as_os_str().to_owned();
+
+		Sidecar.push(".checkpoint");
+
+		PathBuf::from(Sidecar)
+	}
+
+	/// Reads a checkpoint.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the sidecar file.
+	///
+	/// # Returns
+	///
+	/// The checkpoint, `None` if there is none yet, or the error reading or
+	/// parsing it.
+	pub async fn Read(Path:&Path) -> io::Result<Option<Self>> {
+		match read(Path).await {
+			Ok(Content) => Ok(Some(serde_json::from_slice(&Content)?)),
+			Err(_Error) if _Error.kind() == io::ErrorKind::NotFound => Ok(None),
+			Err(_Error) => Err(_Error),
+		}
+	}
+
+	/// Writes the checkpoint to a temporary file, then renames it over the
+	/// sidecar, so an interruption never leaves half a checkpoint.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the sidecar file.
+	///
+	/// # Returns
+	///
+	/// The error writing the checkpoint, if any.
+	pub async fn Write(&self, Path:&Path) -> io::Result<()> {
+		let mut Temporary = Path.as_os_str().to_owned();
+
+		Temporary.push(".tmp");
+
+		write(&Temporary, serde_json::to_vec(self)?).await?;
+
+		rename(&Temporary, Path).await
+	}
+}
+
+use std::{
+	io,
+	path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::fs::{read, rename, write};
+
+use crate::Import::Report;
@@ -0,0 +1,162 @@
+/// One submission read from an import file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The line of the file the submission was read from, counting from 1.
+	#[serde(skip)]
+	pub Number:u64,
+
+	/// The name of the plan function to run.
+	pub Action:String,
+
+	/// The arguments passed to the function; none when absent.
+	#[serde(default)]
+	pub Argument:Vec<Value>,
+
+	/// Metadata attached to the action, such as its `Queue`; none when
+	/// absent.
+	#[serde(default)]
+	pub Metadata:Map<String, Value>,
+}
+
+impl Struct {
+	/// Reads a line of a JSONL file, an object with the fields of `Struct`.
+	///
+	/// # Arguments
+	///
+	/// * `Number` - The number of the line.
+	/// * `Text` - The text of the line.
+	///
+	/// # Returns
+	///
+	/// The submission with its `IdempotencyKey`, or why the line cannot be
+	/// read.
+	pub fn Json(Number:u64, Text:&str) -> Result<Self, String> {
+		let Line = serde_json::from_str::<Self>(Text)
+			.map_err(|_Error| format!("Invalid submission: {}", _Error))?;
+
+		Ok(Struct { Number, ..Line }.Key())
+	}
+
+	/// Reads a record of a CSV file.
+	///
+	/// The `Argument` and `Metadata` columns hold JSON, an array and an
+	/// object; every other column except `Action` becomes a metadata entry
+	/// holding its text, unless the record leaves it empty.
+	///
+	/// # Arguments
+	///
+	/// * `Number` - The number of the line.
+	/// * `Text` - The text of the record.
+	/// * `Header` - The column names, from the first line of the file.
+	///
+	/// # Returns
+	///
+	/// The submission with its `IdempotencyKey`, or why the record cannot be
+	/// read.
+	pub fn Csv(Number:u64, Text:&str, Header:&[String]) -> Result<Self, String> {
+		let Field = Split(Text)?;
+
+		if Field.len() != Header.len() {
+			return Err(format!("Expected {} columns, found {}", Header.len(), Field.len()));
+		}
+
+		let mut Line =
+			Struct { Number, Action:String::new(), Argument:Vec::new(), Metadata:Map::new() };
+
+		for (Name, Field) in Header.iter().zip(Field) {
+			match Name.as_str() {
+				"Action" => Line.Action = Field,
+				"Argument" if !Field.is_empty() => {
+					Line.Argument = serde_json::from_str(&Field)
+						.map_err(|_Error| format!("Invalid Argument column: {}", _Error))?;
+				},
+				"Metadata" if !Field.is_empty() => {
+					Line.Metadata.extend(
+						serde_json::from_str::<Map<String, Value>>(&Field)
+							.map_err(|_Error| format!("Invalid Metadata column: {}", _Error))?,
+					);
+				},
+				"Argument" | "Metadata" => {},
+				_ if Field.is_empty() => {},
+				_ => {
+					Line.Metadata.insert(Name.clone(), Value::String(Field));
+				},
+			}
+		}
+
+		if Line.Action.is_empty() {
+			return Err("Missing Action column".to_string());
+		}
+
+		Ok(Line.Key())
+	}
+
+	/// Reads the header of a CSV file.
+	///
+	/// # Arguments
+	///
+	/// * `Text` - The first line of the file.
+	///
+	/// # Returns
+	///
+	/// The column names, or why the header cannot be read.
+	pub fn Header(Text:&str) -> Result<Vec<String>, String> {
+		let Header = Split(Text)?;
+
+		if !Header.iter().any(|Name| Name == "Action") {
+			return Err("The header names no Action column".to_string());
+		}
+
+		Ok(Header)
+	}
+
+	/// Sets the `IdempotencyKey` metadata of the line, unless it has one:
+	/// its number and the start of its `Digest::ActionDigest`, the same on
+	/// every run over the same file.
+	fn Key(mut self) -> Self {
+		if !self.Metadata.contains_key("IdempotencyKey") {
+			let Digest = Hex(&ActionDigest(&self.Action, &self.Argument, &self.Metadata));
+
+			let Key = format!("{}-{}", self.Number, &Digest[..16]);
+
+			self.Metadata.insert("IdempotencyKey".to_string(), json!(Key));
+		}
+
+		self
+	}
+}
+
+/// Splits a CSV record into its fields, unquoting quoted ones, where `""`
+/// stands for a quote.
+fn Split(Text:&str) -> Result<Vec<String>, String> {
+	let (mut Field, mut Current, mut Quoted) = (Vec::new(), String::new(), false);
+
+	let mut Character = Text.trim_end_matches(['\r', '\n']).chars().peekable();
+
+	while let Some(Next) = Character.next() {
+		match (Quoted, Next) {
+			(true, '"') if Character.peek() == Some(&'"') => {
+				Character.next();
+
+				Current.push('"');
+			},
+			(true, '"') => Quoted = false,
+			(false, '"') if Current.is_empty() => Quoted = true,
+			(false, ',') => Field.push(std::mem::take(&mut Current)),
+			(_, Next) => Current.push(Next),
+		}
+	}
+
+	if Quoted {
+		return Err("Unterminated quoted field".to_string());
+	}
+
+	Field.push(Current);
+
+	Ok(Field)
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::Digest::{ActionDigest, Hex};
@@ -0,0 +1,76 @@
+/// Imports lines into a `Life` or production line of this process.
+///
+/// Each line becomes an action of the plan, delivered the way a source
+/// delivers its actions; the depth reported is that of the production line
+/// the action routes to.
+pub struct Struct {
+	/// Where the actions go.
+	Target:Target,
+
+	/// The plan providing the functions.
+	Plan:Arc<Formality>,
+}
+
+impl Struct {
+	/// Creates a destination delivering into a target.
+	///
+	/// # Arguments
+	///
+	/// * `Target` - A `Life`, routing by `Queue` metadata, or a production
+	///   line.
+	/// * `Plan` - The plan providing the functions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Target:impl Into<Target>, Plan:Arc<Formality>) -> Self {
+		Struct { Target:Target.into(), Plan }
+	}
+
+	/// Builds the action of a line, whose metadata named after a `Reserved`
+	/// key sets that option.
+	fn Action(&self, Line:&Line) -> Box<dyn ActionTrait> {
+		let Action = Line.Metadata.iter().fold(
+			Action::Struct::New(&Line.Action, (), self.Plan.clone()),
+			|Action, (Key, Value)| {
+				match Reserved::Parse(Key) {
+					Some(Key) => Action.WithOption(Key, Value.clone()),
+					None => Action.WithMetadata(Key, Value.clone()),
+				}
+			},
+		);
+
+		Box::new(Action.WithOption(Reserved::Argument, Value::Array(Line.Argument.clone())))
+	}
+}
+
+#[async_trait]
+impl Destination for Struct {
+	async fn Submit(&self, Line:&Line) -> Result<(), String> {
+		self.Target.Deliver(self.Action(Line)).await.map_err(|_Error| _Error.to_string())
+	}
+
+	async fn Depth(&self, Line:&Line) -> Option<usize> {
+		let Production = self.Target.Resolve(self.Action(Line).as_ref()).await.ok()?;
+
+		usize::try_from(Production.Stats().Depth).ok()
+	}
+
+	fn Runtime(&self) -> Arc<dyn Runtime> { self.Target.Runtime() }
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::{
+	Enum::{Sequence::Reserved::Enum as Reserved, Source::Target::Enum as Target},
+	Import::Line::Struct as Line,
+	Struct::Sequence::{Action, Plan::Formality::Struct as Formality},
+	Trait::{
+		Import::Destination::Trait as Destination,
+		Runtime::Trait as Runtime,
+		Sequence::Action::Trait as ActionTrait,
+	},
+};
@@ -0,0 +1,151 @@
+/// How `Import::Run` paces and checks the lines it submits.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// How many lines are submitted between two checkpoints.
+	pub Batch:usize,
+
+	/// How many lines of a batch may be submitting at the same time.
+	pub Concurrency:usize,
+
+	/// The largest number of lines submitted per second, or `None` for no
+	/// limit.
+	pub Rate:Option<f64>,
+
+	/// How many actions may wait in the queue of a line before submitting
+	/// it waits for the queue to drain, or `None` to never wait.
+	pub Depth:Option<usize>,
+
+	/// How often a full queue is looked at again.
+	pub Poll:Duration,
+
+	/// The manifest every line must name an action of, or `None` to leave
+	/// unknown actions to the destination.
+	pub Manifest:Option<Manifest>,
+
+	/// Stops the import after the batch being submitted once cancelled.
+	pub Cancellation:Option<Cancellation>,
+}
+
+impl Struct {
+	/// Creates options submitting batches of 100 lines, 4 at a time, without
+	/// a rate limit, depth limit or manifest.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct {
+			Batch:100,
+			Concurrency:4,
+			Rate:None,
+			Depth:None,
+			Poll:Duration::from_millis(50),
+			Manifest:None,
+			Cancellation:None,
+		}
+	}
+
+	/// Sets how many lines are submitted between two checkpoints.
+	///
+	/// # Arguments
+	///
+	/// * `Batch` - The batch size, at least 1.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBatch(mut self, Batch:usize) -> Self {
+		self.Batch = Batch.max(1);
+
+		self
+	}
+
+	/// Sets how many lines may be submitting at the same time.
+	///
+	/// # Arguments
+	///
+	/// * `Concurrency` - The concurrency limit, at least 1.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithConcurrency(mut self, Concurrency:usize) -> Self {
+		self.Concurrency = Concurrency.max(1);
+
+		self
+	}
+
+	/// Limits how many lines are submitted per second.
+	///
+	/// # Arguments
+	///
+	/// * `Rate` - The number of lines per second.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRate(mut self, Rate:f64) -> Self {
+		self.Rate = Some(Rate);
+
+		self
+	}
+
+	/// Makes submitting wait while the queue of a line is full.
+	///
+	/// # Arguments
+	///
+	/// * `Depth` - How many actions may wait in the queue.
+	/// * `Poll` - How often a full queue is looked at again.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDepth(mut self, Depth:usize, Poll:Duration) -> Self {
+		self.Depth = Some(Depth);
+
+		self.Poll = Poll;
+
+		self
+	}
+
+	/// Refuses lines naming an action the manifest lacks.
+	///
+	/// # Arguments
+	///
+	/// * `Manifest` - The manifest of the plan the lines are for.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithManifest(mut self, Manifest:Manifest) -> Self {
+		self.Manifest = Some(Manifest);
+
+		self
+	}
+
+	/// Sets the token stopping the import.
+	///
+	/// # Arguments
+	///
+	/// * `Cancellation` - The token.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCancellation(mut self, Cancellation:Cancellation) -> Self {
+		self.Cancellation = Some(Cancellation);
+
+		self
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use std::time::Duration;
+
+use crate::Struct::Sequence::{
+	Cancellation::Struct as Cancellation,
+	Plan::Manifest::Struct as Manifest,
+};
@@ -0,0 +1,18 @@
+/// What an import did with the lines of its file, over every run that
+/// resumed it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The number of lines submitted and accepted.
+	pub Accepted:u64,
+
+	/// The lines refused, in file order.
+	pub Rejected:Vec<Rejection::Struct>,
+
+	/// Whether the import reached the end of the file; `false` if it was
+	/// interrupted and resumes on the next run.
+	pub Complete:bool,
+}
+
+use serde::{Deserialize, Serialize};
+
+pub mod Rejection;
@@ -0,0 +1,12 @@
+/// A line an import did not submit, and why.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The line of the file, counting from 1.
+	pub Line:u64,
+
+	/// Why the line was refused: it could not be read, it failed validation
+	/// or its destination refused it.
+	pub Reason:String,
+}
+
+use serde::{Deserialize, Serialize};
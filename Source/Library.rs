@@ -1,6 +1,11 @@
 #![allow(non_snake_case)]
 #![feature(fn_traits)]
 
+extern crate self as Echo;
+
+pub use serde_json;
+pub use EchoMacro as Macro;
+
 pub mod Struct;
 
 pub mod Trait;
@@ -8,3 +13,15 @@ pub mod Trait;
 pub mod Type;
 
 pub mod Enum;
+
+pub mod Fn;
+
+pub mod Builtin;
+
+pub mod Digest;
+
+pub mod Import;
+
+pub mod Time;
+
+pub mod Wire;
@@ -0,0 +1,792 @@
+/// A client of the message protocol, connected to a `Tcp` transport.
+///
+/// One connection multiplexes any number of requests: submissions are
+/// correlated with their results by identifier, and every other message is
+/// answered in the order it was sent. Lifecycle events are fanned out to
+/// every `Subscribe` stream.
+///
+/// When the connection drops, requests waiting for an answer and
+/// submissions waiting for a result fail with `Error::ConnectionLost`. If
+/// the configuration has a `Backoff`, the client then reconnects,
+/// authenticates and restores its event subscription, so existing streams
+/// resume; submissions marked `Idempotent` are submitted again on the new
+/// connection instead of failing. Requests made while reconnecting wait for
+/// the connection up to the timeout. Without a `Backoff`, or once the
+/// attempts run out, the client stays `Disconnected` and event streams end.
+///
+/// A result the server sends in chunks is put back together and checked
+/// against its digest before its submission resolves; a chunk out of order
+/// or a digest that does not match aborts the transfer on the server and
+/// fails the submission with `Error::Transfer`.
+///
+/// A submission with a timeout or deadline of its own waits for its result
+/// that long instead of the configured timeout, then is forgotten and, if it
+/// asks to, cancelled on the server.
+pub struct Struct {
+	/// The state shared with the supervising task.
+	Shared:Arc<Shared>,
+
+	/// The task reading replies and reconnecting.
+	Supervisor:Spawned::Struct,
+}
+
+impl Struct {
+	/// Connects to a server and authenticates if a token is configured.
+	///
+	/// # Arguments
+	///
+	/// * `Address` - The address of the `Tcp` transport, e.g. `127.0.0.1:9000`.
+	/// * `Config` - The settings of the connection.
+	///
+	/// # Returns
+	///
+	/// The connected client, `Error::Connection` if the server cannot be
+	/// reached, or the error replied to `Auth`.
+	pub async fn Connect(Address:&str, Config:Config::Struct) -> Result<Self, Error> {
+		let (Sender, _) = broadcast::channel(1024);
+
+		let Shared = Arc::new(Shared {
+			Address:Address.to_string(),
+			Config,
+			Writer:Mutex::new(None),
+			Pending:StdMutex::new(HashMap::new()),
+			Assembly:StdMutex::new(HashMap::new()),
+			Waiting:StdMutex::new(VecDeque::new()),
+			Event:StdMutex::new(Some(Sender)),
+			State:watch::Sender::new(State::Connected),
+			Filter:StdMutex::new(None),
+			Subscribing:Mutex::new(()),
+			Next:AtomicU64::new(1),
+		});
+
+		let (Reader, Auth) = Shared.Open(Vec::new()).await?;
+
+		let Supervisor = Shared.Config.Runtime.Spawn(Box::pin(Shared.clone().Supervise(Reader)));
+
+		let Client = Struct { Supervisor, Shared };
+
+		if let Some(Auth) = Auth {
+			match Client.Shared.Answer(Auth).await? {
+				Reply::Authenticated { .. } => {},
+				Other => return Err(Unexpected(Other)),
+			}
+		}
+
+		Ok(Client)
+	}
+
+	/// Observes the connection state, e.g. to render it.
+	///
+	/// # Returns
+	///
+	/// A receiver of the current state and its changes.
+	pub fn State(&self) -> watch::Receiver<State> { self.Shared.State.subscribe() }
+
+	/// Submits an action and returns a handle resolving to its result.
+	///
+	/// # Arguments
+	///
+	/// * `Submission` - The action to submit.
+	///
+	/// # Returns
+	///
+	/// The in-flight submission, or the error that prevented sending it.
+	pub async fn Submit(&self, Submission:Submission::Struct) -> Result<Submitted::Struct, Error> {
+		let Id = self.Shared.Next.fetch_add(1, Ordering::Relaxed).to_string();
+
+		let (Sender, Receiver) = oneshot::channel();
+
+		let (Idempotent, Limit, Cancel) =
+			(Submission.Idempotent, Submission.Limit(), Submission.CancelOnTimeout);
+
+		let Message = Submission.Into(Id.clone());
+
+		Lock(&self.Shared.Pending)
+			.insert(Id.clone(), Waiter { Sender, Message:Idempotent.then(|| Message.clone()) });
+
+		if let Err(_Error) = self.Shared.Send(Message, None).await {
+			Lock(&self.Shared.Pending).remove(&Id);
+
+			return Err(_Error);
+		}
+
+		Ok(Submitted::Struct::New(Id, Receiver, self.Shared.Config.Timeout).WithClient(
+			&self.Shared,
+			Limit,
+			Cancel,
+		))
+	}
+
+	/// Submits an action without waiting for its result.
+	///
+	/// # Arguments
+	///
+	/// * `Submission` - The action to submit.
+	///
+	/// # Returns
+	///
+	/// The identifier of the submission, or the error that prevented sending
+	/// it.
+	pub async fn SubmitAndForget(&self, Submission:Submission::Struct) -> Result<String, Error> {
+		let Id = self.Shared.Next.fetch_add(1, Ordering::Relaxed).to_string();
+
+		self.Shared.Send(Submission.Into(Id.clone()), None).await?;
+
+		Ok(Id)
+	}
+
+	/// Cancels a submission that has not completed.
+	///
+	/// A cancelled submission's `Wait` fails with `Error::Failed`.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the submission.
+	///
+	/// # Returns
+	///
+	/// Whether the submission was withdrawn; `false` if it had already
+	/// completed.
+	pub async fn Cancel(&self, Id:&str) -> Result<bool, Error> {
+		match self.Shared.Request(Message::Cancel { Id:Id.to_string() }).await? {
+			Reply::Cancelled { Cancelled, .. } => Ok(Cancelled),
+			Other => Err(Unexpected(Other)),
+		}
+	}
+
+	/// Lists the actions the server can run.
+	///
+	/// # Returns
+	///
+	/// The names of the actions of the server's plan.
+	pub async fn Describe(&self) -> Result<Vec<String>, Error> {
+		match self.Shared.Request(Message::Describe).await? {
+			Reply::Description { Action, .. } => Ok(Action),
+			Other => Err(Unexpected(Other)),
+		}
+	}
+
+	/// Lists the error codes the server's replies may carry.
+	///
+	/// # Returns
+	///
+	/// The catalogue of the server, empty if it predates codes.
+	pub async fn Catalogue(&self) -> Result<Vec<Catalogue>, Error> {
+		match self.Shared.Request(Message::Describe).await? {
+			Reply::Description { Catalogue, .. } => Ok(Catalogue),
+			Other => Err(Unexpected(Other)),
+		}
+	}
+
+	/// Streams lifecycle events of the server.
+	///
+	/// The server streams the union of every filter requested on this
+	/// client; each stream only yields the types it asked for. Streams
+	/// survive reconnections.
+	///
+	/// # Arguments
+	///
+	/// * `Filter` - The event types to stream, such as `Completed`, or every
+	///   type when empty.
+	///
+	/// # Returns
+	///
+	/// A stream of events ending when the client is disconnected for good.
+	pub async fn Subscribe(&self, Filter:Vec<String>) -> Result<impl Stream<Item = Event>, Error> {
+		let Receiver = Lock(&self.Shared.Event)
+			.as_ref()
+			.map(|Sender| Sender.subscribe())
+			.ok_or(Error::ConnectionLost)?;
+
+		let _Subscribing = self.Shared.Subscribing.lock().await;
+
+		let Union = {
+			let mut Requested = Lock(&self.Shared.Filter);
+
+			let Union = match (Requested.as_ref(), Filter.is_empty()) {
+				(Some(Requested), _) if Requested.is_empty() => None,
+				(_, true) => Some(Vec::new()),
+				(Some(Requested), false) if Filter.iter().all(|Type| Requested.contains(Type)) => {
+					None
+				},
+				(Requested, false) => {
+					let mut Union = Requested.cloned().unwrap_or_default();
+
+					Union.extend(
+						Filter
+							.iter()
+							.filter(|Type| !Union.contains(Type))
+							.cloned()
+							.collect::<Vec<_>>(),
+					);
+
+					Some(Union)
+				},
+			};
+
+			// Recorded first, so a reconnection in the meantime restores it
+			if let Some(Union) = &Union {
+				*Requested = Some(Union.clone());
+			}
+
+			Union
+		};
+
+		if let Some(Union) = Union {
+			match self.Shared.Request(Message::Subscribe { Filter:Union }).await? {
+				Reply::Subscribed => {},
+				Other => return Err(Unexpected(Other)),
+			}
+		}
+
+		Ok(stream::unfold((Receiver, Filter), |(mut Receiver, Filter)| async move {
+			loop {
+				match Receiver.recv().await {
+					Ok(Event)
+						if Filter.is_empty() || Filter.iter().any(|Type| Type == Event.Type()) =>
+					{
+						return Some((Event, (Receiver, Filter)));
+					},
+					Ok(_) | Err(RecvError::Lagged(_)) => {},
+					Err(RecvError::Closed) => return None,
+				}
+			}
+		}))
+	}
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) { self.Supervisor.Abort(); }
+}
+
+/// Submits imported lines without waiting for their results; a line counts
+/// as accepted once sent, and the depth of its `Queue` is read from `Stats`.
+#[async_trait]
+impl Destination for Struct {
+	async fn Submit(&self, Line:&Line) -> Result<(), String> {
+		let Submission = Submission::Struct {
+			Action:Line.Action.clone(),
+			Argument:Line.Argument.clone(),
+			Metadata:Line.Metadata.clone(),
+			..Default::default()
+		};
+
+		self.SubmitAndForget(Submission)
+			.await
+			.map(|_| ())
+			.map_err(|_Error| _Error.to_string())
+	}
+
+	async fn Depth(&self, Line:&Line) -> Option<usize> {
+		let Queue = Line.Metadata.get("Queue")?.as_str()?;
+
+		match self.Shared.Request(Message::Stats).await.ok()? {
+			Reply::Stats { Stats } => usize::try_from(Stats.Queue.get(Queue)?.Depth).ok(),
+			_ => None,
+		}
+	}
+
+	fn Runtime(&self) -> Arc<dyn Runtime> { self.Shared.Config.Runtime.clone() }
+}
+
+impl Submission::Struct {
+	/// Turns the submission into its wire message, carrying its deadline as
+	/// the `Deadline` option, a `Time::Timestamp` in Unix milliseconds.
+	fn Into(mut self, Id:String) -> Message {
+		if let Some(Deadline) = self.Deadline {
+			let Deadline = Timestamp(
+				SystemTime::now() + Deadline.saturating_duration_since(Instant::now()),
+			);
+
+			let Deadline = Value::from(Deadline.Millis());
+
+			self.Options.insert(Reserved::Deadline.Name().to_string(), Deadline);
+		}
+
+		Message::Submit {
+			Id,
+			Action:self.Action,
+			Argument:self.Argument.into_iter().map(Arg::Json).collect(),
+			Metadata:self.Metadata,
+			Options:self.Options,
+		}
+	}
+}
+
+/// The state shared between a client and its supervising task.
+struct Shared {
+	/// The address of the server.
+	Address:String,
+
+	/// The settings of the connection.
+	Config:Config::Struct,
+
+	/// The writing half of the connection, or `None` while disconnected.
+	Writer:Mutex<Option<Output>>,
+
+	/// The submissions waiting for their result, by identifier.
+	Pending:StdMutex<HashMap<String, Waiter>>,
+
+	/// The results of pending submissions arriving in chunks, by identifier.
+	Assembly:StdMutex<HashMap<String, Assembly::Struct>>,
+
+	/// The answer senders of other messages, in the order they were sent.
+	Waiting:StdMutex<VecDeque<Answer>>,
+
+	/// Fans events out to subscribers; taken once disconnected for good.
+	Event:StdMutex<Option<broadcast::Sender<Event>>>,
+
+	/// The connection state.
+	State:watch::Sender<State>,
+
+	/// The event types requested from the server so far; empty once every
+	/// type is requested.
+	Filter:StdMutex<Option<Vec<String>>>,
+
+	/// Keeps concurrent subscriptions from sending their filters out of
+	/// order.
+	Subscribing:Mutex<()>,
+
+	/// The identifier of the next submission.
+	Next:AtomicU64,
+}
+
+/// A submission waiting for its result.
+struct Waiter {
+	/// Receives the result.
+	Sender:oneshot::Sender<Result<Value, Error>>,
+
+	/// The message to submit again after a reconnection, if the submission
+	/// is idempotent.
+	Message:Option<Message>,
+}
+
+/// The sender of an answer to a message.
+type Answer = oneshot::Sender<Result<Reply, Error>>;
+
+/// The reading half of a connection.
+type Input = Frame::Length::Reader::Struct<OwnedReadHalf>;
+
+/// The writing half of a connection.
+type Output = Frame::Length::Writer::Struct<OwnedWriteHalf>;
+
+impl Shared {
+	/// Reads replies, reconnecting whenever the connection drops until the
+	/// client is disconnected for good.
+	async fn Supervise(self: Arc<Self>, mut Reader:Input) {
+		loop {
+			let Superseded = self.Dispatch(&mut Reader).await;
+
+			let Resubmit = self.Lose().await;
+
+			// Reconnecting would take the connection back from its successor
+			let (Some(Backoff), false) = (self.Config.Backoff, Superseded) else {
+				return self.Close();
+			};
+
+			let (mut Delay, mut Attempt) = (Backoff.Initial, 0);
+
+			Reader = loop {
+				self.Config.Runtime.Sleep(Delay).await;
+
+				match self.Open(Resubmit.clone()).await {
+					Ok((Reader, Auth)) => {
+						if let Some(Auth) = Auth {
+							let Shared = self.clone();
+
+							self.Config.Runtime.Spawn(Box::pin(async move {
+								if let Err(_Error) = Shared.Answer(Auth).await {
+									warn!("Cannot authenticate after reconnecting: {}", _Error);
+								}
+							}));
+						}
+
+						break Reader;
+					},
+					Err(_Error) => {
+						Attempt += 1;
+
+						warn!("Reconnection attempt {} failed: {}", Attempt, _Error);
+
+						if Backoff.Attempts.is_some_and(|Attempts| Attempt >= Attempts) {
+							return self.Close();
+						}
+
+						Delay = Backoff.Next(Delay);
+					},
+				}
+			};
+		}
+	}
+
+	/// Opens a connection, then authenticates, restores the event
+	/// subscription and submits the given messages before anything else is
+	/// written.
+	///
+	/// Returns the reading half together with the waiter of the `Auth`
+	/// answer, if a token is configured.
+	async fn Open(
+		&self,
+		Resubmit:Vec<Message>,
+	) -> Result<(Input, Option<oneshot::Receiver<Result<Reply, Error>>>), Error> {
+		let Stream = TcpStream::connect(&self.Address)
+			.await
+			.map_err(|_Error| Error::Connection(_Error.to_string()))?;
+
+		let _ = Stream.set_nodelay(true);
+
+		let (Input, Output) = Stream.into_split();
+
+		let mut Writer = self.Writer.lock().await;
+
+		let Filter = Lock(&self.Filter).clone();
+
+		let mut Output = Frame::Length::Writer::Struct::New(Output);
+
+		let mut Auth = None;
+
+		if let Some(Token) = self.Config.Token.clone() {
+			let (Sender, Receiver) = oneshot::channel();
+
+			self.Write(
+				&mut Output,
+				&Message::Auth { Token, Delivery:Delivery::default() },
+				Some(Sender),
+			)
+			.await?;
+
+			Auth = Some(Receiver);
+		}
+
+		if let Some(Filter) = Filter {
+			self.Write(&mut Output, &Message::Subscribe { Filter }, Some(oneshot::channel().0))
+				.await?;
+		}
+
+		for Message in Resubmit {
+			self.Write(&mut Output, &Message, None).await?;
+		}
+
+		*Writer = Some(Output);
+
+		self.State.send_replace(State::Connected);
+
+		Ok((Frame::Length::Reader::Struct::New(Input, 16 * 1024 * 1024), Auth))
+	}
+
+	/// Dispatches replies until the connection drops. Results offloaded by
+	/// the server are fetched, and results sent in chunks put together,
+	/// before their submission resolves.
+	///
+	/// Returns whether the server closed the connection because a newer one
+	/// of the same identity took it over.
+	async fn Dispatch(self: &Arc<Self>, Reader:&mut Input) -> bool {
+		while let Ok(Some(Frame)) = Reader.Read().await {
+			let Reply = match self.Config.Codec.DecodeReply(&Frame) {
+				Ok(Reply) => Reply,
+				Err(_Error) => {
+					warn!("Cannot decode reply: {}", _Error);
+
+					continue;
+				},
+			};
+
+			match Reply {
+				Reply::Ack { .. } => {},
+				Reply::Result { Id, Value, .. } => {
+					match Stored::Parse(&Value) {
+						Some(Stored) => {
+							let Shared = self.clone();
+
+							// Fetched aside, as the answer comes through here
+							self.Config.Runtime.Spawn(Box::pin(async move {
+								let Result = match Shared.Request(Message::Fetch { Stored }).await {
+									Ok(Reply::Fetched { Value }) => Ok(Value),
+									Ok(Other) => Err(Unexpected(Other)),
+									Err(_Error) => Err(_Error),
+								};
+
+								Shared.Resolve(&Id, Result);
+							}));
+						},
+						None => self.Resolve(&Id, Ok(Value)),
+					}
+				},
+				Reply::ResultChunk { Id, Index, Total, Data } => {
+					self.Chunk(Id, Index, Total, &Data)
+				},
+				Reply::ResultEnd { Id, Digest, .. } => {
+					let Some(Assembly) = Lock(&self.Assembly).remove(&Id) else {
+						continue;
+					};
+
+					match Assembly.Finish(&Digest) {
+						Ok(Data) => {
+							let Result = serde_json::from_slice(&Data)
+								.map_err(|_Error| Error::Protocol(_Error.to_string()));
+
+							self.Resolve(&Id, Result);
+						},
+						Err((Code, Message)) => self.Abort(Id, Code, Message),
+					}
+				},
+				Reply::ResultAbort { Id, Code, Message } => {
+					Lock(&self.Assembly).remove(&Id);
+
+					self.Resolve(&Id, Err(Error::Transfer(Code, Message)));
+				},
+				Reply::Error { Id: Some(Id), Message, .. } => {
+					self.Resolve(&Id, Err(Error::Failed(Message)))
+				},
+				Reply::Event { Event } => {
+					if let Some(Sender) = Lock(&self.Event).as_ref() {
+						let _ = Sender.send(Event);
+					}
+				},
+				Reply::Superseded { Connection } => {
+					warn!("Connection taken over by connection {}", Connection);
+
+					return true;
+				},
+				Reply => {
+					if let Some(Answer) = Lock(&self.Waiting).pop_front() {
+						let _ = Answer.send(Ok(Reply));
+					}
+				},
+			}
+		}
+
+		false
+	}
+
+	/// Adds a chunk to the result of a pending submission, aborting the
+	/// transfer if the chunk is not the one expected.
+	fn Chunk(self: &Arc<Self>, Id:String, Index:u64, Total:u64, Data:&str) {
+		if !Lock(&self.Pending).contains_key(&Id) {
+			return;
+		}
+
+		let Pushed = match STANDARD.decode(Data) {
+			Ok(Data) => {
+				Lock(&self.Assembly)
+					.entry(Id.clone())
+					.or_insert_with(|| Assembly::Struct::New(Total))
+					.Push(Index, Total, &Data)
+			},
+			Err(_Error) => {
+				Err((Code::TransferAborted, format!("Chunk {} is not base64: {}", Index, _Error)))
+			},
+		};
+
+		if let Err((Code, Message)) = Pushed {
+			self.Abort(Id, Code, Message);
+		}
+	}
+
+	/// Gives up the transfer of a result: the server is told to send no
+	/// more of it, and the submission fails with `Error::Transfer`.
+	fn Abort(self: &Arc<Self>, Id:String, Code:Code, Message:String) {
+		warn!("Aborting the transfer of {}: {}", Id, Message);
+
+		Lock(&self.Assembly).remove(&Id);
+
+		self.Resolve(&Id, Err(Error::Transfer(Code, Message.clone())));
+
+		let Shared = self.clone();
+
+		// Written aside, as the writer may be busy
+		self.Config.Runtime.Spawn(Box::pin(async move {
+			let Abort = Message::ResultAbort { Id, Code, Message };
+
+			if let Err(_Error) = Shared.Send(Abort, None).await {
+				warn!("Cannot abort the transfer: {}", _Error);
+			}
+		}));
+	}
+
+	/// Forgets the dropped connection and fails everything waiting on it,
+	/// except idempotent submissions when reconnecting.
+	///
+	/// Returns the messages of the idempotent submissions to send again.
+	async fn Lose(&self) -> Vec<Message> {
+		let mut Writer = self.Writer.lock().await;
+
+		*Writer = None;
+
+		// Results resent on the next connection start from their first chunk
+		Lock(&self.Assembly).clear();
+
+		let Reconnect = self.Config.Backoff.is_some();
+
+		self.State
+			.send_replace(if Reconnect { State::Reconnecting } else { State::Disconnected });
+
+		for Answer in Lock(&self.Waiting).drain(..) {
+			let _ = Answer.send(Err(Error::ConnectionLost));
+		}
+
+		let mut Resubmit = Vec::new();
+
+		Lock(&self.Pending).retain(|_, Waiter| match (&Waiter.Message, Reconnect) {
+			(Some(Message), true) => {
+				Resubmit.push(Message.clone());
+
+				true
+			},
+			_ => false,
+		});
+
+		Resubmit
+	}
+
+	/// Disconnects for good, failing every remaining submission and ending
+	/// event streams.
+	fn Close(&self) {
+		self.State.send_replace(State::Disconnected);
+
+		for (_, Waiter) in Lock(&self.Pending).drain() {
+			let _ = Waiter.Sender.send(Err(Error::ConnectionLost));
+		}
+
+		Lock(&self.Event).take();
+	}
+
+	/// Hands the result of a submission to its waiter, if it has one.
+	fn Resolve(&self, Id:&str, Result:Result<Value, Error>) {
+		if let Some(Waiter) = Lock(&self.Pending).remove(Id) {
+			let _ = Waiter.Sender.send(Result);
+		}
+	}
+
+	/// Sends a message answered in order and waits for the answer.
+	async fn Request(&self, Message:Message) -> Result<Reply, Error> {
+		let (Sender, Receiver) = oneshot::channel();
+
+		self.Send(Message, Some(Sender)).await?;
+
+		self.Answer(Receiver).await
+	}
+
+	/// Waits for the answer to a message.
+	async fn Answer(
+		&self,
+		Receiver:oneshot::Receiver<Result<Reply, Error>>,
+	) -> Result<Reply, Error> {
+		match self.Config.Runtime.Timeout(self.Config.Timeout, Receiver).await {
+			Some(Ok(Ok(Reply::Error { Message, .. }))) => Err(Error::Failed(Message)),
+			Some(Ok(Ok(Reply::Denied { Message }))) => Err(Error::Denied(Message)),
+			Some(Ok(Result)) => Result,
+			Some(Err(_)) => Err(Error::ConnectionLost),
+			None => Err(Error::Timeout),
+		}
+	}
+
+	/// Writes a message, waiting for a reconnection up to the timeout if the
+	/// connection is down.
+	async fn Send(&self, Message:Message, Answer:Option<Answer>) -> Result<(), Error> {
+		let mut State = self.State.subscribe();
+
+		let Deadline = self.Config.Runtime.Now() + self.Config.Timeout;
+
+		loop {
+			if let Some(Writer) = self.Writer.lock().await.as_mut() {
+				return self.Write(Writer, &Message, Answer).await;
+			}
+
+			let Changed = State.wait_for(|State| *State != State::Reconnecting);
+
+			match self.Config.Runtime.TimeoutAt(Deadline, Changed).await {
+				Some(Ok(Current)) if *Current == State::Connected => {},
+				Some(_) => return Err(Error::ConnectionLost),
+				None => return Err(Error::Timeout),
+			}
+		}
+	}
+
+	/// Writes a message to a connection, registering the waiter of its
+	/// answer in the same order the server will answer.
+	async fn Write(
+		&self,
+		Writer:&mut Output,
+		Message:&Message,
+		Answer:Option<Answer>,
+	) -> Result<(), Error> {
+		let Frame = self.Config.Codec.EncodeMessage(Message).map_err(Error::Protocol)?;
+
+		if let Some(Answer) = Answer {
+			Lock(&self.Waiting).push_back(Answer);
+		}
+
+		Writer.Write(&Frame).await.map_err(|_| Error::ConnectionLost)
+	}
+}
+
+/// Locks a mutex, ignoring poisoning.
+fn Lock<T>(Mutex:&StdMutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+/// Describes an answer that does not match its request.
+fn Unexpected(Reply:Reply) -> Error { Error::Protocol(format!("Unexpected reply: {:?}", Reply)) }
+
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+		Mutex as StdMutex,
+		MutexGuard,
+	},
+	time::SystemTime,
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::{stream, Stream};
+use log::warn;
+use serde_json::Value;
+use tokio::{
+	net::{
+		tcp::{OwnedReadHalf, OwnedWriteHalf},
+		TcpStream,
+	},
+	sync::{
+		broadcast::{self, error::RecvError},
+		oneshot,
+		watch,
+		Mutex,
+	},
+	time::Instant,
+};
+
+use crate::{
+	Enum::{
+		Client::{Error::Enum as Error, State::Enum as State},
+		Event::Enum as Event,
+		Sequence::{Action::Arg::Enum as Arg, Reserved::Enum as Reserved},
+		Transport::{
+			Code::Enum as Code,
+			Delivery::Enum as Delivery,
+			Message::Enum as Message,
+			Reply::Enum as Reply,
+		},
+	},
+	Struct::{
+		Runtime::Spawned,
+		Storage::Stored::Struct as Stored,
+		Transport::{Catalogue::Struct as Catalogue, Frame},
+	},
+	Import::Line::Struct as Line,
+	Time::Timestamp::Struct as Timestamp,
+	Trait::{
+		Import::Destination::Trait as Destination,
+		Runtime::Trait as Runtime,
+		Transport::{Reader::Trait as Reader, Writer::Trait as Writer},
+	},
+};
+
+pub mod Assembly;
+pub mod Backoff;
+pub mod Config;
+pub mod Submission;
+pub mod Submitted;
@@ -0,0 +1,104 @@
+/// Puts a result sent in chunks back together.
+///
+/// Chunks must come in order, each telling the same total; the bytes are
+/// hashed as they come, and the digest of the `ResultEnd` must match them.
+/// A failed check names the code the transfer is aborted with.
+pub struct Struct {
+	/// The number of chunks of the result.
+	Total:u64,
+
+	/// The position of the chunk expected next.
+	Next:u64,
+
+	/// The bytes of the chunks so far.
+	Data:Vec<u8>,
+
+	/// The hash of the bytes so far.
+	Digest:Sha256::Stream,
+}
+
+impl Struct {
+	/// Starts putting a result together.
+	///
+	/// # Arguments
+	///
+	/// * `Total` - The number of chunks the first one announced.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Total:u64) -> Self {
+		Struct { Total, Next:0, Data:Vec::new(), Digest:Sha256::Stream::New() }
+	}
+
+	/// Adds the next chunk.
+	///
+	/// # Arguments
+	///
+	/// * `Index` - The position of the chunk.
+	/// * `Total` - The number of chunks the chunk announces.
+	/// * `Data` - The bytes of the chunk.
+	///
+	/// # Returns
+	///
+	/// `Ok(())`, or `ChunkOrder` with a description if the chunk is not the
+	/// one expected.
+	pub fn Push(&mut self, Index:u64, Total:u64, Data:&[u8]) -> Result<(), (Code, String)> {
+		if Total != self.Total {
+			return Err((
+				Code::ChunkOrder,
+				format!("Chunk {} announces {} chunks, not {}", Index, Total, self.Total),
+			));
+		}
+
+		if Index != self.Next {
+			return Err((
+				Code::ChunkOrder,
+				format!("Chunk {} of {} came where chunk {} was expected", Index, Total, self.Next),
+			));
+		}
+
+		self.Digest.Update(Data);
+
+		self.Data.extend_from_slice(Data);
+
+		self.Next += 1;
+
+		Ok(())
+	}
+
+	/// Ends the result, checking it against its digest.
+	///
+	/// # Arguments
+	///
+	/// * `Digest` - The digest of the `ResultEnd`, in hexadecimal.
+	///
+	/// # Returns
+	///
+	/// The bytes of the result, `ChunkOrder` if chunks are missing, or
+	/// `DigestMismatch` if the bytes do not hash to the digest.
+	pub fn Finish(self, Digest:&str) -> Result<Vec<u8>, (Code, String)> {
+		if self.Next != self.Total {
+			return Err((
+				Code::ChunkOrder,
+				format!("The result ended after {} of {} chunks", self.Next, self.Total),
+			));
+		}
+
+		let Actual = Hex(&self.Digest.Finish());
+
+		if !Actual.eq_ignore_ascii_case(Digest) {
+			return Err((
+				Code::DigestMismatch,
+				format!("The chunks hash to {}, not {}", Actual, Digest),
+			));
+		}
+
+		Ok(self.Data)
+	}
+}
+
+use crate::{
+	Digest::{Hex, Sha256},
+	Enum::Transport::Code::Enum as Code,
+};
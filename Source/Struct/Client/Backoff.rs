@@ -0,0 +1,92 @@
+/// How a client waits between reconnection attempts.
+///
+/// The delay starts at `Initial` and is multiplied by `Factor` after every
+/// failed attempt, up to `Maximum`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Struct {
+	/// The delay before the first attempt.
+	pub Initial:Duration,
+
+	/// The longest delay between attempts.
+	pub Maximum:Duration,
+
+	/// The growth of the delay after each failed attempt.
+	pub Factor:f64,
+
+	/// The number of failed attempts after which the client gives up, or
+	/// `None` to retry forever.
+	pub Attempts:Option<u32>,
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Struct {
+			Initial:Duration::from_millis(100),
+			Maximum:Duration::from_secs(30),
+			Factor:2.0,
+			Attempts:None,
+		}
+	}
+}
+
+impl Struct {
+	/// Creates a new backoff doubling the delay from `Initial` up to
+	/// `Maximum`, retrying forever.
+	///
+	/// # Arguments
+	///
+	/// * `Initial` - The delay before the first attempt.
+	/// * `Maximum` - The longest delay between attempts.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Initial:Duration, Maximum:Duration) -> Self {
+		Struct { Initial, Maximum, ..Default::default() }
+	}
+
+	/// Sets the growth of the delay.
+	///
+	/// # Arguments
+	///
+	/// * `Factor` - The multiplier applied after each failed attempt.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithFactor(mut self, Factor:f64) -> Self {
+		self.Factor = Factor;
+
+		self
+	}
+
+	/// Sets the number of failed attempts after which the client gives up.
+	///
+	/// # Arguments
+	///
+	/// * `Attempts` - The number of failed attempts allowed.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAttempts(mut self, Attempts:u32) -> Self {
+		self.Attempts = Some(Attempts);
+
+		self
+	}
+
+	/// Computes the delay following a failed attempt.
+	///
+	/// # Arguments
+	///
+	/// * `Delay` - The delay before the failed attempt.
+	///
+	/// # Returns
+	///
+	/// The delay before the next attempt.
+	pub fn Next(&self, Delay:Duration) -> Duration {
+		Delay.mul_f64(self.Factor.max(1.0)).min(self.Maximum)
+	}
+}
+
+use std::time::Duration;
@@ -0,0 +1,126 @@
+/// The settings of a client connection.
+#[derive(Clone)]
+pub struct Struct {
+	/// The token sent with `Auth` after connecting, or `None` to skip
+	/// authentication.
+	pub Token:Option<String>,
+
+	/// The codec encoding messages and decoding replies; must match the
+	/// server's.
+	pub Codec:Arc<dyn Codec>,
+
+	/// How long to wait for an answer or a result.
+	pub Timeout:Duration,
+
+	/// How to reconnect after the connection drops, or `None` to stay
+	/// disconnected.
+	pub Backoff:Option<Backoff::Struct>,
+
+	/// The runtime the client spawns its tasks on and measures its timeouts
+	/// and backoffs with.
+	pub Runtime:Arc<dyn Runtime>,
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Struct {
+			Token:None,
+			Codec:Arc::new(Json::Struct),
+			Timeout:Duration::from_secs(30),
+			Backoff:None,
+			Runtime:Arc::new(Tokio::Struct),
+		}
+	}
+}
+
+impl Struct {
+	/// Creates a new configuration using the JSON codec, no token and a
+	/// 30-second timeout, without reconnection, on tokio.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Sets the token sent after connecting.
+	///
+	/// # Arguments
+	///
+	/// * `Token` - The shared secret configured on the server.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithToken(mut self, Token:&str) -> Self {
+		self.Token = Some(Token.to_string());
+
+		self
+	}
+
+	/// Sets the codec.
+	///
+	/// # Arguments
+	///
+	/// * `Codec` - The codec matching the server's.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCodec(mut self, Codec:Arc<dyn Codec>) -> Self {
+		self.Codec = Codec;
+
+		self
+	}
+
+	/// Enables reconnection.
+	///
+	/// # Arguments
+	///
+	/// * `Backoff` - How to wait between reconnection attempts.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBackoff(mut self, Backoff:Backoff::Struct) -> Self {
+		self.Backoff = Some(Backoff);
+
+		self
+	}
+
+	/// Sets the runtime.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime to spawn, sleep and time out with.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Runtime;
+
+		self
+	}
+
+	/// Sets the timeout.
+	///
+	/// # Arguments
+	///
+	/// * `Timeout` - How long to wait for an answer or a result.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithTimeout(mut self, Timeout:Duration) -> Self {
+		self.Timeout = Timeout;
+
+		self
+	}
+}
+
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+	Struct::{Client::Backoff, Runtime::Tokio, Transport::Codec::Json},
+	Trait::{Runtime::Trait as Runtime, Transport::Codec::Trait as Codec},
+};
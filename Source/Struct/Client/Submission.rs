@@ -0,0 +1,167 @@
+/// An action to submit through a client.
+#[derive(Clone, Debug, Default)]
+pub struct Struct {
+	/// The name of the plan function to run.
+	pub Action:String,
+
+	/// The arguments passed to the function.
+	pub Argument:Vec<Value>,
+
+	/// Metadata attached to the action.
+	pub Metadata:Map<String, Value>,
+
+	/// The reserved options of the action by name, such as its `Queue`,
+	/// sent apart from its metadata.
+	pub Options:Map<String, Value>,
+
+	/// Whether running the action twice is harmless, so a reconnecting
+	/// client may submit it again instead of failing it.
+	pub Idempotent:bool,
+
+	/// How long to wait for the result, counted from the submission, instead
+	/// of the configured timeout.
+	pub Timeout:Option<Duration>,
+
+	/// When to stop waiting for the result; sent along as the `Deadline`
+	/// option, so the server gives up on the action at the same time.
+	pub Deadline:Option<Instant>,
+
+	/// Whether to cancel the action on the server once the wait for its
+	/// result runs past `Timeout` or `Deadline`.
+	pub CancelOnTimeout:bool,
+}
+
+impl Struct {
+	/// Creates a new submission without arguments or metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The name of the plan function to run.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Action:&str) -> Self { Struct { Action:Action.to_string(), ..Default::default() } }
+
+	/// Appends an argument.
+	///
+	/// # Arguments
+	///
+	/// * `Argument` - The argument passed to the function.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithArgument(mut self, Argument:Value) -> Self {
+		self.Argument.push(Argument);
+
+		self
+	}
+
+	/// Sets a metadata entry.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key.
+	/// * `Value` - The value stored under `Key`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithMetadata(mut self, Key:&str, Value:Value) -> Self {
+		self.Metadata.insert(Key.to_string(), Value);
+
+		self
+	}
+
+	/// Sets a reserved option, such as the `Queue` of the action.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The reserved key.
+	/// * `Value` - The value of the option.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOption(mut self, Key:Reserved, Value:Value) -> Self {
+		self.Options.insert(Key.Name().to_string(), Value);
+
+		self
+	}
+
+	/// Marks the action as safe to submit again after a reconnection.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithIdempotent(mut self) -> Self {
+		self.Idempotent = true;
+
+		self
+	}
+
+	/// Sets how long to wait for the result.
+	///
+	/// # Arguments
+	///
+	/// * `Timeout` - The time granted from the submission on.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithTimeout(mut self, Timeout:Duration) -> Self {
+		self.Timeout = Some(Timeout);
+
+		self
+	}
+
+	/// Sets the deadline of the action, on both sides of the connection.
+	///
+	/// # Arguments
+	///
+	/// * `Deadline` - When to stop waiting for the result.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDeadline(mut self, Deadline:Instant) -> Self {
+		self.Deadline = Some(Deadline);
+
+		self
+	}
+
+	/// Sets whether to cancel the action on the server once its wait times
+	/// out.
+	///
+	/// # Arguments
+	///
+	/// * `Cancel` - Whether to send `Cancel` after timing out.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCancelOnTimeout(mut self, Cancel:bool) -> Self {
+		self.CancelOnTimeout = Cancel;
+
+		self
+	}
+
+	/// Returns when to stop waiting for the result: the earlier of the
+	/// timeout, counted from now, and the deadline.
+	pub fn Limit(&self) -> Option<Instant> {
+		let Timeout = self.Timeout.map(|Timeout| Instant::now() + Timeout);
+
+		match (Timeout, self.Deadline) {
+			(Some(Timeout), Some(Deadline)) => Some(Timeout.min(Deadline)),
+			(Timeout, Deadline) => Timeout.or(Deadline),
+		}
+	}
+}
+
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+use tokio::time::Instant;
+
+use crate::Enum::Sequence::Reserved::Enum as Reserved;
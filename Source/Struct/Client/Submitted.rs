@@ -0,0 +1,137 @@
+/// A submission in flight, resolved by the result carrying its identifier.
+pub struct Struct {
+	/// The identifier of the submission.
+	Id:String,
+
+	/// Receives the result from the connection.
+	Receiver:Receiver<Result<Value, Error>>,
+
+	/// How long to wait for the result.
+	Timeout:Duration,
+
+	/// When to give up on the result instead, if the submission set a
+	/// timeout or deadline of its own.
+	Limit:Option<Instant>,
+
+	/// Whether to cancel the action on the server when giving up at `Limit`.
+	Cancel:bool,
+
+	/// The client, to forget the submission when giving up on it.
+	Shared:Weak<Shared>,
+
+	/// The runtime measuring the timeout, the client's once tied to it.
+	Runtime:Arc<dyn Runtime>,
+}
+
+impl Struct {
+	/// Creates a new submission handle.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the submission.
+	/// * `Receiver` - Receives the result from the connection.
+	/// * `Timeout` - How long to wait for the result.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Id:String, Receiver:Receiver<Result<Value, Error>>, Timeout:Duration) -> Self {
+		Struct {
+			Id,
+			Receiver,
+			Timeout,
+			Limit:None,
+			Cancel:false,
+			Shared:Weak::new(),
+			Runtime:Arc::new(Tokio::Struct),
+		}
+	}
+
+	/// Ties the handle to its client and to the limit of its submission.
+	pub(super) fn WithClient(
+		mut self,
+		Shared:&Arc<Shared>,
+		Limit:Option<Instant>,
+		Cancel:bool,
+	) -> Self {
+		self.Shared = Arc::downgrade(Shared);
+
+		self.Runtime = Shared.Config.Runtime.clone();
+
+		self.Limit = Limit;
+
+		self.Cancel = Cancel;
+
+		self
+	}
+
+	/// Returns the identifier of the submission, e.g. to cancel it.
+	pub fn Id(&self) -> &str { &self.Id }
+
+	/// Waits for the result of the action.
+	///
+	/// Giving up forgets the submission, so a late result is dropped; at the
+	/// submission's own limit, the action is also cancelled on the server if
+	/// the submission asked for it.
+	///
+	/// # Returns
+	///
+	/// The value returned by the function, `Error::Failed` if the server
+	/// rejected or failed the action, `Error::ConnectionLost` if the
+	/// connection dropped first, `Error::ClientTimeout` past the
+	/// submission's own timeout or deadline, or `Error::Timeout` past the
+	/// configured one.
+	pub async fn Wait(mut self) -> Result<Value, Error> {
+		let Limit = self.Limit.unwrap_or_else(|| self.Runtime.Now() + self.Timeout);
+
+		match self.Runtime.clone().TimeoutAt(Limit, &mut self.Receiver).await {
+			Some(Ok(Result)) => Result,
+			Some(Err(_)) => Err(Error::ConnectionLost),
+			None => {
+				self.Expire().await;
+
+				Err(if self.Limit.is_some() { Error::ClientTimeout } else { Error::Timeout })
+			},
+		}
+	}
+
+	/// Forgets the submission and cancels its action if asked to.
+	async fn Expire(&self) {
+		let Some(Shared) = self.Shared.upgrade() else {
+			return;
+		};
+
+		Lock(&Shared.Pending).remove(&self.Id);
+
+		if !(self.Cancel && self.Limit.is_some()) {
+			return;
+		}
+
+		match Shared.Request(Message::Cancel { Id:self.Id.clone() }).await {
+			Ok(Reply::Cancelled { Cancelled: true, .. }) => {},
+			Ok(_) => warn!("Submission {} was not cancelled after timing out", self.Id),
+			Err(_Error) => {
+				warn!("Cannot cancel submission {} after timing out: {}", self.Id, _Error)
+			},
+		}
+	}
+}
+
+use std::{
+	sync::{Arc, Weak},
+	time::Duration,
+};
+
+use log::warn;
+use serde_json::Value;
+use tokio::{sync::oneshot::Receiver, time::Instant};
+
+use super::{Lock, Shared};
+use crate::{
+	Enum::{
+		Client::Error::Enum as Error,
+		Transport::{Message::Enum as Message, Reply::Enum as Reply},
+	},
+	Struct::Runtime::Tokio,
+	Trait::Runtime::Trait as Runtime,
+};
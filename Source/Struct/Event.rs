@@ -0,0 +1,3 @@
+pub mod Bus;
+
+pub mod Subscription;
@@ -0,0 +1,67 @@
+/// A process-wide stream of lifecycle events.
+///
+/// The bus is a `tokio::sync::broadcast` channel: every subscriber sees every
+/// event published after it subscribed. Publishing is cheap when nobody
+/// listens, because the event is not even built. A subscriber that falls
+/// more than the capacity behind loses the oldest events; its
+/// `Subscription` skips past the gap and counts the lost events in
+/// `echo_events_dropped_total` and in `Dropped`.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The sending half of the broadcast channel.
+	Sender:Sender<Event>,
+
+	/// The number of events lost by lagging subscribers.
+	Dropped:Arc<AtomicU64>,
+}
+
+impl Struct {
+	/// Creates a new bus buffering up to `Capacity` events per subscriber.
+	///
+	/// # Arguments
+	///
+	/// * `Capacity` - How many events a subscriber may lag behind.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Capacity:usize) -> Self {
+		Struct { Sender:channel(Capacity).0, Dropped:Arc::new(AtomicU64::new(0)) }
+	}
+
+	/// Publishes an event, building it only if someone is subscribed.
+	///
+	/// # Arguments
+	///
+	/// * `Event` - Builds the event to publish.
+	pub fn Emit(&self, Event:impl FnOnce() -> Event) {
+		if self.Sender.receiver_count() > 0 {
+			let _ = self.Sender.send(Event());
+		}
+	}
+
+	/// Subscribes to events published from now on.
+	///
+	/// # Returns
+	///
+	/// A new `Subscription` to the bus.
+	pub fn Subscribe(&self) -> Subscription::Struct {
+		Subscription::Struct::New(self.Sender.subscribe(), self.Dropped.clone())
+	}
+
+	/// Returns the number of events lost by lagging subscribers so far.
+	pub fn Dropped(&self) -> u64 { self.Dropped.load(Ordering::Relaxed) }
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(1024) }
+}
+
+use std::sync::{
+	atomic::{AtomicU64, Ordering},
+	Arc,
+};
+
+use tokio::sync::broadcast::{channel, Sender};
+
+use crate::{Enum::Event::Enum as Event, Struct::Event::Subscription};
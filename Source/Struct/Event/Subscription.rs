@@ -0,0 +1,105 @@
+/// A subscriber's view of the event bus.
+pub struct Struct {
+	/// The receiving half of the broadcast channel.
+	Receiver:Receiver<Event>,
+
+	/// The bus-wide count of events lost by lagging subscribers.
+	Dropped:Arc<AtomicU64>,
+}
+
+impl Struct {
+	/// Creates a new subscription.
+	///
+	/// # Arguments
+	///
+	/// * `Receiver` - The receiving half of the broadcast channel.
+	/// * `Dropped` - The bus-wide count of lost events.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Receiver:Receiver<Event>, Dropped:Arc<AtomicU64>) -> Self {
+		Struct { Receiver, Dropped }
+	}
+
+	/// Receives the next event.
+	///
+	/// If the subscriber lagged behind, the lost events are counted and
+	/// reception continues with the oldest event still buffered.
+	///
+	/// # Returns
+	///
+	/// The next event, or `None` once the bus is gone.
+	pub async fn Recv(&mut self) -> Option<Event> {
+		loop {
+			match self.Receiver.recv().await {
+				Ok(Event) => return Some(Event),
+				Err(RecvError::Lagged(Count)) => {
+					self.Dropped.fetch_add(Count, Ordering::Relaxed);
+
+					counter!("echo_events_dropped_total").increment(Count);
+				},
+				Err(RecvError::Closed) => return None,
+			}
+		}
+	}
+
+	/// Receives the next event if one is buffered, without waiting; lost
+	/// events are counted as by `Recv`.
+	///
+	/// # Returns
+	///
+	/// The next event, or `None` when none is buffered or the bus is gone.
+	pub fn TryRecv(&mut self) -> Option<Event> {
+		loop {
+			match self.Receiver.try_recv() {
+				Ok(Event) => return Some(Event),
+				Err(TryRecvError::Lagged(Count)) => {
+					self.Dropped.fetch_add(Count, Ordering::Relaxed);
+
+					counter!("echo_events_dropped_total").increment(Count);
+				},
+				Err(_) => return None,
+			}
+		}
+	}
+
+	/// Writes every event to a transport writer, one JSON frame per event.
+	///
+	/// Pairing this with `Frame::Socket::Writer` streams the bus to a
+	/// WebSocket monitoring client.
+	///
+	/// # Arguments
+	///
+	/// * `Writer` - The writer receiving the events.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the bus is gone, or the I/O error that ended writing.
+	pub async fn Forward(mut self, mut Writer:impl Writer) -> io::Result<()> {
+		while let Some(Event) = self.Recv().await {
+			Writer.Write(&serde_json::to_vec(&Event)?).await?;
+		}
+
+		Ok(())
+	}
+
+	/// Consumes the subscription, returning the raw broadcast receiver.
+	pub fn Receiver(self) -> Receiver<Event> { self.Receiver }
+}
+
+use std::{
+	io,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+};
+
+use metrics::counter;
+use tokio::sync::broadcast::{
+	error::{RecvError, TryRecvError},
+	Receiver,
+};
+
+use crate::{Enum::Event::Enum as Event, Trait::Transport::Writer::Trait as Writer};
@@ -0,0 +1,9 @@
+pub mod Depth;
+
+pub mod Latency;
+
+pub mod Outcome;
+
+pub mod Recent;
+
+pub mod Report;
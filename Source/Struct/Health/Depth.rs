@@ -0,0 +1,59 @@
+/// A health check tripping when a Karma queue grows too deep.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The name of the Karma queue.
+	pub Queue:String,
+
+	/// The depth from which the queue is `Degraded`.
+	pub Degraded:u64,
+
+	/// The depth from which the queue is `Unhealthy`.
+	pub Unhealthy:u64,
+}
+
+impl Struct {
+	/// Creates a new depth check.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the Karma queue.
+	/// * `Degraded` - The depth from which the queue is `Degraded`.
+	/// * `Unhealthy` - The depth from which the queue is `Unhealthy`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Queue:&str, Degraded:u64, Unhealthy:u64) -> Self {
+		Struct { Queue:Queue.to_string(), Degraded, Unhealthy }
+	}
+}
+
+#[async_trait]
+impl Check for Struct {
+	fn Name(&self) -> String { format!("Depth:{}", self.Queue) }
+
+	async fn Check(&self, Life:&Life) -> Outcome {
+		let Some(Depth) = Life.Karma.get(&self.Queue).map(|Production| Production.Stats().Depth)
+		else {
+			return Outcome::New(Status::Unhealthy, format!("Queue {} does not exist", self.Queue));
+		};
+
+		let Status = if Depth >= self.Unhealthy {
+			Status::Unhealthy
+		} else if Depth >= self.Degraded {
+			Status::Degraded
+		} else {
+			Status::Healthy
+		};
+
+		Outcome::New(Status, format!("Queue {} holds {} actions", self.Queue, Depth))
+	}
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Health::Status::Enum as Status,
+	Struct::{Health::Outcome::Struct as Outcome, Sequence::Life::Struct as Life},
+	Trait::Health::Check::Trait as Check,
+};
@@ -0,0 +1,85 @@
+/// A health check tripping when the runtime is slow to poll ready tasks.
+///
+/// Every evaluation spawns a trivial task on the runtime of the `Life` and
+/// measures how long it waits before a worker first polls it, recording the
+/// measure in the `echo_runtime_poll_latency_seconds` histogram. The
+/// latency grows when plan functions hold their workers with CPU-heavy
+/// work; such functions should be `Blocking` or call
+/// `Invocation::Checkpoint` in their loops.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The latency from which the runtime is `Degraded`.
+	pub Degraded:Duration,
+
+	/// The latency from which the runtime is `Unhealthy`.
+	pub Unhealthy:Duration,
+}
+
+impl Struct {
+	/// Creates a new latency check.
+	///
+	/// # Arguments
+	///
+	/// * `Degraded` - The latency from which the runtime is `Degraded`.
+	/// * `Unhealthy` - The latency from which the runtime is `Unhealthy`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Degraded:Duration, Unhealthy:Duration) -> Self { Struct { Degraded, Unhealthy } }
+
+	/// Measures how long a task spawned now waits to be polled, and records
+	/// it in the `echo_runtime_poll_latency_seconds` histogram.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime to spawn the task on.
+	///
+	/// # Returns
+	///
+	/// The latency of this one task.
+	pub async fn Probe(Runtime:&dyn Runtime) -> Duration {
+		let (Spawned, (Sender, Receiver)) = (Instant::now(), oneshot::channel());
+
+		Runtime.Spawn(Box::pin(async move {
+			let _ = Sender.send(Spawned.elapsed());
+		}));
+
+		let Latency = Receiver.await.unwrap_or_else(|_| Spawned.elapsed());
+
+		histogram!("echo_runtime_poll_latency_seconds").record(Latency.as_secs_f64());
+
+		Latency
+	}
+}
+
+#[async_trait]
+impl Check for Struct {
+	fn Name(&self) -> String { "Latency".to_string() }
+
+	async fn Check(&self, Life:&Life) -> Outcome {
+		let Latency = Self::Probe(&*Life.Runtime()).await;
+
+		let Status = if Latency >= self.Unhealthy {
+			Status::Unhealthy
+		} else if Latency >= self.Degraded {
+			Status::Degraded
+		} else {
+			Status::Healthy
+		};
+
+		Outcome::New(Status, format!("A ready task waited {:?} to be polled", Latency))
+	}
+}
+
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use metrics::histogram;
+use tokio::sync::oneshot;
+
+use crate::{
+	Enum::Health::Status::Enum as Status,
+	Struct::{Health::Outcome::Struct as Outcome, Sequence::Life::Struct as Life},
+	Trait::{Health::Check::Trait as Check, Runtime::Trait as Runtime},
+};
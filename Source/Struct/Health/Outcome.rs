@@ -0,0 +1,30 @@
+/// The result of one health check.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Check"))]
+pub struct Struct {
+	/// The status reported by the check.
+	pub Status:Status,
+
+	/// A human-readable explanation of the status.
+	pub Message:String,
+}
+
+impl Struct {
+	/// Creates a new outcome.
+	///
+	/// # Arguments
+	///
+	/// * `Status` - The status reported by the check.
+	/// * `Message` - A human-readable explanation of the status.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Status:Status, Message:impl Into<String>) -> Self {
+		Struct { Status, Message:Message.into() }
+	}
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Health::Status::Enum as Status;
@@ -0,0 +1,67 @@
+/// A health check tripping when work is waiting but nothing succeeded lately.
+///
+/// An idle process is healthy however long ago its last success was; a
+/// process with queued actions and no success within the window is wedged.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// How recently an action must have succeeded while work is waiting.
+	pub Within:Duration,
+}
+
+impl Struct {
+	/// Creates a new recent-success check.
+	///
+	/// # Arguments
+	///
+	/// * `Within` - How recently an action must have succeeded.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Within:Duration) -> Self { Struct { Within } }
+}
+
+#[async_trait]
+impl Check for Struct {
+	fn Name(&self) -> String { "Recent".to_string() }
+
+	async fn Check(&self, Life:&Life) -> Outcome {
+		let Stats = Life.Stats();
+
+		let Waiting = Stats.Queue.values().map(|Queue| Queue.Depth).sum::<u64>();
+
+		let Last = Stats.Sequence.iter().filter_map(|Count| Count.Last).max();
+
+		let Since = Last.map(|Last| {
+			SystemTime::now()
+				.duration_since(UNIX_EPOCH + Duration::from_millis(Last))
+				.unwrap_or(Duration::ZERO)
+		});
+
+		match (Waiting, Since) {
+			(0, _) => Outcome::New(Status::Healthy, "No work is waiting"),
+			(_, Some(Since)) if Since <= self.Within => {
+				Outcome::New(Status::Healthy, format!("Last success {:?} ago", Since))
+			},
+			(Waiting, Since) => Outcome::New(
+				Status::Unhealthy,
+				format!(
+					"{} actions waiting and no success within {:?} (last: {})",
+					Waiting,
+					self.Within,
+					Since.map_or("never".to_string(), |Since| format!("{:?} ago", Since))
+				),
+			),
+		}
+	}
+}
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Health::Status::Enum as Status,
+	Struct::{Health::Outcome::Struct as Outcome, Sequence::Life::Struct as Life},
+	Trait::Health::Check::Trait as Check,
+};
@@ -0,0 +1,45 @@
+/// The aggregated result of every health check of a `Life`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Report"))]
+pub struct Struct {
+	/// The worst status of all checks, or `Healthy` without checks.
+	pub Status:Status,
+
+	/// The outcome of every check, by name.
+	pub Check:BTreeMap<String, Outcome>,
+}
+
+impl Struct {
+	/// Aggregates check outcomes, the worst status winning.
+	///
+	/// # Arguments
+	///
+	/// * `Check` - The outcome of every check, by name.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Check:BTreeMap<String, Outcome>) -> Self {
+		let Status = Check
+			.values()
+			.map(|Outcome| Outcome.Status)
+			.max()
+			.unwrap_or(Status::Healthy);
+
+		Struct { Status, Check }
+	}
+
+	/// Returns whether the process is alive; a report could be produced, so
+	/// it is.
+	pub fn Live(&self) -> bool { true }
+
+	/// Returns whether the process is ready to accept work: no check is
+	/// `Unhealthy`.
+	pub fn Ready(&self) -> bool { self.Status != Status::Unhealthy }
+}
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Enum::Health::Status::Enum as Status, Struct::Health::Outcome::Struct as Outcome};
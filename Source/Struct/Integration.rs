@@ -0,0 +1,3 @@
+pub mod Tauri {
+	pub mod ResultBridge;
+}
@@ -0,0 +1,282 @@
+/// Bridges lifecycle events from the event bus to a Tauri webview without
+/// flooding it.
+///
+/// Progress events (`Enqueued`, `Started`, `Retried`) are coalesced per
+/// action: at most `Rate` of them per second reach the sink as
+/// `ActionProgress`, and one arriving too early replaces the one held before
+/// it, so the latest state is sent once the action's interval elapses. A
+/// final event drops the progress still held for its action.
+///
+/// Final events (`Completed`, `Failed`, `Expired`) are sent one by one as
+/// `ActionResult` while at most `Batch` arrive within a `Window`. Once more
+/// arrive, the rest of the window is held and sent as a single
+/// `ActionResults` event when the window ends. Task and connection events
+/// are not bridged.
+pub struct Struct<S:EventSink> {
+	/// Where the events are emitted.
+	Sink:S,
+
+	/// How many progress events per action may be emitted per second.
+	Rate:u32,
+
+	/// How many results a window may emit one by one.
+	Batch:usize,
+
+	/// How long a result window lasts.
+	Window:Duration,
+
+	/// The runtime measuring the intervals and windows.
+	Runtime:Arc<dyn Runtime>,
+}
+
+/// The progress of one action.
+struct Progress {
+	/// When the last progress event of the action was emitted.
+	Emitted:Instant,
+
+	/// The latest progress event held back, if any.
+	Held:Option<Event>,
+}
+
+impl<S:EventSink> Struct<S> {
+	/// Creates a bridge emitting 10 progress events per action per second and
+	/// batching more than 20 results per 100 milliseconds.
+	///
+	/// # Arguments
+	///
+	/// * `Sink` - Where the events are emitted.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Sink:S) -> Self {
+		Struct {
+			Sink,
+			Rate:10,
+			Batch:20,
+			Window:Duration::from_millis(100),
+			Runtime:Arc::new(Tokio::Struct),
+		}
+	}
+
+	/// Sets how many progress events per action may be emitted per second.
+	///
+	/// # Arguments
+	///
+	/// * `Rate` - The rate, at least 1.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRate(mut self, Rate:u32) -> Self {
+		self.Rate = Rate.max(1);
+
+		self
+	}
+
+	/// Sets how many results a window may emit before batching.
+	///
+	/// # Arguments
+	///
+	/// * `Batch` - The number of results emitted one by one per window.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBatch(mut self, Batch:usize) -> Self {
+		self.Batch = Batch;
+
+		self
+	}
+
+	/// Sets how long a result window lasts.
+	///
+	/// # Arguments
+	///
+	/// * `Window` - The length of the window.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithWindow(mut self, Window:Duration) -> Self {
+		self.Window = Window;
+
+		self
+	}
+
+	/// Sets the runtime measuring the intervals and windows, e.g. the one of
+	/// the `Life` whose bus is bridged.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Runtime;
+
+		self
+	}
+
+	/// Bridges events until the bus is gone, then emits everything held.
+	///
+	/// # Arguments
+	///
+	/// * `Subscription` - The subscription to the event bus.
+	pub async fn Run(self, mut Subscription:Subscription) {
+		let Interval = Duration::from_secs(1) / self.Rate;
+
+		let mut Action:HashMap<u64, Progress> = HashMap::new();
+
+		// The end of the current window, the results emitted in it and the
+		// ones held for its batch
+		let mut Window:Option<Instant> = None;
+
+		let mut Emitted = 0;
+
+		let mut Result:Vec<Event> = Vec::new();
+
+		loop {
+			let Due = Action
+				.values()
+				.filter(|Progress| Progress.Held.is_some())
+				.map(|Progress| Progress.Emitted + Interval)
+				.chain(Window)
+				.min();
+
+			let Next = select! {
+				Event = Subscription.Recv() => Some(Event),
+				_ = self.Until(Due) => None,
+			};
+
+			let Now = self.Runtime.Now();
+
+			match Next {
+				Some(None) => break,
+				Some(Some(Event)) => match &Event {
+					Event::Enqueued { Sequence, .. }
+					| Event::Started { Sequence }
+					| Event::Retried { Sequence, .. } => match Action.get_mut(Sequence) {
+						Some(Progress) if Now < Progress.Emitted + Interval => {
+							Progress.Held = Some(Event);
+						},
+						_ => {
+							Action.insert(*Sequence, Progress { Emitted:Now, Held:None });
+
+							self.Emit("ActionProgress", &Event);
+						},
+					},
+					Event::Completed { Sequence, .. }
+					| Event::Failed { Sequence, .. }
+					| Event::Expired { Sequence } => {
+						Action.remove(Sequence);
+
+						if Window.is_none_or(|End| Now >= End) {
+							self.Flush(&mut Result);
+
+							Window = Some(Now + self.Window);
+
+							Emitted = 0;
+						}
+
+						if Emitted < self.Batch {
+							Emitted += 1;
+
+							self.Emit("ActionResult", &Event);
+						} else {
+							Result.push(Event);
+						}
+					},
+					Event::TaskFailed { .. }
+					| Event::Opened { .. }
+					| Event::Closed { .. }
+					| Event::Audit { .. }
+					| Event::StuckAction { .. }
+					| Event::MissingHook { .. }
+					| Event::RefreshFailed { .. }
+					| Event::Node { .. }
+					| Event::Admission { .. } => {},
+				},
+				None => {},
+			}
+
+			for Progress in Action.values_mut() {
+				if Now >= Progress.Emitted + Interval {
+					if let Some(Event) = Progress.Held.take() {
+						Progress.Emitted = Now;
+
+						self.Emit("ActionProgress", &Event);
+					}
+				}
+			}
+
+			if Window.is_some_and(|End| Now >= End) {
+				self.Flush(&mut Result);
+
+				Window = None;
+			}
+		}
+
+		for Event in Action.into_values().filter_map(|Progress| Progress.Held) {
+			self.Emit("ActionProgress", &Event);
+		}
+
+		self.Flush(&mut Result);
+	}
+
+	/// Emits the results held for a batch as one `ActionResults` event.
+	fn Flush(&self, Result:&mut Vec<Event>) {
+		if Result.is_empty() {
+			return;
+		}
+
+		let Payload = Value::Array(
+			Result
+				.drain(..)
+				.filter_map(|Event| serde_json::to_value(Event).ok())
+				.collect(),
+		);
+
+		if let Err(_Error) = self.Sink.Emit("ActionResults", Payload) {
+			warn!("Failed to emit ActionResults: {}", _Error);
+		}
+	}
+
+	/// Emits one event.
+	fn Emit(&self, Name:&str, Event:&Event) {
+		let Payload = match serde_json::to_value(Event) {
+			Ok(Payload) => Payload,
+			Err(_Error) => {
+				warn!("Failed to serialize {} for {}: {}", Event.Type(), Name, _Error);
+
+				return;
+			},
+		};
+
+		if let Err(_Error) = self.Sink.Emit(Name, Payload) {
+			warn!("Failed to emit {}: {}", Name, _Error);
+		}
+	}
+
+	/// Waits until a deadline, or forever without one.
+	async fn Until(&self, Due:Option<Instant>) {
+		match Due {
+			Some(Due) => self.Runtime.SleepUntil(Due).await,
+			None => pending().await,
+		}
+	}
+}
+
+use std::{collections::HashMap, future::pending, sync::Arc, time::Duration};
+
+use log::warn;
+use serde_json::Value;
+use tokio::{select, time::Instant};
+
+use crate::{
+	Enum::Event::Enum as Event,
+	Struct::{Event::Subscription::Struct as Subscription, Runtime::Tokio},
+	Trait::{Integration::EventSink::Trait as EventSink, Runtime::Trait as Runtime},
+};
@@ -0,0 +1,5 @@
+pub mod Interval;
+
+pub mod Spawned;
+
+pub mod Tokio;
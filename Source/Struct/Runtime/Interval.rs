@@ -0,0 +1,58 @@
+/// Ticks at a regular period, measured with the `SleepUntil` of a
+/// `Runtime`.
+///
+/// The first tick is immediate. A tick missed by more than a period, e.g.
+/// because the work between ticks ran long, is not caught up on: the next
+/// one comes a period after it was taken.
+pub struct Struct {
+	/// The runtime waiting for the ticks.
+	Runtime:Arc<dyn Runtime>,
+
+	/// The time between ticks.
+	Period:Duration,
+
+	/// When the next tick is due.
+	Next:Instant,
+}
+
+impl Struct {
+	/// Creates an interval whose first tick is due now.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime waiting for the ticks.
+	/// * `Period` - The time between ticks.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Runtime:Arc<dyn Runtime>, Period:Duration) -> Self {
+		let Next = Runtime.Now();
+
+		Struct { Runtime, Period, Next }
+	}
+
+	/// Waits for the next tick.
+	///
+	/// Dropping the future before it resolves leaves the tick due, so it can
+	/// be raced in `select!`.
+	///
+	/// # Returns
+	///
+	/// When the tick was due.
+	pub async fn Tick(&mut self) -> Instant {
+		self.Runtime.SleepUntil(self.Next).await;
+
+		let (Due, Now) = (self.Next, self.Runtime.Now());
+
+		self.Next = if Due + self.Period <= Now { Now + self.Period } else { Due + self.Period };
+
+		Due
+	}
+}
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::Instant;
+
+use crate::Trait::Runtime::Trait as Runtime;
@@ -0,0 +1,148 @@
+/// A task spawned by a `Runtime`.
+///
+/// Awaiting the handle waits for the task to end, with `Exit` if it did not
+/// return. Dropping the handle detaches the task; `Abort` stops it.
+pub struct Struct {
+	/// Resolves once the task ended.
+	Join:Join,
+
+	/// Stops the task.
+	Abort:Box<dyn Fn() + Send + Sync>,
+
+	/// Tells whether the task ended.
+	Finished:Box<dyn Fn() -> bool + Send + Sync>,
+}
+
+impl Struct {
+	/// Creates a handle from its parts.
+	///
+	/// # Arguments
+	///
+	/// * `Join` - Resolves once the task ended.
+	/// * `Abort` - Stops the task.
+	/// * `Finished` - Tells whether the task ended.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(
+		Join:Join,
+		Abort:impl Fn() + Send + Sync + 'static,
+		Finished:impl Fn() -> bool + Send + Sync + 'static,
+	) -> Self {
+		Struct { Join, Abort:Box::new(Abort), Finished:Box::new(Finished) }
+	}
+
+	/// Prepares a task for an executor that runs detached futures only.
+	///
+	/// The task is made abortable and its panic caught, so the handle works
+	/// the same on any executor.
+	///
+	/// # Arguments
+	///
+	/// * `Task` - The task to run.
+	///
+	/// # Returns
+	///
+	/// The future for the executor to run, and the handle of the task.
+	pub fn Detached(Task:BoxFuture<'static, ()>) -> (BoxFuture<'static, ()>, Self) {
+		let (Handle, Registration) = AbortHandle::new_pair();
+
+		let (Sender, Receiver) = oneshot::channel();
+
+		let Finished = Arc::new(AtomicBool::new(false));
+
+		let Run = {
+			let Finished = Finished.clone();
+
+			async move {
+				let Caught = AssertUnwindSafe(Task).catch_unwind();
+
+				let Outcome = match Abortable::new(Caught, Registration).await {
+					Ok(Ok(())) => Ok(()),
+					Ok(Err(Panic)) => Err(Exit::Panicked(Describe(Panic))),
+					Err(_) => Err(Exit::Cancelled),
+				};
+
+				Finished.store(true, Ordering::SeqCst);
+
+				let _ = Sender.send(Outcome);
+			}
+		};
+
+		let Join = async move { Receiver.await.unwrap_or(Err(Exit::Cancelled)) };
+
+		(
+			Box::pin(Run),
+			Self::New(Box::pin(Join), move || Handle.abort(), move || {
+				Finished.load(Ordering::SeqCst)
+			}),
+		)
+	}
+
+	/// Stops the task; awaiting the handle then yields `Exit::Cancelled`
+	/// unless the task already ended.
+	pub fn Abort(&self) { (self.Abort)(); }
+
+	/// Returns whether the task ended.
+	pub fn IsFinished(&self) -> bool { (self.Finished)() }
+}
+
+impl Future for Struct {
+	type Output = Result<(), Exit>;
+
+	fn poll(mut self: Pin<&mut Self>, Context:&mut Context<'_>) -> Poll<Self::Output> {
+		self.Join.as_mut().poll(Context)
+	}
+}
+
+impl From<JoinHandle<()>> for Struct {
+	/// Wraps the handle of a task spawned on tokio.
+	fn from(Task:JoinHandle<()>) -> Self {
+		let (Abort, Finished) = (Task.abort_handle(), Task.abort_handle());
+
+		let Join = async move {
+			Task.await.map_err(|_Error| {
+				if _Error.is_cancelled() {
+					Exit::Cancelled
+				} else {
+					Exit::Panicked(Describe(_Error.into_panic()))
+				}
+			})
+		};
+
+		Self::New(Box::pin(Join), move || Abort.abort(), move || Finished.is_finished())
+	}
+}
+
+/// Resolves once a task ended; `Sync` so the handle can be shared.
+pub type Join = Pin<Box<dyn Future<Output = Result<(), Exit>> + Send + Sync>>;
+
+/// Describes a panic by its message, when it has one.
+fn Describe(Panic:Box<dyn Any + Send>) -> String {
+	Panic
+		.downcast_ref::<&str>()
+		.map(|Message| Message.to_string())
+		.or_else(|| Panic.downcast_ref::<String>().cloned())
+		.unwrap_or_else(|| "panicked without a message".to_string())
+}
+
+use std::{
+	any::Any,
+	future::Future,
+	panic::AssertUnwindSafe,
+	pin::Pin,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	task::{Context, Poll},
+};
+
+use futures::{
+	future::{AbortHandle, Abortable, BoxFuture},
+	FutureExt,
+};
+use tokio::{sync::oneshot, task::JoinHandle};
+
+use crate::Enum::Runtime::Exit::Enum as Exit;
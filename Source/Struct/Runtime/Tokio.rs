@@ -0,0 +1,34 @@
+/// The default `Runtime`: spawns on the current tokio runtime, blocking tasks
+/// on its blocking thread pool, and waits with its timers, so a paused tokio
+/// clock applies.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+impl Runtime for Struct {
+	fn Spawn(&self, Task:BoxFuture<'static, ()>) -> Spawned::Struct { tokio::spawn(Task).into() }
+
+	fn SpawnBlocking(&self, Task:BoxFuture<'static, ()>) -> Spawned::Struct {
+		let Handle = Handle::current();
+
+		spawn_blocking(move || Handle.block_on(Task)).into()
+	}
+
+	fn Sleep(&self, Length:Duration) -> BoxFuture<'static, ()> { Box::pin(sleep(Length)) }
+
+	fn SleepUntil(&self, Deadline:Instant) -> BoxFuture<'static, ()> {
+		Box::pin(sleep_until(Deadline))
+	}
+
+	fn Now(&self) -> Instant { Instant::now() }
+}
+
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use tokio::{
+	runtime::Handle,
+	task::spawn_blocking,
+	time::{sleep, sleep_until, Instant},
+};
+
+use crate::{Struct::Runtime::Spawned, Trait::Runtime::Trait as Runtime};
@@ -12,6 +12,12 @@ pub struct Struct {
 
 	/// A signal indicating whether the sequence should continue running.
 	pub Time:Signal::Struct<bool>,
+
+	/// The counters of executed actions, registered with `Life`.
+	Activity:Arc<Activity::Struct>,
+
+	/// The token `ShutdownNow` cancels, cancelling the actions executing.
+	Abort:Cancellation::Struct,
 }
 
 impl Struct {
@@ -27,7 +33,20 @@ impl Struct {
 	///
 	/// A new `Struct` instance with the `Time` signal initialized to `false`.
 	pub fn New(Site:Arc<dyn Site>, Production:Arc<Production::Struct>, Life:Life::Struct) -> Self {
-		Struct { Site, Production, Life, Time:Signal::Struct::New(false) }
+		Production.Attach(&Life.Bus);
+
+		Production.Enlist(&Life.Actions);
+
+		let Activity = Life.Registry.Sequence();
+
+		Struct {
+			Site,
+			Production,
+			Life,
+			Time:Signal::Struct::New(false),
+			Activity,
+			Abort:Cancellation::Struct::New(),
+		}
 	}
 
 	/// Runs the sequence, processing actions until the `Time` signal is set to
@@ -35,18 +54,534 @@ impl Struct {
 	///
 	/// This method continuously checks for new actions in the `Work` queue and
 	/// processes them. If an error occurs during processing, it logs the
-	/// error.
+	/// error. Every step is published on the event bus of `Life`.
 	pub async fn Run(&self) {
+		let Runtime = self.Life.Runtime();
+
 		while !self.Time.Get().await {
-			if let Some(Action) = self.Production.Do().await {
-				match self.Again(Action).await {
-					Ok(_) => {},
-					Err(e) => error!("Error processing action: {}", e),
-				}
-			} else {
+			if self.ConsumeOne().await.is_none() {
 				// Add a small delay to prevent tight looping when there are no
 				// actions
-				sleep(std::time::Duration::from_millis(100)).await;
+				Runtime.Sleep(Duration::from_millis(100)).await;
+			}
+		}
+	}
+
+	/// Runs a single step of `Run`: dequeues the next action of `Production`
+	/// and executes it with retries, exactly as `Run` would.
+	///
+	/// External loops, such as one driven by a UI runtime or a custom
+	/// scheduler, call this to drive the sequence without reimplementing its
+	/// retries, events and dead-lettering. The action is processed under the
+	/// settings of the Karma queue `Production` is, dead-letter queue
+	/// included, or the defaults if it is none. It ignores the `Time` signal;
+	/// such loops watch `ShutdownToken` themselves.
+	///
+	/// # Returns
+	///
+	/// `None` if no action was due: the queue was empty, or its first action
+	/// was delayed and handed to the timer service or held by the
+	/// backpressure gate. Otherwise the outcome of the action after its last
+	/// attempt.
+	pub async fn ConsumeOne(&self) -> Option<Result<(), Error>> {
+		let (Stamp, Action, Reservation) = self.Next(&self.Production).await??;
+
+		let Outcome = self.Process(&self.Production, Stamp, Action, &self.Settings(), 0).await;
+
+		if let Some(Reservation) = Reservation {
+			Reservation.Ack();
+		}
+
+		Some(Outcome)
+	}
+
+	/// Returns the settings of the Karma queue `Production` is, if any.
+	fn Settings(&self) -> Settings {
+		self.Life
+			.Karma
+			.iter()
+			.find(|Queue| Arc::ptr_eq(Queue.value(), &self.Production))
+			.map(|Queue| self.Life.Settings(Queue.key()))
+			.unwrap_or_default()
+	}
+
+	/// Returns the production line the sequence consumes in `Run`.
+	pub fn Queue(&self) -> Arc<Production::Struct> { self.Production.clone() }
+
+	/// Returns the context actions are executed in.
+	pub fn Context(&self) -> &Life::Struct { &self.Life }
+
+	/// Returns a read-only view of the signal set by `Shutdown`.
+	pub fn ShutdownToken(&self) -> Signal::Reader::Struct<bool> {
+		Signal::Reader::Struct::New(self.Time.clone())
+	}
+
+	/// Returns the site actions are handed to.
+	pub fn Worker(&self) -> Arc<dyn Site> { self.Site.clone() }
+
+	/// Lists the actions executing right now in any sequence sharing `Life`,
+	/// the longest running first, without waiting on them.
+	pub fn InFlight(&self) -> Vec<InFlight> { self.Life.Watchdog.InFlight() }
+
+	/// Runs every Karma queue of `Life`, each under its own settings.
+	///
+	/// Each queue gets a consumer honouring the queue's `Settings`
+	/// (concurrency, batch size, rate limit and dead-letter queue), read
+	/// again before every dequeue so reloaded settings apply to subsequent
+	/// work. Queues added to Karma later are picked up as well, and the
+	/// consumers of queues removed from Karma stop. Returns once the `Time`
+	/// signal is set and running actions have finished.
+	pub async fn RunKarma(&self) {
+		let mut Consumer = HashMap::<String, (Arc<Production::Struct>, Spawned::Struct)>::new();
+
+		let Runtime = self.Life.Runtime();
+
+		while !self.Time.Get().await {
+			// Queues evicted or replaced since the last round
+			Consumer.retain(|Queue, (Production, Consumer)| {
+				let Current = self
+					.Life
+					.Karma
+					.get(Queue)
+					.is_some_and(|Entry| Arc::ptr_eq(Entry.value(), Production));
+
+				if !Current {
+					Consumer.Abort();
+				}
+
+				Current
+			});
+
+			for Entry in self.Life.Karma.iter() {
+				if !Consumer.contains_key(Entry.key()) {
+					let (Sequence, Queue, Production) =
+						(self.clone(), Entry.key().clone(), Entry.value().clone());
+
+					let Name = format!("consumer.{}", Queue);
+
+					Consumer.insert(
+						Entry.key().clone(),
+						(
+							Entry.value().clone(),
+							self.Life.Overseer.Spawn(
+								&Name,
+								Restart::Always { Backoff:Duration::from_secs(1) },
+								move || {
+									let (Sequence, Queue, Production) =
+										(Sequence.clone(), Queue.clone(), Production.clone());
+
+									async move { Sequence.Consume(Queue, Production).await }
+								},
+							),
+						),
+					);
+				}
+			}
+
+			Runtime.Sleep(Duration::from_millis(100)).await;
+		}
+
+		for (_, (_, Consumer)) in Consumer {
+			let _ = Consumer.await;
+		}
+	}
+
+	/// Consumes one Karma queue according to its settings.
+	async fn Consume(&self, Queue:String, Production:Arc<Production::Struct>) {
+		let Running = Arc::new(AtomicUsize::new(0));
+
+		let Done = Arc::new(Notify::new());
+
+		let Runtime = self.Life.Runtime();
+
+		let mut Next = Runtime.Now();
+
+		while !self.Time.Get().await {
+			let Settings = self.Life.Settings(&Queue);
+
+			let Free = Settings
+				.Concurrency
+				.max(1)
+				.saturating_sub(Running.load(Ordering::SeqCst));
+
+			let mut Taken = 0;
+
+			// Nothing is taken while the queue is paused or handed off
+			while Taken < Free.min(Settings.Batch.max(1))
+				&& !Settings.Paused
+				&& !self.Life.Handoff.Paused()
+			{
+				if let Some(Rate) = Settings.Rate.filter(|Rate| *Rate > 0.0) {
+					let Now = Runtime.Now();
+
+					if Now < Next {
+						break;
+					}
+
+					Next = Next.max(Now) + Duration::from_secs_f64(1.0 / Rate);
+				}
+
+				let Some(Next) = self.Next(&Production).await else {
+					break;
+				};
+
+				let Some((Stamp, Action, Reservation)) = Next else {
+					continue;
+				};
+
+				Taken += 1;
+
+				let Worker = Running.fetch_add(1, Ordering::SeqCst);
+
+				let (Sequence, Production, Running, Done, Settings) = (
+					self.clone(),
+					Production.clone(),
+					Running.clone(),
+					Done.clone(),
+					Settings.clone(),
+				);
+
+				Runtime.Spawn(Box::pin(async move {
+					let _ = Sequence.Process(&Production, Stamp, Action, &Settings, Worker).await;
+
+					if let Some(Reservation) = Reservation {
+						Reservation.Ack();
+					}
+
+					Running.fetch_sub(1, Ordering::SeqCst);
+
+					Done.notify_one();
+				}));
+			}
+
+			if Taken == 0 {
+				// Wait for a running action to finish, the rate limit to
+				// allow the next dequeue, or new work to arrive
+				let Wait = Next
+					.saturating_duration_since(Runtime.Now())
+					.max(Duration::from_millis(10));
+
+				select! {
+					_ = Done.notified() => {},
+					_ = Runtime.Sleep(Wait.min(Duration::from_millis(100))) => {},
+				}
+			}
+		}
+
+		while Running.load(Ordering::SeqCst) > 0 {
+			Done.notified().await;
+		}
+	}
+
+	/// Executes a dequeued action with retries, publishing its progress on
+	/// the event bus, handing it to the dead-letter queue if it fails and
+	/// completing its `Pending` on the production line it came from.
+	///
+	/// An action whose `Ttl` or `ExpiresAt` passed while it waited is not
+	/// executed; it completes with `DeadlineExceeded` instead, and one
+	/// cancelled while it waited completes with `Cancellation`. An executed
+	/// action is tracked by the `Watchdog` of `Life` as running on `Worker`
+	/// until it completes.
+	///
+	/// # Returns
+	///
+	/// The outcome the action completed with.
+	async fn Process(
+		&self,
+		Production:&Production::Struct,
+		Stamp:Stamp,
+		Action:Arc<dyn crate::Trait::Sequence::Action::Trait>,
+		Settings:&Settings,
+		Worker:usize,
+	) -> Result<(), Error> {
+		let Sequence = Stamp.Sequence;
+
+		if Stamp.Expired(Action.as_ref()).await {
+			self.Life.Bus.Emit(|| Event::Expired { Sequence });
+
+			counter!("echo_actions_expired_total").increment(1);
+
+			let Expired = Error::DeadlineExceeded(format!(
+				"action {} expired after waiting {:?}",
+				Sequence,
+				Stamp.Wait()
+			));
+
+			Action.Fail(&Expired);
+
+			if Settings.DeadLetterExpired {
+				self.DeadLetter(Settings, Self::Letter(Action, "expired", &Expired, 0))
+					.await;
+			}
+
+			Production.Complete(Sequence, Err(Expired.clone()));
+
+			return Err(Expired);
+		}
+
+		let Cancellation = Action.Cancellation();
+
+		if Cancellation.as_ref().is_some_and(Cancellation::Struct::IsCancelled) {
+			let Cancelled =
+				Error::Cancellation(format!("action {} was cancelled before it ran", Sequence));
+
+			self.Life.Bus.Emit(|| {
+				Event::Failed { Sequence, Error:Cancelled.to_string(), History:History::default() }
+			});
+
+			Action.Fail(&Cancelled);
+
+			Production.Complete(Sequence, Err(Cancelled.clone()));
+
+			return Err(Cancelled);
+		}
+
+		let Wait = Stamp.Wait();
+
+		self.Life.Bus.Emit(|| Event::Started { Sequence });
+
+		self.Activity.Begin();
+
+		let Started = Instant::now();
+
+		let Watched = self.Life.Watchdog.Watch(
+			Sequence,
+			Action
+				.Metadata("Action")
+				.await
+				.and_then(|Type| Type.as_str().map(str::to_string))
+				.unwrap_or_default(),
+			Worker,
+		);
+
+		let Again = self.Again(Action.clone(), Sequence, &Watched);
+
+		let (Result, History, Annotation) = match Cancellation {
+			Some(Cancellation) => {
+				pin!(Again);
+
+				select! {
+					Outcome = &mut Again => Outcome,
+					_ = self.Abort.Cancelled() => {
+						Cancellation.Cancel();
+
+						Again.await
+					},
+				}
+			},
+			None => Again.await,
+		};
+
+		drop(Watched);
+
+		self.Activity.End(Result.is_ok());
+
+		self.Account(Action.as_ref(), Wait, Started.elapsed(), &Result, &History)
+			.await;
+
+		match &Result {
+			Ok(_) => self
+				.Life
+				.Bus
+				.Emit(|| Event::Completed { Sequence, History, Annotation }),
+			Err(e) => {
+				error!("Error processing action: {}", e);
+
+				let Attempts = History.Attempts.len() as u64 + History.Omitted as u64;
+
+				self.Life
+					.Bus
+					.Emit(|| Event::Failed { Sequence, Error:e.to_string(), History });
+
+				Action.Fail(e);
+
+				let Reason = match e {
+					Error::UnknownAction { .. } => "unknown",
+					_ => "failed",
+				};
+
+				// A cancelled action is not worth keeping
+				if !Action.Cancellation().is_some_and(|Cancellation| Cancellation.IsCancelled()) {
+					self.DeadLetter(Settings, Self::Letter(Action, Reason, e, Attempts))
+						.await;
+				}
+			},
+		}
+
+		let Outcome = Result.as_ref().map(|_| ()).map_err(Error::clone);
+
+		Production.Complete(Sequence, Result);
+
+		Outcome
+	}
+
+	/// Records what an executed action cost in the `Cost` account of `Life`,
+	/// under its `Action` metadata.
+	async fn Account(
+		&self,
+		Action:&dyn crate::Trait::Sequence::Action::Trait,
+		Wait:Duration,
+		Execution:Duration,
+		Result:&Result<Value, Error>,
+		History:&History,
+	) {
+		// Sizes are those of the JSON encoding, good enough for planning
+		let Size = |Value:&Value| serde_json::to_vec(Value).map_or(0, |Bytes| Bytes.len() as u64);
+
+		self.Life.Cost.Record(&Cost::Sample::Struct {
+			Type:Action
+				.Metadata("Action")
+				.await
+				.and_then(|Type| Type.as_str().map(str::to_string))
+				.unwrap_or_default(),
+			Execution,
+			Wait,
+			Retries:(History.Attempts.len() as u64 + History.Omitted as u64).saturating_sub(1),
+			Argument:Action.ArgumentSize().await,
+			Result:Result.as_ref().map_or(0, Size),
+			Failed:Result.is_err(),
+		});
+	}
+
+	/// Takes the next action of a production line: reserved if the line
+	/// leases its actions, dequeued for good otherwise.
+	///
+	/// # Returns
+	///
+	/// `None` if the line is empty, `Some(None)` if its first action is
+	/// delayed and was handed to the timer service or was submitted by an
+	/// identity the backpressure gate holds, or the due action with
+	/// its stamp and, for a leasing line, its reservation to acknowledge
+	/// once processed.
+	async fn Next(
+		&self,
+		Production:&Arc<Production::Struct>,
+	) -> Option<Option<(Stamp, Arc<dyn crate::Trait::Sequence::Action::Trait>, Option<Reservation>)>>
+	{
+		if Production.Leasing().is_none() {
+			let (Stamp, Action) = Production.DequeueStamped().await?;
+
+			let Some(Action) = self.Defer(Production, Stamp, Action).await else {
+				return Some(None);
+			};
+
+			// The backpressure gate holds the action of a pressed identity
+			if let Some(Identity) = self.Life.Backpressure.Throttled(Action.as_ref()).await {
+				self.Life
+					.Backpressure
+					.Hold(Identity, Production.clone(), Stamp, Action)
+					.await;
+
+				return Some(None);
+			}
+
+			return Some(Some((Stamp, Arc::from(Action), None)));
+		}
+
+		let Reservation = Production.DoReserve().await?;
+
+		let (Stamp, Action) = (Reservation.Stamp(), Reservation.Action());
+
+		let Cancelled = Action.Cancellation().is_some_and(|Cancellation| Cancellation.IsCancelled());
+
+		// The timer service holds a delayed action instead of its lease
+		if let Some(Due) = Stamp.Due(Action.as_ref()).await.filter(|_| !Cancelled) {
+			Reservation.Ack();
+
+			self.Hold(Due, Production, Stamp, Box::new(Action)).await;
+
+			return Some(None);
+		}
+
+		if let Some(Identity) = self.Life.Backpressure.Throttled(Action.as_ref()).await {
+			Reservation.Ack();
+
+			self.Life
+				.Backpressure
+				.Hold(Identity, Production.clone(), Stamp, Box::new(Action))
+				.await;
+
+			return Some(None);
+		}
+
+		Some(Some((Stamp, Action, Some(Reservation))))
+	}
+
+	/// Hands an action whose `Delay` has not elapsed to the timer service of
+	/// `Life`, which puts it back into the production line once due.
+	///
+	/// # Returns
+	///
+	/// The action if it is due now or was cancelled, or `None` if the timer
+	/// service holds it.
+	async fn Defer(
+		&self,
+		Production:&Arc<Production::Struct>,
+		Stamp:Stamp,
+		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
+	) -> Option<Box<dyn crate::Trait::Sequence::Action::Trait>> {
+		if Action.Cancellation().is_some_and(|Cancellation| Cancellation.IsCancelled()) {
+			return Some(Action);
+		}
+
+		match Stamp.Due(Action.as_ref()).await {
+			Some(Due) => {
+				self.Hold(Due, Production, Stamp, Action).await;
+
+				None
+			},
+			None => Some(Action),
+		}
+	}
+
+	/// Runs the `BeforeDelay` hooks of a delayed action, then hands it to the
+	/// timer service; a failing hook is logged and the action still waits.
+	async fn Hold(
+		&self,
+		Due:Instant,
+		Production:&Arc<Production::Struct>,
+		Stamp:Stamp,
+		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
+	) {
+		if let Err(_Error) = Action.Stage(&self.Life, Phase::BeforeDelay).await {
+			warn!("A BeforeDelay hook of action {} failed: {}", Stamp.Sequence, _Error);
+		}
+
+		self.Life.Timer.Schedule(Due, Production.clone(), Stamp, Action).await;
+	}
+
+	/// Annotates an action for its dead-letter queue with why it ended up
+	/// there: its `Reason` (`failed`, `unknown` or `expired`), its last
+	/// `Error`, the `ErrorClass` of that error and the `Attempts` it took.
+	fn Letter(
+		Action:Arc<dyn crate::Trait::Sequence::Action::Trait>,
+		Reason:&str,
+		Error:&Error,
+		Attempts:u64,
+	) -> Box<dyn crate::Trait::Sequence::Action::Trait> {
+		Box::new(
+			Annotated::New(Action)
+				.WithMetadata("Reason", json!(Reason))
+				.WithMetadata("Error", json!(Error.to_string()))
+				.WithMetadata("ErrorClass", json!(Error.Class()))
+				.WithMetadata("Attempts", json!(Attempts)),
+		)
+	}
+
+	/// Hands an action to the dead-letter queue named in `Settings`, if any.
+	async fn DeadLetter(
+		&self,
+		Settings:&Settings,
+		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
+	) {
+		if let Some(DeadLetter) = &Settings.DeadLetter {
+			match self.Life.Karma.get(DeadLetter).map(|Production| Production.clone()) {
+				Some(Production) => {
+					if let Err(_Error) = Production.Enqueue(Action).await {
+						warn!("Dead-letter queue {} refused an action: {}", DeadLetter, _Error);
+					}
+				},
+				None => warn!("Dead-letter queue {} does not exist", DeadLetter),
 			}
 		}
 	}
@@ -55,64 +590,199 @@ impl Struct {
 	///
 	/// # Arguments
 	///
-	/// * `Action` - The action to be executed.
+	/// * `Action` - The action to be executed, shared across attempts.
+	/// * `Sequence` - The sequence number of the action, for events.
+	/// * `Watched` - The guard of the execution in the `Watchdog`, told about
+	///   every attempt.
 	///
 	/// # Returns
 	///
-	/// A `Result` indicating success or failure of the action execution.
+	/// A `Result` containing the value recorded by the action (`Null` if it
+	/// recorded none), or the error of the last attempt, together with the
+	/// history of all attempts and the annotations they wrote.
 	///
-	/// This method will retry the action execution up to a maximum number of
-	/// times (defined by `End` in `Life.Fate`) with exponential backoff and
-	/// jitter.
+	/// The retries follow `Fn::Retry`: up to `End` attempts (3 unless set in
+	/// `Life.Fate`) with exponential backoff and jitter drawn from
+	/// `Life.Randomness`. Every attempt runs inside its own `Invocation`
+	/// scope, which carries the history of the attempts before it. The
+	/// history keeps the first and last `History` attempts (10 unless set in
+	/// `Life.Fate`). An action type without a function in the plan is not
+	/// retried. An action declaring an `OutputTo` destination gets a fresh
+	/// `Sink` on it at every attempt, and its value describes the output
+	/// instead of containing it. The annotations of every attempt are
+	/// written into the action's metadata as it ends.
 	async fn Again(
 		&self,
-		Action:Box<dyn crate::Trait::Sequence::Action::Trait>,
-	) -> Result<(), crate::Enum::Sequence::Action::Error::Enum> {
+		Action:Arc<dyn crate::Trait::Sequence::Action::Trait>,
+		Sequence:u64,
+		Watched:&Guard,
+	) -> (Result<Value, Error>, History, Map<String, Value>) {
 		let End = self.Life.Fate.get_int("End").unwrap_or(3) as u32;
 
-		let mut Attempt = 0;
+		let Keep = self.Life.Fate.get_int("History").unwrap_or(10) as usize;
 
-		loop {
-			match self.Site.Receive(Action.Clone(), &self.Life).await {
-				Ok(_) => return Ok(()),
-				Err(e) => {
-					Attempt += 1;
+		// The history before the next attempt, updated before each backoff
+		let Before = Arc::new(std::sync::Mutex::new(History::New(Keep)));
 
-					if Attempt >= End {
-						return Err(e);
-					}
+		let Bus = self.Life.Bus.clone();
 
-					let Again = Duration::from_secs(
-						2u64.pow(Attempt) + rand::thread_rng().gen_range(0..1000),
-					);
+		let Retry = Retry::Struct::New()
+			.WithAttempts(End)
+			.WithBackoff(Backoff::Exponential {
+				Base:Duration::from_secs(1),
+				Jitter:Duration::from_secs(1),
+			})
+			.WithKeep(Keep)
+			.WithRandomness(self.Life.Randomness.clone())
+			.WithRuntime(self.Life.Runtime())
+			.WithClassify(|_Error| !matches!(_Error, Error::UnknownAction { .. }))
+			.WithCancellation(Action.Cancellation().unwrap_or_default())
+			.WithHook({
+				let Before = Before.clone();
+
+				move |History:&History, _Error:&Error| {
+					*Before.lock().unwrap_or_else(|Poison| Poison.into_inner()) = History.clone();
+
+					let (Attempt, Again) = History
+						.Last()
+						.map(|Last| {
+							(Last.Attempt, Duration::from_millis(Last.Backoff.unwrap_or(0)))
+						})
+						.unwrap_or_default();
 
 					warn!("Action failed, retrying in {:?}. Attempt {} of {}", Again, Attempt, End);
 
-					sleep(Again).await;
-				},
+					Bus.Emit(|| Event::Retried { Sequence, Attempt, Error:_Error.to_string() });
+				}
+			});
+
+		let Annotation = std::sync::Mutex::new(Map::new());
+
+		let mut Attempt = 0;
+
+		let (Result, History) = crate::Fn::Retry::Fn(&Retry, || {
+			Attempt += 1;
+
+			let History = Before.lock().unwrap_or_else(|Poison| Poison.into_inner()).clone();
+
+			let (Action, Attempt, Annotation) = (Action.clone(), Attempt, &Annotation);
+
+			async move {
+				let mut Invocation = Invocation::Struct::New(Sequence, Attempt)
+					.WithMetadata(Action.as_ref())
+					.await
+					.WithHistory(History)
+					.WithRuntime(self.Life.Runtime());
+
+				Watched.Attempt(Attempt, &Invocation);
+
+				if let Some((Destination, Sink)) =
+					Sink::Struct::Open(Action.as_ref(), &self.Life.Fate).await?
+				{
+					Invocation = Invocation.WithSink(Destination, Sink);
+				}
+
+				let Output = Invocation.clone();
+
+				let Result = Invocation.Scope(self.Site.Receive(Action.clone(), &self.Life)).await;
+
+				for (Key, Value) in Output.Annotations() {
+					Action.Annotate(&Key, Value.clone());
+
+					Annotation
+						.lock()
+						.unwrap_or_else(|Poison| Poison.into_inner())
+						.insert(Key, Value);
+				}
+
+				// A streamed output is described instead of returned
+				Result
+					.map(|()| Output.Streamed().or_else(|| Output.Output()).unwrap_or(Value::Null))
 			}
-		}
+		})
+		.await;
 
-		// unreachable!("Loop should have returned or errored")
+		(Result, History, Annotation.into_inner().unwrap_or_else(|Poison| Poison.into_inner()))
 	}
 
 	/// Signals the sequence to shut down by setting the `Time` signal to true.
+	///
+	/// Actions already executing run to completion; see `ShutdownNow`.
 	pub async fn Shutdown(&self) { self.Time.Set(true).await; }
+
+	/// Shuts the sequence down like `Shutdown`, cancelling the actions it is
+	/// executing instead of waiting for them, see `Action::Cancel`.
+	pub async fn ShutdownNow(&self) {
+		self.Abort.Cancel();
+
+		self.Shutdown().await;
+	}
 }
 
 pub use std::sync::Arc;
-use std::time::Duration;
+use std::{
+	collections::HashMap,
+	sync::atomic::{AtomicUsize, Ordering},
+	time::Duration,
+};
 
 use log::{error, warn};
-use rand::Rng;
+use metrics::counter;
+use serde_json::{json, Map, Value};
 pub use tokio::sync::Mutex;
-use tokio::time::sleep;
+use tokio::{pin, select, sync::Notify, time::Instant};
 
 pub mod Action;
+pub mod ActionRegistry;
+pub mod Admission;
+pub mod Attempt;
+pub mod Cancellation;
+pub mod DeadLetter;
+pub mod Glob;
+pub mod Graph;
+pub mod Handle;
+pub mod Invocation;
+pub mod License;
 pub mod Life;
+pub mod Memo;
+pub mod Overseer;
 pub mod Plan;
+pub mod Plugin;
 pub mod Production;
+pub mod Randomness;
+pub mod Reconcile;
+pub mod Replay;
+pub mod Retry;
+pub mod Secret;
 pub mod Signal;
+pub mod Sink;
+pub mod Timer;
 pub mod Vector;
+pub mod Watchdog;
 
-use crate::Trait::Sequence::Site::Trait as Site;
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{
+			Action::Error::Enum as Error,
+			Backoff::Enum as Backoff,
+			Phase::Enum as Phase,
+			Restart::Enum as Restart,
+		},
+	},
+	Struct::{
+		Runtime::Spawned,
+		Sequence::{
+			Action::Annotated::Struct as Annotated,
+			Attempt::History::Struct as History,
+			Production::{
+				Reservation::Struct as Reservation,
+				Settings::Struct as Settings,
+				Stamp::Struct as Stamp,
+			},
+			Watchdog::{Guard::Struct as Guard, InFlight::Struct as InFlight},
+		},
+		Stats::{Activity, Cost},
+	},
+	Trait::Sequence::Site::Trait as Site,
+};
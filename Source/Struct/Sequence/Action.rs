@@ -1,70 +1,359 @@
 /// Represents an action with metadata, content, license, and plan.
 ///
 /// This struct is generic over `T`, which must implement `Send` and `Sync`.
+/// `T` does not need to be `Clone`; see `Cloneable` for opting into copies.
 #[derive(Clone, Debug)]
 pub struct Struct<T:Send + Sync> {
 	pub Metadata:Vector,
 	pub Content:T,
 	pub License:Signal<bool>,
 	pub Plan:Arc<Formality>,
+
+	/// The copy function recorded by `Cloneable`, or `None` when the action
+	/// is non-cloneable.
+	Copy:Option<fn(&Self) -> Self>,
+
+	/// Serializes `Content` into arguments when there is no `Argument`
+	/// metadata, recorded by `New`.
+	Encode:Option<fn(&T) -> serde_json::Result<serde_json::Value>>,
+
+	/// The token cancelling the action, shared by its clones and the
+	/// follow-ups of its chain; see `Cancel`.
+	Cancel:Cancellation,
+
+	/// How far the function of the action got, from 0.0 to 1.0, if the
+	/// action exposes it; see `WithProgress`.
+	pub Progress:Option<Signal<f32>>,
+}
+
+/// The serialized form of an action, without its plan.
+#[derive(Serialize)]
+struct Outgoing<'a, T> {
+	Metadata:serde_json::Map<String, serde_json::Value>,
+	Content:&'a T,
+	License:bool,
+}
+
+/// The deserialized form of an action, before a plan is attached.
+#[derive(Deserialize)]
+struct Incoming<T> {
+	Metadata:serde_json::Map<String, serde_json::Value>,
+	Content:T,
+	License:bool,
 }
 
 impl<T:Send + Sync + Serialize> Serialize for Struct<T> {
-	fn serialize<S>(&self, _serializer:S) -> Result<S::Ok, S::Error>
+	/// Writes the metadata, content, and current license of the action. The
+	/// plan is left out; see `Attach`.
+	fn serialize<S>(&self, Serializer:S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer, {
-		unimplemented!()
+		let License = self.License.Peek().ok_or_else(|| {
+			serde::ser::Error::custom("License is being updated while serializing")
+		})?;
+
+		Outgoing { Metadata:self.Metadata.Snapshot(), Content:&self.Content, License }
+			.serialize(Serializer)
 	}
 }
 
-impl<'de, T:Send + Sync + Deserialize<'de>> Deserialize<'de> for Struct<T> {
-	fn deserialize<D>(_deserializer:D) -> Result<Self, D::Error>
+impl<'de, T:Send + Sync + Serialize + Deserialize<'de>> Deserialize<'de> for Struct<T> {
+	/// Reads an action written by `Serialize`. It comes back with an empty
+	/// plan, which `Attach` replaces before the action is executed.
+	fn deserialize<D>(Deserializer:D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>, {
-		unimplemented!()
+		let Incoming { Metadata:Entry, Content, License } = Incoming::deserialize(Deserializer)?;
+
+		let Metadata = Vector::New();
+
+		for (Key, Value) in Entry {
+			Metadata.Insert(Key, Value);
+		}
+
+		Ok(Struct {
+			Metadata,
+			Content,
+			License:Signal::New(License),
+			Plan:Arc::new(Formality::New()),
+			Copy:None,
+			Encode:Some(|Content| serde_json::to_value(Content)),
+			Cancel:Cancellation::New(),
+			Progress:None,
+		})
 	}
 }
 
-impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
-	/// Creates a new `Struct` instance.
+impl<T:Send + Sync> Struct<T> {
+	/// Adds metadata to the action.
 	///
 	/// # Arguments
 	///
-	/// * `Action` - The name of the action.
-	/// * `Content` - The content of the action.
-	/// * `Plan` - The plan for executing the action.
+	/// * `Key` - The key for the metadata.
+	/// * `Value` - The value for the metadata.
 	///
 	/// # Returns
 	///
-	/// A new `Struct` instance.
-	pub fn New(Action:&str, Content:T, Plan:Arc<Formality>) -> Self {
-		let mut Metadata = Vector::New();
+	/// The modified `Struct` instance.
+	pub fn WithMetadata(self, Key:&str, Value:serde_json::Value) -> Self {
+		self.Metadata.Insert(Key.to_string(), Value);
+
+		self
+	}
 
-		Metadata.Insert("Action".to_string(), serde_json::json!(Action));
+	/// Sets an option of the action, stored under its reserved key, e.g.
+	/// `__echo.Delay`, and also under its legacy flat name while the plan
+	/// keeps `LegacyKeys`.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The reserved key.
+	/// * `Value` - The value of the option.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOption(self, Key:Reserved, Value:serde_json::Value) -> Self {
+		if self.Plan.LegacyKeys() {
+			self.Metadata.Insert(Key.Name().to_string(), Value.clone());
+		}
 
-		Metadata.Insert("License".to_string(), serde_json::json!("valid"));
+		self.Metadata.Insert(Key.Key(), Value);
 
-		Struct { Metadata, Content, License:Signal::New(true), Plan }
+		self
 	}
 
-	/// Adds metadata to the action.
+	/// Routes the action to a Karma queue.
 	///
 	/// # Arguments
 	///
-	/// * `Key` - The key for the metadata.
-	/// * `Value` - The value for the metadata.
+	/// * `Queue` - The name of the queue.
 	///
 	/// # Returns
 	///
 	/// The modified `Struct` instance.
-	pub fn WithMetadata(mut self, Key:&str, Value:serde_json::Value) -> Self {
-		self.Metadata.Insert(Key.to_string(), Value);
+	pub fn WithQueue(self, Queue:&str) -> Self {
+		self.WithOption(Reserved::Queue, serde_json::Value::String(Queue.to_string()))
+	}
+
+	/// Delays the action before it runs.
+	///
+	/// The delay is written with its units, such as `"1s500ms"`, as a bare
+	/// `Delay` number is deprecated; see `Time::Legacy`.
+	///
+	/// # Arguments
+	///
+	/// * `Delay` - How long the action waits.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDelay(self, Delay:Duration) -> Self {
+		self.WithOption(Reserved::Delay, serde_json::json!(Span::from(Delay).to_string()))
+	}
+
+	/// Limits how long each attempt of the action may run.
+	///
+	/// # Arguments
+	///
+	/// * `Timeout` - The limit of one attempt.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithTimeout(self, Timeout:Duration) -> Self {
+		self.WithOption(Reserved::Timeout, serde_json::json!(Span::from(Timeout)))
+	}
+
+	/// Sets the priority of the action in its production line.
+	///
+	/// # Arguments
+	///
+	/// * `Priority` - The priority, higher first.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithPriority(self, Priority:i64) -> Self {
+		self.WithOption(Reserved::Priority, serde_json::Value::from(Priority))
+	}
+
+	/// Keys the action, so that once it completed, another action under the
+	/// same key is not executed again; see `Life::Idempotent`.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The idempotency key.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithIdempotencyKey(self, Key:&str) -> Self {
+		self.WithOption(Reserved::IdempotencyKey, serde_json::Value::String(Key.to_string()))
+	}
+
+	/// Exposes the progress of the action's function.
+	///
+	/// A function added with `Formality::AddWithProgress` reports into the
+	/// signal as it runs; callers keep a clone of it, taken before the
+	/// action is enqueued, to poll it. The signal reads 1.0 once the
+	/// function succeeded, whether or not it reported that itself.
+	///
+	/// # Arguments
+	///
+	/// * `Progress` - The signal, usually starting at 0.0.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithProgress(mut self, Progress:Signal<f32>) -> Self {
+		self.Progress = Some(Progress);
+
+		self
+	}
+
+	/// Attaches the plan the action executes with, e.g. after it was
+	/// deserialized.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan for executing the action.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Attach(mut self, Plan:Arc<Formality>) -> Self {
+		self.Plan = Plan;
 
 		self
 	}
 
+	/// Reads a metadata entry, falling back to the default the plan holds
+	/// for the action's type at the time of reading.
+	///
+	/// The name of a reserved key, flat or prefixed, reads the option, see
+	/// `ReadOption`, so the flat metadata of a user named alike is not taken
+	/// for it.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key to look up.
+	///
+	/// # Returns
+	///
+	/// The value the action sets under `Key`, else the plan's default, if
+	/// any.
+	pub async fn Lookup(&self, Key:&str) -> Option<serde_json::Value> {
+		let Value = match Reserved::Parse(Key) {
+			Some(Key) => self.ReadOption(Key).await,
+			None => self.Metadata.Get(Key).await,
+		};
+
+		if Value.is_some() {
+			return Value;
+		}
+
+		let Action = self.ReadOption(Reserved::Action).await?;
+
+		let Key = Reserved::Parse(Key).map_or(Key, |Reserved| Reserved.Name());
+
+		self.Plan.Default(Action.as_str()?, Key)
+	}
+
+	/// Reads an option of the action from its reserved key, or, while the
+	/// plan keeps `LegacyKeys`, from its legacy flat name with a deprecation
+	/// warning.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The reserved key.
+	///
+	/// # Returns
+	///
+	/// The value the action sets, without the plan's default.
+	pub async fn ReadOption(&self, Key:Reserved) -> Option<serde_json::Value> {
+		if let Some(Value) = self.Metadata.Get(&Key.Key()).await {
+			return Some(Value);
+		}
+
+		if !self.Plan.LegacyKeys() {
+			return None;
+		}
+
+		let Value = self.Metadata.Get(Key.Name()).await?;
+
+		Key.Deprecated();
+
+		Some(Value)
+	}
+
+	/// Describes the action to admission policies: its function, its
+	/// arguments as it would be called with them, and all its metadata.
+	///
+	/// # Returns
+	///
+	/// The view of the action; arguments that cannot be described are left
+	/// out, the action failing once it runs.
+	pub async fn View(&self) -> View {
+		let Action = match self.ReadOption(Reserved::Action).await {
+			Some(serde_json::Value::String(Action)) => Action,
+			_ => String::new(),
+		};
+
+		let Argument = match self.ReadOption(Reserved::Argument).await {
+			Some(serde_json::Value::Array(Argument)) => Argument,
+			Some(_) => Vec::new(),
+			None => self.Content().unwrap_or_default(),
+		};
+
+		View::New(Action, Argument, self.Metadata.Snapshot())
+	}
+
+	/// Cancels the action.
+	///
+	/// An action cancelled while it waits in its queue or out its `Delay`
+	/// is not executed once due; a running one fails with
+	/// `Error::Cancellation` before its hooks, or as soon as its function
+	/// awaits, without executing its `NextAction`, and is not retried. A
+	/// `Blocking` function is left running in the background.
+	pub fn Cancel(&self) { self.Cancel.Cancel(); }
+
+	/// Returns the token cancelling the action, to cancel it once it was
+	/// submitted.
+	pub fn Cancellation(&self) -> Cancellation { self.Cancel.clone() }
+
+	/// Creates an independent copy of the action, which is not cancelled
+	/// along with it.
+	///
+	/// # Returns
+	///
+	/// A copy of the action if it was marked with `Cloneable`, or
+	/// `Error::NonCloneable` otherwise.
+	pub fn Duplicate(&self) -> Result<Self, Error> {
+		match self.Copy {
+			Some(Function) => Ok(Struct { Cancel:Cancellation::New(), ..Function(self) }),
+			None => Err(Error::NonCloneable(
+				"Action was not marked Cloneable when it was created".to_string(),
+			)),
+		}
+	}
+
 	/// Executes the action.
 	///
+	/// Once it succeeded, its `OnSuccess` and then its `NextAction`
+	/// follow-ups run; once it failed, its `OnFailure` ones do, after which
+	/// the failure is returned unless its `AbsorbFailure` metadata is `true`.
+	/// A cancelled action runs neither.
+	///
+	/// An action whose `IdempotencyKey` option names a key `Life::Idempotent`
+	/// remembers is not executed, nor are its follow-ups; its result is the
+	/// remembered one. Once it and its follow-ups succeeded, its key is
+	/// remembered with its value.
+	///
+	/// A `Delay` is honoured only when the action is enqueued, by the
+	/// sequence holding it until it is due. `Execute` checks it is well-formed
+	/// but does not wait, so an action executed directly runs at once.
+	///
 	/// # Arguments
 	///
 	/// * `Context` - The context in which to execute the action.
@@ -73,9 +362,100 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 	///
 	/// A `Result` indicating success or failure.
 	pub async fn Execute(&self, Context:&Life) -> Result<(), Error> {
+		let Key = self.ReadOption(Reserved::IdempotencyKey).await.map(|Key| {
+			match Key {
+				serde_json::Value::String(Key) => Key,
+				Key => Key.to_string(),
+			}
+		});
+
+		if let Some((Key, Value)) =
+			Key.as_deref().and_then(|Key| Some((Key, Context.Idempotent(Key)?)))
+		{
+			info!("Skipping action with idempotency key {}, which completed already", Key);
+
+			counter!("echo_idempotent_skipped_total").increment(1);
+
+			self.Keep(Context, Value).await?;
+
+			return Ok(());
+		}
+
+		let Maximum = Life::Chain(&Context.Fate).Depth;
+
+		let Outcome = self.Traced(Context).await;
+
+		let Output = Key.as_ref().and_then(|_| Outcome.as_ref().ok().cloned());
+
+		// The follow-ups still to run, the next one on top
+		let mut Stack = Vec::new();
+
+		self.Branch(&mut Stack, Maximum, 0, serde_json::Map::new(), Outcome).await?;
+
+		self.Next(Context, Maximum, Stack).await?;
+
+		if let (Some(Key), Some(Output)) = (Key, Output) {
+			Context.Remember(&Key, Output);
+		}
+
+		Ok(())
+	}
+
+	/// Executes the action without its follow-ups, as `Step`, publishing
+	/// `Event::Node` as it starts and ends when it is the node of a graph.
+	async fn Traced(&self, Context:&Life) -> Result<serde_json::Value, Error> {
+		let Some((Graph, Node)) = self.Node().await else {
+			return self.Step(Context).await;
+		};
+
+		Context.Bus.Emit(|| {
+			Event::Node {
+				Graph:Graph.clone(),
+				Node:Node.clone(),
+				State:State::Running,
+				DurationMs:None,
+			}
+		});
+
+		let Start = Instant::now();
+
+		let Outcome = self.Step(Context).await;
+
+		let State = if Outcome.is_ok() { State::Done } else { State::Failed };
+
+		let DurationMs = Some(Start.elapsed().as_millis() as u64);
+
+		Context.Bus.Emit(|| Event::Node { Graph, Node, State, DurationMs });
+
+		Outcome
+	}
+
+	/// Reads the graph and node the action is reported as, from its `Graph`
+	/// and `Node` options; a graph without a node is the root `0`.
+	///
+	/// # Returns
+	///
+	/// The names of the graph and node, or `None` when the action names no
+	/// graph.
+	async fn Node(&self) -> Option<(String, String)> {
+		let Graph = self.ReadOption(Reserved::Graph).await?.as_str()?.to_string();
+
+		let Node = match self.ReadOption(Reserved::Node).await {
+			Some(serde_json::Value::String(Node)) => Node,
+			_ => "0".to_string(),
+		};
+
+		Some((Graph, Node))
+	}
+
+	/// Executes the action without its follow-ups.
+	///
+	/// # Returns
+	///
+	/// The value of the action.
+	async fn Step(&self, Context:&Life) -> Result<serde_json::Value, Error> {
 		let Action = self
-			.Metadata
-			.Get("Action")
+			.ReadOption(Reserved::Action)
 			.await
 			.ok_or_else(|| Error::Execution("Action not found".to_string()))?
 			.as_str()
@@ -84,21 +464,61 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 
 		info!("Executing action: {}", Action);
 
-		self.License().await?;
+		self.License(Context).await?;
 
 		self.Delay().await?;
 
-		self.Hooks(Context).await?;
+		// Fail before the hooks run if nothing can execute the action
+		if !self.Plan.Has(&Action) {
+			return Err(self.Plan.Unknown(&Action));
+		}
+
+		self.Continue(&Action)?;
+
+		let Output = match self.Hooked(&Action, Context).await {
+			Ok(Output) => Output,
+			Err(_Error) => {
+				if let Err(_Hook) = self.Hooks(Context, Phase::Failed).await {
+					warn!("A Failed hook of action {} failed: {}", Action, _Hook);
+				}
+
+				return Err(_Error);
+			},
+		};
+
+		self.Continue(&Action)?;
+
+		Ok(Output)
+	}
+
+	/// Executes the `BeforeFunction` hooks, the function and, once it
+	/// succeeded, the `AfterFunction` hooks.
+	async fn Hooked(&self, Action:&str, Context:&Life) -> Result<serde_json::Value, Error> {
+		self.Hooks(Context, Phase::BeforeFunction).await?;
 
-		self.Function(&Action).await?;
+		let Output = self.Function(Action, Context).await?;
 
-		self.Next(Context).await?;
+		self.Hooks(Context, Phase::AfterFunction).await?;
+
+		Ok(Output)
+	}
+
+	/// Fails with `Error::Cancellation` once the action was cancelled.
+	fn Continue(&self, Action:&str) -> Result<(), Error> {
+		if self.Cancel.IsCancelled() {
+			return Err(Error::Cancellation(format!("Action {} was cancelled", Action)));
+		}
 
 		Ok(())
 	}
 
-	/// Checks if the action is licensed.
-	async fn License(&self) -> Result<(), Error> {
+	/// Checks if the action is licensed, with the `LicenseValidator` of the
+	/// context when it has one and else with the `License` signal.
+	async fn License(&self, Context:&Life) -> Result<(), Error> {
+		if let Some(Validator) = &Context.License {
+			return Validator.Validate(&self.Metadata).await;
+		}
+
 		if !self.License.Get().await {
 			return Err(Error::License("Invalid action license".to_string()));
 		}
@@ -106,72 +526,638 @@ impl<T:Send + Sync + Serialize + for<'de> Deserialize<'de>> Struct<T> {
 		Ok(())
 	}
 
-	/// Applies any delay specified in the metadata.
+	/// Checks that the `Delay` of the action, if any, is a `Time::Duration`.
+	///
+	/// The sequence holding the action reads its delay leniently, so a
+	/// malformed one fails the action here rather than letting it run at once.
+	/// A well-formed one is not waited for here; see `Execute`.
 	async fn Delay(&self) -> Result<(), Error> {
-		if let Some(Delay) = self.Metadata.Get("Delay").await {
-			tokio::time::sleep(tokio::time::Duration::from_secs(Delay.as_u64().unwrap_or(0))).await;
+		let Some(Delay) = self.Lookup("Delay").await else {
+			return Ok(());
+		};
+
+		Span::deserialize(&Delay).map(|_| ()).map_err(|_Error| {
+			Error::Execution(format!("Invalid Delay metadata {}: {}", Delay, _Error))
+		})
+	}
+
+	/// Runs the hooks of a stage the action reaches outside `Execute`,
+	/// `Accepted` or `BeforeDelay`; see `Phase`.
+	///
+	/// # Arguments
+	///
+	/// * `Context` - The context whose hooks are run.
+	/// * `Phase` - The stage reached.
+	///
+	/// # Returns
+	///
+	/// The error of the first hook failing, if any.
+	pub async fn Stage(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		self.Hooks(Context, Phase).await
+	}
+
+	/// Executes the hooks the metadata specifies for the stage, by name or
+	/// by pattern, in the order `Life::Expand` resolves them.
+	///
+	/// A reference matching no hook is handled as the `MissingHooks`
+	/// metadata says, `ignore`, `warn` or `fail`, or else as
+	/// `Life::MissingHook` does.
+	async fn Hooks(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		if let Some(Hooks) = self.Lookup("Hooks").await {
+			let Reference = Phase.References(&Hooks)?;
+
+			if Reference.is_empty() {
+				return Ok(());
+			}
+
+			let Missing = match self.Lookup("MissingHooks").await {
+				Some(Name) => {
+					Name.as_str().and_then(MissingHook::Parse).ok_or_else(|| {
+						Error::Execution(format!("Invalid MissingHooks metadata: {}", Name))
+					})?
+				},
+				None => Context.MissingHook(),
+			};
+
+			for Hook in Context.ExpandWith(&Reference, Missing)? {
+				Hook.Call()?;
+			}
 		}
 
 		Ok(())
 	}
 
-	/// Executes any hooks specified in the metadata.
-	async fn Hooks(&self, Context:&Life) -> Result<(), Error> {
-		if let Some(Hooks) = self.Metadata.Get("Hooks").await {
-			for Hook in Hooks.as_array().unwrap_or(&Vec::new()) {
-				if let Some(HookFn) = Context.Span.get(Hook.as_str().unwrap_or("")) {
-					HookFn()?;
+	/// Executes the function associated with the action.
+	///
+	/// A function whose signature is `Blocking` is driven on the blocking
+	/// thread pool, inside the invocation of the current attempt. Dropping
+	/// the attempt, e.g. once its deadline passes, does not stop it; a long
+	/// loop should call `Invocation::Checkpoint` to end early.
+	///
+	/// The function runs for at most the `Timeout` of the action, a
+	/// `Time::Duration`, and fails with `Error::Timeout` past it, or with
+	/// `Error::Cancellation` once the action is cancelled; a blocking
+	/// function is left running in the background either way.
+	///
+	/// The arguments are checked against the `InputTypes` of the signature
+	/// before the function runs, and its value against the `OutputType`
+	/// after; a mismatch fails with `Error::Execution`.
+	///
+	/// A function whose signature is `Cacheable` does not run while the
+	/// `Life` memo holds a result for the same arguments within its
+	/// `HardTtl`; a result past its `SoftTtl` is served while one execution
+	/// in the background refreshes it.
+	///
+	/// The function of an action exposing its `Progress` runs in an
+	/// invocation carrying the signal, and the signal is set to 1.0 once it
+	/// succeeded.
+	async fn Function(&self, Action:&str, Context:&Life) -> Result<serde_json::Value, Error> {
+		let Function = self.Plan.Get(Action).ok_or_else(|| self.Plan.Unknown(Action))?;
+
+		let Argument = self.Argument(Context).await?;
+
+		self.Plan.CheckInput(Action, &Argument)?;
+
+		let Limit = self.Lookup("Timeout").await.and_then(|Timeout| {
+			Span::deserialize(&Timeout)
+				.map_err(|_Error| {
+					warn!("Ignoring invalid Timeout metadata {}: {}", Timeout, _Error)
+				})
+				.ok()
+		});
+
+		let Blocking = self.Plan.Blocking(Action);
+
+		let Key = match self.Plan.Cacheable(Action) {
+			Some(Cacheable) => {
+				let Key = Memo::Key(
+					Action,
+					&Argument.iter().cloned().map(Arg::Value).collect::<Vec<_>>(),
+				);
+
+				let Now = Context.Runtime().Now();
+
+				if let Some((Value, Stale)) = Context.Memo.Get(&Key, &Cacheable, Now) {
+					let State = if Stale { "stale" } else { "fresh" };
+
+					counter!("echo_memo_served_total", "state" => State).increment(1);
+
+					if Stale && Context.Memo.Claim(&Key) {
+						let Running = Run(Context, Action, Blocking, Function, Argument, Limit);
+
+						Refresh(Context, Action, Key, Running);
+					}
+
+					return self.Keep(Context, Value).await;
 				}
+
+				Some(Key)
+			},
+			None => None,
+		};
+
+		let Running = Run(Context, Action, Blocking, Function, Argument, Limit);
+
+		let Running = async {
+			match &self.Progress {
+				Some(Progress) => {
+					Invocation::Current()
+						.unwrap_or_else(|| Invocation::New(0, 1).WithRuntime(Context.Runtime()))
+						.WithProgress(Progress.clone())
+						.Scope(Running)
+						.await
+				},
+				None => Running.await,
 			}
+		};
+
+		let Value = select! {
+			Value = Running => Value?,
+			_ = self.Cancel.Cancelled() => {
+				return Err(Error::Cancellation(format!("Action {} was cancelled", Action)));
+			},
+		};
+
+		self.Plan.CheckOutput(Action, &Value)?;
+
+		if let Some(Progress) = &self.Progress {
+			Progress.Set(1.0).await;
 		}
 
-		Ok(())
+		if let Some(Key) = Key {
+			Context.Memo.Put(Key, Value.clone(), Context.Runtime().Now());
+		}
+
+		self.Keep(Context, Value).await
 	}
 
-	/// Executes the function associated with the action.
-	async fn Function(&self, Action:&str) -> Result<(), Error> {
-		if let Some(Function) = self.Plan.Remove(Action) {
-			self.Result(Function.call((self.Argument().await?,)).await?).await?;
-		} else {
-			return Err(Error::Execution(format!("No function found for action type: {}", Action)));
+	/// Records the value of the function as the output of the invocation and
+	/// the result of the action.
+	async fn Keep(
+		&self,
+		Context:&Life,
+		Value:serde_json::Value,
+	) -> Result<serde_json::Value, Error> {
+		Invocation::Record(Value.clone());
+
+		self.Result(Context, Value.clone()).await?;
+
+		Ok(Value)
+	}
+
+	/// Executes the follow-ups on the stack and theirs, in the order a
+	/// depth-first walk of the chain visits them, failing at the first that
+	/// fails unabsorbed or at a follow-up deeper than the `[chain]` depth
+	/// limit.
+	///
+	/// `OnSuccess`, `NextAction` and `OnFailure` are each an object naming
+	/// its `Action` next to the rest of its metadata, or an array of them run
+	/// in order. A follow-up of a success receives the value of the action
+	/// before it as its `Previous` metadata, which a templated argument pipes
+	/// in with `{{metadata:Previous}}`, or a field of it with e.g.
+	/// `{{metadata:Previous/content}}`; one of a failure receives the error
+	/// message.
+	///
+	/// The chain is walked with a stack instead of by recursion, so a long
+	/// one costs no stack depth, and each follow-up is taken out of it as it
+	/// runs rather than copied with the rest of the chain: its own follow-ups
+	/// are not among its metadata.
+	async fn Next(
+		&self,
+		Context:&Life,
+		Maximum:Option<usize>,
+		mut Stack:Vec<Pending>,
+	) -> Result<(), Error> {
+		while let Some(Pending) = Stack.pop() {
+			let (Next, Depth, Previous) = match Pending {
+				Pending::Run { Next, Depth, Previous } => (Next, Depth, Previous),
+				Pending::Fail(_Error) => return Err(_Error),
+			};
+
+			let serde_json::Value::Object(mut Metadata) = Next else {
+				return Err(Error::Execution("NextAction is not an object".to_string()));
+			};
+
+			// Reserved keys are read under their prefix, then in their flat form
+			let Name = [Reserved::Action.Key(), Reserved::Action.Name().to_string()]
+				.iter()
+				.find_map(|Key| Metadata.get(Key)?.as_str())
+				.ok_or_else(|| Error::Execution("NextAction names no Action".to_string()))?
+				.to_string();
+
+			let Taken = BRANCH
+				.iter()
+				.filter_map(|Key| {
+					let Prefixed = Metadata.remove(&format!("{}{}", PREFIX, Key));
+
+					let Flat = Metadata.remove(*Key);
+
+					Some((Key.to_string(), Prefixed.or(Flat)?))
+				})
+				.collect();
+
+			let Next = Metadata.into_iter().fold(
+				Struct::New(&Name, serde_json::Value::Null, self.Plan.clone()),
+				|Next, (Key, Value)| {
+					match Reserved::Parse(&Key) {
+						Some(Key) => Next.WithOption(Key, Value),
+						None => Next.WithMetadata(&Key, Value),
+					}
+				},
+			);
+
+			let Next = Struct { Cancel:self.Cancel.clone(), ..Next.WithMetadata("Previous", Previous) };
+
+			let Outcome = Next.Traced(Context).await;
+
+			Next.Branch(&mut Stack, Maximum, Depth, Taken, Outcome).await?;
 		}
 
 		Ok(())
 	}
 
-	/// Executes the next action, if specified.
-	async fn Next(&self, Context:&Life) -> Result<(), Error> {
-		if let Some(Next) = self.Metadata.Get("NextAction").await {
-			let Next:Struct<T> = serde_json::from_value(Next.clone()).map_err(|_Error| {
-				Error::Execution(format!("Failed to parse NextAction: {}", _Error))
-			})?;
+	/// Pushes the follow-ups the outcome of the action leads to onto the
+	/// stack of `Next`: `OnSuccess` on top of `NextAction` after a success,
+	/// `OnFailure` after a failure, on top of the failure itself unless it is
+	/// absorbed.
+	///
+	/// A follow-up is read from `Taken`, the follow-ups taken out of the
+	/// metadata of the action, and else looked up, so that an action without
+	/// one of its own may have the plan's default. The follow-ups of the node
+	/// of a graph become nodes of it, see `Tag`.
+	///
+	/// # Returns
+	///
+	/// The failure of the action when it has no `OnFailure` or was
+	/// cancelled, or `Error::ChainLimit` when its follow-ups would be deeper
+	/// than `Maximum`.
+	async fn Branch(
+		&self,
+		Stack:&mut Vec<Pending>,
+		Maximum:Option<usize>,
+		Depth:usize,
+		mut Taken:serde_json::Map<String, serde_json::Value>,
+		Outcome:Result<serde_json::Value, Error>,
+	) -> Result<(), Error> {
+		let Node = self.Node().await;
+
+		match Outcome {
+			Ok(Output) => {
+				if let Some(Next) = self.Follow(&mut Taken, "NextAction").await {
+					let Next = Tag(Next, Node.as_ref(), "NextAction");
+
+					Push(Stack, Maximum, Depth, Next, Output.clone())?;
+				}
+
+				if let Some(Success) = self.Follow(&mut Taken, "OnSuccess").await {
+					let Success = Tag(Success, Node.as_ref(), "OnSuccess");
+
+					Push(Stack, Maximum, Depth, Success, Output)?;
+				}
+			},
+			Err(Error::Cancellation(_Error)) => return Err(Error::Cancellation(_Error)),
+			Err(_Error) => {
+				let Some(Failure) = self.Follow(&mut Taken, "OnFailure").await else {
+					return Err(_Error);
+				};
+
+				let Previous = serde_json::Value::String(_Error.to_string());
+
+				if self.Lookup("AbsorbFailure").await != Some(serde_json::Value::Bool(true)) {
+					Stack.push(Pending::Fail(_Error));
+				}
+
+				let Failure = Tag(Failure, Node.as_ref(), "OnFailure");
 
-			Next.Execute(Context).await?;
+				Push(Stack, Maximum, Depth, Failure, Previous)?;
+			},
 		}
 
 		Ok(())
 	}
 
+	/// Reads the follow-ups under `Key`, from `Taken` or else the metadata.
+	async fn Follow(
+		&self,
+		Taken:&mut serde_json::Map<String, serde_json::Value>,
+		Key:&str,
+	) -> Option<serde_json::Value> {
+		match Taken.remove(Key) {
+			Some(Value) => Some(Value),
+			None => self.Lookup(Key).await,
+		}
+	}
+
 	/// Retrieves the arguments for the action.
-	async fn Argument(&self) -> Result<Vec<serde_json::Value>, Error> { Ok(vec![]) }
+	///
+	/// The elements of the `Argument` metadata are the arguments when it is
+	/// set. Otherwise `Content` is serialized: an array is spread into the
+	/// arguments, `null` gives none and any other value is the only one, so
+	/// `New("Read", json!(["input.txt"]), Plan)` reads `input.txt`.
+	///
+	/// With `Template` metadata set to `true`, placeholders in the arguments
+	/// are resolved first, see `Fn::Template`.
+	async fn Argument(&self, Context:&Life) -> Result<Vec<Arg>, Error> {
+		let Argument = match self.ReadOption(Reserved::Argument).await {
+			Some(serde_json::Value::Array(Argument)) => Argument,
+			Some(_) => return Err(Error::Execution("Argument is not an array".to_string())),
+			None => self.Content()?,
+		};
+
+		if self.ReadOption(Reserved::Template).await != Some(serde_json::Value::Bool(true)) {
+			return Ok(Argument.into_iter().map(Arg::Json).collect());
+		}
+
+		let mut Resolved = Vec::with_capacity(Argument.len());
+
+		for Argument in Argument {
+			Resolved.push(Arg::Json(
+				crate::Fn::Template::Fn(Context, &self.Metadata, Argument).await?,
+			));
+		}
+
+		Ok(Resolved)
+	}
+
+	/// Serializes `Content` into arguments, for an action without `Argument`
+	/// metadata.
+	fn Content(&self) -> Result<Vec<serde_json::Value>, Error> {
+		let Some(Encode) = self.Encode else {
+			return Ok(vec![]);
+		};
+
+		let Content = Encode(&self.Content).map_err(|_Error| {
+			Error::Execution(format!("Content cannot be passed as arguments: {}", _Error))
+		})?;
+
+		Ok(match Content {
+			serde_json::Value::Null => vec![],
+			serde_json::Value::Array(Argument) => Argument,
+			Content => vec![Content],
+		})
+	}
+
+	/// Keeps the value the function returned, as the action's `Result`
+	/// metadata and, for an action with an `Id`, in the `Life` cache under
+	/// `Result:<Id>`, where `{{cache:Result:<Id>}}` reads it back.
+	async fn Result(&self, Context:&Life, Output:serde_json::Value) -> Result<(), Error> {
+		if let Some(Id) = self.Metadata.Get("Id").await {
+			let Id = match Id {
+				serde_json::Value::String(Id) => Id,
+				Id => Id.to_string(),
+			};
+
+			Context.Cache.Put(format!("Result:{}", Id), Output.clone());
+		}
+
+		self.Metadata.Insert("Result".to_string(), Output);
+
+		Ok(())
+	}
+}
+
+impl<T:Send + Sync + Serialize> Struct<T> {
+	/// Creates a new `Struct` instance.
+	///
+	/// Without `Argument` metadata, the function of the action is called
+	/// with `Content` as its arguments, see `Argument`.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The name of the action.
+	/// * `Content` - The content of the action.
+	/// * `Plan` - The plan for executing the action.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Action:&str, Content:T, Plan:Arc<Formality>) -> Self {
+		Struct {
+			Metadata:Vector::New(),
+			Content,
+			License:Signal::New(true),
+			Plan,
+			Copy:None,
+			Encode:Some(|Content| serde_json::to_value(Content)),
+			Cancel:Cancellation::New(),
+			Progress:None,
+		}
+		.WithOption(Reserved::Action, serde_json::json!(Action))
+		.WithOption(Reserved::License, serde_json::json!("valid"))
+	}
+}
+
+impl<T:Send + Sync + Clone> Struct<T> {
+	/// Marks the action as cloneable, so that `Duplicate` can copy it.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Cloneable(mut self) -> Self {
+		self.Copy = Some(Self::clone);
+
+		self
+	}
+}
+
+/// The metadata keys naming the follow-ups of an action.
+pub(crate) const BRANCH:[&str; 3] = ["OnSuccess", "NextAction", "OnFailure"];
+
+/// An entry on the stack of a chain being walked.
+enum Pending {
+	/// A follow-up still to run, with its depth and the value it receives as
+	/// `Previous`.
+	Run { Next:serde_json::Value, Depth:usize, Previous:serde_json::Value },
+
+	/// The failure an `OnFailure` follow-up compensates, returned once it
+	/// ran.
+	Fail(Error),
+}
+
+/// Pushes follow-ups onto the stack of `Next`, the first one on top, unless
+/// they would be deeper than `Maximum`.
+fn Push(
+	Stack:&mut Vec<Pending>,
+	Maximum:Option<usize>,
+	Depth:usize,
+	Next:serde_json::Value,
+	Previous:serde_json::Value,
+) -> Result<(), Error> {
+	if let Some(Maximum) = Maximum.filter(|Maximum| Depth >= *Maximum) {
+		return Err(Error::ChainLimit { Limit:"depth".to_string(), Maximum });
+	}
+
+	let Follow = match Next {
+		serde_json::Value::Array(Follow) => Follow,
+		Next => vec![Next],
+	};
 
-	/// Processes the result of the action.
-	async fn Result(&self, _Result:serde_json::Value) -> Result<(), Error> { Ok(()) }
+	Stack.extend(Follow.into_iter().rev().map(|Next| {
+		Pending::Run { Next, Depth:Depth + 1, Previous:Previous.clone() }
+	}));
+
+	Ok(())
+}
+
+/// Makes the follow-ups under `Key` of the node of a graph nodes of the same
+/// graph, each named by its path from the node unless it names its own, see
+/// `Graph::Path`.
+///
+/// # Arguments
+///
+/// * `Next` - The follow-ups, one or an array of them.
+/// * `Node` - The graph and node of the action they follow, if any.
+/// * `Key` - The metadata key they were read from.
+fn Tag(
+	Next:serde_json::Value,
+	Node:Option<&(String, String)>,
+	Key:&str,
+) -> serde_json::Value {
+	let Some((Graph, Parent)) = Node else {
+		return Next;
+	};
+
+	let Name = |Index:usize, mut Next:serde_json::Value| {
+		if let serde_json::Value::Object(Metadata) = &mut Next {
+			Metadata.entry("Graph").or_insert_with(|| serde_json::Value::String(Graph.clone()));
+
+			Metadata
+				.entry("Node")
+				.or_insert_with(|| serde_json::Value::String(Path(Parent, Key, Index)));
+		}
+
+		Next
+	};
+
+	match Next {
+		serde_json::Value::Array(Follow) => {
+			serde_json::Value::Array(
+				Follow.into_iter().enumerate().map(|(Index, Next)| Name(Index, Next)).collect(),
+			)
+		},
+		Next => Name(0, Next),
+	}
 }
 
-use std::{fmt::Debug, sync::Arc};
+/// Calls a function, spawned as a blocking task of the runtime of `Context`
+/// if it blocks, for at most `Limit`.
+fn Run(
+	Context:&Life,
+	Action:&str,
+	Blocking:bool,
+	Function:Function,
+	Argument:Vec<Arg>,
+	Limit:Option<Span>,
+) -> impl Future<Output = Result<serde_json::Value, Error>> + Send + 'static {
+	let (Action, Runtime) = (Action.to_string(), Context.Runtime());
 
-use log::info;
+	async move {
+		let Running = async {
+			if Blocking {
+				let (Sender, Receiver) = oneshot::channel();
+
+				let Current = Invocation::Current();
+
+				let Task = Runtime.SpawnBlocking(Box::pin(async move {
+					let Output = match Current {
+						Some(Current) => Current.Scope(Function(Argument)).await,
+						None => Function(Argument).await,
+					};
+
+					let _ = Sender.send(Output);
+				}));
+
+				match Receiver.await {
+					Ok(Output) => Output,
+					Err(_) => {
+						let Exit = Task.await.err().unwrap_or(Exit::Cancelled);
+
+						Err(Error::Execution(format!(
+							"Blocking function {} failed: {}",
+							Action, Exit
+						)))
+					},
+				}
+			} else {
+				Function(Argument).await
+			}
+		};
+
+		match Limit {
+			Some(Limit) => {
+				Runtime.Timeout(Limit.Get(), Running).await.ok_or_else(|| {
+					Error::Timeout(format!("{} ran past its timeout of {}", Action, Limit))
+				})?
+			},
+			None => Running.await,
+		}
+	}
+}
+
+/// Refreshes the stale result of a cacheable action in the background,
+/// releasing its key in the memo once done.
+///
+/// A failed refresh keeps the stale result, publishing a `RefreshFailed`
+/// event and counting it in `echo_memo_refresh_failures_total`.
+fn Refresh(
+	Context:&Life,
+	Action:&str,
+	Key:String,
+	Running:impl Future<Output = Result<serde_json::Value, Error>> + Send + 'static,
+) {
+	let (Memo, Bus, Action, Runtime) =
+		(Context.Memo.clone(), Context.Bus.clone(), Action.to_string(), Context.Runtime());
+
+	Runtime.clone().Spawn(Box::pin(async move {
+		match Running.await {
+			Ok(Value) => Memo.Put(Key.clone(), Value, Runtime.Now()),
+			Err(_Error) => {
+				warn!("Refreshing the result of {} failed: {}", Action, _Error);
+
+				counter!("echo_memo_refresh_failures_total").increment(1);
+
+				Bus.Emit(|| Event::RefreshFailed { Action, Error:_Error.to_string() });
+			},
+		}
+
+		Memo.Release(&Key);
+	}));
+}
+
+use std::{fmt::Debug, future::Future, sync::Arc, time::Duration};
+
+use log::{info, warn};
+use metrics::counter;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tokio::{select, sync::oneshot, time::Instant};
 
 use crate::{
-	Enum::Sequence::Action::Error::Enum as Error,
+	Enum::{
+		Event::Enum as Event,
+		Runtime::Exit::Enum as Exit,
+		Sequence::{
+			Action::{Arg::Enum as Arg, Error::Enum as Error},
+			Graph::State::Enum as State,
+			MissingHook::Enum as MissingHook,
+			Phase::Enum as Phase,
+			Reserved::{Enum as Reserved, PREFIX},
+		},
+	},
 	Struct::Sequence::{
+		Admission::View::Struct as View,
+		Cancellation::Struct as Cancellation,
+		Graph::Path,
+		Invocation::Struct as Invocation,
 		Life::Struct as Life,
+		Memo::Struct as Memo,
 		Plan::Formality::Struct as Formality,
 		Signal::Struct as Signal,
 		Vector::Struct as Vector,
 	},
+	Time::Duration::Struct as Span,
+	Type::Sequence::Action::Function::Type as Function,
 };
 
+pub mod Annotated;
+pub mod Cacheable;
 pub mod Signature;
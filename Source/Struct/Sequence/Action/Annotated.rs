@@ -0,0 +1,122 @@
+/// An action carrying extra metadata on top of its own.
+///
+/// Used to attach context, such as the `Reason` an action was moved to a
+/// dead-letter queue, to an action that is shared and cannot be changed.
+pub struct Struct {
+	/// The wrapped action.
+	pub Action:Arc<dyn Action>,
+
+	/// The metadata overriding the action's own.
+	pub Metadata:Map<String, Value>,
+
+	/// The keys of the action's own metadata that read as absent.
+	pub Hidden:Vec<String>,
+}
+
+impl Struct {
+	/// Creates a new annotated action without extra metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to wrap.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Action:Arc<dyn Action>) -> Self {
+		Struct { Action, Metadata:Map::new(), Hidden:Vec::new() }
+	}
+
+	/// Adds a metadata entry, overriding the action's own.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key.
+	/// * `Value` - The value stored under `Key`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithMetadata(mut self, Key:&str, Value:Value) -> Self {
+		self.Metadata.insert(Key.to_string(), Value);
+
+		self
+	}
+
+	/// Hides a metadata entry of the action, so it reads as absent unless
+	/// added again with `WithMetadata`.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithoutMetadata(mut self, Key:&str) -> Self {
+		self.Metadata.remove(Key);
+
+		self.Hidden.push(Key.to_string());
+
+		self
+	}
+}
+
+#[async_trait]
+impl Action for Struct {
+	async fn Execute(&self, Context:&Life) -> Result<(), Error> {
+		self.Action.Execute(Context).await
+	}
+
+	fn Duplicate(&self) -> Result<Box<dyn Action>, Error> { self.Action.Duplicate() }
+
+	async fn Metadata(&self, Key:&str) -> Option<Value> {
+		match self.Metadata.get(Key) {
+			Some(Value) => Some(Value.clone()),
+			None if self.Hidden.iter().any(|Hidden| Hidden == Key) => None,
+			None => self.Action.Metadata(Key).await,
+		}
+	}
+
+	fn Annotate(&self, Key:&str, Value:Value) { self.Action.Annotate(Key, Value) }
+
+	async fn ArgumentSize(&self) -> u64 { self.Action.ArgumentSize().await }
+
+	fn Partial(&self) -> Option<Writer> { self.Action.Partial() }
+
+	async fn Stage(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		self.Action.Stage(Context, Phase).await
+	}
+
+	fn Cancellation(&self) -> Option<Cancellation> { self.Action.Cancellation() }
+
+	fn Fail(&self, Error:&Error) { self.Action.Fail(Error) }
+
+	async fn View(&self) -> View {
+		let mut View = self.Action.View().await;
+
+		for Hidden in &self.Hidden {
+			View.Metadata.remove(Hidden);
+		}
+
+		View.Apply(&self.Metadata);
+
+		View
+	}
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::Sequence::{Action::Error::Enum as Error, Phase::Enum as Phase},
+	Struct::Sequence::{
+		Admission::View::Struct as View,
+		Cancellation::Struct as Cancellation,
+		Life::Struct as Life,
+	},
+	Trait::Sequence::Action::Trait as Action,
+	Type::Sequence::Action::Writer::Type as Writer,
+};
@@ -0,0 +1,59 @@
+/// How long the result of a cacheable action is served, set with
+/// `Signature::WithCacheable`.
+///
+/// An action with the same type and arguments as one that completed less
+/// than `SoftTtl` ago returns its result without running. Up to `HardTtl`
+/// the result is still returned at once, while a single execution in the
+/// background refreshes it; past `HardTtl` the action runs as usual.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// How long a result is served as it is.
+	pub SoftTtl:Span,
+
+	/// How long a result is served at all, refreshed once it is older than
+	/// `SoftTtl`.
+	pub HardTtl:Span,
+}
+
+impl Struct {
+	/// Creates a new cache setting.
+	///
+	/// # Arguments
+	///
+	/// * `SoftTtl` - How long a result is served as it is.
+	/// * `HardTtl` - How long a result is served at all; raised to `SoftTtl`
+	///   if shorter.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(SoftTtl:Duration, HardTtl:Duration) -> Self {
+		Struct { SoftTtl:SoftTtl.into(), HardTtl:HardTtl.max(SoftTtl).into() }
+	}
+
+	/// Tells how fresh a result of the given age is.
+	///
+	/// # Arguments
+	///
+	/// * `Age` - How long ago the result was stored.
+	///
+	/// # Returns
+	///
+	/// `Some(false)` within `SoftTtl`, `Some(true)` within `HardTtl` and
+	/// `None` past it.
+	pub fn Stale(&self, Age:Duration) -> Option<bool> {
+		if Age < self.SoftTtl.Get() {
+			Some(false)
+		} else if Age < self.HardTtl.Get() {
+			Some(true)
+		} else {
+			None
+		}
+	}
+}
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Time::Duration::Struct as Span;
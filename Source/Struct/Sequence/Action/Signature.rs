@@ -2,7 +2,10 @@
 ///
 /// This struct encapsulates the name of an action signature, which can be used
 /// for identifying and describing different types of actions within a system.
-#[derive(Clone, Debug)]
+///
+/// Signatures carry everything known about an action type except its
+/// function, so a plan exports them as its `Manifest`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Struct {
 	/// The name of the action signature.
 	///
@@ -10,4 +13,273 @@ pub struct Struct {
 	/// action. It can be used to look up or reference specific actions within
 	/// a larger system.
 	pub Name:String,
+
+	/// Whether the function of the action does CPU-heavy or otherwise
+	/// blocking work.
+	///
+	/// Such a function runs on the blocking thread pool instead of a runtime
+	/// worker, so it does not hold up the other actions and tasks scheduled
+	/// there. It may still be written as async code; it is driven to
+	/// completion on its own thread, inside the invocation of its attempt.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub Blocking:bool,
+
+	/// The name of the type the arguments of the action decode into, if
+	/// known, as registered by `Plan::WithHandler`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Input:Option<String>,
+
+	/// The name of the type the result of the action encodes from, if known.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Output:Option<String>,
+
+	/// The JSON type of each argument, in order, checked before the function
+	/// runs; empty to check nothing. A type is `string`, `number`,
+	/// `integer`, `boolean`, `array`, `object`, `null`, `bytes`, which also
+	/// takes text, or `any`.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub InputTypes:Vec<String>,
+
+	/// The JSON type of the result, one of `InputTypes`, checked once the
+	/// function returned, if known.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub OutputType:Option<String>,
+
+	/// How long the results of the action are served without running it
+	/// again, if they are.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Cacheable:Option<Cacheable>,
+
+	/// The metadata every action of the type carries unless it sets the key
+	/// itself, e.g. its `Hooks` or `Timeout`.
+	#[serde(default, skip_serializing_if = "Map::is_empty")]
+	pub Defaults:Map<String, Value>,
+}
+
+impl Struct {
+	/// Creates a new signature for a non-blocking function.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Name:&str) -> Self { Struct { Name:Name.to_string(), ..Self::default() } }
+
+	/// Sets whether the function of the action runs on the blocking thread
+	/// pool.
+	///
+	/// # Arguments
+	///
+	/// * `Blocking` - Whether the function blocks.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBlocking(mut self, Blocking:bool) -> Self {
+		self.Blocking = Blocking;
+
+		self
+	}
+
+	/// Sets the types the arguments decode into and the result encodes from.
+	///
+	/// # Arguments
+	///
+	/// * `Input` - The name of the argument type.
+	/// * `Output` - The name of the result type.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithTypes(mut self, Input:&str, Output:&str) -> Self {
+		self.Input = Some(Input.to_string());
+
+		self.Output = Some(Output.to_string());
+
+		self
+	}
+
+	/// Sets the JSON type of each argument, checked before the function runs.
+	///
+	/// # Arguments
+	///
+	/// * `InputTypes` - The types of the arguments, in order.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithInputTypes(mut self, InputTypes:&[&str]) -> Self {
+		self.InputTypes = InputTypes.iter().map(|Type| Type.to_string()).collect();
+
+		self
+	}
+
+	/// Sets the JSON type of the result, checked once the function returned.
+	///
+	/// # Arguments
+	///
+	/// * `OutputType` - The type of the result.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOutputType(mut self, OutputType:&str) -> Self {
+		self.OutputType = Some(OutputType.to_string());
+
+		self
+	}
+
+	/// Checks the arguments of a call against `InputTypes`.
+	///
+	/// # Arguments
+	///
+	/// * `Argument` - The arguments the function would receive.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if there are as many arguments as types and each has its
+	/// type, or an `Error::Execution` naming the first that does not.
+	pub fn CheckInput(&self, Argument:&[Arg]) -> Result<(), Error> {
+		if self.InputTypes.is_empty() {
+			return Ok(());
+		}
+
+		if Argument.len() != self.InputTypes.len() {
+			return Err(Error::Execution(format!(
+				"Action {} expects {} arguments ({}), got {}",
+				self.Name,
+				self.InputTypes.len(),
+				self.InputTypes.join(", "),
+				Argument.len()
+			)));
+		}
+
+		for (Index, (Type, Argument)) in self.InputTypes.iter().zip(Argument).enumerate() {
+			if !self.Matches(Type, Argument)? {
+				return Err(Error::Execution(format!(
+					"Argument {} of action {} must be {}, got {}",
+					Index,
+					self.Name,
+					Type,
+					Describe(Argument)
+				)));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Checks the result of a call against `OutputType`.
+	///
+	/// # Arguments
+	///
+	/// * `Value` - The value the function returned.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the result has its type or none is set, or an
+	/// `Error::Execution` naming the type expected.
+	pub fn CheckOutput(&self, Value:&Value) -> Result<(), Error> {
+		let Some(Type) = &self.OutputType else {
+			return Ok(());
+		};
+
+		let Value = Arg::Json(Value.clone());
+
+		if self.Matches(Type, &Value)? {
+			return Ok(());
+		}
+
+		Err(Error::Execution(format!(
+			"Action {} must return {}, got {}",
+			self.Name,
+			Type,
+			Describe(&Value)
+		)))
+	}
+
+	/// Checks whether an argument has a type of `InputTypes`.
+	fn Matches(&self, Type:&str, Argument:&Arg) -> Result<bool, Error> {
+		Ok(match (Type, Argument) {
+			("any", _) => true,
+			("string", Arg::Json(Value::String(_)) | Arg::Str(_)) => true,
+			("bytes", Arg::Json(Value::String(_)) | Arg::Str(_) | Arg::Bytes(_)) => true,
+			("number", Arg::Json(Value::Number(_))) => true,
+			("integer", Arg::Json(Value::Number(Number))) => !Number.is_f64(),
+			("boolean", Arg::Json(Value::Bool(_))) => true,
+			("array", Arg::Json(Value::Array(_))) => true,
+			("object", Arg::Json(Value::Object(_))) => true,
+			("null", Arg::Json(Value::Null)) => true,
+			(
+				"string" | "bytes" | "number" | "integer" | "boolean" | "array" | "object" | "null",
+				_,
+			) => false,
+			(Type, _) => {
+				return Err(Error::Execution(format!(
+					"Signature of action {} names an unknown type: {}",
+					self.Name, Type
+				)));
+			},
+		})
+	}
+
+	/// Serves the results of the action for a while instead of running it
+	/// again with the same arguments.
+	///
+	/// # Arguments
+	///
+	/// * `SoftTtl` - How long a result is served as it is.
+	/// * `HardTtl` - How long a result is served while it is refreshed in
+	///   the background.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCacheable(mut self, SoftTtl:Duration, HardTtl:Duration) -> Self {
+		self.Cacheable = Some(Cacheable::New(SoftTtl, HardTtl));
+
+		self
+	}
+
+	/// Sets the default metadata of the actions of the type.
+	///
+	/// # Arguments
+	///
+	/// * `Defaults` - The metadata applied where an action sets none.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDefaults(mut self, Defaults:Map<String, Value>) -> Self {
+		self.Defaults = Defaults;
+
+		self
+	}
+}
+
+/// Names the JSON type of an argument, for an error.
+fn Describe(Argument:&Arg) -> &'static str {
+	match Argument {
+		Arg::Json(Value::String(_)) | Arg::Str(_) => "string",
+		Arg::Json(Value::Number(Number)) if Number.is_f64() => "number",
+		Arg::Json(Value::Number(_)) => "integer",
+		Arg::Json(Value::Bool(_)) => "boolean",
+		Arg::Json(Value::Array(_)) => "array",
+		Arg::Json(Value::Object(_)) => "object",
+		Arg::Json(Value::Null) => "null",
+		Arg::Bytes(_) => "bytes",
+	}
 }
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::Action::Cacheable::Struct as Cacheable,
+};
@@ -0,0 +1,290 @@
+/// Tracks the lifecycle of every action enqueued into the production lines of
+/// a `Life`, so no per-action state outlives its action.
+///
+/// A production line enlisted with `Production::Enlist` reports each of its
+/// actions here: `Queued` when enqueued or put back, `Running` when dequeued
+/// and `Completed` when its outcome was reported. Every `Interval` a sweep
+/// abandons queued actions that are no longer in their line, resolving their
+/// completions with `Error::Abandoned`, forgets actions whose line was
+/// dropped, and removes terminal entries older than `Retention`. The number
+/// of entries is published as the `echo_registry_entries` gauge.
+pub struct Struct {
+	/// The entries, by production line and sequence number.
+	Entry:Mutex<HashMap<(usize, u64), Record>>,
+
+	/// How long terminal entries are kept, in milliseconds.
+	Retention:AtomicU64,
+
+	/// How often the registry is swept, in milliseconds.
+	Interval:AtomicU64,
+
+	/// The task sweeping the registry, started on first use.
+	Task:OnceLock<Spawned::Struct>,
+
+	/// The overseer running the task.
+	Overseer:Arc<Overseer>,
+}
+
+/// The lifecycle of one action.
+struct Record {
+	/// The production line of the action.
+	Line:Weak<Production>,
+
+	/// Where the action is in its lifecycle.
+	State:Lifecycle,
+
+	/// When the state last changed.
+	Changed:Instant,
+}
+
+impl Struct {
+	/// Creates an empty registry without starting its sweeps.
+	///
+	/// # Arguments
+	///
+	/// * `Retention` - How long terminal entries are kept.
+	/// * `Interval` - How often the registry is swept.
+	/// * `Overseer` - The overseer running the sweeps, which restarts them
+	///   if they panic.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Retention:Duration, Interval:Duration, Overseer:Arc<Overseer>) -> Self {
+		let Registry = Struct {
+			Entry:Mutex::new(HashMap::new()),
+			Retention:AtomicU64::new(0),
+			Interval:AtomicU64::new(0),
+			Task:OnceLock::new(),
+			Overseer,
+		};
+
+		Registry.Configure(Retention, Interval);
+
+		Registry
+	}
+
+	/// Changes how long terminal entries are kept and how often the registry
+	/// is swept; a running sweep task picks the interval up after its next
+	/// sweep.
+	///
+	/// # Arguments
+	///
+	/// * `Retention` - How long terminal entries are kept.
+	/// * `Interval` - How often the registry is swept.
+	pub fn Configure(&self, Retention:Duration, Interval:Duration) {
+		self.Retention.store(Retention.as_millis() as u64, Ordering::Relaxed);
+
+		self.Interval
+			.store(Interval.as_millis().max(1) as u64, Ordering::Relaxed);
+	}
+
+	/// Records a change in the lifecycle of an action.
+	///
+	/// Production lines call this under their queue lock, so a sweep never
+	/// sees an action queued in the registry but missing from its line
+	/// while it moves.
+	///
+	/// # Arguments
+	///
+	/// * `Line` - The production line of the action.
+	/// * `Sequence` - The sequence number of the action.
+	/// * `State` - Where the action is now.
+	pub fn Track(self: &Arc<Self>, Line:&Weak<Production>, Sequence:u64, State:Lifecycle) {
+		self.Start();
+
+		let Length = {
+			let mut Entry = self.Lock();
+
+			let Key = (Line.as_ptr() as usize, Sequence);
+
+			match Entry.get_mut(&Key) {
+				// An abandoned action stays abandoned
+				Some(Record) if Record.State == Lifecycle::Abandoned => {},
+				Some(Record) => {
+					Record.State = State;
+
+					Record.Changed = Instant::now();
+				},
+				None => {
+					Entry.insert(Key, Record { Line:Line.clone(), State, Changed:Instant::now() });
+				},
+			}
+
+			Entry.len()
+		};
+
+		gauge!("echo_registry_entries").set(Length as f64);
+	}
+
+	/// Returns where an action is in its lifecycle, if it is tracked.
+	///
+	/// # Arguments
+	///
+	/// * `Line` - The production line of the action.
+	/// * `Sequence` - The sequence number of the action.
+	pub fn State(&self, Line:&Arc<Production>, Sequence:u64) -> Option<Lifecycle> {
+		self.Lock()
+			.get(&(Arc::as_ptr(Line) as usize, Sequence))
+			.map(|Record| Record.State)
+	}
+
+	/// Returns the number of entries.
+	pub fn Len(&self) -> usize { self.Lock().len() }
+
+	/// Returns whether the registry is empty.
+	pub fn IsEmpty(&self) -> bool { self.Lock().is_empty() }
+
+	/// Abandons orphaned actions and removes stale entries now.
+	///
+	/// # Returns
+	///
+	/// The number of actions abandoned by this sweep.
+	pub async fn Sweep(&self) -> usize {
+		let Line = {
+			let Entry = self.Lock();
+
+			let mut Line:HashMap<usize, Arc<Production>> = HashMap::new();
+
+			for ((Key, _), Record) in Entry.iter() {
+				if Record.State == Lifecycle::Queued && !Line.contains_key(Key) {
+					Line.extend(Record.Line.upgrade().map(|Production| (*Key, Production)));
+				}
+			}
+
+			Line
+		};
+
+		let mut Abandoned = 0;
+
+		for Production in Line.into_values() {
+			Abandoned += Production.Orphans().await;
+		}
+
+		let Retention = Duration::from_millis(self.Retention.load(Ordering::Relaxed));
+
+		let Length = {
+			let mut Entry = self.Lock();
+
+			Entry.retain(|_, Record| {
+				Record.Line.strong_count() > 0
+					&& !(Record.State.Terminal() && Record.Changed.elapsed() >= Retention)
+			});
+
+			Entry.len()
+		};
+
+		if Abandoned > 0 {
+			counter!("echo_actions_abandoned_total").increment(Abandoned as u64);
+		}
+
+		gauge!("echo_registry_entries").set(Length as f64);
+
+		Abandoned
+	}
+
+	/// Marks the queued actions of a line that are not in it as abandoned.
+	///
+	/// Called by the line with its queue lock held.
+	///
+	/// # Arguments
+	///
+	/// * `Line` - The production line.
+	/// * `Present` - The sequence numbers of the actions in the line.
+	///
+	/// # Returns
+	///
+	/// The sequence numbers of the abandoned actions.
+	pub(crate) fn Orphaned(&self, Line:&Weak<Production>, Present:&HashSet<u64>) -> Vec<u64> {
+		let Line = Line.as_ptr() as usize;
+
+		let mut Orphaned = Vec::new();
+
+		for ((Key, Sequence), Record) in self.Lock().iter_mut() {
+			if *Key == Line && Record.State == Lifecycle::Queued && !Present.contains(Sequence) {
+				Record.State = Lifecycle::Abandoned;
+
+				Record.Changed = Instant::now();
+
+				Orphaned.push(*Sequence);
+			}
+		}
+
+		Orphaned
+	}
+
+	/// Starts the sweeping task unless it runs already.
+	fn Start(self: &Arc<Self>) {
+		self.Task.get_or_init(|| {
+			let (Owner, Runtime) = (Arc::downgrade(self), self.Overseer.Runtime());
+
+			self.Overseer.Spawn(
+				"registry",
+				Restart::Always { Backoff:Duration::from_secs(1) },
+				move || {
+					let (Owner, Runtime) = (Owner.clone(), Runtime.clone());
+
+					async move {
+						while let Some(Interval) =
+							Owner.upgrade().map(|Registry| Registry.Interval())
+						{
+							Runtime.Sleep(Interval).await;
+
+							let Some(Registry) = Owner.upgrade() else {
+								break;
+							};
+
+							Registry.Sweep().await;
+						}
+					}
+				},
+			)
+		});
+	}
+
+	/// Returns how often the registry is swept.
+	fn Interval(&self) -> Duration { Duration::from_millis(self.Interval.load(Ordering::Relaxed)) }
+
+	/// Takes the lock of the entries, ignoring poisoning.
+	fn Lock(&self) -> MutexGuard<'_, HashMap<(usize, u64), Record>> {
+		self.Entry.lock().unwrap_or_else(|Poison| Poison.into_inner())
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Self::New(Duration::from_secs(60), Duration::from_secs(5), Arc::new(Overseer::default()))
+	}
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		if let Some(Task) = self.Task.get() {
+			Task.Abort();
+		}
+	}
+}
+
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+		OnceLock,
+		Weak,
+	},
+	time::Duration,
+};
+
+use metrics::{counter, gauge};
+use tokio::time::Instant;
+
+use crate::{
+	Enum::Sequence::{Lifecycle::Enum as Lifecycle, Restart::Enum as Restart},
+	Struct::{
+		Runtime::Spawned,
+		Sequence::{Overseer::Struct as Overseer, Production::Struct as Production},
+	},
+};
@@ -0,0 +1,4 @@
+pub mod Identity;
+pub mod PathPrefix;
+pub mod Roles;
+pub mod View;
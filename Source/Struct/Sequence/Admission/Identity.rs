@@ -0,0 +1,32 @@
+/// Who submits an action, as admission policies see it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The name of the submitter: the identity a connection authenticated
+	/// as, `anonymous`, or `local` in process.
+	pub Name:String,
+
+	/// What the submitter is allowed to do.
+	pub Role:Role,
+}
+
+impl Struct {
+	/// Creates an identity.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the submitter.
+	/// * `Role` - Its role.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Name:impl Into<String>, Role:Role) -> Self { Struct { Name:Name.into(), Role } }
+
+	/// Returns the identity of actions submitted in process, through
+	/// `Life::Submit` or a source: `local`, as a `Client`.
+	pub fn Local() -> Self { Self::New("local", Role::Client) }
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Transport::Role::Enum as Role;
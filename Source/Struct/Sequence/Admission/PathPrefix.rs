@@ -0,0 +1,163 @@
+/// Guards the paths the file-operation built-ins (`Builtin::Fs`) are
+/// submitted with.
+///
+/// Paths are normalised lexically, relative to the root of the built-ins
+/// as they resolve them, so `/etc/../secret/key` is `secret/key`. A
+/// submission is refused if a path falls under a denied prefix, escapes the
+/// root, or, once a prefix is allowed, falls under none of those allowed.
+/// The glob of a `List` is refused if it may reach under a denied prefix.
+/// Other action types are admitted unchecked.
+#[derive(Clone, Debug, Default)]
+pub struct Struct {
+	/// The prefixes paths may fall under, any if empty.
+	Allow:Vec<PathBuf>,
+
+	/// The prefixes paths may not fall under.
+	Deny:Vec<PathBuf>,
+}
+
+/// The file-operation actions and the positions of their path arguments.
+const ACTIONS:[(&str, &[usize]); 8] = [
+	("Read", &[0]),
+	("Write", &[0]),
+	("Append", &[0]),
+	("Copy", &[0, 1]),
+	("Move", &[0, 1]),
+	("Delete", &[0]),
+	("List", &[0]),
+	("Exists", &[0]),
+];
+
+impl Struct {
+	/// Creates a guard admitting every path under the root.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Confines the paths to a prefix, and the others allowed.
+	///
+	/// # Arguments
+	///
+	/// * `Prefix` - The prefix, e.g. `public`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Allow(mut self, Prefix:&str) -> Self {
+		self.Allow.extend(Normal(Prefix));
+
+		self
+	}
+
+	/// Refuses the paths under a prefix.
+	///
+	/// # Arguments
+	///
+	/// * `Prefix` - The prefix, e.g. `secret`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Deny(mut self, Prefix:&str) -> Self {
+		self.Deny.extend(Normal(Prefix));
+
+		self
+	}
+
+	/// Checks one path argument.
+	///
+	/// # Returns
+	///
+	/// Why the path is refused, if it is.
+	fn Check(&self, Action:&str, Path:&str) -> Option<String> {
+		let Some(Normal) = Normal(Path) else {
+			return Some(format!("Path {} escapes the root", Path));
+		};
+
+		// A glob reaches every path under its literal leading components
+		let List = Action == "List";
+
+		let Normal:PathBuf = if List {
+			Normal
+				.components()
+				.take_while(|Part| !Glob::Struct::Is(&Part.as_os_str().to_string_lossy()))
+				.collect()
+		} else {
+			Normal
+		};
+
+		let Under =
+			|Prefix:&PathBuf| Normal.starts_with(Prefix) || (List && Prefix.starts_with(&Normal));
+
+		if let Some(Prefix) = self.Deny.iter().find(|Prefix| Under(Prefix)) {
+			return Some(format!("Path {} is under the denied prefix {}", Path, Prefix.display()));
+		}
+
+		if !self.Allow.is_empty() && !self.Allow.iter().any(|Prefix| Normal.starts_with(Prefix)) {
+			return Some(format!("Path {} is under no allowed prefix", Path));
+		}
+
+		None
+	}
+}
+
+#[async_trait]
+impl AdmissionPolicy for Struct {
+	fn Name(&self) -> String { "PathPrefix".to_string() }
+
+	async fn Admit(&self, _Identity:&Identity, Action:&View) -> Decision {
+		let Some((_, Position)) = ACTIONS.iter().find(|(Name, _)| *Name == Action.Action) else {
+			return Decision::Allow;
+		};
+
+		// An argument that is not text fails when the action runs
+		let Refused = Position
+			.iter()
+			.filter_map(|Position| Action.Argument.get(*Position)?.as_str())
+			.find_map(|Path| self.Check(&Action.Action, Path));
+
+		match Refused {
+			Some(Reason) => Decision::Forbid(Reason),
+			None => Decision::Allow,
+		}
+	}
+}
+
+/// Normalises a path lexically relative to the root, as `Fs::Resolve`
+/// does before resolving links.
+///
+/// # Returns
+///
+/// The path, or `None` if it escapes the root.
+fn Normal(Path:&str) -> Option<PathBuf> {
+	let mut Normal = PathBuf::new();
+
+	for Component in std::path::Path::new(Path).components() {
+		match Component {
+			Component::Normal(Part) => Normal.push(Part),
+			Component::CurDir | Component::RootDir | Component::Prefix(_) => {},
+			Component::ParentDir => {
+				if !Normal.pop() {
+					return None;
+				}
+			},
+		}
+	}
+
+	Some(Normal)
+}
+
+use std::path::{Component, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Decision::Enum as Decision,
+	Struct::Sequence::{
+		Admission::{Identity::Struct as Identity, View::Struct as View},
+		Glob,
+	},
+	Trait::Sequence::AdmissionPolicy::Trait as AdmissionPolicy,
+};
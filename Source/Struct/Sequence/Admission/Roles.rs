@@ -0,0 +1,96 @@
+/// Admits action types by the role of the submitter.
+///
+/// A role lists the action types it may not submit, and may list the only
+/// ones it may, as patterns where `*` matches any run of characters and `?`
+/// one character. A denied type is refused even if allowed too; a role
+/// listing no allowed type may submit any type it is not denied.
+#[derive(Clone, Debug, Default)]
+pub struct Struct {
+	/// The action types each role may only submit.
+	Allow:BTreeMap<Role, Vec<Glob::Struct>>,
+
+	/// The action types each role may not submit.
+	Deny:BTreeMap<Role, Vec<Glob::Struct>>,
+}
+
+impl Struct {
+	/// Creates a policy admitting every action type.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Lets a role submit the action types matching a pattern, and only
+	/// those and the others allowed.
+	///
+	/// # Arguments
+	///
+	/// * `Role` - The role.
+	/// * `Pattern` - The action types, e.g. `Read` or `Report.*`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Allow(mut self, Role:Role, Pattern:&str) -> Self {
+		self.Allow.entry(Role).or_default().push(Glob::Struct::New(Pattern));
+
+		self
+	}
+
+	/// Refuses a role the action types matching a pattern.
+	///
+	/// # Arguments
+	///
+	/// * `Role` - The role.
+	/// * `Pattern` - The action types, e.g. `Delete` or `Admin.*`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Deny(mut self, Role:Role, Pattern:&str) -> Self {
+		self.Deny.entry(Role).or_default().push(Glob::Struct::New(Pattern));
+
+		self
+	}
+}
+
+#[async_trait]
+impl AdmissionPolicy for Struct {
+	fn Name(&self) -> String { "Roles".to_string() }
+
+	async fn Admit(&self, Identity:&Identity, Action:&View) -> Decision {
+		let Matches = |Pattern:Option<&Vec<Glob::Struct>>| {
+			Pattern.map(|Pattern| Pattern.iter().any(|Glob| Glob.Match(&Action.Action)))
+		};
+
+		if Matches(self.Deny.get(&Identity.Role)) == Some(true) {
+			return Decision::Forbid(format!(
+				"{:?} {} may not submit {}",
+				Identity.Role, Identity.Name, Action.Action
+			));
+		}
+
+		if Matches(self.Allow.get(&Identity.Role)) == Some(false) {
+			return Decision::Forbid(format!(
+				"{:?} {} is not allowed to submit {}",
+				Identity.Role, Identity.Name, Action.Action
+			));
+		}
+
+		Decision::Allow
+	}
+}
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::{Sequence::Decision::Enum as Decision, Transport::Role::Enum as Role},
+	Struct::Sequence::{
+		Admission::{Identity::Struct as Identity, View::Struct as View},
+		Glob,
+	},
+	Trait::Sequence::AdmissionPolicy::Trait as AdmissionPolicy,
+};
@@ -0,0 +1,94 @@
+/// What admission policies see of a submitted action.
+///
+/// Reserved options appear in `Metadata` under their flat name, e.g.
+/// `Queue`, whether the action sets them under their `__echo.` key, in
+/// their legacy form or as the `Options` of a wire submission.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The plan function the action calls.
+	pub Action:String,
+
+	/// The arguments of the call.
+	pub Argument:Vec<Value>,
+
+	/// The metadata and options of the action, by key.
+	pub Metadata:Map<String, Value>,
+}
+
+impl Struct {
+	/// Describes an action from its metadata, reserved keys normalised to
+	/// their flat name; a prefixed key wins over its flat form.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The plan function the action calls.
+	/// * `Argument` - The arguments of the call.
+	/// * `Metadata` - The metadata of the action.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Action:String, Argument:Vec<Value>, Metadata:Map<String, Value>) -> Self {
+		let (Prefixed, Flat):(Vec<_>, Vec<_>) =
+			Metadata.into_iter().partition(|(Key, _)| Key.starts_with(PREFIX));
+
+		let mut View = Struct { Action, Argument, Metadata:Map::new() };
+
+		for (Key, Value) in Flat.into_iter().chain(Prefixed) {
+			let Key = Reserved::Parse(&Key).map_or(Key, |Reserved| Reserved.Name().to_string());
+
+			if Key != Reserved::Action.Name() && Key != Reserved::Argument.Name() {
+				View.Metadata.insert(Key, Value);
+			}
+		}
+
+		View
+	}
+
+	/// Reads an entry of the metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key, a reserved one with or without its prefix.
+	///
+	/// # Returns
+	///
+	/// The value, or `None` if the action sets none.
+	pub fn Get(&self, Key:&str) -> Option<&Value> {
+		self.Metadata.get(Reserved::Parse(Key).map_or(Key, |Reserved| Reserved.Name()))
+	}
+
+	/// Applies the patch of a `Modify` decision, as the action will carry
+	/// it; `Action` and `Argument` entries replace the call.
+	///
+	/// # Arguments
+	///
+	/// * `Patch` - The metadata set, by key.
+	pub fn Apply(&mut self, Patch:&Map<String, Value>) {
+		for (Key, Value) in Patch {
+			match Reserved::Parse(Key) {
+				Some(Reserved::Action) => {
+					if let Value::String(Action) = Value {
+						self.Action = Action.clone();
+					}
+				},
+				Some(Reserved::Argument) => {
+					if let Value::Array(Argument) = Value {
+						self.Argument = Argument.clone();
+					}
+				},
+				Some(Reserved) => {
+					self.Metadata.insert(Reserved.Name().to_string(), Value.clone());
+				},
+				None => {
+					self.Metadata.insert(Key.clone(), Value.clone());
+				},
+			}
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::Enum::Sequence::Reserved::{Enum as Reserved, PREFIX};
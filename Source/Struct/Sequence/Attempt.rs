@@ -0,0 +1,93 @@
+/// What happened during one attempt of an action.
+///
+/// `Fn::Retry` records an entry per attempt into a `History`; for actions
+/// retried by `Sequence::Again`, it travels with the `Completed` and `Failed`
+/// events and the transport `Result` reply.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Attempt"))]
+pub struct Struct {
+	/// The number of the attempt, starting at 1.
+	pub Attempt:u32,
+
+	/// The Unix time in milliseconds at which the attempt started.
+	pub Started:u64,
+
+	/// The Unix time in milliseconds at which the attempt ended.
+	pub Ended:u64,
+
+	/// How the attempt ended.
+	pub Outcome:Outcome,
+
+	/// The error of a failed attempt, cut to 512 characters.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Error:Option<String>,
+
+	/// The backoff in milliseconds applied before the next attempt, if the
+	/// action was retried.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Backoff:Option<u64>,
+}
+
+impl Struct {
+	/// Records an attempt that ends now.
+	///
+	/// # Arguments
+	///
+	/// * `Attempt` - The number of the attempt, starting at 1.
+	/// * `Started` - The Unix time in milliseconds at which it started.
+	/// * `Result` - The error of the attempt, if it failed.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance without a backoff.
+	pub fn New<T, E:Display>(Attempt:u32, Started:u64, Result:&Result<T, E>) -> Self {
+		Struct {
+			Attempt,
+			Started,
+			Ended:Self::Now(),
+			Outcome:match Result {
+				Ok(_) => Outcome::Succeeded,
+				Err(_) => Outcome::Failed,
+			},
+			Error:Result
+				.as_ref()
+				.err()
+				.map(|_Error| _Error.to_string().chars().take(512).collect()),
+			Backoff:None,
+		}
+	}
+
+	/// Sets the backoff applied before the next attempt.
+	///
+	/// # Arguments
+	///
+	/// * `Backoff` - The delay before the next attempt.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBackoff(mut self, Backoff:Duration) -> Self {
+		self.Backoff = Some(Backoff.as_millis() as u64);
+
+		self
+	}
+
+	/// Returns the current Unix time in milliseconds.
+	pub fn Now() -> u64 {
+		SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|Now| Now.as_millis() as u64)
+			.unwrap_or_default()
+	}
+}
+
+use std::{
+	fmt::Display,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Sequence::Attempt::Outcome::Enum as Outcome;
+
+pub mod History;
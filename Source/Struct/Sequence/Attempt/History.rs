@@ -0,0 +1,64 @@
+/// The attempts of one action, capped for actions retried many times.
+///
+/// The first and the last `Keep` attempts are retained; attempts in between
+/// are dropped and only counted, so their numbers leave a gap.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "History"))]
+pub struct Struct {
+	/// The retained attempts, oldest first.
+	pub Attempts:Vec<Attempt>,
+
+	/// The number of attempts dropped from the middle.
+	#[serde(default)]
+	pub Omitted:u32,
+
+	/// How many attempts are retained at each end.
+	#[serde(skip, default = "Keep")]
+	Keep:usize,
+}
+
+impl Struct {
+	/// Creates an empty history.
+	///
+	/// # Arguments
+	///
+	/// * `Keep` - How many attempts are retained at each end.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Keep:usize) -> Self { Struct { Attempts:Vec::new(), Omitted:0, Keep } }
+
+	/// Appends an attempt, dropping the oldest attempt past the first `Keep`
+	/// when the history is full.
+	///
+	/// # Arguments
+	///
+	/// * `Attempt` - The attempt to append.
+	pub fn Push(&mut self, Attempt:Attempt) {
+		if self.Attempts.len() >= self.Keep.max(1) * 2 {
+			self.Attempts.remove(self.Keep.max(1));
+
+			self.Omitted += 1;
+		}
+
+		self.Attempts.push(Attempt);
+	}
+
+	/// Returns the last attempt, if any.
+	pub fn Last(&self) -> Option<&Attempt> { self.Attempts.last() }
+
+	/// Returns the last attempt for changing it, if any.
+	pub fn LastMut(&mut self) -> Option<&mut Attempt> { self.Attempts.last_mut() }
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Keep()) }
+}
+
+/// The number of attempts retained at each end by default.
+fn Keep() -> usize { 10 }
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Sequence::Attempt::Struct as Attempt;
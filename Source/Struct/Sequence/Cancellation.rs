@@ -0,0 +1,35 @@
+/// A token cancelling work that waits on it, shared by cloning.
+///
+/// Once cancelled, a token stays cancelled; every clone observes it.
+#[derive(Clone, Debug)]
+pub struct Struct(Arc<watch::Sender<bool>>);
+
+impl Struct {
+	/// Creates a token that is not cancelled.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Struct(Arc::new(watch::Sender::new(false))) }
+
+	/// Cancels the token, waking everything waiting on it.
+	pub fn Cancel(&self) { self.0.send_replace(true); }
+
+	/// Returns whether the token was cancelled.
+	pub fn IsCancelled(&self) -> bool { *self.0.borrow() }
+
+	/// Waits until the token is cancelled.
+	pub async fn Cancelled(&self) {
+		let mut Receiver = self.0.subscribe();
+
+		let _ = Receiver.wait_for(|Cancelled| *Cancelled).await;
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use std::sync::Arc;
+
+use tokio::sync::watch;
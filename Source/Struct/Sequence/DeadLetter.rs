@@ -0,0 +1,292 @@
+/// Inspects and clears the dead-letter queues of a `Life`.
+///
+/// A dead-letter queue is a Karma queue named as `DeadLetter` in the
+/// settings of another; `Sequence` moves the actions that failed there,
+/// annotated with why. `List` shows them, `Requeue` sends them back for
+/// another try and `Purge` deletes them. Every operation is logged and
+/// published as an `Audit` event.
+///
+/// Requeues run one at a time and remember what they requeued, bounded by
+/// the `[stores.requeued]` limits, so requeuing an entry twice, even
+/// concurrently, requeues it once and reports `AlreadyRequeued` the second
+/// time.
+pub struct Struct {
+	/// The Karma queues of the `Life`, dead-letter queues among them.
+	Karma:Arc<DashMap<String, Arc<Production>>>,
+
+	/// The event bus on which operations are audited.
+	Bus:Bus::Struct,
+
+	/// The entries requeued, by dead-letter queue and sequence number, with
+	/// their target queue and sequence number there.
+	Requeued:Store::Struct<(String, u64), (String, u64)>,
+
+	/// Held while entries are requeued.
+	Requeuing:Mutex<()>,
+}
+
+impl Struct {
+	/// Creates the dead-letter tooling over the Karma queues of a `Life`.
+	///
+	/// # Arguments
+	///
+	/// * `Karma` - The Karma queues.
+	/// * `Bus` - The event bus on which operations are audited.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Karma:Arc<DashMap<String, Arc<Production>>>, Bus:Bus::Struct) -> Self {
+		Struct {
+			Karma,
+			Bus,
+			Requeued:Store::Struct::New("requeued", Limit::default()),
+			Requeuing:Mutex::new(()),
+		}
+	}
+
+	/// Bounds how many requeued entries are remembered.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How many entries may be remembered at once.
+	pub fn Bound(&self, Limit:Limit) { self.Requeued.Resize(Limit); }
+
+	/// Lists the entries of a dead-letter queue.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the dead-letter queue.
+	/// * `Filter` - The entries to list.
+	///
+	/// # Returns
+	///
+	/// The selected entries in dequeue order, or `Error::Routing` if the
+	/// queue does not exist.
+	pub async fn List(
+		&self,
+		Queue:&str,
+		Filter:&Filter::Struct,
+	) -> Result<Vec<Entry::Struct>, Error> {
+		let Listed = self.Entries(&*self.Line(Queue)?, Filter).await;
+
+		self.Audit("DeadLetter.List", Queue, Self::Sequence(&Listed), None);
+
+		Ok(Listed)
+	}
+
+	/// Sends entries of a dead-letter queue back for another try.
+	///
+	/// Each action leaves the dead-letter queue and is enqueued into the
+	/// target with its failure metadata (`Reason`, `Error`, `ErrorClass` and
+	/// `Attempts`) and its `DeliveryCount` stripped, so it starts over with
+	/// fresh attempts.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the dead-letter queue.
+	/// * `Sequence` - The sequence numbers of the entries to requeue.
+	/// * `Target` - The name of the queue to enqueue them into.
+	///
+	/// # Returns
+	///
+	/// What happened to every entry, in the order asked for, or
+	/// `Error::Routing` if either queue does not exist.
+	pub async fn Requeue(
+		&self,
+		Queue:&str,
+		Sequence:&[u64],
+		Target:&str,
+	) -> Result<Vec<Requeue>, Error> {
+		let (Line, Destination) = (self.Line(Queue)?, self.Line(Target)?);
+
+		let _Requeuing = self.Requeuing.lock().await;
+
+		let mut Outcome = Vec::with_capacity(Sequence.len());
+
+		for Sequence in Sequence {
+			let Key = (Queue.to_string(), *Sequence);
+
+			if let Some((Target, Requeued)) = self.Requeued.Get(&Key) {
+				Outcome.push(Requeue::AlreadyRequeued { Sequence:*Sequence, Target, Requeued });
+
+				continue;
+			}
+
+			let Some((Stamp, Action)) = Line.Extract(&[*Sequence]).await.pop() else {
+				Outcome.push(Requeue::Missing { Sequence:*Sequence });
+
+				continue;
+			};
+
+			let Action:Arc<dyn ActionTrait> = Arc::from(Action);
+
+			let Fresh = ["Reason", "Error", "ErrorClass", "Attempts", "DeliveryCount"]
+				.into_iter()
+				.fold(Annotated::New(Action.clone()), |Fresh, Key| Fresh.WithoutMetadata(Key));
+
+			match Destination.Enqueue(Box::new(Fresh)).await {
+				Ok(Requeued) => {
+					self.Requeued.Insert(
+						Key,
+						(Target.to_string(), Requeued.Sequence),
+						Target.len() + size_of::<u64>() * 2,
+					);
+
+					Outcome.push(Requeue::Requeued {
+						Sequence:*Sequence,
+						Target:Target.to_string(),
+						Requeued:Requeued.Sequence,
+					});
+				},
+				Err(_Error) => {
+					Line.Reinject(Stamp, Box::new(Action)).await;
+
+					Outcome.push(Requeue::Refused { Sequence:*Sequence, Error:_Error.to_string() });
+				},
+			}
+		}
+
+		let Requeued = Outcome
+			.iter()
+			.filter_map(|Outcome| match Outcome {
+				Requeue::Requeued { Sequence, .. } => Some(*Sequence),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		self.Audit("DeadLetter.Requeue", Queue, Requeued, Some(Target));
+
+		Ok(Outcome)
+	}
+
+	/// Deletes entries of a dead-letter queue for good.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the dead-letter queue.
+	/// * `Filter` - The entries to delete.
+	/// * `DryRun` - Whether to only report what would be deleted.
+	///
+	/// # Returns
+	///
+	/// The entries deleted, or that would be deleted, or `Error::Routing` if
+	/// the queue does not exist.
+	pub async fn Purge(
+		&self,
+		Queue:&str,
+		Filter:&Filter::Struct,
+		DryRun:bool,
+	) -> Result<Vec<Entry::Struct>, Error> {
+		let Line = self.Line(Queue)?;
+
+		let mut Selected = self.Entries(&Line, Filter).await;
+
+		if DryRun {
+			self.Audit("DeadLetter.PurgePreview", Queue, Self::Sequence(&Selected), None);
+
+			return Ok(Selected);
+		}
+
+		let Purged = Line
+			.Extract(&Self::Sequence(&Selected))
+			.await
+			.into_iter()
+			.map(|(Stamp, _)| Stamp.Sequence)
+			.collect::<Vec<_>>();
+
+		// Entries taken by a consumer meanwhile were not purged
+		Selected.retain(|Entry| Purged.contains(&Entry.Sequence));
+
+		self.Audit("DeadLetter.Purge", Queue, Self::Sequence(&Selected), None);
+
+		Ok(Selected)
+	}
+
+	/// Finds a Karma queue by name.
+	fn Line(&self, Queue:&str) -> Result<Arc<Production>, Error> {
+		self.Karma
+			.get(Queue)
+			.map(|Production| Production.clone())
+			.ok_or_else(|| Error::Routing(format!("Unknown queue: {}", Queue)))
+	}
+
+	/// Returns the sequence numbers of entries.
+	fn Sequence(Entry:&[Entry::Struct]) -> Vec<u64> {
+		Entry.iter().map(|Entry| Entry.Sequence).collect()
+	}
+
+	/// Describes the entries of a queue a filter selects.
+	async fn Entries(&self, Line:&Production, Filter:&Filter::Struct) -> Vec<Entry::Struct> {
+		let Now = SystemTime::now();
+
+		Line.Inspect(&["Action", "Reason", "ErrorClass", "Error", "Attempts"])
+			.await
+			.into_iter()
+			.map(|(Stamp, Metadata)| {
+				let Text = |Key:&str| Metadata.get(Key).and_then(Value::as_str).map(str::to_string);
+
+				Entry::Struct {
+					Sequence:Stamp.Sequence,
+					Action:Text("Action"),
+					Reason:Text("Reason"),
+					Class:Text("ErrorClass"),
+					Error:Text("Error"),
+					Attempts:Metadata.get("Attempts").and_then(Value::as_u64),
+					DeadLettered:Stamp
+						.Time
+						.duration_since(UNIX_EPOCH)
+						.unwrap_or_default()
+						.as_millis() as u64,
+					AgeMs:Now.duration_since(Stamp.Time).unwrap_or_default().as_millis() as u64,
+				}
+			})
+			.filter(|Entry| Filter.Match(Entry))
+			.collect()
+	}
+
+	/// Logs an operation and publishes it as an `Audit` event.
+	fn Audit(&self, Operation:&str, Queue:&str, Sequence:Vec<u64>, Target:Option<&str>) {
+		info!(
+			"{} on dead-letter queue {}: {} entries{}",
+			Operation,
+			Queue,
+			Sequence.len(),
+			Target.map(|Target| format!(" into {}", Target)).unwrap_or_default()
+		);
+
+		self.Bus.Emit(|| Event::Audit {
+			Operation:Operation.to_string(),
+			Queue:Queue.to_string(),
+			Sequence,
+			Target:Target.map(str::to_string),
+		});
+	}
+}
+
+use std::{
+	mem::size_of,
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use log::info;
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{Action::Error::Enum as Error, DeadLetter::Requeue::Enum as Requeue},
+	},
+	Struct::{
+		Event::Bus,
+		Sequence::{Action::Annotated::Struct as Annotated, Production::Struct as Production},
+		Store::{self, Limit::Struct as Limit},
+	},
+	Trait::Sequence::Action::Trait as ActionTrait,
+};
+
+pub mod Entry;
+pub mod Filter;
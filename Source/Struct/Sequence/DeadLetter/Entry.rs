@@ -0,0 +1,40 @@
+/// An action waiting in a dead-letter queue, as listed by `DeadLetter::List`.
+///
+/// What it says about the failure comes from the metadata `Sequence`
+/// attaches when it dead-letters an action; actions put into the queue
+/// another way leave those fields empty.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "DeadEntry"))]
+pub struct Struct {
+	/// The sequence number of the action in the dead-letter queue, by which
+	/// `Requeue` and `Purge` identify it.
+	pub Sequence:u64,
+
+	/// The action type, its `Action` metadata.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Action:Option<String>,
+
+	/// Why the action was dead-lettered: `failed`, `unknown` or `expired`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Reason:Option<String>,
+
+	/// The class of its last error, e.g. `Execution`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Class:Option<String>,
+
+	/// Its last error.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Error:Option<String>,
+
+	/// How many attempts it took.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Attempts:Option<u64>,
+
+	/// When it entered the dead-letter queue, in Unix milliseconds.
+	pub DeadLettered:u64,
+
+	/// How long it has been in the dead-letter queue, in milliseconds.
+	pub AgeMs:u64,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,120 @@
+/// Selects the entries of a dead-letter queue `DeadLetter::List` and
+/// `DeadLetter::Purge` operate on.
+///
+/// Every criterion set must hold; the default filter selects every entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "DeadFilter"))]
+#[serde(default)]
+pub struct Struct {
+	/// The action type entries must have.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub Action:Option<String>,
+
+	/// The error class entries must have, e.g. `DeadlineExceeded`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub Class:Option<String>,
+
+	/// How many milliseconds entries must at least have been dead-lettered.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub OlderThanMs:Option<u64>,
+
+	/// How many milliseconds entries may at most have been dead-lettered.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub NewerThanMs:Option<u64>,
+}
+
+impl Struct {
+	/// Creates a filter selecting every entry.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Selects entries of one action type.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action type.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAction(mut self, Action:&str) -> Self {
+		self.Action = Some(Action.to_string());
+
+		self
+	}
+
+	/// Selects entries whose last error is of one class.
+	///
+	/// # Arguments
+	///
+	/// * `Class` - The error class, as returned by `Error::Class`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithClass(mut self, Class:&str) -> Self {
+		self.Class = Some(Class.to_string());
+
+		self
+	}
+
+	/// Selects entries dead-lettered at least some time ago.
+	///
+	/// # Arguments
+	///
+	/// * `Age` - The minimum time in the dead-letter queue.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOlderThan(mut self, Age:Duration) -> Self {
+		self.OlderThanMs = Some(Age.as_millis() as u64);
+
+		self
+	}
+
+	/// Selects entries dead-lettered at most some time ago.
+	///
+	/// # Arguments
+	///
+	/// * `Age` - The maximum time in the dead-letter queue.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithNewerThan(mut self, Age:Duration) -> Self {
+		self.NewerThanMs = Some(Age.as_millis() as u64);
+
+		self
+	}
+
+	/// Checks whether the filter selects an entry.
+	///
+	/// # Arguments
+	///
+	/// * `Entry` - The entry to check.
+	///
+	/// # Returns
+	///
+	/// `true` if every criterion set holds for the entry.
+	pub fn Match(&self, Entry:&Entry) -> bool {
+		self.Action
+			.as_ref()
+			.is_none_or(|Action| Entry.Action.as_ref() == Some(Action))
+			&& self
+				.Class
+				.as_ref()
+				.is_none_or(|Class| Entry.Class.as_ref() == Some(Class))
+			&& self.OlderThanMs.is_none_or(|Age| Entry.AgeMs >= Age)
+			&& self.NewerThanMs.is_none_or(|Age| Entry.AgeMs <= Age)
+	}
+}
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Sequence::DeadLetter::Entry::Struct as Entry;
@@ -0,0 +1,88 @@
+/// A glob-style pattern over names such as hook names, compiled once.
+///
+/// `*` matches any run of characters, including none, and `?` matches
+/// exactly one character; every other character matches itself.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The pattern as written.
+	Source:String,
+
+	/// The parts of the pattern between its `*`s, as characters.
+	Segment:Vec<Vec<char>>,
+}
+
+impl Struct {
+	/// Compiles a pattern.
+	///
+	/// # Arguments
+	///
+	/// * `Pattern` - The pattern, e.g. `audit.*`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Pattern:&str) -> Self {
+		Struct {
+			Source:Pattern.to_string(),
+			Segment:Pattern.split('*').map(|Segment| Segment.chars().collect()).collect(),
+		}
+	}
+
+	/// Returns whether a text contains a wildcard, so that it is a pattern
+	/// rather than a plain name.
+	pub fn Is(Text:&str) -> bool { Text.contains(['*', '?']) }
+
+	/// Returns the pattern as written.
+	pub fn Source(&self) -> &str { &self.Source }
+
+	/// Checks whether a name matches the pattern.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name to check.
+	///
+	/// # Returns
+	///
+	/// `true` if the whole name matches.
+	pub fn Match(&self, Name:&str) -> bool {
+		let Name:Vec<char> = Name.chars().collect();
+
+		let (First, Last) = match self.Segment.as_slice() {
+			[Only] => return Self::Fits(Only, &Name),
+			[First, .., Last] => (First, Last),
+			[] => return Name.is_empty(),
+		};
+
+		if Name.len() < First.len() + Last.len()
+			|| !Self::Fits(First, &Name[..First.len()])
+			|| !Self::Fits(Last, &Name[Name.len() - Last.len()..])
+		{
+			return false;
+		}
+
+		let mut Rest = &Name[First.len()..Name.len() - Last.len()];
+
+		// The leftmost place of every fixed-length part leaves the most room
+		// for the parts after it
+		for Middle in &self.Segment[1..self.Segment.len() - 1] {
+			let Found = (0..(Rest.len() + 1).saturating_sub(Middle.len()))
+				.find(|Start| Self::Fits(Middle, &Rest[*Start..*Start + Middle.len()]));
+
+			match Found {
+				Some(Start) => Rest = &Rest[Start + Middle.len()..],
+				None => return false,
+			}
+		}
+
+		true
+	}
+
+	/// Checks whether a part of a pattern matches a text of its length.
+	fn Fits(Segment:&[char], Text:&[char]) -> bool {
+		Segment.len() == Text.len()
+			&& Segment
+				.iter()
+				.zip(Text)
+				.all(|(Pattern, Character)| *Pattern == '?' || Pattern == Character)
+	}
+}
@@ -0,0 +1,253 @@
+/// A description of a graph of actions, for rendering before and during
+/// execution: its nodes, each with the state of its action, and the edges
+/// between them.
+///
+/// Describe a graph with `WithNode` and `WithEdge`, or read the chain of an
+/// action with `Chain`; follow its execution with `Dag`. Nodes and edges
+/// keep the order they were added in, so a graph in the same state always
+/// serializes and renders the same.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The nodes, in the order they were added.
+	pub Node:Vec<Node::Struct>,
+
+	/// The edges, each between two of the nodes.
+	pub Edge:Vec<Edge::Struct>,
+}
+
+impl Struct {
+	/// Creates an empty graph.
+	pub fn New() -> Self { Self::default() }
+
+	/// Adds a pending node, or renames the action of the node with its
+	/// identifier.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the node, the `Node` metadata of its
+	///   action.
+	/// * `Action` - The name of the action.
+	pub fn WithNode(mut self, Id:&str, Action:&str) -> Self {
+		match self.Node.iter_mut().find(|Node| Node.Id == Id) {
+			Some(Node) => Node.Action = Action.to_string(),
+			None => self.Node.push(Node::Struct::New(Id, Action)),
+		}
+
+		self
+	}
+
+	/// Adds an edge between two nodes already added.
+	///
+	/// # Arguments
+	///
+	/// * `From` - The identifier of the node running first.
+	/// * `To` - The identifier of the node running after it.
+	///
+	/// # Returns
+	///
+	/// The graph, or an error naming the node missing from it.
+	pub fn WithEdge(self, From:&str, To:&str) -> Result<Self, String> { self.Link(From, To, None) }
+
+	/// Describes the chain of an action: a node for the action and one for
+	/// every follow-up under its `OnSuccess`, `NextAction` and `OnFailure`
+	/// metadata, each linked from the node it follows by an edge labelled
+	/// with its key.
+	///
+	/// Nodes are identified as `Execute` reports them: the action by its
+	/// `Node` metadata, `0` when absent, and a follow-up by its own or else
+	/// its `Path`. Follow-ups the action would take from the defaults of its
+	/// plan are not described.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The first action of the chain.
+	///
+	/// # Returns
+	///
+	/// The graph of the chain, or an error naming a follow-up that is not an
+	/// action.
+	pub fn Chain<T:Send + Sync>(Action:&Action<T>) -> Result<Self, String> {
+		let mut Graph = Self::New();
+
+		// The follow-ups still to describe, each with the edge leading to it
+		let mut Stack:Vec<(Option<Edge::Struct>, Map<String, Value>)> =
+			vec![(None, Action.Metadata.Snapshot())];
+
+		while let Some((Edge, Metadata)) = Stack.pop() {
+			let Id = match (&Edge, Metadata.get("Node")) {
+				(Some(Edge), _) => Edge.To.clone(),
+				(None, Some(Value::String(Id))) => Id.clone(),
+				(None, _) => "0".to_string(),
+			};
+
+			let Name = Metadata
+				.get("Action")
+				.and_then(Value::as_str)
+				.ok_or_else(|| format!("The action of node {} names no Action", Id))?;
+
+			Graph = Graph.WithNode(&Id, Name);
+
+			if let Some(Edge) = Edge {
+				Graph = Graph.Link(&Edge.From, &Edge.To, Edge.Label.as_deref())?;
+			}
+
+			// Pushed last to first, so nodes are added in key and array order
+			for Key in BRANCH.iter().rev() {
+				let Follow = match Metadata.get(*Key) {
+					Some(Value::Array(Follow)) => Follow.clone(),
+					Some(Next) => vec![Next.clone()],
+					None => continue,
+				};
+
+				for (Index, Next) in Follow.into_iter().enumerate().rev() {
+					let Value::Object(Next) = Next else {
+						return Err(format!("{} of node {} is not an object", Key, Id));
+					};
+
+					let To = match Next.get("Node") {
+						Some(Value::String(To)) => To.clone(),
+						_ => Path(&Id, Key, Index),
+					};
+
+					let Edge = Edge::Struct { From:Id.clone(), To, Label:Some(Key.to_string()) };
+
+					Stack.push((Some(Edge), Next));
+				}
+			}
+		}
+
+		Ok(Graph)
+	}
+
+	/// Returns the node with an identifier, if the graph has one.
+	pub fn Get(&self, Id:&str) -> Option<&Node::Struct> {
+		self.Node.iter().find(|Node| Node.Id == Id)
+	}
+
+	/// Renders the graph in the DOT language of Graphviz, for debugging:
+	/// each node is labelled with its action, state and duration, each edge
+	/// with its label.
+	///
+	/// # Returns
+	///
+	/// The `digraph`, the same for the same graph.
+	pub fn ToDot(&self) -> String {
+		let mut Dot = String::from("digraph {\n\tnode [shape=box];\n");
+
+		for Node in &self.Node {
+			let mut Label = format!("{}\n{}", Node.Action, Node.State.Name());
+
+			if let Some(DurationMs) = Node.DurationMs {
+				Label.push_str(&format!(" {}ms", DurationMs));
+			}
+
+			Dot.push_str(&format!("\t{} [label={}];\n", Quote(&Node.Id), Quote(&Label)));
+		}
+
+		for Edge in &self.Edge {
+			Dot.push_str(&format!("\t{} -> {}", Quote(&Edge.From), Quote(&Edge.To)));
+
+			if let Some(Label) = &Edge.Label {
+				Dot.push_str(&format!(" [label={}]", Quote(Label)));
+			}
+
+			Dot.push_str(";\n");
+		}
+
+		Dot.push('}');
+
+		Dot
+	}
+
+	/// Records that a node entered a state, published as `Event::Node`.
+	///
+	/// A node that succeeded skips the pending nodes reached only through
+	/// its `OnFailure` edges, and one that failed those reached through its
+	/// other edges. An unknown node is ignored.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the node.
+	/// * `State` - The state it entered.
+	/// * `DurationMs` - How long its action ran, once it ended.
+	pub fn Apply(&mut self, Id:&str, State:State, DurationMs:Option<u64>) {
+		let Some(Node) = self.Node.iter_mut().find(|Node| Node.Id == Id) else {
+			return;
+		};
+
+		Node.State = State;
+
+		Node.DurationMs = DurationMs.or(Node.DurationMs);
+
+		let Failed = match State {
+			State::Done => false,
+			State::Failed => true,
+			_ => return,
+		};
+
+		let mut Stack = self
+			.Edge
+			.iter()
+			.filter(|Edge| Edge.From == Id && (Edge.Label.as_deref() == Some("OnFailure")) != Failed)
+			.map(|Edge| Edge.To.clone())
+			.collect::<Vec<_>>();
+
+		while let Some(Id) = Stack.pop() {
+			let Some(Node) =
+				self.Node.iter_mut().find(|Node| Node.Id == Id && Node.State == State::Pending)
+			else {
+				continue;
+			};
+
+			Node.State = State::Skipped;
+
+			Stack.extend(self.Edge.iter().filter(|Edge| Edge.From == Id).map(|Edge| Edge.To.clone()));
+		}
+	}
+
+	/// Adds an edge between two nodes already added, with a label.
+	fn Link(mut self, From:&str, To:&str, Label:Option<&str>) -> Result<Self, String> {
+		for Id in [From, To] {
+			if self.Get(Id).is_none() {
+				return Err(format!("Edge from {} to {} names an unknown node {}", From, To, Id));
+			}
+		}
+
+		self.Edge.push(Edge::Struct {
+			From:From.to_string(),
+			To:To.to_string(),
+			Label:Label.map(str::to_string),
+		});
+
+		Ok(self)
+	}
+}
+
+/// Names a follow-up of a node, e.g. `0/OnSuccess/1` for the second
+/// `OnSuccess` follow-up of the root.
+///
+/// # Arguments
+///
+/// * `Parent` - The identifier of the node it follows.
+/// * `Key` - The metadata key it was read from.
+/// * `Index` - Its position under the key, 0 for a single follow-up.
+pub fn Path(Parent:&str, Key:&str, Index:usize) -> String {
+	format!("{}/{}/{}", Parent, Key, Index)
+}
+
+/// Quotes an identifier for DOT, escaping quotes, backslashes and newlines.
+fn Quote(Text:&str) -> String {
+	format!("\"{}\"", Text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::Sequence::Graph::State::Enum as State,
+	Struct::Sequence::Action::{Struct as Action, BRANCH},
+};
+
+pub mod Dag;
+pub mod Edge;
+pub mod Node;
@@ -0,0 +1,63 @@
+/// A graph whose node states follow the execution of its actions, from the
+/// `Event::Node` events of the bus of their `Life`.
+///
+/// An action is a node of the graph when its `Graph` metadata names it and
+/// its `Node` metadata the node; the follow-ups of its chain are named by
+/// `Execute`, as `Graph::Chain` names them. Create the dag before its
+/// actions run, as it only sees the events published after.
+pub struct Struct {
+	/// The name of the graph in the `Graph` metadata of its actions.
+	Name:String,
+
+	/// The graph and the events not yet applied to it, under one lock so an
+	/// export never sees half an update.
+	Tracked:Mutex<(Graph, Subscription)>,
+}
+
+impl Struct {
+	/// Starts following the execution of a graph.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the graph.
+	/// * `Graph` - The nodes and edges of the graph, all pending.
+	/// * `Context` - The context whose actions run the graph.
+	pub fn New(Name:&str, Graph:Graph, Context:&Life) -> Self {
+		Struct { Name:Name.to_string(), Tracked:Mutex::new((Graph, Context.Subscribe())) }
+	}
+
+	/// Exports the graph as it is now: the events published so far are
+	/// applied to it first, so repeated exports follow the execution.
+	///
+	/// A dag exported less often than the bus buffers events misses some
+	/// of them, counted as dropped by the bus, and keeps stale states.
+	///
+	/// # Returns
+	///
+	/// A copy of the graph, every edge between two of its nodes.
+	pub fn Export(&self) -> Graph {
+		let mut Tracked = self.Tracked.lock().unwrap_or_else(|Poisoned| Poisoned.into_inner());
+
+		let (Graph, Subscription) = &mut *Tracked;
+
+		while let Some(Event) = Subscription.TryRecv() {
+			if let Event::Node { Graph:Name, Node, State, DurationMs } = Event {
+				if Name == self.Name {
+					Graph.Apply(&Node, State, DurationMs);
+				}
+			}
+		}
+
+		Graph.clone()
+	}
+}
+
+use std::sync::Mutex;
+
+use crate::{
+	Enum::Event::Enum as Event,
+	Struct::{
+		Event::Subscription::Struct as Subscription,
+		Sequence::{Graph::Struct as Graph, Life::Struct as Life},
+	},
+};
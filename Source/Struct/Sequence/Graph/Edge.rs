@@ -0,0 +1,15 @@
+/// An edge of a `Graph`: its `To` node runs after its `From` node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The identifier of the node running first.
+	pub From:String,
+
+	/// The identifier of the node running after it.
+	pub To:String,
+
+	/// The follow-up key of a chain the edge stands for, e.g. `OnFailure`.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Label:Option<String>,
+}
+
+use serde::{Deserialize, Serialize};
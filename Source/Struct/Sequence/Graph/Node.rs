@@ -0,0 +1,32 @@
+/// A node of a `Graph`: one action and where it is in its execution.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The identifier of the node, unique in its graph.
+	pub Id:String,
+
+	/// The name of the action.
+	pub Action:String,
+
+	/// Where the action is in its execution.
+	pub State:State,
+
+	/// How long the action ran, in milliseconds, once it ended.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub DurationMs:Option<u64>,
+}
+
+impl Struct {
+	/// Creates a pending node.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the node.
+	/// * `Action` - The name of the action.
+	pub fn New(Id:&str, Action:&str) -> Self {
+		Struct { Id:Id.to_string(), Action:Action.to_string(), State:State::Pending, DurationMs:None }
+	}
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Sequence::Graph::State::Enum as State;
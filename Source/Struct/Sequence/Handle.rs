@@ -0,0 +1,75 @@
+/// A sequence run as a component of a `Supervisor`.
+///
+/// Starting the handle spawns `RunKarma` on the sequence. Shutting it down
+/// sets the sequence's `Time` signal and waits for the actions already
+/// running to finish, up to the deadline.
+pub struct Struct {
+	/// The sequence being run.
+	Sequence:Arc<Sequence>,
+
+	/// The task running the sequence, once started.
+	Task:Option<Spawned::Struct>,
+}
+
+impl Struct {
+	/// Creates a handle without starting the sequence.
+	///
+	/// # Arguments
+	///
+	/// * `Sequence` - The sequence to run.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Sequence:Arc<Sequence>) -> Self { Struct { Sequence, Task:None } }
+
+	/// Returns the sequence being run.
+	pub fn Sequence(&self) -> &Arc<Sequence> { &self.Sequence }
+}
+
+#[async_trait]
+impl Component for Struct {
+	async fn Start(&mut self) -> Result<(), String> {
+		if self.Task.is_none() {
+			let (Sequence, Runtime) = (self.Sequence.clone(), self.Sequence.Life.Runtime());
+
+			self.Task = Some(Runtime.Spawn(Box::pin(async move { Sequence.RunKarma().await })));
+		}
+
+		Ok(())
+	}
+
+	async fn Shutdown(&mut self, Deadline:Instant) -> Result<(), String> {
+		self.Sequence.Shutdown().await;
+
+		let Some(Task) = self.Task.as_mut() else {
+			return Ok(());
+		};
+
+		let Runtime = self.Sequence.Life.Runtime();
+
+		let Finished = match Runtime.TimeoutAt(Deadline, &mut *Task).await {
+			Some(Finished) => Finished.map_err(|_Error| _Error.to_string()),
+			None => {
+				Task.Abort();
+
+				Err("Sequence did not drain before its deadline".to_string())
+			},
+		};
+
+		self.Task = None;
+
+		Finished
+	}
+}
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::{
+	Struct::{
+		Runtime::Spawned,
+		Sequence::{Arc, Struct as Sequence},
+	},
+	Trait::Supervisor::Component::Trait as Component,
+};
@@ -0,0 +1,443 @@
+/// The execution context of one attempt of an action.
+///
+/// Sequences run every attempt inside an invocation scope, so plan functions
+/// and handlers retrieve it with `Current` without changing their
+/// signatures. The scope is task-local: work the function spawns onto other
+/// tasks must carry a clone along.
+#[derive(Clone)]
+pub struct Struct {
+	/// The identifier of the action: its sequence number in the production
+	/// line it was dequeued from.
+	Id:u64,
+
+	/// The number of the attempt, starting at 1.
+	Attempt:u32,
+
+	/// The instant by which the attempt should finish, if any.
+	Deadline:Option<Instant>,
+
+	/// Whether the attempt has been cancelled.
+	Cancelled:Arc<AtomicBool>,
+
+	/// The value the action produced, once recorded.
+	Output:Arc<Mutex<Option<Value>>>,
+
+	/// The attempts of the action before this one.
+	History:Arc<History>,
+
+	/// The output stream of the attempt, until a handler takes it.
+	Sink:Arc<Mutex<Option<Sink>>>,
+
+	/// Where the attempt streams its output and how many bytes it wrote, if
+	/// the action declared a destination.
+	Streamed:Option<(Destination, Arc<AtomicU64>)>,
+
+	/// The annotations written during the attempt, shared by every clone.
+	Annotation:Arc<Vector>,
+
+	/// Where the function reports how far it got, if the action exposes
+	/// its progress.
+	Progress:Option<Signal<f32>>,
+
+	/// The runtime of the `Life` running the attempt, if set.
+	Runtime:Option<Arc<dyn Runtime>>,
+}
+
+impl Debug for Struct {
+	fn fmt(&self, Formatter:&mut Formatter<'_>) -> fmt::Result {
+		Formatter
+			.debug_struct("Invocation")
+			.field("Id", &self.Id)
+			.field("Attempt", &self.Attempt)
+			.field("Deadline", &self.Deadline)
+			.field("Cancelled", &self.Cancelled)
+			.field("Output", &self.Output)
+			.field("History", &self.History)
+			.field("Streamed", &self.Streamed)
+			.field("Annotation", &self.Annotation)
+			.field("Progress", &self.Progress)
+			.finish_non_exhaustive()
+	}
+}
+
+/// The metadata keys annotations may not overwrite, as the sequence and
+/// transports rely on them.
+pub const RESERVED:[&str; 8] =
+	["Id", "ActionType", "Action", "Argument", "License", "Queue", "SubmittedBy", "Result"];
+
+impl Struct {
+	/// Creates a new invocation without a deadline.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the action.
+	/// * `Attempt` - The number of the attempt, starting at 1.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Id:u64, Attempt:u32) -> Self {
+		Struct {
+			Id,
+			Attempt,
+			Deadline:None,
+			Cancelled:Arc::new(AtomicBool::new(false)),
+			Output:Arc::new(Mutex::new(None)),
+			History:Arc::new(History::default()),
+			Sink:Arc::new(Mutex::new(None)),
+			Streamed:None,
+			Annotation:Arc::new(Vector::New()),
+			Progress:None,
+			Runtime:None,
+		}
+	}
+
+	/// Sets the attempts of the action before this one.
+	///
+	/// # Arguments
+	///
+	/// * `History` - The attempts made so far.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithHistory(mut self, History:History) -> Self {
+		self.History = Arc::new(History);
+
+		self
+	}
+
+	/// Sets the deadline, keeping an earlier one if already set.
+	///
+	/// # Arguments
+	///
+	/// * `Deadline` - The instant by which the attempt should finish.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDeadline(mut self, Deadline:Instant) -> Self {
+		self.Deadline = Some(self.Deadline.map_or(Deadline, |Current| Current.min(Deadline)));
+
+		self
+	}
+
+	/// Sets the output stream of the attempt.
+	///
+	/// # Arguments
+	///
+	/// * `Destination` - Where the stream leads.
+	/// * `Sink` - The stream handed to the handler.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithSink(mut self, Destination:Destination, Sink:Sink) -> Self {
+		self.Streamed = Some((Destination, Sink.Counter()));
+
+		self.Sink = Arc::new(Mutex::new(Some(Sink)));
+
+		self
+	}
+
+	/// Sets where the function reports its progress.
+	///
+	/// # Arguments
+	///
+	/// * `Progress` - The progress signal of the action, from 0.0 to 1.0.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithProgress(mut self, Progress:Signal<f32>) -> Self {
+		self.Progress = Some(Progress);
+
+		self
+	}
+
+	/// Sets the runtime of the `Life` running the attempt, which plan
+	/// functions spawn, sleep and time out with through `Runtime`.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Some(Runtime);
+
+		self
+	}
+
+	/// Derives the deadline from an action's metadata.
+	///
+	/// `Timeout` is a `Time::Duration` granted to each attempt; `Deadline`
+	/// is a `Time::Timestamp`. When both are present the earlier one applies.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action whose metadata is read.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub async fn WithMetadata(mut self, Action:&dyn Action) -> Self {
+		if let Some(Timeout) = Metadata::<Span>(Action, "Timeout").await {
+			self = self.WithDeadline(Instant::now() + Timeout.Get());
+		}
+
+		if let Some(Deadline) = Metadata::<Timestamp>(Action, "Deadline").await {
+			self = self.WithDeadline(Instant::now() + Deadline.Left());
+		}
+
+		self
+	}
+
+	/// Runs a future inside this invocation's scope.
+	///
+	/// # Arguments
+	///
+	/// * `Future` - The future to run.
+	///
+	/// # Returns
+	///
+	/// The output of the future.
+	pub async fn Scope<F:Future>(self, Future:F) -> F::Output { Active.scope(self, Future).await }
+
+	/// Returns the invocation of the current task, if any.
+	pub fn Current() -> Option<Self> { Active.try_with(Clone::clone).ok() }
+
+	/// Returns the runtime of the current invocation, which built-in
+	/// functions spawn, sleep and time out with.
+	///
+	/// # Returns
+	///
+	/// The runtime of the `Life` running the attempt, or tokio's outside an
+	/// invocation or when none was set.
+	pub fn Runtime() -> Arc<dyn Runtime> {
+		Self::Current()
+			.and_then(|Current| Current.Runtime)
+			.unwrap_or_else(|| Arc::new(Tokio::Struct))
+	}
+
+	/// Bounds a timeout by the time remaining in the current invocation.
+	///
+	/// # Arguments
+	///
+	/// * `Timeout` - The timeout an operation would use on its own.
+	///
+	/// # Returns
+	///
+	/// The smaller of `Timeout` and the remaining time, or `Timeout` outside
+	/// an invocation or without a deadline.
+	pub fn Bound(Timeout:Duration) -> Duration {
+		Self::Current()
+			.and_then(|Current| Current.RemainingTime())
+			.map_or(Timeout, |Left| Left.min(Timeout))
+	}
+
+	/// Records the value produced by the action of the current invocation.
+	///
+	/// The first recorded value wins, so the value of an action's own
+	/// function is kept when it goes on to run a `NextAction`. Outside an
+	/// invocation this does nothing.
+	///
+	/// # Arguments
+	///
+	/// * `Value` - The value produced by the action.
+	pub fn Record(Value:Value) {
+		let _ = Active.try_with(|Current| {
+			Current
+				.Output
+				.lock()
+				.unwrap_or_else(|Poison| Poison.into_inner())
+				.get_or_insert(Value);
+		});
+	}
+
+	/// Returns the value recorded by the action, if any.
+	pub fn Output(&self) -> Option<Value> {
+		self.Output
+			.lock()
+			.unwrap_or_else(|Poison| Poison.into_inner())
+			.clone()
+	}
+
+	/// Takes the output stream of the attempt, opened on the destination the
+	/// action declared in its `OutputTo` metadata.
+	///
+	/// # Returns
+	///
+	/// The stream, or `None` if the action declared no destination or the
+	/// stream was taken already.
+	pub fn OutputSink(&self) -> Option<Sink> {
+		self.Sink.lock().unwrap_or_else(|Poison| Poison.into_inner()).take()
+	}
+
+	/// Describes the output streamed by the attempt, in place of its
+	/// content.
+	///
+	/// # Returns
+	///
+	/// An object with the `OutputTo` destination and the `Bytes` written, or
+	/// `None` if the action declared no destination.
+	pub fn Streamed(&self) -> Option<Value> {
+		self.Streamed.as_ref().map(|(Destination, Written)| {
+			json!({ "OutputTo": Destination, "Bytes": Written.load(Ordering::Relaxed) })
+		})
+	}
+
+	/// Annotates the action with a value, e.g. its progress.
+	///
+	/// Handlers, hooks and the tasks they spawn may annotate concurrently.
+	/// Once the attempt ends the annotations are written into the action's
+	/// metadata and carried by its `Completed` event and result.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key, not one of `RESERVED`.
+	/// * `Value` - The value stored under `Key`, replacing any earlier one.
+	///
+	/// # Returns
+	///
+	/// `Ok(())`, or `Error::Execution` if the key is reserved.
+	pub fn Annotate(&self, Key:&str, Value:Value) -> Result<(), Error> {
+		Self::Writable(Key)?;
+
+		self.Annotation.Insert(Key.to_string(), Value);
+
+		Ok(())
+	}
+
+	/// Adds to a counter annotation in one step, so concurrent increments
+	/// are never lost.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key of the counter, not one of `RESERVED`.
+	/// * `By` - The amount to add; a missing counter starts at zero.
+	///
+	/// # Returns
+	///
+	/// The counter after the increment, or `Error::Execution` if the key is
+	/// reserved.
+	pub fn IncrementCounter(&self, Key:&str, By:i64) -> Result<i64, Error> {
+		Self::Writable(Key)?;
+
+		Ok(self.Annotation.Increment(Key, By))
+	}
+
+	/// Returns the annotations written during the attempt so far.
+	pub fn Annotations(&self) -> Map<String, Value> { self.Annotation.Snapshot() }
+
+	/// Refuses the keys annotations may not overwrite, including every key
+	/// under the prefix of `Reserved`.
+	fn Writable(Key:&str) -> Result<(), Error> {
+		if RESERVED.contains(&Key) || Key.starts_with(PREFIX) {
+			return Err(Error::Execution(format!("Metadata key {} is reserved", Key)));
+		}
+
+		Ok(())
+	}
+
+	/// Returns the identifier of the action.
+	pub fn ActionId(&self) -> u64 { self.Id }
+
+	/// Returns the number of the attempt, starting at 1.
+	pub fn Attempt(&self) -> u32 { self.Attempt }
+
+	/// Returns the attempts of the action before this one.
+	pub fn History(&self) -> &History { &self.History }
+
+	/// Returns the progress signal of the action, if it exposes one.
+	pub fn Progress(&self) -> Option<Signal<f32>> { self.Progress.clone() }
+
+	/// Returns the instant by which the attempt should finish, if any.
+	pub fn Deadline(&self) -> Option<Instant> { self.Deadline }
+
+	/// Returns the time left until the deadline, if any; zero once it passed.
+	pub fn RemainingTime(&self) -> Option<Duration> {
+		self.Deadline
+			.map(|Deadline| Deadline.saturating_duration_since(Instant::now()))
+	}
+
+	/// Returns whether the attempt was cancelled or its deadline passed.
+	pub fn IsCancelled(&self) -> bool {
+		self.Cancelled.load(Ordering::Relaxed) || self.RemainingTime() == Some(Duration::ZERO)
+	}
+
+	/// Cancels the attempt; every clone of the invocation observes it.
+	pub fn Cancel(&self) { self.Cancelled.store(true, Ordering::Relaxed); }
+
+	/// Yields to the scheduler, then checks whether the attempt should go on.
+	///
+	/// Functions doing long CPU-bound loops call it every so often, so the
+	/// other tasks of their worker get to run and a cancelled or expired
+	/// attempt ends promptly instead of running to completion. A loop that
+	/// cannot yield at all belongs in a `Blocking` function.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` to continue, `Error::Cancellation` once the attempt was
+	/// cancelled, or `Error::DeadlineExceeded` once its deadline passed.
+	pub async fn Checkpoint(&self) -> Result<(), Error> {
+		yield_now().await;
+
+		if self.Cancelled.load(Ordering::Relaxed) {
+			return Err(Error::Cancellation(format!(
+				"Attempt {} of action {} was cancelled",
+				self.Attempt, self.Id
+			)));
+		}
+
+		if self.RemainingTime() == Some(Duration::ZERO) {
+			return Err(Error::DeadlineExceeded(format!(
+				"Attempt {} of action {} ran past its deadline",
+				self.Attempt, self.Id
+			)));
+		}
+
+		Ok(())
+	}
+}
+
+tokio::task_local! {
+	/// The invocation of the attempt running on the current task.
+	#[allow(non_upper_case_globals)]
+	static Active: Struct;
+}
+
+use std::{
+	fmt::{self, Debug, Formatter},
+	future::Future,
+	sync::{
+		atomic::{AtomicBool, AtomicU64, Ordering},
+		Arc,
+		Mutex,
+	},
+	time::Duration,
+};
+
+use serde_json::{json, Map, Value};
+use tokio::{task::yield_now, time::Instant};
+
+use crate::{
+	Enum::Sequence::{
+		Action::Error::Enum as Error,
+		Destination::Enum as Destination,
+		Reserved::PREFIX,
+	},
+	Struct::{
+		Runtime::Tokio,
+		Sequence::{
+			Attempt::History::Struct as History,
+			Signal::Struct as Signal,
+			Sink::Struct as Sink,
+			Vector::Struct as Vector,
+		},
+	},
+	Time::{Duration::Struct as Span, Metadata, Timestamp::Struct as Timestamp},
+	Trait::{Runtime::Trait as Runtime, Sequence::Action::Trait as Action},
+};
@@ -0,0 +1,2 @@
+pub mod AlwaysValid;
+pub mod Expiry;
@@ -0,0 +1,16 @@
+/// Accepts the license of every action.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+#[async_trait]
+impl Validator for Struct {
+	async fn Validate(&self, _Metadata:&Vector) -> Result<(), Error> { Ok(()) }
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Vector::Struct as Vector,
+	Trait::Sequence::LicenseValidator::Trait as Validator,
+};
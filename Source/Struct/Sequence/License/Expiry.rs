@@ -0,0 +1,46 @@
+/// Accepts the license of an action until the `Expires` timestamp of its
+/// metadata, a `Time::Timestamp` in RFC 3339 or Unix milliseconds.
+///
+/// An action without `Expires`, or with one that is not a timestamp, is
+/// refused.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+impl Struct {
+	/// Creates a validator reading `Expires`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Struct }
+}
+
+#[async_trait]
+impl Validator for Struct {
+	async fn Validate(&self, Metadata:&Vector) -> Result<(), Error> {
+		let Expires = Metadata
+			.Get("Expires")
+			.await
+			.ok_or_else(|| Error::InvalidLicense("License names no Expires".to_string()))?;
+
+		let Expires = Timestamp::deserialize(&Expires).map_err(|_Error| {
+			Error::InvalidLicense(format!("Invalid Expires metadata {}: {}", Expires, _Error))
+		})?;
+
+		if Expires.Passed() {
+			return Err(Error::InvalidLicense(format!("License expired at {}", Expires)));
+		}
+
+		Ok(())
+	}
+}
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Vector::Struct as Vector,
+	Time::Timestamp::Struct as Timestamp,
+	Trait::Sequence::LicenseValidator::Trait as Validator,
+};
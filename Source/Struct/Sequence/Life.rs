@@ -6,22 +6,1205 @@ pub struct Struct {
 	/// during execution.
 	pub Span:Arc<DashMap<String, crate::Type::Sequence::Action::Cycle::Type>>,
 
+	/// The hooks registered under name patterns with `AddHookPattern`, by
+	/// pattern, each compiled once.
+	pub Pattern:Arc<DashMap<String, (Glob::Struct, crate::Type::Sequence::Action::Pattern::Type)>>,
+
 	/// A shared reference to the configuration settings.
 	/// This allows for runtime access to various configuration parameters.
 	pub Fate:Arc<Config>,
 
 	/// A thread-safe cache for storing arbitrary JSON values.
 	/// This cache can be used for temporary storage of data during action
-	/// execution.
-	pub Cache:Arc<crate::Struct::Sequence::Mutex<DashMap<String, serde_json::Value>>>,
+	/// execution. It is bounded by the `[stores.cache]` limits and evicts
+	/// its least recently used entries.
+	pub Cache:Arc<Store::Struct<String, serde_json::Value>>,
+
+	/// The results of the actions whose signature is `Cacheable`, bounded
+	/// by the `[stores.memo]` limits.
+	pub Memo:Arc<Memo::Struct>,
 
 	/// A thread-safe map of production queues, identified by string keys.
 	/// Each production queue (represented by `Production`) can hold a series
 	/// of actions to be executed.
 	pub Karma:Arc<DashMap<String, Arc<crate::Struct::Sequence::Production::Struct>>>,
+
+	/// The factory creating Karma queues for actions routed to a missing
+	/// one, unset unless built with `Builder::WithQueueFactory`.
+	pub Dynamic:Arc<Dynamic::Struct>,
+
+	/// The gate holding the actions of identities whose result consumers
+	/// fall behind, pressed by pumps built with `WithBackpressure`.
+	pub Backpressure:Arc<Backpressure::Struct>,
+
+	/// The pause of the Karma consumers while the queued actions are handed
+	/// off to another process.
+	pub Handoff:Arc<Handoff::Struct>,
+
+	/// The event bus on which queues, sequences and transports publish
+	/// lifecycle events.
+	pub Bus:Bus::Struct,
+
+	/// How each Karma queue is consumed, keyed by lowercase queue name.
+	/// Queues without an entry use the default settings.
+	pub Settings:Arc<DashMap<String, Settings>>,
+
+	/// The registry of counters that sequences and transports of this
+	/// context update, read by `Stats`.
+	pub Registry:Arc<Registry::Struct>,
+
+	/// The cost of the executed actions by action type, recorded by
+	/// sequences and bounded as configured by `[cost]`.
+	pub Cost:Arc<Cost::Struct>,
+
+	/// The tooling that lists, requeues and purges the entries of the
+	/// dead-letter queues among `Karma`.
+	pub DeadLetter:Arc<DeadLetter::Struct>,
+
+	/// The watchdog flagging executions that run too long, configured by
+	/// `[watchdog]`.
+	pub Watchdog:Arc<Watchdog::Struct>,
+
+	/// The history of the submissions received by the transports serving
+	/// this context, configured by `[submissions]`.
+	pub History:Arc<History::Struct>,
+
+	/// The health checks evaluated by `Health`, by name.
+	pub Check:Arc<DashMap<String, Arc<dyn Check>>>,
+
+	/// The source of random choices such as retry jitter, seeded from the
+	/// thread RNG unless built with `Builder::WithRngSeed`.
+	pub Randomness:Arc<Randomness::Struct>,
+
+	/// The timer service holding actions whose `Delay` has not elapsed, so
+	/// they do not occupy a sequence while they wait.
+	pub Timer:Arc<Timer::Struct>,
+
+	/// The registry tracking the lifecycle of every action in the Karma
+	/// queues, swept as configured by `[registry]` when built or reloaded.
+	pub Actions:Arc<ActionRegistry::Struct>,
+
+	/// The overseer running internal tasks such as the timer service, the
+	/// registry sweeps and the Karma consumers.
+	pub Overseer:Arc<Overseer::Struct>,
+
+	/// What an action does with a hook reference matching no hook, if set
+	/// with `Builder::WithMissingHook`; `hooks.missing` applies otherwise.
+	pub MissingHook:Option<MissingHook>,
+
+	/// Validates the license of every action before it runs, if set with
+	/// `Builder::WithLicenseValidator`; the `License` signal of each action
+	/// decides otherwise.
+	pub License:Option<Arc<dyn LicenseValidator>>,
+
+	/// The admission policies every submission goes through, in order, as
+	/// added with `Builder::WithAdmissionPolicy`; see `Admit`.
+	pub Admission:Arc<Vec<Arc<dyn AdmissionPolicy>>>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` with empty spans, cache and queues.
+	///
+	/// # Arguments
+	///
+	/// * `Fate` - The configuration settings.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance with a default event bus.
+	pub fn New(Fate:Arc<Config>) -> Self {
+		let Bus = Bus::Struct::default();
+
+		let Overseer = Arc::new(Overseer::Struct::New(Bus.clone()));
+
+		let Karma = Arc::new(DashMap::new());
+
+		let DeadLetter = Arc::new(DeadLetter::Struct::New(Karma.clone(), Bus.clone()));
+
+		DeadLetter.Bound(Self::Limit(&Fate, "requeued"));
+
+		let Watchdog = Arc::new(Watchdog::Struct::New(Bus.clone(), Overseer.clone()));
+
+		Struct {
+			Span:Arc::new(DashMap::new()),
+			Pattern:Arc::new(DashMap::new()),
+			Cache:Arc::new(Store::Struct::New("cache", Self::Limit(&Fate, "cache"))),
+			Memo:Arc::new(Memo::Struct::New(Self::Limit(&Fate, "memo"))),
+			Fate,
+			Karma,
+			Dynamic:Arc::new(Dynamic::Struct::New()),
+			Backpressure:Arc::new(Backpressure::Struct::New()),
+			Handoff:Arc::new(Handoff::Struct::New()),
+			Bus,
+			Settings:Arc::new(DashMap::new()),
+			Registry:Arc::new(Registry::Struct::New()),
+			Cost:Arc::new(Cost::Struct::New(32, Overseer.clone())),
+			DeadLetter,
+			Watchdog,
+			History:Arc::new(History::Struct::New(Overseer.clone())),
+			Check:Arc::new(DashMap::new()),
+			Randomness:Arc::new(Randomness::Struct::New()),
+			Timer:Arc::new(Timer::Struct::New(Overseer.clone())),
+			Actions:Arc::new(ActionRegistry::Struct::New(
+				Duration::from_secs(60),
+				Duration::from_secs(5),
+				Overseer.clone(),
+			)),
+			Overseer,
+			MissingHook:None,
+			License:None,
+			Admission:Arc::new(Vec::new()),
+		}
+	}
+
+	/// Lists the internal tasks running under the overseer.
+	///
+	/// # Returns
+	///
+	/// The running tasks with their restart counts.
+	pub fn Tasks(&self) -> Vec<Task::Struct> { self.Overseer.Tasks() }
+
+	/// Returns the runtime the tasks of this context are spawned on, set
+	/// with `Builder::WithRuntime`.
+	pub fn Runtime(&self) -> Arc<dyn Runtime> { self.Overseer.Runtime() }
+
+	/// Starts building a `Struct` with named queues and their settings.
+	///
+	/// # Returns
+	///
+	/// A new `Builder`.
+	pub fn Builder() -> Builder::Struct { Builder::Struct::New() }
+
+	/// Returns the settings of a Karma queue.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the queue, matched case-insensitively.
+	///
+	/// # Returns
+	///
+	/// The settings of the queue, or the default settings.
+	pub fn Settings(&self, Queue:&str) -> Settings {
+		self.Settings
+			.get(&Queue.to_lowercase())
+			.map(|Settings| Settings.clone())
+			.unwrap_or_default()
+	}
+
+	/// Stops the consumers of a Karma queue from dequeuing its actions,
+	/// leaving the other queues flowing; the queue still accepts actions.
+	///
+	/// The flag lives in the settings of the queue, so a reloaded
+	/// `[queues.<name>]` section sets it to its `paused` key.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the queue, matched case-insensitively.
+	pub fn PauseQueue(&self, Queue:&str) { self.Flag(Queue, true); }
+
+	/// Lets the consumers of a Karma queue paused by `PauseQueue` dequeue
+	/// its actions again.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The name of the queue, matched case-insensitively.
+	pub fn ResumeQueue(&self, Queue:&str) { self.Flag(Queue, false); }
+
+	/// Lists the paused Karma queues.
+	///
+	/// # Returns
+	///
+	/// The lowercase names of the queues, sorted.
+	pub fn PausedQueues(&self) -> Vec<String> {
+		let mut Paused = self
+			.Settings
+			.iter()
+			.filter(|Entry| Entry.value().Paused)
+			.map(|Entry| Entry.key().clone())
+			.collect::<Vec<_>>();
+
+		Paused.sort();
+
+		Paused
+	}
+
+	/// Sets the paused flag in the settings of a queue.
+	fn Flag(&self, Queue:&str, Paused:bool) {
+		let mut Settings = self.Settings.entry(Queue.to_lowercase()).or_default();
+
+		if Settings.Paused != Paused {
+			Settings.Paused = Paused;
+
+			info!("{} the Karma queue {}", if Paused { "Paused" } else { "Resumed" }, Queue);
+		}
+	}
+
+	/// Summarizes the contents of every Karma queue; see
+	/// `Production::Summary`.
+	///
+	/// Each queue is locked in turn while it is walked, so the summaries may
+	/// be a few operations apart.
+	///
+	/// # Returns
+	///
+	/// The summary of every Karma queue, by name.
+	pub async fn Summary(&self) -> BTreeMap<String, Summary::Struct> {
+		let Karma = self
+			.Karma
+			.iter()
+			.map(|Entry| (Entry.key().clone(), Entry.value().clone()))
+			.collect::<Vec<_>>();
+
+		let mut Summary = BTreeMap::new();
+
+		for (Name, Production) in Karma {
+			Summary.insert(Name, Production.Summary().await);
+		}
+
+		Summary
+	}
+
+	/// Reports what the context is doing right now.
+	///
+	/// Collection only reads counters and never waits on a lock.
+	///
+	/// # Returns
+	///
+	/// A serializable `Snapshot` of queues, sequences, transports and cache.
+	pub fn Stats(&self) -> Snapshot::Struct {
+		let Queue = self
+			.Karma
+			.iter()
+			.map(|Entry| {
+				let Paused = self.Settings(Entry.key()).Paused;
+
+				(Entry.key().clone(), Queue::Struct { Paused, ..Entry.value().Stats() })
+			})
+			.collect::<BTreeMap<_, _>>();
+
+		let DeadLetter = Queue
+			.iter()
+			.filter(|(Name, _)| {
+				self.Settings.iter().any(|Settings| {
+					Settings
+						.DeadLetter
+						.as_deref()
+						.is_some_and(|DeadLetter| DeadLetter.eq_ignore_ascii_case(Name))
+				})
+			})
+			.map(|(_, Stats)| Stats.Depth)
+			.sum();
+
+		Snapshot::Struct {
+			Queue,
+			Sequence:self.Registry.Sequences(),
+			Connection:self
+				.Registry
+				.Transports()
+				.into_iter()
+				.fold(Default::default(), |Sum, Count| Sum + Count),
+			Cache:Some(self.Cache.Len()),
+			DeadLetter,
+			Cost:self.Cost.Report(),
+		}
+	}
+
+	/// Reports which action types consumed the most worker time and queue
+	/// space so far.
+	///
+	/// # Returns
+	///
+	/// A serializable `Report` of the cost by action type.
+	pub fn CostReport(&self) -> Cost::Report::Struct { self.Cost.Report() }
+
+	/// Registers a health check, replacing any check of the same name.
+	///
+	/// # Arguments
+	///
+	/// * `Check` - The health check to evaluate in `Health`.
+	pub fn Register(&self, Check:Arc<dyn Check>) { self.Check.insert(Check.Name(), Check); }
+
+	/// Evaluates every registered health check.
+	///
+	/// Checks run concurrently, each under its own timeout; a check that
+	/// times out is reported as `Unhealthy`.
+	///
+	/// # Returns
+	///
+	/// A `Report` whose status is the worst of all checks.
+	pub async fn Health(&self) -> Report::Struct {
+		let Check = self
+			.Check
+			.iter()
+			.map(|Entry| Entry.value().clone())
+			.collect::<Vec<_>>();
+
+		let Runtime = self.Runtime();
+
+		let Outcome = join_all(Check.into_iter().map(|Check| {
+			let Runtime = Runtime.clone();
+
+			async move {
+				let Outcome =
+					Runtime.Timeout(Check.Timeout(), Check.Check(self)).await.unwrap_or_else(|| {
+						Outcome::New(
+							Status::Unhealthy,
+							format!("Check timed out after {:?}", Check.Timeout()),
+						)
+					});
+
+				(Check.Name(), Outcome)
+			}
+		}))
+		.await;
+
+		Report::Struct::New(Outcome.into_iter().collect())
+	}
+
+	/// Replaces queue settings and store limits from a reloaded
+	/// configuration.
+	///
+	/// Every `[queues.<name>]` section overrides the settings of its queue;
+	/// consumers pick the new settings up for subsequently dequeued work.
+	/// The `[stores.cache]`, `[stores.memo]` and `[stores.results]` sections
+	/// bound the cache, the results of cacheable actions and the completions
+	/// waiting on every Karma queue.
+	///
+	/// # Arguments
+	///
+	/// * `Fate` - The reloaded configuration settings.
+	pub fn Reload(&self, Fate:&Config) {
+		for (Name, Settings) in Self::Queues(Fate) {
+			self.Settings.insert(Name, Settings);
+		}
+
+		self.Bound(Fate);
+	}
+
+	/// Applies the `[stores.<name>]` limits of a configuration to the cache,
+	/// the memo, the requeued dead-letter entries and the completions of
+	/// every Karma queue.
+	pub(crate) fn Bound(&self, Fate:&Config) {
+		self.Cache.Resize(Self::Limit(Fate, "cache"));
+
+		self.Memo.Resize(Self::Limit(Fate, "memo"));
+
+		self.DeadLetter.Bound(Self::Limit(Fate, "requeued"));
+
+		let Limit = Self::Limit(Fate, "results");
+
+		let Chain = Self::Chain(Fate);
+
+		for Entry in self.Karma.iter() {
+			Entry.value().Bound(&format!("results.{}", Entry.key()), Limit);
+
+			Entry.value().Restrict(Chain);
+		}
+
+		// `[registry]` gives the retention and sweep interval
+		let Positive = |Key:&str, Default:Duration| {
+			Self::Span(Fate, Key).filter(|Length| !Length.is_zero()).unwrap_or(Default)
+		};
+
+		self.Actions.Configure(
+			Positive("registry.retention", Duration::from_secs(60)),
+			Positive("registry.interval", Duration::from_secs(5)),
+		);
+
+		// `[cost]` gives the number of types kept apart and, optionally, the
+		// storage log the report is appended to every `interval`
+		let Dump = Fate.get_string("cost.dump").ok().map(|Path| {
+			(PathBuf::from(Path), Positive("cost.interval", Duration::from_secs(60)))
+		});
+
+		self.Cost
+			.Configure(Fate.get_int("cost.limit").map_or(32, |Limit| Limit.max(0) as usize), Dump);
+
+		// `[watchdog]` gives the stuck threshold and sweep interval, a
+		// threshold of 0 disabling it, and whether stuck executions are
+		// cancelled
+		self.Watchdog.Configure(
+			match Self::Span(Fate, "watchdog.threshold") {
+				Some(Threshold) if Threshold.is_zero() => None,
+				Some(Threshold) => Some(Threshold),
+				None => Some(Duration::from_secs(300)),
+			},
+			Positive("watchdog.interval", Duration::from_secs(1)),
+			Fate.get_bool("watchdog.cancel").unwrap_or(false),
+		);
+
+		// `[submissions]` gives how many records are kept in memory and,
+		// optionally, the storage log every record is appended to
+		self.History.Configure(
+			Fate.get_int("submissions.limit")
+				.map_or(10_000, |Limit| Limit.max(0) as usize),
+			Fate.get_string("submissions.log").ok().map(PathBuf::from),
+		);
+	}
+
+	/// Reads a `Time::Duration` from a configuration, such as `250` for
+	/// milliseconds or `"5m"`; a bare number for a key that counted seconds
+	/// is reported, see `Time::Legacy`.
+	pub(crate) fn Span(Fate:&Config, Key:&str) -> Option<Duration> {
+		match Fate.get::<Span>(Key) {
+			Ok(Length) => {
+				if let Ok(Value) = Fate.get::<serde_json::Value>(Key) {
+					Legacy(Key, &Value);
+				}
+
+				Some(Length.Get())
+			},
+			Err(ConfigError::NotFound(_)) => None,
+			Err(_Error) => {
+				warn!("Ignoring invalid duration {}: {}", Key, _Error);
+
+				None
+			},
+		}
+	}
+
+	/// Reads the `[stores.<name>]` section of a configuration.
+	pub(crate) fn Limit(Fate:&Config, Store:&str) -> Limit {
+		match Fate.get::<Limit>(&format!("stores.{}", Store)) {
+			Ok(Limit) => Limit,
+			Err(ConfigError::NotFound(_)) => Limit::default(),
+			Err(_Error) => {
+				warn!("Ignoring invalid limits for store {}: {}", Store, _Error);
+
+				Limit::default()
+			},
+		}
+	}
+
+	/// Reads the `[chain]` section of a configuration.
+	pub(crate) fn Chain(Fate:&Config) -> Chain {
+		match Fate.get::<Chain>("chain") {
+			Ok(Chain) => Chain,
+			Err(ConfigError::NotFound(_)) => Chain::default(),
+			Err(_Error) => {
+				warn!("Ignoring invalid chain limits: {}", _Error);
+
+				Chain::default()
+			},
+		}
+	}
+
+	/// Reads the `[queues.<name>]` sections of a configuration.
+	pub(crate) fn Queues(Fate:&Config) -> Vec<(String, Settings)> {
+		Fate.get_table("queues")
+			.unwrap_or_default()
+			.into_iter()
+			.filter_map(|(Name, Section)| match Section.try_deserialize::<Settings>() {
+				Ok(Settings) => Some((Name.to_lowercase(), Settings)),
+				Err(_Error) => {
+					warn!("Ignoring invalid settings for queue {}: {}", Name, _Error);
+
+					None
+				},
+			})
+			.collect()
+	}
+
+	/// Routes an action to its Karma queue and returns its completion.
+	///
+	/// The action is submitted as `Identity::Local`, see `SubmitAs`.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to submit; its `Queue` metadata names the queue.
+	///
+	/// # Returns
+	///
+	/// The `Pending` completion of the action.
+	pub async fn Submit(&self, Action:Box<dyn Action>) -> Pending::Struct {
+		self.SubmitAs(&Identity::Local(), Action).await
+	}
+
+	/// Routes an action submitted by an identity to its Karma queue and
+	/// returns its completion.
+	///
+	/// The admission policies decide about the action first, see `Screen`;
+	/// its `Accepted` hooks run once it is routed, before it is enqueued.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - Who submits the action.
+	/// * `Action` - The action to submit; its `Queue` metadata names the queue.
+	///
+	/// # Returns
+	///
+	/// The `Pending` completion of the action, already failed with
+	/// `Error::Admission` if a policy refused it, with `Error::Routing` if it
+	/// cannot be routed, or with the error of an `Accepted` hook refusing it.
+	pub async fn SubmitAs(&self, Identity:&Identity, Action:Box<dyn Action>) -> Pending::Struct {
+		if let Err(_Error) = self.Screen(Identity, Action.as_ref()).await {
+			return Pending::Struct::Failed(_Error);
+		}
+
+		let Production = match crate::Fn::Route::Fn(self, Action.as_ref()).await {
+			Ok(Production) => Production,
+			Err(_Error) => return Pending::Struct::Failed(_Error),
+		};
+
+		if let Err(_Error) = Action.Stage(self, Phase::Accepted).await {
+			return Pending::Struct::Failed(_Error);
+		}
+
+		Production.Submit(Action).await
+	}
+
+	/// Evaluates the admission policies about a submission, in the order
+	/// they were added.
+	///
+	/// The first `Deny` ends the evaluation, its reason prefixed with the
+	/// name of the policy. A `Modify` patches the view the policies after it
+	/// see; a later patch of the same key wins. The decision is published as
+	/// an `Admission` event, logged when it is not `Allow`, and counted in
+	/// `echo_admission_total` by decision. Without policies everything is
+	/// allowed, unrecorded.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - Who submits the action.
+	/// * `View` - What is submitted.
+	///
+	/// # Returns
+	///
+	/// `Deny`, `Modify` with the patches of every policy, or `Allow`.
+	pub async fn Admit(&self, Identity:&Identity, View:View) -> Decision {
+		self.Evaluate(Identity, View, false).await
+	}
+
+	/// Previews what the admission policies decide about an action without
+	/// submitting it, e.g. to show the patches a `Modify` would apply.
+	///
+	/// The decision is published as an `Admission` event marked `DryRun`,
+	/// and neither logged nor counted.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - Who would submit the action.
+	/// * `Action` - The action.
+	///
+	/// # Returns
+	///
+	/// The decision `Admit` would reach.
+	pub async fn DryRun(&self, Identity:&Identity, Action:&dyn Action) -> Decision {
+		self.Evaluate(Identity, Action.View().await, true).await
+	}
+
+	/// Admits an action submitted in process, applying the patches of a
+	/// `Modify` to it with `Annotate`, an entry named after a `Reserved` key
+	/// under its prefixed key.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - Who submits the action.
+	/// * `Action` - The action.
+	///
+	/// # Returns
+	///
+	/// `Error::Admission` if a policy refused the action.
+	pub(crate) async fn Screen(&self, Identity:&Identity, Action:&dyn Action) -> Result<(), Error> {
+		if self.Admission.is_empty() {
+			return Ok(());
+		}
+
+		match self.Admit(Identity, Action.View().await).await {
+			Decision::Allow => Ok(()),
+			Decision::Deny { Reason, Code } => Err(Error::Admission { Reason, Code }),
+			Decision::Modify { Patch } => {
+				for (Key, Value) in Patch {
+					Action.Annotate(&Reserved::Parse(&Key).map_or(Key, Reserved::Key), Value);
+				}
+
+				Ok(())
+			},
+		}
+	}
+
+	/// Evaluates the admission policies, see `Admit`.
+	async fn Evaluate(&self, Identity:&Identity, mut View:View, DryRun:bool) -> Decision {
+		if self.Admission.is_empty() {
+			return Decision::Allow;
+		}
+
+		let (mut Patch, mut Policy, mut Denied) = (serde_json::Map::new(), Vec::new(), None);
+
+		for Admission in self.Admission.iter() {
+			match Admission.Admit(Identity, &View).await {
+				Decision::Allow => {},
+				Decision::Modify { Patch:Set } => {
+					View.Apply(&Set);
+
+					Patch.extend(Set);
+
+					Policy.push(Admission.Name());
+				},
+				Decision::Deny { Reason, Code } => {
+					Denied = Some(Decision::Deny {
+						Reason:format!("{}: {}", Admission.Name(), Reason),
+						Code,
+					});
+
+					Policy.push(Admission.Name());
+
+					break;
+				},
+			}
+		}
+
+		let Decision = match Denied {
+			Some(Denied) => Denied,
+			None if Patch.is_empty() => Decision::Allow,
+			None => Decision::Modify { Patch },
+		};
+
+		if !DryRun {
+			match &Decision {
+				Decision::Allow => {},
+				Decision::Deny { Reason, .. } => {
+					info!("Denied {} submitted by {}: {}", View.Action, Identity.Name, Reason)
+				},
+				Decision::Modify { Patch } => {
+					info!(
+						"Patched {} submitted by {} with {}",
+						View.Action,
+						Identity.Name,
+						serde_json::Value::Object(Patch.clone())
+					)
+				},
+			}
+
+			counter!("echo_admission_total", "decision" => Decision.Name()).increment(1);
+		}
+
+		self.Bus.Emit(|| Event::Admission {
+			Identity:Identity.Name.clone(),
+			Action:View.Action.clone(),
+			Decision:Decision.clone(),
+			Policy,
+			DryRun,
+		});
+
+		Decision
+	}
+
+	/// Reads the result an action with an idempotency key completed with,
+	/// while it is remembered.
+	///
+	/// An action whose `IdempotencyKey` option names a remembered key is not
+	/// executed again; it ends with the remembered result instead. A key is
+	/// remembered in `Cache`, under `Idempotency:<key>`, for the
+	/// `[idempotency] window`, ten minutes unless set, and may be evicted
+	/// earlier by the `[stores.cache]` limits.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The idempotency key.
+	///
+	/// # Returns
+	///
+	/// The result, or `None` if the key is not remembered or its window
+	/// passed.
+	pub fn Idempotent(&self, Key:&str) -> Option<serde_json::Value> {
+		let Key = format!("Idempotency:{}", Key);
+
+		let Entry = self.Cache.Get(&Key)?;
+
+		let Until = Entry.get("Until").and_then(|Until| Timestamp::deserialize(Until).ok());
+
+		if Until.is_none_or(|Until| Until.Passed()) {
+			self.Cache.Remove(&Key);
+
+			return None;
+		}
+
+		Entry.get("Result").cloned()
+	}
+
+	/// Remembers the result of an action completed with an idempotency key,
+	/// for the `[idempotency] window`; a window of 0 remembers nothing.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The idempotency key.
+	/// * `Result` - The result the action completed with.
+	pub(crate) fn Remember(&self, Key:&str, Result:serde_json::Value) {
+		let Window =
+			Self::Span(&self.Fate, "idempotency.window").unwrap_or(Duration::from_secs(600));
+
+		if Window.is_zero() {
+			return;
+		}
+
+		let Until = Timestamp(SystemTime::now() + Window);
+
+		self.Cache.Put(
+			format!("Idempotency:{}", Key),
+			serde_json::json!({ "Result": Result, "Until": Until }),
+		);
+	}
+
+	/// Forgets an idempotency key, so the next action carrying it is
+	/// executed again.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The idempotency key.
+	///
+	/// # Returns
+	///
+	/// Whether the key was remembered.
+	pub fn ForgetIdempotency(&self, Key:&str) -> bool {
+		self.Cache.Remove(&format!("Idempotency:{}", Key)).is_some()
+	}
+
+	/// Subscribes to the lifecycle events of this context.
+	///
+	/// # Returns
+	///
+	/// A new `Subscription` to the event bus.
+	pub fn Subscribe(&self) -> Subscription::Struct { self.Bus.Subscribe() }
+
+	/// Registers a hook for every name matching a pattern.
+	///
+	/// The pattern is compiled once, here. An action referencing a matching
+	/// name in its `Hooks` metadata calls the hook with that name.
+	///
+	/// # Arguments
+	///
+	/// * `Pattern` - The pattern, where `*` matches any run of characters and
+	///   `?` one character, e.g. `metrics.*`.
+	/// * `Hook` - The hook, called with the matched name.
+	pub fn AddHookPattern<F>(&self, Pattern:&str, Hook:F)
+	where
+		F: Fn(&str) -> Result<(), Error> + Send + Sync + 'static, {
+		self.Pattern
+			.insert(Pattern.to_string(), (Glob::Struct::New(Pattern), Arc::new(Hook)));
+	}
+
+	/// Returns what an action does with a hook reference matching no hook,
+	/// unless its `MissingHooks` metadata says otherwise: the policy set with
+	/// `Builder::WithMissingHook`, else `hooks.missing` (`ignore`, `warn` or
+	/// `fail`), else `Fail` if the older `hooks.strict` is set, else
+	/// `Ignore`.
+	pub fn MissingHook(&self) -> MissingHook {
+		if let Some(Policy) = self.MissingHook {
+			return Policy;
+		}
+
+		if let Ok(Name) = self.Fate.get_string("hooks.missing") {
+			match MissingHook::Parse(&Name) {
+				Some(Policy) => return Policy,
+				None => warn!("Unknown policy {} for hooks.missing", Name),
+			}
+		}
+
+		if self.Fate.get_bool("hooks.strict").unwrap_or(false) {
+			MissingHook::Fail
+		} else {
+			MissingHook::Ignore
+		}
+	}
+
+	/// Resolves the hooks referenced in an action's `Hooks` metadata.
+	///
+	/// A reference is either a name or a pattern; a pattern stands for the
+	/// names registered in `Span` that it matches, sorted lexicographically,
+	/// and a name is kept once even if referenced again. The hooks run in a
+	/// deterministic order: first the hook registered in `Span` under each
+	/// name, in reference order, then the hooks registered with
+	/// `AddHookPattern`, sorted lexicographically by pattern, once for every
+	/// name they match.
+	///
+	/// A reference matching no hook is handled as `MissingHook` says. More
+	/// hooks than `hooks.expansion` (64 unless set) fail with
+	/// `Error::HookLimit` before any of them runs.
+	///
+	/// # Arguments
+	///
+	/// * `Reference` - The names and patterns referenced.
+	///
+	/// # Returns
+	///
+	/// The hooks to run, in order.
+	pub fn Expand(&self, Reference:&[&str]) -> Result<Vec<Hook>, Error> {
+		self.ExpandWith(Reference, self.MissingHook())
+	}
+
+	/// Resolves the hooks referenced in an action's `Hooks` metadata like
+	/// `Expand`, under a given policy for references matching no hook.
+	///
+	/// # Arguments
+	///
+	/// * `Reference` - The names and patterns referenced.
+	/// * `Missing` - What is done with a reference matching no hook: under
+	///   `Fail` it fails with `Error::UnknownHook`, under `Warn` it is logged,
+	///   published as a `MissingHook` event and counted in
+	///   `echo_hooks_missing_total`.
+	///
+	/// # Returns
+	///
+	/// The hooks to run, in order.
+	pub fn ExpandWith(&self, Reference:&[&str], Missing:MissingHook) -> Result<Vec<Hook>, Error> {
+		let Maximum = self
+			.Fate
+			.get_int("hooks.expansion")
+			.map_or(64, |Maximum| Maximum.max(0) as usize);
+
+		let mut Pattern:Vec<_> = self
+			.Pattern
+			.iter()
+			.map(|Entry| (Entry.key().clone(), Entry.value().clone()))
+			.collect();
+
+		Pattern.sort_by(|(Left, _), (Right, _)| Left.cmp(Right));
+
+		let mut Name:Vec<String> = Vec::new();
+
+		for Reference in Reference {
+			let Matched = self.Matched(Reference);
+
+			if Matched.is_empty() {
+				match Missing {
+					MissingHook::Ignore => debug!("Hook reference {} matches no hook", Reference),
+					MissingHook::Warn => {
+						warn!("Hook reference {} matches no hook", Reference);
+
+						counter!("echo_hooks_missing_total").increment(1);
+
+						self.Bus.Emit(|| Event::MissingHook {
+							Sequence:Invocation::Current().map(|Current| Current.ActionId()),
+							Hook:Reference.to_string(),
+						});
+					},
+					MissingHook::Fail => return Err(Error::UnknownHook(Reference.to_string())),
+				}
+			}
+
+			for Matched in Matched {
+				if !Name.contains(&Matched) {
+					Name.push(Matched);
+				}
+			}
+
+			if Name.len() > Maximum {
+				return Err(Error::HookLimit { Maximum });
+			}
+		}
+
+		let mut Resolved:Vec<Hook> = Name
+			.iter()
+			.filter_map(|Name| {
+				self.Span
+					.get(Name)
+					.map(|Cycle| Hook::Exact { Name:Name.clone(), Hook:Cycle.clone() })
+			})
+			.collect();
+
+		for (Source, (Glob, Function)) in &Pattern {
+			for Name in Name.iter().filter(|Name| Glob.Match(Name)) {
+				if Resolved.len() >= Maximum {
+					return Err(Error::HookLimit { Maximum });
+				}
+
+				Resolved.push(Hook::Pattern {
+					Pattern:Source.clone(),
+					Name:Name.clone(),
+					Hook:Function.clone(),
+				});
+			}
+		}
+
+		Ok(Resolved)
+	}
+
+	/// Resolves one hook reference to the names of the hooks it calls: the
+	/// exact hooks a pattern matches, sorted, or the name itself if an exact
+	/// hook or a pattern hook handles it.
+	fn Matched(&self, Reference:&str) -> Vec<String> {
+		if Glob::Struct::Is(Reference) {
+			let Glob = Glob::Struct::New(Reference);
+
+			let mut Matched:Vec<String> = self
+				.Span
+				.iter()
+				.map(|Entry| Entry.key().clone())
+				.filter(|Name| Glob.Match(Name))
+				.collect();
+
+			Matched.sort();
+
+			Matched
+		} else if self.Span.contains_key(Reference)
+			|| self.Pattern.iter().any(|Entry| Entry.value().0.Match(Reference))
+		{
+			vec![Reference.to_string()]
+		} else {
+			Vec::new()
+		}
+	}
+
+	/// Checks the actions queued in the Karma queues against a plan, such
+	/// as the actions restored into them at startup, applying the
+	/// `[reconcile]` policy to those that cannot run.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan the actions are to run with.
+	///
+	/// # Returns
+	///
+	/// What was found and done, also logged.
+	pub async fn Reconcile(&self, Plan:&Formality) -> Reconcile::Report::Struct {
+		self.ReconcileWith(Plan, &Reconcile::Struct::Configured(&self.Fate))
+			.await
+	}
+
+	/// Checks the actions queued in the Karma queues against a plan,
+	/// classifying each as runnable, missing its function, missing a hook or
+	/// undeserializable, and applying a policy to those that cannot run.
+	///
+	/// Dead-letter queues are not scanned. Actions moved or removed have
+	/// their completion resolved with the error they would have failed
+	/// with, and every move or removal is published as an `Audit` event.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan the actions are to run with.
+	/// * `Policy` - What is done with each class of action that cannot run.
+	///
+	/// # Returns
+	///
+	/// What was found and done, also logged.
+	pub async fn ReconcileWith(
+		&self,
+		Plan:&Formality,
+		Policy:&Reconcile::Struct,
+	) -> Reconcile::Report::Struct {
+		let mut Report = Reconcile::Report::Struct::default();
+
+		for (Name, Line) in self.Lines() {
+			let DeadLetter = self.Settings(&Name).DeadLetter.and_then(|DeadLetter| {
+				self.Karma
+					.get(&DeadLetter)
+					.map(|Production| (DeadLetter, Production.clone()))
+			});
+
+			let mut Orphan = HashMap::new();
+
+			for (Stamp, Metadata) in Line.Inspect(&["Action", "Argument", "Hooks"]).await {
+				let (Class, Failure) = self.Classify(Plan, &Metadata);
+
+				Report.Count(Class);
+
+				let Some(Failure) = Failure else {
+					continue;
+				};
+
+				match (Policy.Get(Class), &DeadLetter) {
+					(Policy::Keep, _) | (Policy::DeadLetter, None) => Report.Kept += 1,
+					(Applied, _) => {
+						Orphan.insert(Stamp.Sequence, (Class, Applied, Failure));
+					},
+				}
+			}
+
+			if Orphan.is_empty() {
+				continue;
+			}
+
+			let (mut Lettered, mut Dropped) = (Vec::new(), Vec::new());
+
+			for (Stamp, Action) in Line.Extract(&Orphan.keys().copied().collect::<Vec<_>>()).await {
+				let Some((Class, Applied, Failure)) = Orphan.remove(&Stamp.Sequence) else {
+					continue;
+				};
+
+				Action.Fail(&Failure);
+
+				if let (Policy::DeadLetter, Some((Target, Production))) = (Applied, &DeadLetter) {
+					let Letter = Annotated::New(Arc::from(Action))
+						.WithMetadata("Reason", serde_json::json!(Class.Reason()))
+						.WithMetadata("Error", serde_json::json!(Failure.to_string()))
+						.WithMetadata("ErrorClass", serde_json::json!(Failure.Class()));
+
+					match Production.Enqueue(Box::new(Letter)).await {
+						Ok(_) => Lettered.push(Stamp.Sequence),
+						Err(_Error) => {
+							warn!("Dead-letter queue {} refused an action: {}", Target, _Error);
+
+							Dropped.push(Stamp.Sequence);
+						},
+					}
+				} else {
+					Dropped.push(Stamp.Sequence);
+				}
+
+				Line.Complete(Stamp.Sequence, Err(Failure));
+			}
+
+			Report.DeadLettered += Lettered.len();
+
+			Report.Dropped += Dropped.len();
+
+			for (Operation, Sequence, Target) in [
+				(
+					"Reconcile.DeadLetter",
+					Lettered,
+					DeadLetter.as_ref().map(|(Target, _)| Target.clone()),
+				),
+				("Reconcile.Drop", Dropped, None),
+			] {
+				if !Sequence.is_empty() {
+					self.Bus.Emit(|| Event::Audit {
+						Operation:Operation.to_string(),
+						Queue:Name.clone(),
+						Sequence,
+						Target,
+					});
+				}
+			}
+		}
+
+		info!("Reconciled the Karma queues: {}", Report);
+
+		Report
+	}
+
+	/// Lists the Karma queues that are not the dead-letter queue of another.
+	pub(crate) fn Lines(&self) -> Vec<(String, Arc<crate::Struct::Sequence::Production::Struct>)> {
+		self.Karma
+			.iter()
+			.map(|Entry| (Entry.key().clone(), Entry.value().clone()))
+			.filter(|(Name, _)| {
+				!self.Settings.iter().any(|Settings| {
+					Settings
+						.DeadLetter
+						.as_deref()
+						.is_some_and(|DeadLetter| DeadLetter.eq_ignore_ascii_case(Name))
+				})
+			})
+			.collect()
+	}
+
+	/// Classifies a queued action by its `Action`, `Argument` and `Hooks`
+	/// metadata. A hook reference matching no hook is reported whatever the
+	/// `MissingHook` policy the action would run under.
+	///
+	/// # Returns
+	///
+	/// The class, with the error the action would fail with unless it is
+	/// runnable.
+	fn Classify(
+		&self,
+		Plan:&Formality,
+		Metadata:&serde_json::Map<String, serde_json::Value>,
+	) -> (Class, Option<Error>) {
+		let Name = match Metadata.get("Action") {
+			// Actions that are not plan calls run however they implement it
+			None => return (Class::Runnable, None),
+			Some(serde_json::Value::String(Name)) => Name,
+			Some(_) => {
+				return (
+					Class::Undeserializable,
+					Some(Error::Execution("Action metadata is not a string".to_string())),
+				);
+			},
+		};
+
+		if Metadata.get("Argument").is_some_and(|Argument| !Argument.is_array()) {
+			return (
+				Class::Undeserializable,
+				Some(Error::Execution(format!("Argument of {} is not an array", Name))),
+			);
+		}
+
+		if !Plan.Has(Name) {
+			return (Class::MissingFunction, Some(Plan.Unknown(Name)));
+		}
+
+		let Missing = Metadata
+			.get("Hooks")
+			.map(Phase::Every)
+			.unwrap_or_default()
+			.into_iter()
+			.find(|Reference| self.Matched(Reference).is_empty());
+
+		match Missing {
+			Some(Reference) => {
+				(Class::MissingHook, Some(Error::UnknownHook(Reference.to_string())))
+			},
+			None => (Class::Runnable, None),
+		}
+	}
 }
 
-use config::Config;
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::PathBuf,
+	time::{Duration, SystemTime},
+};
+
+use config::{Config, ConfigError};
 use dashmap::DashMap;
+use futures::future::join_all;
+use log::{debug, info, warn};
+use metrics::counter;
+use serde::Deserialize;
+
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Health::Status::Enum as Status,
+		Sequence::{
+			Action::Error::Enum as Error,
+			Decision::Enum as Decision,
+			Hook::Enum as Hook,
+			MissingHook::Enum as MissingHook,
+			Phase::Enum as Phase,
+			Reconcile::{Class::Enum as Class, Policy::Enum as Policy},
+			Reserved::Enum as Reserved,
+		},
+	},
+	Struct::{
+		Event::{Bus, Subscription},
+		Health::{Outcome::Struct as Outcome, Report},
+		Sequence::{
+			Action::Annotated::Struct as Annotated,
+			ActionRegistry,
+			Admission::{Identity::Struct as Identity, View::Struct as View},
+			Arc,
+			DeadLetter,
+			Glob,
+			Invocation::Struct as Invocation,
+			Memo,
+			Overseer::{self, Task},
+			Plan::Formality::Struct as Formality,
+			Production::{Chain::Struct as Chain, Pending, Settings::Struct as Settings},
+			Randomness,
+			Reconcile,
+			Timer,
+			Watchdog,
+		},
+		Stats::{Cost, Queue, Registry, Snapshot, Summary},
+		Store::{self, Limit::Struct as Limit},
+		Transport::History,
+	},
+	Time::{Duration::Struct as Span, Legacy, Timestamp::Struct as Timestamp},
+	Trait::{
+		Health::Check::Trait as Check,
+		Runtime::Trait as Runtime,
+		Sequence::{
+			Action::Trait as Action,
+			AdmissionPolicy::Trait as AdmissionPolicy,
+			LicenseValidator::Trait as LicenseValidator,
+		},
+	},
+};
 
-use crate::Struct::Sequence::Arc;
+pub mod Backpressure;
+pub mod Builder;
+pub mod Dynamic;
+pub mod Handoff;
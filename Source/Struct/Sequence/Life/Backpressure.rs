@@ -0,0 +1,164 @@
+/// Pauses the actions of identities whose result consumers fall behind.
+///
+/// A `Pump` built with `WithBackpressure` tags every submitted action with
+/// the identity of its connection as `SubmittedBy` metadata, presses that
+/// identity here while the outbound channel of the connection holds more
+/// replies than its high-water mark, and releases it once the channel
+/// drains below its low-water mark. A sequence taking an action of a
+/// pressed identity hands it here instead of starting it and moves on to
+/// the next one, so the work of other identities is unaffected. Once no
+/// connection presses the identity, its held actions go back to their
+/// production lines with their original stamps.
+pub struct Struct {
+	/// The gate of every identity pressed or holding actions, by identity.
+	Gate:Mutex<HashMap<String, Gate>>,
+}
+
+/// The state of one identity.
+#[derive(Default)]
+struct Gate {
+	/// The connections above their high-water mark.
+	Pressed:HashSet<u64>,
+
+	/// The actions held, in the order they were taken.
+	Held:Vec<(Arc<Production>, Stamp, Box<dyn Action>)>,
+}
+
+impl Struct {
+	/// Creates a new instance pressing no identity.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Struct { Gate:Mutex::new(HashMap::new()) } }
+
+	/// Marks a connection of an identity as above its high-water mark, so
+	/// the actions of the identity are held from now on.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - The identity the connection authenticated as.
+	/// * `Connection` - The identifier of the connection.
+	pub fn Press(&self, Identity:&str, Connection:u64) {
+		let mut Gate = Lock(&self.Gate);
+
+		if Gate
+			.entry(Identity.to_string())
+			.or_default()
+			.Pressed
+			.insert(Connection)
+		{
+			info!("Pausing the actions of {}: connection {} is behind", Identity, Connection);
+
+			counter!("echo_backpressure_pressed_total").increment(1);
+		}
+	}
+
+	/// Marks a connection of an identity as below its low-water mark, or
+	/// closed. Once no connection presses the identity, its held actions
+	/// are put back into their production lines.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - The identity the connection authenticated as.
+	/// * `Connection` - The identifier of the connection.
+	pub async fn Release(&self, Identity:&str, Connection:u64) {
+		let Held = {
+			let mut Gate = Lock(&self.Gate);
+
+			let Some(Entry) = Gate.get_mut(Identity) else {
+				return;
+			};
+
+			if !Entry.Pressed.remove(&Connection) || !Entry.Pressed.is_empty() {
+				return;
+			}
+
+			info!("Resuming the actions of {}", Identity);
+
+			Gate.remove(Identity).map(|Entry| Entry.Held).unwrap_or_default()
+		};
+
+		for (Production, Stamp, Action) in Held {
+			Production.Reinject(Stamp, Action).await;
+		}
+	}
+
+	/// Checks whether an identity is pressed by any of its connections.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - The identity to check.
+	pub fn Pressed(&self, Identity:&str) -> bool {
+		Lock(&self.Gate)
+			.get(Identity)
+			.is_some_and(|Entry| !Entry.Pressed.is_empty())
+	}
+
+	/// Counts the actions held for every identity.
+	pub fn Held(&self) -> usize { Lock(&self.Gate).values().map(|Entry| Entry.Held.len()).sum() }
+
+	/// Returns the identity an action was submitted by if it is pressed.
+	pub(crate) async fn Throttled(&self, Action:&dyn Action) -> Option<String> {
+		if Lock(&self.Gate).is_empty() {
+			return None;
+		}
+
+		let Identity = Action.Metadata("SubmittedBy").await?.as_str()?.to_string();
+
+		self.Pressed(&Identity).then_some(Identity)
+	}
+
+	/// Holds a dequeued action of an identity until it is released, or puts
+	/// it back at once if it was released meanwhile.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - The identity the action was submitted by.
+	/// * `Production` - The production line the action was dequeued from.
+	/// * `Stamp` - The stamp the action received when it was enqueued.
+	/// * `Action` - The action to hold.
+	pub(crate) async fn Hold(
+		&self,
+		Identity:String,
+		Production:Arc<Production>,
+		Stamp:Stamp,
+		Action:Box<dyn Action>,
+	) {
+		{
+			let mut Gate = Lock(&self.Gate);
+
+			if let Some(Entry) = Gate.get_mut(&Identity).filter(|Entry| !Entry.Pressed.is_empty()) {
+				Entry.Held.push((Production, Stamp, Action));
+
+				counter!("echo_backpressure_held_total").increment(1);
+
+				return;
+			}
+		}
+
+		Production.Reinject(Stamp, Action).await;
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{Arc, Mutex, MutexGuard},
+};
+
+use log::info;
+use metrics::counter;
+
+use crate::{
+	Struct::Sequence::Production::{Stamp::Struct as Stamp, Struct as Production},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,219 @@
+/// Builds a `Life` together with its Karma queues and their settings.
+pub struct Struct {
+	/// The lifecycle being built.
+	Life:Life,
+}
+
+impl Struct {
+	/// Creates a new builder with an empty configuration.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Struct { Life:Life::New(Arc::new(Config::default())) } }
+
+	/// Sets the configuration settings.
+	///
+	/// Any `[queues.<name>]` sections are loaded as queue settings; settings
+	/// given to `WithQueue` take precedence.
+	///
+	/// # Arguments
+	///
+	/// * `Fate` - The configuration settings.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithFate(mut self, Fate:Arc<Config>) -> Self {
+		for (Name, Settings) in Life::Queues(&Fate) {
+			self.Life.Settings.entry(Name).or_insert(Settings);
+		}
+
+		self.Life.Fate = Fate;
+
+		self
+	}
+
+	/// Adds a named Karma queue with its settings.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the queue.
+	/// * `Production` - The production line of the queue.
+	/// * `Settings` - How the queue is consumed.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithQueue(self, Name:&str, Production:Arc<Production>, Settings:Settings) -> Self {
+		Production.Attach(&self.Life.Bus);
+
+		Production.Enlist(&self.Life.Actions);
+
+		self.Life.Karma.insert(Name.to_string(), Production);
+
+		self.Life.Settings.insert(Name.to_lowercase(), Settings);
+
+		self
+	}
+
+	/// Creates Karma queues on demand for actions routed to a missing one.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How many created queues may exist at once; beyond it, the
+	///   least recently routed one holding no action is evicted.
+	/// * `Factory` - Creates the production line and settings of a queue from
+	///   its name; called once per created queue.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithQueueFactory<F>(self, Limit:usize, Factory:F) -> Self
+	where
+		F: Fn(&str) -> (Arc<Production>, Settings) + Send + Sync + 'static, {
+		self.Life.Dynamic.Configure(Some(Arc::new(Factory)), Limit);
+
+		self
+	}
+
+	/// Seeds the source of random choices, making retry jitter
+	/// reproducible.
+	///
+	/// # Arguments
+	///
+	/// * `Seed` - The seed of the generator.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRngSeed(mut self, Seed:u64) -> Self {
+		self.Life.Randomness = Arc::new(Randomness::Struct::Seeded(Seed));
+
+		self
+	}
+
+	/// Sets what actions do with a hook reference matching no hook, unless
+	/// their `MissingHooks` metadata says otherwise; takes precedence over
+	/// `hooks.missing`.
+	///
+	/// # Arguments
+	///
+	/// * `Policy` - The policy.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithMissingHook(mut self, Policy:MissingHook) -> Self {
+		self.Life.MissingHook = Some(Policy);
+
+		self
+	}
+
+	/// Validates the license of every action before it runs, in place of
+	/// the `License` signal of the action.
+	///
+	/// # Arguments
+	///
+	/// * `Validator` - The validator, e.g. `License::Expiry`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithLicenseValidator(mut self, Validator:Arc<dyn LicenseValidator>) -> Self {
+		self.Life.License = Some(Validator);
+
+		self
+	}
+
+	/// Adds an admission policy, evaluated after those added before it for
+	/// every submission; see `Life::Admit`.
+	///
+	/// # Arguments
+	///
+	/// * `Policy` - The policy, e.g. `Admission::Roles` or
+	///   `Admission::PathPrefix`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAdmissionPolicy(mut self, Policy:Arc<dyn AdmissionPolicy>) -> Self {
+		Arc::make_mut(&mut self.Life.Admission).push(Policy);
+
+		self
+	}
+
+	/// Registers a health check.
+	///
+	/// # Arguments
+	///
+	/// * `Check` - The health check to evaluate in `Life::Health`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCheck(self, Check:Arc<dyn Check>) -> Self {
+		self.Life.Register(Check);
+
+		self
+	}
+
+	/// Spawns the internal tasks of the lifecycle, and of the sequences and
+	/// sources run on it, on a runtime other than tokio's.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime to spawn on and wait with.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Life.Overseer.Use(Runtime);
+
+		self
+	}
+
+	/// Builds the lifecycle.
+	///
+	/// The `[stores.<name>]` limits of the configuration are applied to the
+	/// cache and to the completions of every queue, and the seed of its
+	/// source of random choices is logged.
+	///
+	/// # Returns
+	///
+	/// The built `Life`.
+	pub fn Build(self) -> Life {
+		self.Life.Bound(&self.Life.Fate.clone());
+
+		info!("Randomness seeded with {}", self.Life.Randomness.Seed());
+
+		self.Life
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use std::sync::Arc;
+
+use config::Config;
+use log::info;
+
+use crate::{
+	Enum::Sequence::MissingHook::Enum as MissingHook,
+	Struct::Sequence::{
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Randomness,
+	},
+	Trait::{
+		Health::Check::Trait as Check,
+		Runtime::Trait as Runtime,
+		Sequence::{
+			AdmissionPolicy::Trait as AdmissionPolicy,
+			LicenseValidator::Trait as LicenseValidator,
+		},
+	},
+};
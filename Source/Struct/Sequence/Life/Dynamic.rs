@@ -0,0 +1,177 @@
+/// Creates Karma queues on demand, for actions routed to a queue that does
+/// not exist yet, such as one queue per customer.
+///
+/// Creation is serialized, so concurrent misses on one name call the factory
+/// once and all route to the queue it returns; consumers running
+/// `Sequence::RunKarma` pick the queue up on their next round. The queues
+/// created here count against a cap: once it is reached, the least recently
+/// routed of them holding no action is evicted to make room, and creation
+/// fails with `Error::Routing` if none can be. Queues added with
+/// `Builder::WithQueue` neither count nor are evicted.
+pub struct Struct {
+	/// The factory creating queues, or `None` to create none.
+	Factory:Mutex<Option<Factory>>,
+
+	/// How many queues the factory may have created at once.
+	Limit:AtomicUsize,
+
+	/// The queues the factory created, with the last time an action was
+	/// routed to each.
+	Queue:Mutex<HashMap<String, Instant>>,
+}
+
+impl Struct {
+	/// Creates a new instance creating no queues, capped at 1024 once a
+	/// factory is configured.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct {
+			Factory:Mutex::new(None),
+			Limit:AtomicUsize::new(1024),
+			Queue:Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Changes the factory and the cap. Queues created before stay.
+	///
+	/// # Arguments
+	///
+	/// * `Factory` - The factory creating queues, or `None` to create none.
+	/// * `Limit` - How many created queues may exist at once.
+	pub fn Configure(&self, Factory:Option<Factory>, Limit:usize) {
+		*Lock(&self.Factory) = Factory;
+
+		self.Limit.store(Limit, Ordering::Relaxed);
+	}
+
+	/// Lists the queues the factory created that still exist.
+	///
+	/// # Returns
+	///
+	/// Their names, the least recently routed first.
+	pub fn Queues(&self) -> Vec<String> {
+		let mut Queue = Lock(&self.Queue)
+			.iter()
+			.map(|(Name, Used)| (*Used, Name.clone()))
+			.collect::<Vec<_>>();
+
+		Queue.sort();
+
+		Queue.into_iter().map(|(_, Name)| Name).collect()
+	}
+
+	/// Records that an action was routed to a queue.
+	pub(crate) fn Touch(&self, Name:&str) {
+		if let Some(Used) = Lock(&self.Queue).get_mut(Name) {
+			*Used = Instant::now();
+		}
+	}
+
+	/// Returns the queue of a name, creating it with the factory if missing.
+	///
+	/// # Returns
+	///
+	/// The production line of the queue, or `Error::Routing` if there is no
+	/// factory or the cap is reached with no queue to evict.
+	pub(crate) fn Create(&self, Life:&Life, Name:&str) -> Result<Arc<Production>, Error> {
+		let mut Queue = Lock(&self.Queue);
+
+		// Another miss on the name may have created it meanwhile
+		if let Some(Production) = Life.Karma.get(Name).map(|Production| Production.clone()) {
+			if let Some(Used) = Queue.get_mut(Name) {
+				*Used = Instant::now();
+			}
+
+			return Ok(Production);
+		}
+
+		let Some(Factory) = Lock(&self.Factory).clone() else {
+			return Err(Error::Routing(format!("Unknown queue: {}", Name)));
+		};
+
+		let Limit = self.Limit.load(Ordering::Relaxed);
+
+		if Queue.len() >= Limit {
+			let Idle = Queue
+				.iter()
+				.filter(|(Name, _)| {
+					Life.Karma
+						.get(*Name)
+						.is_none_or(|Production| Production.Stats().Depth == 0)
+				})
+				.min_by_key(|(_, Used)| **Used)
+				.map(|(Name, _)| Name.clone());
+
+			let Some(Idle) = Idle else {
+				return Err(Error::Routing(format!(
+					"Cannot create queue {}: {} dynamic queues exist and none is idle",
+					Name, Limit
+				)));
+			};
+
+			Queue.remove(&Idle);
+
+			Life.Karma.remove(&Idle);
+
+			Life.Settings.remove(&Idle.to_lowercase());
+
+			counter!("echo_queues_evicted_total").increment(1);
+
+			info!("Evicted idle queue {} to create queue {}", Idle, Name);
+		}
+
+		let (Production, Settings) = Factory(Name);
+
+		Production.Attach(&Life.Bus);
+
+		Production.Enlist(&Life.Actions);
+
+		Production.Bound(&format!("results.{}", Name), Life::Limit(&Life.Fate, "results"));
+
+		Production.Restrict(Life::Chain(&Life.Fate));
+
+		Life.Settings.insert(Name.to_lowercase(), Settings);
+
+		Life.Karma.insert(Name.to_string(), Production.clone());
+
+		Queue.insert(Name.to_string(), Instant::now());
+
+		counter!("echo_queues_created_total").increment(1);
+
+		info!("Created queue {}", Name);
+
+		Ok(Production)
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+	},
+};
+
+use log::info;
+use metrics::counter;
+use tokio::time::Instant;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Life::Struct as Life, Production::Struct as Production},
+	Type::Sequence::Production::Factory::Type as Factory,
+};
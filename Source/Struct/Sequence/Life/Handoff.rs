@@ -0,0 +1,62 @@
+/// Pauses the Karma consumers while the queued actions are handed off to
+/// another process.
+///
+/// A handoff takes `Begin` for as long as it runs, so handoffs run one at a
+/// time and a new one waits until the actions of an interrupted one are back
+/// in their queues.
+pub struct Struct {
+	/// Whether the Karma consumers take no actions.
+	Paused:AtomicBool,
+
+	/// Held by the running handoff.
+	Running:Arc<Mutex<()>>,
+}
+
+impl Struct {
+	/// Creates a new instance pausing nothing.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct { Paused:AtomicBool::new(false), Running:Arc::new(Mutex::new(())) }
+	}
+
+	/// Stops the Karma consumers from taking actions; running actions go on.
+	pub fn Pause(&self) {
+		if !self.Paused.swap(true, Ordering::SeqCst) {
+			info!("Pausing the Karma consumers for a handoff");
+		}
+	}
+
+	/// Lets the Karma consumers take actions again.
+	pub fn Resume(&self) {
+		if self.Paused.swap(false, Ordering::SeqCst) {
+			info!("Resuming the Karma consumers");
+		}
+	}
+
+	/// Returns whether the Karma consumers are paused.
+	pub fn Paused(&self) -> bool { self.Paused.load(Ordering::SeqCst) }
+
+	/// Waits until no other handoff runs.
+	///
+	/// # Returns
+	///
+	/// The guard held for as long as the handoff runs.
+	pub(crate) async fn Begin(&self) -> OwnedMutexGuard<()> {
+		self.Running.clone().lock_owned().await
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+
+use log::info;
+use tokio::sync::{Mutex, OwnedMutexGuard};
@@ -0,0 +1,128 @@
+/// Keeps the results of cacheable actions and the refreshes running for
+/// them.
+///
+/// Results are keyed by `Digest::ActionDigest` of the action type and its
+/// arguments, stamped with the time they were stored, and bounded by the
+/// `[stores.memo]` limits. The same key single-flights the background
+/// refreshes of stale results: while one runs, other stale hits on the key
+/// start none.
+pub struct Struct {
+	/// The results with the time they were stored, by key.
+	Entry:Store::Struct<String, (Value, Instant)>,
+
+	/// The keys whose result is being refreshed.
+	Refreshing:DashMap<String, ()>,
+}
+
+impl Struct {
+	/// Creates a new, empty memo.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How much the memo may hold.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Limit:Limit::Struct) -> Self {
+		Struct { Entry:Store::Struct::New("memo", Limit), Refreshing:DashMap::new() }
+	}
+
+	/// Returns the key of an action.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action type.
+	/// * `Argument` - The arguments of the action.
+	///
+	/// # Returns
+	///
+	/// The digest of both, in hex.
+	pub fn Key(Action:&str, Argument:&[Value]) -> String {
+		Digest::Hex(&Digest::ActionDigest(Action, Argument, &Map::new()))
+	}
+
+	/// Reads the result stored under a key, if it is still served.
+	///
+	/// A result past its `HardTtl` is removed.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the action.
+	/// * `Cacheable` - How long results of the action are served.
+	/// * `Now` - The current time.
+	///
+	/// # Returns
+	///
+	/// The result and whether it is stale, or `None` if there is none to
+	/// serve.
+	pub fn Get(&self, Key:&String, Cacheable:&Cacheable, Now:Instant) -> Option<(Value, bool)> {
+		let (Value, Stored) = self.Entry.Get(Key)?;
+
+		match Cacheable.Stale(Now.saturating_duration_since(Stored)) {
+			Some(Stale) => Some((Value, Stale)),
+			None => {
+				self.Entry.Remove(Key);
+
+				None
+			},
+		}
+	}
+
+	/// Stores a result under a key.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the action.
+	/// * `Value` - The result of the action.
+	/// * `Now` - The current time, which the result is as of.
+	pub fn Put(&self, Key:String, Value:Value, Now:Instant) {
+		let Size = serde_json::to_vec(&Value).map(|Bytes| Bytes.len()).unwrap_or_default();
+
+		self.Entry.Insert(Key, (Value, Now), Size);
+	}
+
+	/// Claims the refresh of a key.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the action.
+	///
+	/// # Returns
+	///
+	/// `true` if no refresh of the key was running, in which case the caller
+	/// runs it and calls `Release` once it ended.
+	pub fn Claim(&self, Key:&str) -> bool { self.Refreshing.insert(Key.to_string(), ()).is_none() }
+
+	/// Releases the refresh of a key claimed with `Claim`.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the action.
+	pub fn Release(&self, Key:&str) { self.Refreshing.remove(Key); }
+
+	/// Applies new limits, evicting the least recently used results.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How much the memo may hold.
+	pub fn Resize(&self, Limit:Limit::Struct) { self.Entry.Resize(Limit); }
+
+	/// Returns the number of results stored.
+	pub fn Len(&self) -> usize { self.Entry.Len() }
+
+	/// Checks whether no result is stored.
+	pub fn IsEmpty(&self) -> bool { self.Entry.IsEmpty() }
+}
+
+use dashmap::DashMap;
+use serde_json::{Map, Value};
+use tokio::time::Instant;
+
+use crate::{
+	Digest,
+	Struct::{
+		Sequence::Action::Cacheable::Struct as Cacheable,
+		Store::{self, Limit},
+	},
+};
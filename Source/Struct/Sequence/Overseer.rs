@@ -0,0 +1,173 @@
+/// Spawns the internal tasks of a `Life` and watches over them.
+///
+/// Every task runs under a name. When it panics, the overseer logs the
+/// panic, publishes `Event::TaskFailed` on the event bus, increments
+/// `echo_task_failures_total` and restarts the task as its `Restart` policy
+/// says. Tasks are listed with their restart counts by `Tasks` until they
+/// finish. Aborting the handle returned by `Spawn` stops the task as well.
+///
+/// Tasks are spawned, and restarts waited for, through its `Runtime`.
+pub struct Struct {
+	/// The event bus on which failures are published.
+	Bus:Bus::Struct,
+
+	/// The runtime the tasks are spawned on.
+	Runtime:RwLock<Arc<dyn Runtime>>,
+
+	/// The running tasks, by spawn number.
+	Task:Arc<Mutex<BTreeMap<u64, Task::Struct>>>,
+
+	/// The spawn number handed to the next task.
+	Next:AtomicU64,
+}
+
+/// Removes a task from the list when its oversight ends.
+struct Listing(Arc<Mutex<BTreeMap<u64, Task::Struct>>>, u64);
+
+/// Aborts a task when dropped.
+struct Abort(Spawned::Struct);
+
+impl Struct {
+	/// Creates an overseer publishing failures on a bus, spawning on
+	/// tokio.
+	///
+	/// # Arguments
+	///
+	/// * `Bus` - The event bus on which failures are published.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Bus:Bus::Struct) -> Self {
+		Struct {
+			Bus,
+			Runtime:RwLock::new(Arc::new(Tokio::Struct)),
+			Task:Arc::new(Mutex::new(BTreeMap::new())),
+			Next:AtomicU64::new(0),
+		}
+	}
+
+	/// Returns the runtime tasks are spawned on.
+	pub fn Runtime(&self) -> Arc<dyn Runtime> {
+		self.Runtime.read().unwrap_or_else(|Poison| Poison.into_inner()).clone()
+	}
+
+	/// Replaces the runtime tasks are spawned on; tasks already running
+	/// stay where they are.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime to spawn on.
+	pub fn Use(&self, Runtime:Arc<dyn Runtime>) {
+		*self.Runtime.write().unwrap_or_else(|Poison| Poison.into_inner()) = Runtime;
+	}
+
+	/// Spawns a supervised task.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the task, used in events, metrics and `Tasks`.
+	/// * `Restart` - What to do when the task panics.
+	/// * `Task` - Builds the task; called again for every restart.
+	///
+	/// # Returns
+	///
+	/// The handle of the oversight, finishing once the task returned or
+	/// stayed down.
+	pub fn Spawn<F, Fut>(&self, Name:&str, Restart:Restart, Task:F) -> Spawned::Struct
+	where
+		F: Fn() -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = ()> + Send + 'static, {
+		let Id = self.Next.fetch_add(1, Ordering::Relaxed);
+
+		Lock(&self.Task).insert(Id, Task::Struct { Name:Name.to_string(), Restarts:0 });
+
+		let (Bus, Listed, Name, Runtime) =
+			(self.Bus.clone(), Listing(self.Task.clone(), Id), Name.to_string(), self.Runtime());
+
+		Runtime.clone().Spawn(Box::pin(async move {
+			let mut Restarts = 0;
+
+			loop {
+				let mut Running = Abort(Runtime.Spawn(Box::pin(Task())));
+
+				let Error = match (&mut Running.0).await {
+					Ok(()) | Err(Exit::Cancelled) => return,
+					Err(Exit::Panicked(Error)) => Error,
+				};
+
+				error!("Task {} panicked: {}", Name, Error);
+
+				counter!("echo_task_failures_total", "task" => Name.clone()).increment(1);
+
+				Bus.Emit(|| Event::TaskFailed { Name:Name.clone(), Error:Error.clone() });
+
+				let Backoff = match Restart {
+					Restart::Limited { Restarts: Limit, Backoff } if Restarts < Limit => Backoff,
+					Restart::Always { Backoff } => Backoff,
+					Restart::Never | Restart::Limited { .. } => return,
+				};
+
+				Restarts += 1;
+
+				if let Some(Task) = Lock(&Listed.0).get_mut(&Listed.1) {
+					Task.Restarts = Restarts;
+				}
+
+				warn!("Restarting task {} in {:?} (restart {})", Name, Backoff, Restarts);
+
+				Runtime.Sleep(Backoff).await;
+			}
+		}))
+	}
+
+	/// Lists the running tasks.
+	///
+	/// # Returns
+	///
+	/// The running tasks in spawn order, with their restart counts.
+	pub fn Tasks(&self) -> Vec<Task::Struct> { Lock(&self.Task).values().cloned().collect() }
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Bus::Struct::default()) }
+}
+
+impl Drop for Listing {
+	fn drop(&mut self) { Lock(&self.0).remove(&self.1); }
+}
+
+impl Drop for Abort {
+	fn drop(&mut self) { self.0.Abort(); }
+}
+
+/// Takes the lock of the task list, ignoring poisoning.
+fn Lock(Task:&Mutex<BTreeMap<u64, Task::Struct>>) -> MutexGuard<'_, BTreeMap<u64, Task::Struct>> {
+	Task.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::BTreeMap,
+	future::Future,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+		RwLock,
+	},
+};
+
+use log::{error, warn};
+use metrics::counter;
+
+use crate::{
+	Enum::{Event::Enum as Event, Runtime::Exit::Enum as Exit, Sequence::Restart::Enum as Restart},
+	Struct::{
+		Event::Bus,
+		Runtime::{Spawned, Tokio},
+	},
+	Trait::Runtime::Trait as Runtime,
+};
+
+pub mod Task;
@@ -0,0 +1,11 @@
+/// A running internal task, as listed by `Life::Tasks`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The name the task was spawned under, e.g. `timer`.
+	pub Name:String,
+
+	/// How many times the task was restarted after a panic.
+	pub Restarts:u32,
+}
+
+use serde::{Deserialize, Serialize};
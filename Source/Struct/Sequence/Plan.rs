@@ -56,6 +56,176 @@ impl Struct {
 		Ok(self)
 	}
 
+	/// Adds a function taking its arguments as `Arg` to the plan, see
+	/// `Formality::AddArg`.
+	///
+	/// # Arguments
+	/// * `Name` - The name of the function.
+	/// * `Function` - The function to add.
+	///
+	/// # Returns
+	/// A `Result` containing the modified `Struct` instance if successful,
+	/// or an error message as a `String` if the operation fails.
+	pub fn WithArgFunction<F, Fut>(mut self, Name:&str, Function:F) -> Result<Self, String>
+	where
+		F: Fn(Vec<Arg>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<serde_json::Value, crate::Enum::Sequence::Action::Error::Enum>>
+			+ Send
+			+ 'static, {
+		self.Formality.AddArg(Name, Function)?;
+
+		Ok(self)
+	}
+
+	/// Sets whether the actions of the plan read reserved metadata keys in
+	/// their legacy flat form too, see `Formality::WithLegacyKeys`.
+	///
+	/// # Arguments
+	/// * `Legacy` - Whether the flat forms are read and written.
+	///
+	/// # Returns
+	/// The modified `Struct` instance, allowing for method chaining.
+	pub fn WithLegacyKeys(mut self, Legacy:bool) -> Self {
+		self.Formality.WithLegacyKeys(Legacy);
+
+		self
+	}
+
+	/// Adds a function reporting its progress to the plan, see
+	/// `Formality::AddWithProgress`.
+	///
+	/// # Arguments
+	/// * `Name` - The name of the function.
+	/// * `Function` - The function to add.
+	///
+	/// # Returns
+	/// A `Result` containing the modified `Struct` instance if successful,
+	/// or an error message as a `String` if the operation fails.
+	pub fn WithProgressFunction<F, Fut>(mut self, Name:&str, Function:F) -> Result<Self, String>
+	where
+		F: Fn(Vec<serde_json::Value>, Signal<f32>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<serde_json::Value, crate::Enum::Sequence::Action::Error::Enum>>
+			+ Send
+			+ 'static, {
+		self.Formality.AddWithProgress(Name, Function)?;
+
+		Ok(self)
+	}
+
+	/// Sets the default metadata of an action type, see
+	/// `Formality::WithDefaults`.
+	///
+	/// # Arguments
+	/// * `Name` - The name of the action type, signed already.
+	/// * `Defaults` - The metadata applied where an action sets none.
+	///
+	/// # Returns
+	/// The modified `Struct` instance, or an error message if the type is not
+	/// signed or a key is reserved.
+	pub fn WithDefaults(
+		self,
+		Name:&str,
+		Defaults:serde_json::Map<String, serde_json::Value>,
+	) -> Result<Self, String> {
+		self.Formality.WithDefaults(Name, Defaults)?;
+
+		Ok(self)
+	}
+
+	/// Transforms the arguments of every function of the plan, see
+	/// `Formality::WithArgumentTransformer`.
+	///
+	/// # Arguments
+	/// * `Transformer` - The transformer, replacing any previous one.
+	///
+	/// # Returns
+	/// The modified `Struct` instance, allowing for method chaining.
+	pub fn WithArgumentTransformer(
+		mut self,
+		Transformer:Arc<dyn crate::Trait::Sequence::Transformer::Trait>,
+	) -> Self {
+		self.Formality.WithArgumentTransformer(Transformer);
+
+		self
+	}
+
+	/// Adds a typed handler to the plan.
+	///
+	/// The handler is registered under its `Kind`, together with a matching
+	/// signature. Its request is decoded from the action's arguments with
+	/// `Fn::Argument::Decode`, so failures such as unknown fields or wrong
+	/// types are reported as `Error::InvalidRequest`, naming the kind and the
+	/// path to the offending value, instead of panicking. The
+	/// arguments are converted into JSON only as the request is decoded.
+	///
+	/// # Arguments
+	/// * `Handler` - The handler to add.
+	///
+	/// # Type Parameters
+	/// * `H` - The type of the handler.
+	///
+	/// # Returns
+	/// A `Result` containing the modified `Struct` instance if successful,
+	/// or an error message as a `String` if the operation fails.
+	pub fn WithHandler<H:Handler>(self, Handler:H) -> Result<Self, String> {
+		let Handler = Arc::new(Handler);
+
+		self.WithSignature(
+			crate::Struct::Sequence::Action::Signature::Struct::New(H::Kind()).WithTypes(
+				std::any::type_name::<H::Request>(),
+				std::any::type_name::<H::Response>(),
+			),
+		)
+		.WithArgFunction(H::Kind(), move |Argument:Vec<Arg>| {
+			let Handler = Handler.clone();
+
+			async move {
+				let Request = crate::Fn::Argument::Decode::Fn(H::Kind(), Argument)?;
+
+				serde_json::to_value(Handler.Run(Request).await?).map_err(|_Error| {
+					Error::Execution(format!("Invalid response from {}: {}", H::Kind(), _Error))
+				})
+			}
+		})
+	}
+
+	/// Builds a plan from registration records collected across modules.
+	///
+	/// Every record is registered together with a matching signature. Records
+	/// that are compiled out with `#[cfg]` simply never reach this call.
+	///
+	/// # Arguments
+	/// * `Records` - The records to register, typically the concatenation of
+	///   per-module record slices.
+	///
+	/// # Returns
+	/// A `Result` containing the new `Struct` instance, or an error message
+	/// naming both definition sites if two records share a name.
+	pub fn FromRecords<'a>(
+		Records:impl IntoIterator<Item = &'a Record::Struct>,
+	) -> Result<Self, String> {
+		let mut Site = HashMap::<&str, &Record::Struct>::new();
+
+		let mut Plan = Self::New();
+
+		for Record in Records {
+			if let Some(Previous) = Site.insert(Record.Name, Record) {
+				return Err(format!(
+					"Duplicate action {}: defined in {} and in {}",
+					Record.Name,
+					Previous.Site(),
+					Record.Site()
+				));
+			}
+
+			Plan = Plan
+				.WithSignature(crate::Struct::Sequence::Action::Signature::Struct::New(Record.Name))
+				.WithFunction(Record.Name, Record.Function)?;
+		}
+
+		Ok(Plan)
+	}
+
 	/// Finalizes the plan and returns the `Formality`.
 	///
 	/// # Returns
@@ -63,6 +233,17 @@ impl Struct {
 	pub fn Build(self) -> Formality::Struct { self.Formality }
 }
 
+use std::{collections::HashMap, sync::Arc};
+
 use futures::Future;
 
+use crate::{
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::Signal::Struct as Signal,
+	Trait::Sequence::Handler::Trait as Handler,
+};
+
 pub mod Formality;
+pub mod Manifest;
+pub mod Record;
+pub mod Typed;
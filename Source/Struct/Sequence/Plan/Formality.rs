@@ -6,19 +6,19 @@ pub struct Struct {
 	/// A concurrent hash map storing action signatures, keyed by their names.
 	Signature:DashMap<String, Signature>,
 
-	/// A concurrent hash map storing boxed functions, keyed by action names.
-	///
-	/// These functions take a vector of JSON values as input and return a
-	/// pinned future that resolves to a Result containing either a JSON value
-	/// or an Error.
-	Function: DashMap<
-		String,
-		Box<
-			dyn Fn(Vec<Value>) -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send>>
-				+ Send
-				+ Sync,
-		>,
-	>,
+	/// A concurrent hash map storing shared functions, keyed by action names.
+	///
+	/// These functions take a vector of `Arg` as input and return a pinned
+	/// future that resolves to a Result containing either a JSON value or an
+	/// Error.
+	Function:DashMap<String, Function>,
+
+	/// Transforms the arguments of every call, if set.
+	Transformer:Option<Arc<dyn Transformer>>,
+
+	/// Whether reserved metadata keys are still read in their flat form and
+	/// written in both; see `Reserved`.
+	Legacy:bool,
 }
 
 impl Struct {
@@ -27,7 +27,14 @@ impl Struct {
 	/// # Returns
 	///
 	/// A new `Struct` instance.
-	pub fn New() -> Self { Self { Signature:DashMap::new(), Function:DashMap::new() } }
+	pub fn New() -> Self {
+		Self {
+			Signature:DashMap::new(),
+			Function:DashMap::new(),
+			Transformer:None,
+			Legacy:true,
+		}
+	}
 
 	/// Adds a signature to the Signature DashMap.
 	///
@@ -67,18 +74,349 @@ impl Struct {
 			return Err(format!("No signature found for function: {}", Name));
 		}
 
+		self.Function.insert(Name.to_string(), Self::Box(Function));
+
+		Ok(self)
+	}
+
+	/// Adds a function taking its arguments as `Arg`, so payloads such as
+	/// the bytes of a binary frame reach it without being converted or
+	/// copied.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function.
+	/// * `Function` - The function to be added.
+	///
+	/// # Returns
+	///
+	/// A Result containing either a mutable reference to self or an error
+	/// string.
+	///
+	/// # Errors
+	///
+	/// Returns an error if no signature is found for the given function name.
+	pub fn AddArg<F, Fut>(&mut self, Name:&str, Function:F) -> Result<&mut Self, String>
+	where
+		F: Fn(Vec<Arg>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<Value, Error>> + Send + 'static, {
+		if !self.Signature.contains_key(Name) {
+			return Err(format!("No signature found for function: {}", Name));
+		}
+
 		self.Function.insert(
 			Name.to_string(),
-			Box::new(
-				move |Argument:Vec<Value>| -> Pin<
-					Box<dyn Future<Output = Result<Value, Error>> + Send>,
-				> { Box::pin(Function(Argument)) },
-			),
+			Arc::new(move |Argument:Vec<Arg>| -> Pinned { Box::pin(Function(Argument)) }),
 		);
 
 		Ok(self)
 	}
 
+	/// Sets whether the actions of the plan still read reserved metadata
+	/// keys in their legacy flat form, e.g. `Delay` for `__echo.Delay`, with
+	/// a deprecation warning, and write their options in both forms. On by
+	/// default for this release.
+	///
+	/// # Arguments
+	///
+	/// * `Legacy` - Whether the flat forms are read and written.
+	///
+	/// # Returns
+	///
+	/// A mutable reference to self for method chaining.
+	pub fn WithLegacyKeys(&mut self, Legacy:bool) -> &mut Self {
+		self.Legacy = Legacy;
+
+		self
+	}
+
+	/// Returns whether reserved metadata keys are read and written in their
+	/// legacy flat form too.
+	pub fn LegacyKeys(&self) -> bool { self.Legacy }
+
+	/// Adds a function reporting its progress, e.g. one copying a large
+	/// file.
+	///
+	/// Besides its arguments, the function receives a signal it sets from
+	/// 0.0 to 1.0 as it goes on: the `Progress` of the action it runs for,
+	/// so callers holding a clone of that signal observe it, or a signal
+	/// nobody observes if the action exposes none.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function.
+	/// * `Function` - The function to be added.
+	///
+	/// # Returns
+	///
+	/// A Result containing either a mutable reference to self or an error
+	/// string.
+	///
+	/// # Errors
+	///
+	/// Returns an error if no signature is found for the given function name.
+	pub fn AddWithProgress<F, Fut>(&mut self, Name:&str, Function:F) -> Result<&mut Self, String>
+	where
+		F: Fn(Vec<Value>, Signal<f32>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<Value, Error>> + Send + 'static, {
+		self.Add(Name, move |Argument| {
+			let Progress = Invocation::Current()
+				.and_then(|Invocation| Invocation.Progress())
+				.unwrap_or_else(|| Signal::New(0.0));
+
+			Function(Argument, Progress)
+		})
+	}
+
+	/// Transforms the arguments of every function as they cross into it,
+	/// e.g. with a `Secret::Resolver`.
+	///
+	/// The transformer runs after the arguments were resolved from the
+	/// action and before the function is called, and masks what the function
+	/// returns; the action itself keeps the arguments as submitted. As a
+	/// transformer works on JSON values, every argument is converted with
+	/// `Arg::Value` first.
+	///
+	/// # Arguments
+	///
+	/// * `Transformer` - The transformer, replacing any previous one.
+	///
+	/// # Returns
+	///
+	/// A mutable reference to self for method chaining.
+	pub fn WithArgumentTransformer(&mut self, Transformer:Arc<dyn Transformer>) -> &mut Self {
+		self.Transformer = Some(Transformer);
+
+		self
+	}
+
+	/// Adds a signature and its function to a plan that may already be
+	/// shared, e.g. by a running transport.
+	///
+	/// Actions of the new type accepted before, and not yet executed, run
+	/// with the function once dequeued. This is the building block for
+	/// hot-extending a plan.
+	///
+	/// # Arguments
+	///
+	/// * `Signature` - The signature of the action.
+	/// * `Function` - The function executing the action.
+	pub fn Extend<F, Fut>(&self, Signature:Signature, Function:F)
+	where
+		F: Fn(Vec<Value>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<Value, Error>> + Send + 'static, {
+		self.Function.insert(Signature.Name.clone(), Self::Box(Function));
+
+		self.Signature.insert(Signature.Name.clone(), Signature);
+	}
+
+	/// Replaces the function registered for an action.
+	///
+	/// Executions that already looked up the previous function finish with
+	/// it; every lookup afterwards gets the replacement. This is the building
+	/// block for hot-reloading functions.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function to replace.
+	/// * `Function` - The replacement function.
+	///
+	/// # Returns
+	///
+	/// A Result containing the previous function, or an error string.
+	///
+	/// # Errors
+	///
+	/// Returns an error if no function is registered under the given name.
+	pub fn Replace<F, Fut>(&self, Name:&str, Function:F) -> Result<Function, String>
+	where
+		F: Fn(Vec<Value>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<Value, Error>> + Send + 'static, {
+		match self.Function.get_mut(Name) {
+			Some(mut Entry) => Ok(std::mem::replace(Entry.value_mut(), Self::Box(Function))),
+			None => Err(format!("No function found to replace: {}", Name)),
+		}
+	}
+
+	/// Sets the default metadata of an action type, also on a plan that is
+	/// already shared.
+	///
+	/// Actions read their defaults as they execute, so a change applies to
+	/// every action executed afterwards, whenever it was submitted; metadata
+	/// an action sets itself always wins.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action type.
+	/// * `Defaults` - The metadata applied where an action sets none, replacing
+	///   the previous defaults.
+	///
+	/// # Returns
+	///
+	/// A Result containing a reference to self, or an error string.
+	///
+	/// # Errors
+	///
+	/// Returns an error if no signature is found for the action type, or if
+	/// the defaults set a key of `Invocation::RESERVED`.
+	pub fn WithDefaults(&self, Name:&str, Defaults:Map<String, Value>) -> Result<&Self, String> {
+		if let Some(Key) = Defaults.keys().find(|Key| RESERVED.contains(&Key.as_str())) {
+			return Err(format!("Metadata key {} is reserved and cannot default", Key));
+		}
+
+		match self.Signature.get_mut(Name) {
+			Some(mut Signature) => Signature.Defaults = Defaults,
+			None => return Err(format!("No signature found for defaults: {}", Name)),
+		}
+
+		Ok(self)
+	}
+
+	/// Returns the default of a metadata key for an action type.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action type.
+	/// * `Key` - The metadata key.
+	///
+	/// # Returns
+	///
+	/// The value actions of the type carry under `Key` unless they set it.
+	pub fn Default(&self, Name:&str, Key:&str) -> Option<Value> {
+		self.Signature.get(Name)?.Defaults.get(Key).cloned()
+	}
+
+	/// Returns the function registered for an action, leaving it in place.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function.
+	///
+	/// # Returns
+	///
+	/// An Option containing a shared handle to the function, if it exists,
+	/// calling it through the argument transformer if one is set.
+	pub fn Get(&self, Name:&str) -> Option<Function> {
+		let Function = self.Function.get(Name).map(|Entry| Entry.value().clone())?;
+
+		let Some(Transformer) = self.Transformer.clone() else {
+			return Some(Function);
+		};
+
+		let Signature = self
+			.Signature
+			.get(Name)
+			.map(|Entry| Entry.value().clone())
+			.unwrap_or_else(|| Signature::New(Name));
+
+		Some(Arc::new(move |Argument:Vec<Arg>| -> Pinned {
+			let (Function, Transformer, Signature) =
+				(Function.clone(), Transformer.clone(), Signature.clone());
+
+			Box::pin(async move {
+				let Argument = Transformer
+					.Transform(&Signature, Argument.into_iter().map(Arg::Value).collect())
+					.await?;
+
+				Transformer.Mask(Function(Argument.into_iter().map(Arg::Json).collect()).await)
+			})
+		}))
+	}
+
+	/// Checks whether a function is registered for an action.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the function.
+	///
+	/// # Returns
+	///
+	/// `true` if the plan handles the action type.
+	pub fn Has(&self, Name:&str) -> bool { self.Function.contains_key(Name) }
+
+	/// Checks whether the function of an action runs on the blocking thread
+	/// pool.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	///
+	/// # Returns
+	///
+	/// `true` if the action's signature is `Blocking`.
+	pub fn Blocking(&self, Name:&str) -> bool {
+		self.Signature.get(Name).is_some_and(|Signature| Signature.Blocking)
+	}
+
+	/// Returns how long the results of an action are served, if its
+	/// signature is `Cacheable`.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	///
+	/// # Returns
+	///
+	/// The cache setting of the action's signature, if any.
+	pub fn Cacheable(&self, Name:&str) -> Option<Cacheable> {
+		self.Signature.get(Name).and_then(|Signature| Signature.Cacheable)
+	}
+
+	/// Checks the arguments of a call against the `InputTypes` of the
+	/// signature of its action, if it has one.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	/// * `Argument` - The arguments the function would receive.
+	///
+	/// # Returns
+	///
+	/// `Ok(())`, or an `Error::Execution` naming the first argument whose
+	/// type or count does not match.
+	pub fn CheckInput(&self, Name:&str, Argument:&[Arg]) -> Result<(), Error> {
+		self.Signature.get(Name).map_or(Ok(()), |Signature| Signature.CheckInput(Argument))
+	}
+
+	/// Checks the result of a call against the `OutputType` of the signature
+	/// of its action, if it has one.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	/// * `Value` - The value the function returned.
+	///
+	/// # Returns
+	///
+	/// `Ok(())`, or an `Error::Execution` naming the type expected.
+	pub fn CheckOutput(&self, Name:&str, Value:&Value) -> Result<(), Error> {
+		self.Signature.get(Name).map_or(Ok(()), |Signature| Signature.CheckOutput(Value))
+	}
+
+	/// Describes an action type the plan does not handle.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the missing function.
+	///
+	/// # Returns
+	///
+	/// An `Error::UnknownAction` listing up to 16 of the known action types,
+	/// followed by how many more there are.
+	pub fn Unknown(&self, Name:&str) -> Error {
+		let mut Known = self.Names();
+
+		if Known.len() > 16 {
+			let More = Known.len() - 16;
+
+			Known.truncate(16);
+
+			Known.push(format!("and {} more", More));
+		}
+
+		Error::UnknownAction { Action:Name.to_string(), Known }
+	}
+
 	/// Removes and returns a function from the Function DashMap.
 	///
 	/// # Arguments
@@ -87,19 +425,70 @@ impl Struct {
 	///
 	/// # Returns
 	///
-	/// An Option containing a reference to the removed function, if it exists.
-	pub fn Remove(
-		&self,
-		Name:&str,
-	) -> Option<
-		Box<
-			dyn Fn(Vec<Value>) -> Pin<Box<dyn Future<Output = Result<Value, Error>> + Send>>
-				+ Send
-				+ Sync,
-		>,
-	> {
+	/// An Option containing the removed function, if it exists.
+	pub fn Remove(&self, Name:&str) -> Option<Function> {
 		self.Function.remove(Name).map(|(_, v)| v)
 	}
+
+	/// Describes the actions the plan provides, without their functions.
+	///
+	/// # Returns
+	///
+	/// The signatures of the actions that have a function, sorted by name.
+	pub fn ExportManifest(&self) -> Manifest::Struct {
+		Manifest::Struct::New(
+			self.Signature
+				.iter()
+				.filter(|Entry| self.Function.contains_key(Entry.key()))
+				.map(|Entry| Entry.value().clone())
+				.collect(),
+		)
+	}
+
+	/// Checks that the plan provides the actions of a manifest with
+	/// compatible signatures.
+	///
+	/// # Arguments
+	///
+	/// * `Manifest` - The manifest the plan is expected to provide.
+	///
+	/// # Returns
+	///
+	/// How the plan differs from the manifest; the plan satisfies it if the
+	/// diff is `Compatible`.
+	pub fn ValidateAgainstManifest(&self, Manifest:&Manifest::Struct) -> Diff {
+		Manifest.Diff(&self.ExportManifest())
+	}
+
+	/// Lists the names of the functions in the DashMap.
+	///
+	/// # Returns
+	///
+	/// The names of the registered functions, sorted.
+	pub fn Names(&self) -> Vec<String> {
+		let mut Names = self
+			.Function
+			.iter()
+			.map(|Entry| Entry.key().clone())
+			.collect::<Vec<_>>();
+
+		Names.sort();
+
+		Names
+	}
+}
+
+impl Struct {
+	/// Wraps a function into the shared, type-erased form stored in the map,
+	/// converting its arguments into JSON values with `Arg::Value`.
+	fn Box<F, Fut>(Function:F) -> Function
+	where
+		F: Fn(Vec<Value>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<Value, Error>> + Send + 'static, {
+		Arc::new(move |Argument:Vec<Arg>| -> Pinned {
+			Box::pin(Function(Argument.into_iter().map(Arg::Value).collect()))
+		})
+	}
 }
 
 impl Debug for Struct {
@@ -110,13 +499,20 @@ impl Debug for Struct {
 	}
 }
 
-use std::{fmt::Debug, pin::Pin};
+use std::{fmt::Debug, sync::Arc};
 
 use dashmap::DashMap;
 use futures::Future;
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 use crate::{
-	Enum::Sequence::Action::Error::Enum as Error,
-	Struct::Sequence::Action::Signature::Struct as Signature,
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::{
+		Action::{Cacheable::Struct as Cacheable, Signature::Struct as Signature},
+		Invocation::{Struct as Invocation, RESERVED},
+		Plan::Manifest::{self, Diff::Struct as Diff},
+		Signal::Struct as Signal,
+	},
+	Trait::Sequence::Transformer::Trait as Transformer,
+	Type::Sequence::Action::{Function::Type as Function, Future::Type as Pinned},
 };
@@ -0,0 +1,124 @@
+/// The signatures a plan exposes, without their functions.
+///
+/// A manifest is exported from a built plan with
+/// `Formality::ExportManifest` and serializes with serde to JSON, TOML or
+/// any other format, so deployments can compare what they expose and a new
+/// environment can be checked against the one it replaces.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The signatures of the actions, sorted by name.
+	#[serde(default)]
+	pub Action:Vec<Signature>,
+}
+
+impl Struct {
+	/// Creates a manifest of signatures, sorting them by name.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The signatures of the actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(mut Action:Vec<Signature>) -> Self {
+		Action.sort_by(|Left, Right| Left.Name.cmp(&Right.Name));
+
+		Struct { Action }
+	}
+
+	/// Compares another manifest with this one.
+	///
+	/// A type this manifest leaves unknown matches any type; every other
+	/// field of a signature present in both must be equal.
+	///
+	/// # Arguments
+	///
+	/// * `Found` - The manifest compared with this expected one.
+	///
+	/// # Returns
+	///
+	/// The actions `Found` lacks, the actions it adds, and the fields of the
+	/// shared actions that differ.
+	pub fn Diff(&self, Found:&Self) -> Diff::Struct {
+		let Expected = self.Index();
+
+		let Found = Found.Index();
+
+		let mut Diff = Diff::Struct::default();
+
+		for (Name, Expected) in &Expected {
+			let Some(Found) = Found.get(Name) else {
+				Diff.Missing.push(Name.to_string());
+
+				continue;
+			};
+
+			let Field = [
+				("Blocking", json!(Expected.Blocking), json!(Found.Blocking)),
+				("Input", json!(Expected.Input), json!(Found.Input)),
+				("Output", json!(Expected.Output), json!(Found.Output)),
+				("InputTypes", Self::Types(Expected), Self::Types(Found)),
+				("OutputType", json!(Expected.OutputType), json!(Found.OutputType)),
+				("Defaults", Self::Defaults(Expected), Self::Defaults(Found)),
+			];
+
+			for (Field, Expected, Found) in Field {
+				if Expected != Found && !Expected.is_null() {
+					Diff.Changed.push(Change::Struct {
+						Action:Name.to_string(),
+						Field:Field.to_string(),
+						Expected,
+						Found,
+					});
+				}
+			}
+		}
+
+		Diff.Extra = Found
+			.keys()
+			.filter(|Name| !Expected.contains_key(*Name))
+			.map(|Name| Name.to_string())
+			.collect();
+
+		Diff
+	}
+
+	/// Describes the default metadata of a signature for comparison, `null`
+	/// when it has none, so a manifest without defaults matches any.
+	fn Defaults(Signature:&Signature) -> Value {
+		if Signature.Defaults.is_empty() {
+			return Value::Null;
+		}
+
+		Value::Object(Signature.Defaults.clone())
+	}
+
+	/// Describes the argument types of a signature for comparison, `null`
+	/// when it has none, so a manifest without them matches any.
+	fn Types(Signature:&Signature) -> Value {
+		if Signature.InputTypes.is_empty() {
+			return Value::Null;
+		}
+
+		json!(Signature.InputTypes)
+	}
+
+	/// Indexes the signatures by name.
+	fn Index(&self) -> BTreeMap<&str, &Signature> {
+		self.Action
+			.iter()
+			.map(|Signature| (Signature.Name.as_str(), Signature))
+			.collect()
+	}
+}
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::Struct::Sequence::Action::Signature::Struct as Signature;
+
+pub mod Change;
+pub mod Diff;
@@ -0,0 +1,18 @@
+/// A field of an action's signature that differs between two manifests.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The name of the action.
+	pub Action:String,
+
+	/// The field that differs: `Blocking`, `Input` or `Output`.
+	pub Field:String,
+
+	/// The value in the expected manifest.
+	pub Expected:Value,
+
+	/// The value in the manifest compared with it.
+	pub Found:Value,
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
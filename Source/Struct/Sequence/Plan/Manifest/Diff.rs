@@ -0,0 +1,53 @@
+/// How a manifest differs from the one it is compared with, as returned by
+/// `Manifest::Diff` and `Formality::ValidateAgainstManifest`.
+///
+/// Displaying it renders one line per difference: `-` for a missing action,
+/// `+` for an extra one and `~` for a changed field.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The expected actions that are not provided, sorted.
+	pub Missing:Vec<String>,
+
+	/// The provided actions that are not expected, sorted.
+	pub Extra:Vec<String>,
+
+	/// The fields of shared actions that differ, by action.
+	pub Changed:Vec<Change>,
+}
+
+impl Struct {
+	/// Returns whether the compared manifest provides every expected action
+	/// with the expected signature. Extra actions are compatible.
+	pub fn Compatible(&self) -> bool { self.Missing.is_empty() && self.Changed.is_empty() }
+
+	/// Returns whether the manifests are the same.
+	pub fn Empty(&self) -> bool { self.Compatible() && self.Extra.is_empty() }
+}
+
+impl Display for Struct {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		for Name in &self.Missing {
+			writeln!(f, "- {}", Name)?;
+		}
+
+		for Name in &self.Extra {
+			writeln!(f, "+ {}", Name)?;
+		}
+
+		for Change in &self.Changed {
+			writeln!(
+				f,
+				"~ {}.{}: {} -> {}",
+				Change.Action, Change.Field, Change.Expected, Change.Found
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Sequence::Plan::Manifest::Change::Struct as Change;
@@ -0,0 +1,66 @@
+/// A registration record for a plan function defined next to its feature code.
+///
+/// Records are usually built with the `Echo::Record!` macro, which captures
+/// the module path and source location of the definition, and are collected
+/// into a plan with `Plan::FromRecords`.
+#[derive(Clone, Copy)]
+pub struct Struct {
+	/// The name of the action the function answers to.
+	pub Name:&'static str,
+
+	/// The module path of the definition, as given by `module_path!`.
+	pub Module:&'static str,
+
+	/// The source file of the definition, as given by `file!`.
+	pub File:&'static str,
+
+	/// The source line of the definition, as given by `line!`.
+	pub Line:u32,
+
+	/// The function registered for the action.
+	pub Function:fn(Vec<Value>) -> Future,
+}
+
+impl Struct {
+	/// Describes where the record was defined, for diagnostics.
+	///
+	/// # Returns
+	///
+	/// A `String` of the form `module (file:line)`.
+	pub fn Site(&self) -> String { format!("{} ({}:{})", self.Module, self.File, self.Line) }
+}
+
+impl Debug for Struct {
+	fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Record")
+			.field("Name", &self.Name)
+			.field("Site", &self.Site())
+			.finish()
+	}
+}
+
+/// Builds a `Plan::Record::Struct` for an async plan function, capturing the
+/// module path and source location of the call site.
+///
+/// # Arguments
+///
+/// * `Name` - The name of the action.
+/// * `Function` - A path to an `async fn(Vec<Value>) -> Result<Value, Error>`.
+#[macro_export]
+macro_rules! Record {
+	($Name:expr, $Function:path) => {
+		$crate::Struct::Sequence::Plan::Record::Struct {
+			Name:$Name,
+			Module:module_path!(),
+			File:file!(),
+			Line:line!(),
+			Function:|Argument| ::std::boxed::Box::pin($Function(Argument)),
+		}
+	};
+}
+
+use std::fmt::Debug;
+
+use serde_json::Value;
+
+use crate::Type::Sequence::Action::Future::Type as Future;
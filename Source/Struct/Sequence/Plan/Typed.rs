@@ -0,0 +1,94 @@
+/// A plan whose complete set of actions is known at compile time.
+///
+/// Actions are the variants of an enum `E` implementing `Set`, and `H` is the
+/// handler answering them. Dispatch is a `match` on the variant, with no map
+/// lookup or boxed future in between. The plan converts into a dynamic
+/// `Formality`, so the same actions can still be served to name-addressed
+/// actions such as those arriving over the wire.
+pub struct Struct<E, H> {
+	/// The handler answering the actions of the set.
+	Handler:Arc<H>,
+
+	/// Marks the action set served by this plan.
+	Set:PhantomData<fn() -> E>,
+}
+
+impl<E:Set<H>, H:Send + Sync + 'static> Struct<E, H> {
+	/// Creates a new typed plan.
+	///
+	/// # Arguments
+	/// * `Handler` - The handler answering the actions of the set.
+	///
+	/// # Returns
+	/// A new `Struct` instance.
+	pub fn New(Handler:H) -> Self { Self { Handler:Arc::new(Handler), Set:PhantomData } }
+
+	/// Executes a typed action by dispatching it to the handler.
+	///
+	/// # Arguments
+	/// * `Action` - The action to execute.
+	///
+	/// # Returns
+	/// The handler's result.
+	pub async fn Execute(&self, Action:E) -> Result<Value, Error> {
+		Action.Dispatch(&self.Handler).await
+	}
+
+	/// Decodes an action by name and executes it.
+	///
+	/// # Arguments
+	/// * `Name` - The name of the action.
+	/// * `Argument` - The argument vector of the action.
+	///
+	/// # Returns
+	/// The handler's result, or an `Error::Execution` if the action is not
+	/// part of the set, or an `Error::InvalidRequest` if its arguments could
+	/// not be decoded.
+	pub async fn Call(&self, Name:&str, Argument:Vec<Value>) -> Result<Value, Error> {
+		self.Execute(E::Decode(Name, Argument)?).await
+	}
+
+	/// Registers every action of the set into a dynamic `Formality`.
+	///
+	/// # Arguments
+	/// * `Formality` - The formality to extend.
+	///
+	/// # Returns
+	/// A `Result` indicating whether all actions could be registered.
+	pub fn Extend(&self, Formality:&mut Formality::Struct) -> Result<(), String> {
+		for Name in E::Names() {
+			let Handler = self.Handler.clone();
+
+			Formality
+				.Sign(Signature::New(Name))
+				.Add(Name, move |Argument:Vec<Value>| {
+					let Handler = Handler.clone();
+
+					async move { E::Decode(Name, Argument)?.Dispatch(&Handler).await }
+				})?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<E:Set<H>, H:Send + Sync + 'static> From<Struct<E, H>> for Formality::Struct {
+	fn from(Typed:Struct<E, H>) -> Self {
+		let mut Formality = Formality::Struct::New();
+
+		// A fresh formality has no conflicting names, so registration cannot fail
+		let _ = Typed.Extend(&mut Formality);
+
+		Formality
+	}
+}
+
+use std::{marker::PhantomData, sync::Arc};
+
+use serde_json::Value;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Action::Signature::Struct as Signature, Plan::Formality},
+	Trait::Sequence::Set::Trait as Set,
+};
@@ -0,0 +1,80 @@
+/// A reloadable slot hosting a plugin that provides action functions.
+///
+/// Functions registered through the slot look up the current plugin on every
+/// call, so `Reload` swaps in a new plugin version atomically for all actions
+/// executed afterwards, while calls already in progress finish on the version
+/// they started with.
+#[derive(Clone)]
+pub struct Struct {
+	/// The plugin currently answering calls.
+	Current:Arc<RwLock<Arc<dyn Plugin>>>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` hosting the given plugin.
+	///
+	/// # Arguments
+	///
+	/// * `Plugin` - The plugin to host.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Plugin:Arc<dyn Plugin>) -> Self { Struct { Current:Arc::new(RwLock::new(Plugin)) } }
+
+	/// Registers every action described by the current plugin into a
+	/// formality.
+	///
+	/// # Arguments
+	///
+	/// * `Formality` - The formality to register the actions into.
+	///
+	/// # Returns
+	///
+	/// A `Result` indicating whether all actions could be registered.
+	pub fn Register(&self, Formality:&mut Formality) -> Result<(), String> {
+		for Signature in self.Plugin().Describe() {
+			let Name = Signature.Name.clone();
+
+			let Action = Name.clone();
+
+			let Slot = self.clone();
+
+			Formality.Sign(Signature).Add(&Name, move |Argument:Vec<Value>| {
+				let Plugin = Slot.Plugin();
+
+				let Action = Action.clone();
+
+				async move { Plugin.Run(&Action, Argument).await }
+			})?;
+		}
+
+		Ok(())
+	}
+
+	/// Swaps in a new plugin version for subsequently executed actions.
+	///
+	/// Actions that the new version no longer describes fail when called;
+	/// actions it newly describes have to be added with `Register`.
+	///
+	/// # Arguments
+	///
+	/// * `Plugin` - The new plugin version.
+	pub fn Reload(&self, Plugin:Arc<dyn Plugin>) {
+		*self.Current.write().unwrap_or_else(PoisonError::into_inner) = Plugin;
+	}
+
+	/// Returns the plugin currently answering calls.
+	pub fn Plugin(&self) -> Arc<dyn Plugin> {
+		self.Current.read().unwrap_or_else(PoisonError::into_inner).clone()
+	}
+}
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use serde_json::Value;
+
+use crate::{
+	Struct::Sequence::Plan::Formality::Struct as Formality,
+	Trait::Sequence::Plugin::Trait as Plugin,
+};
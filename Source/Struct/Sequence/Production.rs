@@ -5,9 +5,185 @@ pub struct Struct {
 	/// - `Arc` provides shared ownership and thread-safety.
 	/// - `Mutex` ensures exclusive access to the queue.
 	/// - `VecDeque` is used as an efficient double-ended queue.
+	/// - `Stamp` records the sequence number and enqueue time of each action.
 	/// - `Box<dyn Action>` allows for dynamic dispatch of different action
 	///   types.
-	Line:Arc<Mutex<VecDeque<Box<dyn Action>>>>,
+	Line:Arc<Mutex<VecDeque<Entry>>>,
+
+	/// The sequence number handed to the next enqueued action.
+	Sequence:AtomicU64,
+
+	/// The number of actions dequeued so far.
+	Dequeued:AtomicU64,
+
+	/// The event bus on which enqueued actions are announced, once attached.
+	Bus:OnceLock<Bus>,
+
+	/// The completions of submitted actions, by sequence number.
+	///
+	/// Bounded by `Bound`; an evicted completion resolves with
+	/// `Error::ResultEvicted`.
+	Waiting:Store::Struct<u64, Completion>,
+
+	/// The registry tracking the lifecycle of the line's actions, and the
+	/// line itself as the registry knows it, once enlisted.
+	Registry:OnceLock<(Arc<ActionRegistry>, Weak<Struct>)>,
+
+	/// How large a follow-up chain `Take` and `Submit` accept.
+	Chain:RwLock<Chain::Struct>,
+
+	/// How long reservations last, if the line leases its actions.
+	Leasing:RwLock<Option<Duration>>,
+
+	/// The actions handed out with `DoReserve` and not yet acknowledged.
+	Leases:std::sync::Mutex<Leases>,
+
+	/// How dequeues are shared between identities, if they are.
+	Fairness:RwLock<Option<Fairness::Struct>>,
+
+	/// The buckets of the queued actions while dequeuing fairly.
+	Buckets:std::sync::Mutex<Buckets>,
+}
+
+/// The actions of a line handed out with `DoReserve`.
+#[derive(Default)]
+struct Leases {
+	/// The leases held, by sequence number.
+	Held:HashMap<u64, Lease>,
+
+	/// How often the redelivered actions back in the line were handed out,
+	/// by sequence number.
+	Delivered:HashMap<u64, u64>,
+}
+
+/// The queued actions of a line dequeuing fairly, by bucket.
+#[derive(Default)]
+struct Buckets {
+	/// The bucket of every queued action, by sequence number.
+	Of:HashMap<u64, String>,
+
+	/// The stamps of the queued actions of every non-empty bucket, in
+	/// dequeue order.
+	Queued:BTreeMap<String, VecDeque<Stamp::Struct>>,
+
+	/// The non-empty buckets in the order they are served.
+	Rotation:VecDeque<String>,
+
+	/// Whether the actions queued when fairness was turned on still lack a
+	/// bucket.
+	Stale:bool,
+}
+
+impl Buckets {
+	/// Returns the bucket of an action of an identity, giving the identity a
+	/// bucket of its own if one is free.
+	fn Assign(&self, Identity:Option<String>, Limit:usize) -> String {
+		match Identity {
+			Some(Identity)
+				if self.Queued.contains_key(&Identity)
+					|| self.Queued.keys().filter(|Bucket| *Bucket != Fairness::SHARED).count()
+						< Limit =>
+			{
+				Identity
+			},
+			_ => Fairness::SHARED.to_string(),
+		}
+	}
+
+	/// Adds a queued action to a bucket behind its actions of the same
+	/// priority or a higher one, serving a bucket that was empty last.
+	fn Add(&mut self, Bucket:String, Stamp:Stamp::Struct) {
+		let Queued = self.Queued.entry(Bucket.clone()).or_default();
+
+		if Queued.is_empty() {
+			self.Rotation.push_back(Bucket.clone());
+		}
+
+		let Index = Queued
+			.iter()
+			.rposition(|Queued| Queued.Priority >= Stamp.Priority)
+			.map_or(0, |Index| Index + 1);
+
+		Queued.insert(Index, Stamp);
+
+		self.Of.insert(Stamp.Sequence, Bucket);
+	}
+
+	/// Removes an action that left the line other than in turn.
+	fn Remove(&mut self, Sequence:u64) {
+		let Some(Bucket) = self.Of.remove(&Sequence) else {
+			return;
+		};
+
+		if let Some(Queued) = self.Queued.get_mut(&Bucket) {
+			Queued.retain(|Stamp| Stamp.Sequence != Sequence);
+
+			if Queued.is_empty() {
+				self.Queued.remove(&Bucket);
+
+				self.Rotation.retain(|Served| *Served != Bucket);
+			}
+		}
+	}
+
+	/// Takes the oldest action of a priority out of the next bucket in turn
+	/// holding one; the other buckets keep their turn.
+	///
+	/// # Arguments
+	///
+	/// * `Band` - The priority, the highest one queued.
+	///
+	/// # Returns
+	///
+	/// The sequence number of the action, or `None` if no bucket holds one.
+	fn Next(&mut self, Band:i64) -> Option<u64> {
+		// A bucket serves its highest priority first
+		let Turn = self.Rotation.iter().position(|Bucket| {
+			self.Queued
+				.get(Bucket)
+				.and_then(VecDeque::front)
+				.is_some_and(|Stamp| Stamp.Priority == Band)
+		})?;
+
+		let Bucket = self.Rotation.remove(Turn)?;
+
+		let Queued = self.Queued.get_mut(&Bucket)?;
+
+		let Stamp = Queued.pop_front()?;
+
+		if Queued.is_empty() {
+			self.Queued.remove(&Bucket);
+		} else {
+			self.Rotation.push_back(Bucket);
+		}
+
+		self.Of.remove(&Stamp.Sequence);
+
+		Some(Stamp.Sequence)
+	}
+
+	/// Returns the number of queued actions of every bucket.
+	fn Depth(&self) -> BTreeMap<String, u64> {
+		self.Queued
+			.iter()
+			.map(|(Bucket, Queued)| (Bucket.clone(), Queued.len() as u64))
+			.collect()
+	}
+}
+
+/// One reserved action.
+struct Lease {
+	/// The stamp the action received when it was enqueued.
+	Stamp:Stamp::Struct,
+
+	/// The action.
+	Action:Arc<dyn Action>,
+
+	/// How many times the action was handed out.
+	Deliveries:u64,
+
+	/// When the lease expires.
+	Deadline:Instant,
 }
 
 impl Struct {
@@ -16,7 +192,21 @@ impl Struct {
 	/// # Returns
 	///
 	/// A new `Struct` with an empty action queue.
-	pub fn New() -> Self { Struct { Line:Arc::new(Mutex::new(VecDeque::new())) } }
+	pub fn New() -> Self {
+		Struct {
+			Line:Arc::new(Mutex::new(VecDeque::new())),
+			Sequence:AtomicU64::new(0),
+			Dequeued:AtomicU64::new(0),
+			Bus:OnceLock::new(),
+			Waiting:Store::Struct::New("results", Limit::default()),
+			Registry:OnceLock::new(),
+			Chain:RwLock::new(Chain::Struct::default()),
+			Leasing:RwLock::new(None),
+			Leases:std::sync::Mutex::new(Leases::default()),
+			Fairness:RwLock::new(None),
+			Buckets:std::sync::Mutex::new(Buckets::default()),
+		}
+	}
 
 	/// Attempts to retrieve and remove the first action from the queue.
 	///
@@ -26,18 +216,896 @@ impl Struct {
 	///
 	/// `Option<Box<dyn Action>>` - The first action in the queue if it exists,
 	/// or `None` if the queue is empty.
-	pub async fn Do(&self) -> Option<Box<dyn Action>> { self.Line.lock().await.pop_front() }
+	pub async fn Dequeue(&self) -> Option<Box<dyn Action>> {
+		self.DequeueStamped().await.map(|(_, Action)| Action)
+	}
+
+	/// Retrieves and removes the first action from the queue; see `Dequeue`.
+	#[deprecated(note = "use `Dequeue`")]
+	pub async fn Do(&self) -> Option<Box<dyn Action>> { self.Dequeue().await }
+
+	/// Retrieves and removes the first action from the queue; see `Dequeue`.
+	#[deprecated(note = "use `Dequeue`")]
+	pub async fn Execute(&self) -> Option<Box<dyn Action>> { self.Dequeue().await }
 
-	/// Adds a new action to the end of the queue.
+	/// Attempts to retrieve and remove the first action from the queue,
+	/// together with the stamp it received when it was enqueued.
 	///
-	/// This method is asynchronous and will await the lock on the queue.
+	/// The time the action spent waiting is recorded in the
+	/// `echo_queue_wait_seconds` histogram.
+	///
+	/// # Returns
+	///
+	/// `Option<(Stamp::Struct, Box<dyn Action>)>` - The first action in the
+	/// queue and its stamp if it exists, or `None` if the queue is empty.
+	pub async fn DequeueStamped(&self) -> Option<(Stamp::Struct, Box<dyn Action>)> {
+		let (Stamp, Action) = {
+			let mut Line = self.Line.lock().await;
+
+			let (Stamp, Action) = self.Pick(&mut Line).await?;
+
+			self.Track(Stamp.Sequence, Lifecycle::Running);
+
+			(Stamp, Action)
+		};
+
+		self.Dequeued.fetch_add(1, Ordering::Relaxed);
+
+		histogram!("echo_queue_wait_seconds").record(Stamp.Wait().as_secs_f64());
+
+		Some((Stamp, Action))
+	}
+
+	/// Retrieves and removes the first action from the queue with its stamp;
+	/// see `DequeueStamped`.
+	#[deprecated(note = "use `DequeueStamped`")]
+	pub async fn DoStamped(&self) -> Option<(Stamp::Struct, Box<dyn Action>)> {
+		self.DequeueStamped().await
+	}
+
+	/// Adds a new action to the queue, unless its follow-up chain exceeds
+	/// the limits of the line.
+	///
+	/// The action goes behind every queued action of its `Priority` or a
+	/// higher one, and ahead of those of a lower one, so `Dequeue` takes
+	/// actions by priority, then in the order they were enqueued.
+	///
+	/// This method is asynchronous and will await the lock on the queue. The
+	/// sequence number is assigned while the lock is held, so among actions
+	/// of one priority queue order and sequence order always agree.
 	///
 	/// # Arguments
 	///
 	/// * `Action` - The action to be added to the queue.
-	pub async fn Assign(&self, Action:Box<dyn Action>) { self.Line.lock().await.push_back(Action); }
+	///
+	/// # Returns
+	///
+	/// The `Stamp` assigned to the action, or the error of `Check` without
+	/// enqueueing anything.
+	pub async fn Enqueue(&self, Action:Box<dyn Action>) -> Result<Stamp::Struct, Error> {
+		self.Check(Action.as_ref()).await?;
+
+		Ok(self.Push(Action, None).await)
+	}
+
+	/// Adds a new action to the end of the queue; see `Enqueue`.
+	#[deprecated(note = "use `Enqueue`")]
+	pub async fn Assign(&self, Action:Box<dyn Action>) -> Result<Stamp::Struct, Error> {
+		self.Enqueue(Action).await
+	}
+
+	/// Adds a new action to the end of the queue; see `Enqueue`.
+	#[deprecated(note = "use `Enqueue`")]
+	pub async fn Take(&self, Action:Box<dyn Action>) -> Result<Stamp::Struct, Error> {
+		self.Enqueue(Action).await
+	}
+
+	/// Checks the `Priority` of an action and its follow-up chain against
+	/// the limits of the line, without executing anything.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to check.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the action may be enqueued, `Error::Execution` if its
+	/// `Priority` is not an integer, or `Error::ChainLimit` naming the first
+	/// limit exceeded.
+	pub async fn Check(&self, Action:&dyn Action) -> Result<(), Error> {
+		// Read leniently by `Priority`, so rejected here rather than run at 0
+		if let Some(Priority) = Action.Metadata("Priority").await {
+			if Priority.as_i64().is_none() {
+				return Err(Error::Execution(format!(
+					"Invalid Priority metadata {}: expected an integer",
+					Priority
+				)));
+			}
+		}
+
+		let mut Follow = Vec::new();
+
+		for Key in ["OnSuccess", "NextAction", "OnFailure"] {
+			if let Some(Next) = Action.Metadata(Key).await {
+				Follow.push(Next);
+			}
+		}
+
+		if Follow.is_empty() {
+			return Ok(());
+		}
+
+		let Chain = *self.Chain.read().unwrap_or_else(|Poison| Poison.into_inner());
+
+		Chain.Check(&Value::Array(Follow))
+	}
+
+	/// Sets how large a follow-up chain the line accepts.
+	///
+	/// # Arguments
+	///
+	/// * `Chain` - The limits on the chain.
+	pub fn Restrict(&self, Chain:Chain::Struct) {
+		*self.Chain.write().unwrap_or_else(|Poison| Poison.into_inner()) = Chain;
+	}
+
+	/// Makes the line deliver its actions at least once.
+	///
+	/// With a lease, sequences reserve the actions of the line with
+	/// `DoReserve` and acknowledge them once processed, so an action whose
+	/// consumer died is handed out again after its lease expired. An action
+	/// still running when its lease expires is handed out again as well.
+	///
+	/// # Arguments
+	///
+	/// * `Lease` - How long reservations last, or `None` to dequeue actions for
+	///   good.
+	pub fn Lease(&self, Lease:Option<Duration>) {
+		*self.Leasing.write().unwrap_or_else(|Poison| Poison.into_inner()) = Lease;
+	}
+
+	/// Shares the dequeues of the line between the identities submitting to
+	/// it, instead of dequeuing in submission order.
+	///
+	/// Actions of one identity still dequeue in submission order.
+	///
+	/// # Arguments
+	///
+	/// * `Fairness` - How actions are bucketed, or `None` to dequeue in
+	///   submission order.
+	pub fn Fair(&self, Fairness:Option<Fairness::Struct>) {
+		*self.Fairness.write().unwrap_or_else(|Poison| Poison.into_inner()) = Fairness;
+
+		// The actions already queued are bucketed at the next pick
+		let Stale = self.Fairness().is_some()
+			&& self.Line.try_lock().map_or(true, |Line| !Line.is_empty());
+
+		*self.Buckets() = Buckets { Stale, ..Buckets::default() };
+	}
+
+	/// Returns how dequeues are shared between identities, if they are.
+	pub fn Fairness(&self) -> Option<Fairness::Struct> {
+		self.Fairness
+			.read()
+			.unwrap_or_else(|Poison| Poison.into_inner())
+			.clone()
+	}
+
+	/// Returns how long reservations last, if the line leases its actions.
+	pub fn Leasing(&self) -> Option<Duration> {
+		*self.Leasing.read().unwrap_or_else(|Poison| Poison.into_inner())
+	}
+
+	/// Reserves the first action of the queue for the lease set with `Lease`,
+	/// or 30 seconds without one.
+	///
+	/// Leases that expired are reaped first. The reserved action carries how
+	/// many times it was handed out as its `DeliveryCount` metadata,
+	/// starting at 1.
+	///
+	/// # Returns
+	///
+	/// The reservation of the first action in the queue, or `None` if the
+	/// queue is empty.
+	pub async fn DoReserve(self: &Arc<Self>) -> Option<Reservation::Struct> {
+		self.Reap().await;
+
+		let Deadline = Instant::now() + self.Leasing().unwrap_or(Duration::from_secs(30));
+
+		let (Stamp, Action) = self.DequeueStamped().await?;
+
+		let Action:Arc<dyn Action> = Arc::from(Action);
+
+		let Deliveries = {
+			let mut Leases = self.Leases();
+
+			let Deliveries = Leases.Delivered.remove(&Stamp.Sequence).unwrap_or(1);
+
+			Leases.Held.insert(
+				Stamp.Sequence,
+				Lease { Stamp, Action:Action.clone(), Deliveries, Deadline },
+			);
+
+			Deliveries
+		};
+
+		Some(Reservation::Struct::New(self.clone(), Stamp, Action, Deliveries))
+	}
+
+	/// Puts the actions whose lease expired back at the end of the queue,
+	/// counting one more delivery for each.
+	///
+	/// # Returns
+	///
+	/// The number of actions put back.
+	pub async fn Reap(&self) -> usize {
+		let Expired = {
+			let mut Leases = self.Leases();
+
+			let Now = Instant::now();
+
+			let Sequence:Vec<u64> = Leases
+				.Held
+				.iter()
+				.filter(|(_, Lease)| Lease.Deadline <= Now)
+				.map(|(Sequence, _)| *Sequence)
+				.collect();
+
+			Sequence
+				.iter()
+				.filter_map(|Sequence| Leases.Held.remove(Sequence))
+				.collect::<Vec<_>>()
+		};
+
+		let Count = Expired.len();
+
+		for Lease in Expired {
+			self.Redeliver(Lease).await;
+		}
+
+		if Count > 0 {
+			counter!("echo_leases_expired_total").increment(Count as u64);
+		}
+
+		Count
+	}
+
+	/// Removes a reserved action for good, if its lease is still held.
+	///
+	/// # Returns
+	///
+	/// Whether the lease was held.
+	pub(crate) fn Acknowledge(&self, Sequence:u64, Deliveries:u64) -> bool {
+		let Held = self.Claim(Sequence, Deliveries).is_some();
+
+		if Held {
+			self.Track(Sequence, Lifecycle::Completed);
+		}
+
+		Held
+	}
+
+	/// Gives a reserved action back, if its lease is still held.
+	///
+	/// # Returns
+	///
+	/// The action if it left the line, `None` if it was requeued or its
+	/// lease had expired.
+	pub(crate) async fn Release(
+		&self,
+		Sequence:u64,
+		Deliveries:u64,
+		Requeue:bool,
+	) -> Option<Arc<dyn Action>> {
+		let Lease = self.Claim(Sequence, Deliveries)?;
+
+		if Requeue {
+			self.Redeliver(Lease).await;
+
+			return None;
+		}
+
+		self.Track(Sequence, Lifecycle::Completed);
+
+		Some(Lease.Action)
+	}
+
+	/// Takes the lease of an action, unless it expired and the action was
+	/// handed out again since.
+	fn Claim(&self, Sequence:u64, Deliveries:u64) -> Option<Lease> {
+		let mut Leases = self.Leases();
+
+		if Leases.Held.get(&Sequence)?.Deliveries != Deliveries {
+			return None;
+		}
+
+		Leases.Held.remove(&Sequence)
+	}
+
+	/// Puts a leased action back into the queue, behind the actions of its
+	/// priority.
+	async fn Redeliver(&self, Lease:Lease) {
+		let mut Line = self.Line.lock().await;
+
+		self.Leases()
+			.Delivered
+			.insert(Lease.Stamp.Sequence, Lease.Deliveries + 1);
+
+		self.Track(Lease.Stamp.Sequence, Lifecycle::Queued);
+
+		let Identity = self.Bucketed(Lease.Action.as_ref()).await;
+
+		self.Place(&mut Line, (Lease.Stamp, Box::new(Lease.Action)), Identity);
+
+		self.Dequeued.fetch_sub(1, Ordering::Relaxed);
+	}
+
+	/// Takes the next action out of the line: the first one, or while
+	/// dequeuing fairly the oldest one of the highest priority queued from
+	/// the next bucket in turn holding one.
+	async fn Pick(&self, Line:&mut VecDeque<Entry>) -> Option<Entry> {
+		let Some(Fairness) = self.Fairness() else {
+			return Line.pop_front();
+		};
+
+		if self.Buckets().Stale {
+			self.Rebucket(Line, &Fairness).await;
+		}
+
+		// The line is ordered by priority
+		let Band = Line.front()?.0.Priority;
+
+		let Sequence = self.Buckets().Next(Band)?;
+
+		// The oldest action of a bucket sits near the head of the line
+		let Index = Line.iter().position(|(Stamp, _)| Stamp.Sequence == Sequence)?;
+
+		Line.remove(Index)
+	}
+
+	/// Puts every queued action into a bucket, once fairness was turned on.
+	async fn Rebucket(&self, Line:&VecDeque<Entry>, Fairness:&Fairness::Struct) {
+		let mut Identity = Vec::with_capacity(Line.len());
+
+		for (Stamp, Action) in Line.iter() {
+			Identity.push((*Stamp, Self::Identity(Action.as_ref(), &Fairness.Key).await));
+		}
+
+		let mut Buckets = self.Buckets();
+
+		*Buckets = Buckets::default();
+
+		for (Stamp, Identity) in Identity {
+			let Bucket = Buckets.Assign(Identity, Fairness.Buckets);
+
+			Buckets.Add(Bucket, Stamp);
+		}
+	}
+
+	/// Puts an entry into the line with `Insert` and, while dequeuing fairly,
+	/// into the bucket of its identity.
+	fn Place(&self, Line:&mut VecDeque<Entry>, Entry:Entry, Identity:Option<String>) {
+		if let Some(Fairness) = self.Fairness() {
+			let mut Buckets = self.Buckets();
+
+			// Bucketed with the rest of the line at the next pick
+			if !Buckets.Stale {
+				let Bucket = Buckets.Assign(Identity, Fairness.Buckets);
+
+				Buckets.Add(Bucket, Entry.0);
+			}
+		}
+
+		Insert(Line, Entry);
+	}
+
+	/// Reads the identity of an action while dequeuing fairly.
+	async fn Bucketed(&self, Action:&dyn Action) -> Option<String> {
+		Self::Identity(Action, &self.Fairness()?.Key).await
+	}
+
+	/// Reads the identity of an action from its metadata.
+	async fn Identity(Action:&dyn Action, Key:&str) -> Option<String> {
+		match Action.Metadata(Key).await? {
+			Value::Null => None,
+			Value::String(Identity) => Some(Identity),
+			Identity => Some(Identity.to_string()),
+		}
+	}
+
+	/// Takes the lock of the buckets, ignoring poisoning.
+	fn Buckets(&self) -> std::sync::MutexGuard<'_, Buckets> {
+		self.Buckets.lock().unwrap_or_else(|Poison| Poison.into_inner())
+	}
+
+	/// Takes the lock of the leases, ignoring poisoning.
+	fn Leases(&self) -> std::sync::MutexGuard<'_, Leases> {
+		self.Leases.lock().unwrap_or_else(|Poison| Poison.into_inner())
+	}
+
+	/// Puts a dequeued action back into the queue, behind the actions of its
+	/// priority, keeping its stamp so its sequence number and completion stay
+	/// attached.
+	///
+	/// The timer service uses this to return delayed actions once they are
+	/// due. The action counts as not dequeued again.
+	///
+	/// # Arguments
+	///
+	/// * `Stamp` - The stamp the action received when it was enqueued.
+	/// * `Action` - The action to put back.
+	pub async fn Reinject(&self, Stamp:Stamp::Struct, Action:Box<dyn Action>) {
+		let mut Line = self.Line.lock().await;
+
+		self.Track(Stamp.Sequence, Lifecycle::Queued);
+
+		let Identity = self.Bucketed(Action.as_ref()).await;
+
+		self.Place(&mut Line, (Stamp, Action), Identity);
+
+		self.Dequeued.fetch_sub(1, Ordering::Relaxed);
+	}
+
+	/// Adds a new action to the end of the queue and returns its completion.
+	///
+	/// The returned `Pending` resolves once a sequence finished the action:
+	/// with the value the action recorded, or with the error of its last
+	/// attempt. An action failing `Check` is not enqueued; its `Pending`
+	/// resolves with the error.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to be added to the queue.
+	///
+	/// # Returns
+	///
+	/// The `Pending` completion of the action.
+	pub async fn Submit(&self, Action:Box<dyn Action>) -> Pending::Struct {
+		if let Err(_Error) = self.Check(Action.as_ref()).await {
+			return Pending::Struct::Failed(_Error);
+		}
+
+		let (Action, Cancelled) = Pending::Struct::Guard(Action);
+
+		let (Sender, Receiver) = channel();
+
+		let Stamp = self
+			.Push(
+				Action,
+				Some(Box::new(move |_, Result| {
+					let _ = Sender.send(Result);
+				})),
+			)
+			.await;
+
+		Pending::Struct::New(Stamp.Sequence, Receiver, Cancelled)
+	}
+
+	/// Adds several actions to the end of the queue and returns their
+	/// completions as one batch.
+	///
+	/// The actions are enqueued one at a time, so consumers can start on the
+	/// first while the rest are still being added.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The actions to be added to the queue, in order.
+	///
+	/// # Returns
+	///
+	/// The `Batch` reporting the outcome of every action.
+	pub async fn SubmitBatch(&self, Action:Vec<Box<dyn Action>>) -> Batch::Struct {
+		let (Sender, Receiver) = unbounded_channel();
+
+		let Completed = Arc::new(AtomicUsize::new(0));
+
+		let mut Id = Vec::with_capacity(Action.len());
+
+		for Action in Action {
+			let (Sender, Completed) = (Sender.clone(), Completed.clone());
+
+			let Stamp = self
+				.Push(
+					Action,
+					Some(Box::new(move |Sequence, Result| {
+						Completed.fetch_add(1, Ordering::Relaxed);
+
+						let _ = Sender.send((Sequence, Result));
+					})),
+				)
+				.await;
+
+			Id.push(Stamp.Sequence);
+		}
+
+		Batch::Struct::New(Id, Receiver, Completed)
+	}
+
+	/// Reports the outcome of a finished action to its `Pending` or `Batch`,
+	/// if it was submitted.
+	///
+	/// Sequences call this when they are done with an action.
+	///
+	/// # Arguments
+	///
+	/// * `Sequence` - The sequence number of the action.
+	/// * `Result` - The value the action recorded, or its last error.
+	pub fn Complete(&self, Sequence:u64, Result:Result<Value, Error>) {
+		self.Track(Sequence, Lifecycle::Completed);
+
+		if let Some(Completion) = self.Waiting.Remove(&Sequence) {
+			Completion(Sequence, Result);
+		}
+	}
+
+	/// Removes every queued action without running it.
+	///
+	/// The completions of the removed actions resolve with
+	/// `Error::Abandoned`: at the next sweep of the registry the line is
+	/// enlisted in, or right away if it is not enlisted.
+	///
+	/// # Returns
+	///
+	/// The number of actions removed.
+	pub async fn Clear(&self) -> usize {
+		let Removed = {
+			let mut Line = self.Line.lock().await;
+
+			let Removed = Line.drain(..).map(|(Stamp, _)| Stamp.Sequence).collect::<Vec<_>>();
+
+			let mut Leases = self.Leases();
+
+			for Sequence in &Removed {
+				Leases.Delivered.remove(Sequence);
+			}
+
+			*self.Buckets() = Buckets::default();
+
+			self.Dequeued.fetch_add(Removed.len() as u64, Ordering::Relaxed);
+
+			Removed
+		};
+
+		if self.Registry.get().is_none() {
+			for Sequence in &Removed {
+				self.Abandon(*Sequence);
+			}
+		}
+
+		Removed.len()
+	}
+
+	/// Reads metadata of every queued action without dequeuing it.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata keys to read.
+	///
+	/// # Returns
+	///
+	/// The stamp of every queued action in dequeue order, with the values it
+	/// has under `Key`.
+	pub async fn Inspect(&self, Key:&[&str]) -> Vec<(Stamp::Struct, Map<String, Value>)> {
+		let Line = self.Line.lock().await;
+
+		let mut Inspected = Vec::with_capacity(Line.len());
+
+		for (Stamp, Action) in Line.iter() {
+			let mut Metadata = Map::new();
+
+			for Key in Key {
+				if let Some(Value) = Action.Metadata(Key).await {
+					Metadata.insert(Key.to_string(), Value);
+				}
+			}
+
+			Inspected.push((*Stamp, Metadata));
+		}
+
+		Inspected
+	}
+
+	/// Summarizes the queued actions without dequeuing or copying them.
+	///
+	/// The line is walked once under its lock, reading the `Action` metadata
+	/// and the argument size of every action, so the cost is bounded by the
+	/// length of the line.
+	///
+	/// # Returns
+	///
+	/// The queued actions grouped by type, age and priority.
+	pub async fn Summary(&self) -> Summary::Struct {
+		let Line = self.Line.lock().await;
+
+		let mut Summary = Summary::Struct::default();
+
+		for (Stamp, Action) in Line.iter() {
+			let Name = Action
+				.Metadata("Action")
+				.await
+				.and_then(|Name| Name.as_str().map(str::to_string));
+
+			Summary.Add(Name, Stamp.Wait(), Stamp.Priority, Action.ArgumentSize().await);
+		}
+
+		Summary
+	}
+
+	/// Removes specific queued actions without running them.
+	///
+	/// Unlike `Clear`, the completions of the removed actions stay attached,
+	/// so an action put back with `Reinject` still reports to them.
+	///
+	/// # Arguments
+	///
+	/// * `Sequence` - The sequence numbers of the actions to remove.
+	///
+	/// # Returns
+	///
+	/// The removed actions with their stamps, in dequeue order; actions no
+	/// longer queued are skipped.
+	pub async fn Extract(&self, Sequence:&[u64]) -> Vec<(Stamp::Struct, Box<dyn Action>)> {
+		let mut Line = self.Line.lock().await;
+
+		let mut Extracted = Vec::new();
+
+		let mut Index = 0;
+
+		while Index < Line.len() {
+			if Sequence.contains(&Line[Index].0.Sequence) {
+				if let Some(Entry) = Line.remove(Index) {
+					Extracted.push(Entry);
+				}
+			} else {
+				Index += 1;
+			}
+		}
+
+		let mut Leases = self.Leases();
+
+		let mut Buckets = self.Buckets();
+
+		for (Stamp, _) in &Extracted {
+			Leases.Delivered.remove(&Stamp.Sequence);
+
+			Buckets.Remove(Stamp.Sequence);
+
+			self.Track(Stamp.Sequence, Lifecycle::Completed);
+		}
+
+		self.Dequeued.fetch_add(Extracted.len() as u64, Ordering::Relaxed);
+
+		Extracted
+	}
+
+	/// Reports the lifecycle of this line's actions to a registry.
+	///
+	/// A production line reports to the first registry it is enlisted in;
+	/// `Sequence::New` and `Life::Builder::WithQueue` enlist it in the
+	/// registry of their `Life`.
+	///
+	/// # Arguments
+	///
+	/// * `Registry` - The registry to report to.
+	pub fn Enlist(self: &Arc<Self>, Registry:&Arc<ActionRegistry>) {
+		let _ = self.Registry.set((Registry.clone(), Arc::downgrade(self)));
+	}
+
+	/// Abandons the actions the registry expects in this line that are no
+	/// longer in it.
+	///
+	/// # Returns
+	///
+	/// The number of actions abandoned.
+	pub(crate) async fn Orphans(&self) -> usize {
+		let Some((Registry, Own)) = self.Registry.get() else {
+			return 0;
+		};
+
+		let Orphaned = {
+			let Line = self.Line.lock().await;
+
+			Registry.Orphaned(Own, &Line.iter().map(|(Stamp, _)| Stamp.Sequence).collect())
+		};
+
+		for Sequence in &Orphaned {
+			self.Abandon(*Sequence);
+		}
+
+		Orphaned.len()
+	}
+
+	/// Resolves the completion of an action with `Error::Abandoned`.
+	fn Abandon(&self, Sequence:u64) {
+		if let Some(Completion) = self.Waiting.Remove(&Sequence) {
+			Completion(
+				Sequence,
+				Err(Error::Abandoned(format!(
+					"action {} left its production line without finishing",
+					Sequence
+				))),
+			);
+		}
+	}
+
+	/// Reports where an action is to the registry, if enlisted.
+	fn Track(&self, Sequence:u64, State:Lifecycle) {
+		if let Some((Registry, Own)) = self.Registry.get() {
+			Registry.Track(Own, Sequence, State);
+		}
+	}
+
+	/// Names and bounds the store of completions waiting on this line.
+	///
+	/// Completions that no longer fit resolve with `Error::ResultEvicted`.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the store, used in metrics.
+	/// * `Limit` - How many completions may wait at once.
+	pub fn Bound(&self, Name:&str, Limit:Limit) {
+		self.Waiting.Rename(Name);
+
+		Self::Evict(self.Waiting.Resize(Limit));
+	}
+
+	/// Resolves evicted completions with `Error::ResultEvicted`.
+	fn Evict(Evicted:Vec<(u64, Completion)>) {
+		for (Sequence, Completion) in Evicted {
+			Completion(
+				Sequence,
+				Err(Error::ResultEvicted(format!(
+					"completion of action {} was evicted before it finished",
+					Sequence
+				))),
+			);
+		}
+	}
+
+	/// Adds an action to the queue, registering its completion under the
+	/// lock so a sequence cannot finish it first.
+	async fn Push(&self, Action:Box<dyn Action>, Completion:Option<Completion>) -> Stamp::Struct {
+		let Name = match self.Bus.get() {
+			Some(_) => Action
+				.Metadata("Action")
+				.await
+				.and_then(|Name| Name.as_str().map(str::to_string)),
+			None => None,
+		};
+
+		let Priority = Action.Priority().await;
+
+		let Identity = self.Bucketed(Action.as_ref()).await;
+
+		let (Stamp, Evicted) = {
+			let mut Line = self.Line.lock().await;
+
+			let Stamp = Stamp::Struct::New(self.Sequence.fetch_add(1, Ordering::Relaxed))
+				.WithPriority(Priority);
+
+			let Evicted = match Completion {
+				Some(Completion) => self.Waiting.Insert(Stamp.Sequence, Completion, 0),
+				None => Vec::new(),
+			};
+
+			self.Track(Stamp.Sequence, Lifecycle::Queued);
+
+			self.Place(&mut Line, (Stamp, Action), Identity);
+
+			(Stamp, Evicted)
+		};
+
+		Self::Evict(Evicted);
+
+		if let Some(Bus) = self.Bus.get() {
+			Bus.Emit(|| Event::Enqueued { Sequence:Stamp.Sequence, Action:Name });
+		}
+
+		Stamp
+	}
+
+	/// Reads the counters of the queue without taking its lock.
+	///
+	/// # Returns
+	///
+	/// The depth and the enqueue and dequeue totals of the queue.
+	pub fn Stats(&self) -> Queue::Struct {
+		let Dequeued = self.Dequeued.load(Ordering::Relaxed);
+
+		let Enqueued = self.Sequence.load(Ordering::Relaxed).max(Dequeued);
+
+		let Buckets = match self.Fairness() {
+			Some(_) => self.Buckets().Depth(),
+			None => BTreeMap::new(),
+		};
+
+		Queue::Struct { Depth:Enqueued - Dequeued, Enqueued, Dequeued, Paused:false, Buckets }
+	}
+
+	/// Attaches the event bus on which enqueued actions are announced.
+	///
+	/// A production line publishes on the first bus attached to it;
+	/// `Sequence::New` attaches the bus of its `Life`.
+	///
+	/// # Arguments
+	///
+	/// * `Bus` - The event bus to publish on.
+	pub fn Attach(&self, Bus:&Bus) { let _ = self.Bus.set(Bus.clone()); }
+
+	/// Returns the event bus attached to the production line, if any.
+	pub fn Bus(&self) -> Option<&Bus> { self.Bus.get() }
+
+	/// Returns the stamps of all queued actions in dequeue order.
+	///
+	/// # Returns
+	///
+	/// A `Vec<Stamp>` describing the current contents of the queue.
+	pub async fn Snapshot(&self) -> Vec<Stamp::Struct> {
+		self.Line.lock().await.iter().map(|(Stamp, _)| *Stamp).collect()
+	}
+}
+
+#[async_trait]
+impl QueueTrait for Struct {
+	async fn Enqueue(&self, Action:Box<dyn Action>) -> Result<Stamp::Struct, Error> {
+		Struct::Enqueue(self, Action).await
+	}
+
+	async fn Dequeue(&self) -> Option<Box<dyn Action>> { Struct::Dequeue(self).await }
+
+	fn Depth(&self) -> u64 { self.Stats().Depth }
 }
 
-use std::{collections::VecDeque, sync::Arc};
+/// Puts an entry into a line behind every entry of its priority or a higher
+/// one, looking from the back as most actions share a priority.
+fn Insert(Line:&mut VecDeque<Entry>, Entry:Entry) {
+	let Index = Line
+		.iter()
+		.rposition(|(Stamp, _)| Stamp.Priority >= Entry.0.Priority)
+		.map_or(0, |Index| Index + 1);
+
+	Line.insert(Index, Entry);
+}
+
+use std::{
+	collections::{BTreeMap, HashMap, VecDeque},
+	sync::{
+		atomic::{AtomicU64, AtomicUsize, Ordering},
+		Arc,
+		OnceLock,
+		RwLock,
+		Weak,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use metrics::{counter, histogram};
+use serde_json::{Map, Value};
+use tokio::{
+	sync::{mpsc::unbounded_channel, oneshot::channel},
+	time::Instant,
+};
+
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{Action::Error::Enum as Error, Lifecycle::Enum as Lifecycle},
+	},
+	Struct::{
+		Event::Bus::Struct as Bus,
+		Sequence::{ActionRegistry::Struct as ActionRegistry, Mutex},
+		Stats::{Queue, Summary},
+		Store::{self, Limit::Struct as Limit},
+	},
+	Trait::Sequence::{Action::Trait as Action, Queue::Trait as QueueTrait},
+	Type::Sequence::Production::{Completion::Type as Completion, Entry::Type as Entry},
+};
 
-use crate::{Struct::Sequence::Mutex, Trait::Sequence::Action::Trait as Action};
+pub mod Batch;
+pub mod Chain;
+pub mod Fairness;
+pub mod Pending;
+pub mod Reservation;
+pub mod Settings;
+pub mod Stamp;
@@ -0,0 +1,125 @@
+/// The completions of actions submitted together with
+/// `Production::SubmitBatch`.
+///
+/// Every action of the batch reports into one shared channel, so a batch of
+/// any size costs a single channel rather than one per action.
+pub struct Struct {
+	/// The sequence numbers of the actions, in submission order.
+	Id:Vec<u64>,
+
+	/// Receives each outcome as its action finishes.
+	Receiver:UnboundedReceiver<(u64, Result<Value, Error>)>,
+
+	/// The number of finished actions.
+	Completed:Arc<AtomicUsize>,
+
+	/// The runtime timing `WaitAll` against its deadline.
+	Runtime:Arc<dyn Runtime>,
+}
+
+impl Struct {
+	/// Creates a new batch.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The sequence numbers of the actions, in submission order.
+	/// * `Receiver` - Receives each outcome as its action finishes.
+	/// * `Completed` - The counter of finished actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(
+		Id:Vec<u64>,
+		Receiver:UnboundedReceiver<(u64, Result<Value, Error>)>,
+		Completed:Arc<AtomicUsize>,
+	) -> Self {
+		Struct { Id, Receiver, Completed, Runtime:Arc::new(Tokio::Struct) }
+	}
+
+	/// Sets the runtime timing `WaitAll`, such as that of the `Life` the
+	/// production line belongs to.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Runtime;
+
+		self
+	}
+
+	/// Returns the sequence numbers of the actions, in submission order.
+	pub fn Id(&self) -> &[u64] { &self.Id }
+
+	/// Returns a live view of the batch's progress, which keeps updating
+	/// after the batch is consumed.
+	pub fn Progress(&self) -> Progress::Struct {
+		Progress::Struct::New(self.Completed.clone(), self.Id.len())
+	}
+
+	/// Streams outcomes in the order the actions finish.
+	///
+	/// # Returns
+	///
+	/// A stream of sequence numbers and outcomes, ending once every action
+	/// finished.
+	pub fn Results(self) -> impl Stream<Item = (u64, Result<Value, Error>)> {
+		let Left = self.Id.len();
+
+		stream::unfold((self.Receiver, Left), |(mut Receiver, Left)| async move {
+			if Left == 0 {
+				return None;
+			}
+
+			Receiver.recv().await.map(|Outcome| (Outcome, (Receiver, Left - 1)))
+		})
+	}
+
+	/// Waits for every action to finish, or for the deadline.
+	///
+	/// # Arguments
+	///
+	/// * `Deadline` - The instant after which the remaining actions are
+	///   reported as pending.
+	///
+	/// # Returns
+	///
+	/// The outcome of each action in submission order; `None` for actions
+	/// still pending at the deadline.
+	pub async fn WaitAll(mut self, Deadline:Instant) -> Vec<(u64, Option<Result<Value, Error>>)> {
+		let mut Outcome = HashMap::with_capacity(self.Id.len());
+
+		while Outcome.len() < self.Id.len() {
+			match self.Runtime.TimeoutAt(Deadline, self.Receiver.recv()).await {
+				Some(Some((Id, Result))) => {
+					Outcome.insert(Id, Result);
+				},
+				Some(None) | None => break,
+			}
+		}
+
+		self.Id.iter().map(|Id| (*Id, Outcome.remove(Id))).collect()
+	}
+}
+
+use std::{
+	collections::HashMap,
+	sync::{atomic::AtomicUsize, Arc},
+};
+
+use futures::{stream, Stream};
+use serde_json::Value;
+use tokio::{sync::mpsc::UnboundedReceiver, time::Instant};
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Runtime::Tokio,
+	Trait::Runtime::Trait as Runtime,
+};
+
+pub mod Progress;
@@ -0,0 +1,34 @@
+/// A live view of how many actions of a batch finished.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The number of finished actions, updated as they finish.
+	Completed:Arc<AtomicUsize>,
+
+	/// The number of actions in the batch.
+	Total:usize,
+}
+
+impl Struct {
+	/// Creates a new progress view.
+	///
+	/// # Arguments
+	///
+	/// * `Completed` - The counter of finished actions.
+	/// * `Total` - The number of actions in the batch.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Completed:Arc<AtomicUsize>, Total:usize) -> Self { Struct { Completed, Total } }
+
+	/// Returns the number of actions that finished, successfully or not.
+	pub fn Completed(&self) -> usize { self.Completed.load(Ordering::Relaxed) }
+
+	/// Returns the number of actions in the batch.
+	pub fn Total(&self) -> usize { self.Total }
+}
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
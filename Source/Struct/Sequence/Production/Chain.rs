@@ -0,0 +1,146 @@
+/// How large a follow-up chain a production line accepts.
+///
+/// An action declares its follow-ups in its `NextAction`, `OnSuccess` and
+/// `OnFailure` metadata: an action object, whose own keys continue the chain,
+/// or an array of them for a fan-out. The chain is walked without executing anything when
+/// the action is submitted. Limits are read from the `[chain]` configuration
+/// section, whose keys are the lowercase field names (`depth`, `nodes`,
+/// `bytes`). A limit of `None` leaves that dimension unbounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Struct {
+	/// The maximum number of follow-ups on any path of the chain.
+	#[serde(rename = "depth")]
+	pub Depth:Option<usize>,
+
+	/// The maximum number of follow-ups in the whole chain.
+	#[serde(rename = "nodes")]
+	pub Nodes:Option<usize>,
+
+	/// The maximum serialized size of the chain, in bytes.
+	#[serde(rename = "bytes")]
+	pub Bytes:Option<usize>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` with the default limits: a depth of 64, 10000
+	/// follow-ups and 1 MiB.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Struct { Depth:Some(64), Nodes:Some(10_000), Bytes:Some(1024 * 1024) } }
+
+	/// Sets the maximum depth of the chain.
+	///
+	/// # Arguments
+	///
+	/// * `Depth` - The maximum number of follow-ups on any path, or `None` for
+	///   no limit.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDepth(mut self, Depth:Option<usize>) -> Self {
+		self.Depth = Depth;
+
+		self
+	}
+
+	/// Sets the maximum number of follow-ups.
+	///
+	/// # Arguments
+	///
+	/// * `Nodes` - The maximum number of follow-ups, or `None` for no limit.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithNodes(mut self, Nodes:Option<usize>) -> Self {
+		self.Nodes = Nodes;
+
+		self
+	}
+
+	/// Sets the maximum serialized size of the chain.
+	///
+	/// # Arguments
+	///
+	/// * `Bytes` - The maximum size in bytes, or `None` for no limit.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBytes(mut self, Bytes:Option<usize>) -> Self {
+		self.Bytes = Bytes;
+
+		self
+	}
+
+	/// Walks a declared chain and checks it against the limits.
+	///
+	/// The walk stops at the first limit exceeded, so an enormous chain costs
+	/// no more than the limits allow.
+	///
+	/// # Arguments
+	///
+	/// * `NextAction` - The follow-ups of the submitted action.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the chain is within every limit, or `Error::ChainLimit`
+	/// naming the first limit exceeded.
+	pub fn Check(&self, NextAction:&Value) -> Result<(), Error> {
+		let Exceeded =
+			|Limit:&str, Maximum:usize| Error::ChainLimit { Limit:Limit.to_string(), Maximum };
+
+		let mut Stack = vec![(NextAction, 1)];
+
+		let mut Nodes = 0;
+
+		while let Some((Next, Depth)) = Stack.pop() {
+			let Follow = match Next {
+				Value::Null => continue,
+				Value::Array(Follow) => Follow.iter().collect(),
+				Next => vec![Next],
+			};
+
+			for Node in Follow {
+				Nodes += 1;
+
+				if let Some(Maximum) = self.Depth.filter(|Maximum| Depth > *Maximum) {
+					return Err(Exceeded("depth", Maximum));
+				}
+
+				if let Some(Maximum) = self.Nodes.filter(|Maximum| Nodes > *Maximum) {
+					return Err(Exceeded("nodes", Maximum));
+				}
+
+				for Key in ["OnSuccess", "NextAction", "OnFailure"] {
+					if let Some(Next) = Node.get(Key) {
+						Stack.push((Next, Depth + 1));
+					}
+				}
+			}
+		}
+
+		if let Some(Maximum) = self.Bytes {
+			let Bytes = serde_json::to_vec(NextAction).map(|Bytes| Bytes.len()).unwrap_or(0);
+
+			if Bytes > Maximum {
+				return Err(Exceeded("bytes", Maximum));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
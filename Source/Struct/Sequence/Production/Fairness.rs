@@ -0,0 +1,52 @@
+/// How a production line shares its dequeues between the identities
+/// submitting to it; see `Production::Fair`.
+///
+/// Queued actions are bucketed by the value of their `Key` metadata, the
+/// identity a pump records as `SubmittedBy` by default. Dequeues take the
+/// oldest action of each non-empty bucket in turn, so a single action of one
+/// identity waits behind at most one action of every other identity instead
+/// of behind their whole backlog. Up to `Buckets` identities get a bucket of
+/// their own at a time; actions of further identities, and those without
+/// the metadata, share the `*` bucket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Struct {
+	/// The metadata key identifying the submitter of an action.
+	pub Key:String,
+
+	/// How many identities get a bucket of their own.
+	pub Buckets:usize,
+}
+
+impl Struct {
+	/// Creates fair dequeuing by the `SubmittedBy` metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Buckets` - How many identities get a bucket of their own, at least
+	///   one.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Buckets:usize) -> Self {
+		Struct { Key:"SubmittedBy".to_string(), Buckets:Buckets.max(1) }
+	}
+
+	/// Sets the metadata key identifying the submitter of an action.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key, e.g. `Tenant`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithKey(mut self, Key:&str) -> Self {
+		self.Key = Key.to_string();
+
+		self
+	}
+}
+
+/// The bucket shared by actions without a bucket of their own.
+pub const SHARED:&str = "*";
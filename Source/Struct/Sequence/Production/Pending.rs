@@ -0,0 +1,163 @@
+/// The completion of an action submitted with `Production::Submit`.
+///
+/// Awaiting it yields the value the action recorded, or the error of its
+/// last attempt once retries are exhausted. Dropping it does not cancel the
+/// action unless `CancelOnDrop` was requested.
+pub struct Struct {
+	/// The sequence number of the action in its production line.
+	Id:u64,
+
+	/// Receives the outcome from the sequence.
+	Receiver:Receiver<Result<Value, Error>>,
+
+	/// Set to withdraw the action before it runs.
+	Cancelled:Arc<AtomicBool>,
+
+	/// Whether dropping the handle withdraws the action.
+	CancelOnDrop:bool,
+}
+
+impl Struct {
+	/// Creates a new pending completion.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The sequence number of the action.
+	/// * `Receiver` - Receives the outcome from the sequence.
+	/// * `Cancelled` - The flag withdrawing the action, see `Guard`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Id:u64, Receiver:Receiver<Result<Value, Error>>, Cancelled:Arc<AtomicBool>) -> Self {
+		Struct { Id, Receiver, Cancelled, CancelOnDrop:false }
+	}
+
+	/// Creates a completion that has already failed, e.g. because the
+	/// action could not be routed.
+	///
+	/// # Arguments
+	///
+	/// * `Error` - The error the completion resolves to.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn Failed(Error:Error) -> Self {
+		let (Sender, Receiver) = channel();
+
+		let _ = Sender.send(Err(Error));
+
+		Self::New(0, Receiver, Arc::new(AtomicBool::new(false)))
+	}
+
+	/// Returns the sequence number of the action in its production line.
+	pub fn Id(&self) -> u64 { self.Id }
+
+	/// Withdraws the action when the handle is dropped before it ran.
+	///
+	/// An action that already started is not interrupted.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn CancelOnDrop(mut self) -> Self {
+		self.CancelOnDrop = true;
+
+		self
+	}
+
+	/// Wraps an action so that its `Pending` can withdraw it.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to wrap.
+	///
+	/// # Returns
+	///
+	/// The wrapped action and the flag withdrawing it.
+	pub fn Guard(Action:Box<dyn Action>) -> (Box<dyn Action>, Arc<AtomicBool>) {
+		let Cancelled = Arc::new(AtomicBool::new(false));
+
+		(Box::new(Guard { Action, Cancelled:Cancelled.clone() }), Cancelled)
+	}
+}
+
+impl Future for Struct {
+	type Output = Result<Value, Error>;
+
+	fn poll(mut self: Pin<&mut Self>, Context:&mut Context<'_>) -> Poll<Self::Output> {
+		Pin::new(&mut self.Receiver).poll(Context).map(|Outcome| {
+			Outcome.unwrap_or_else(|_| {
+				Err(Error::Cancellation("Action was dropped before it completed".to_string()))
+			})
+		})
+	}
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		if self.CancelOnDrop {
+			self.Cancelled.store(true, Ordering::SeqCst);
+		}
+	}
+}
+
+/// An action that is skipped once its `Pending` withdrew it.
+struct Guard {
+	/// The wrapped action.
+	Action:Box<dyn Action>,
+
+	/// Whether the action was withdrawn.
+	Cancelled:Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl Action for Guard {
+	async fn Execute(&self, Context:&Life) -> Result<(), Error> {
+		if self.Cancelled.load(Ordering::SeqCst) {
+			return Ok(());
+		}
+
+		self.Action.Execute(Context).await
+	}
+
+	fn Duplicate(&self) -> Result<Box<dyn Action>, Error> { self.Action.Duplicate() }
+
+	async fn Metadata(&self, Key:&str) -> Option<Value> { self.Action.Metadata(Key).await }
+
+	fn Annotate(&self, Key:&str, Value:Value) { self.Action.Annotate(Key, Value) }
+
+	async fn ArgumentSize(&self) -> u64 { self.Action.ArgumentSize().await }
+
+	fn Partial(&self) -> Option<Writer> { self.Action.Partial() }
+
+	async fn Stage(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		self.Action.Stage(Context, Phase).await
+	}
+
+	fn Cancellation(&self) -> Option<Cancellation> { self.Action.Cancellation() }
+
+	fn Fail(&self, Error:&Error) { self.Action.Fail(Error) }
+}
+
+use std::{
+	future::Future,
+	pin::Pin,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	task::{Context, Poll},
+};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::sync::oneshot::{channel, Receiver};
+
+use crate::{
+	Enum::Sequence::{Action::Error::Enum as Error, Phase::Enum as Phase},
+	Struct::Sequence::{Cancellation::Struct as Cancellation, Life::Struct as Life},
+	Trait::Sequence::Action::Trait as Action,
+	Type::Sequence::Action::Writer::Type as Writer,
+};
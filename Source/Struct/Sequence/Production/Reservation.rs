@@ -0,0 +1,93 @@
+/// An action reserved with `Production::DoReserve`, invisible to other
+/// consumers until its lease expires.
+///
+/// `Ack` removes the action for good and `Nack` gives it back. A reservation
+/// dropped without either stays leased until the next reap after its lease
+/// expired, which puts the action back into its line with its
+/// `DeliveryCount` metadata incremented.
+pub struct Struct {
+	/// The production line the action was reserved from.
+	Production:Arc<Production>,
+
+	/// The stamp the action received when it was enqueued.
+	Stamp:Stamp,
+
+	/// The action, carrying its `DeliveryCount` metadata.
+	Action:Arc<dyn Action>,
+
+	/// How many times the action was handed out, this time included.
+	Deliveries:u64,
+}
+
+impl Struct {
+	/// Creates a new reservation.
+	///
+	/// # Arguments
+	///
+	/// * `Production` - The production line holding the lease.
+	/// * `Stamp` - The stamp of the action.
+	/// * `Action` - The reserved action.
+	/// * `Deliveries` - How many times the action was handed out.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub(crate) fn New(
+		Production:Arc<Production>,
+		Stamp:Stamp,
+		Action:Arc<dyn Action>,
+		Deliveries:u64,
+	) -> Self {
+		let Action =
+			Arc::new(Annotated::New(Action).WithMetadata("DeliveryCount", Value::from(Deliveries)));
+
+		Struct { Production, Stamp, Action, Deliveries }
+	}
+
+	/// Returns the stamp the action received when it was enqueued.
+	pub fn Stamp(&self) -> Stamp { self.Stamp }
+
+	/// Returns the reserved action, carrying its `DeliveryCount` metadata.
+	pub fn Action(&self) -> Arc<dyn Action> { self.Action.clone() }
+
+	/// Returns how many times the action was handed out, this time included.
+	pub fn Deliveries(&self) -> u64 { self.Deliveries }
+
+	/// Removes the action from its line for good.
+	///
+	/// # Returns
+	///
+	/// `true` if the lease was still held, `false` if it had expired and the
+	/// action was handed out again.
+	pub fn Ack(self) -> bool { self.Production.Acknowledge(self.Stamp.Sequence, self.Deliveries) }
+
+	/// Gives the action back.
+	///
+	/// # Arguments
+	///
+	/// * `Requeue` - Whether the action goes back to the end of its line, with
+	///   its `DeliveryCount` incremented, or leaves it for good.
+	///
+	/// # Returns
+	///
+	/// The action when it left its line, so the caller can hand it to a
+	/// dead-letter queue; `None` when it was requeued or the lease had
+	/// expired.
+	pub async fn Nack(self, Requeue:bool) -> Option<Arc<dyn Action>> {
+		self.Production
+			.Release(self.Stamp.Sequence, self.Deliveries, Requeue)
+			.await
+	}
+}
+
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::{
+	Struct::Sequence::{
+		Action::Annotated::Struct as Annotated,
+		Production::{Stamp::Struct as Stamp, Struct as Production},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
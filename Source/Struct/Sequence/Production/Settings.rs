@@ -0,0 +1,153 @@
+/// How a production line is consumed by `Sequence::RunKarma`.
+///
+/// Settings are attached per Karma queue through `Life::Builder` or read from
+/// a `[queues.<name>]` configuration section, whose keys are the lowercase
+/// field names (`concurrency`, `batch`, `rate`, `deadletter`,
+/// `deadletterexpired`, `paused`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Struct {
+	/// How many actions of the queue may execute at the same time.
+	#[serde(rename = "concurrency")]
+	pub Concurrency:usize,
+
+	/// How many actions are dequeued at once when capacity is available.
+	/// `1` dequeues one action at a time.
+	#[serde(rename = "batch")]
+	pub Batch:usize,
+
+	/// The largest number of actions dequeued per second, or `None` for no
+	/// limit.
+	#[serde(rename = "rate")]
+	pub Rate:Option<f64>,
+
+	/// The Karma queue receiving actions that failed their final attempt, or
+	/// `None` to drop them.
+	#[serde(rename = "deadletter")]
+	pub DeadLetter:Option<String>,
+
+	/// Whether actions that expired before running also go to the
+	/// dead-letter queue, with `Reason` metadata set to `expired`.
+	#[serde(rename = "deadletterexpired")]
+	pub DeadLetterExpired:bool,
+
+	/// Whether the queue is paused: it still accepts actions, but none is
+	/// dequeued until it is resumed.
+	#[serde(rename = "paused")]
+	pub Paused:bool,
+}
+
+impl Struct {
+	/// Creates settings consuming one action at a time without a rate limit
+	/// or dead-letter queue.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct {
+			Concurrency:1,
+			Batch:1,
+			Rate:None,
+			DeadLetter:None,
+			DeadLetterExpired:false,
+			Paused:false,
+		}
+	}
+
+	/// Sets how many actions may execute at the same time.
+	///
+	/// # Arguments
+	///
+	/// * `Concurrency` - The concurrency limit, at least 1.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithConcurrency(mut self, Concurrency:usize) -> Self {
+		self.Concurrency = Concurrency.max(1);
+
+		self
+	}
+
+	/// Sets how many actions are dequeued at once.
+	///
+	/// # Arguments
+	///
+	/// * `Batch` - The batch size, at least 1.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBatch(mut self, Batch:usize) -> Self {
+		self.Batch = Batch.max(1);
+
+		self
+	}
+
+	/// Limits how many actions are dequeued per second.
+	///
+	/// # Arguments
+	///
+	/// * `Rate` - The number of actions per second.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRate(mut self, Rate:f64) -> Self {
+		self.Rate = Some(Rate);
+
+		self
+	}
+
+	/// Sets the queue receiving actions that failed their final attempt.
+	///
+	/// # Arguments
+	///
+	/// * `DeadLetter` - The name of a Karma queue.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDeadLetter(mut self, DeadLetter:&str) -> Self {
+		self.DeadLetter = Some(DeadLetter.to_string());
+
+		self
+	}
+
+	/// Sets whether expired actions go to the dead-letter queue.
+	///
+	/// # Arguments
+	///
+	/// * `DeadLetterExpired` - Whether to keep expired actions.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDeadLetterExpired(mut self, DeadLetterExpired:bool) -> Self {
+		self.DeadLetterExpired = DeadLetterExpired;
+
+		self
+	}
+
+	/// Sets whether the queue is paused.
+	///
+	/// # Arguments
+	///
+	/// * `Paused` - Whether no action is dequeued.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithPaused(mut self, Paused:bool) -> Self {
+		self.Paused = Paused;
+
+		self
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use serde::{Deserialize, Serialize};
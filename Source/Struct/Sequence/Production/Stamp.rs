@@ -0,0 +1,104 @@
+/// Records when and in which order an action entered a production line.
+///
+/// A stamp is assigned by `Production::Assign` and travels with the action
+/// until it is dequeued, so ordering issues can be traced after the fact.
+#[derive(Clone, Copy, Debug)]
+pub struct Struct {
+	/// The sequence number of the action, unique and monotonically increasing
+	/// per production line.
+	pub Sequence:u64,
+
+	/// The monotonic instant at which the action was enqueued.
+	///
+	/// This uses `tokio::time::Instant`, so it follows the paused test clock.
+	pub Instant:Instant,
+
+	/// The wall-clock time at which the action was enqueued.
+	pub Time:SystemTime,
+
+	/// The `Priority` of the action when it was enqueued, which places it in
+	/// the line.
+	pub Priority:i64,
+}
+
+impl Struct {
+	/// Creates a new `Struct` for the given sequence number, stamped now.
+	///
+	/// # Arguments
+	///
+	/// * `Sequence` - The sequence number assigned to the action.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance carrying the current instant and time.
+	pub fn New(Sequence:u64) -> Self {
+		Struct { Sequence, Instant:Instant::now(), Time:SystemTime::now(), Priority:0 }
+	}
+
+	/// Records the priority of the action.
+	///
+	/// # Arguments
+	///
+	/// * `Priority` - The priority of the action, see `Action::Priority`.
+	pub fn WithPriority(self, Priority:i64) -> Self { Struct { Priority, ..self } }
+
+	/// Computes how long the action has been waiting since it was enqueued.
+	///
+	/// # Returns
+	///
+	/// The elapsed `Duration` since the action was stamped.
+	pub fn Wait(&self) -> Duration { self.Instant.elapsed() }
+
+	/// Computes when a delayed action becomes due.
+	///
+	/// `Delay` is a `Time::Duration` counted from when the action was
+	/// enqueued, so `150.5` or `"1s 500ms"` stagger actions below a second.
+	/// A malformed delay holds nothing; `Action::Execute` then fails with
+	/// `Error::Execution`.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action whose metadata is read.
+	///
+	/// # Returns
+	///
+	/// The instant the action is due, or `None` if it has no valid delay or
+	/// the delay has elapsed.
+	pub async fn Due(&self, Action:&dyn Action) -> Option<Instant> {
+		let Delay = Metadata::<Span>(Action, "Delay").await?;
+
+		let Due = self.Instant.checked_add(Delay.Get())?;
+
+		(Due > Instant::now()).then_some(Due)
+	}
+
+	/// Checks whether the action outlived its time-to-live.
+	///
+	/// `Ttl` is a `Time::Duration` the action may wait after it was
+	/// enqueued; `ExpiresAt` is a `Time::Timestamp`.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action whose metadata is read.
+	///
+	/// # Returns
+	///
+	/// `true` if either limit has passed.
+	pub async fn Expired(&self, Action:&dyn Action) -> bool {
+		let Ttl = Metadata::<Span>(Action, "Ttl").await;
+
+		let ExpiresAt = Metadata::<Timestamp>(Action, "ExpiresAt").await;
+
+		Ttl.is_some_and(|Ttl| self.Wait() >= Ttl.Get())
+			|| ExpiresAt.is_some_and(|ExpiresAt| ExpiresAt.Passed())
+	}
+}
+
+use std::time::{Duration, SystemTime};
+
+use tokio::time::Instant;
+
+use crate::{
+	Time::{Duration::Struct as Span, Metadata, Timestamp::Struct as Timestamp},
+	Trait::Sequence::Action::Trait as Action,
+};
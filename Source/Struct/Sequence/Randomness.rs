@@ -0,0 +1,73 @@
+/// The source of every random choice a `Life` makes, such as retry jitter.
+///
+/// A handle is always seeded: either with a fixed seed, which makes the
+/// choices reproducible, or with a seed drawn from the thread RNG. The seed
+/// is logged when a `Life` is built, so a run seen in the logs can be
+/// repeated with `Life::Builder().WithRngSeed(..)`.
+#[derive(Debug)]
+pub struct Struct {
+	/// The seed the generator started from.
+	Seed:u64,
+
+	/// The generator, shared by every user of the handle.
+	Generator:Mutex<StdRng>,
+}
+
+impl Struct {
+	/// Creates a handle seeded from the thread RNG.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::Seeded(rand::thread_rng().gen()) }
+
+	/// Creates a handle from a fixed seed.
+	///
+	/// # Arguments
+	///
+	/// * `Seed` - The seed of the generator.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance making the same choices for the same seed.
+	pub fn Seeded(Seed:u64) -> Self {
+		Struct { Seed, Generator:Mutex::new(StdRng::seed_from_u64(Seed)) }
+	}
+
+	/// Returns the seed the generator started from.
+	pub fn Seed(&self) -> u64 { self.Seed }
+
+	/// Draws a number from a range.
+	///
+	/// # Arguments
+	///
+	/// * `Range` - The half-open range to draw from; must not be empty.
+	///
+	/// # Returns
+	///
+	/// A number in `Range`.
+	pub fn Range(&self, Range:Range<u64>) -> u64 { self.Lock().gen_range(Range) }
+
+	/// Shuffles a slice in place, e.g. to pick work-steal victims.
+	///
+	/// # Arguments
+	///
+	/// * `Slice` - The slice to shuffle.
+	pub fn Shuffle<T>(&self, Slice:&mut [T]) { Slice.shuffle(&mut *self.Lock()); }
+
+	/// Takes the lock of the generator, ignoring poisoning.
+	fn Lock(&self) -> MutexGuard<'_, StdRng> {
+		self.Generator.lock().unwrap_or_else(|Poison| Poison.into_inner())
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use std::{
+	ops::Range,
+	sync::{Mutex, MutexGuard},
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
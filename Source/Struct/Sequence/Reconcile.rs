@@ -0,0 +1,104 @@
+/// The policy `Life::Reconcile` applies to each class of queued action that
+/// cannot run, read from the `[reconcile]` section of the configuration:
+/// `missing_function`, `missing_hook` and `undeserializable`, each `keep`,
+/// `dead_letter` or `drop`. Every class is dead-lettered unless set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Struct {
+	/// What is done with actions whose function the plan lacks.
+	pub MissingFunction:Policy,
+
+	/// What is done with actions referencing a hook that matches none.
+	pub MissingHook:Policy,
+
+	/// What is done with actions that cannot be read as a plan call.
+	pub Undeserializable:Policy,
+}
+
+impl Struct {
+	/// Creates a policy dead-lettering every class.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct {
+			MissingFunction:Policy::DeadLetter,
+			MissingHook:Policy::DeadLetter,
+			Undeserializable:Policy::DeadLetter,
+		}
+	}
+
+	/// Reads the policy from the `[reconcile]` section of a configuration,
+	/// logging and ignoring unknown names.
+	///
+	/// # Arguments
+	///
+	/// * `Fate` - The configuration settings.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn Configured(Fate:&Config) -> Self {
+		let Read = |Key:&str| {
+			let Name = Fate.get_string(&format!("reconcile.{}", Key)).ok()?;
+
+			let Policy = Policy::Parse(&Name);
+
+			if Policy.is_none() {
+				warn!("Unknown reconcile policy {} for {}", Name, Key);
+			}
+
+			Policy
+		};
+
+		let Default = Self::New();
+
+		Struct {
+			MissingFunction:Read("missing_function").unwrap_or(Default.MissingFunction),
+			MissingHook:Read("missing_hook").unwrap_or(Default.MissingHook),
+			Undeserializable:Read("undeserializable").unwrap_or(Default.Undeserializable),
+		}
+	}
+
+	/// Sets the policy of one class.
+	///
+	/// # Arguments
+	///
+	/// * `Class` - The class; `Runnable` actions are always kept.
+	/// * `Policy` - What is done with its actions.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithPolicy(mut self, Class:Class, Policy:Policy) -> Self {
+		match Class {
+			Class::Runnable => {},
+			Class::MissingFunction => self.MissingFunction = Policy,
+			Class::MissingHook => self.MissingHook = Policy,
+			Class::Undeserializable => self.Undeserializable = Policy,
+		}
+
+		self
+	}
+
+	/// Returns the policy of one class.
+	pub fn Get(&self, Class:Class) -> Policy {
+		match Class {
+			Class::Runnable => Policy::Keep,
+			Class::MissingFunction => self.MissingFunction,
+			Class::MissingHook => self.MissingHook,
+			Class::Undeserializable => self.Undeserializable,
+		}
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use config::Config;
+use log::warn;
+
+use crate::Enum::Sequence::Reconcile::{Class::Enum as Class, Policy::Enum as Policy};
+
+pub mod Report;
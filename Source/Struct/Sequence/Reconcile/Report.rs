@@ -0,0 +1,68 @@
+/// What `Life::Reconcile` found in the Karma queues and did about it.
+///
+/// Every scanned action counts once under its class; every action that
+/// cannot run counts once more under what was done with it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The actions that can run.
+	pub Runnable:usize,
+
+	/// The actions whose function the plan lacks.
+	pub MissingFunction:usize,
+
+	/// The actions referencing a hook that matches none.
+	pub MissingHook:usize,
+
+	/// The actions that cannot be read as a plan call.
+	pub Undeserializable:usize,
+
+	/// The actions that cannot run but were left queued.
+	pub Kept:usize,
+
+	/// The actions moved to a dead-letter queue.
+	pub DeadLettered:usize,
+
+	/// The actions removed.
+	pub Dropped:usize,
+}
+
+impl Struct {
+	/// Counts one scanned action of a class.
+	pub(crate) fn Count(&mut self, Class:Class) {
+		match Class {
+			Class::Runnable => self.Runnable += 1,
+			Class::MissingFunction => self.MissingFunction += 1,
+			Class::MissingHook => self.MissingHook += 1,
+			Class::Undeserializable => self.Undeserializable += 1,
+		}
+	}
+
+	/// Returns how many actions were scanned.
+	pub fn Scanned(&self) -> usize {
+		self.Runnable + self.MissingFunction + self.MissingHook + self.Undeserializable
+	}
+}
+
+impl Display for Struct {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{} actions: {} runnable, {} missing a function, {} missing a hook, {} \
+			 undeserializable; {} kept, {} dead-lettered, {} dropped",
+			self.Scanned(),
+			self.Runnable,
+			self.MissingFunction,
+			self.MissingHook,
+			self.Undeserializable,
+			self.Kept,
+			self.DeadLettered,
+			self.Dropped
+		)
+	}
+}
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Sequence::Reconcile::Class::Enum as Class;
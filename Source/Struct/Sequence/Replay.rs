@@ -0,0 +1,3 @@
+pub mod Record;
+pub mod Recorder;
+pub mod Replayer;
@@ -0,0 +1,83 @@
+/// One action a `Recorder` saw, written as a line of its JSONL file.
+///
+/// An action is identified by its `Action` and `Argument` metadata; the
+/// digest of both is what a `Replayer` matches incoming actions against.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The name of the plan function the action runs.
+	pub Action:String,
+
+	/// The arguments passed to the function.
+	pub Argument:Vec<Value>,
+
+	/// The digest of `Action` and `Argument`.
+	pub Digest:String,
+
+	/// The value the action recorded, if it succeeded with one.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Value:Option<Value>,
+
+	/// The error the action failed with, if any.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Error:Option<Error>,
+
+	/// The Unix time in milliseconds at which the action started.
+	pub Started:u64,
+
+	/// How long the action took, in milliseconds.
+	pub Elapsed:u64,
+}
+
+impl Struct {
+	/// Reads the identity of an action from its metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to identify.
+	///
+	/// # Returns
+	///
+	/// The name of the action and its arguments; an action without `Action`
+	/// metadata is named by an empty string.
+	pub async fn Identify(Action:&dyn Action) -> (String, Vec<Value>) {
+		let Name = Action
+			.Metadata("Action")
+			.await
+			.and_then(|Name| Name.as_str().map(str::to_string))
+			.unwrap_or_default();
+
+		let Argument = Action
+			.Metadata("Argument")
+			.await
+			.and_then(|Argument| Argument.as_array().cloned())
+			.unwrap_or_default();
+
+		(Name, Argument)
+	}
+
+	/// Computes the digest matching an action to its recording.
+	///
+	/// The digest is `Digest::ActionDigest` of the name and arguments,
+	/// stable across processes, platforms and builds.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The name of the action.
+	/// * `Argument` - The arguments of the action.
+	///
+	/// # Returns
+	///
+	/// The digest as a hexadecimal string.
+	pub fn Digest(Action:&str, Argument:&[Value]) -> String {
+		Digest::Hex(&Digest::ActionDigest(Action, Argument, &Map::new()))
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Digest,
+	Enum::Sequence::Action::Error::Enum as Error,
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,112 @@
+/// A site that logs every action it handles before passing on the outcome.
+///
+/// Each action is delegated to the inner site; its name, arguments, outcome
+/// and timing are then appended to a JSONL file as a `Record`, one line per
+/// attempt. A `Replayer` reads the file back. Failing to write a record is
+/// logged and does not fail the action.
+pub struct Struct {
+	/// The site actions are delegated to.
+	Site:Arc<dyn Site>,
+
+	/// The file records are appended to.
+	File:Mutex<File>,
+}
+
+impl Struct {
+	/// Creates a recorder appending to a file, creating it if needed.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the JSONL file.
+	/// * `Site` - The site actions are delegated to.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance, or the error opening the file.
+	pub async fn Create(Path:impl AsRef<Path>, Site:Arc<dyn Site>) -> io::Result<Self> {
+		let File = OpenOptions::new().create(true).append(true).open(Path).await?;
+
+		Ok(Struct { Site, File:Mutex::new(File) })
+	}
+
+	/// Appends a record as one line of the file.
+	async fn Write(&self, Record:&Record) -> io::Result<()> {
+		let mut Line = serde_json::to_vec(Record)?;
+
+		Line.push(b'\n');
+
+		let mut File = self.File.lock().await;
+
+		File.write_all(&Line).await?;
+
+		File.flush().await
+	}
+}
+
+#[async_trait]
+impl Site for Struct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		let (Name, Argument) = Record::Identify(Action.as_ref()).await;
+
+		let Started = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|Started| Started.as_millis() as u64)
+			.unwrap_or_default();
+
+		let Instant = Instant::now();
+
+		let Result = self.Site.Receive(Action, Context).await;
+
+		let Elapsed = Instant.elapsed().as_millis() as u64;
+
+		let (Value, Error) = match Result {
+			Ok(()) => (Invocation::Current().and_then(|Invocation| Invocation.Output()), None),
+			Err(_Error) => (None, Some(_Error)),
+		};
+
+		let Record = Record {
+			Digest:Record::Digest(&Name, &Argument),
+			Action:Name,
+			Argument,
+			Value,
+			Error,
+			Started,
+			Elapsed,
+		};
+
+		if let Err(_Error) = self.Write(&Record).await {
+			warn!("Failed to record action {}: {}", Record.Action, _Error);
+		}
+
+		match Record.Error {
+			Some(_Error) => Err(_Error),
+			None => Ok(()),
+		}
+	}
+}
+
+use std::{
+	io,
+	path::Path,
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use log::warn;
+use tokio::{
+	fs::{File, OpenOptions},
+	io::AsyncWriteExt,
+	sync::Mutex,
+	time::Instant,
+};
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Invocation::Struct as Invocation,
+		Life::Struct as Life,
+		Replay::Record::Struct as Record,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
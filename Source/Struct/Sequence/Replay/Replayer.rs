@@ -0,0 +1,157 @@
+/// Plays back the actions logged by a `Recorder`.
+///
+/// A replayer either re-enqueues the recorded actions against a real site
+/// with `Replay`, for regression runs, or serves as a stub site answering
+/// every action with its recorded outcome, so layers above the site can be
+/// tested without side effects. Stubbed actions are matched by the digest of
+/// their name and arguments; identical actions receive their recordings in
+/// order, the last one repeating. An action without a recording fails with
+/// an `Error::Execution` naming it.
+pub struct Struct {
+	/// The records in the order they were written.
+	Record:Vec<Record>,
+
+	/// The recordings not yet served, by digest.
+	Remaining:Mutex<HashMap<String, VecDeque<Record>>>,
+}
+
+impl Struct {
+	/// Creates a replayer from records.
+	///
+	/// # Arguments
+	///
+	/// * `Record` - The records in the order they were written.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Record:Vec<Record>) -> Self {
+		let mut Remaining = HashMap::<String, VecDeque<Record>>::new();
+
+		for Record in &Record {
+			Remaining
+				.entry(Record.Digest.clone())
+				.or_default()
+				.push_back(Record.clone());
+		}
+
+		Struct { Record, Remaining:Mutex::new(Remaining) }
+	}
+
+	/// Reads the records of a JSONL file written by a `Recorder`.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the JSONL file.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance, or the error reading or parsing the file.
+	pub async fn Open(Path:impl AsRef<Path>) -> io::Result<Self> {
+		let Content = read_to_string(Path).await?;
+
+		let Record = Content
+			.lines()
+			.filter(|Line| !Line.trim().is_empty())
+			.map(serde_json::from_str)
+			.collect::<Result<Vec<Record>, _>>()?;
+
+		Ok(Self::New(Record))
+	}
+
+	/// Returns the records in the order they were written.
+	pub fn Record(&self) -> &[Record] { &self.Record }
+
+	/// Enqueues every recorded action again, in order.
+	///
+	/// The actions run the functions of the given plan with their recorded
+	/// arguments; comparing their completions with `Record` reveals
+	/// regressions.
+	///
+	/// # Arguments
+	///
+	/// * `Production` - The production line to enqueue on.
+	/// * `Plan` - The plan providing the functions.
+	///
+	/// # Returns
+	///
+	/// The `Pending` completion of every action, in record order.
+	pub async fn Replay(
+		&self,
+		Production:&Production,
+		Plan:Arc<Formality>,
+	) -> Vec<Pending::Struct> {
+		let mut Pending = Vec::with_capacity(self.Record.len());
+
+		for Record in &self.Record {
+			let Action = Action::Struct::New(&Record.Action, (), Plan.clone())
+				.WithOption(Reserved::Argument, Value::Array(Record.Argument.clone()));
+
+			Pending.push(Production.Submit(Box::new(Action)).await);
+		}
+
+		Pending
+	}
+
+	/// Takes the next recording of an action.
+	fn Take(&self, Digest:&str) -> Option<Record> {
+		let mut Remaining = self.Remaining.lock().unwrap_or_else(|Poison| Poison.into_inner());
+
+		let Queue = Remaining.get_mut(Digest)?;
+
+		if Queue.len() > 1 {
+			Queue.pop_front()
+		} else {
+			Queue.front().cloned()
+		}
+	}
+}
+
+#[async_trait]
+impl Site for Struct {
+	async fn Receive(&self, Action:Arc<dyn ActionTrait>, _Context:&Life) -> Result<(), Error> {
+		let (Name, Argument) = Record::Identify(Action.as_ref()).await;
+
+		let Record = self.Take(&Record::Digest(&Name, &Argument)).ok_or_else(|| {
+			Error::Execution(format!(
+				"No recording of action {} with arguments {}",
+				Name,
+				Value::Array(Argument.clone())
+			))
+		})?;
+
+		if let Some(_Error) = Record.Error {
+			return Err(_Error);
+		}
+
+		if let Some(Value) = Record.Value {
+			Invocation::Record(Value);
+		}
+
+		Ok(())
+	}
+}
+
+use std::{
+	collections::{HashMap, VecDeque},
+	io,
+	path::Path,
+	sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::fs::read_to_string;
+
+use crate::{
+	Enum::Sequence::{Action::Error::Enum as Error, Reserved::Enum as Reserved},
+	Struct::Sequence::{
+		Action,
+		Invocation::Struct as Invocation,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Formality,
+		Production::{Pending, Struct as Production},
+		Replay::Record::Struct as Record,
+	},
+	Trait::Sequence::{Action::Trait as ActionTrait, Site::Trait as Site},
+};
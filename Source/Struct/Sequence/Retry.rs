@@ -0,0 +1,237 @@
+/// How `Fn::Retry` retries a failing operation.
+///
+/// The policy bounds the number of attempts, chooses the backoff between
+/// them and decides, through its classifier, which errors are worth another
+/// attempt. `Sequence` retries its actions with such a policy, so an
+/// operation retried inside a handler follows the same rules.
+pub struct Struct<E> {
+	/// The maximum number of attempts, at least 1.
+	pub Attempts:u32,
+
+	/// The delay after each failed attempt.
+	pub Backoff:Backoff,
+
+	/// How many of the first and last attempts the history keeps.
+	pub Keep:usize,
+
+	/// Whether an error may be retried.
+	Classify:Arc<dyn Fn(&E) -> bool + Send + Sync>,
+
+	/// Called before each backoff with the history so far, whose last
+	/// attempt carries the backoff, and the error of that attempt.
+	Hook:Option<Hook<E>>,
+
+	/// The source of the backoff jitter.
+	Randomness:Arc<Randomness>,
+
+	/// The token ending the retries at the next backoff.
+	Cancellation:Option<Cancellation>,
+
+	/// The runtime waiting out the backoffs.
+	Runtime:Arc<dyn Runtime>,
+}
+
+impl<E> Struct<E> {
+	/// Creates a policy making 3 attempts, 100 milliseconds apart, retrying
+	/// every error.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct {
+			Attempts:3,
+			Backoff:Backoff::Fixed(Duration::from_millis(100)),
+			Keep:10,
+			Classify:Arc::new(|_| true),
+			Hook:None,
+			Randomness:Arc::new(Randomness::New()),
+			Cancellation:None,
+			Runtime:Arc::new(Tokio::Struct),
+		}
+	}
+
+	/// Sets the maximum number of attempts.
+	///
+	/// # Arguments
+	///
+	/// * `Attempts` - The number of attempts, at least 1.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAttempts(mut self, Attempts:u32) -> Self {
+		self.Attempts = Attempts.max(1);
+
+		self
+	}
+
+	/// Sets the delay after each failed attempt.
+	///
+	/// # Arguments
+	///
+	/// * `Backoff` - The backoff strategy.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBackoff(mut self, Backoff:Backoff) -> Self {
+		self.Backoff = Backoff;
+
+		self
+	}
+
+	/// Sets how many of the first and last attempts the history keeps.
+	///
+	/// # Arguments
+	///
+	/// * `Keep` - The number of attempts kept at each end.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithKeep(mut self, Keep:usize) -> Self {
+		self.Keep = Keep;
+
+		self
+	}
+
+	/// Sets which errors may be retried; any other error ends the retries
+	/// at once.
+	///
+	/// # Arguments
+	///
+	/// * `Classify` - Returns `true` for an error worth another attempt.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithClassify(mut self, Classify:impl Fn(&E) -> bool + Send + Sync + 'static) -> Self {
+		self.Classify = Arc::new(Classify);
+
+		self
+	}
+
+	/// Sets a hook called before each backoff.
+	///
+	/// # Arguments
+	///
+	/// * `Hook` - Receives the history so far, whose last attempt carries the
+	///   backoff, and the error of that attempt.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithHook(mut self, Hook:impl Fn(&History, &E) + Send + Sync + 'static) -> Self {
+		self.Hook = Some(Arc::new(Hook));
+
+		self
+	}
+
+	/// Sets the source of the backoff jitter, e.g. the seeded one of a
+	/// `Life`.
+	///
+	/// # Arguments
+	///
+	/// * `Randomness` - The source of random choices.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRandomness(mut self, Randomness:Arc<Randomness>) -> Self {
+		self.Randomness = Randomness;
+
+		self
+	}
+
+	/// Sets a token ending the retries: once cancelled, a pending backoff
+	/// ends at once and no further attempt is made.
+	///
+	/// # Arguments
+	///
+	/// * `Cancellation` - The cancellation token.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCancellation(mut self, Cancellation:Cancellation) -> Self {
+		self.Cancellation = Some(Cancellation);
+
+		self
+	}
+
+	/// Sets the runtime waiting out the backoffs, tokio's unless set.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime to sleep on.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Runtime;
+
+		self
+	}
+
+	/// Returns whether an error may be retried.
+	pub fn Retryable(&self, Error:&E) -> bool { (self.Classify)(Error) }
+
+	/// Computes the delay after a failed attempt.
+	///
+	/// # Arguments
+	///
+	/// * `Attempt` - The number of the failed attempt, starting at 1.
+	pub fn Delay(&self, Attempt:u32) -> Duration { self.Backoff.Delay(Attempt, &self.Randomness) }
+
+	/// Calls the hook, if any.
+	pub(crate) fn Notify(&self, History:&History, Error:&E) {
+		if let Some(Hook) = &self.Hook {
+			Hook(History, Error);
+		}
+	}
+
+	/// Waits out a backoff on the clock of the runtime.
+	///
+	/// # Returns
+	///
+	/// `false` if the token was cancelled before or during the wait.
+	pub(crate) async fn Wait(&self, Backoff:Duration) -> bool {
+		match &self.Cancellation {
+			Some(Cancellation) => {
+				select! {
+					_ = Cancellation.Cancelled() => false,
+					_ = self.Runtime.Sleep(Backoff) => !Cancellation.IsCancelled(),
+				}
+			},
+			None => {
+				self.Runtime.Sleep(Backoff).await;
+
+				true
+			},
+		}
+	}
+}
+
+impl<E> Default for Struct<E> {
+	fn default() -> Self { Self::New() }
+}
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::select;
+
+use crate::{
+	Enum::Sequence::Backoff::Enum as Backoff,
+	Struct::{
+		Runtime::Tokio,
+		Sequence::{
+			Attempt::History::Struct as History,
+			Cancellation::Struct as Cancellation,
+			Randomness::Struct as Randomness,
+		},
+	},
+	Trait::Runtime::Trait as Runtime,
+	Type::Sequence::Retry::Hook::Type as Hook,
+};
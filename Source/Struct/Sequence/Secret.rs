@@ -0,0 +1,3 @@
+pub mod Environment;
+pub mod File;
+pub mod Resolver;
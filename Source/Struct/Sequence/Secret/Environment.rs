@@ -0,0 +1,45 @@
+/// Reads secrets from environment variables.
+#[derive(Clone, Debug, Default)]
+pub struct Struct {
+	/// Put before the name of a secret to form its variable.
+	Prefix:String,
+}
+
+impl Struct {
+	/// Creates a provider reading the variable named like the secret.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Reads the variable of a secret under a prefix, e.g. `ECHO_SECRET_`.
+	///
+	/// # Arguments
+	///
+	/// * `Prefix` - Put before the name of a secret.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithPrefix(mut self, Prefix:&str) -> Self {
+		self.Prefix = Prefix.to_string();
+
+		self
+	}
+}
+
+#[async_trait]
+impl Provider for Struct {
+	async fn Secret(&self, Name:&str) -> Result<String, String> {
+		let Variable = format!("{}{}", self.Prefix, Name);
+
+		env::var(&Variable).map_err(|_Error| format!("{} is not set: {}", Variable, _Error))
+	}
+}
+
+use std::env;
+
+use async_trait::async_trait;
+
+use crate::Trait::Sequence::Secret::Trait as Provider;
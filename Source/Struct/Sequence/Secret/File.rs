@@ -0,0 +1,44 @@
+/// Reads secrets from the files of a directory, one secret per file named
+/// like it, as mounted by container orchestrators.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The directory holding the secrets.
+	Directory:PathBuf,
+}
+
+impl Struct {
+	/// Creates a provider reading a directory.
+	///
+	/// # Arguments
+	///
+	/// * `Directory` - The directory holding the secrets.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Directory:impl Into<PathBuf>) -> Self { Struct { Directory:Directory.into() } }
+}
+
+#[async_trait]
+impl Provider for Struct {
+	async fn Secret(&self, Name:&str) -> Result<String, String> {
+		// A name must not reach outside the directory
+		if Name.is_empty() || Name.contains(['/', '\\']) || Name.starts_with('.') {
+			return Err(format!("{} is not a valid secret name", Name));
+		}
+
+		let Content = fs::read_to_string(self.Directory.join(Name))
+			.await
+			.map_err(|_Error| format!("Cannot read secret {}: {}", Name, _Error))?;
+
+		// Files usually end with a newline that is not part of the secret
+		Ok(Content.trim_end_matches(['\r', '\n']).to_string())
+	}
+}
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use crate::Trait::Sequence::Secret::Trait as Provider;
@@ -0,0 +1,141 @@
+/// An argument transformer resolving secret references into their values.
+///
+/// A reference is an object with a single `$secret` key naming the secret,
+/// e.g. `{"$secret":"db_password"}`, anywhere in the arguments; the function
+/// receives the value the provider returns for it, as a string. Results are
+/// masked: a string equal to a resolved secret becomes its reference again
+/// and a secret within a longer string or an execution error becomes `***`.
+pub struct Struct {
+	/// The provider the secrets are read from.
+	Provider:Arc<dyn Provider>,
+
+	/// The names of the secrets resolved so far, by value.
+	Known:DashMap<String, String>,
+}
+
+impl Struct {
+	/// Creates a resolver reading secrets from a provider.
+	///
+	/// # Arguments
+	///
+	/// * `Provider` - The provider the secrets are read from.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Provider:Arc<dyn Provider>) -> Self { Struct { Provider, Known:DashMap::new() } }
+
+	/// Replaces the secret references of a value with their values.
+	async fn Resolve(&self, Value:Value) -> Result<Value, Error> {
+		match Value {
+			Value::Object(Object) => {
+				if let Some(Name) = Self::Reference(&Object) {
+					let Secret = self.Provider.Secret(Name).await.map_err(|_Error| {
+						Error::Execution(format!("Cannot resolve secret {}: {}", Name, _Error))
+					})?;
+
+					if !Secret.is_empty() {
+						self.Known.insert(Secret.clone(), Name.to_string());
+					}
+
+					return Ok(Value::String(Secret));
+				}
+
+				let mut Resolved = Map::with_capacity(Object.len());
+
+				for (Key, Value) in Object {
+					Resolved.insert(Key, Box::pin(self.Resolve(Value)).await?);
+				}
+
+				Ok(Value::Object(Resolved))
+			},
+			Value::Array(Array) => {
+				let mut Resolved = Vec::with_capacity(Array.len());
+
+				for Value in Array {
+					Resolved.push(Box::pin(self.Resolve(Value)).await?);
+				}
+
+				Ok(Value::Array(Resolved))
+			},
+			Value => Ok(Value),
+		}
+	}
+
+	/// Returns the name a secret reference points to.
+	fn Reference(Object:&Map<String, Value>) -> Option<&str> {
+		match (Object.len(), Object.get("$secret")) {
+			(1, Some(Value::String(Name))) => Some(Name),
+			_ => None,
+		}
+	}
+
+	/// Replaces the resolved secrets in a value with their references.
+	fn Masked(&self, Value:Value) -> Value {
+		match Value {
+			Value::String(Text) => match self.Known.get(&Text) {
+				Some(Name) => json!({ "$secret": Name.value() }),
+				None => Value::String(self.Text(Text)),
+			},
+			Value::Array(Array) => {
+				Value::Array(Array.into_iter().map(|Value| self.Masked(Value)).collect())
+			},
+			Value::Object(Object) => Value::Object(
+				Object
+					.into_iter()
+					.map(|(Key, Value)| (Key, self.Masked(Value)))
+					.collect(),
+			),
+			Value => Value,
+		}
+	}
+
+	/// Replaces the resolved secrets within a text with `***`.
+	fn Text(&self, Text:String) -> String {
+		self.Known.iter().fold(Text, |Text, Entry| {
+			if Text.contains(Entry.key().as_str()) {
+				Text.replace(Entry.key().as_str(), "***")
+			} else {
+				Text
+			}
+		})
+	}
+}
+
+#[async_trait]
+impl Transformer for Struct {
+	async fn Transform(
+		&self,
+		_Signature:&Signature,
+		Argument:Vec<Value>,
+	) -> Result<Vec<Value>, Error> {
+		let mut Resolved = Vec::with_capacity(Argument.len());
+
+		for Argument in Argument {
+			Resolved.push(self.Resolve(Argument).await?);
+		}
+
+		Ok(Resolved)
+	}
+
+	fn Mask(&self, Result:Result<Value, Error>) -> Result<Value, Error> {
+		match Result {
+			Ok(Value) => Ok(self.Masked(Value)),
+			// Functions report their failures as execution errors
+			Err(Error::Execution(Message)) => Err(Error::Execution(self.Text(Message))),
+			Err(_Error) => Err(_Error),
+		}
+	}
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use serde_json::{json, Map, Value};
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Action::Signature::Struct as Signature,
+	Trait::Sequence::{Secret::Trait as Provider, Transformer::Trait as Transformer},
+};
@@ -36,6 +36,19 @@ impl<T> Struct<T> {
 		self.0.lock().await.clone()
 	}
 
+	/// Retrieves a clone of the stored value without waiting, for callers
+	/// that cannot await.
+	///
+	/// # Returns
+	///
+	/// A clone of the stored value, or `None` while another holder has it
+	/// locked.
+	pub fn Peek(&self) -> Option<T>
+	where
+		T: Clone, {
+		self.0.try_lock().ok().map(|Value| Value.clone())
+	}
+
 	/// Sets a new value for the stored data.
 	///
 	/// This method acquires the mutex lock and replaces the stored value with
@@ -48,3 +61,5 @@ impl<T> Struct<T> {
 }
 
 use crate::Struct::Sequence::{Arc, Mutex};
+
+pub mod Reader;
@@ -0,0 +1,30 @@
+/// A read-only view of a `Signal`, for code that observes a value without
+/// being allowed to change it.
+#[derive(Clone, Debug)]
+pub struct Struct<T>(Signal::Struct<T>);
+
+impl<T> Struct<T> {
+	/// Creates a view of a signal.
+	///
+	/// # Arguments
+	///
+	/// * `Signal` - The signal to observe.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Signal:Signal::Struct<T>) -> Self { Struct(Signal) }
+
+	/// Retrieves a clone of the current value of the signal.
+	///
+	/// # Returns
+	///
+	/// A clone of the stored value.
+	pub async fn Get(&self) -> T
+	where
+		T: Clone, {
+		self.0.Get().await
+	}
+}
+
+use crate::Struct::Sequence::Signal;
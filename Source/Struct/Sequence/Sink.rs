@@ -0,0 +1,139 @@
+/// The output stream of one attempt, handed to handlers by
+/// `Invocation::OutputSink`.
+///
+/// Handlers producing more than fits in a `Value` write into the sink
+/// instead of returning their content, and shut it down when done. Writes
+/// complete only as fast as the destination accepts them, so a slow
+/// destination slows the handler down instead of buffering its output. The
+/// number of bytes accepted is counted for the result of the action.
+pub struct Struct {
+	/// The destination stream.
+	Writer:Writer,
+
+	/// The bytes written so far, shared with the invocation.
+	Written:Arc<AtomicU64>,
+}
+
+impl Struct {
+	/// Creates a sink writing into a stream.
+	///
+	/// # Arguments
+	///
+	/// * `Writer` - The destination stream.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Writer:Writer) -> Self { Struct { Writer, Written:Arc::new(AtomicU64::new(0)) } }
+
+	/// Opens the destination an action declares in its `OutputTo` metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action about to run.
+	/// * `Fate` - The configuration, whose `output.root` jails file
+	///   destinations.
+	///
+	/// # Returns
+	///
+	/// The destination and its sink, `None` if the action declares none, or
+	/// an `Error::Execution` if the destination cannot be opened.
+	pub async fn Open(
+		Action:&dyn Action,
+		Fate:&Config,
+	) -> Result<Option<(Destination, Self)>, Error> {
+		let Some(OutputTo) = Action.Metadata("OutputTo").await else {
+			return Ok(None);
+		};
+
+		let Destination = serde_json::from_value::<Destination>(OutputTo)
+			.map_err(|_Error| Error::Execution(format!("Invalid OutputTo: {}", _Error)))?;
+
+		let Writer:Writer = match &Destination {
+			Destination::File(Path) => {
+				let Root = Fate.get_string("output.root").map_err(|_| {
+					Error::Execution(
+						"File output requires output.root to be configured".to_string(),
+					)
+				})?;
+
+				let Resolved = Fs::New(Root).Resolve(Path).await?;
+
+				let Failure = |_Error:std::io::Error| {
+					Error::Execution(format!("Cannot open output {}: {}", Path, _Error))
+				};
+
+				if let Some(Parent) = Resolved.parent() {
+					fs::create_dir_all(Parent).await.map_err(Failure)?;
+				}
+
+				Box::new(File::create(&Resolved).await.map_err(Failure)?)
+			},
+			Destination::Partial => Action.Partial().ok_or_else(|| {
+				Error::Execution("Action was not submitted over a streaming connection".to_string())
+			})?,
+		};
+
+		Ok(Some((Destination, Self::New(Writer))))
+	}
+
+	/// Returns the number of bytes written so far.
+	pub fn Written(&self) -> u64 { self.Written.load(Ordering::Relaxed) }
+
+	/// Returns the counter of bytes written, shared with the sink.
+	pub(crate) fn Counter(&self) -> Arc<AtomicU64> { self.Written.clone() }
+}
+
+impl Debug for Struct {
+	fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Sink")
+			.field("Written", &self.Written())
+			.finish_non_exhaustive()
+	}
+}
+
+impl AsyncWrite for Struct {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		Context:&mut Context<'_>,
+		Buffer:&[u8],
+	) -> Poll<io::Result<usize>> {
+		let Written = ready!(Pin::new(&mut self.Writer).poll_write(Context, Buffer))?;
+
+		self.Written.fetch_add(Written as u64, Ordering::Relaxed);
+
+		Poll::Ready(Ok(Written))
+	}
+
+	fn poll_flush(mut self: Pin<&mut Self>, Context:&mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.Writer).poll_flush(Context)
+	}
+
+	fn poll_shutdown(mut self: Pin<&mut Self>, Context:&mut Context<'_>) -> Poll<io::Result<()>> {
+		Pin::new(&mut self.Writer).poll_shutdown(Context)
+	}
+}
+
+use std::{
+	fmt::Debug,
+	io,
+	pin::Pin,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	task::{ready, Context, Poll},
+};
+
+use config::Config;
+use tokio::{
+	fs::{self, File},
+	io::AsyncWrite,
+};
+
+use crate::{
+	Builtin::Fs::Struct as Fs,
+	Enum::Sequence::{Action::Error::Enum as Error, Destination::Enum as Destination},
+	Trait::Sequence::Action::Trait as Action,
+	Type::Sequence::Action::Writer::Type as Writer,
+};
@@ -0,0 +1,277 @@
+/// Holds delayed actions until they are due, off the worker path.
+///
+/// A sequence that dequeues an action whose `Delay` has not elapsed hands it
+/// here and moves on. A single task keeps the actions in a time-ordered heap
+/// and puts each back into its production line once due, with its original
+/// stamp. The task is started on first use. After `Shutdown` every held
+/// action is put back at once, and later ones are not held at all.
+pub struct Struct {
+	/// The held actions, earliest due first.
+	Heap:Arc<Mutex<BinaryHeap<Entry>>>,
+
+	/// Wakes the task when an earlier action arrives or on shutdown.
+	Wake:Arc<Notify>,
+
+	/// Whether the service was shut down.
+	Down:Arc<AtomicBool>,
+
+	/// The number of actions held, readable without the heap lock.
+	Held:Arc<AtomicUsize>,
+
+	/// The number of actions handed out so far, to keep equal due times in
+	/// arrival order.
+	Next:AtomicU64,
+
+	/// The task putting due actions back.
+	Task:OnceLock<Spawned::Struct>,
+
+	/// The overseer running the task.
+	Overseer:Arc<Overseer>,
+}
+
+/// An action waiting in the heap.
+struct Entry {
+	/// When the action is due.
+	Due:Instant,
+
+	/// The arrival number of the action.
+	Order:u64,
+
+	/// The production line the action goes back to.
+	Production:Arc<Production>,
+
+	/// The stamp the action received when it was enqueued.
+	Stamp:Stamp,
+
+	/// The delayed action.
+	Action:Box<dyn Action>,
+}
+
+impl Struct {
+	/// Creates a new timer service without starting its task.
+	///
+	/// # Arguments
+	///
+	/// * `Overseer` - The overseer running the task, which restarts it if
+	///   it panics.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Overseer:Arc<Overseer>) -> Self {
+		Struct {
+			Heap:Arc::new(Mutex::new(BinaryHeap::new())),
+			Wake:Arc::new(Notify::new()),
+			Down:Arc::new(AtomicBool::new(false)),
+			Held:Arc::new(AtomicUsize::new(0)),
+			Next:AtomicU64::new(0),
+			Task:OnceLock::new(),
+			Overseer,
+		}
+	}
+
+	/// Holds an action until it is due, then puts it back into its
+	/// production line.
+	///
+	/// # Arguments
+	///
+	/// * `Due` - When the action is due.
+	/// * `Production` - The production line the action was dequeued from.
+	/// * `Stamp` - The stamp the action received when it was enqueued.
+	/// * `Action` - The delayed action.
+	pub async fn Schedule(
+		&self,
+		Due:Instant,
+		Production:Arc<Production>,
+		Stamp:Stamp,
+		Action:Box<dyn Action>,
+	) {
+		self.Task.get_or_init(|| {
+			let (Heap, Wake, Down, Held, Runtime) = (
+				self.Heap.clone(),
+				self.Wake.clone(),
+				self.Down.clone(),
+				self.Held.clone(),
+				self.Overseer.Runtime(),
+			);
+
+			self.Overseer.Spawn(
+				"timer",
+				Restart::Always { Backoff:Duration::from_millis(100) },
+				move || {
+					let Runtime = Runtime.clone();
+
+					Self::Run(Heap.clone(), Wake.clone(), Down.clone(), Held.clone(), Runtime)
+				},
+			)
+		});
+
+		let Order = self.Next.fetch_add(1, Ordering::Relaxed);
+
+		let mut Heap = self.Heap.lock().await;
+
+		// Read under the lock, so the task cannot drain the heap for the last
+		// time between the check and the push
+		if self.Down.load(Ordering::SeqCst) {
+			drop(Heap);
+
+			Production.Reinject(Stamp, Action).await;
+
+			return;
+		}
+
+		Heap.push(Entry { Due, Order, Production, Stamp, Action });
+
+		Self::Count(&self.Held, Heap.len());
+
+		drop(Heap);
+
+		self.Wake.notify_one();
+	}
+
+	/// Returns the number of actions held.
+	pub fn Pending(&self) -> usize { self.Held.load(Ordering::Relaxed) }
+
+	/// Stops holding actions and puts every held action back now.
+	pub fn Shutdown(&self) {
+		self.Down.store(true, Ordering::SeqCst);
+
+		self.Wake.notify_one();
+	}
+
+	/// Puts actions back as they fall due, until shut down.
+	async fn Run(
+		Heap:Arc<Mutex<BinaryHeap<Entry>>>,
+		Wake:Arc<Notify>,
+		Down:Arc<AtomicBool>,
+		Held:Arc<AtomicUsize>,
+		Runtime:Arc<dyn Runtime>,
+	) {
+		loop {
+			let Next = Heap.lock().await.peek().map(|Entry| Entry.Due);
+
+			match Next {
+				Some(Next) => {
+					select! {
+						_ = Wake.notified() => {},
+						_ = Runtime.SleepUntil(Next) => {},
+					}
+				},
+				None => Wake.notified().await,
+			}
+
+			let (Due, Down) = {
+				let mut Heap = Heap.lock().await;
+
+				let Down = Down.load(Ordering::SeqCst);
+
+				let mut Due = Vec::new();
+
+				let Now = Runtime.Now();
+
+				while Heap.peek().is_some_and(|Entry| Down || Entry.Due <= Now) {
+					Due.extend(Heap.pop());
+				}
+
+				Self::Count(&Held, Heap.len());
+
+				(Due, Down)
+			};
+
+			for Entry in Due {
+				Entry.Production.Reinject(Entry.Stamp, Entry.Action).await;
+			}
+
+			if Down {
+				break;
+			}
+		}
+	}
+
+	/// Publishes the number of actions held.
+	fn Count(Held:&AtomicUsize, Pending:usize) {
+		Held.store(Pending, Ordering::Relaxed);
+
+		gauge!("echo_timer_pending").set(Pending as f64);
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Arc::new(Overseer::default())) }
+}
+
+#[async_trait]
+impl Component for Arc<Struct> {
+	/// Does nothing; the task starts on first use.
+	async fn Start(&mut self) -> Result<(), String> { Ok(()) }
+
+	/// Shuts the service down and waits until every held action is back in
+	/// its production line.
+	async fn Shutdown(&mut self, Deadline:Instant) -> Result<(), String> {
+		Struct::Shutdown(self);
+
+		let Runtime = self.Overseer.Runtime();
+
+		while self.Task.get().is_some_and(|Task| !Task.IsFinished()) {
+			if Runtime.Now() >= Deadline {
+				return Err(format!("{} delayed actions still held", self.Pending()));
+			}
+
+			Runtime.Sleep(Duration::from_millis(10)).await;
+		}
+
+		Ok(())
+	}
+}
+
+impl PartialEq for Entry {
+	fn eq(&self, Other:&Self) -> bool { self.cmp(Other) == CmpOrdering::Equal }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+	fn partial_cmp(&self, Other:&Self) -> Option<CmpOrdering> { Some(self.cmp(Other)) }
+}
+
+impl Ord for Entry {
+	// Reversed, so the heap pops the earliest due action first
+	fn cmp(&self, Other:&Self) -> CmpOrdering {
+		(Other.Due, Other.Order).cmp(&(self.Due, self.Order))
+	}
+}
+
+use std::{
+	cmp::Ordering as CmpOrdering,
+	collections::BinaryHeap,
+	sync::{
+		atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+		Arc,
+		OnceLock,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use metrics::gauge;
+use tokio::{
+	select,
+	sync::{Mutex, Notify},
+	time::Instant,
+};
+
+use crate::{
+	Enum::Sequence::Restart::Enum as Restart,
+	Struct::{
+		Runtime::Spawned,
+		Sequence::{
+			Overseer::Struct as Overseer,
+			Production::{Stamp::Struct as Stamp, Struct as Production},
+		},
+	},
+	Trait::{
+		Runtime::Trait as Runtime,
+		Sequence::Action::Trait as Action,
+		Supervisor::Component::Trait as Component,
+	},
+};
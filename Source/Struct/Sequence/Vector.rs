@@ -18,13 +18,53 @@ impl Struct {
 
 	/// Inserts a key-value pair into the store.
 	///
-	/// If the key already exists, the value is updated.
+	/// If the key already exists, the value is updated. The store only needs
+	/// to be shared, so an executing action's metadata can be written while
+	/// others read it.
 	///
 	/// # Arguments
 	///
 	/// * `Key` - The key as a `String`.
 	/// * `Value` - The value as a `serde_json::Value`.
-	pub fn Insert(&mut self, Key:String, Value:serde_json::Value) { self.Entry.insert(Key, Value); }
+	pub fn Insert(&self, Key:String, Value:serde_json::Value) { self.Entry.insert(Key, Value); }
+
+	/// Adds to the integer stored under a key in one step, so concurrent
+	/// increments are never lost.
+	///
+	/// A missing or non-integer value counts as zero.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the counter.
+	/// * `By` - The amount to add.
+	///
+	/// # Returns
+	///
+	/// The value of the counter after the increment.
+	pub fn Increment(&self, Key:&str, By:i64) -> i64 {
+		let mut Entry = self
+			.Entry
+			.entry(Key.to_string())
+			.or_insert(serde_json::Value::from(0));
+
+		let Counter = Entry.value().as_i64().unwrap_or(0).saturating_add(By);
+
+		*Entry.value_mut() = serde_json::Value::from(Counter);
+
+		Counter
+	}
+
+	/// Copies every key-value pair out of the store.
+	///
+	/// # Returns
+	///
+	/// The entries as a JSON object map.
+	pub fn Snapshot(&self) -> serde_json::Map<String, serde_json::Value> {
+		self.Entry
+			.iter()
+			.map(|Entry| (Entry.key().clone(), Entry.value().clone()))
+			.collect()
+	}
 
 	/// Retrieves a value from the store by its key.
 	///
@@ -0,0 +1,341 @@
+/// Detects actions whose execution does not end, such as a handler stuck on
+/// a deadlock without a timeout.
+///
+/// Sequences register every execution with `Watch` and keep the returned
+/// guard until it ends, so the table only holds executions in flight. Once
+/// a threshold is configured, a task sweeps the table every interval and
+/// flags the executions running longer: each is reported once, as a
+/// `StuckAction` event and the `echo_actions_stuck_total` counter, and its
+/// running attempt is cancelled if so configured. Cancellation is
+/// cooperative; a handler that never checks `Invocation::IsCancelled` keeps
+/// its worker slot.
+pub struct Struct {
+	/// The executions in flight, by key.
+	Running:Mutex<HashMap<u64, Entry>>,
+
+	/// The key of the next execution.
+	Next:AtomicU64,
+
+	/// When executions are flagged and what happens to them.
+	Policy:Mutex<Policy>,
+
+	/// The task sweeping the table, started on first use.
+	Task:Mutex<Option<Spawned::Struct>>,
+
+	/// The event bus on which stuck executions are reported.
+	Bus:Bus::Struct,
+
+	/// The overseer running the task.
+	Overseer:Arc<Overseer>,
+}
+
+/// An execution in the table.
+struct Entry {
+	/// What is known about the execution.
+	Execution:Execution::Struct,
+
+	/// The invocation of its running attempt.
+	Invocation:Option<Invocation>,
+}
+
+/// When executions are flagged and what happens to them.
+#[derive(Clone, Copy)]
+struct Policy {
+	/// How long an execution may run before it is flagged, or `None` to
+	/// flag none.
+	Threshold:Option<Duration>,
+
+	/// How often the table is swept.
+	Interval:Duration,
+
+	/// Whether the running attempt of a flagged execution is cancelled.
+	Cancel:bool,
+}
+
+impl Struct {
+	/// Creates a watchdog flagging executions running longer than five
+	/// minutes, swept every second, without cancelling them.
+	///
+	/// # Arguments
+	///
+	/// * `Bus` - The event bus on which stuck executions are reported.
+	/// * `Overseer` - The overseer running the sweeps.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Bus:Bus::Struct, Overseer:Arc<Overseer>) -> Self {
+		Struct {
+			Running:Mutex::new(HashMap::new()),
+			Next:AtomicU64::new(0),
+			Policy:Mutex::new(Policy {
+				Threshold:Some(Duration::from_secs(300)),
+				Interval:Duration::from_secs(1),
+				Cancel:false,
+			}),
+			Task:Mutex::new(None),
+			Bus,
+			Overseer,
+		}
+	}
+
+	/// Changes when executions are flagged and what happens to them. A
+	/// running sweep task is stopped and restarted on the next execution.
+	///
+	/// # Arguments
+	///
+	/// * `Threshold` - How long an execution may run before it is flagged, or
+	///   `None` to flag none.
+	/// * `Interval` - How often the table is swept.
+	/// * `Cancel` - Whether the running attempt of a flagged execution is
+	///   cancelled.
+	pub fn Configure(&self, Threshold:Option<Duration>, Interval:Duration, Cancel:bool) {
+		*Lock(&self.Policy) = Policy { Threshold, Interval, Cancel };
+
+		if let Some(Task) = Lock(&self.Task).take() {
+			Task.Abort();
+		}
+	}
+
+	/// Registers an execution until the returned guard is dropped.
+	///
+	/// # Arguments
+	///
+	/// * `Sequence` - The sequence number of the action.
+	/// * `Action` - The type of the action.
+	/// * `Worker` - The worker slot running the action.
+	///
+	/// # Returns
+	///
+	/// The guard keeping the execution in the table.
+	pub fn Watch(self: &Arc<Self>, Sequence:u64, Action:String, Worker:usize) -> Guard::Struct {
+		self.Start();
+
+		let Key = self.Next.fetch_add(1, Ordering::Relaxed);
+
+		Lock(&self.Running).insert(
+			Key,
+			Entry {
+				Execution:Execution::Struct {
+					Sequence,
+					Action,
+					Worker,
+					Started:Instant::now(),
+					Attempt:0,
+					Stuck:false,
+				},
+				Invocation:None,
+			},
+		);
+
+		Guard::Struct { Key, Owner:self.clone() }
+	}
+
+	/// Lists the executions in flight.
+	///
+	/// # Returns
+	///
+	/// The executions, the longest running first.
+	pub fn Running(&self) -> Vec<Execution::Struct> {
+		let mut Running = Lock(&self.Running)
+			.values()
+			.map(|Entry| Entry.Execution.clone())
+			.collect::<Vec<_>>();
+
+		Running.sort_by_key(|Execution| Execution.Started);
+
+		Running
+	}
+
+	/// Describes the executions in flight, with the deadline of their
+	/// running attempt. The table is only locked to copy it, so reading it
+	/// never waits on an execution.
+	///
+	/// # Returns
+	///
+	/// The executions, the longest running first.
+	pub fn InFlight(&self) -> Vec<InFlight::Struct> {
+		let Entry = Lock(&self.Running)
+			.values()
+			.map(|Entry| {
+				(Entry.Execution.clone(), Entry.Invocation.as_ref().and_then(Invocation::Deadline))
+			})
+			.collect::<Vec<_>>();
+
+		let (Now, Wall) = (Instant::now(), SystemTime::now());
+
+		// Instants become wall-clock times relative to now
+		let Unix = |Instant:Instant| {
+			let Time = if Instant <= Now { Wall - (Now - Instant) } else { Wall + (Instant - Now) };
+
+			Time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+		};
+
+		let mut InFlight = Entry
+			.into_iter()
+			.map(|(Execution, Deadline)| InFlight::Struct {
+				Sequence:Execution.Sequence,
+				Action:Execution.Action,
+				Worker:Execution.Worker,
+				Started:Unix(Execution.Started),
+				Attempt:Execution.Attempt,
+				ElapsedMs:(Now - Execution.Started).as_millis() as u64,
+				Deadline:Deadline.map(Unix),
+				Stuck:Execution.Stuck,
+			})
+			.collect::<Vec<_>>();
+
+		InFlight.sort_by_key(|InFlight| std::cmp::Reverse(InFlight.ElapsedMs));
+
+		InFlight
+	}
+
+	/// Flags the executions running longer than the threshold that were not
+	/// flagged yet.
+	///
+	/// # Returns
+	///
+	/// The executions flagged by this sweep.
+	pub fn Sweep(&self) -> Vec<Execution::Struct> {
+		let Policy = *Lock(&self.Policy);
+
+		let Some(Threshold) = Policy.Threshold else {
+			return Vec::new();
+		};
+
+		let mut Flagged = Vec::new();
+
+		for Entry in Lock(&self.Running).values_mut() {
+			if Entry.Execution.Stuck || Entry.Execution.Started.elapsed() < Threshold {
+				continue;
+			}
+
+			Entry.Execution.Stuck = true;
+
+			if Policy.Cancel {
+				if let Some(Invocation) = &Entry.Invocation {
+					Invocation.Cancel();
+				}
+			}
+
+			Flagged.push(Entry.Execution.clone());
+		}
+
+		for Execution in &Flagged {
+			let Elapsed = Execution.Started.elapsed();
+
+			warn!(
+				"Action {} ({}) on worker {} is stuck: running for {:?} in attempt {}",
+				Execution.Sequence, Execution.Action, Execution.Worker, Elapsed, Execution.Attempt
+			);
+
+			counter!("echo_actions_stuck_total").increment(1);
+
+			self.Bus.Emit(|| Event::StuckAction {
+				Sequence:Execution.Sequence,
+				Action:Execution.Action.clone(),
+				Worker:Execution.Worker,
+				ElapsedMs:Elapsed.as_millis() as u64,
+				Attempt:Execution.Attempt,
+				Cancelled:Policy.Cancel,
+			});
+		}
+
+		Flagged
+	}
+
+	/// Records that another attempt of an execution began.
+	pub(crate) fn Attempt(&self, Key:u64, Attempt:u32, Invocation:&Invocation) {
+		if let Some(Entry) = Lock(&self.Running).get_mut(&Key) {
+			Entry.Execution.Attempt = Attempt;
+
+			Entry.Invocation = Some(Invocation.clone());
+		}
+	}
+
+	/// Removes an execution from the table.
+	pub(crate) fn Forget(&self, Key:u64) { Lock(&self.Running).remove(&Key); }
+
+	/// Starts the sweep task if a threshold is configured and it does not
+	/// run already.
+	fn Start(self: &Arc<Self>) {
+		let Policy = *Lock(&self.Policy);
+
+		if Policy.Threshold.is_none() {
+			return;
+		}
+
+		let mut Task = Lock(&self.Task);
+
+		if Task.is_some() {
+			return;
+		}
+
+		let (Owner, Runtime) = (Arc::downgrade(self), self.Overseer.Runtime());
+
+		*Task = Some(self.Overseer.Spawn(
+			"watchdog",
+			Restart::Always { Backoff:Duration::from_secs(1) },
+			move || {
+				let (Owner, Runtime) = (Owner.clone(), Runtime.clone());
+
+				async move {
+					loop {
+						Runtime.Sleep(Policy.Interval).await;
+
+						let Some(Watchdog) = Owner.upgrade() else {
+							break;
+						};
+
+						Watchdog.Sweep();
+					}
+				}
+			},
+		));
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Bus::Struct::default(), Arc::new(Overseer::default())) }
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		if let Some(Task) = Lock(&self.Task).take() {
+			Task.Abort();
+		}
+	}
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+	},
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::warn;
+use metrics::counter;
+use tokio::time::Instant;
+
+use crate::{
+	Enum::{Event::Enum as Event, Sequence::Restart::Enum as Restart},
+	Struct::{
+		Event::Bus,
+		Runtime::Spawned,
+		Sequence::{Invocation::Struct as Invocation, Overseer::Struct as Overseer},
+	},
+};
+
+pub mod Execution;
+pub mod Guard;
+pub mod InFlight;
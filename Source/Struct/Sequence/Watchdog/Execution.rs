@@ -0,0 +1,25 @@
+/// An execution in flight, as tracked by `Watchdog`.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The sequence number of the action in its production line.
+	pub Sequence:u64,
+
+	/// The type of the action, its `Action` metadata.
+	pub Action:String,
+
+	/// The worker slot running the action: how many actions of its queue
+	/// were already running when it started.
+	pub Worker:usize,
+
+	/// When the execution started.
+	pub Started:Instant,
+
+	/// The attempt running, starting at 1; 0 until the first attempt
+	/// begins.
+	pub Attempt:u32,
+
+	/// Whether the execution was flagged as stuck.
+	pub Stuck:bool,
+}
+
+use tokio::time::Instant;
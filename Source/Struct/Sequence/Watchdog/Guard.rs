@@ -0,0 +1,32 @@
+/// Keeps an execution in the table of a `Watchdog` until dropped.
+///
+/// Returned by `Watchdog::Watch`; dropping it, however the execution ends,
+/// removes the execution from the table.
+pub struct Struct {
+	/// The key of the execution in the table.
+	pub(super) Key:u64,
+
+	/// The watchdog tracking the execution.
+	pub(super) Owner:Arc<Watchdog>,
+}
+
+impl Struct {
+	/// Records that another attempt of the execution began.
+	///
+	/// # Arguments
+	///
+	/// * `Attempt` - The number of the attempt, starting at 1.
+	/// * `Invocation` - The invocation of the attempt, cancelled if the
+	///   watchdog is configured to cancel stuck executions.
+	pub fn Attempt(&self, Attempt:u32, Invocation:&Invocation) {
+		self.Owner.Attempt(self.Key, Attempt, Invocation);
+	}
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) { self.Owner.Forget(self.Key); }
+}
+
+use std::sync::Arc;
+
+use crate::Struct::Sequence::{Invocation::Struct as Invocation, Watchdog::Struct as Watchdog};
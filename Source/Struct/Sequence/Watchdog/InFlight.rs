@@ -0,0 +1,34 @@
+/// An action executing right now, as listed by `Sequence::InFlight`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "InFlight"))]
+pub struct Struct {
+	/// The sequence number of the action in its production line.
+	pub Sequence:u64,
+
+	/// The type of the action, its `Action` metadata.
+	pub Action:String,
+
+	/// The worker slot running the action.
+	pub Worker:usize,
+
+	/// When the execution started, in Unix milliseconds.
+	pub Started:u64,
+
+	/// The attempt running, starting at 1; 0 until the first attempt
+	/// begins.
+	pub Attempt:u32,
+
+	/// How long the execution has run, in milliseconds.
+	pub ElapsedMs:u64,
+
+	/// When the running attempt must finish, in Unix milliseconds, if it
+	/// has a deadline.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Deadline:Option<u64>,
+
+	/// Whether the watchdog flagged the execution as stuck.
+	#[serde(default)]
+	pub Stuck:bool,
+}
+
+use serde::{Deserialize, Serialize};
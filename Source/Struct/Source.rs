@@ -0,0 +1,10 @@
+pub mod Channel;
+
+pub mod Handle;
+
+pub mod Template;
+
+pub mod Ticker;
+
+#[cfg(feature = "Watch")]
+pub mod Watch;
@@ -0,0 +1,67 @@
+/// A source forwarding actions from a tokio channel.
+///
+/// Applications that already produce work on an `mpsc` channel attach the
+/// receiver instead of writing a forwarding loop. Items are delivered in
+/// order, one at a time, so a bounded channel applies backpressure to its
+/// senders. The source ends once every sender is dropped and the channel is
+/// drained, or when its handle is shut down.
+pub struct Struct;
+
+impl Struct {
+	/// Forwards actions from a receiver to a target.
+	///
+	/// # Arguments
+	///
+	/// * `Receiver` - The receiving half of the channel.
+	/// * `Target` - A single `Production`, or a `Life` whose Karma queues are
+	///   selected by each action's `Queue` metadata.
+	///
+	/// # Returns
+	///
+	/// A `Handle` stopping the source.
+	pub fn Attach(Receiver:Receiver<Box<dyn Action>>, Target:impl Into<Target>) -> Handle::Struct {
+		Self::AttachTyped(Receiver, Target)
+	}
+
+	/// Forwards items convertible into actions from a receiver to a target.
+	///
+	/// # Arguments
+	///
+	/// * `Receiver` - The receiving half of the channel.
+	/// * `Target` - A single `Production`, or a `Life` whose Karma queues are
+	///   selected by each action's `Queue` metadata.
+	///
+	/// # Returns
+	///
+	/// A `Handle` stopping the source.
+	pub fn AttachTyped<T>(mut Receiver:Receiver<T>, Target:impl Into<Target>) -> Handle::Struct
+	where
+		T: Into<Box<dyn Action>> + Send + 'static, {
+		let Target = Target.into();
+
+		Handle::Struct::SpawnOn(Target.Runtime(), move |mut Stop| async move {
+			loop {
+				select! {
+					_ = Stopped(&mut Stop) => break,
+					Item = Receiver.recv() => match Item {
+						Some(Item) => {
+							if let Err(_Error) = Target.Deliver(Item.into()).await {
+								Report::Fn("channel", &_Error.to_string());
+							}
+						},
+						None => break,
+					},
+				}
+			}
+		})
+	}
+}
+
+use tokio::{select, sync::mpsc::Receiver};
+
+use crate::{
+	Enum::Source::Target::Enum as Target,
+	Fn::Report,
+	Struct::Source::Handle::{self, Stopped},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,119 @@
+/// A handle to a running source task.
+///
+/// Sources run as background tasks feeding actions into production lines.
+/// The handle stops them promptly through a shutdown token and waits for the
+/// task to finish.
+pub struct Struct {
+	/// The shutdown token observed by the source task.
+	Stop:watch::Sender<bool>,
+
+	/// The source task itself.
+	Task:Spawned::Struct,
+
+	/// The runtime the task was spawned on, which also times its shutdown.
+	Runtime:Arc<dyn Runtime>,
+}
+
+impl Struct {
+	/// Spawns a source task on tokio and returns its handle.
+	///
+	/// # Arguments
+	///
+	/// * `Source` - Builds the task from the shutdown receiver; the task should
+	///   return once the receiver observes `true` or is closed.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` controlling the spawned task.
+	pub fn Spawn<F, Fut>(Source:F) -> Self
+	where
+		F: FnOnce(watch::Receiver<bool>) -> Fut,
+		Fut: Future<Output = ()> + Send + 'static, {
+		Self::SpawnOn(Arc::new(Tokio::Struct), Source)
+	}
+
+	/// Spawns a source task on a runtime, such as that of the `Life` it
+	/// feeds, and returns its handle.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime to spawn on.
+	/// * `Source` - Builds the task from the shutdown receiver, as for
+	///   `Spawn`.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` controlling the spawned task.
+	pub fn SpawnOn<F, Fut>(Runtime:Arc<dyn Runtime>, Source:F) -> Self
+	where
+		F: FnOnce(watch::Receiver<bool>) -> Fut,
+		Fut: Future<Output = ()> + Send + 'static, {
+		let (Stop, Receiver) = watch::channel(false);
+
+		let Task = Runtime.Spawn(Box::pin(Source(Receiver)));
+
+		Struct { Stop, Task, Runtime }
+	}
+
+	/// Returns whether the source task has finished.
+	pub fn IsFinished(&self) -> bool { self.Task.IsFinished() }
+
+	/// Signals the source to stop and waits for its task to finish.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the task finished, or how it ended otherwise.
+	pub async fn Shutdown(self) -> Result<(), Exit> {
+		let _ = self.Stop.send(true);
+
+		self.Task.await
+	}
+
+	/// Waits for the source task to finish on its own, for sources that end
+	/// when their input ends.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the task finished, or how it ended otherwise.
+	pub async fn Join(self) -> Result<(), Exit> { self.Task.await }
+}
+
+#[async_trait]
+impl Component for Struct {
+	/// Does nothing; the source runs from when it was spawned.
+	async fn Start(&mut self) -> Result<(), String> { Ok(()) }
+
+	/// Signals the source to stop and waits for its task until the deadline,
+	/// aborting it past that.
+	async fn Shutdown(&mut self, Deadline:Instant) -> Result<(), String> {
+		let _ = self.Stop.send(true);
+
+		match self.Runtime.TimeoutAt(Deadline, &mut self.Task).await {
+			Some(Finished) => Finished.map_err(|_Error| _Error.to_string()),
+			None => {
+				self.Task.Abort();
+
+				Err("Source did not stop before its deadline".to_string())
+			},
+		}
+	}
+}
+
+/// Waits until a shutdown receiver observes `true` or its sender is dropped.
+///
+/// # Arguments
+///
+/// * `Stop` - The shutdown receiver handed to the source task.
+pub async fn Stopped(Stop:&mut watch::Receiver<bool>) { let _ = Stop.wait_for(|Stop| *Stop).await; }
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::Future;
+use tokio::{sync::watch, time::Instant};
+
+use crate::{
+	Enum::Runtime::Exit::Enum as Exit,
+	Struct::Runtime::{Spawned, Tokio},
+	Trait::{Runtime::Trait as Runtime, Supervisor::Component::Trait as Component},
+};
@@ -0,0 +1,100 @@
+/// A template from which sources instantiate actions.
+///
+/// The template names the action, its content and extra metadata. String
+/// values anywhere in the content may contain `{Key}` placeholders, which are
+/// replaced by the values a source supplies when instantiating it (for
+/// example `{Path}` for the file that triggered a watcher).
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The name of the action.
+	pub Action:String,
+
+	/// The content of the action, possibly containing placeholders.
+	pub Content:Value,
+
+	/// Additional metadata stamped onto every instantiated action.
+	pub Metadata:Map<String, Value>,
+
+	/// The plan used to execute instantiated actions.
+	pub Plan:Arc<Formality>,
+}
+
+impl Struct {
+	/// Creates a new template.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The name of the action.
+	/// * `Content` - The content of the action, possibly with placeholders.
+	/// * `Plan` - The plan used to execute instantiated actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` with no additional metadata.
+	pub fn New(Action:&str, Content:Value, Plan:Arc<Formality>) -> Self {
+		Struct { Action:Action.to_string(), Content, Metadata:Map::new(), Plan }
+	}
+
+	/// Adds metadata stamped onto every instantiated action.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key for the metadata.
+	/// * `Value` - The value for the metadata.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithMetadata(mut self, Key:&str, Value:Value) -> Self {
+		self.Metadata.insert(Key.to_string(), Value);
+
+		self
+	}
+
+	/// Instantiates an action, replacing placeholders in its content.
+	///
+	/// # Arguments
+	///
+	/// * `Substitution` - The `(Key, Value)` pairs replacing `{Key}`.
+	///
+	/// # Returns
+	///
+	/// A new action ready to be enqueued.
+	pub fn Instantiate(&self, Substitution:&[(&str, &str)]) -> Action<Value> {
+		let mut Action =
+			Action::New(&self.Action, Replace(&self.Content, Substitution), self.Plan.clone());
+
+		for (Key, Value) in &self.Metadata {
+			Action = Action.WithMetadata(Key, Value.clone());
+		}
+
+		Action
+	}
+}
+
+/// Replaces placeholders in every string of a JSON value.
+fn Replace(Value:&Value, Substitution:&[(&str, &str)]) -> Value {
+	match Value {
+		Value::String(Text) => {
+			Value::String(Substitution.iter().fold(Text.clone(), |Text, (Key, Value)| {
+				Text.replace(&format!("{{{}}}", Key), Value)
+			}))
+		},
+		Value::Array(Item) => {
+			Value::Array(Item.iter().map(|Item| Replace(Item, Substitution)).collect())
+		},
+		Value::Object(Entry) => Value::Object(
+			Entry
+				.iter()
+				.map(|(Key, Item)| (Key.clone(), Replace(Item, Substitution)))
+				.collect(),
+		),
+		Other => Other.clone(),
+	}
+}
+
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::Struct::Sequence::{Action::Struct as Action, Plan::Formality::Struct as Formality};
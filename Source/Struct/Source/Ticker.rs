@@ -0,0 +1,168 @@
+/// A source that enqueues an action at a fixed interval.
+///
+/// Every tick builds an action from the factory and enqueues it into the
+/// named Karma queue. The first tick fires one interval after `Start`; ticks
+/// are numbered from 1.
+#[derive(Clone)]
+pub struct Struct {
+	/// The time between ticks.
+	pub Interval:Duration,
+
+	/// Builds the action for a tick from its number.
+	pub Factory:Factory,
+
+	/// The name of the Karma queue receiving the actions.
+	pub Queue:String,
+
+	/// Whether a tick is skipped while the previous tick's action is still
+	/// queued or running.
+	pub SkipIfPrevPending:bool,
+}
+
+impl Struct {
+	/// Creates a ticker instantiating a template on every tick.
+	///
+	/// The tick number is stamped into the `TickNumber` metadata key and is
+	/// available to the template's content as `{TickNumber}`.
+	///
+	/// # Arguments
+	///
+	/// * `Interval` - The time between ticks.
+	/// * `Template` - The action emitted on every tick.
+	/// * `Queue` - The name of the Karma queue receiving the actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Interval:Duration, Template:Template::Struct, Queue:&str) -> Self {
+		Self::WithFactory(
+			Interval,
+			move |Tick| {
+				Box::new(
+					Template
+						.Instantiate(&[("TickNumber", &Tick.to_string())])
+						.WithMetadata("TickNumber", Tick.into()),
+				) as Box<dyn Action>
+			},
+			Queue,
+		)
+	}
+
+	/// Creates a ticker building its actions with a factory.
+	///
+	/// # Arguments
+	///
+	/// * `Interval` - The time between ticks.
+	/// * `Factory` - Builds the action for a tick from its number.
+	/// * `Queue` - The name of the Karma queue receiving the actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn WithFactory<F>(Interval:Duration, Factory:F, Queue:&str) -> Self
+	where
+		F: Fn(u64) -> Box<dyn Action> + Send + Sync + 'static, {
+		Struct {
+			Interval,
+			Factory:Arc::new(Factory),
+			Queue:Queue.to_string(),
+			SkipIfPrevPending:false,
+		}
+	}
+
+	/// Sets whether ticks are skipped while the previous action is pending.
+	///
+	/// # Arguments
+	///
+	/// * `SkipIfPrevPending` - `true` to skip such ticks.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithSkipIfPrevPending(mut self, SkipIfPrevPending:bool) -> Self {
+		self.SkipIfPrevPending = SkipIfPrevPending;
+
+		self
+	}
+
+	/// Starts the ticker as a background task.
+	///
+	/// # Arguments
+	///
+	/// * `Life` - The lifecycle whose Karma queue receives the actions.
+	///
+	/// # Returns
+	///
+	/// A `Handle` stopping the ticker.
+	pub fn Start(self, Life:Life) -> Handle::Struct {
+		Handle::Struct::SpawnOn(Life.Runtime(), move |mut Stop| async move {
+			let mut Tick = interval_at(Instant::now() + self.Interval, self.Interval);
+
+			Tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+			let mut Number = 0;
+
+			let mut Previous:Option<Arc<()>> = None;
+
+			loop {
+				select! {
+					_ = Stopped(&mut Stop) => break,
+					_ = Tick.tick() => {
+						Number += 1;
+
+						if self.SkipIfPrevPending && Previous.as_ref().is_some_and(Tracked::Struct::Pending) {
+							debug!("Ticker skipped tick {} of {}: previous action pending", Number, self.Queue);
+
+							continue;
+						}
+
+						let Production = match Life.Karma.get(&self.Queue) {
+							Some(Production) => Production.clone(),
+							None => {
+								Report::Fn("ticker", &format!("Unknown queue {}", self.Queue));
+
+								continue;
+							},
+						};
+
+						let Token = Arc::new(());
+
+						if let Err(_Error) = Production
+							.Enqueue(Box::new(Tracked::Struct::New((self.Factory)(Number), Token.clone())))
+							.await
+						{
+							Report::Fn("ticker", &format!("Queue {} refused tick {}: {}", self.Queue, Number, _Error));
+
+							continue;
+						}
+
+						Previous = Some(Token);
+					},
+				}
+			}
+		})
+	}
+}
+
+use std::{sync::Arc, time::Duration};
+
+use log::debug;
+use tokio::{
+	select,
+	time::{interval_at, Instant, MissedTickBehavior},
+};
+
+use crate::{
+	Fn::Report,
+	Struct::{
+		Sequence::Life::Struct as Life,
+		Source::{
+			Handle::{self, Stopped},
+			Template,
+		},
+	},
+	Trait::Sequence::Action::Trait as Action,
+	Type::Source::Factory::Type as Factory,
+};
+
+pub mod Tracked;
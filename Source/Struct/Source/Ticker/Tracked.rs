@@ -0,0 +1,74 @@
+/// Wraps an action so its owner can tell whether it is still pending.
+///
+/// The wrapper holds a token shared with the owner. While the action is
+/// queued or running the wrapper is alive; once the sequence drops it, the
+/// token's strong count falls back to the owner's reference.
+pub struct Struct {
+	/// The wrapped action.
+	Action:Box<dyn Action>,
+
+	/// The token shared with the owner.
+	Token:Arc<()>,
+}
+
+impl Struct {
+	/// Wraps an action with a token.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to wrap.
+	/// * `Token` - The token shared with the owner.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Action:Box<dyn Action>, Token:Arc<()>) -> Self { Struct { Action, Token } }
+
+	/// Returns whether any wrapper holding the token is still alive.
+	///
+	/// # Arguments
+	///
+	/// * `Token` - The owner's reference to the token.
+	pub fn Pending(Token:&Arc<()>) -> bool { Arc::strong_count(Token) > 1 }
+}
+
+#[async_trait]
+impl Action for Struct {
+	async fn Execute(&self, Context:&Life) -> Result<(), Error> {
+		self.Action.Execute(Context).await
+	}
+
+	fn Duplicate(&self) -> Result<Box<dyn Action>, Error> {
+		// Copies share the token, so a retried copy still counts as pending
+		Ok(Box::new(Struct::New(self.Action.Duplicate()?, self.Token.clone())))
+	}
+
+	async fn Metadata(&self, Key:&str) -> Option<serde_json::Value> {
+		self.Action.Metadata(Key).await
+	}
+
+	fn Annotate(&self, Key:&str, Value:serde_json::Value) { self.Action.Annotate(Key, Value) }
+
+	async fn ArgumentSize(&self) -> u64 { self.Action.ArgumentSize().await }
+
+	fn Partial(&self) -> Option<Writer> { self.Action.Partial() }
+
+	async fn Stage(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		self.Action.Stage(Context, Phase).await
+	}
+
+	fn Cancellation(&self) -> Option<Cancellation> { self.Action.Cancellation() }
+
+	fn Fail(&self, Error:&Error) { self.Action.Fail(Error) }
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::{Action::Error::Enum as Error, Phase::Enum as Phase},
+	Struct::Sequence::{Cancellation::Struct as Cancellation, Life::Struct as Life},
+	Trait::Sequence::Action::Trait as Action,
+	Type::Sequence::Action::Writer::Type as Writer,
+};
@@ -0,0 +1,253 @@
+/// A source that enqueues actions when files appear or change.
+///
+/// The watcher polls its locations at a fixed interval and compares each
+/// file's modification time and size with the previous scan. A file whose
+/// signature changed is emitted once it has stayed unchanged for the
+/// location's debounce window, so a burst of writes yields one action.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The watched locations.
+	pub Entry:Vec<Entry::Struct>,
+
+	/// How often the locations are scanned.
+	pub Interval:Duration,
+
+	/// Whether files present at startup are emitted as if just created.
+	pub Scan:bool,
+}
+
+impl Struct {
+	/// Creates a new watcher with no locations, polling every 50 milliseconds
+	/// and without a startup scan.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct { Entry:Vec::new(), Interval:Duration::from_millis(50), Scan:false }
+	}
+
+	/// Adds a watched location.
+	///
+	/// # Arguments
+	///
+	/// * `Entry` - The location to watch.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithEntry(mut self, Entry:Entry::Struct) -> Self {
+		self.Entry.push(Entry);
+
+		self
+	}
+
+	/// Sets the polling interval.
+	///
+	/// # Arguments
+	///
+	/// * `Interval` - How often the locations are scanned.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithInterval(mut self, Interval:Duration) -> Self {
+		self.Interval = Interval;
+
+		self
+	}
+
+	/// Sets whether files present at startup are emitted.
+	///
+	/// # Arguments
+	///
+	/// * `Scan` - `true` to emit files that existed before the watcher started.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithScan(mut self, Scan:bool) -> Self {
+		self.Scan = Scan;
+
+		self
+	}
+
+	/// Starts the watcher as a background task.
+	///
+	/// # Arguments
+	///
+	/// * `Life` - The lifecycle whose Karma queues receive emitted actions.
+	///
+	/// # Returns
+	///
+	/// A `Handle` stopping the watcher.
+	pub fn Start(self, Life:Life) -> Handle::Struct {
+		let Runtime = Life.Runtime();
+
+		Handle::Struct::SpawnOn(Runtime.clone(), move |mut Stop| async move {
+			let mut Seen = Vec::with_capacity(self.Entry.len());
+
+			let mut Pending = vec![HashMap::<PathBuf, Instant>::new(); self.Entry.len()];
+
+			for (Index, Entry) in self.Entry.iter().enumerate() {
+				let Current = Scan(Entry).await;
+
+				if self.Scan {
+					let Now = Runtime.Now();
+
+					Pending[Index].extend(Current.keys().map(|Path| (Path.clone(), Now)));
+				}
+
+				Seen.push(Current);
+			}
+
+			let mut Tick = Interval::Struct::New(Runtime.clone(), self.Interval);
+
+			loop {
+				select! {
+					_ = Stopped(&mut Stop) => break,
+					_ = Tick.Tick() => {
+						for (Index, Entry) in self.Entry.iter().enumerate() {
+							let Current = Scan(Entry).await;
+
+							let Now = Runtime.Now();
+
+							for (Path, Signature) in &Current {
+								if Seen[Index].get(Path) != Some(Signature) {
+									Pending[Index].insert(Path.clone(), Now);
+								}
+							}
+
+							Pending[Index].retain(|Path, _| Current.contains_key(Path));
+
+							Seen[Index] = Current;
+
+							let Ready = Pending[Index]
+								.iter()
+								.filter(|(_, Changed)| Now.duration_since(**Changed) >= Entry.Debounce)
+								.map(|(Path, _)| Path.clone())
+								.collect::<Vec<_>>();
+
+							for Path in Ready {
+								Pending[Index].remove(&Path);
+
+								Emit(&Life, Entry, &Path).await;
+							}
+						}
+					},
+				}
+			}
+		})
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+/// The signature compared between scans: modification time and size.
+type Signature = (Option<SystemTime>, u64);
+
+/// Lists the files under a location that match its glob.
+async fn Scan(Entry:&Entry::Struct) -> HashMap<PathBuf, Signature> {
+	let mut Found = HashMap::new();
+
+	let mut Directory = vec![Entry.Path.clone()];
+
+	while let Some(Current) = Directory.pop() {
+		let mut Reader = match fs::read_dir(&Current).await {
+			Ok(Reader) => Reader,
+			Err(_Error) => {
+				Report::Fn("watch", &format!("Cannot read {}: {}", Current.display(), _Error));
+
+				continue;
+			},
+		};
+
+		loop {
+			let Item = match Reader.next_entry().await {
+				Ok(Some(Item)) => Item,
+				Ok(None) => break,
+				Err(_Error) => {
+					Report::Fn("watch", &format!("Cannot read {}: {}", Current.display(), _Error));
+
+					break;
+				},
+			};
+
+			let Path = Item.path();
+
+			let Metadata = match Item.metadata().await {
+				Ok(Metadata) => Metadata,
+				// The file vanished between listing and inspection.
+				Err(_) => continue,
+			};
+
+			if Metadata.is_dir() {
+				Directory.push(Path);
+
+				continue;
+			}
+
+			let Relative = Path
+				.strip_prefix(&Entry.Path)
+				.unwrap_or(&Path)
+				.components()
+				.map(|Component| Component.as_os_str().to_string_lossy())
+				.collect::<Vec<_>>()
+				.join("/");
+
+			if Glob::Fn(&Entry.Glob, &Relative) {
+				Found.insert(Path, (Metadata.modified().ok(), Metadata.len()));
+			}
+		}
+	}
+
+	Found
+}
+
+/// Instantiates a location's template for a file and enqueues it.
+async fn Emit(Life:&Life, Entry:&Entry::Struct, Path:&Path) {
+	let Production = match Life.Karma.get(&Entry.Queue) {
+		Some(Production) => Production.clone(),
+		None => {
+			return Report::Fn(
+				"watch",
+				&format!("Unknown queue {} for {}", Entry.Queue, Path.display()),
+			);
+		},
+	};
+
+	if let Err(_Error) = Production
+		.Enqueue(Box::new(Entry.Template.Instantiate(&[("Path", &Path.to_string_lossy())])))
+		.await
+	{
+		Report::Fn(
+			"watch",
+			&format!("Queue {} refused {}: {}", Entry.Queue, Path.display(), _Error),
+		);
+	}
+}
+
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	time::{Duration, SystemTime},
+};
+
+use tokio::{
+	fs,
+	select,
+	time::Instant,
+};
+
+use crate::{
+	Fn::{Glob, Report},
+	Struct::{
+		Runtime::Interval,
+		Sequence::Life::Struct as Life,
+		Source::Handle::{self, Stopped},
+	},
+};
+
+pub mod Entry;
@@ -0,0 +1,64 @@
+/// One watched location of a `Watch` source.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// The directory to watch, recursively.
+	pub Path:PathBuf,
+
+	/// The glob matched against paths relative to `Path`, using `/` as the
+	/// separator.
+	pub Glob:String,
+
+	/// How long a file must stay unchanged before its action is emitted.
+	/// Changes within this window are coalesced into one action.
+	pub Debounce:Duration,
+
+	/// The action emitted for a matching file; `{Path}` is replaced by the
+	/// full path of the file.
+	pub Template:Template::Struct,
+
+	/// The name of the Karma queue receiving emitted actions.
+	pub Queue:String,
+}
+
+impl Struct {
+	/// Creates a new watched location with a debounce of 100 milliseconds.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The directory to watch.
+	/// * `Glob` - The glob selecting files within the directory.
+	/// * `Template` - The action emitted for a matching file.
+	/// * `Queue` - The name of the Karma queue receiving emitted actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Path:impl Into<PathBuf>, Glob:&str, Template:Template::Struct, Queue:&str) -> Self {
+		Struct {
+			Path:Path.into(),
+			Glob:Glob.to_string(),
+			Debounce:Duration::from_millis(100),
+			Template,
+			Queue:Queue.to_string(),
+		}
+	}
+
+	/// Sets the debounce window.
+	///
+	/// # Arguments
+	///
+	/// * `Debounce` - How long a file must stay unchanged before emission.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithDebounce(mut self, Debounce:Duration) -> Self {
+		self.Debounce = Debounce;
+
+		self
+	}
+}
+
+use std::{path::PathBuf, time::Duration};
+
+use crate::Struct::Source::Template;
@@ -0,0 +1,11 @@
+pub mod Activity;
+
+pub mod Cost;
+
+pub mod Queue;
+
+pub mod Registry;
+
+pub mod Snapshot;
+
+pub mod Summary;
@@ -0,0 +1,73 @@
+/// Lock-free counters of the work a component performs.
+///
+/// Sequences count the actions they execute and transports the connections
+/// they serve; both register their counters with the `Registry` of `Life`.
+#[derive(Debug, Default)]
+pub struct Struct {
+	/// The number of units currently in progress.
+	Active:AtomicU64,
+
+	/// The number of units finished.
+	Total:AtomicU64,
+
+	/// The number of finished units that failed.
+	Failed:AtomicU64,
+
+	/// When a unit last succeeded, in Unix milliseconds, or 0 if never.
+	Last:AtomicU64,
+}
+
+impl Struct {
+	/// Creates new counters, all zero.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Records that a unit of work started.
+	pub fn Begin(&self) { self.Active.fetch_add(1, Ordering::Relaxed); }
+
+	/// Records that a unit of work finished.
+	///
+	/// # Arguments
+	///
+	/// * `Success` - Whether the unit succeeded.
+	pub fn End(&self, Success:bool) {
+		self.Total.fetch_add(1, Ordering::Relaxed);
+
+		if Success {
+			let Now = SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_millis() as u64;
+
+			self.Last.store(Now, Ordering::Relaxed);
+		} else {
+			self.Failed.fetch_add(1, Ordering::Relaxed);
+		}
+
+		self.Active.fetch_sub(1, Ordering::Relaxed);
+	}
+
+	/// Reads the counters.
+	///
+	/// # Returns
+	///
+	/// A `Count` with the current values.
+	pub fn Count(&self) -> Count::Struct {
+		Count::Struct {
+			Active:self.Active.load(Ordering::Relaxed),
+			Total:self.Total.load(Ordering::Relaxed),
+			Failed:self.Failed.load(Ordering::Relaxed),
+			Last:Some(self.Last.load(Ordering::Relaxed)).filter(|Last| *Last > 0),
+		}
+	}
+}
+
+use std::{
+	sync::atomic::{AtomicU64, Ordering},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+pub mod Count;
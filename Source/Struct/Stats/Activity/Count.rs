@@ -0,0 +1,31 @@
+/// The values of an `Activity` at one point in time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Count"))]
+pub struct Struct {
+	/// The number of units currently in progress.
+	pub Active:u64,
+
+	/// The number of units finished.
+	pub Total:u64,
+
+	/// The number of finished units that failed.
+	pub Failed:u64,
+
+	/// When a unit last succeeded, in Unix milliseconds, or `None` if never.
+	pub Last:Option<u64>,
+}
+
+impl std::ops::Add for Struct {
+	type Output = Self;
+
+	fn add(self, Other:Self) -> Self {
+		Struct {
+			Active:self.Active + Other.Active,
+			Total:self.Total + Other.Total,
+			Failed:self.Failed + Other.Failed,
+			Last:self.Last.max(Other.Last),
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,257 @@
+/// Accounts the cost of the actions executed in a `Life` by action type,
+/// for capacity planning.
+///
+/// Sequences record every executed action with `Record`; the totals are
+/// maintained incrementally and read with `Report`. At most `Limit` types
+/// are kept apart: when another type arrives, the type with the least
+/// cumulative execution time is folded into the `Other` bucket, so memory
+/// stays bounded however many types are seen.
+///
+/// When configured with a dump path, a task appends the report to that
+/// storage log every interval, starting on the first recorded action.
+pub struct Struct {
+	/// The totals, by type, and the folded ones.
+	Tally:Mutex<Tally>,
+
+	/// How many types are kept apart.
+	Limit:AtomicUsize,
+
+	/// Where and how often the report is dumped, if at all.
+	Dump:Mutex<Option<(PathBuf, Duration)>>,
+
+	/// The task dumping the report, started on first use.
+	Task:Mutex<Option<Spawned::Struct>>,
+
+	/// The overseer running the task.
+	Overseer:Arc<Overseer>,
+}
+
+/// The totals behind a report.
+#[derive(Default)]
+struct Tally {
+	/// The totals of the types kept apart.
+	Type:HashMap<String, Total::Struct>,
+
+	/// The totals of the folded types.
+	Other:Total::Struct,
+
+	/// How many times a type was folded.
+	Folded:u64,
+}
+
+impl Struct {
+	/// Creates an empty account that does not dump its report.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How many types are kept apart.
+	/// * `Overseer` - The overseer running the dumps.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Limit:usize, Overseer:Arc<Overseer>) -> Self {
+		Struct {
+			Tally:Mutex::new(Tally::default()),
+			Limit:AtomicUsize::new(Limit),
+			Dump:Mutex::new(None),
+			Task:Mutex::new(None),
+			Overseer,
+		}
+	}
+
+	/// Changes how many types are kept apart and where the report is
+	/// dumped. Types beyond a lowered limit are folded right away; a running
+	/// dump task is stopped and restarted on the next recorded action.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How many types are kept apart.
+	/// * `Dump` - The storage log the report is appended to and the interval
+	///   between dumps, or `None` to stop dumping.
+	pub fn Configure(&self, Limit:usize, Dump:Option<(PathBuf, Duration)>) {
+		self.Limit.store(Limit, Ordering::Relaxed);
+
+		{
+			let mut Tally = Lock(&self.Tally);
+
+			while Tally.Type.len() > Limit {
+				Tally.Fold();
+			}
+		}
+
+		*Lock(&self.Dump) = Dump;
+
+		if let Some(Task) = Lock(&self.Task).take() {
+			Task.Abort();
+		}
+	}
+
+	/// Adds the cost of one executed action to the totals of its type.
+	///
+	/// # Arguments
+	///
+	/// * `Sample` - What the action cost.
+	pub fn Record(self: &Arc<Self>, Sample:&Sample::Struct) {
+		self.Start();
+
+		let Limit = self.Limit.load(Ordering::Relaxed);
+
+		let mut Tally = Lock(&self.Tally);
+
+		if let Some(Total) = Tally.Type.get_mut(&Sample.Type) {
+			Total.Record(Sample);
+
+			return;
+		}
+
+		if Limit == 0 {
+			Tally.Other.Record(Sample);
+
+			Tally.Folded += 1;
+
+			return;
+		}
+
+		while Tally.Type.len() >= Limit {
+			Tally.Fold();
+		}
+
+		Tally.Type.entry(Sample.Type.clone()).or_default().Record(Sample);
+	}
+
+	/// Reads the totals.
+	///
+	/// # Returns
+	///
+	/// A serializable `Report` of the cost by type.
+	pub fn Report(&self) -> Report::Struct {
+		let Tally = Lock(&self.Tally);
+
+		Report::Struct {
+			Type:Tally
+				.Type
+				.iter()
+				.map(|(Type, Total)| (Type.clone(), *Total))
+				.collect::<BTreeMap<_, _>>(),
+			Other:Tally.Other,
+			Folded:Tally.Folded,
+		}
+	}
+
+	/// Starts the dumping task if a dump is configured and it does not run
+	/// already.
+	fn Start(self: &Arc<Self>) {
+		let Some((Path, Interval)) = Lock(&self.Dump).clone() else {
+			return;
+		};
+
+		let mut Task = Lock(&self.Task);
+
+		if Task.is_some() {
+			return;
+		}
+
+		let (Owner, Runtime) = (Arc::downgrade(self), self.Overseer.Runtime());
+
+		*Task = Some(self.Overseer.Spawn(
+			"cost",
+			Restart::Always { Backoff:Duration::from_secs(1) },
+			move || {
+				let (Owner, Path, Runtime) = (Owner.clone(), Path.clone(), Runtime.clone());
+
+				async move {
+					let Storage = Storage::New(Arc::new(Json));
+
+					loop {
+						Runtime.Sleep(Interval).await;
+
+						let Some(Cost) = Owner.upgrade() else {
+							break;
+						};
+
+						let Report = serde_json::to_value(Cost.Report()).unwrap_or_default();
+
+						drop(Cost);
+
+						let Appended = async {
+							let mut Log = Storage.Append(&Path).await?;
+
+							Log.Append(&Report).await?;
+
+							Log.Close().await
+						};
+
+						if let Err(_Error) = Appended.await {
+							warn!("Cannot dump the cost report to {}: {}", Path.display(), _Error);
+						}
+					}
+				}
+			},
+		));
+	}
+}
+
+impl Tally {
+	/// Folds the type with the least cumulative execution time into
+	/// `Other`, the lexicographically first one on a tie.
+	fn Fold(&mut self) {
+		let Cheapest = self
+			.Type
+			.iter()
+			.min_by(|(Left, LeftTotal), (Right, RightTotal)| {
+				LeftTotal.Execution.cmp(&RightTotal.Execution).then(Left.cmp(Right))
+			})
+			.map(|(Type, _)| Type.clone());
+
+		if let Some(Total) = Cheapest.and_then(|Cheapest| self.Type.remove(&Cheapest)) {
+			self.Other = self.Other + Total;
+
+			self.Folded += 1;
+		}
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(32, Arc::new(Overseer::default())) }
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		if let Some(Task) = Lock(&self.Task).take() {
+			Task.Abort();
+		}
+	}
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::PathBuf,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+	},
+	time::Duration,
+};
+
+use log::warn;
+
+use crate::{
+	Enum::Sequence::Restart::Enum as Restart,
+	Struct::{
+		Runtime::Spawned,
+		Sequence::Overseer::Struct as Overseer,
+		Storage::{Codec::Json::Struct as Json, Struct as Storage},
+	},
+};
+
+pub mod Report;
+pub mod Sample;
+pub mod Total;
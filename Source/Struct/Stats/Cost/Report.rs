@@ -0,0 +1,33 @@
+/// The cost of the actions executed in a `Life`, by action type.
+///
+/// Returned by `Life::CostReport` and carried in `Life::Stats`. Only the
+/// costliest types are kept apart; the others are summed up in `Other`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Cost"))]
+pub struct Struct {
+	/// The cost of every type kept apart, by type.
+	pub Type:BTreeMap<String, Total::Struct>,
+
+	/// The cost of the types folded together to bound the report.
+	pub Other:Total::Struct,
+
+	/// How many times a type was folded into `Other`.
+	pub Folded:u64,
+}
+
+impl Struct {
+	/// Sums the cost of every type, `Other` included.
+	///
+	/// # Returns
+	///
+	/// The cost of all actions executed.
+	pub fn Sum(&self) -> Total::Struct {
+		self.Type.values().fold(self.Other, |Sum, Total| Sum + *Total)
+	}
+}
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Stats::Cost::Total;
@@ -0,0 +1,27 @@
+/// What one executed action cost, as measured by the sequence that ran it.
+#[derive(Clone, Debug, Default)]
+pub struct Struct {
+	/// The type of the action, its `Action` metadata.
+	pub Type:String,
+
+	/// How long the sequence spent on the action, retries and their backoff
+	/// included.
+	pub Execution:Duration,
+
+	/// How long the action waited in its production line before it started.
+	pub Wait:Duration,
+
+	/// How many attempts beyond the first it took.
+	pub Retries:u64,
+
+	/// The approximate size of its arguments, in bytes of JSON.
+	pub Argument:u64,
+
+	/// The approximate size of its result, in bytes of JSON.
+	pub Result:u64,
+
+	/// Whether its final attempt failed.
+	pub Failed:bool,
+}
+
+use std::time::Duration;
@@ -0,0 +1,81 @@
+/// The accumulated cost of the actions of one type. Times are in
+/// microseconds and sizes in bytes of JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Total"))]
+pub struct Struct {
+	/// The number of actions executed.
+	pub Count:u64,
+
+	/// The number of actions whose final attempt failed.
+	pub Failed:u64,
+
+	/// The cumulative execution time.
+	pub Execution:u64,
+
+	/// The mean execution time.
+	pub Average:u64,
+
+	/// The longest execution time.
+	pub Maximum:u64,
+
+	/// The cumulative time actions waited in their production line.
+	pub Wait:u64,
+
+	/// The number of attempts beyond the first.
+	pub Retries:u64,
+
+	/// The approximate cumulative size of the arguments.
+	pub Argument:u64,
+
+	/// The approximate cumulative size of the results.
+	pub Result:u64,
+}
+
+impl Struct {
+	/// Adds the cost of one executed action.
+	///
+	/// # Arguments
+	///
+	/// * `Sample` - What the action cost.
+	pub fn Record(&mut self, Sample:&Sample) {
+		let Execution = Sample.Execution.as_micros() as u64;
+
+		*self = *self
+			+ Struct {
+				Count:1,
+				Failed:Sample.Failed as u64,
+				Execution,
+				Average:Execution,
+				Maximum:Execution,
+				Wait:Sample.Wait.as_micros() as u64,
+				Retries:Sample.Retries,
+				Argument:Sample.Argument,
+				Result:Sample.Result,
+			};
+	}
+}
+
+impl std::ops::Add for Struct {
+	type Output = Self;
+
+	fn add(self, Other:Self) -> Self {
+		let (Count, Execution) =
+			(self.Count + Other.Count, self.Execution.saturating_add(Other.Execution));
+
+		Struct {
+			Count,
+			Failed:self.Failed + Other.Failed,
+			Execution,
+			Average:Execution.checked_div(Count).unwrap_or(0),
+			Maximum:self.Maximum.max(Other.Maximum),
+			Wait:self.Wait.saturating_add(Other.Wait),
+			Retries:self.Retries + Other.Retries,
+			Argument:self.Argument.saturating_add(Other.Argument),
+			Result:self.Result.saturating_add(Other.Result),
+		}
+	}
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Stats::Cost::Sample::Struct as Sample;
@@ -0,0 +1,26 @@
+/// The counters of a production line at one point in time.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Queue"))]
+pub struct Struct {
+	/// The number of actions waiting in the line.
+	pub Depth:u64,
+
+	/// The number of actions ever enqueued.
+	pub Enqueued:u64,
+
+	/// The number of actions ever dequeued.
+	pub Dequeued:u64,
+
+	/// Whether the queue is paused; see `Life::PauseQueue`.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub Paused:bool,
+
+	/// The number of actions waiting in every bucket while the line dequeues
+	/// fairly; see `Production::Fair`.
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub Buckets:BTreeMap<String, u64>,
+}
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
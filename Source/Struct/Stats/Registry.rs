@@ -0,0 +1,85 @@
+/// Where components of a `Life` register the counters `Life::Stats` reads.
+///
+/// Components register on construction and keep the only strong reference
+/// to their counters, so a dropped component disappears from the registry.
+#[derive(Debug, Default)]
+pub struct Struct {
+	/// The counters of sequences, keyed by registration number.
+	Sequence:DashMap<u64, Weak<Activity::Struct>>,
+
+	/// The counters of transports, keyed by registration number.
+	Transport:DashMap<u64, Weak<Activity::Struct>>,
+
+	/// The registration number handed out next.
+	Next:AtomicU64,
+}
+
+impl Struct {
+	/// Creates a new, empty registry.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Registers the counters of a sequence.
+	///
+	/// # Returns
+	///
+	/// The counters the sequence updates.
+	pub fn Sequence(&self) -> Arc<Activity::Struct> { Self::Register(&self.Sequence, &self.Next) }
+
+	/// Registers the counters of a transport, counting its connections.
+	///
+	/// # Returns
+	///
+	/// The counters the transport updates.
+	pub fn Transport(&self) -> Arc<Activity::Struct> { Self::Register(&self.Transport, &self.Next) }
+
+	/// Reads the counters of every live sequence.
+	pub fn Sequences(&self) -> Vec<Count::Struct> { Self::Read(&self.Sequence) }
+
+	/// Reads the counters of every live transport.
+	pub fn Transports(&self) -> Vec<Count::Struct> { Self::Read(&self.Transport) }
+
+	/// Adds new counters to a map.
+	fn Register(
+		Map:&DashMap<u64, Weak<Activity::Struct>>,
+		Next:&AtomicU64,
+	) -> Arc<Activity::Struct> {
+		let Activity = Arc::new(Activity::Struct::New());
+
+		Map.insert(Next.fetch_add(1, Ordering::Relaxed), Arc::downgrade(&Activity));
+
+		Activity
+	}
+
+	/// Reads the live counters of a map, forgetting dropped ones.
+	fn Read(Map:&DashMap<u64, Weak<Activity::Struct>>) -> Vec<Count::Struct> {
+		Map.retain(|_, Activity| Activity.strong_count() > 0);
+
+		let mut Entry = Map
+			.iter()
+			.filter_map(|Entry| {
+				Entry
+					.value()
+					.upgrade()
+					.map(|Activity| (*Entry.key(), Activity.Count()))
+			})
+			.collect::<Vec<_>>();
+
+		Entry.sort_by_key(|(Key, _)| *Key);
+
+		Entry.into_iter().map(|(_, Count)| Count).collect()
+	}
+}
+
+use std::sync::{
+	atomic::{AtomicU64, Ordering},
+	Arc,
+	Weak,
+};
+
+use dashmap::DashMap;
+
+use crate::Struct::Stats::Activity::{self, Count};
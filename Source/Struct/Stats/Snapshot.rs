@@ -0,0 +1,35 @@
+/// What an Echo process is doing at one point in time.
+///
+/// Returned by `Life::Stats`. Every part is read without waiting on locks,
+/// so values taken from different components may be a few operations
+/// apart.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Snapshot"))]
+pub struct Struct {
+	/// The counters of every Karma queue, by name.
+	pub Queue:BTreeMap<String, Queue::Struct>,
+
+	/// The counters of every sequence: actions in flight, processed and
+	/// failed.
+	pub Sequence:Vec<Count::Struct>,
+
+	/// The connection counters of all transports together: open, served and
+	/// failed connections.
+	pub Connection:Count::Struct,
+
+	/// The number of cache entries.
+	pub Cache:Option<usize>,
+
+	/// The number of actions waiting in dead-letter queues.
+	pub DeadLetter:u64,
+
+	/// The cost of the actions executed so far, by action type.
+	#[serde(default)]
+	pub Cost:Cost::Report::Struct,
+}
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Stats::{Activity::Count, Cost, Queue};
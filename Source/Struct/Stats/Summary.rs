@@ -0,0 +1,67 @@
+/// What waits in a production line, grouped instead of listed.
+///
+/// Returned by `Production::Summary` and, for every Karma queue, by
+/// `Life::Summary`. It is computed in one pass over the line without copying
+/// the actions, so a dashboard can poll a queue too long to snapshot.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Summary"))]
+pub struct Struct {
+	/// The number of actions waiting.
+	pub Count:u64,
+
+	/// The number of actions waiting, by action type. Actions without an
+	/// `Action` name are only counted in `Count`.
+	pub Action:BTreeMap<String, u64>,
+
+	/// The number of actions waiting, by how long they have been waiting.
+	pub Age:Age::Struct,
+
+	/// The number of actions waiting, by their `Priority` metadata written
+	/// out as a key; actions without one count as priority `"0"`.
+	pub Priority:BTreeMap<String, u64>,
+
+	/// The approximate size of the arguments of the actions waiting, in
+	/// bytes of JSON.
+	pub Bytes:u64,
+
+	/// The action waiting longest; omitted while the line is empty.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Oldest:Option<Oldest::Struct>,
+}
+
+impl Struct {
+	/// Counts one waiting action in the summary.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The type of the action, if it has one.
+	/// * `Wait` - How long it has been waiting.
+	/// * `Priority` - Its priority.
+	/// * `Bytes` - The size of its arguments.
+	pub fn Add(&mut self, Action:Option<String>, Wait:Duration, Priority:i64, Bytes:u64) {
+		self.Count += 1;
+
+		self.Age.Add(Wait);
+
+		*self.Priority.entry(Priority.to_string()).or_default() += 1;
+
+		self.Bytes += Bytes;
+
+		let AgeMs = Wait.as_millis() as u64;
+
+		if self.Oldest.as_ref().is_none_or(|Oldest| AgeMs > Oldest.AgeMs) {
+			self.Oldest = Some(Oldest::Struct { Action:Action.clone(), AgeMs });
+		}
+
+		if let Some(Action) = Action {
+			*self.Action.entry(Action).or_default() += 1;
+		}
+	}
+}
+
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+pub mod Age;
+pub mod Oldest;
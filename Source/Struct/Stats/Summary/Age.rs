@@ -0,0 +1,43 @@
+/// The number of actions waiting in a production line, by how long they
+/// have been waiting. Every action is counted in exactly one bucket.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Age"))]
+pub struct Struct {
+	/// Waiting for less than a second.
+	pub Second:u64,
+
+	/// Waiting for at least a second and less than 10 seconds.
+	pub TenSeconds:u64,
+
+	/// Waiting for at least 10 seconds and less than a minute.
+	pub Minute:u64,
+
+	/// Waiting for at least a minute and less than 10 minutes.
+	pub TenMinutes:u64,
+
+	/// Waiting for 10 minutes or longer.
+	pub Older:u64,
+}
+
+impl Struct {
+	/// Counts an action in the bucket of its wait.
+	///
+	/// # Arguments
+	///
+	/// * `Wait` - How long the action has been waiting.
+	pub fn Add(&mut self, Wait:Duration) {
+		let Bucket = match Wait.as_secs() {
+			0 => &mut self.Second,
+			1..10 => &mut self.TenSeconds,
+			10..60 => &mut self.Minute,
+			60..600 => &mut self.TenMinutes,
+			_ => &mut self.Older,
+		};
+
+		*Bucket += 1;
+	}
+}
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,13 @@
+/// The action waiting longest in a production line.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Oldest"))]
+pub struct Struct {
+	/// The type of the action, if it has one.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Action:Option<String>,
+
+	/// How long it has been waiting, in milliseconds.
+	pub AgeMs:u64,
+}
+
+use serde::{Deserialize, Serialize};
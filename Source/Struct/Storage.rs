@@ -0,0 +1,307 @@
+/// Reads and writes the files persistence features keep on disk.
+///
+/// Every file starts with a header of the magic bytes `ECHO`, a format
+/// version and the identifier of the codec its records are encoded with.
+/// Records follow as frames of a little-endian `u32` length, the CRC-32 of
+/// the encoded record and the record itself.
+///
+/// Whole files are written atomically: into a temporary file next to the
+/// target, named uniquely per write, which is then renamed over it. Append-only logs are opened with
+/// `Append`. `Read` returns every intact record and lists the corrupt ones
+/// it skipped; a record whose length runs past the end of the file ends the
+/// read, as nothing after it can be trusted to be aligned.
+#[derive(Clone)]
+pub struct Struct {
+	/// The codec records are encoded with.
+	Codec:Arc<dyn CodecTrait>,
+
+	/// When files are flushed to disk.
+	Fsync:Fsync,
+}
+
+impl Struct {
+	/// Creates a storage helper with the `OnClose` fsync policy.
+	///
+	/// # Arguments
+	///
+	/// * `Codec` - The codec records are encoded with.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Codec:Arc<dyn CodecTrait>) -> Self { Struct { Codec, Fsync:Fsync::default() } }
+
+	/// Sets when files are flushed to disk.
+	///
+	/// # Arguments
+	///
+	/// * `Fsync` - The fsync policy.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithFsync(mut self, Fsync:Fsync) -> Self {
+		self.Fsync = Fsync;
+
+		self
+	}
+
+	/// Replaces a file with the given records, atomically.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the file.
+	/// * `Record` - The records to write, in order.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the file was renamed into place, or the error of the
+	/// first failed step; the previous file is then left untouched.
+	pub async fn Write(&self, Path:impl AsRef<Path>, Record:&[Value]) -> io::Result<()> {
+		let Path = Path.as_ref();
+
+		let mut Content = self.Header().to_vec();
+
+		for Record in Record {
+			Content.extend(self.Frame(Record)?);
+		}
+
+		// Unique per write, so concurrent writers never share a temporary file
+		let mut Temporary = Path.as_os_str().to_owned();
+
+		Temporary.push(format!(
+			".{}-{}.tmp",
+			std::process::id(),
+			Written.fetch_add(1, Ordering::Relaxed)
+		));
+
+		let Temporary = PathBuf::from(Temporary);
+
+		let Placed = async {
+			let mut File = File::create_new(&Temporary).await?;
+
+			File.write_all(&Content).await?;
+
+			if self.Fsync != Fsync::Never {
+				File.sync_all().await?;
+			}
+
+			drop(File);
+
+			fs::rename(&Temporary, Path).await
+		}
+		.await;
+
+		if let Err(_Error) = Placed {
+			let _ = fs::remove_file(&Temporary).await;
+
+			return Err(_Error);
+		}
+
+		if self.Fsync == Fsync::Always {
+			// Persist the rename itself; directories cannot be opened on every
+			// platform, so this is best effort
+			if let Some(Directory) =
+				Path.parent().filter(|Directory| !Directory.as_os_str().is_empty())
+			{
+				if let Ok(Directory) = File::open(Directory).await {
+					let _ = Directory.sync_all().await;
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Opens an append-only log, creating it with a header if needed.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the log.
+	///
+	/// # Returns
+	///
+	/// The opened `Log`, or `InvalidData` if an existing file has a foreign
+	/// header.
+	pub async fn Append(&self, Path:impl AsRef<Path>) -> io::Result<Log::Struct> {
+		let mut File = OpenOptions::new()
+			.create(true)
+			.read(true)
+			.append(true)
+			.open(Path)
+			.await?;
+
+		if File.metadata().await?.len() == 0 {
+			File.write_all(&self.Header()).await?;
+		} else {
+			let mut Header = [0; 6];
+
+			File.read_exact(&mut Header).await?;
+
+			self.Check(&Header)?;
+		}
+
+		Ok(Log::Struct::New(File, self.clone()))
+	}
+
+	/// Reads every intact record of a file.
+	///
+	/// # Arguments
+	///
+	/// * `Path` - The path of the file.
+	///
+	/// # Returns
+	///
+	/// The recovered records and the skipped ones, or `InvalidData` if the
+	/// header is missing, of another version or of another codec.
+	pub async fn Read(&self, Path:impl AsRef<Path>) -> io::Result<Recovered::Struct> {
+		let Content = fs::read(Path).await?;
+
+		if Content.len() < 6 {
+			return Err(Invalid("file is shorter than its header".to_string()));
+		}
+
+		self.Check(&Content[..6])?;
+
+		let mut Recovered = Recovered::Struct::default();
+
+		let mut Offset = 6;
+
+		while Offset < Content.len() {
+			let Skip = |Reason:String| Skipped::Struct { Offset:Offset as u64, Reason };
+
+			let Rest = &Content[Offset..];
+
+			if Rest.len() < 8 {
+				Recovered.Skipped.push(Skip("record header is cut off".to_string()));
+
+				break;
+			}
+
+			let Length = u32::from_le_bytes([Rest[0], Rest[1], Rest[2], Rest[3]]) as usize;
+
+			let Sum = u32::from_le_bytes([Rest[4], Rest[5], Rest[6], Rest[7]]);
+
+			let Some(Payload) = Rest.get(8..8 + Length) else {
+				Recovered.Skipped.push(Skip(format!(
+					"record of {} bytes runs past the end of the file",
+					Length
+				)));
+
+				break;
+			};
+
+			if Checksum(Payload) != Sum {
+				Recovered.Skipped.push(Skip("checksum mismatch".to_string()));
+			} else {
+				match self.Codec.Decode(Payload) {
+					Ok(Record) => Recovered.Record.push(Record),
+					Err(_Error) => Recovered.Skipped.push(Skip(format!("undecodable: {}", _Error))),
+				}
+			}
+
+			Offset += 8 + Length;
+		}
+
+		Ok(Recovered)
+	}
+
+	/// Encodes one record as a frame.
+	pub(crate) fn Frame(&self, Record:&Value) -> io::Result<Vec<u8>> {
+		let Payload = self.Codec.Encode(Record).map_err(Invalid)?;
+
+		let Length = u32::try_from(Payload.len())
+			.map_err(|_| Invalid(format!("record of {} bytes is too large", Payload.len())))?;
+
+		let mut Frame = Vec::with_capacity(8 + Payload.len());
+
+		Frame.extend(Length.to_le_bytes());
+
+		Frame.extend(Checksum(&Payload).to_le_bytes());
+
+		Frame.extend(Payload);
+
+		Ok(Frame)
+	}
+
+	/// Returns the fsync policy.
+	pub fn Fsync(&self) -> Fsync { self.Fsync }
+
+	/// Builds the header of a file.
+	fn Header(&self) -> [u8; 6] { [b'E', b'C', b'H', b'O', 1, self.Codec.Id()] }
+
+	/// Checks the header of a file against this helper.
+	fn Check(&self, Header:&[u8]) -> io::Result<()> {
+		if &Header[..4] != b"ECHO" {
+			return Err(Invalid("file has no storage header".to_string()));
+		}
+
+		if Header[4] != 1 {
+			return Err(Invalid(format!("unsupported storage version {}", Header[4])));
+		}
+
+		if Header[5] != self.Codec.Id() {
+			return Err(Invalid(format!(
+				"file was written with codec {}, not {}",
+				Header[5],
+				self.Codec.Id()
+			)));
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Arc::new(Json::Struct)) }
+}
+
+/// Computes the CRC-32 (IEEE) of a byte slice.
+fn Checksum(Byte:&[u8]) -> u32 {
+	!Byte.iter().fold(!0u32, |Sum, Byte| {
+		(0..8).fold(Sum ^ u32::from(*Byte), |Sum, _| {
+			if Sum & 1 == 1 {
+				(Sum >> 1) ^ 0xEDB8_8320
+			} else {
+				Sum >> 1
+			}
+		})
+	})
+}
+
+/// The number of files written by this process, naming their temporary files.
+#[allow(non_upper_case_globals)]
+static Written:AtomicU64 = AtomicU64::new(0);
+
+/// Builds an `InvalidData` error.
+fn Invalid(Message:String) -> io::Error { io::Error::new(ErrorKind::InvalidData, Message) }
+
+use std::{
+	io::{self, ErrorKind},
+	path::{Path, PathBuf},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+};
+
+use serde_json::Value;
+use tokio::{
+	fs::{self, File, OpenOptions},
+	io::{AsyncReadExt, AsyncWriteExt},
+};
+
+use crate::{Enum::Storage::Fsync::Enum as Fsync, Trait::Storage::Codec::Trait as CodecTrait};
+
+pub mod Codec {
+	pub mod Json;
+}
+
+pub mod Log;
+pub mod Memory;
+pub mod Offload;
+pub mod Recovered;
+pub mod Skipped;
+pub mod Stored;
+
+use self::Codec::Json;
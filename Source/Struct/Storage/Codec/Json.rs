@@ -0,0 +1,20 @@
+/// The JSON storage codec, encoding every record as one compact JSON
+/// document. Its identifier is 1.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+impl Codec for Struct {
+	fn Id(&self) -> u8 { 1 }
+
+	fn Encode(&self, Record:&Value) -> Result<Vec<u8>, String> {
+		serde_json::to_vec(Record).map_err(|_Error| _Error.to_string())
+	}
+
+	fn Decode(&self, Record:&[u8]) -> Result<Value, String> {
+		serde_json::from_slice(Record).map_err(|_Error| _Error.to_string())
+	}
+}
+
+use serde_json::Value;
+
+use crate::Trait::Storage::Codec::Trait as Codec;
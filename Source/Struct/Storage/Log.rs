@@ -0,0 +1,64 @@
+/// An append-only log of storage records, opened with `Storage::Append`.
+///
+/// Every record is framed with its length and CRC-32, so a reader can skip
+/// records torn by a crash. Under the `Always` policy every append is
+/// flushed to disk; under `OnClose` only `Close` flushes.
+pub struct Struct {
+	/// The log file, opened for appending.
+	File:File,
+
+	/// The storage helper framing the records.
+	Storage:Storage,
+}
+
+impl Struct {
+	/// Wraps an opened log file.
+	///
+	/// # Arguments
+	///
+	/// * `File` - The log file, opened for appending, its header written.
+	/// * `Storage` - The storage helper framing the records.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(File:File, Storage:Storage) -> Self { Struct { File, Storage } }
+
+	/// Appends one record.
+	///
+	/// # Arguments
+	///
+	/// * `Record` - The record to append.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the record was written, and flushed to disk under the
+	/// `Always` policy.
+	pub async fn Append(&mut self, Record:&Value) -> io::Result<()> {
+		self.File.write_all(&self.Storage.Frame(Record)?).await?;
+
+		if self.Storage.Fsync() == Fsync::Always {
+			self.File.sync_data().await?;
+		}
+
+		Ok(())
+	}
+
+	/// Closes the log, flushing it to disk unless the policy is `Never`.
+	pub async fn Close(mut self) -> io::Result<()> {
+		self.File.flush().await?;
+
+		if self.Storage.Fsync() != Fsync::Never {
+			self.File.sync_all().await?;
+		}
+
+		Ok(())
+	}
+}
+
+use std::io;
+
+use serde_json::Value;
+use tokio::{fs::File, io::AsyncWriteExt};
+
+use crate::{Enum::Storage::Fsync::Enum as Fsync, Struct::Storage::Struct as Storage};
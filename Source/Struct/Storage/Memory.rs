@@ -0,0 +1,77 @@
+/// The default `ResultStore`: bodies kept in process memory, in a bounded
+/// `Store` named `results` that evicts the least recently used ones.
+pub struct Struct {
+	/// The bodies and their media types, by reference.
+	Store:Store::Struct<String, (Bytes, String)>,
+}
+
+impl Struct {
+	/// Creates a new, empty store.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How much the store may hold.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Limit:Limit::Struct) -> Self {
+		Struct { Store:Store::Struct::New("results", Limit) }
+	}
+
+	/// Returns the number of bodies held.
+	pub fn Len(&self) -> usize { self.Store.Len() }
+
+	/// Returns whether no body is held.
+	pub fn IsEmpty(&self) -> bool { self.Store.IsEmpty() }
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Limit::Struct::New()) }
+}
+
+#[async_trait]
+impl ResultStore for Struct {
+	async fn Put(&self, Id:&str, Body:Vec<u8>, ContentType:&str) -> Result<Stored, String> {
+		let Stored = Stored::New(&format!("memory:{}", Id), Body.len() as u64, ContentType);
+
+		let Size = Body.len();
+
+		self.Store
+			.Insert(Stored.Ref.clone(), (Bytes::from(Body), ContentType.to_string()), Size);
+
+		Ok(Stored)
+	}
+
+	async fn Get(&self, Stored:&Stored) -> Result<Vec<u8>, String> {
+		self.Store
+			.Get(&Stored.Ref)
+			.map(|(Body, _)| Body.to_vec())
+			.ok_or_else(|| format!("No stored result {}", Stored.Ref))
+	}
+
+	async fn GetRange(&self, Stored:&Stored, Offset:u64, Length:usize) -> Result<Vec<u8>, String> {
+		// The body is shared, not copied, before the range is cut out of it
+		self.Store
+			.Get(&Stored.Ref)
+			.map(|(Body, _)| Range(&Body, Offset, Length).to_vec())
+			.ok_or_else(|| format!("No stored result {}", Stored.Ref))
+	}
+
+	async fn Delete(&self, Stored:&Stored) -> Result<(), String> {
+		self.Store.Remove(&Stored.Ref);
+
+		Ok(())
+	}
+}
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::{
+	Struct::{
+		Storage::Stored::Struct as Stored,
+		Store::{self, Limit},
+	},
+	Trait::Storage::ResultStore::{Range, Trait as ResultStore},
+};
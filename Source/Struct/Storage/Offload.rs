@@ -0,0 +1,185 @@
+/// Moves results above a size threshold out of the replies into a
+/// `ResultStore`, leaving a `Stored` marker in their place.
+///
+/// A result the store refuses is delivered inline with a warning. Stored
+/// results are deleted once older than the retention, by `Sweep`, which
+/// every offload also runs.
+///
+/// Offloads and their failures are counted by the
+/// `echo_result_offloaded_total` and `echo_result_offload_failed_total`
+/// counters.
+pub struct Struct {
+	/// Where the results are kept.
+	Store:Arc<dyn ResultStore>,
+
+	/// The largest encoded result delivered inline, in bytes.
+	Threshold:usize,
+
+	/// How long a stored result is kept, or `None` to keep it until the
+	/// store drops it.
+	Retention:Option<Duration>,
+
+	/// The results stored, the oldest first.
+	Stored:Mutex<VecDeque<(Instant, Stored)>>,
+}
+
+impl Struct {
+	/// Creates a new offload keeping stored results for an hour.
+	///
+	/// # Arguments
+	///
+	/// * `Store` - Where the results are kept.
+	/// * `Threshold` - The largest encoded result delivered inline, in bytes.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Store:Arc<dyn ResultStore>, Threshold:usize) -> Self {
+		Struct {
+			Store,
+			Threshold,
+			Retention:Some(Duration::from_secs(3600)),
+			Stored:Mutex::new(VecDeque::new()),
+		}
+	}
+
+	/// Sets how long stored results are kept.
+	///
+	/// # Arguments
+	///
+	/// * `Retention` - How long a stored result is kept, or `None` to keep it
+	///   until the store drops it.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRetention(mut self, Retention:Option<Duration>) -> Self {
+		self.Retention = Retention;
+
+		self
+	}
+
+	/// Stores a result if it is above the threshold.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the submission the result belongs to.
+	/// * `Value` - The result.
+	///
+	/// # Returns
+	///
+	/// The marker of the stored result, or the result itself if it is small
+	/// enough or the store refused it.
+	pub async fn Offload(&self, Id:&str, Value:Value) -> Value {
+		let Body = match serde_json::to_vec(&Value) {
+			Ok(Body) if Body.len() > self.Threshold => Body,
+			_ => return Value,
+		};
+
+		self.Sweep().await;
+
+		match self.Store.Put(Id, Body, "application/json").await {
+			Ok(Stored) => {
+				counter!("echo_result_offloaded_total").increment(1);
+
+				let Marker = Stored.Marker();
+
+				Lock(&self.Stored).push_back((Instant::now(), Stored));
+
+				Marker
+			},
+			Err(_Error) => {
+				warn!("Cannot offload the result of {}, delivering it inline: {}", Id, _Error);
+
+				counter!("echo_result_offload_failed_total").increment(1);
+
+				Value
+			},
+		}
+	}
+
+	/// Reads a stored result back.
+	///
+	/// # Arguments
+	///
+	/// * `Stored` - The reference of the result.
+	///
+	/// # Returns
+	///
+	/// The result, or a description of why it cannot be read.
+	pub async fn Resolve(&self, Stored:&Stored) -> Result<Value, String> {
+		let Body = self.Store.Get(Stored).await?;
+
+		serde_json::from_slice(&Body).map_err(|_Error| _Error.to_string())
+	}
+
+	/// Reads part of a stored result, to send it in chunks.
+	///
+	/// # Arguments
+	///
+	/// * `Stored` - The reference of the result.
+	/// * `Offset` - The position of the first byte to read.
+	/// * `Length` - The most bytes to read.
+	///
+	/// # Returns
+	///
+	/// The bytes of the encoded result, or a description of why they cannot
+	/// be read.
+	pub async fn Range(&self, Stored:&Stored, Offset:u64, Length:usize) -> Result<Vec<u8>, String> {
+		self.Store.GetRange(Stored, Offset, Length).await
+	}
+
+	/// Deletes the stored results older than the retention.
+	///
+	/// # Returns
+	///
+	/// The number of results deleted.
+	pub async fn Sweep(&self) -> usize {
+		let Some(Retention) = self.Retention else {
+			return 0;
+		};
+
+		let Expired = {
+			let mut Stored = Lock(&self.Stored);
+
+			let Count = Stored.iter().take_while(|(At, _)| At.elapsed() >= Retention).count();
+
+			Stored.drain(..Count).map(|(_, Stored)| Stored).collect::<Vec<_>>()
+		};
+
+		for Stored in &Expired {
+			if let Err(_Error) = self.Store.Delete(Stored).await {
+				warn!("Cannot delete the stored result {}: {}", Stored.Ref, _Error);
+			}
+		}
+
+		Expired.len()
+	}
+
+	/// Returns the number of stored results not yet swept.
+	pub fn Len(&self) -> usize { Lock(&self.Stored).len() }
+
+	/// Returns whether no stored result awaits a sweep.
+	pub fn IsEmpty(&self) -> bool { self.Len() == 0 }
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Mutex, MutexGuard},
+	time::Duration,
+};
+
+use log::warn;
+use metrics::counter;
+use serde_json::Value;
+use tokio::time::Instant;
+
+use crate::{
+	Struct::Storage::Stored::Struct as Stored,
+	Trait::Storage::ResultStore::Trait as ResultStore,
+};
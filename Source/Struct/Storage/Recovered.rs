@@ -0,0 +1,13 @@
+/// What a storage reader recovered from a file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Struct {
+	/// The intact records, in file order.
+	pub Record:Vec<Value>,
+
+	/// The records that were corrupt or cut off, in file order.
+	pub Skipped:Vec<Skipped::Struct>,
+}
+
+use serde_json::Value;
+
+use crate::Struct::Storage::Skipped;
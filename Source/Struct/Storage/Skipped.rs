@@ -0,0 +1,11 @@
+/// A record a storage reader could not recover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+	/// The byte offset of the record in the file.
+	pub Offset:u64,
+
+	/// Why the record was skipped.
+	pub Reason:String,
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,57 @@
+/// A reference to a result kept in a `ResultStore`.
+///
+/// On the wire a stored result takes the place of the value as a marker
+/// object, `{"$stored": {"ref": ..., "size": ..., "content_type": ...}}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Stored"))]
+pub struct Struct {
+	/// Where the store keeps the body.
+	#[serde(rename = "ref")]
+	pub Ref:String,
+
+	/// The size of the body, in bytes.
+	#[serde(rename = "size")]
+	pub Size:u64,
+
+	/// The media type of the body.
+	#[serde(rename = "content_type")]
+	pub ContentType:String,
+}
+
+impl Struct {
+	/// Creates a new reference.
+	///
+	/// # Arguments
+	///
+	/// * `Ref` - Where the store keeps the body.
+	/// * `Size` - The size of the body, in bytes.
+	/// * `ContentType` - The media type of the body.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Ref:&str, Size:u64, ContentType:&str) -> Self {
+		Struct { Ref:Ref.to_string(), Size, ContentType:ContentType.to_string() }
+	}
+
+	/// Returns the marker standing in for the stored value.
+	pub fn Marker(&self) -> Value { json!({ "$stored": self }) }
+
+	/// Reads the reference out of a marker.
+	///
+	/// # Arguments
+	///
+	/// * `Value` - A value that may be a marker.
+	///
+	/// # Returns
+	///
+	/// The reference, or `None` if the value is not a marker.
+	pub fn Parse(Value:&Value) -> Option<Self> {
+		let Marker = Value.as_object().filter(|Marker| Marker.len() == 1)?;
+
+		serde_json::from_value(Marker.get("$stored")?.clone()).ok()
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
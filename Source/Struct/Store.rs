@@ -0,0 +1,299 @@
+/// A bounded map evicting its least recently used entries.
+///
+/// A store holds at most as many entries and bytes as its `Limit` allows;
+/// the size of an entry is given when it is inserted. Entries pushed out by
+/// an insertion or a smaller limit are handed back to the caller, which may
+/// still have to resolve whoever waits on them.
+///
+/// The size of a store and its evictions are published as the
+/// `echo_store_entries` and `echo_store_bytes` gauges and the
+/// `echo_store_evicted_total` counter, labelled with the store name.
+pub struct Struct<K, V> {
+	/// The entries and their recency.
+	Inner:Mutex<Inner<K, V>>,
+
+	/// The number of entries held.
+	Entries:AtomicUsize,
+
+	/// The approximate size of the entries held, in bytes.
+	Bytes:AtomicUsize,
+
+	/// The number of entries evicted so far.
+	Evicted:AtomicU64,
+}
+
+/// The state of a store behind its lock.
+struct Inner<K, V> {
+	/// The name of the store, used as the `store` metric label.
+	Name:String,
+
+	/// How much the store may hold.
+	Limit:Limit::Struct,
+
+	/// The entries by key, with their size and last use.
+	Entry:HashMap<K, (V, usize, u64)>,
+
+	/// The keys by last use, least recent first.
+	Order:BTreeMap<u64, K>,
+
+	/// The approximate size of all entries, in bytes.
+	Size:usize,
+
+	/// The use counter handed out next.
+	Tick:u64,
+}
+
+impl<K:Eq + Hash + Clone, V> Struct<K, V> {
+	/// Creates a new, empty store.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the store, used in metrics.
+	/// * `Limit` - How much the store may hold.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Name:&str, Limit:Limit::Struct) -> Self {
+		Struct {
+			Inner:Mutex::new(Inner {
+				Name:Name.to_string(),
+				Limit,
+				Entry:HashMap::new(),
+				Order:BTreeMap::new(),
+				Size:0,
+				Tick:0,
+			}),
+			Entries:AtomicUsize::new(0),
+			Bytes:AtomicUsize::new(0),
+			Evicted:AtomicU64::new(0),
+		}
+	}
+
+	/// Inserts an entry as the most recently used, replacing any entry of
+	/// the same key.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the entry.
+	/// * `Value` - The value of the entry.
+	/// * `Size` - The approximate size of the entry, in bytes.
+	///
+	/// # Returns
+	///
+	/// The entries evicted to make room, least recently used first.
+	pub fn Insert(&self, Key:K, Value:V, Size:usize) -> Vec<(K, V)> {
+		let mut Inner = self.Lock();
+
+		let Tick = Inner.Next();
+
+		Inner.Size += Size;
+
+		if let Some((_, Replaced, Last)) = Inner.Entry.insert(Key.clone(), (Value, Size, Tick)) {
+			Inner.Order.remove(&Last);
+
+			Inner.Size -= Replaced;
+		}
+
+		Inner.Order.insert(Tick, Key);
+
+		let Evicted = self.Evict(&mut Inner);
+
+		self.Publish(&Inner);
+
+		Evicted
+	}
+
+	/// Reads an entry and marks it as the most recently used.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the entry.
+	///
+	/// # Returns
+	///
+	/// A copy of the value, or `None` if there is no such entry.
+	pub fn Get(&self, Key:&K) -> Option<V>
+	where
+		V: Clone, {
+		let mut Inner = self.Lock();
+
+		let Tick = Inner.Next();
+
+		let Inner = &mut *Inner;
+
+		let (Value, _, Last) = Inner.Entry.get_mut(Key)?;
+
+		if let Some(Key) = Inner.Order.remove(Last) {
+			Inner.Order.insert(Tick, Key);
+		}
+
+		*Last = Tick;
+
+		Some(Value.clone())
+	}
+
+	/// Removes an entry.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the entry.
+	///
+	/// # Returns
+	///
+	/// The removed value, or `None` if there was no such entry.
+	pub fn Remove(&self, Key:&K) -> Option<V> {
+		let mut Inner = self.Lock();
+
+		let (Value, Size, Last) = Inner.Entry.remove(Key)?;
+
+		Inner.Order.remove(&Last);
+
+		Inner.Size -= Size;
+
+		self.Publish(&Inner);
+
+		Some(Value)
+	}
+
+	/// Replaces the limits of the store, evicting what no longer fits.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How much the store may hold from now on.
+	///
+	/// # Returns
+	///
+	/// The entries evicted, least recently used first.
+	pub fn Resize(&self, Limit:Limit::Struct) -> Vec<(K, V)> {
+		let mut Inner = self.Lock();
+
+		Inner.Limit = Limit;
+
+		let Evicted = self.Evict(&mut Inner);
+
+		self.Publish(&Inner);
+
+		Evicted
+	}
+
+	/// Returns the limits of the store.
+	pub fn Limit(&self) -> Limit::Struct { self.Lock().Limit }
+
+	/// Renames the store, moving its metrics to the new label.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The new name of the store.
+	pub fn Rename(&self, Name:&str) {
+		let mut Inner = self.Lock();
+
+		Inner.Name = Name.to_string();
+
+		self.Publish(&Inner);
+	}
+
+	/// Returns the name of the store.
+	pub fn Name(&self) -> String { self.Lock().Name.clone() }
+
+	/// Returns the number of entries held, without taking the lock.
+	pub fn Len(&self) -> usize { self.Entries.load(Ordering::Relaxed) }
+
+	/// Returns whether the store is empty, without taking the lock.
+	pub fn IsEmpty(&self) -> bool { self.Len() == 0 }
+
+	/// Returns the approximate size of the entries held, without taking the
+	/// lock.
+	pub fn Bytes(&self) -> usize { self.Bytes.load(Ordering::Relaxed) }
+
+	/// Returns the number of entries evicted so far.
+	pub fn Evicted(&self) -> u64 { self.Evicted.load(Ordering::Relaxed) }
+
+	/// Removes least recently used entries until the store fits its limits.
+	fn Evict(&self, Inner:&mut Inner<K, V>) -> Vec<(K, V)> {
+		let mut Evicted = Vec::new();
+
+		while Inner.Limit.Exceeded(Inner.Entry.len(), Inner.Size) {
+			let Some((_, Key)) = Inner.Order.pop_first() else {
+				break;
+			};
+
+			if let Some((Value, Size, _)) = Inner.Entry.remove(&Key) {
+				Inner.Size -= Size;
+
+				Evicted.push((Key, Value));
+			}
+		}
+
+		if !Evicted.is_empty() {
+			self.Evicted.fetch_add(Evicted.len() as u64, Ordering::Relaxed);
+
+			counter!("echo_store_evicted_total", "store" => Inner.Name.clone())
+				.increment(Evicted.len() as u64);
+		}
+
+		Evicted
+	}
+
+	/// Updates the size counters and gauges of the store.
+	fn Publish(&self, Inner:&Inner<K, V>) {
+		let (Entries, Bytes) = (Inner.Entry.len(), Inner.Size);
+
+		self.Entries.store(Entries, Ordering::Relaxed);
+
+		self.Bytes.store(Bytes, Ordering::Relaxed);
+
+		gauge!("echo_store_entries", "store" => Inner.Name.clone()).set(Entries as f64);
+
+		gauge!("echo_store_bytes", "store" => Inner.Name.clone()).set(Bytes as f64);
+	}
+
+	/// Takes the lock of the store, ignoring poisoning.
+	fn Lock(&self) -> MutexGuard<'_, Inner<K, V>> {
+		self.Inner.lock().unwrap_or_else(|Poison| Poison.into_inner())
+	}
+}
+
+impl<K:Eq + Hash + Clone> Struct<K, Value> {
+	/// Inserts a JSON value, sized by its serialized length.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The key of the entry.
+	/// * `Value` - The value of the entry.
+	///
+	/// # Returns
+	///
+	/// The entries evicted to make room, least recently used first.
+	pub fn Put(&self, Key:K, Value:Value) -> Vec<(K, Value)> {
+		let Size = serde_json::to_vec(&Value)
+			.map(|Bytes| Bytes.len())
+			.unwrap_or_default();
+
+		self.Insert(Key, Value, Size)
+	}
+}
+
+impl<K, V> Inner<K, V> {
+	/// Hands out the next use counter.
+	fn Next(&mut self) -> u64 {
+		self.Tick += 1;
+
+		self.Tick
+	}
+}
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	hash::Hash,
+	sync::{
+		atomic::{AtomicU64, AtomicUsize, Ordering},
+		Mutex,
+		MutexGuard,
+	},
+};
+
+use metrics::{counter, gauge};
+use serde_json::Value;
+
+pub mod Limit;
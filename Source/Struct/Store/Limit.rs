@@ -0,0 +1,77 @@
+/// How much a bounded `Store` may hold.
+///
+/// Limits are read from a `[stores.<name>]` configuration section, whose
+/// keys are the lowercase field names (`entries`, `bytes`). A limit of
+/// `None` leaves that dimension unbounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Struct {
+	/// The maximum number of entries.
+	#[serde(rename = "entries")]
+	pub Entries:Option<usize>,
+
+	/// The maximum approximate size of all entries, in bytes.
+	#[serde(rename = "bytes")]
+	pub Bytes:Option<usize>,
+}
+
+impl Struct {
+	/// Creates a new `Struct` with the default limits: 10000 entries and
+	/// 64 MiB.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Struct { Entries:Some(10_000), Bytes:Some(64 * 1024 * 1024) } }
+
+	/// Sets the maximum number of entries.
+	///
+	/// # Arguments
+	///
+	/// * `Entries` - The maximum number of entries, or `None` for no limit.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithEntries(mut self, Entries:Option<usize>) -> Self {
+		self.Entries = Entries;
+
+		self
+	}
+
+	/// Sets the maximum approximate size of all entries.
+	///
+	/// # Arguments
+	///
+	/// * `Bytes` - The maximum size in bytes, or `None` for no limit.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBytes(mut self, Bytes:Option<usize>) -> Self {
+		self.Bytes = Bytes;
+
+		self
+	}
+
+	/// Checks whether a store of the given size is over the limits.
+	///
+	/// # Arguments
+	///
+	/// * `Entries` - The number of entries held.
+	/// * `Bytes` - The approximate size of the entries held.
+	///
+	/// # Returns
+	///
+	/// `true` if either limit is exceeded.
+	pub fn Exceeded(&self, Entries:usize, Bytes:usize) -> bool {
+		self.Entries.is_some_and(|Limit| Entries > Limit)
+			|| self.Bytes.is_some_and(|Limit| Bytes > Limit)
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use serde::{Deserialize, Serialize};
@@ -0,0 +1,180 @@
+/// Starts and stops the components of an application in dependency order.
+///
+/// Components are started in the order they were registered and shut down in
+/// reverse, so sources registered after the sequences they feed stop
+/// producing before those sequences drain. Every component has its own
+/// shutdown deadline, and `Shutdown` reports how each one ended. Unlike the
+/// `Sequence::Supervisor` of a `Life`, which restarts internal tasks, this
+/// one owns the top-level parts of an application.
+pub struct Struct {
+	/// The registered components, in registration order.
+	Component:Vec<Entry>,
+
+	/// The number of leading components currently started.
+	Started:usize,
+
+	/// The runtime timing each shutdown against its deadline.
+	Runtime:Arc<dyn Runtime>,
+}
+
+/// A registered component.
+struct Entry {
+	/// The name the component is reported under.
+	Name:String,
+
+	/// How long the component may take to shut down.
+	Deadline:Duration,
+
+	/// The component itself.
+	Component:Box<dyn Component>,
+}
+
+impl Struct {
+	/// Creates a supervisor without components.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self {
+		Struct { Component:Vec::new(), Started:0, Runtime:Arc::new(Tokio::Struct) }
+	}
+
+	/// Sets the runtime timing shutdowns, such as that of the `Life` the
+	/// components belong to.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Runtime;
+
+		self
+	}
+
+	/// Registers a component, started after and stopped before every
+	/// component registered so far.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name the component is reported under.
+	/// * `Component` - The component.
+	/// * `Deadline` - How long the component may take to shut down.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Register(
+		mut self,
+		Name:&str,
+		Component:impl Component + 'static,
+		Deadline:Duration,
+	) -> Self {
+		self.Component.push(Entry {
+			Name:Name.to_string(),
+			Deadline,
+			Component:Box::new(Component),
+		});
+
+		self
+	}
+
+	/// Starts every component not yet started, in registration order.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once every component runs.
+	///
+	/// # Errors
+	///
+	/// `Error::Start` if a component failed to start. The components started
+	/// before it are shut down again, in reverse order, and the error
+	/// carries their report.
+	pub async fn Start(&mut self) -> Result<(), Error> {
+		while let Some(Entry) = self.Component.get_mut(self.Started) {
+			info!("Starting component {}", Entry.Name);
+
+			if let Err(Reason) = Entry.Component.Start().await {
+				let Component = Entry.Name.clone();
+
+				error!("Component {} failed to start: {}", Component, Reason);
+
+				let Rollback = self.Shutdown().await;
+
+				return Err(Error::Start { Component, Reason, Rollback });
+			}
+
+			self.Started += 1;
+		}
+
+		Ok(())
+	}
+
+	/// Shuts every started component down, in reverse registration order.
+	///
+	/// Each component gets its own deadline, counted from when its shutdown
+	/// begins. A component still stopping at its deadline is reported as
+	/// `Expired` and the next one is shut down regardless.
+	///
+	/// # Returns
+	///
+	/// The report of every shutdown, in the order they ran.
+	pub async fn Shutdown(&mut self) -> Report::Struct {
+		let mut Report = Report::Struct::default();
+
+		while self.Started > 0 {
+			self.Started -= 1;
+
+			let Entry = &mut self.Component[self.Started];
+
+			info!("Shutting down component {}", Entry.Name);
+
+			let Begin = self.Runtime.Now();
+
+			let Deadline = Begin + Entry.Deadline;
+
+			let Stopping = Entry.Component.Shutdown(Deadline);
+
+			let Outcome = match self.Runtime.TimeoutAt(Deadline, Stopping).await {
+				Some(Ok(())) => Outcome::Stopped,
+				// A component giving up at its deadline did not stop in time
+				Some(Err(_)) if self.Runtime.Now() >= Deadline => Outcome::Expired,
+				Some(Err(Reason)) => Outcome::Failed(Reason),
+				None => Outcome::Expired,
+			};
+
+			if Outcome != Outcome::Stopped {
+				warn!("Component {} did not stop cleanly: {:?}", Entry.Name, Outcome);
+			}
+
+			Report.Component.push(Stop::Struct {
+				Name:Entry.Name.clone(),
+				Outcome,
+				Elapsed:self.Runtime.Now().duration_since(Begin),
+			});
+		}
+
+		Report
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New() }
+}
+
+use std::{sync::Arc, time::Duration};
+
+use log::{error, info, warn};
+
+use crate::{
+	Enum::Supervisor::{Error::Enum as Error, Outcome::Enum as Outcome},
+	Struct::Runtime::Tokio,
+	Trait::{Runtime::Trait as Runtime, Supervisor::Component::Trait as Component},
+};
+
+pub mod Report;
+
+pub mod Stop;
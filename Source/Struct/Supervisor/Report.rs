@@ -0,0 +1,20 @@
+/// The consolidated shutdown of every started component of a `Supervisor`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Struct {
+	/// The shutdown of every component, in the order they were stopped.
+	pub Component:Vec<Stop>,
+}
+
+impl Struct {
+	/// Returns whether every component stopped in time without an error.
+	pub fn Clean(&self) -> bool {
+		self.Component.iter().all(|Stop| Stop.Outcome == Outcome::Stopped)
+	}
+
+	/// Returns the components that did not stop cleanly.
+	pub fn Unclean(&self) -> Vec<&Stop> {
+		self.Component.iter().filter(|Stop| Stop.Outcome != Outcome::Stopped).collect()
+	}
+}
+
+use crate::{Enum::Supervisor::Outcome::Enum as Outcome, Struct::Supervisor::Stop::Struct as Stop};
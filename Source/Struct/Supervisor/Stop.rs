@@ -0,0 +1,16 @@
+/// The shutdown of one component of a `Supervisor`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Struct {
+	/// The name the component was registered under.
+	pub Name:String,
+
+	/// How the shutdown ended.
+	pub Outcome:Outcome,
+
+	/// How long the shutdown took, or was waited on.
+	pub Elapsed:Duration,
+}
+
+use std::time::Duration;
+
+use crate::Enum::Supervisor::Outcome::Enum as Outcome;
@@ -0,0 +1,56 @@
+pub mod Codec {
+	pub mod Json;
+}
+
+pub mod Frame {
+	pub mod Idle;
+
+	pub mod Length {
+		pub mod Reader;
+
+		pub mod Writer;
+	}
+
+	pub mod Line {
+		pub mod Reader;
+
+		pub mod Writer;
+	}
+
+	pub mod Socket {
+		pub mod Reader;
+
+		pub mod Writer;
+	}
+}
+
+pub mod Catalogue;
+
+pub mod Chunked;
+
+pub mod Drain;
+
+pub mod Handoff;
+
+pub mod History;
+
+pub mod Ingest;
+
+pub mod Job;
+
+pub mod Order;
+
+pub mod Partial;
+
+pub mod Pump;
+
+#[cfg(feature = "Stdio")]
+pub mod Stdio;
+
+#[cfg(feature = "Tcp")]
+pub mod Tcp;
+
+pub mod Terminal;
+
+#[cfg(all(unix, feature = "Unix"))]
+pub mod Unix;
@@ -0,0 +1,17 @@
+/// One entry of the error code catalogue, as listed by `Describe`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Catalogue"))]
+pub struct Struct {
+	/// The code carried by `Error` replies.
+	pub Code:Code,
+
+	/// Whether submitting the action again may succeed.
+	pub Retryable:bool,
+
+	/// What the code means.
+	pub Description:String,
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Enum::Transport::Code::Enum as Code;
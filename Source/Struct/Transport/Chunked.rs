@@ -0,0 +1,181 @@
+/// A result sent in chunks, by a pump built with `WithChunking`.
+///
+/// The result lies in the result store of the pump's `Offload`, and every
+/// chunk is read from there as it is sent, so a large result is never held
+/// whole. The chunks go out as `ResultChunk` replies, in order, followed by
+/// a `ResultEnd` carrying the SHA-256 of their bytes; if a chunk cannot be
+/// read, the transfer ends with a `ResultAbort` instead.
+///
+/// A chunk is read before the transfer moves on, so a `Next` abandoned
+/// halfway, e.g. by a `select!`, reads the same chunk again.
+pub struct Struct {
+	/// The identifier of the submission.
+	Id:String,
+
+	/// Where the encoded result is kept.
+	Stored:Stored,
+
+	/// The attempts the action took.
+	History:History,
+
+	/// The annotations the action wrote while running.
+	Annotation:Map<String, Value>,
+
+	/// Where the result is read from.
+	Offload:Arc<Offload::Struct>,
+
+	/// The most bytes per chunk.
+	Size:usize,
+
+	/// The position of the next chunk.
+	Index:u64,
+
+	/// The number of chunks.
+	Total:u64,
+
+	/// The hash of the chunks sent so far.
+	Digest:Sha256::Stream,
+
+	/// Whether the `ResultEnd` or `ResultAbort` was produced.
+	Ended:bool,
+}
+
+impl Struct {
+	/// Starts the transfer of a result whose value is a `$stored` marker.
+	///
+	/// # Arguments
+	///
+	/// * `Reply` - The reply that would carry the result.
+	/// * `Offload` - Where the result is kept.
+	/// * `Size` - The most bytes per chunk.
+	///
+	/// # Returns
+	///
+	/// The transfer, or `None` if the reply is not a `Result` holding a
+	/// marker.
+	pub fn New(Reply:&Reply, Offload:&Arc<Offload::Struct>, Size:usize) -> Option<Self> {
+		let Reply::Result { Id, Value, History, Annotation, .. } = Reply else {
+			return None;
+		};
+
+		let Stored = Stored::Parse(Value)?;
+
+		let Size = Size.max(1);
+
+		counter!("echo_result_chunked_total").increment(1);
+
+		Some(Struct {
+			Id:Id.clone(),
+			Total:Stored.Size.div_ceil(Size as u64),
+			Stored,
+			History:History.clone(),
+			Annotation:Annotation.clone(),
+			Offload:Offload.clone(),
+			Size,
+			Index:0,
+			Digest:Sha256::Stream::New(),
+			Ended:false,
+		})
+	}
+
+	/// Returns the identifier of the submission.
+	pub fn Id(&self) -> &str { &self.Id }
+
+	/// Returns whether the last reply of the transfer was produced.
+	pub fn Done(&self) -> bool { self.Ended }
+
+	/// Produces the next reply of the transfer: a chunk, then the end.
+	///
+	/// # Returns
+	///
+	/// The `ResultChunk` read from the store, the `ResultEnd` once every
+	/// chunk was produced, or a `ResultAbort` if the store cannot give the
+	/// chunk back.
+	pub async fn Next(&mut self) -> Reply {
+		if self.Index >= self.Total {
+			self.Ended = true;
+
+			return Reply::ResultEnd {
+				Id:self.Id.clone(),
+				Digest:Hex(&std::mem::take(&mut self.Digest).Finish()),
+				History:self.History.clone(),
+				Annotation:self.Annotation.clone(),
+			};
+		}
+
+		let Offset = self.Index * self.Size as u64;
+
+		let Expected = (self.Stored.Size - Offset).min(self.Size as u64) as usize;
+
+		let Data = match self.Offload.Range(&self.Stored, Offset, self.Size).await {
+			Ok(Data) if Data.len() == Expected => Data,
+			Ok(Data) => {
+				return self.Abort(format!(
+					"Chunk {} of the stored result {} has {} bytes, not {}",
+					self.Index,
+					self.Stored.Ref,
+					Data.len(),
+					Expected
+				));
+			},
+			Err(_Error) => {
+				return self.Abort(format!(
+					"Cannot read chunk {} of the stored result {}: {}",
+					self.Index, self.Stored.Ref, _Error
+				));
+			},
+		};
+
+		self.Digest.Update(&Data);
+
+		let Index = self.Index;
+
+		self.Index += 1;
+
+		Reply::ResultChunk {
+			Id:self.Id.clone(),
+			Index,
+			Total:self.Total,
+			Data:STANDARD.encode(Data),
+		}
+	}
+
+	/// Returns the `Result` reply the transfer started from, so it can be
+	/// sent again from its first chunk, e.g. on a connection taking over.
+	pub fn Restart(self) -> Reply {
+		Reply::Result {
+			Id:self.Id,
+			Value:self.Stored.Marker(),
+			History:self.History,
+			Annotation:self.Annotation,
+			OutOfOrder:false,
+		}
+	}
+
+	/// Ends the transfer with a `ResultAbort`.
+	fn Abort(&mut self, Message:String) -> Reply {
+		warn!("Aborted the transfer of {}: {}", self.Id, Message);
+
+		counter!("echo_result_transfer_aborted_total").increment(1);
+
+		self.Ended = true;
+
+		Reply::ResultAbort { Id:self.Id.clone(), Code:Code::TransferAborted, Message }
+	}
+}
+
+use std::sync::Arc;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::warn;
+use metrics::counter;
+use serde_json::{Map, Value};
+
+use crate::{
+	Digest::{Hex, Sha256},
+	Enum::Transport::{Code::Enum as Code, Reply::Enum as Reply},
+	Struct::{
+		Sequence::Attempt::History::Struct as History,
+		Storage::{Offload, Stored::Struct as Stored},
+	},
+};
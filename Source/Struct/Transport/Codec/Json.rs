@@ -0,0 +1,107 @@
+/// The JSON codec, encoding every message as one compact JSON document.
+///
+/// A client may also submit a payload as a binary frame, on a framing that
+/// carries any bytes such as WebSocket or length-prefixed streams: a zero
+/// byte, the length of a JSON `Submit` message as four big-endian bytes,
+/// the message, then the payload. The payload is appended to the message's
+/// `Argument` as `Arg::Bytes`, sharing the frame's buffer rather than
+/// copying it; `EncodeBinary` writes such a frame.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Struct;
+
+/// The first byte of a binary frame, which no JSON document starts with.
+const BINARY:u8 = 0;
+
+impl Struct {
+	/// Encodes a `Submit` message and a payload into a binary frame.
+	///
+	/// # Arguments
+	///
+	/// * `Message` - The `Submit` message, without the payload.
+	/// * `Payload` - The bytes appended to its arguments.
+	///
+	/// # Returns
+	///
+	/// The frame, or a description of why encoding failed.
+	pub fn EncodeBinary(&self, Message:&Message, Payload:&[u8]) -> Result<Vec<u8>, String> {
+		if !matches!(Message, Message::Submit { .. }) {
+			return Err("A binary frame carries a Submit message".to_string());
+		}
+
+		let Header = serde_json::to_vec(Message).map_err(|_Error| _Error.to_string())?;
+
+		let Length = u32::try_from(Header.len())
+			.map_err(|_| format!("Message of {} bytes is too long", Header.len()))?;
+
+		let mut Frame = Vec::with_capacity(5 + Header.len() + Payload.len());
+
+		Frame.push(BINARY);
+
+		Frame.extend_from_slice(&Length.to_be_bytes());
+
+		Frame.extend_from_slice(&Header);
+
+		Frame.extend_from_slice(Payload);
+
+		Ok(Frame)
+	}
+}
+
+impl Codec for Struct {
+	fn Decode(&self, Frame:&[u8]) -> Result<Message, String> {
+		if Frame.first() == Some(&BINARY) {
+			return self.DecodeFrame(Frame.to_vec());
+		}
+
+		serde_json::from_slice(Frame).map_err(|_Error| _Error.to_string())
+	}
+
+	fn DecodeFrame(&self, Frame:Vec<u8>) -> Result<Message, String> {
+		if Frame.first() != Some(&BINARY) {
+			return serde_json::from_slice(&Frame).map_err(|_Error| _Error.to_string());
+		}
+
+		let Length = Frame
+			.get(1..5)
+			.map(|Length| u32::from_be_bytes([Length[0], Length[1], Length[2], Length[3]]))
+			.ok_or("Binary frame lacks the length of its message")? as usize;
+
+		let Header = Frame
+			.get(5..5 + Length)
+			.ok_or("Binary frame is shorter than its message")?;
+
+		let mut Message:Message =
+			serde_json::from_slice(Header).map_err(|_Error| _Error.to_string())?;
+
+		let Message::Submit { Argument, .. } = &mut Message else {
+			return Err("A binary frame carries a Submit message".to_string());
+		};
+
+		// The payload keeps the frame's buffer alive instead of copying it
+		Argument.push(Arg::Bytes(Bytes::from(Frame).slice(5 + Length..)));
+
+		Ok(Message)
+	}
+
+	fn Encode(&self, Reply:&Reply) -> Result<Vec<u8>, String> {
+		serde_json::to_vec(Reply).map_err(|_Error| _Error.to_string())
+	}
+
+	fn EncodeMessage(&self, Message:&Message) -> Result<Vec<u8>, String> {
+		serde_json::to_vec(Message).map_err(|_Error| _Error.to_string())
+	}
+
+	fn DecodeReply(&self, Frame:&[u8]) -> Result<Reply, String> {
+		serde_json::from_slice(Frame).map_err(|_Error| _Error.to_string())
+	}
+}
+
+use bytes::Bytes;
+
+use crate::{
+	Enum::{
+		Sequence::Action::Arg::Enum as Arg,
+		Transport::{Message::Enum as Message, Reply::Enum as Reply},
+	},
+	Trait::Transport::Codec::Trait as Codec,
+};
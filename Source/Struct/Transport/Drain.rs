@@ -0,0 +1,28 @@
+/// What happened while a pump was drained.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Drain"))]
+pub struct Struct {
+	/// Whether all work finished before the deadline.
+	pub Drained:bool,
+
+	/// The number of actions that completed during the drain.
+	pub Completed:u64,
+
+	/// The number of actions that failed during the drain.
+	pub Failed:u64,
+
+	/// The number of actions still queued or running at the end.
+	pub Remaining:u64,
+
+	/// How long the drain took, in milliseconds.
+	pub Elapsed:u64,
+
+	/// The actions still executing when the deadline expired, the longest
+	/// running first.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub Abandoned:Vec<InFlight>,
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Sequence::Watchdog::InFlight::Struct as InFlight;
@@ -0,0 +1,66 @@
+/// Ends a stream that stays silent for too long.
+///
+/// Wraps another reader; a read that receives no frame within the timeout
+/// fails with `TimedOut`, which makes the pump stop reading and drain.
+pub struct Struct<R> {
+	/// The wrapped reader.
+	Reader:R,
+
+	/// How long a read may wait for a frame.
+	Timeout:Duration,
+
+	/// The runtime measuring the timeout.
+	Runtime:Arc<dyn Runtime>,
+}
+
+impl<R:Reader> Struct<R> {
+	/// Wraps a reader with an idle timeout.
+	///
+	/// # Arguments
+	///
+	/// * `Reader` - The reader to wrap.
+	/// * `Timeout` - How long a read may wait for a frame.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Reader:R, Timeout:Duration) -> Self {
+		Struct { Reader, Timeout, Runtime:Arc::new(Tokio::Struct) }
+	}
+
+	/// Sets the runtime measuring the timeout, tokio's unless set.
+	///
+	/// # Arguments
+	///
+	/// * `Runtime` - The runtime, e.g. the one of the pump's `Life`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRuntime(mut self, Runtime:Arc<dyn Runtime>) -> Self {
+		self.Runtime = Runtime;
+
+		self
+	}
+}
+
+#[async_trait]
+impl<R:Reader> Reader for Struct<R> {
+	async fn Read(&mut self) -> io::Result<Option<Vec<u8>>> {
+		self.Runtime.Timeout(self.Timeout, self.Reader.Read()).await.ok_or_else(|| {
+			io::Error::new(
+				io::ErrorKind::TimedOut,
+				format!("No frame received for {:?}", self.Timeout),
+			)
+		})?
+	}
+}
+
+use std::{io, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+
+use crate::{
+	Struct::Runtime::Tokio,
+	Trait::{Runtime::Trait as Runtime, Transport::Reader::Trait as Reader},
+};
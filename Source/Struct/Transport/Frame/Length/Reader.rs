@@ -0,0 +1,61 @@
+/// Reads length-prefixed frames.
+///
+/// Each frame is a big-endian `u32` byte count followed by that many bytes.
+/// Frames larger than the limit are rejected with `InvalidData`, since the
+/// stream cannot be resynchronised after an oversized prefix.
+pub struct Struct<R> {
+	/// The stream being read.
+	Stream:R,
+
+	/// The largest accepted frame, in bytes.
+	Limit:usize,
+}
+
+impl<R:AsyncRead + Unpin + Send> Struct<R> {
+	/// Creates a new length-prefixed reader.
+	///
+	/// # Arguments
+	///
+	/// * `Stream` - The stream to read from.
+	/// * `Limit` - The largest accepted frame, in bytes.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Stream:R, Limit:usize) -> Self { Struct { Stream, Limit } }
+}
+
+#[async_trait]
+impl<R:AsyncRead + Unpin + Send> Reader for Struct<R> {
+	async fn Read(&mut self) -> io::Result<Option<Vec<u8>>> {
+		let mut Prefix = [0u8; 4];
+
+		match self.Stream.read_exact(&mut Prefix).await {
+			Ok(_) => {},
+			Err(_Error) if _Error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+			Err(_Error) => return Err(_Error),
+		}
+
+		let Size = u32::from_be_bytes(Prefix) as usize;
+
+		if Size > self.Limit {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!("Frame of {} bytes exceeds the limit of {}", Size, self.Limit),
+			));
+		}
+
+		let mut Frame = vec![0u8; Size];
+
+		self.Stream.read_exact(&mut Frame).await?;
+
+		Ok(Some(Frame))
+	}
+}
+
+use std::io;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::Trait::Transport::Reader::Trait as Reader;
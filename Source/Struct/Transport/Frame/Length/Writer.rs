@@ -0,0 +1,42 @@
+/// Writes length-prefixed frames.
+///
+/// Each frame is written as a big-endian `u32` byte count followed by the
+/// frame itself.
+pub struct Struct<W> {
+	/// The stream being written.
+	Stream:W,
+}
+
+impl<W:AsyncWrite + Unpin + Send> Struct<W> {
+	/// Creates a new length-prefixed writer.
+	///
+	/// # Arguments
+	///
+	/// * `Stream` - The stream to write to.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Stream:W) -> Self { Struct { Stream } }
+}
+
+#[async_trait]
+impl<W:AsyncWrite + Unpin + Send> Writer for Struct<W> {
+	async fn Write(&mut self, Frame:&[u8]) -> io::Result<()> {
+		let Size = u32::try_from(Frame.len())
+			.map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Frame exceeds 4 GiB"))?;
+
+		self.Stream.write_all(&Size.to_be_bytes()).await?;
+
+		self.Stream.write_all(Frame).await?;
+
+		self.Stream.flush().await
+	}
+}
+
+use std::io;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::Trait::Transport::Writer::Trait as Writer;
@@ -0,0 +1,49 @@
+/// Reads newline-delimited frames.
+///
+/// Each line is one frame; the trailing `\n` (and `\r`, if any) is removed
+/// and blank lines are skipped.
+pub struct Struct<R> {
+	/// The buffered stream being read.
+	Stream:BufReader<R>,
+}
+
+impl<R:AsyncRead + Unpin + Send> Struct<R> {
+	/// Creates a new line reader.
+	///
+	/// # Arguments
+	///
+	/// * `Stream` - The stream to read from.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Stream:R) -> Self { Struct { Stream:BufReader::new(Stream) } }
+}
+
+#[async_trait]
+impl<R:AsyncRead + Unpin + Send> Reader for Struct<R> {
+	async fn Read(&mut self) -> io::Result<Option<Vec<u8>>> {
+		loop {
+			let mut Line = Vec::new();
+
+			if self.Stream.read_until(b'\n', &mut Line).await? == 0 {
+				return Ok(None);
+			}
+
+			while matches!(Line.last(), Some(b'\n' | b'\r')) {
+				Line.pop();
+			}
+
+			if !Line.iter().all(u8::is_ascii_whitespace) {
+				return Ok(Some(Line));
+			}
+		}
+	}
+}
+
+use std::io;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+use crate::Trait::Transport::Reader::Trait as Reader;
@@ -0,0 +1,38 @@
+/// Writes newline-delimited frames.
+///
+/// Frames must not contain a newline themselves; compact JSON never does.
+pub struct Struct<W> {
+	/// The stream being written.
+	Stream:W,
+}
+
+impl<W:AsyncWrite + Unpin + Send> Struct<W> {
+	/// Creates a new line writer.
+	///
+	/// # Arguments
+	///
+	/// * `Stream` - The stream to write to.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Stream:W) -> Self { Struct { Stream } }
+}
+
+#[async_trait]
+impl<W:AsyncWrite + Unpin + Send> Writer for Struct<W> {
+	async fn Write(&mut self, Frame:&[u8]) -> io::Result<()> {
+		self.Stream.write_all(Frame).await?;
+
+		self.Stream.write_all(b"\n").await?;
+
+		self.Stream.flush().await
+	}
+}
+
+use std::io;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::Trait::Transport::Writer::Trait as Writer;
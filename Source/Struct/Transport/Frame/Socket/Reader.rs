@@ -0,0 +1,56 @@
+/// Reads frames from WebSocket messages.
+///
+/// Wraps the receiving half of a split `tokio-tungstenite` stream, so a
+/// WebSocket can be served by the shared message pump. Text and binary
+/// messages are frames. Pings and pongs carry no frame and are skipped;
+/// `tungstenite` queues the answer to a ping itself, sent with the next
+/// write. A close message ends the stream like the end of a byte stream, so
+/// the pump drains pending replies; an error of the WebSocket is returned as
+/// an I/O error, ending the connection.
+pub struct Struct<S> {
+	/// The receiving half of the WebSocket.
+	Stream:S,
+}
+
+impl<S> Struct<S>
+where
+	S: Stream<Item = Result<Message, WsError>> + Unpin + Send,
+{
+	/// Creates a new WebSocket reader.
+	///
+	/// # Arguments
+	///
+	/// * `Stream` - The receiving half of the WebSocket.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Stream:S) -> Self { Struct { Stream } }
+}
+
+#[async_trait]
+impl<S> Reader for Struct<S>
+where
+	S: Stream<Item = Result<Message, WsError>> + Unpin + Send,
+{
+	async fn Read(&mut self) -> io::Result<Option<Vec<u8>>> {
+		loop {
+			match self.Stream.next().await {
+				Some(Ok(Message::Text(Text))) => return Ok(Some(Text.into_bytes())),
+				Some(Ok(Message::Binary(Frame))) => return Ok(Some(Frame)),
+				Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => {},
+				Some(Ok(Message::Close(_))) | None => return Ok(None),
+				Some(Err(WsError::ConnectionClosed)) => return Ok(None),
+				Some(Err(_Error)) => return Err(io::Error::other(_Error)),
+			}
+		}
+	}
+}
+
+use std::io;
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+
+use crate::Trait::Transport::Reader::Trait as Reader;
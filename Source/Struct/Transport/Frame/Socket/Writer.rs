@@ -0,0 +1,55 @@
+/// Writes frames as WebSocket messages.
+///
+/// Wraps the sending half of a split `tokio-tungstenite` stream, so anything
+/// producing frames (such as an event `Subscription`) can feed a WebSocket.
+/// UTF-8 frames are sent as text messages, others as binary messages.
+pub struct Struct<S> {
+	/// The sending half of the WebSocket.
+	Sink:S,
+}
+
+impl<S> Struct<S>
+where
+	S: Sink<Message, Error = WsError> + Unpin + Send,
+{
+	/// Creates a new WebSocket writer.
+	///
+	/// # Arguments
+	///
+	/// * `Sink` - The sending half of the WebSocket.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Sink:S) -> Self { Struct { Sink } }
+}
+
+#[async_trait]
+impl<S> Writer for Struct<S>
+where
+	S: Sink<Message, Error = WsError> + Unpin + Send,
+{
+	async fn Write(&mut self, Frame:&[u8]) -> io::Result<()> {
+		let Message = match String::from_utf8(Frame.to_vec()) {
+			Ok(Text) => Message::Text(Text),
+			Err(_Error) => Message::Binary(_Error.into_bytes()),
+		};
+
+		self.Sink.send(Message).await.map_err(io::Error::other)
+	}
+
+	async fn Close(&mut self) -> io::Result<()> {
+		match self.Sink.close().await {
+			Ok(()) | Err(WsError::ConnectionClosed | WsError::AlreadyClosed) => Ok(()),
+			Err(_Error) => Err(io::Error::other(_Error)),
+		}
+	}
+}
+
+use std::io;
+
+use async_trait::async_trait;
+use futures::{Sink, SinkExt};
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+
+use crate::Trait::Transport::Writer::Trait as Writer;
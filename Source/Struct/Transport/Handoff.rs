@@ -0,0 +1,57 @@
+/// What a handoff of queued actions between two processes moved.
+///
+/// A handoff runs over an admin connection to the old process. The new
+/// process, through a `Receiver`, sends `Handoff`; the old process pauses
+/// its Karma consumers and answers with `HandoffChunk` replies, each
+/// carrying up to the requested number of queued actions taken out of
+/// their queues. The new process enqueues the actions of a chunk into its
+/// own Karma queues, routed by their `Queue` metadata, and acknowledges the
+/// chunk with `HandoffAck`. Only then does the old process complete the
+/// actions of the chunk as transferred and send the next one. Once nothing
+/// is left, it answers with `HandoffDone` carrying its summary, which both
+/// sides log; the old consumers stay paused.
+///
+/// A chunk not acknowledged when the connection ends goes back into the old
+/// queues, in its original order, and the old consumers resume. Every
+/// action carries a key unique in the old process, so a `Receiver` handed
+/// a chunk again after a reconnection skips what it already enqueued.
+/// Actions in flight are not handed off, and neither are those that are not
+/// calls of a plan function, as they cannot be serialized; they are counted
+/// as kept.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Handoff"))]
+pub struct Struct {
+	/// The chunks acknowledged.
+	pub Chunks:u64,
+
+	/// The actions moved to the new process.
+	pub Transferred:u64,
+
+	/// The actions left queued in the old process because they cannot be
+	/// serialized.
+	pub Kept:u64,
+
+	/// The actions received again after a reconnection and skipped.
+	pub Duplicate:u64,
+
+	/// The actions the new process could not enqueue.
+	pub Refused:u64,
+}
+
+impl Display for Struct {
+	fn fmt(&self, f:&mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{} transferred in {} chunks, {} kept, {} duplicate, {} refused",
+			self.Transferred, self.Chunks, self.Kept, self.Duplicate, self.Refused
+		)
+	}
+}
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+pub mod Receiver;
+pub mod Sender;
+pub mod Transfer;
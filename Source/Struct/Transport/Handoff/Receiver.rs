@@ -0,0 +1,223 @@
+/// The new side of a handoff: asks a pump for the queued actions of its
+/// process and enqueues them into the Karma queues of a `Life`.
+///
+/// The keys of the actions enqueued are kept across `Run` calls, so running
+/// again after a lost connection skips the actions of a chunk enqueued but
+/// not acknowledged.
+pub struct Struct {
+	/// The lifecycle the actions are enqueued into.
+	Life:Life,
+
+	/// The plan providing the functions of the actions.
+	Plan:Arc<Formality>,
+
+	/// The codec encoding messages and decoding replies.
+	Codec:Arc<dyn Codec>,
+
+	/// The admin token presented to the pump, if any.
+	Token:Option<String>,
+
+	/// The most actions per chunk asked for.
+	Chunk:Option<usize>,
+
+	/// The keys of the actions enqueued.
+	Seen:HashSet<String>,
+
+	/// What this side received so far.
+	Summary:Handoff::Struct,
+}
+
+impl Struct {
+	/// Creates a new instance.
+	///
+	/// # Arguments
+	///
+	/// * `Life` - The lifecycle the actions are enqueued into.
+	/// * `Plan` - The plan providing the functions of the actions.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Life:Life, Plan:Arc<Formality>) -> Self {
+		Struct {
+			Life,
+			Plan,
+			Codec:Arc::new(Json::Struct),
+			Token:None,
+			Chunk:None,
+			Seen:HashSet::new(),
+			Summary:Handoff::Struct::default(),
+		}
+	}
+
+	/// Sets the codec, which must match the pump's.
+	///
+	/// # Arguments
+	///
+	/// * `Codec` - The codec encoding messages and decoding replies.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCodec(mut self, Codec:Arc<dyn Codec>) -> Self {
+		self.Codec = Codec;
+
+		self
+	}
+
+	/// Authenticates with a token granting the `Admin` role.
+	///
+	/// # Arguments
+	///
+	/// * `Token` - The admin token of the pump.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithToken(mut self, Token:&str) -> Self {
+		self.Token = Some(Token.to_string());
+
+		self
+	}
+
+	/// Sets the most actions per chunk; the pump's default when unset.
+	///
+	/// # Arguments
+	///
+	/// * `Chunk` - The most actions per chunk.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithChunk(mut self, Chunk:usize) -> Self {
+		self.Chunk = Some(Chunk);
+
+		self
+	}
+
+	/// Runs a handoff over a connection to the old process.
+	///
+	/// # Arguments
+	///
+	/// * `Reader` - The reading half of the framed stream.
+	/// * `Writer` - The writing half of the framed stream.
+	///
+	/// # Returns
+	///
+	/// What this side received across every run, with the actions the old
+	/// process kept, or an error if the connection ended before
+	/// `HandoffDone` or the pump refused the handoff.
+	pub async fn Run(
+		&mut self,
+		mut Reader:impl Reader,
+		mut Writer:impl Writer,
+	) -> io::Result<Handoff::Struct> {
+		if let Some(Token) = &self.Token {
+			self.Send(
+				&mut Writer,
+				&Message::Auth { Token:Token.clone(), Delivery:Delivery::default() },
+			)
+			.await?;
+		}
+
+		self.Send(&mut Writer, &Message::Handoff { Chunk:self.Chunk }).await?;
+
+		loop {
+			let Frame = Reader.Read().await?.ok_or_else(|| {
+				io::Error::new(
+					io::ErrorKind::UnexpectedEof,
+					"Connection ended before the handoff did",
+				)
+			})?;
+
+			let Reply = self
+				.Codec
+				.DecodeReply(&Frame)
+				.map_err(|_Error| io::Error::new(io::ErrorKind::InvalidData, _Error))?;
+
+			match Reply {
+				Reply::Authenticated { .. } => {},
+				Reply::HandoffChunk { Chunk, Action } => {
+					for Transfer in Action {
+						self.Enqueue(Transfer).await;
+					}
+
+					self.Summary.Chunks += 1;
+
+					self.Send(&mut Writer, &Message::HandoffAck { Chunk }).await?;
+				},
+				Reply::HandoffDone { Handoff } => {
+					self.Summary.Kept = Handoff.Kept;
+
+					info!("Received the handoff: {}", self.Summary);
+
+					return Ok(self.Summary.clone());
+				},
+				Reply::Error { Message, .. } | Reply::Denied { Message } => {
+					return Err(io::Error::new(io::ErrorKind::PermissionDenied, Message));
+				},
+				_ => {},
+			}
+		}
+	}
+
+	/// Enqueues a handed off action, unless it was enqueued before.
+	async fn Enqueue(&mut self, Transfer:Transfer::Struct) {
+		if self.Seen.contains(&Transfer.Key) {
+			self.Summary.Duplicate += 1;
+
+			return;
+		}
+
+		let Key = Transfer.Key.clone();
+
+		let Action = Transfer.Into(self.Plan.clone());
+
+		let Enqueued = match Route::Fn(&self.Life, Action.as_ref()).await {
+			Ok(Production) => Production.Enqueue(Action).await.map(|_| ()),
+			Err(_Error) => Err(_Error),
+		};
+
+		match Enqueued {
+			Ok(()) => {
+				self.Seen.insert(Key);
+
+				self.Summary.Transferred += 1;
+			},
+			Err(_Error) => {
+				warn!("Cannot enqueue the handed off action {}: {}", Key, _Error);
+
+				self.Seen.insert(Key);
+
+				self.Summary.Refused += 1;
+			},
+		}
+	}
+
+	/// Encodes and writes a message.
+	async fn Send(&self, Writer:&mut impl Writer, Message:&Message) -> io::Result<()> {
+		let Frame = self
+			.Codec
+			.EncodeMessage(Message)
+			.map_err(|_Error| io::Error::new(io::ErrorKind::InvalidData, _Error))?;
+
+		Writer.Write(&Frame).await
+	}
+}
+
+use std::{collections::HashSet, io, sync::Arc};
+
+use log::{info, warn};
+
+use crate::{
+	Enum::Transport::{Delivery::Enum as Delivery, Message::Enum as Message, Reply::Enum as Reply},
+	Fn::Route,
+	Struct::{
+		Sequence::{Life::Struct as Life, Plan::Formality::Struct as Formality},
+		Transport::{
+			Codec::Json,
+			Handoff::{self, Transfer},
+		},
+	},
+	Trait::Transport::{Codec::Trait as Codec, Reader::Trait as Reader, Writer::Trait as Writer},
+};
@@ -0,0 +1,306 @@
+/// The old side of a handoff: takes the queued actions out of the Karma
+/// queues of a `Life` chunk by chunk, for the pump connection that asked for
+/// them.
+///
+/// Creating it pauses the Karma consumers. Dropping it with a chunk not
+/// acknowledged puts the actions of the chunk back ahead of those still
+/// queued and resumes the consumers; after `HandoffDone` they stay paused.
+pub struct Struct {
+	/// The lifecycle whose queues are handed off.
+	Life:Life,
+
+	/// The most actions per chunk.
+	Chunk:usize,
+
+	/// The number of the last chunk sent.
+	Number:u64,
+
+	/// The actions of the last chunk, until it is acknowledged.
+	Outstanding:Vec<Outstanding>,
+
+	/// What the handoff moved so far.
+	Summary:Handoff::Struct,
+
+	/// Whether `HandoffDone` was sent.
+	Done:bool,
+
+	/// Held until the actions of an interrupted handoff are back.
+	Guard:Option<OwnedMutexGuard<()>>,
+}
+
+/// An action sent in the last chunk, with the queue it was taken from.
+struct Outstanding {
+	/// The name of the queue.
+	Queue:String,
+
+	/// The production line of the queue.
+	Production:Arc<Production>,
+
+	/// The stamp the action received when it was enqueued.
+	Stamp:Stamp::Struct,
+
+	/// The metadata of the action among `Transfer::KEYS`.
+	Metadata:Map<String, Value>,
+
+	/// The action itself.
+	Action:Box<dyn Action>,
+}
+
+impl Struct {
+	/// Pauses the Karma consumers of a lifecycle for a handoff, once no
+	/// other handoff runs.
+	///
+	/// # Arguments
+	///
+	/// * `Life` - The lifecycle whose queues are handed off.
+	/// * `Chunk` - The most actions per chunk, at least one.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub async fn New(Life:Life, Chunk:usize) -> Self {
+		let Guard = Life.Handoff.Begin().await;
+
+		Life.Handoff.Pause();
+
+		Struct {
+			Life,
+			Chunk:Chunk.max(1),
+			Number:0,
+			Outstanding:Vec::new(),
+			Summary:Handoff::Struct::default(),
+			Done:false,
+			Guard:Some(Guard),
+		}
+	}
+
+	/// Answers a `Handoff` message or an acknowledged chunk.
+	///
+	/// # Returns
+	///
+	/// The chunk not acknowledged yet, the next chunk, or `HandoffDone` once
+	/// no queued action can be handed off.
+	pub async fn Next(&mut self) -> Reply {
+		if self.Outstanding.is_empty() && !self.Done {
+			self.Take().await;
+		}
+
+		if self.Outstanding.is_empty() {
+			if !self.Done {
+				self.Done = true;
+
+				self.Summary.Kept = self.Kept().await;
+
+				info!("Handed off the Karma queues: {}", self.Summary);
+			}
+
+			return Reply::HandoffDone { Handoff:self.Summary.clone() };
+		}
+
+		Reply::HandoffChunk {
+			Chunk:self.Number,
+			Action:self
+				.Outstanding
+				.iter()
+				.filter_map(|Outstanding| {
+					Transfer::Struct::New(
+						&Outstanding.Queue,
+						Outstanding.Stamp.Sequence,
+						Outstanding.Metadata.clone(),
+					)
+				})
+				.collect(),
+		}
+	}
+
+	/// Completes the actions of the last chunk as handed off.
+	///
+	/// # Arguments
+	///
+	/// * `Chunk` - The number of the chunk acknowledged.
+	///
+	/// # Returns
+	///
+	/// An error if the chunk is not the one waiting for acknowledgement.
+	pub fn Ack(&mut self, Chunk:u64) -> Result<(), String> {
+		if Chunk != self.Number || self.Outstanding.is_empty() {
+			return Err(format!("Chunk {} is not waiting for acknowledgement", Chunk));
+		}
+
+		let mut Affected = BTreeMap::<String, Vec<u64>>::new();
+
+		for Outstanding in self.Outstanding.drain(..) {
+			Outstanding.Production.Complete(
+				Outstanding.Stamp.Sequence,
+				Err(Error::Abandoned("Handed off".to_string())),
+			);
+
+			Affected
+				.entry(Outstanding.Queue)
+				.or_default()
+				.push(Outstanding.Stamp.Sequence);
+		}
+
+		self.Summary.Chunks += 1;
+
+		for (Queue, Sequence) in Affected {
+			self.Summary.Transferred += Sequence.len() as u64;
+
+			counter!("echo_handoff_transferred_total").increment(Sequence.len() as u64);
+
+			self.Life.Bus.Emit(|| Event::Audit {
+				Operation:"Handoff.Transfer".to_string(),
+				Queue,
+				Sequence,
+				Target:None,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Takes the next chunk out of the queues, in queue name and dequeue
+	/// order.
+	async fn Take(&mut self) {
+		let mut Lines = self.Life.Lines();
+
+		Lines.sort_by(|(Left, _), (Right, _)| Left.cmp(Right));
+
+		for (Queue, Production) in Lines {
+			let Free = self.Chunk - self.Outstanding.len();
+
+			if Free == 0 {
+				break;
+			}
+
+			let mut Metadata = Production
+				.Inspect(&Transfer::KEYS)
+				.await
+				.into_iter()
+				.filter(|(_, Metadata)| Metadata.get("Action").is_some_and(Value::is_string))
+				.take(Free)
+				.map(|(Stamp, Metadata)| (Stamp.Sequence, Metadata))
+				.collect::<HashMap<_, _>>();
+
+			let Sequence = Metadata.keys().copied().collect::<Vec<_>>();
+
+			for (Stamp, Action) in Production.Extract(&Sequence).await {
+				self.Outstanding.push(Outstanding {
+					Queue:Queue.clone(),
+					Production:Production.clone(),
+					Stamp,
+					Metadata:Metadata.remove(&Stamp.Sequence).unwrap_or_default(),
+					Action,
+				});
+			}
+		}
+
+		if !self.Outstanding.is_empty() {
+			self.Number += 1;
+		}
+	}
+
+	/// Counts the queued actions that cannot be handed off.
+	async fn Kept(&self) -> u64 {
+		let mut Kept = 0;
+
+		for (_, Production) in self.Life.Lines() {
+			Kept += Production
+				.Inspect(&Transfer::KEYS[..1])
+				.await
+				.iter()
+				.filter(|(_, Metadata)| !Metadata.get("Action").is_some_and(Value::is_string))
+				.count() as u64;
+		}
+
+		Kept
+	}
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		let (Outstanding, Guard) = (std::mem::take(&mut self.Outstanding), self.Guard.take());
+
+		if self.Done {
+			return;
+		}
+
+		if Outstanding.is_empty() {
+			self.Life.Handoff.Resume();
+
+			return;
+		}
+
+		warn!(
+			"Handoff interrupted: putting {} actions of chunk {} back",
+			Outstanding.len(),
+			self.Number
+		);
+
+		let Handoff = self.Life.Handoff.clone();
+
+		self.Life.Runtime().Spawn(Box::pin(async move {
+			let mut Lines = Vec::<(Arc<Production>, Vec<Outstanding>)>::new();
+
+			for Outstanding in Outstanding {
+				match Lines
+					.iter_mut()
+					.find(|(Production, _)| Arc::ptr_eq(Production, &Outstanding.Production))
+				{
+					Some((_, Line)) => Line.push(Outstanding),
+					None => Lines.push((Outstanding.Production.clone(), vec![Outstanding])),
+				}
+			}
+
+			for (Production, Line) in Lines {
+				// The actions still queued move behind those put back
+				let Queued = Production
+					.Snapshot()
+					.await
+					.iter()
+					.map(|Stamp| Stamp.Sequence)
+					.collect::<Vec<_>>();
+
+				let Queued = Production.Extract(&Queued).await;
+
+				for (Stamp, Action) in Line
+					.into_iter()
+					.map(|Outstanding| (Outstanding.Stamp, Outstanding.Action))
+					.chain(Queued)
+				{
+					Production.Reinject(Stamp, Action).await;
+				}
+			}
+
+			Handoff.Resume();
+
+			drop(Guard);
+		}));
+	}
+}
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::Arc,
+};
+
+use log::{info, warn};
+use metrics::counter;
+use serde_json::{Map, Value};
+use tokio::sync::OwnedMutexGuard;
+
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::Action::Error::Enum as Error,
+		Transport::Reply::Enum as Reply,
+	},
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Production::{Stamp, Struct as Production},
+		},
+		Transport::Handoff::{self, Transfer},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,105 @@
+/// A queued action as handed off in a `HandoffChunk`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Transfer"))]
+pub struct Struct {
+	/// Identifies the action in the old process, as its queue and sequence
+	/// number, e.g. `main#42`.
+	pub Key:String,
+
+	/// The plan function the action calls.
+	pub Action:String,
+
+	/// The arguments of the call.
+	#[serde(default)]
+	pub Argument:Vec<Value>,
+
+	/// The metadata of the action among `KEYS`, including the `Queue` it
+	/// was taken from.
+	#[serde(default)]
+	pub Metadata:Map<String, Value>,
+}
+
+/// The metadata keys carried over with an action.
+pub const KEYS:[&str; 16] = [
+	"Action",
+	"Argument",
+	"Queue",
+	"Hooks",
+	"Delay",
+	"Ttl",
+	"Timeout",
+	"Deadline",
+	"ExpiresAt",
+	"NextAction",
+	"OnSuccess",
+	"OnFailure",
+	"AbsorbFailure",
+	"OutputTo",
+	"IdempotencyKey",
+	"SubmittedBy",
+];
+
+impl Struct {
+	/// Describes a queued action from its metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Queue` - The queue the action waits in.
+	/// * `Sequence` - Its sequence number in that queue.
+	/// * `Metadata` - Its metadata among `KEYS`.
+	///
+	/// # Returns
+	///
+	/// The transfer, or `None` if the action is not a call of a plan
+	/// function.
+	pub fn New(Queue:&str, Sequence:u64, mut Metadata:Map<String, Value>) -> Option<Self> {
+		let Action = match Metadata.remove("Action") {
+			Some(Value::String(Action)) => Action,
+			_ => return None,
+		};
+
+		let Argument = match Metadata.remove("Argument") {
+			Some(Value::Array(Argument)) => Argument,
+			None | Some(Value::Null) => Vec::new(),
+			Some(_) => return None,
+		};
+
+		Metadata
+			.entry("Queue")
+			.or_insert_with(|| Value::String(Queue.to_string()));
+
+		Some(Struct { Key:format!("{}#{}", Queue, Sequence), Action, Argument, Metadata })
+	}
+
+	/// Rebuilds the action to run it with a plan, its metadata named after a
+	/// `Reserved` key as that option.
+	///
+	/// # Arguments
+	///
+	/// * `Plan` - The plan providing the function.
+	pub fn Into(self, Plan:Arc<Formality>) -> Box<dyn Action> {
+		let Action = self.Metadata.into_iter().fold(
+			Echo::New(&self.Action, Value::Null, Plan)
+				.WithOption(Reserved::Argument, Value::Array(self.Argument)),
+			|Action, (Key, Value)| {
+				match Reserved::Parse(&Key) {
+					Some(Key) => Action.WithOption(Key, Value),
+					None => Action.WithMetadata(&Key, Value),
+				}
+			},
+		);
+
+		Box::new(Action)
+	}
+}
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+	Enum::Sequence::Reserved::Enum as Reserved,
+	Struct::Sequence::{Action::Struct as Echo, Plan::Formality::Struct as Formality},
+	Trait::Sequence::Action::Trait as Action,
+};
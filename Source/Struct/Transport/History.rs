@@ -0,0 +1,274 @@
+/// The ingestion-side history of a transport: a record of every submission
+/// it received, accepted or not, queried by identity, time and admission.
+///
+/// Pumps hand records to `Record`, which only queues them, so recording
+/// never holds up the accept path; a task ingests the queue in order,
+/// numbering each record, keeping the latest `Limit` of them in memory and,
+/// if configured, appending them to a storage log. Queries see every record
+/// handed over before they started, but only the ones still in memory.
+pub struct Struct {
+	/// The queue of records waiting to be ingested.
+	Sender:UnboundedSender<Command>,
+
+	/// The receiving end of the queue, held by the ingesting task.
+	Receiver:Arc<AsyncMutex<UnboundedReceiver<Command>>>,
+
+	/// The latest records, oldest first.
+	Record:Mutex<VecDeque<Record::Struct>>,
+
+	/// How many records are kept in memory.
+	Limit:AtomicUsize,
+
+	/// The storage log records are appended to, if any.
+	Log:Mutex<Option<PathBuf>>,
+
+	/// The sequence number of the next record ingested.
+	Next:AtomicU64,
+
+	/// The ingesting task, started on first use.
+	Task:Mutex<Option<Spawned::Struct>>,
+
+	/// The overseer running the task.
+	Overseer:Arc<Overseer>,
+}
+
+/// What the ingesting task is asked to do.
+enum Command {
+	/// Ingest a record.
+	Record(Record::Struct),
+
+	/// Report once every record queued before is ingested.
+	Flush(oneshot::Sender<()>),
+}
+
+impl Struct {
+	/// Creates an empty history keeping 10000 records in memory, without a
+	/// storage log.
+	///
+	/// # Arguments
+	///
+	/// * `Overseer` - The overseer running the ingesting task.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Overseer:Arc<Overseer>) -> Self {
+		let (Sender, Receiver) = unbounded_channel();
+
+		Struct {
+			Sender,
+			Receiver:Arc::new(AsyncMutex::new(Receiver)),
+			Record:Mutex::new(VecDeque::new()),
+			Limit:AtomicUsize::new(10_000),
+			Log:Mutex::new(None),
+			Next:AtomicU64::new(0),
+			Task:Mutex::new(None),
+			Overseer,
+		}
+	}
+
+	/// Changes how many records are kept in memory and where records are
+	/// logged. The oldest records beyond a lowered limit are dropped right
+	/// away.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How many records are kept in memory.
+	/// * `Log` - The storage log records are appended to, or `None` to log
+	///   none.
+	pub fn Configure(&self, Limit:usize, Log:Option<PathBuf>) {
+		self.Limit.store(Limit, Ordering::Relaxed);
+
+		{
+			let mut Record = Lock(&self.Record);
+
+			while Record.len() > Limit {
+				Record.pop_front();
+			}
+		}
+
+		*Lock(&self.Log) = Log;
+	}
+
+	/// Queues a record for ingestion without waiting for it.
+	///
+	/// # Arguments
+	///
+	/// * `Record` - The record; its `Sequence` is assigned on ingestion.
+	pub fn Record(self: &Arc<Self>, Record:Record::Struct) {
+		self.Start();
+
+		let _ = self.Sender.send(Command::Record(Record));
+	}
+
+	/// Selects one page of the records in memory.
+	///
+	/// # Arguments
+	///
+	/// * `Query` - The records to select and the page to return.
+	///
+	/// # Returns
+	///
+	/// The selected records after the `After` cursor, oldest first, at most
+	/// `Limit` of them.
+	pub async fn QuerySubmissions(self: &Arc<Self>, Query:&Query::Struct) -> Page::Struct {
+		self.Start();
+
+		let (Flushed, Ingested) = oneshot::channel();
+
+		if self.Sender.send(Command::Flush(Flushed)).is_ok() {
+			let _ = Ingested.await;
+		}
+
+		let Limit = Query.Limit.clamp(1, 1000);
+
+		let Record = Lock(&self.Record);
+
+		let mut Selected = Record
+			.iter()
+			.filter(|Record| Query.After.is_none_or(|After| Record.Sequence > After))
+			.filter(|Record| Query.Match(Record));
+
+		let Page = Selected.by_ref().take(Limit).cloned().collect::<Vec<_>>();
+
+		// A page is followed by another only if more records are selected
+		let Next = Selected.next().and(Page.last().map(|Record| Record.Sequence));
+
+		Page::Struct { Record:Page, Next }
+	}
+
+	/// Ingests a batch of records.
+	async fn Ingest(&self, mut Batch:Vec<Record::Struct>) {
+		let Limit = self.Limit.load(Ordering::Relaxed);
+
+		for Record in &mut Batch {
+			Record.Sequence = self.Next.fetch_add(1, Ordering::Relaxed);
+		}
+
+		{
+			let mut Record = Lock(&self.Record);
+
+			Record.extend(Batch.iter().cloned());
+
+			while Record.len() > Limit {
+				Record.pop_front();
+			}
+		}
+
+		let Some(Path) = Lock(&self.Log).clone() else {
+			return;
+		};
+
+		let Appended = async {
+			let mut Log = Storage::New(Arc::new(Json)).Append(&Path).await?;
+
+			for Record in &Batch {
+				Log.Append(&serde_json::to_value(Record).unwrap_or_default()).await?;
+			}
+
+			Log.Close().await
+		};
+
+		if let Err(_Error) = Appended.await {
+			warn!("Cannot log submissions to {}: {}", Path.display(), _Error);
+		}
+	}
+
+	/// Starts the ingesting task if it does not run already.
+	fn Start(self: &Arc<Self>) {
+		let mut Task = Lock(&self.Task);
+
+		if Task.is_some() {
+			return;
+		}
+
+		let (Owner, Receiver) = (Arc::downgrade(self), self.Receiver.clone());
+
+		*Task = Some(self.Overseer.Spawn(
+			"history",
+			Restart::Always { Backoff:Duration::from_secs(1) },
+			move || {
+				let (Owner, Receiver) = (Owner.clone(), Receiver.clone());
+
+				async move {
+					let mut Receiver = Receiver.lock().await;
+
+					while let Some(Command) = Receiver.recv().await {
+						let Some(History) = Owner.upgrade() else {
+							break;
+						};
+
+						// Records queued meanwhile are ingested together
+						let mut Batch = Vec::new();
+
+						let mut Flushed = Vec::new();
+
+						for Command in std::iter::once(Command)
+							.chain(std::iter::from_fn(|| Receiver.try_recv().ok()))
+						{
+							match Command {
+								Command::Record(Record) => Batch.push(Record),
+								Command::Flush(Sender) => Flushed.push(Sender),
+							}
+						}
+
+						History.Ingest(Batch).await;
+
+						for Sender in Flushed {
+							let _ = Sender.send(());
+						}
+					}
+				}
+			},
+		));
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(Arc::new(Overseer::default())) }
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		if let Some(Task) = Lock(&self.Task).take() {
+			Task.Abort();
+		}
+	}
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::VecDeque,
+	path::PathBuf,
+	sync::{
+		atomic::{AtomicU64, AtomicUsize, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+	},
+	time::Duration,
+};
+
+use log::warn;
+use tokio::sync::{
+	mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+	oneshot,
+	Mutex as AsyncMutex,
+};
+
+use crate::{
+	Enum::Sequence::Restart::Enum as Restart,
+	Struct::{
+		Runtime::Spawned,
+		Sequence::Overseer::Struct as Overseer,
+		Storage::{Codec::Json::Struct as Json, Struct as Storage},
+	},
+};
+
+pub mod Page;
+pub mod Query;
+pub mod Record;
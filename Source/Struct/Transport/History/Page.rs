@@ -0,0 +1,16 @@
+/// One page of the records returned by `History::QuerySubmissions`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "SubmissionPage"))]
+pub struct Struct {
+	/// The records selected, oldest first.
+	pub Record:Vec<Record>,
+
+	/// The cursor of the next page, passed as `After`; absent on the last
+	/// page.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Next:Option<u64>,
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::Struct::Transport::History::Record::Struct as Record;
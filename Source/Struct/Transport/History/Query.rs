@@ -0,0 +1,148 @@
+/// Selects the records `History::QuerySubmissions` returns, one page at a
+/// time.
+///
+/// Every criterion set must hold; the default query selects every record,
+/// 100 per page.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "SubmissionQuery"))]
+#[serde(default)]
+pub struct Struct {
+	/// The identity records must have.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub Identity:Option<String>,
+
+	/// The earliest time records may have, in Unix milliseconds.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub From:Option<u64>,
+
+	/// The time records must be earlier than, in Unix milliseconds.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub To:Option<u64>,
+
+	/// The admission records must have.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub Admission:Option<Admission>,
+
+	/// The `Next` cursor of the previous page; the first page when absent.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub After:Option<u64>,
+
+	/// How many records a page holds at most, between 1 and 1000.
+	pub Limit:usize,
+}
+
+impl Struct {
+	/// Creates a query selecting every record, 100 per page.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New() -> Self { Self::default() }
+
+	/// Selects the records of one identity.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - The identity.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithIdentity(mut self, Identity:&str) -> Self {
+		self.Identity = Some(Identity.to_string());
+
+		self
+	}
+
+	/// Selects the records received in a time range.
+	///
+	/// # Arguments
+	///
+	/// * `From` - The earliest time, in Unix milliseconds.
+	/// * `To` - The time records must be earlier than, in Unix milliseconds.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithRange(mut self, From:u64, To:u64) -> Self {
+		(self.From, self.To) = (Some(From), Some(To));
+
+		self
+	}
+
+	/// Selects the accepted or the rejected records.
+	///
+	/// # Arguments
+	///
+	/// * `Admission` - The admission.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAdmission(mut self, Admission:Admission) -> Self {
+		self.Admission = Some(Admission);
+
+		self
+	}
+
+	/// Continues after a page.
+	///
+	/// # Arguments
+	///
+	/// * `After` - The `Next` cursor of the page.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAfter(mut self, After:u64) -> Self {
+		self.After = Some(After);
+
+		self
+	}
+
+	/// Sets how many records a page holds at most.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - The page size, clamped between 1 and 1000.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithLimit(mut self, Limit:usize) -> Self {
+		self.Limit = Limit;
+
+		self
+	}
+
+	/// Checks whether the query selects a record, ignoring pagination.
+	///
+	/// # Arguments
+	///
+	/// * `Record` - The record to check.
+	///
+	/// # Returns
+	///
+	/// `true` if every criterion set holds for the record.
+	pub fn Match(&self, Record:&Record) -> bool {
+		self.Identity
+			.as_ref()
+			.is_none_or(|Identity| *Identity == Record.Identity)
+			&& self.From.is_none_or(|From| Record.Time >= From)
+			&& self.To.is_none_or(|To| Record.Time < To)
+			&& self.Admission.is_none_or(|Admission| Admission == Record.Admission)
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Struct { Identity:None, From:None, To:None, Admission:None, After:None, Limit:100 }
+	}
+}
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	Enum::Transport::Admission::Enum as Admission,
+	Struct::Transport::History::Record::Struct as Record,
+};
@@ -0,0 +1,85 @@
+/// What a transport received as one submission, as recorded by `History`.
+///
+/// Records are written when a submission is accepted or rejected, before
+/// the action runs; what happened during execution is reported by events.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "Schema", derive(schemars::JsonSchema), schemars(rename = "Submission"))]
+pub struct Struct {
+	/// The position of the record in its history, assigned when it is
+	/// ingested and used as the pagination cursor.
+	pub Sequence:u64,
+
+	/// When the submission was received, in Unix milliseconds.
+	pub Time:u64,
+
+	/// Who submitted it: the identity of the token the connection
+	/// authenticated with, else its role in lowercase, or `anonymous` before
+	/// authentication.
+	pub Identity:String,
+
+	/// The identifier of the connection, unique per pump.
+	pub Connection:u64,
+
+	/// The client-chosen identifier of the submission; absent for a
+	/// malformed message.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Id:Option<String>,
+
+	/// The action type submitted; absent for a malformed message.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Action:Option<String>,
+
+	/// The `Digest` of the arguments; absent for a malformed message.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Digest:Option<String>,
+
+	/// Whether the submission was accepted.
+	pub Admission:Admission,
+
+	/// Why the submission was rejected.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub Reason:Option<String>,
+}
+
+impl Struct {
+	/// Digests the arguments of a submission, so records tell equal
+	/// arguments apart from different ones without storing them.
+	///
+	/// The digest is `Digest::Canonical` of the array of arguments, in hex,
+	/// so equal arguments digest alike whatever their key order. Text and
+	/// bytes stand in the array as `{"Bytes": length, "Sha256": digest}` of
+	/// their payload, which is hashed in place.
+	///
+	/// # Arguments
+	///
+	/// * `Argument` - The arguments submitted.
+	///
+	/// # Returns
+	///
+	/// Sixty-four lowercase hexadecimal digits.
+	pub fn Digest(Argument:&[Arg]) -> String {
+		let Argument = Argument
+			.iter()
+			.map(|Argument| {
+				match Argument {
+					Arg::Json(Value) => Value.clone(),
+					Payload => {
+						let Data = Payload.Bytes().unwrap_or_default();
+
+						json!({ "Bytes": Data.len(), "Sha256": Digest::Hex(&Digest::Raw(Data)) })
+					},
+				}
+			})
+			.collect();
+
+		Digest::Hex(&Digest::Canonical(&Value::Array(Argument)))
+	}
+}
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+	Digest,
+	Enum::{Sequence::Action::Arg::Enum as Arg, Transport::Admission::Enum as Admission},
+};
@@ -0,0 +1,224 @@
+/// The single stage between transports and the queues they feed.
+///
+/// Pumps built with `WithIngest` push their submitted jobs onto one bounded
+/// channel instead of enqueueing them themselves; a routing task takes them
+/// off in order, routes them to their line, checks them against its limits,
+/// acknowledges and enqueues them. The verdict travels back to the pushing
+/// pump through a oneshot channel, so a burst on one transport never holds
+/// the queue locks while others wait.
+///
+/// Each transport may be given a quota: the number of its jobs waiting in
+/// the channel at once. A job pushed past its transport's quota is refused
+/// with `ECHO_NOT_ACCEPTING` at once and counted in
+/// `echo_ingest_refused_total`.
+pub struct Struct {
+	/// Where jobs are pushed for routing.
+	Sender:mpsc::Sender<Entry>,
+
+	/// The number of jobs each transport may have waiting, by transport.
+	Quota:HashMap<String, usize>,
+
+	/// The number of jobs of each transport waiting, by transport.
+	Waiting:Arc<Mutex<HashMap<String, usize>>>,
+}
+
+/// A job waiting to be routed.
+struct Entry {
+	/// The transport that pushed the job.
+	Transport:String,
+
+	/// The identity of the connection that submitted the job.
+	Identity:String,
+
+	/// The job to route.
+	Job:Job::Struct,
+
+	/// Receives whether the job was enqueued, or why not.
+	Verdict:oneshot::Sender<Verdict>,
+}
+
+/// Whether a job was enqueued, or the code and reason it was refused.
+pub type Verdict = Result<(), (Code, String)>;
+
+impl Struct {
+	/// Creates a new ingestion stage and spawns its routing task on the
+	/// runtime of the target, which runs until the stage is dropped and its
+	/// channel drained.
+	///
+	/// # Arguments
+	///
+	/// * `Target` - Where the jobs are routed.
+	/// * `Capacity` - How many jobs the channel holds before pushing waits.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Target:impl Into<Target>, Capacity:usize) -> Self {
+		let (Sender, Receiver) = mpsc::channel(Capacity.max(1));
+
+		let Waiting = Arc::new(Mutex::new(HashMap::new()));
+
+		let Target = Target.into();
+
+		Target.Runtime().Spawn(Box::pin(Self::Route(Target, Receiver, Waiting.clone())));
+
+		Struct { Sender, Quota:HashMap::new(), Waiting }
+	}
+
+	/// Limits the jobs of a transport waiting in the channel.
+	///
+	/// # Arguments
+	///
+	/// * `Transport` - The name the transport's pump pushes with, e.g. `tcp`.
+	/// * `Quota` - How many of its jobs may wait at once.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithQuota(mut self, Transport:&str, Quota:usize) -> Self {
+		self.Quota.insert(Transport.to_string(), Quota);
+
+		self
+	}
+
+	/// Pushes a job for routing and waits for its verdict.
+	///
+	/// The job replies its acknowledgement or refusal itself, before the
+	/// verdict arrives.
+	///
+	/// # Arguments
+	///
+	/// * `Transport` - The name of the pushing transport.
+	/// * `Identity` - The identity of the submitting connection.
+	/// * `Job` - The job to route.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the job is enqueued, or the code and reason it was
+	/// refused.
+	pub async fn Push(&self, Transport:&str, Identity:&str, Job:Job::Struct) -> Verdict {
+		{
+			let mut Waiting = Lock(&self.Waiting);
+
+			let Count = Waiting.entry(Transport.to_string()).or_default();
+
+			if let Some(Quota) = self.Quota.get(Transport).filter(|Quota| *Count >= **Quota) {
+				counter!("echo_ingest_refused_total", "transport" => Transport.to_string())
+					.increment(1);
+
+				return Self::Refuse(
+					Job,
+					Code::NotAccepting,
+					format!("Transport {} has {} submissions waiting already", Transport, Quota),
+				);
+			}
+
+			*Count += 1;
+		}
+
+		let (Verdict, Receiver) = oneshot::channel();
+
+		let Entry =
+			Entry { Transport:Transport.to_string(), Identity:Identity.to_string(), Job, Verdict };
+
+		if let Err(Unsent) = self.Sender.send(Entry).await {
+			Self::Leave(&self.Waiting, Transport);
+
+			return Self::Refuse(
+				Unsent.0.Job,
+				Code::NotAccepting,
+				"Submissions are not routed any more".to_string(),
+			);
+		}
+
+		Receiver
+			.await
+			.unwrap_or_else(|_| Err((Code::Dropped, "The submission was dropped".to_string())))
+	}
+
+	/// Returns the number of jobs of a transport waiting to be routed.
+	///
+	/// # Arguments
+	///
+	/// * `Transport` - The name of the transport.
+	pub fn Waiting(&self, Transport:&str) -> usize {
+		Lock(&self.Waiting).get(Transport).copied().unwrap_or(0)
+	}
+
+	/// Routes the pushed jobs one after the other until every sender is
+	/// gone.
+	async fn Route(
+		Target:Target,
+		mut Receiver:mpsc::Receiver<Entry>,
+		Waiting:Arc<Mutex<HashMap<String, usize>>>,
+	) {
+		while let Some(Entry { Transport, Identity, Job, Verdict }) = Receiver.recv().await {
+			Self::Leave(&Waiting, &Transport);
+
+			let Production = match Target.Resolve(&Job).await {
+				Ok(Production) => Production,
+				Err(_Error) => {
+					debug!(
+						"Cannot route a submission of {} on {}: {}",
+						Identity, Transport, _Error
+					);
+
+					let _ =
+						Verdict.send(Self::Refuse(Job, Code::from(&_Error), _Error.to_string()));
+
+					continue;
+				},
+			};
+
+			// A chain too large for the line is refused before it is acked
+			if let Err(_Error) = Production.Check(&Job).await {
+				debug!("Refused a submission of {} on {}: {}", Identity, Transport, _Error);
+
+				let _ = Verdict.send(Self::Refuse(Job, Code::from(&_Error), _Error.to_string()));
+
+				continue;
+			}
+
+			// Acknowledged before it can run, so the ack precedes the result
+			Job.Ack();
+
+			let _ = Verdict.send(Ok(()));
+
+			// Checked above; a refusal drops the job, which replies
+			let _ = Production.Enqueue(Box::new(Job)).await;
+		}
+	}
+
+	/// Rejects a job, which replies with the error.
+	fn Refuse(Job:Job::Struct, Code:Code, Message:String) -> Verdict {
+		Job.Reject(Code, Message.clone());
+
+		Err((Code, Message))
+	}
+
+	/// Counts a job of a transport out of the channel.
+	fn Leave(Waiting:&Mutex<HashMap<String, usize>>, Transport:&str) {
+		if let Some(Count) = Lock(Waiting).get_mut(Transport) {
+			*Count = Count.saturating_sub(1);
+		}
+	}
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex, MutexGuard},
+};
+
+use log::debug;
+use metrics::counter;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+	Enum::{Source::Target::Enum as Target, Transport::Code::Enum as Code},
+	Struct::Transport::Job,
+};
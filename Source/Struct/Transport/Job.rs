@@ -0,0 +1,397 @@
+/// An action submitted through a transport.
+///
+/// The job runs its plan function with the submitted arguments and reports
+/// back to the submitting connection. A success is replied immediately. A
+/// failure is only replied once the job is dropped, because the sequence may
+/// still retry it; dropping the job without any success reports the last
+/// error, or that it never ran. A cancelled job replies nothing further and
+/// is skipped when dequeued.
+///
+/// A job's results go through its `Terminal`, which jobs resubmitted under
+/// the same identifier share, so a request is answered once however many of
+/// its attempts succeed.
+pub struct Struct {
+	/// The identifier chosen by the client.
+	Id:String,
+
+	/// The name of the plan function to run.
+	Action:String,
+
+	/// The arguments passed to the function, shared with every attempt
+	/// without copying their payloads.
+	Argument:Vec<Arg>,
+
+	/// Metadata attached to the action.
+	Metadata:Map<String, Value>,
+
+	/// The reserved options of the action by name, read before its
+	/// metadata.
+	Options:Map<String, Value>,
+
+	/// The plan providing the function.
+	Plan:Arc<Formality>,
+
+	/// The replies channel of the submitting connection.
+	Reply:UnboundedSender<Reply>,
+
+	/// Whether a result has been replied, shared with the connection so it
+	/// can cancel the job, and with jobs resubmitted under the same
+	/// identifier.
+	Terminal:Arc<Terminal::Struct>,
+
+	/// The code and error of the latest failed attempt.
+	Failure:Mutex<Option<(Code, String)>>,
+
+	/// The partial channel of the submitting connection, if it streams
+	/// output.
+	Partial:Option<Sender<Reply>>,
+}
+
+impl Struct {
+	/// Creates a new job.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier chosen by the client.
+	/// * `Action` - The name of the plan function to run.
+	/// * `Argument` - The arguments passed to the function.
+	/// * `Metadata` - Metadata attached to the action.
+	/// * `Plan` - The plan providing the function.
+	/// * `Reply` - The replies channel of the submitting connection.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(
+		Id:String,
+		Action:String,
+		Argument:Vec<Arg>,
+		Metadata:Map<String, Value>,
+		Plan:Arc<Formality>,
+		Reply:UnboundedSender<Reply>,
+	) -> Self {
+		Struct {
+			Id,
+			Action,
+			Argument,
+			Metadata,
+			Options:Map::new(),
+			Plan,
+			Reply,
+			Terminal:Arc::new(Terminal::Struct::default()),
+			Failure:Mutex::new(None),
+			Partial:None,
+		}
+	}
+
+	/// Lets the job stream output to the `Partial` destination.
+	///
+	/// # Arguments
+	///
+	/// * `Partial` - The bounded partial channel of the submitting connection.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithPartial(mut self, Partial:Sender<Reply>) -> Self {
+		self.Partial = Some(Partial);
+
+		self
+	}
+
+	/// Sets the reserved options of the job, as a submission names them.
+	///
+	/// # Arguments
+	///
+	/// * `Options` - The options by the name of their `Reserved` key.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOptions(mut self, Options:Map<String, Value>) -> Self {
+		self.Options = Options;
+
+		self
+	}
+
+	/// Shares the terminal state of a request, so results of this job and
+	/// of others under the same identifier are delivered once.
+	///
+	/// # Arguments
+	///
+	/// * `Terminal` - The terminal state of the request.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithTerminal(mut self, Terminal:Arc<Terminal::Struct>) -> Self {
+		self.Terminal = Terminal;
+
+		self
+	}
+
+	/// Applies the patch of a `Modify` admission decision: an entry named
+	/// after a `Reserved` key sets that option, e.g. `Queue`, the others
+	/// metadata.
+	///
+	/// # Arguments
+	///
+	/// * `Patch` - The metadata set, by key.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn Patch(mut self, Patch:Map<String, Value>) -> Self {
+		for (Key, Value) in Patch {
+			match Reserved::Parse(&Key) {
+				Some(Reserved::Action) => {
+					if let Value::String(Action) = Value {
+						self.Action = Action;
+					}
+				},
+				Some(Reserved::Argument) => {
+					if let Value::Array(Argument) = Value {
+						self.Argument = Argument.into_iter().map(Arg::Json).collect();
+					}
+				},
+				Some(Key) => {
+					self.Options.insert(Key.Name().to_string(), Value);
+				},
+				None => {
+					self.Metadata.insert(Key, Value);
+				},
+			}
+		}
+
+		self
+	}
+
+	/// Drops the job without running it, replying with the given error.
+	///
+	/// # Arguments
+	///
+	/// * `Code` - The code of the rejection.
+	/// * `Message` - The reason the job was rejected.
+	pub fn Reject(self, Code:Code, Message:String) {
+		*self.Failure.lock().unwrap_or_else(|Poison| Poison.into_inner()) = Some((Code, Message));
+	}
+
+	/// Replies that the job was accepted.
+	pub fn Ack(&self) { let _ = self.Reply.send(Reply::Ack { Id:self.Id.clone() }); }
+
+	/// Reads a reserved option from its legacy flat metadata key, while the
+	/// plan keeps `LegacyKeys`, with a deprecation warning.
+	fn Legacy(&self, Key:Reserved) -> Option<&Value> {
+		let Value = self.Metadata.get(Key.Name()).filter(|_| self.Plan.LegacyKeys())?;
+
+		Key.Deprecated();
+
+		Some(Value)
+	}
+
+	/// Replies with an error, unless the request was answered already.
+	fn Refuse(&self, Code:Code, Message:String) {
+		if !self.Terminal.Close() {
+			return;
+		}
+
+		let _ = self.Reply.send(Reply::Error {
+			Id:Some(self.Id.clone()),
+			Message,
+			Code:Some(Code),
+			OutOfOrder:false,
+		});
+	}
+
+	/// Replies with the value of a successful attempt started at `Started`,
+	/// unless the request was answered already.
+	fn Succeed(&self, Value:Value, Started:u64) {
+		let Invocation = Invocation::Current();
+
+		let mut History = Invocation
+			.as_ref()
+			.map(|Invocation| Invocation.History().clone())
+			.unwrap_or_default();
+
+		History.Push(Attempt::New(
+			Invocation.as_ref().map_or(1, |Invocation| Invocation.Attempt()),
+			Started,
+			&Ok::<_, Error>(()),
+		));
+
+		if self.Terminal.Settle(&self.Id, &History) {
+			let Annotation = Invocation
+				.as_ref()
+				.map(|Invocation| Invocation.Annotations())
+				.unwrap_or_default();
+
+			let _ = self.Reply.send(Reply::Result {
+				Id:self.Id.clone(),
+				Value,
+				History,
+				Annotation,
+				OutOfOrder:false,
+			});
+		}
+	}
+
+	/// Returns a handle cancelling the job.
+	///
+	/// Cancelling closes the terminal state; whoever settled it owns the
+	/// job's final reply.
+	///
+	/// # Returns
+	///
+	/// A weak reference to the terminal state, gone once the job is dropped.
+	pub fn Cancellation(&self) -> Weak<Terminal::Struct> { Arc::downgrade(&self.Terminal) }
+}
+
+#[async_trait]
+impl Action for Struct {
+	async fn Execute(&self, Context:&Life) -> Result<(), Error> {
+		// Cancelled while queued
+		if self.Terminal.Settled() {
+			return Ok(());
+		}
+
+		let Started = Attempt::Now();
+
+		let Key = self.Metadata(Reserved::IdempotencyKey.Name()).await.map(|Key| {
+			match Key {
+				Value::String(Key) => Key,
+				Key => Key.to_string(),
+			}
+		});
+
+		if let Some((Key, Value)) =
+			Key.as_deref().and_then(|Key| Some((Key, Context.Idempotent(Key)?)))
+		{
+			info!("Replying to {} with the result of idempotency key {}", self.Id, Key);
+
+			counter!("echo_idempotent_skipped_total").increment(1);
+
+			self.Succeed(Value, Started);
+
+			return Ok(());
+		}
+
+		let Function = self
+			.Plan
+			.Get(&self.Action)
+			.ok_or_else(|| self.Plan.Unknown(&self.Action));
+
+		match async { Function?.call((self.Argument.clone(),)).await }.await {
+			Ok(Value) => {
+				Invocation::Record(Value.clone());
+
+				if let Some(Key) = Key {
+					Context.Remember(&Key, Value.clone());
+				}
+
+				// A streamed output is described instead of returned
+				let Value = Invocation::Current()
+					.and_then(|Invocation| Invocation.Streamed())
+					.unwrap_or(Value);
+
+				self.Succeed(Value, Started);
+
+				Ok(())
+			},
+			Err(_Error) => {
+				*self.Failure.lock().unwrap_or_else(|Poison| Poison.into_inner()) =
+					Some((Code::from(&_Error), _Error.to_string()));
+
+				Err(_Error)
+			},
+		}
+	}
+
+	async fn Metadata(&self, Key:&str) -> Option<Value> {
+		let Some(Key) = Reserved::Parse(Key) else {
+			return self.Metadata.get(Key).cloned().or_else(|| self.Plan.Default(&self.Action, Key));
+		};
+
+		match Key {
+			Reserved::Action => Some(Value::String(self.Action.clone())),
+			Reserved::Argument => {
+				Some(Value::Array(self.Argument.iter().cloned().map(Arg::Value).collect()))
+			},
+			_ => {
+				self.Options
+					.get(Key.Name())
+					.or_else(|| self.Metadata.get(&Key.Key()))
+					.or_else(|| self.Legacy(Key))
+					.cloned()
+					.or_else(|| self.Plan.Default(&self.Action, Key.Name()))
+			},
+		}
+	}
+
+	async fn ArgumentSize(&self) -> u64 { self.Argument.iter().map(Arg::Size).sum() }
+
+	fn Fail(&self, Error:&Error) { self.Refuse(Code::from(Error), Error.to_string()); }
+
+	fn Partial(&self) -> Option<Writer> {
+		self.Partial
+			.clone()
+			.map(|Partial| Box::new(Partial::Struct::New(self.Id.clone(), Partial)) as Writer)
+	}
+
+	async fn View(&self) -> View {
+		let mut Metadata = self.Metadata.clone();
+
+		// Options win over the metadata, as when the job runs
+		Metadata.extend(self.Options.iter().map(|(Key, Value)| {
+			(Reserved::Parse(Key).map_or(Key.clone(), Reserved::Key), Value.clone())
+		}));
+
+		View::New(
+			self.Action.clone(),
+			self.Argument.iter().cloned().map(Arg::Value).collect(),
+			Metadata,
+		)
+	}
+}
+
+impl Drop for Struct {
+	fn drop(&mut self) {
+		let (Code, Message) = self
+			.Failure
+			.get_mut()
+			.unwrap_or_else(|Poison| Poison.into_inner())
+			.take()
+			.unwrap_or_else(|| (Code::Dropped, "Action was dropped before it ran".to_string()));
+
+		self.Refuse(Code, Message);
+	}
+}
+
+use std::sync::{Arc, Mutex, Weak};
+
+use async_trait::async_trait;
+use log::info;
+use metrics::counter;
+use serde_json::{Map, Value};
+use tokio::sync::mpsc::{Sender, UnboundedSender};
+
+use crate::{
+	Enum::{
+		Sequence::{
+			Action::{Arg::Enum as Arg, Error::Enum as Error},
+			Reserved::Enum as Reserved,
+		},
+		Transport::{Code::Enum as Code, Reply::Enum as Reply},
+	},
+	Struct::{
+		Sequence::{
+			Admission::View::Struct as View,
+			Attempt::Struct as Attempt,
+			Invocation::Struct as Invocation,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Formality,
+		},
+		Transport::{Partial, Terminal},
+	},
+	Trait::Sequence::Action::Trait as Action,
+	Type::Sequence::Action::Writer::Type as Writer,
+};
@@ -0,0 +1,131 @@
+/// Holds the results of a `SubmissionOrder` connection until every earlier
+/// submission has its result.
+///
+/// Each accepted submission takes a slot in arrival order. A result fills
+/// the slot of its submission, and results are released from the front as
+/// long as the front slot is filled. When more than `Limit` results are held,
+/// every held result is released at once, flagged `OutOfOrder`, and the
+/// slots still waiting keep their order. The buffer belongs to the writing
+/// half of the connection and is dropped with it.
+pub struct Struct {
+	/// The submissions in arrival order, each with its result once known.
+	Slot:VecDeque<(String, Option<Reply>)>,
+
+	/// The number of results held.
+	Held:usize,
+
+	/// How many results may be held before they are released out of order.
+	Limit:usize,
+}
+
+impl Struct {
+	/// Creates an empty buffer.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - How many results may be held before they are released out of
+	///   order.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Limit:usize) -> Self { Struct { Slot:VecDeque::new(), Held:0, Limit } }
+
+	/// Takes a slot for an accepted submission. A submission reusing the
+	/// identifier of one still waiting shares its slot, as it shares its
+	/// result.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the submission.
+	pub fn Submitted(&mut self, Id:String) {
+		if !self.Slot.iter().any(|(Waiting, Result)| *Waiting == Id && Result.is_none()) {
+			self.Slot.push_back((Id, None));
+		}
+	}
+
+	/// Records the result of a submission.
+	///
+	/// # Arguments
+	///
+	/// * `Reply` - A `Result` or `Error` reply about a submission.
+	///
+	/// # Returns
+	///
+	/// The replies that may be sent now, in order. A reply about no waiting
+	/// submission, such as a rejection, is returned as it is.
+	pub fn Complete(&mut self, Reply:Reply) -> Vec<Reply> {
+		let Id = match &Reply {
+			Reply::Result { Id, .. } | Reply::Error { Id: Some(Id), .. } => Id,
+			_ => return vec![Reply],
+		};
+
+		let Some(Slot) = self
+			.Slot
+			.iter_mut()
+			.find(|(Waiting, Result)| Waiting == Id && Result.is_none())
+		else {
+			return vec![Reply];
+		};
+
+		Slot.1 = Some(Reply);
+
+		self.Held += 1;
+
+		let mut Ready = Vec::new();
+
+		while self.Slot.front().is_some_and(|(_, Result)| Result.is_some()) {
+			Ready.extend(self.Slot.pop_front().and_then(|(_, Result)| Result));
+
+			self.Held -= 1;
+		}
+
+		if self.Held > self.Limit {
+			warn!(
+				"Reordering buffer exceeded {} results; releasing {} out of order",
+				self.Limit, self.Held
+			);
+
+			counter!("echo_transport_out_of_order_total").increment(self.Held as u64);
+
+			Ready.extend(self.Release());
+		}
+
+		Ready
+	}
+
+	/// Releases every held result, e.g. when the connection ends.
+	///
+	/// # Returns
+	///
+	/// The held results in arrival order, flagged `OutOfOrder`.
+	pub fn Release(&mut self) -> Vec<Reply> {
+		let mut Released = Vec::with_capacity(self.Held);
+
+		self.Slot.retain_mut(|(_, Result)| match Result.take() {
+			Some(mut Reply) => {
+				if let Reply::Result { OutOfOrder, .. } | Reply::Error { OutOfOrder, .. } =
+					&mut Reply
+				{
+					*OutOfOrder = true;
+				}
+
+				Released.push(Reply);
+
+				false
+			},
+			None => true,
+		});
+
+		self.Held = 0;
+
+		Released
+	}
+}
+
+use std::collections::VecDeque;
+
+use log::warn;
+use metrics::counter;
+
+use crate::Enum::Transport::Reply::Enum as Reply;
@@ -0,0 +1,88 @@
+/// Streams the output of a job back to its connection as `Partial`
+/// replies.
+///
+/// Each write becomes one reply of at most 64 KiB, sent through the bounded
+/// partial channel of the connection. A write waits for room in that
+/// channel, so a client reading slowly slows the handler down.
+pub struct Struct {
+	/// The identifier of the submission.
+	Id:String,
+
+	/// The partial channel of the connection.
+	Sender:Sender<Reply>,
+
+	/// The pending reservation of room in the channel, if a write waits.
+	Reserve:Option<Reserve>,
+}
+
+/// A pending reservation of room in the partial channel.
+type Reserve = Pin<Box<dyn Future<Output = Result<OwnedPermit<Reply>, SendError<()>>> + Send>>;
+
+impl Struct {
+	/// Creates a stream for a submission.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the submission.
+	/// * `Sender` - The partial channel of the connection.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Id:String, Sender:Sender<Reply>) -> Self { Struct { Id, Sender, Reserve:None } }
+}
+
+impl AsyncWrite for Struct {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		Context:&mut Context<'_>,
+		Buffer:&[u8],
+	) -> Poll<io::Result<usize>> {
+		let Sender = self.Sender.clone();
+
+		let Reserve = self.Reserve.get_or_insert_with(|| Box::pin(Sender.reserve_owned()));
+
+		let Reserved = ready!(Reserve.as_mut().poll(Context));
+
+		self.Reserve = None;
+
+		match Reserved {
+			Ok(Permit) => {
+				let Length = Buffer.len().min(64 * 1024);
+
+				Permit.send(Reply::Partial {
+					Id:self.Id.clone(),
+					Data:STANDARD.encode(&Buffer[..Length]),
+				});
+
+				Poll::Ready(Ok(Length))
+			},
+			Err(_) => {
+				Poll::Ready(Err(io::Error::new(io::ErrorKind::BrokenPipe, "Connection closed")))
+			},
+		}
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, _Context:&mut Context<'_>) -> Poll<io::Result<()>> {
+		Poll::Ready(Ok(()))
+	}
+
+	fn poll_shutdown(self: Pin<&mut Self>, _Context:&mut Context<'_>) -> Poll<io::Result<()>> {
+		Poll::Ready(Ok(()))
+	}
+}
+
+use std::{
+	future::Future,
+	io,
+	pin::Pin,
+	task::{ready, Context, Poll},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use tokio::{
+	io::AsyncWrite,
+	sync::mpsc::{error::SendError, OwnedPermit, Sender},
+};
+
+use crate::Enum::Transport::Reply::Enum as Reply;
@@ -0,0 +1,1698 @@
+/// The message loop shared by every transport.
+///
+/// A transport only supplies the framing of its stream; the pump decodes
+/// each frame with its codec, enqueues submissions as `Job`s into its target
+/// and writes every `Reply` back. When the reading half ends (or the client
+/// sends `Close`), the pump stops accepting messages and drains: it returns
+/// once every job submitted on the stream has replied.
+///
+/// Connections authenticated with the admin token may also send `Control`
+/// messages to pause, resume or drain the pump; other connections are
+/// denied and the attempt is logged.
+///
+/// Every submission, accepted or rejected, and every malformed message is
+/// recorded in the pump's `History` with the identity of its connection,
+/// and every submitted job carries that identity as `SubmittedBy` metadata.
+///
+/// A pump serving a `Life` and built with `WithBackpressure` pauses the
+/// actions of an identity while one of its connections has more replies
+/// waiting to be written than the high-water mark, until that connection
+/// drains below the low-water mark; see `Life::Backpressure`.
+///
+/// A pump built with `WithOffload` and `WithChunking` streams large results
+/// from its result store in chunks, those of different results taking turns
+/// on the connection; see `Chunked`.
+///
+/// A pump built with `WithIngest` leaves routing and enqueueing to a shared
+/// `Ingest` stage, which serializes the submissions of every transport.
+///
+/// A connection authenticating as an identity that already has one open is
+/// refused, served alongside it or takes it over, as the `Takeover` policy
+/// of the identity's class says; see `WithTakeover`.
+///
+/// A submission reusing the identifier of a job of its connection still in
+/// flight, e.g. one the client retried for want of an `Ack`, joins that
+/// job's `Terminal`: only the first result of the two is delivered, and
+/// later ones are counted as duplicates; see `WithSuppressDuplicates`.
+#[derive(Clone)]
+pub struct Struct {
+	/// Where submitted jobs are enqueued.
+	pub Target:Target,
+
+	/// The plan providing the functions of submitted jobs.
+	pub Plan:Arc<Formality>,
+
+	/// The codec decoding messages and encoding replies.
+	pub Codec:Arc<dyn Codec>,
+
+	/// The token clients must present with `Auth` before anything else, or
+	/// `None` to accept unauthenticated connections.
+	pub Token:Option<String>,
+
+	/// The token granting the `Admin` role, or `None` to refuse every
+	/// `Control` message.
+	pub Admin:Option<String>,
+
+	/// The identities recorded for connections authenticated with a token,
+	/// by token. Tokens listed here are accepted for the `Client` role.
+	pub Identity:HashMap<String, String>,
+
+	/// The history the submissions are recorded in: that of the target's
+	/// `Life` if it has one.
+	pub History:Arc<History::Struct>,
+
+	/// Whether submissions are accepted, shared by every clone of the pump.
+	pub State:Signal<State>,
+
+	/// How many results a `SubmissionOrder` connection may hold before
+	/// releasing them out of order.
+	pub Reorder:usize,
+
+	/// Whether submissions naming an action type the plan does not handle
+	/// are accepted, for deployments extending the plan later.
+	pub AcceptUnknown:bool,
+
+	/// The high- and low-water marks of the replies waiting to be written
+	/// on a connection, or `None` to never pause the actions of a slow
+	/// connection.
+	pub Backpressure:Option<(usize, usize)>,
+
+	/// Where results above a size threshold are offloaded, or `None` to
+	/// deliver every result inline.
+	pub Offload:Option<Arc<Offload::Struct>>,
+
+	/// The most bytes per `ResultChunk` of an offloaded result, or `None` to
+	/// deliver it as a `$stored` marker.
+	pub Chunking:Option<usize>,
+
+	/// The ingestion stage submitted jobs are pushed onto instead of the
+	/// target, with the name of the transport pushing them.
+	pub Ingest:Option<(Arc<Ingest::Struct>, String)>,
+
+	/// The takeover policies by identity class, the first matching class
+	/// applying.
+	pub Takeover:Vec<(Glob::Struct, Takeover)>,
+
+	/// Whether results after the first of a request are withheld, or only
+	/// counted and logged.
+	pub SuppressDuplicates:bool,
+
+	/// The number of duplicate results, shared by every clone of the pump.
+	Duplicates:Arc<AtomicU64>,
+
+	/// The identifier handed to the next connection, for events.
+	Connection:Arc<AtomicU64>,
+
+	/// The connection counters, registered with the target's `Life` if it
+	/// has one.
+	Activity:Arc<Activity::Struct>,
+
+	/// The authenticated connections by identity, shared by every clone of
+	/// the pump.
+	Live:Arc<Mutex<HashMap<String, Vec<Live>>>>,
+}
+
+impl Struct {
+	/// Creates a new pump using the JSON codec and no authentication.
+	///
+	/// Unknown action types are refused unless the target is a `Life` whose
+	/// configuration sets `transport.accept_unknown`, and duplicate results
+	/// are withheld unless it unsets `transport.suppress_duplicates`.
+	///
+	/// # Arguments
+	///
+	/// * `Target` - A single `Production`, or a `Life` whose Karma queues are
+	///   selected by each submission's `Queue` metadata.
+	/// * `Plan` - The plan providing the functions of submitted jobs.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Target:impl Into<Target>, Plan:Arc<Formality>) -> Self {
+		let Target = Target.into();
+
+		let (Activity, AcceptUnknown, SuppressDuplicates, History) = match &Target {
+			Target::Life(Life) => (
+				Life.Registry.Transport(),
+				Life.Fate.get_bool("transport.accept_unknown").unwrap_or(false),
+				Life.Fate.get_bool("transport.suppress_duplicates").unwrap_or(true),
+				Life.History.clone(),
+			),
+			Target::Production(_) => (
+				Arc::new(Activity::Struct::New()),
+				false,
+				true,
+				Arc::new(History::Struct::default()),
+			),
+		};
+
+		Struct {
+			Target,
+			Plan,
+			Codec:Arc::new(Json::Struct),
+			Token:None,
+			Admin:None,
+			Identity:HashMap::new(),
+			History,
+			State:Signal::New(State::Open),
+			Reorder:1024,
+			AcceptUnknown,
+			Backpressure:None,
+			Offload:None,
+			Chunking:None,
+			Ingest:None,
+			Takeover:Vec::new(),
+			SuppressDuplicates,
+			Duplicates:Arc::new(AtomicU64::new(0)),
+			Connection:Arc::new(AtomicU64::new(0)),
+			Activity,
+			Live:Arc::new(Mutex::new(HashMap::new())),
+		}
+	}
+
+	/// Sets the codec.
+	///
+	/// # Arguments
+	///
+	/// * `Codec` - The codec decoding messages and encoding replies.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithCodec(mut self, Codec:Arc<dyn Codec>) -> Self {
+		self.Codec = Codec;
+
+		self
+	}
+
+	/// Requires clients to authenticate with a token.
+	///
+	/// # Arguments
+	///
+	/// * `Token` - The shared secret clients must present.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithToken(mut self, Token:&str) -> Self {
+		self.Token = Some(Token.to_string());
+
+		self
+	}
+
+	/// Sets the token granting the `Admin` role, which may send `Control`
+	/// messages.
+	///
+	/// # Arguments
+	///
+	/// * `Admin` - The shared secret of operators.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAdmin(mut self, Admin:&str) -> Self {
+		self.Admin = Some(Admin.to_string());
+
+		self
+	}
+
+	/// Adds a token identifying whoever authenticates with it, for the
+	/// submission history. The token is accepted for the `Client` role even
+	/// if it differs from the shared one.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - The identity recorded for the token.
+	/// * `Token` - The secret of that identity.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithIdentity(mut self, Identity:&str, Token:&str) -> Self {
+		self.Identity.insert(Token.to_string(), Identity.to_string());
+
+		self
+	}
+
+	/// Sets the history the submissions are recorded in, to share one
+	/// between pumps.
+	///
+	/// # Arguments
+	///
+	/// * `History` - The history.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithHistory(mut self, History:Arc<History::Struct>) -> Self {
+		self.History = History;
+
+		self
+	}
+
+	/// Sets how many results a `SubmissionOrder` connection may hold.
+	///
+	/// # Arguments
+	///
+	/// * `Reorder` - The capacity of each connection's reordering buffer.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithReorder(mut self, Reorder:usize) -> Self {
+		self.Reorder = Reorder;
+
+		self
+	}
+
+	/// Sets whether submissions naming an action type the plan does not
+	/// handle are accepted. Accepted ones run if the function is registered
+	/// by the time they are dequeued, and go to the dead-letter queue
+	/// otherwise.
+	///
+	/// # Arguments
+	///
+	/// * `AcceptUnknown` - Whether unknown action types are accepted.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithAcceptUnknown(mut self, AcceptUnknown:bool) -> Self {
+		self.AcceptUnknown = AcceptUnknown;
+
+		self
+	}
+
+	/// Pauses the actions submitted by the identity of a connection while
+	/// its client falls behind reading replies. Only a pump serving a `Life`
+	/// applies it.
+	///
+	/// # Arguments
+	///
+	/// * `High` - How many replies may wait to be written before the identity
+	///   is paused.
+	/// * `Low` - How few replies must wait before it resumes.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithBackpressure(mut self, High:usize, Low:usize) -> Self {
+		self.Backpressure = Some((High.max(1), Low.min(High.max(1) - 1)));
+
+		self
+	}
+
+	/// Offloads results above the threshold of `Offload` into its store,
+	/// replacing them with a `$stored` marker that clients resolve with
+	/// `Fetch`.
+	///
+	/// # Arguments
+	///
+	/// * `Offload` - Where large results are kept.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithOffload(mut self, Offload:Arc<Offload::Struct>) -> Self {
+		self.Offload = Some(Offload);
+
+		self
+	}
+
+	/// Sends offloaded results in chunks of at most `Size` bytes instead of
+	/// as `$stored` markers: `ResultChunk` replies read from the store of the
+	/// `Offload` one at a time, then a `ResultEnd` carrying their digest.
+	/// The chunks of the results of a connection are sent in turn, each
+	/// result in order. Without `WithOffload` no result is chunked.
+	///
+	/// # Arguments
+	///
+	/// * `Size` - The most bytes per chunk.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithChunking(mut self, Size:usize) -> Self {
+		self.Chunking = Some(Size.max(1));
+
+		self
+	}
+
+	/// Hands submitted jobs to a shared ingestion stage, which routes and
+	/// enqueues them into its own target in order with those of the other
+	/// transports.
+	///
+	/// # Arguments
+	///
+	/// * `Ingest` - The ingestion stage.
+	/// * `Transport` - The name of this pump's transport, e.g. `tcp`, whose
+	///   quota applies.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithIngest(mut self, Ingest:Arc<Ingest::Struct>, Transport:&str) -> Self {
+		self.Ingest = Some((Ingest, Transport.to_string()));
+
+		self
+	}
+
+	/// Sets what happens when a connection authenticates as an identity of
+	/// a class that already has a connection open. Classes are checked in
+	/// the order they were added; identities of no class are `Allow`ed.
+	///
+	/// # Arguments
+	///
+	/// * `Class` - The identities of the class, as a glob pattern such as
+	///   `desktop-*`.
+	/// * `Takeover` - The policy of the class.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithTakeover(mut self, Class:&str, Takeover:Takeover) -> Self {
+		self.Takeover.push((Glob::Struct::New(Class), Takeover));
+
+		self
+	}
+
+	/// Sets whether a result of a request that already has one delivered is
+	/// withheld. Either way it is counted, logged with both attempts and
+	/// recorded in the history of the delivered result.
+	///
+	/// # Arguments
+	///
+	/// * `SuppressDuplicates` - Whether duplicate results are withheld.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithSuppressDuplicates(mut self, SuppressDuplicates:bool) -> Self {
+		self.SuppressDuplicates = SuppressDuplicates;
+
+		self
+	}
+
+	/// Returns the number of duplicate results the pump's jobs produced.
+	pub fn Duplicates(&self) -> u64 { self.Duplicates.load(Ordering::Relaxed) }
+
+	/// Returns the runtime of the target, which transports serving the pump
+	/// spawn their connections on.
+	pub fn Runtime(&self) -> Arc<dyn Runtime> { self.Target.Runtime() }
+
+	/// Returns the takeover policy of an identity.
+	fn Policy(&self, Identity:&str) -> Takeover {
+		self.Takeover
+			.iter()
+			.find(|(Class, _)| Class.Match(Identity))
+			.map(|(_, Takeover)| *Takeover)
+			.unwrap_or_default()
+	}
+
+	/// Serves one stream until it ends and its jobs have replied.
+	///
+	/// A connection that fails to authenticate receives an error and is not
+	/// read any further. Results are written in the `Delivery` order chosen
+	/// with `Auth`. The opening and closing of the stream are published
+	/// on the target's event bus.
+	///
+	/// A connection taken over stops reading and is sent `Superseded`, then
+	/// forwards the replies of its remaining jobs to the connection taking
+	/// over until they have all replied.
+	///
+	/// # Arguments
+	///
+	/// * `Reader` - The reading half of the framed stream.
+	/// * `Writer` - The writing half of the framed stream.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` after a clean end of stream, or the first I/O error of either
+	/// half.
+	pub async fn Run(&self, mut Reader:impl Reader, mut Writer:impl Writer) -> io::Result<()> {
+		let (Sender, mut Receiver) = unbounded_channel();
+
+		// Streamed output is bounded so a slow client slows its handlers down
+		let (Partial, mut Streamed) = channel(16);
+
+		let Connection = self.Connection.fetch_add(1, Ordering::Relaxed);
+
+		let Bus = self.Target.Bus();
+
+		if let Some(Bus) = &Bus {
+			Bus.Emit(|| Event::Opened { Connection });
+		}
+
+		self.Activity.Begin();
+
+		// The identity the connection submits as, and the one it presses
+		let (Submitter, Pressed) = (&Mutex::new(None::<String>), &Mutex::new(None::<String>));
+
+		// Where a takeover is announced, and the connection taking over
+		let (Supersede, mut Superseded) = unbounded_channel::<Successor>();
+
+		let (Successor, Switched) =
+			(&Mutex::new(None::<(u64, UnboundedSender<Reply>)>), &Notify::new());
+
+		// The transfers the client gave up, until the writing half drops them
+		let Aborted = &Mutex::new(HashSet::<String>::new());
+
+		let Read = async move {
+			let mut Role = if self.Token.is_none() { Some(Role::Client) } else { None };
+
+			let mut Session = Session { Connection, Role, ..Session::default() };
+
+			loop {
+				let Frame = select! {
+					Frame = Reader.Read() => match Frame? {
+						Some(Frame) => Frame,
+						None => return Ok(()),
+					},
+					Some(Taking) = Superseded.recv() => {
+						*Lock(Successor) = Some((Taking.Connection, Taking.Sender));
+
+						Switched.notify_one();
+
+						let _ = Taking.Handover.send(Handover {
+							Pending:std::mem::take(&mut Session.Pending),
+							Filter:Session.Filter.take(),
+						});
+
+						return Ok(());
+					},
+				};
+
+				let Message = match self.Codec.DecodeFrame(Frame) {
+					Ok(Message) => Message,
+					Err(_Error) => {
+						let Message = format!("Malformed message: {}", _Error);
+
+						self.Audit(&Session, None, Some(Message.clone()));
+
+						let _ = Sender.send(Reply::Error {
+							Id:None,
+							Message,
+							Code:Some(Code::Malformed),
+							OutOfOrder:false,
+						});
+
+						continue;
+					},
+				};
+
+				match (Role, Message) {
+					(_, Message::Close) => return Ok(()),
+					(_, Message::Auth { Token, Delivery }) => {
+						Role = if self.Admin.as_ref() == Some(&Token) {
+							Some(Role::Admin)
+						} else if self.Token.as_ref().is_none_or(|Expected| *Expected == Token)
+							|| self.Identity.contains_key(&Token)
+						{
+							Some(Role::Client)
+						} else {
+							let _ = Sender.send(Reply::Error {
+								Id:None,
+								Message:"Invalid token".to_string(),
+								Code:Some(Code::Unauthorized),
+								OutOfOrder:false,
+							});
+
+							return Ok(());
+						};
+
+						Session.Identity =
+							Some(self.Identity.get(&Token).cloned().unwrap_or_else(|| {
+								match Role {
+									Some(Role::Admin) => "admin",
+									_ => "client",
+								}
+								.to_string()
+							}));
+
+						Session.Role = Role;
+
+						*Lock(Submitter) = Session.Identity.clone();
+
+						if !self.Join(&mut Session, &Supersede, &Sender).await {
+							let _ = Sender.send(Reply::Error {
+								Id:None,
+								Message:format!(
+									"{} already has a connection open",
+									Session.Submitter()
+								),
+								Code:Some(Code::DuplicateConnection),
+								OutOfOrder:false,
+							});
+
+							return Ok(());
+						}
+
+						let _ = Sender.send(Reply::Authenticated {
+							Role:Role.unwrap_or(Role::Client),
+							Delivery,
+							Version:WIRE_VERSION,
+						});
+					},
+					(Some(Role), Message::Control { Control, DeadlineMs, Queue }) => {
+						let _ = Sender.send(if Role == Role::Admin {
+							self.Control(Control, DeadlineMs, Queue).await
+						} else {
+							warn!(
+								"Denied {:?} control on connection {}: not an admin",
+								Control, Connection
+							);
+
+							counter!("echo_control_denied_total").increment(1);
+
+							Reply::Denied { Message:"Control requires the Admin role".to_string() }
+						});
+					},
+					(Some(Role), Message) if Message.Admin() && Role != Role::Admin => {
+						warn!("Denied admin message on connection {}: not an admin", Connection);
+
+						counter!("echo_control_denied_total").increment(1);
+
+						let _ = Sender.send(Reply::Denied {
+							Message:"The message requires the Admin role".to_string(),
+						});
+					},
+					(Some(_), Message::ResultAbort { Id, Code, Message }) => {
+						warn!(
+							"Connection {} aborted the transfer of {} ({}): {}",
+							Connection,
+							Id,
+							Code.Name(),
+							Message
+						);
+
+						counter!("echo_result_transfer_aborted_total").increment(1);
+
+						Lock(Aborted).insert(Id);
+					},
+					(Some(_), Message) => {
+						self.Handle(Message, &Sender, &Partial, &mut Session).await
+					},
+					(None, Message) => {
+						if let Message::Submit { Id, Action, Argument, .. } = Message {
+							self.Audit(
+								&Session,
+								Some((Id, Action, Record::Digest(&Argument))),
+								Some("Not authenticated".to_string()),
+							);
+						}
+
+						let _ = Sender.send(Reply::Error {
+							Id:None,
+							Message:"Not authenticated".to_string(),
+							Code:Some(Code::Unauthorized),
+							OutOfOrder:false,
+						});
+
+						return Ok(());
+					},
+				}
+			}
+		};
+
+		let Write = async move {
+			let Runtime = self.Target.Runtime();
+
+			// Present while the connection asked for `SubmissionOrder`
+			let mut Order:Option<Order::Struct> = None;
+
+			// The results sent in chunks, the next to send one first, and
+			// whether jobs may still reply
+			let (mut Transfers, mut Open) = (VecDeque::<Chunked::Struct>::new(), true);
+
+			// The connection that took this one over, with the replies left
+			// for it, once one did
+			let (Taken, Left) = 'Served: loop {
+				let Given = std::mem::take(&mut *Lock(Aborted));
+
+				Transfers.retain(|Transfer| !Given.contains(Transfer.Id()));
+
+				if !Open && Transfers.is_empty() {
+					break (None, Vec::new());
+				}
+
+				// Chunks queued before a result was sent are written first;
+				// transfers go on while no other reply waits
+				let (Reply, Chunk) = select! {
+					biased;
+
+					_ = Switched.notified() => match Lock(Successor).take() {
+						Some(Taken) => break 'Served (Some(Taken), Vec::new()),
+						None => continue,
+					},
+					Some(Reply) = Streamed.recv() => (Some(Reply), false),
+					Reply = Receiver.recv(), if Open => {
+						Open = Reply.is_some();
+
+						(Reply, false)
+					},
+					Reply = async { Transfers[0].Next().await }, if !Transfers.is_empty() => {
+						(Some(Reply), true)
+					},
+				};
+
+				self.Pressure(Receiver.len(), Connection, Submitter, Pressed).await;
+
+				let Ready = match Reply {
+					Some(Reply) => self.Ready(Reply, &mut Order).await,
+					// Every job has replied; results still held wait on none
+					None => Order.take().map(|mut Order| Order.Release()).unwrap_or_default(),
+				};
+
+				// Stored results join the transfers instead of going out now
+				let Ready = match (&self.Offload, self.Chunking) {
+					(Some(Offload), Some(Size)) => {
+						Ready
+							.into_iter()
+							.filter_map(|Reply| match Chunked::Struct::New(&Reply, Offload, Size) {
+								Some(Transfer) => {
+									Transfers.push_back(Transfer);
+
+									None
+								},
+								None => Some(Reply),
+							})
+							.collect()
+					},
+					_ => Ready,
+				};
+
+				let mut Ready = Ready.into_iter();
+
+				while let Some(Reply) = Ready.next() {
+					let Written = {
+						let Sent = self.Send(&mut Writer, &Reply);
+
+						tokio::pin!(Sent);
+
+						// A write stalled on a slow client keeps watching the
+						// replies piling up behind it, and gives up on a
+						// client taken over
+						loop {
+							select! {
+								biased;
+
+								_ = Switched.notified() => break None,
+								Sent = &mut Sent => break Some(Sent),
+								_ = Runtime.Sleep(Duration::from_millis(10)), if self.Backpressure.is_some() => {
+									self.Pressure(Receiver.len(), Connection, Submitter, Pressed).await
+								},
+							}
+						}
+					};
+
+					let Taken = Lock(Successor).take();
+
+					match (Written, Taken) {
+						(Some(Sent), _) => Sent?,
+						(None, Some(Taken)) => {
+							break 'Served (Some(Taken), [vec![Reply], Ready.collect()].concat());
+						},
+						(None, None) => self.Send(&mut Writer, &Reply).await?,
+					}
+				}
+
+				// The transfer that sent a chunk waits for the others' turn
+				if let Some(Transfer) = Chunk.then(|| Transfers.pop_front()).flatten() {
+					if !Transfer.Done() {
+						Transfers.push_back(Transfer);
+					}
+				}
+			};
+
+			let Some((Connection, Forward)) = Taken else {
+				return Writer.Close().await;
+			};
+
+			// The client is told and its stream ends, without waiting long on
+			// a half-dead one
+			let _ = Runtime
+				.Timeout(Duration::from_secs(1), async {
+					self.Send(&mut Writer, &Reply::Superseded { Connection }).await?;
+
+					Writer.Close().await
+				})
+				.await;
+
+			drop(Writer);
+
+			// Unfinished transfers start over on the connection taking over
+			let Left = Left
+				.into_iter()
+				.filter(|Reply| {
+					!matches!(
+						Reply,
+						Reply::ResultChunk { .. }
+							| Reply::ResultEnd { .. }
+							| Reply::ResultAbort { .. }
+					)
+				})
+				.chain(Transfers.into_iter().map(Chunked::Struct::Restart));
+
+			for Reply in Left {
+				let _ = Forward.send(Reply);
+			}
+
+			// The remaining jobs reply to the connection taking over, in the
+			// order this one would have written them
+			loop {
+				let Reply = select! {
+					biased;
+
+					Some(Reply) = Streamed.recv() => Reply,
+					Reply = Receiver.recv() => match Reply {
+						Some(Reply) => Reply,
+						None => break,
+					},
+				};
+
+				for Reply in self.Ready(Reply, &mut Order).await {
+					let _ = Forward.send(Reply);
+				}
+			}
+
+			for Reply in Order.map(|mut Order| Order.Release()).unwrap_or_default() {
+				let _ = Forward.send(Reply);
+			}
+
+			Ok(())
+		};
+
+		// Either half failing ends the other, so a broken stream is not left
+		// half served
+		let Served = tokio::try_join!(Read, Write).map(|_| ());
+
+		// A closed connection presses no longer, nor can it be taken over
+		self.Pressure(0, Connection, Submitter, Pressed).await;
+
+		self.Leave(Connection);
+
+		self.Activity.End(Served.is_ok());
+
+		if let Some(Bus) = &Bus {
+			Bus.Emit(|| Event::Closed { Connection });
+		}
+
+		Served
+	}
+
+	/// Presses or releases the identity of a connection in the backpressure
+	/// gate of the target's `Life`, for the replies waiting to be written.
+	async fn Pressure(
+		&self,
+		Backlog:usize,
+		Connection:u64,
+		Submitter:&Mutex<Option<String>>,
+		Pressed:&Mutex<Option<String>>,
+	) {
+		let (Some((High, Low)), Target::Life(Life)) = (self.Backpressure, &self.Target) else {
+			return;
+		};
+
+		let Released = {
+			let mut Pressed = Lock(Pressed);
+
+			match Pressed.take() {
+				Some(Identity) if Backlog <= Low => Some(Identity),
+				None if Backlog >= High => {
+					let Identity =
+						Lock(Submitter).clone().unwrap_or_else(|| "anonymous".to_string());
+
+					Life.Backpressure.Press(&Identity, Connection);
+
+					*Pressed = Some(Identity);
+
+					None
+				},
+				Kept => {
+					*Pressed = Kept;
+
+					None
+				},
+			}
+		};
+
+		if let Some(Identity) = Released {
+			Life.Backpressure.Release(&Identity, Connection).await;
+		}
+	}
+
+	/// Registers an authenticated connection under its identity, applying
+	/// the takeover policy of the identity to the connections it already
+	/// has open.
+	///
+	/// # Returns
+	///
+	/// Whether the connection may go on, `false` if the policy refuses it.
+	async fn Join(
+		&self,
+		Session:&mut Session,
+		Supersede:&UnboundedSender<Successor>,
+		Sender:&UnboundedSender<Reply>,
+	) -> bool {
+		let Identity = Session.Submitter();
+
+		let Previous = {
+			let mut Live = Lock(&self.Live);
+
+			// A connection authenticating again leaves its former identity
+			for Connections in Live.values_mut() {
+				Connections.retain(|Live| Live.Connection != Session.Connection);
+			}
+
+			let Connections = Live.entry(Identity.clone()).or_default();
+
+			let Previous = match self.Policy(&Identity) {
+				Takeover::Reject if !Connections.is_empty() => {
+					warn!(
+						"Refused connection {}: {} already has a connection open",
+						Session.Connection, Identity
+					);
+
+					return false;
+				},
+				Takeover::TakeOver => std::mem::take(Connections),
+				_ => Vec::new(),
+			};
+
+			Connections.push(Live { Connection:Session.Connection, Supersede:Supersede.clone() });
+
+			Previous
+		};
+
+		for Previous in Previous {
+			let (Handover, Received) = oneshot::channel();
+
+			let Taking =
+				Successor { Connection:Session.Connection, Sender:Sender.clone(), Handover };
+
+			if Previous.Supersede.send(Taking).is_err() {
+				continue;
+			}
+
+			// The previous connection answers once it stopped reading
+			let Ok(Handover) = Received.await else {
+				continue;
+			};
+
+			info!(
+				"Connection {} of {} took over connection {}",
+				Session.Connection, Identity, Previous.Connection
+			);
+
+			counter!("echo_transport_takeovers_total").increment(1);
+
+			Session.Pending.extend(Handover.Pending);
+
+			// Without a `Subscribed` reply, which the client did not ask for
+			if let (Some(Filter), None) = (Handover.Filter, &Session.Forward) {
+				Self::Subscribe(&self.Target, Filter, Sender, Session);
+			}
+		}
+
+		true
+	}
+
+	/// Forgets a connection that ended.
+	fn Leave(&self, Connection:u64) {
+		let mut Live = Lock(&self.Live);
+
+		Live.retain(|_, Connections| {
+			Connections.retain(|Live| Live.Connection != Connection);
+
+			!Connections.is_empty()
+		});
+	}
+
+	/// Offloads a large result and passes a reply through the reordering
+	/// buffer of a `SubmissionOrder` connection.
+	///
+	/// # Returns
+	///
+	/// The replies that may be written now, in order.
+	async fn Ready(&self, Reply:Reply, Order:&mut Option<Order::Struct>) -> Vec<Reply> {
+		let Reply = match (Reply, &self.Offload) {
+			(Reply::Result { Id, Value, History, Annotation, OutOfOrder }, Some(Offload)) => {
+				let Value = Offload.Offload(&Id, Value).await;
+
+				Reply::Result { Id, Value, History, Annotation, OutOfOrder }
+			},
+			(Reply, _) => Reply,
+		};
+
+		match (&mut *Order, Reply) {
+			(_, Reply::Authenticated { Role, Delivery, Version }) => {
+				let Released = match Delivery {
+					Delivery::SubmissionOrder => {
+						Order.get_or_insert_with(|| Order::Struct::New(self.Reorder));
+
+						Vec::new()
+					},
+					Delivery::Unordered => {
+						Order.take().map(|mut Order| Order.Release()).unwrap_or_default()
+					},
+				};
+
+				[vec![Reply::Authenticated { Role, Delivery, Version }], Released].concat()
+			},
+			(Some(Order), Reply::Ack { Id }) => {
+				Order.Submitted(Id.clone());
+
+				vec![Reply::Ack { Id }]
+			},
+			(Some(Order), Reply @ (Reply::Result { .. } | Reply::Error { Id: Some(_), .. })) => {
+				Order.Complete(Reply)
+			},
+			(_, Reply) => vec![Reply],
+		}
+	}
+
+	/// Encodes and writes one reply.
+	///
+	/// A reply that cannot be encoded is replaced by an `Error` reply about
+	/// the same submission, and counted in
+	/// `echo_transport_encode_errors_total`.
+	async fn Send(&self, Writer:&mut impl Writer, Reply:&Reply) -> io::Result<()> {
+		let Frame = match self.Codec.Encode(Reply) {
+			Ok(Frame) => Frame,
+			Err(_Error) => {
+				error!("Cannot encode {:?} reply: {}", Reply.Id(), _Error);
+
+				counter!("echo_transport_encode_errors_total").increment(1);
+
+				match self.Codec.Encode(&Reply::Error {
+					Id:Reply.Id().map(str::to_string),
+					Message:format!("Cannot encode reply: {}", _Error),
+					Code:Some(Code::Encoding),
+					OutOfOrder:false,
+				}) {
+					Ok(Frame) => Frame,
+					Err(_Error) => return Ok(()),
+				}
+			},
+		};
+
+		Writer.Write(&Frame).await
+	}
+
+	/// Handles one decoded message of an authenticated stream.
+	async fn Handle(
+		&self,
+		Message:Message,
+		Sender:&UnboundedSender<Reply>,
+		Partial:&mpsc::Sender<Reply>,
+		Session:&mut Session,
+	) {
+		match Message {
+			Message::Submit { Id, Action, Argument, mut Metadata, Options } => {
+				Metadata.insert("SubmittedBy".to_string(), Value::String(Session.Submitter()));
+
+				let Submission = (Id.clone(), Action.clone(), Record::Digest(&Argument));
+
+				let Unknown = (!self.AcceptUnknown && !self.Plan.Has(&Action))
+					.then(|| self.Plan.Unknown(&Action));
+
+				let Unnamed = Options.keys().find(|Key| Reserved::Parse(Key).is_none()).cloned();
+
+				// A retried submission shares the terminal state of the first
+				let Terminal =
+					Session.Pending.get(&Id).and_then(Weak::upgrade).unwrap_or_else(|| {
+						Arc::new(Terminal::Struct::New(
+							self.SuppressDuplicates,
+							self.Duplicates.clone(),
+						))
+					});
+
+				let Job = Job::Struct::New(
+					Id.clone(),
+					Action,
+					Argument,
+					Metadata,
+					self.Plan.clone(),
+					Sender.clone(),
+				)
+				.WithOptions(Options)
+				.WithPartial(Partial.clone())
+				.WithTerminal(Terminal);
+
+				let Refusal = match Unnamed {
+					Some(Key) => {
+						Self::Refuse(Job, Code::Malformed, format!("Unknown option {}", Key))
+					},
+					None => self.Submit(Job, Id, Unknown, Sender, Session).await,
+				};
+
+				self.Audit(Session, Some(Submission), Refusal);
+			},
+			Message::Cancel { Id } => {
+				let Cancelled = Session
+					.Pending
+					.remove(&Id)
+					.and_then(|Terminal| Terminal.upgrade())
+					.is_some_and(|Terminal| Terminal.Close());
+
+				if Cancelled {
+					let _ = Sender.send(Reply::Error {
+						Id:Some(Id.clone()),
+						Message:"Action was cancelled".to_string(),
+						Code:Some(Code::Cancelled),
+						OutOfOrder:false,
+					});
+				}
+
+				let _ = Sender.send(Reply::Cancelled { Id, Cancelled });
+			},
+			Message::Describe => {
+				let _ = Sender.send(Reply::Description {
+					Action:self.Plan.Names(),
+					Version:WIRE_VERSION,
+					Catalogue:Code::Catalogue(),
+				});
+			},
+			Message::Subscribe { Filter } => {
+				let _ = Sender.send(if Self::Subscribe(&self.Target, Filter, Sender, Session) {
+					Reply::Subscribed
+				} else {
+					Reply::Error {
+						Id:None,
+						Message:"Events require a pump serving a Life".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					}
+				});
+			},
+			Message::Ping => {
+				let _ = Sender.send(Reply::Pong);
+			},
+			Message::Health => {
+				let _ = Sender.send(match &self.Target {
+					Target::Life(Life) => Reply::Health { Health:Life.Health().await },
+					Target::Production(_) => Reply::Error {
+						Id:None,
+						Message:"Health requires a pump serving a Life".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					},
+				});
+			},
+			Message::Stats => {
+				let _ = Sender.send(match &self.Target {
+					Target::Life(Life) => Reply::Stats { Stats:Life.Stats() },
+					Target::Production(_) => Reply::Error {
+						Id:None,
+						Message:"Stats require a pump serving a Life".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					},
+				});
+			},
+			Message::Summary => {
+				let _ = Sender.send(match &self.Target {
+					Target::Life(Life) => Reply::Summary { Queue:Life.Summary().await },
+					Target::Production(_) => Reply::Error {
+						Id:None,
+						Message:"Summary requires a pump serving a Life".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					},
+				});
+			},
+			Message::InFlight => {
+				let _ = Sender.send(match &self.Target {
+					Target::Life(Life) => Reply::InFlight { Entry:Life.Watchdog.InFlight() },
+					Target::Production(_) => Reply::Error {
+						Id:None,
+						Message:"InFlight requires a pump serving a Life".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					},
+				});
+			},
+			Message::Fetch { Stored } => {
+				let _ = Sender.send(match &self.Offload {
+					Some(Offload) => match Offload.Resolve(&Stored).await {
+						Ok(Value) => Reply::Fetched { Value },
+						Err(_Error) => Reply::Error {
+							Id:None,
+							Message:format!("Cannot fetch {}: {}", Stored.Ref, _Error),
+							Code:Some(Code::NotFound),
+							OutOfOrder:false,
+						},
+					},
+					None => Reply::Error {
+						Id:None,
+						Message:"Fetch requires a pump offloading results".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					},
+				});
+			},
+			Message::DeadLetterList { Queue, Filter } => {
+				let _ = Sender.send(
+					self.DeadLetter(|DeadLetter| async move {
+						let Entry = DeadLetter.List(&Queue, &Filter).await?;
+
+						Ok(Reply::DeadLetterListed { Queue, Entry })
+					})
+					.await,
+				);
+			},
+			Message::DeadLetterRequeue { Queue, Sequence, Target } => {
+				let _ = Sender.send(
+					self.DeadLetter(|DeadLetter| async move {
+						let Outcome = DeadLetter.Requeue(&Queue, &Sequence, &Target).await?;
+
+						Ok(Reply::DeadLetterRequeued { Queue, Outcome })
+					})
+					.await,
+				);
+			},
+			Message::DeadLetterPurge { Queue, Filter, DryRun } => {
+				let _ = Sender.send(
+					self.DeadLetter(|DeadLetter| async move {
+						let Entry = DeadLetter.Purge(&Queue, &Filter, DryRun).await?;
+
+						Ok(Reply::DeadLetterPurged { Queue, Entry, DryRun })
+					})
+					.await,
+				);
+			},
+			Message::QuerySubmissions { Query } => {
+				let _ = Sender
+					.send(Reply::Submissions { Page:self.History.QuerySubmissions(&Query).await });
+			},
+			Message::Handoff { Chunk } => {
+				let Target::Life(Life) = &self.Target else {
+					let _ = Sender.send(Reply::Error {
+						Id:None,
+						Message:"Handoff requires a pump serving a Life".to_string(),
+						Code:Some(Code::Unsupported),
+						OutOfOrder:false,
+					});
+
+					return;
+				};
+
+				if Session.Handoff.is_none() {
+					Session.Handoff = Some(
+						Handoff::Sender::Struct::New(Life.clone(), Chunk.unwrap_or(100)).await,
+					);
+				}
+
+				if let Some(Handoff) = &mut Session.Handoff {
+					let _ = Sender.send(Handoff.Next().await);
+				}
+			},
+			Message::HandoffAck { Chunk } => {
+				let _ = Sender.send(match &mut Session.Handoff {
+					Some(Handoff) => match Handoff.Ack(Chunk) {
+						Ok(()) => Handoff.Next().await,
+						Err(Message) => Reply::Error {
+							Id:None,
+							Message,
+							Code:Some(Code::NotFound),
+							OutOfOrder:false,
+						},
+					},
+					None => Reply::Error {
+						Id:None,
+						Message:"No handoff runs on this connection".to_string(),
+						Code:Some(Code::NotFound),
+						OutOfOrder:false,
+					},
+				});
+			},
+			// Handled by `Run` before dispatching
+			Message::Auth { .. }
+			| Message::Control { .. }
+			| Message::ResultAbort { .. }
+			| Message::Close => {},
+		}
+	}
+
+	/// Streams the events of the target's bus to a connection, replacing any
+	/// earlier subscription of it.
+	///
+	/// # Returns
+	///
+	/// Whether the target has a bus to subscribe to.
+	fn Subscribe(
+		Target:&Target,
+		Filter:Vec<String>,
+		Sender:&UnboundedSender<Reply>,
+		Session:&mut Session,
+	) -> bool {
+		let Some(Bus) = Target.Bus() else {
+			return false;
+		};
+
+		let (mut Subscription, Forward) = (Bus.Subscribe(), Sender.clone());
+
+		Session.Filter = Some(Filter.clone());
+
+		// Replacing the handle stops the previous subscription
+		Session.Forward = Some(Abort(Target.Runtime().Spawn(Box::pin(async move {
+			while let Some(Event) = Subscription.Recv().await {
+				if !Filter.is_empty() && !Filter.iter().any(|Type| Type == Event.Type()) {
+					continue;
+				}
+
+				if Forward.send(Reply::Event { Event }).is_err() {
+					break;
+				}
+			}
+		}))));
+
+		true
+	}
+
+	/// Enqueues a submitted job into its target, or rejects it.
+	///
+	/// # Returns
+	///
+	/// Why the job was rejected, or `None` if it was acknowledged.
+	async fn Submit(
+		&self,
+		Job:Job::Struct,
+		Id:String,
+		Unknown:Option<Error>,
+		Sender:&UnboundedSender<Reply>,
+		Session:&mut Session,
+	) -> Option<String> {
+		let State = self.State.Get().await;
+
+		if State != State::Open {
+			return Self::Refuse(
+				Job,
+				Code::NotAccepting,
+				format!("Submissions are not accepted: the transport is {:?}", State),
+			);
+		}
+
+		if let Some(_Error) = Unknown {
+			return Self::Refuse(Job, Code::from(&_Error), _Error.to_string());
+		}
+
+		// The admission policies of a `Life` see the job before any queue
+		let Job = match &self.Target {
+			Target::Life(Life) if !Life.Admission.is_empty() => {
+				let Identity =
+					Identity::New(Session.Submitter(), Session.Role.unwrap_or(Role::Client));
+
+				match Life.Admit(&Identity, Job.View().await).await {
+					Decision::Allow => Job,
+					Decision::Modify { Patch } => Job.Patch(Patch),
+					Decision::Deny { Reason, Code } => return Self::Refuse(Job, Code, Reason),
+				}
+			},
+			_ => Job,
+		};
+
+		// Forget completed jobs now and then
+		if Session.Pending.len() >= 64 && Session.Pending.len().is_power_of_two() {
+			Session.Pending.retain(|_, Done| Done.strong_count() > 0);
+		}
+
+		if let Some((Ingest, Transport)) = &self.Ingest {
+			let Cancellation = Job.Cancellation();
+
+			return match Ingest.Push(Transport, &Session.Submitter(), Job).await {
+				Ok(()) => {
+					Session.Pending.insert(Id, Cancellation);
+
+					None
+				},
+				Err((_, Message)) => Some(Message),
+			};
+		}
+
+		match self.Target.Resolve(&Job).await {
+			Ok(Production) => {
+				// A chain too large for the line is refused before it is acked
+				if let Err(_Error) = Production.Check(&Job).await {
+					return Self::Refuse(Job, Code::from(&_Error), _Error.to_string());
+				}
+
+				Session.Pending.insert(Id.clone(), Job.Cancellation());
+
+				let _ = Sender.send(Reply::Ack { Id });
+
+				// Checked above; a refusal drops the job, which replies
+				let _ = Production.Enqueue(Box::new(Job)).await;
+
+				None
+			},
+			Err(_Error) => Self::Refuse(Job, Code::from(&_Error), _Error.to_string()),
+		}
+	}
+
+	/// Rejects a job, which replies with the error.
+	///
+	/// # Returns
+	///
+	/// Why the job was rejected.
+	fn Refuse(Job:Job::Struct, Code:Code, Message:String) -> Option<String> {
+		Job.Reject(Code, Message.clone());
+
+		Some(Message)
+	}
+
+	/// Records a submission, or a malformed message, in the history.
+	///
+	/// # Arguments
+	///
+	/// * `Session` - The connection it arrived on.
+	/// * `Submission` - Its identifier, action type and argument digest, or
+	///   `None` for a malformed message.
+	/// * `Refusal` - Why it was rejected, or `None` if it was accepted.
+	fn Audit(
+		&self,
+		Session:&Session,
+		Submission:Option<(String, String, String)>,
+		Refusal:Option<String>,
+	) {
+		let (Id, Action, Digest) = match Submission {
+			Some((Id, Action, Digest)) => (Some(Id), Some(Action), Some(Digest)),
+			None => (None, None, None),
+		};
+
+		self.History.Record(Record {
+			Sequence:0,
+			Time:SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_millis() as u64,
+			Identity:Session.Submitter(),
+			Connection:Session.Connection,
+			Id,
+			Action,
+			Digest,
+			Admission:if Refusal.is_none() { Admission::Accepted } else { Admission::Rejected },
+			Reason:Refusal,
+		});
+	}
+
+	/// Runs a dead-letter operation on the `Life` the pump serves.
+	async fn DeadLetter<F, O>(&self, Operation:F) -> Reply
+	where
+		F: FnOnce(Arc<DeadLetter::Struct>) -> O,
+		O: Future<Output = Result<Reply, Error>>, {
+		let Target::Life(Life) = &self.Target else {
+			return Reply::Error {
+				Id:None,
+				Message:"Dead-letter operations require a pump serving a Life".to_string(),
+				Code:Some(Code::Unsupported),
+				OutOfOrder:false,
+			};
+		};
+
+		Operation(Life.DeadLetter.clone())
+			.await
+			.unwrap_or_else(|_Error| Reply::Error {
+				Id:None,
+				Message:_Error.to_string(),
+				Code:Some(Code::from(&_Error)),
+				OutOfOrder:false,
+			})
+	}
+
+	/// Runs a control command, on a single Karma queue if `Queue` names one.
+	async fn Control(
+		&self,
+		Control:Control,
+		DeadlineMs:Option<Span>,
+		Queue:Option<String>,
+	) -> Reply {
+		info!("Running {:?} control", Control);
+
+		let Drain = match (Control, Queue, &self.Target) {
+			(Control::Pause | Control::Resume, Some(Queue), Target::Life(Life)) => {
+				if Control == Control::Pause {
+					Life.PauseQueue(&Queue);
+				} else {
+					Life.ResumeQueue(&Queue);
+				}
+
+				None
+			},
+			(Control::Pause | Control::Resume, Some(_), Target::Production(_)) => {
+				return Reply::Error {
+					Id:None,
+					Message:"Pausing a queue requires a pump serving a Life".to_string(),
+					Code:Some(Code::Unsupported),
+					OutOfOrder:false,
+				};
+			},
+			(Control::Drain, ..) => Some(self.Drain(DeadlineMs.map(Span::Get)).await),
+			(Control::Pause, ..) => {
+				self.Shift(State::Open, State::Paused).await;
+
+				None
+			},
+			(Control::Resume, ..) => {
+				self.Shift(State::Paused, State::Open).await;
+
+				None
+			},
+			(Control::Status, ..) => None,
+		};
+
+		let Paused = match &self.Target {
+			Target::Life(Life) => Life.PausedQueues(),
+			Target::Production(_) => Vec::new(),
+		};
+
+		Reply::Control { Control, State:self.State.Get().await, Drain, Paused }
+	}
+
+	/// Changes the state if it currently is `From`.
+	async fn Shift(&self, From:State, To:State) {
+		if self.State.Get().await == From {
+			self.State.Set(To).await;
+		}
+	}
+
+	/// Stops accepting submissions and waits for the remaining work.
+	async fn Drain(&self, Deadline:Option<Duration>) -> Drain::Struct {
+		self.State.Set(State::Draining).await;
+
+		let Runtime = self.Target.Runtime();
+
+		let Start = Runtime.Now();
+
+		let Before = self.Count();
+
+		let mut Remaining = self.Remaining();
+
+		while Remaining > 0
+			&& Deadline.is_none_or(|Deadline| Runtime.Now().duration_since(Start) < Deadline)
+		{
+			Runtime.Sleep(Duration::from_millis(20)).await;
+
+			Remaining = self.Remaining();
+		}
+
+		self.State.Set(State::Drained).await;
+
+		let After = self.Count();
+
+		let Abandoned = match &self.Target {
+			Target::Life(Life) if Remaining > 0 => Life.Watchdog.InFlight(),
+			_ => Vec::new(),
+		};
+
+		Drain::Struct {
+			Drained:Remaining == 0,
+			Completed:(After.Total - After.Failed).saturating_sub(Before.Total - Before.Failed),
+			Failed:After.Failed.saturating_sub(Before.Failed),
+			Remaining,
+			Elapsed:Runtime.Now().duration_since(Start).as_millis() as u64,
+			Abandoned,
+		}
+	}
+
+	/// Sums the counters of the sequences serving the target.
+	fn Count(&self) -> Count::Struct {
+		match &self.Target {
+			Target::Life(Life) => Life
+				.Registry
+				.Sequences()
+				.into_iter()
+				.fold(Count::Struct::default(), |Sum, Count| Sum + Count),
+			Target::Production(_) => Count::Struct::default(),
+		}
+	}
+
+	/// Counts the actions still queued or running for the target.
+	fn Remaining(&self) -> u64 {
+		match &self.Target {
+			Target::Life(Life) => {
+				Life.Karma
+					.iter()
+					.map(|Entry| Entry.value().Stats().Depth)
+					.sum::<u64>() + self.Count().Active
+			},
+			Target::Production(Production) => Production.Stats().Depth,
+		}
+	}
+}
+
+/// The state of one connection.
+#[derive(Default)]
+struct Session {
+	/// The identifier of the connection.
+	Connection:u64,
+
+	/// The identity the connection authenticated as, if it did.
+	Identity:Option<String>,
+
+	/// The role of the connection, once it may submit.
+	Role:Option<Role>,
+
+	/// The cancellation handles of submitted jobs, by identifier.
+	Pending:HashMap<String, Weak<Terminal::Struct>>,
+
+	/// The task forwarding subscribed events, if any.
+	Forward:Option<Abort>,
+
+	/// The event types the connection subscribed to, if it did.
+	Filter:Option<Vec<String>>,
+
+	/// The handoff of the queued actions to this connection, if it asked.
+	Handoff:Option<Handoff::Sender::Struct>,
+}
+
+impl Session {
+	/// Returns the identity submissions are attributed to: the one the
+	/// connection authenticated as, or `anonymous`.
+	fn Submitter(&self) -> String {
+		self.Identity.clone().unwrap_or_else(|| "anonymous".to_string())
+	}
+}
+
+/// An authenticated connection, as the connections of the same identity see
+/// it.
+struct Live {
+	/// The identifier of the connection.
+	Connection:u64,
+
+	/// Where a takeover of the connection is announced.
+	Supersede:UnboundedSender<Successor>,
+}
+
+/// A connection taking another over.
+struct Successor {
+	/// The identifier of the connection taking over.
+	Connection:u64,
+
+	/// Where the replies still owed are forwarded.
+	Sender:UnboundedSender<Reply>,
+
+	/// Where the connection taken over hands over its session once it
+	/// stopped reading.
+	Handover:oneshot::Sender<Handover>,
+}
+
+/// What a connection taken over hands over to its successor.
+struct Handover {
+	/// The cancellation handles of its submitted jobs, by identifier.
+	Pending:HashMap<String, Weak<Terminal::Struct>>,
+
+	/// The event types it subscribed to, if it did.
+	Filter:Option<Vec<String>>,
+}
+
+/// Takes a lock, ignoring poisoning.
+fn Lock<T>(Mutex:&Mutex<T>) -> MutexGuard<'_, T> {
+	Mutex.lock().unwrap_or_else(|Poison| Poison.into_inner())
+}
+
+/// Aborts a task when dropped.
+struct Abort(Spawned::Struct);
+
+impl Drop for Abort {
+	fn drop(&mut self) { self.0.Abort(); }
+}
+
+use std::{
+	collections::{HashMap, HashSet, VecDeque},
+	future::Future,
+	io,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+		Mutex,
+		MutexGuard,
+		Weak,
+	},
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, info, warn};
+use metrics::counter;
+use serde_json::Value;
+use tokio::{
+	select,
+	sync::{
+		mpsc::{self, channel, unbounded_channel, UnboundedSender},
+		oneshot,
+		Notify,
+	},
+};
+
+use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{
+			Action::Error::Enum as Error,
+			Decision::Enum as Decision,
+			Reserved::Enum as Reserved,
+		},
+		Source::Target::Enum as Target,
+		Transport::{
+			Admission::Enum as Admission,
+			Code::Enum as Code,
+			Control::Enum as Control,
+			Delivery::Enum as Delivery,
+			Message::Enum as Message,
+			Reply::Enum as Reply,
+			Role::Enum as Role,
+			State::Enum as State,
+			Takeover::Enum as Takeover,
+		},
+	},
+	Struct::{
+		Runtime::Spawned,
+		Sequence::{
+			Admission::Identity::Struct as Identity,
+			DeadLetter,
+			Glob,
+			Plan::Formality::Struct as Formality,
+			Signal::Struct as Signal,
+		},
+		Stats::{Activity, Activity::Count},
+		Storage::Offload,
+		Transport::{
+			Chunked,
+			Codec::Json,
+			Drain,
+			Handoff,
+			History::{self, Record::Struct as Record},
+			Ingest,
+			Job,
+			Order,
+			Terminal,
+		},
+	},
+	Time::Duration::Struct as Span,
+	Trait::{
+		Runtime::Trait as Runtime,
+		Sequence::Action::Trait as Action,
+		Transport::{Codec::Trait as Codec, Reader::Trait as Reader, Writer::Trait as Writer},
+	},
+	Wire::WIRE_VERSION,
+};
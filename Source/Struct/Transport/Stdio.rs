@@ -0,0 +1,57 @@
+/// A transport serving the message protocol over standard input and output.
+///
+/// Messages and replies are newline-delimited JSON, one per line. This lets
+/// a parent process (such as an editor extension host) drive a worker
+/// without opening a socket. The end of input drains the transport.
+#[derive(Clone)]
+pub struct Struct {
+	/// The message loop serving the streams.
+	pub Pump:Pump::Struct,
+}
+
+impl Struct {
+	/// Creates a new stdio transport.
+	///
+	/// # Arguments
+	///
+	/// * `Pump` - The message loop serving the streams.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Pump:Pump::Struct) -> Self { Struct { Pump } }
+
+	/// Serves the process's standard input and output.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once input ended and every job replied, or the first I/O
+	/// error.
+	pub async fn Run(&self) -> io::Result<()> { self.Serve(stdin(), stdout()).await }
+
+	/// Serves arbitrary streams with the stdio framing.
+	///
+	/// # Arguments
+	///
+	/// * `Input` - The stream messages are read from.
+	/// * `Output` - The stream replies are written to.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once input ended and every job replied, or the first I/O
+	/// error.
+	pub async fn Serve<R, W>(&self, Input:R, Output:W) -> io::Result<()>
+	where
+		R: AsyncRead + Unpin + Send,
+		W: AsyncWrite + Unpin + Send, {
+		self.Pump
+			.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	}
+}
+
+use std::io;
+
+use tokio::io::{stdin, stdout, AsyncRead, AsyncWrite};
+
+use crate::Struct::Transport::{Frame::Line, Pump};
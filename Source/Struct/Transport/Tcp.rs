@@ -0,0 +1,137 @@
+/// A transport serving the message protocol on a TCP port.
+///
+/// For clients that cannot speak WebSocket: every frame is a big-endian
+/// `u32` length followed by one message in the pump's codec. Frames above
+/// the limit and connections idle for longer than the timeout are closed.
+#[derive(Clone)]
+pub struct Struct {
+	/// The message loop serving each connection.
+	pub Pump:Pump::Struct,
+
+	/// The address to listen on, e.g. `127.0.0.1:9000`.
+	pub Address:String,
+
+	/// The largest accepted frame, in bytes.
+	pub Limit:usize,
+
+	/// How long a connection may stay silent before it is closed.
+	pub Idle:Duration,
+}
+
+impl Struct {
+	/// Creates a new TCP transport accepting frames of up to 16 MiB and
+	/// closing connections idle for five minutes.
+	///
+	/// # Arguments
+	///
+	/// * `Pump` - The message loop serving each connection.
+	/// * `Address` - The address to listen on.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Pump:Pump::Struct, Address:&str) -> Self {
+		Struct {
+			Pump,
+			Address:Address.to_string(),
+			Limit:16 * 1024 * 1024,
+			Idle:Duration::from_secs(300),
+		}
+	}
+
+	/// Sets the largest accepted frame.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - The largest accepted frame, in bytes.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithLimit(mut self, Limit:usize) -> Self {
+		self.Limit = Limit;
+
+		self
+	}
+
+	/// Sets the idle timeout.
+	///
+	/// # Arguments
+	///
+	/// * `Idle` - How long a connection may stay silent.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithIdle(mut self, Idle:Duration) -> Self {
+		self.Idle = Idle;
+
+		self
+	}
+
+	/// Binds the port and starts accepting connections.
+	///
+	/// Shutting the handle down stops accepting; open connections finish on
+	/// their own.
+	///
+	/// # Returns
+	///
+	/// A `Handle` stopping the listener together with the bound address
+	/// (useful when listening on port 0), or the I/O error that prevented
+	/// binding.
+	pub async fn Start(self) -> io::Result<(Handle::Struct, SocketAddr)> {
+		let Listener = TcpListener::bind(&self.Address).await?;
+
+		let Address = Listener.local_addr()?;
+
+		let Runtime = self.Pump.Runtime();
+
+		let Handle = Handle::Struct::SpawnOn(Runtime.clone(), move |mut Stop| async move {
+			loop {
+				select! {
+					_ = Stopped(&mut Stop) => break,
+					Connection = Listener.accept() => match Connection {
+						Ok((Stream, Peer)) => {
+							let Pump = self.Pump.clone();
+
+							let (Limit, Idle, Timer) = (self.Limit, self.Idle, Runtime.clone());
+
+							Runtime.Spawn(Box::pin(async move {
+								let _ = Stream.set_nodelay(true);
+
+								let (Input, Output) = Stream.into_split();
+
+								if let Err(_Error) = Pump
+									.Run(
+										Frame::Idle::Struct::New(Frame::Length::Reader::Struct::New(Input, Limit), Idle)
+											.WithRuntime(Timer),
+										Frame::Length::Writer::Struct::New(Output),
+									)
+									.await
+								{
+									warn!("TCP connection from {} ended with an error: {}", Peer, _Error);
+								}
+							}));
+						},
+						Err(_Error) => Report::Fn("tcp", &format!("Cannot accept a connection: {}", _Error)),
+					},
+				}
+			}
+		});
+
+		Ok((Handle, Address))
+	}
+}
+
+use std::{io, net::SocketAddr, time::Duration};
+
+use log::warn;
+use tokio::{net::TcpListener, select};
+
+use crate::{
+	Fn::Report,
+	Struct::{
+		Source::Handle::{self, Stopped},
+		Transport::{Frame, Pump},
+	},
+};
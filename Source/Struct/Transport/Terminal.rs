@@ -0,0 +1,133 @@
+/// The terminal state of one request, shared by every job submitted under
+/// its identifier on a connection.
+///
+/// The first result settles the request and is delivered. A later result is
+/// a duplicate, e.g. of an attempt presumed dead that was only slow: it is
+/// counted, logged with the attempt that won and recorded in the history of
+/// the delivered result, and withheld unless the terminal delivers
+/// duplicates. A cancellation or a final failure settles the request too,
+/// and results after those are dropped as before.
+pub struct Struct {
+	/// Whether the request is settled.
+	Done:AtomicBool,
+
+	/// The attempt whose result was delivered and the history it was
+	/// delivered with, once one was.
+	Delivered:Mutex<Option<(Attempt, History)>>,
+
+	/// Whether duplicates are withheld from the client.
+	Suppress:bool,
+
+	/// The number of duplicates, shared with the pump.
+	Duplicates:Arc<AtomicU64>,
+}
+
+impl Struct {
+	/// Creates the terminal state of an unsettled request.
+	///
+	/// # Arguments
+	///
+	/// * `Suppress` - Whether duplicates are withheld from the client.
+	/// * `Duplicates` - Where duplicates are counted.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Suppress:bool, Duplicates:Arc<AtomicU64>) -> Self {
+		Struct { Done:AtomicBool::new(false), Delivered:Mutex::new(None), Suppress, Duplicates }
+	}
+
+	/// Settles the request with a result, unless it already was.
+	///
+	/// The check and the mark happen under one lock, so of two results
+	/// racing exactly one wins.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the request.
+	/// * `History` - The attempts that produced the result, the last being
+	///   the one that succeeded.
+	///
+	/// # Returns
+	///
+	/// Whether the result is delivered: `true` for the first, `false` for a
+	/// duplicate unless duplicates are delivered, and `false` after a
+	/// cancellation or failure.
+	pub fn Settle(&self, Id:&str, History:&History) -> bool {
+		let mut Delivered = self.Delivered.lock().unwrap_or_else(|Poison| Poison.into_inner());
+
+		let Attempt = History.Last().cloned();
+
+		if !self.Done.swap(true, Ordering::SeqCst) {
+			*Delivered = Attempt.map(|Attempt| (Attempt, History.clone()));
+
+			return true;
+		}
+
+		let (Some((First, Delivered)), Some(mut Attempt)) = (Delivered.as_mut(), Attempt) else {
+			return false;
+		};
+
+		self.Duplicates.fetch_add(1, Ordering::Relaxed);
+
+		counter!("echo_result_duplicates_total").increment(1);
+
+		warn!(
+			"{} a duplicate result of {}: attempt {} ({} to {}) after attempt {} ({} to {}) was \
+			 delivered",
+			if self.Suppress { "Suppressed" } else { "Delivering" },
+			Id,
+			Attempt.Attempt,
+			Attempt.Started,
+			Attempt.Ended,
+			First.Attempt,
+			First.Started,
+			First.Ended
+		);
+
+		Attempt.Outcome = Outcome::Duplicate;
+
+		Delivered.Push(Attempt);
+
+		!self.Suppress
+	}
+
+	/// Settles the request without a result, as a cancellation or a final
+	/// failure does.
+	///
+	/// # Returns
+	///
+	/// Whether this settled it; `false` if it already was.
+	pub fn Close(&self) -> bool { !self.Done.swap(true, Ordering::SeqCst) }
+
+	/// Returns whether the request is settled.
+	pub fn Settled(&self) -> bool { self.Done.load(Ordering::SeqCst) }
+
+	/// Returns the history of the delivered result, duplicates included, if
+	/// a result was delivered.
+	pub fn History(&self) -> Option<History> {
+		self.Delivered
+			.lock()
+			.unwrap_or_else(|Poison| Poison.into_inner())
+			.as_ref()
+			.map(|(_, History)| History.clone())
+	}
+}
+
+impl Default for Struct {
+	fn default() -> Self { Self::New(true, Arc::new(AtomicU64::new(0))) }
+}
+
+use std::sync::{
+	atomic::{AtomicBool, AtomicU64, Ordering},
+	Arc,
+	Mutex,
+};
+
+use log::warn;
+use metrics::counter;
+
+use crate::{
+	Enum::Sequence::Attempt::Outcome::Enum as Outcome,
+	Struct::Sequence::Attempt::{History::Struct as History, Struct as Attempt},
+};
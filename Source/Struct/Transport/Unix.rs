@@ -0,0 +1,163 @@
+/// A transport serving the message protocol on a Unix domain socket.
+///
+/// Local clients connect without opening a TCP port. Each connection uses
+/// length-prefixed frames and is served by its own copy of the pump, so
+/// several transports sharing one pump feed the same queue.
+#[derive(Clone)]
+pub struct Struct {
+	/// The message loop serving each connection.
+	pub Pump:Pump::Struct,
+
+	/// The filesystem path of the socket.
+	pub Path:PathBuf,
+
+	/// The permission bits applied to the socket file.
+	pub Mode:u32,
+
+	/// The largest accepted frame, in bytes.
+	pub Limit:usize,
+}
+
+impl Struct {
+	/// Creates a new Unix transport, reachable only by its owner and
+	/// accepting frames of up to 16 MiB.
+	///
+	/// # Arguments
+	///
+	/// * `Pump` - The message loop serving each connection.
+	/// * `Path` - The filesystem path of the socket.
+	///
+	/// # Returns
+	///
+	/// A new `Struct` instance.
+	pub fn New(Pump:Pump::Struct, Path:impl Into<PathBuf>) -> Self {
+		Struct { Pump, Path:Path.into(), Mode:0o600, Limit:16 * 1024 * 1024 }
+	}
+
+	/// Sets the permission bits of the socket file.
+	///
+	/// # Arguments
+	///
+	/// * `Mode` - The permission bits, e.g. `0o660`.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithMode(mut self, Mode:u32) -> Self {
+		self.Mode = Mode;
+
+		self
+	}
+
+	/// Sets the largest accepted frame.
+	///
+	/// # Arguments
+	///
+	/// * `Limit` - The largest accepted frame, in bytes.
+	///
+	/// # Returns
+	///
+	/// The modified `Struct` instance.
+	pub fn WithLimit(mut self, Limit:usize) -> Self {
+		self.Limit = Limit;
+
+		self
+	}
+
+	/// Binds the socket and starts accepting connections.
+	///
+	/// A socket file left behind by a previous process is removed first;
+	/// one that still accepts connections is reported as `AddrInUse`.
+	/// Shutting the handle down stops accepting and removes the socket file,
+	/// while open connections finish on their own.
+	///
+	/// # Returns
+	///
+	/// A `Handle` stopping the listener, or the I/O error that prevented
+	/// binding.
+	pub async fn Start(self) -> io::Result<Handle::Struct> {
+		Self::Clean(&self.Path).await?;
+
+		let Listener = UnixListener::bind(&self.Path)?;
+
+		fs::set_permissions(&self.Path, Permissions::from_mode(self.Mode)).await?;
+
+		let Runtime = self.Pump.Runtime();
+
+		Ok(Handle::Struct::SpawnOn(Runtime.clone(), move |mut Stop| async move {
+			loop {
+				select! {
+					_ = Stopped(&mut Stop) => break,
+					Connection = Listener.accept() => match Connection {
+						Ok((Stream, _)) => {
+							let Pump = self.Pump.clone();
+
+							let Limit = self.Limit;
+
+							Runtime.Spawn(Box::pin(async move {
+								let (Input, Output) = Stream.into_split();
+
+								if let Err(_Error) = Pump
+									.Run(Length::Reader::Struct::New(Input, Limit), Length::Writer::Struct::New(Output))
+									.await
+								{
+									warn!("Unix connection ended with an error: {}", _Error);
+								}
+							}));
+						},
+						Err(_Error) => Report::Fn("unix", &format!("Cannot accept a connection: {}", _Error)),
+					},
+				}
+			}
+
+			let _ = fs::remove_file(&self.Path).await;
+		}))
+	}
+
+	/// Removes a stale socket file at the path, if any.
+	async fn Clean(Path:&Path) -> io::Result<()> {
+		let Metadata = match fs::symlink_metadata(Path).await {
+			Ok(Metadata) => Metadata,
+			Err(_Error) if _Error.kind() == io::ErrorKind::NotFound => return Ok(()),
+			Err(_Error) => return Err(_Error),
+		};
+
+		if !Metadata.file_type().is_socket() {
+			return Err(io::Error::new(
+				io::ErrorKind::AlreadyExists,
+				format!("{} exists and is not a socket", Path.display()),
+			));
+		}
+
+		if UnixStream::connect(Path).await.is_ok() {
+			return Err(io::Error::new(
+				io::ErrorKind::AddrInUse,
+				format!("{} is served by another process", Path.display()),
+			));
+		}
+
+		fs::remove_file(Path).await
+	}
+}
+
+use std::{
+	fs::Permissions,
+	io,
+	os::unix::fs::{FileTypeExt, PermissionsExt},
+	path::{Path, PathBuf},
+};
+
+use log::warn;
+use tokio::{
+	fs,
+	net::{UnixListener, UnixStream},
+	select,
+};
+
+use crate::{
+	Fn::Report,
+	Struct::{
+		Source::Handle::{self, Stopped},
+		Transport::{Frame::Length, Pump},
+	},
+};
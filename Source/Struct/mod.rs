@@ -1 +1,24 @@
+#[cfg(feature = "Client")]
+pub mod Client;
+
+pub mod Event;
+
+pub mod Health;
+
+#[cfg(feature = "Tauri")]
+pub mod Integration;
+
+pub mod Runtime;
+
 pub mod Sequence;
+pub mod Source;
+
+pub mod Stats;
+
+pub mod Storage;
+
+pub mod Store;
+
+pub mod Supervisor;
+
+pub mod Transport;
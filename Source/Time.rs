@@ -0,0 +1,111 @@
+//! Durations and points in time as they appear in metadata, configuration
+//! and wire messages, read the same way everywhere.
+//!
+//! A `Duration` is written as a number of milliseconds, integer or
+//! fractional, or as a string of numbers each followed by a unit, such as
+//! `"250ms"`, `"2s"`, `"5m"` or `"1h30m"`. A `Timestamp` is written as an
+//! RFC 3339 string such as `"2026-10-16T04:22:15.250Z"`, or as a number of
+//! milliseconds since the Unix epoch. Negative values, and values beyond
+//! `MAXIMUM` or the year 9999, are rejected.
+//!
+//! The reserved metadata `Delay`, `Ttl` and `Timeout` are durations, and
+//! `Deadline` and `ExpiresAt` timestamps.
+//!
+//! `Delay` and the `[registry]`, `[cost]` and `[watchdog]` intervals counted
+//! seconds before they became durations. A bare number for them is still
+//! read as milliseconds, but is deprecated and logged once per key; writing
+//! a unit, as in `"60s"`, says which is meant.
+
+/// The longest duration accepted, a hundred years of 365 days.
+pub const MAXIMUM:std::time::Duration = std::time::Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+/// Reads a metadata entry of an action as a duration or timestamp.
+///
+/// A value that does not parse is logged and treated as absent, the way an
+/// action without the entry is handled.
+///
+/// # Arguments
+///
+/// * `Action` - The action whose metadata is read.
+/// * `Key` - The metadata key, such as `Timeout`.
+///
+/// # Returns
+///
+/// The parsed value, or `None` if the entry is absent or invalid.
+pub async fn Metadata<T:DeserializeOwned>(Action:&dyn Action, Key:&str) -> Option<T> {
+	let Value = Action.Metadata(Key).await?;
+
+	Legacy(Key, &Value);
+
+	T::deserialize(&Value)
+		.map_err(|_Error| warn!("Ignoring invalid {} metadata {}: {}", Key, Value, _Error))
+		.ok()
+}
+
+/// Warns, once per key and process, that a bare number was read for a key
+/// that counted seconds before it became a duration.
+///
+/// The number is read as milliseconds regardless, so a `Delay` of `60`
+/// that used to wait a minute now waits 60ms. Zero reads the same either
+/// way and is not reported.
+///
+/// # Arguments
+///
+/// * `Key` - The metadata or configuration key, such as `Delay`.
+/// * `Value` - The value read for it.
+pub(crate) fn Legacy(Key:&str, Value:&Value) {
+	if !SECONDS.contains(&Key) {
+		return;
+	}
+
+	let Bare = match Value {
+		Value::Number(Number) => Number.as_f64(),
+		Value::String(Text) => Text.trim().parse::<f64>().ok(),
+		_ => None,
+	};
+
+	if !Bare.is_some_and(|Number| Number != 0.0) {
+		return;
+	}
+
+	let First = Warned.lock().unwrap_or_else(|Poison| Poison.into_inner()).insert(Key.to_string());
+
+	if First {
+		warn!(
+			"{} {} is a bare number, read as milliseconds; it counted seconds in earlier \
+			 releases. Bare numbers for {} are deprecated: write a unit, such as \"{}ms\" or \
+			 \"{}s\"",
+			Key,
+			Value,
+			Key,
+			Bare.unwrap_or_default(),
+			Bare.unwrap_or_default()
+		);
+	}
+}
+
+/// The keys that counted seconds before they became durations.
+const SECONDS:[&str; 6] = [
+	"Delay",
+	"registry.retention",
+	"registry.interval",
+	"cost.interval",
+	"watchdog.threshold",
+	"watchdog.interval",
+];
+
+/// The keys a bare number was read for already.
+#[allow(non_upper_case_globals)]
+static Warned:Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+use std::{collections::BTreeSet, sync::Mutex};
+
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::Trait::Sequence::Action::Trait as Action;
+
+pub mod Duration;
+
+pub mod Timestamp;
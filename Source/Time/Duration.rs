@@ -0,0 +1,265 @@
+/// A length of time as written in metadata, configuration and wire messages.
+///
+/// It reads a number as milliseconds, fractional ones included, and a string
+/// as either such a number or a sequence of numbers each followed by a unit
+/// of `ns`, `us`, `ms`, `s`, `m`, `h` or `d`, such as `"1.5s"` or `"1h30m"`.
+/// It is written as an integer of milliseconds when it is a whole number of
+/// them, and as its `Display` form otherwise, e.g. `"1ms500us"`.
+///
+/// A bare number for `Delay` or the `[registry]`, `[cost]` and `[watchdog]`
+/// intervals, which counted seconds in earlier releases, is deprecated and
+/// logged once per key; see `Time::Legacy`. Write such values with a unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Struct(pub Duration);
+
+impl Struct {
+	/// Creates a duration from a number of milliseconds.
+	///
+	/// # Arguments
+	///
+	/// * `Millis` - The number of milliseconds, possibly fractional.
+	///
+	/// # Returns
+	///
+	/// The duration, or why the number is negative, not finite or beyond
+	/// `MAXIMUM`.
+	pub fn Millis(Millis:f64) -> Result<Self, String> {
+		if !Millis.is_finite() || Millis < 0.0 {
+			return Err(format!("Duration must be a non-negative number: {}", Millis));
+		}
+
+		if Millis / 1000.0 > MAXIMUM.as_secs_f64() {
+			return Err(Self::Exceeding(Millis));
+		}
+
+		Duration::try_from_secs_f64(Millis / 1000.0)
+			.map_err(|_Error| _Error.to_string())
+			.and_then(Self::Bounded)
+	}
+
+	/// Returns the `std::time::Duration` it holds.
+	pub fn Get(self) -> Duration { self.0 }
+
+	/// Accepts a duration up to `MAXIMUM`.
+	fn Bounded(Duration:Duration) -> Result<Self, String> {
+		if Duration > MAXIMUM {
+			return Err(Self::Exceeding(format!("{:?}", Duration)));
+		}
+
+		Ok(Struct(Duration))
+	}
+
+	/// Describes a duration beyond `MAXIMUM`.
+	fn Exceeding(Duration:impl Display) -> String {
+		format!("Duration {} exceeds the maximum of {:?}", Duration, MAXIMUM)
+	}
+
+	/// Returns the number of nanoseconds in a unit.
+	fn Unit(Unit:&str) -> Option<u128> {
+		Some(match Unit {
+			"ns" => 1,
+			"us" | "µs" => 1_000,
+			"ms" => 1_000_000,
+			"s" => 1_000_000_000,
+			"m" => 60_000_000_000,
+			"h" => 3_600_000_000_000,
+			"d" => 86_400_000_000_000,
+			_ => return None,
+		})
+	}
+}
+
+impl FromStr for Struct {
+	type Err = String;
+
+	fn from_str(Text:&str) -> Result<Self, String> {
+		let Text = Text.trim();
+
+		if Text.is_empty() {
+			return Err("Duration is empty".to_string());
+		}
+
+		// A bare number counts milliseconds, as it does outside a string
+		if let Ok(Millis) = Text.parse::<f64>() {
+			return Self::Millis(Millis);
+		}
+
+		let Invalid = || format!("Invalid duration: {:?}", Text);
+
+		let mut Rest = Text;
+
+		let mut Nanos:u128 = 0;
+
+		while !Rest.is_empty() {
+			let Split = Rest
+				.find(|Character:char| !Character.is_ascii_digit() && Character != '.')
+				.ok_or_else(|| format!("Duration {:?} lacks a unit at its end", Text))?;
+
+			let (Number, Tail) = Rest.split_at(Split);
+
+			let Length = Tail
+				.find(|Character:char| Character.is_ascii_digit() || Character == '.')
+				.unwrap_or(Tail.len());
+
+			let (Unit, Tail) = Tail.split_at(Length);
+
+			let Unit = Self::Unit(Unit.trim())
+				.ok_or_else(|| format!("Duration {:?} has an unknown unit {:?}", Text, Unit))?;
+
+			let (Whole, Fraction) = Number.split_once('.').unwrap_or((Number, ""));
+
+			if Whole.is_empty() && Fraction.is_empty() {
+				return Err(Invalid());
+			}
+
+			// Only a number too large for any unit fails to parse
+			let Whole = if Whole.is_empty() {
+				0
+			} else {
+				Whole.parse::<u128>().map_err(|_| Self::Exceeding(Text))?
+			};
+
+			// Digits past nanosecond precision cannot change the value
+			let Fraction = &Fraction[..Fraction.len().min(24)];
+
+			let Part = if Fraction.is_empty() {
+				0
+			} else {
+				Fraction.parse::<u128>().map_err(|_| Invalid())? * Unit
+					/ 10u128.pow(Fraction.len() as u32)
+			};
+
+			Nanos = Whole
+				.checked_mul(Unit)
+				.and_then(|Whole| Whole.checked_add(Part))
+				.and_then(|Segment| Nanos.checked_add(Segment))
+				.filter(|Nanos| *Nanos <= MAXIMUM.as_nanos())
+				.ok_or_else(|| Self::Exceeding(Text))?;
+
+			Rest = Tail.trim_start();
+		}
+
+		Self::Bounded(Duration::from_nanos(Nanos as u64))
+	}
+}
+
+impl Display for Struct {
+	/// Writes the duration as its non-zero units from days down to
+	/// nanoseconds, e.g. `1h30m` or `250ms`, and a zero duration as `0ms`.
+	fn fmt(&self, Formatter:&mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.0.is_zero() {
+			return Formatter.write_str("0ms");
+		}
+
+		let mut Left = self.0.as_nanos();
+
+		for Unit in ["d", "h", "m", "s", "ms", "us", "ns"] {
+			let Size = Self::Unit(Unit).unwrap_or(1);
+
+			if Left >= Size {
+				write!(Formatter, "{}{}", Left / Size, Unit)?;
+
+				Left %= Size;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl From<Duration> for Struct {
+	fn from(Duration:Duration) -> Self { Struct(Duration) }
+}
+
+impl From<Struct> for Duration {
+	fn from(Duration:Struct) -> Self { Duration.0 }
+}
+
+impl Serialize for Struct {
+	fn serialize<S>(&self, Serializer:S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer, {
+		if self.0.subsec_nanos().is_multiple_of(1_000_000) {
+			Serializer.serialize_u64(self.0.as_millis() as u64)
+		} else {
+			Serializer.collect_str(self)
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Struct {
+	fn deserialize<D>(Deserializer:D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>, {
+		Deserializer.deserialize_any(Visitor)
+	}
+}
+
+/// Reads a `Struct` from a number or a string.
+struct Visitor;
+
+impl de::Visitor<'_> for Visitor {
+	type Value = Struct;
+
+	fn expecting(&self, Formatter:&mut fmt::Formatter<'_>) -> fmt::Result {
+		Formatter.write_str("a number of milliseconds or a duration such as \"2s\"")
+	}
+
+	fn visit_u64<E:de::Error>(self, Millis:u64) -> Result<Struct, E> {
+		Duration::from_secs(Millis / 1000)
+			.checked_add(Duration::from_millis(Millis % 1000))
+			.ok_or_else(|| E::custom("Duration overflows"))
+			.and_then(|Duration| Struct::Bounded(Duration).map_err(E::custom))
+	}
+
+	fn visit_i64<E:de::Error>(self, Millis:i64) -> Result<Struct, E> {
+		u64::try_from(Millis)
+			.map_err(|_| E::custom(format!("Duration must be a non-negative number: {}", Millis)))
+			.and_then(|Millis| self.visit_u64(Millis))
+	}
+
+	fn visit_f64<E:de::Error>(self, Millis:f64) -> Result<Struct, E> {
+		Struct::Millis(Millis).map_err(E::custom)
+	}
+
+	fn visit_str<E:de::Error>(self, Text:&str) -> Result<Struct, E> {
+		Text.parse().map_err(E::custom)
+	}
+}
+
+#[cfg(feature = "Schema")]
+impl schemars::JsonSchema for Struct {
+	fn schema_name() -> String { "Duration".to_string() }
+
+	fn json_schema(Generator:&mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		use schemars::schema::{Metadata, SchemaObject, SubschemaValidation};
+
+		SchemaObject {
+			metadata:Some(Box::new(Metadata {
+				description:Some(
+					"Milliseconds, or a string such as \"250ms\", \"2s\" or \"1h30m\"".to_string(),
+				),
+				..Default::default()
+			})),
+			subschemas:Some(Box::new(SubschemaValidation {
+				any_of:Some(vec![
+					Generator.subschema_for::<f64>(),
+					Generator.subschema_for::<String>(),
+				]),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
+
+use std::{
+	fmt::{self, Display},
+	str::FromStr,
+	time::Duration,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Time::MAXIMUM;
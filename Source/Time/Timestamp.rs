@@ -0,0 +1,300 @@
+/// A point in time as written in metadata and wire messages.
+///
+/// It reads an RFC 3339 string, such as `"2026-10-16T04:22:15Z"` or
+/// `"2026-10-16T06:22:15.250+02:00"`, or an integer of milliseconds since
+/// the Unix epoch. Times before the epoch, after the year 9999 or on a leap
+/// second are rejected. It is written as an RFC 3339 string in UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Struct(pub SystemTime);
+
+/// The number of seconds from the Unix epoch to the end of the year 9999.
+const LAST:u64 = 253_402_300_800;
+
+impl Struct {
+	/// Returns the current time.
+	pub fn Now() -> Self { Struct(SystemTime::now()) }
+
+	/// Creates a timestamp from milliseconds since the Unix epoch.
+	///
+	/// # Arguments
+	///
+	/// * `Millis` - The number of milliseconds since the epoch.
+	///
+	/// # Returns
+	///
+	/// The timestamp, or why it lies after the year 9999.
+	pub fn UnixMillis(Millis:u64) -> Result<Self, String> {
+		if Millis / 1000 >= LAST {
+			return Err(format!("Timestamp {} lies after the year 9999", Millis));
+		}
+
+		Ok(Struct(UNIX_EPOCH + Duration::from_millis(Millis)))
+	}
+
+	/// Returns the number of milliseconds since the Unix epoch.
+	pub fn Millis(&self) -> u64 {
+		self.0.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+	}
+
+	/// Returns how long until the timestamp, zero once it passed.
+	pub fn Left(&self) -> Duration { self.0.duration_since(SystemTime::now()).unwrap_or_default() }
+
+	/// Returns whether the timestamp passed.
+	pub fn Passed(&self) -> bool { SystemTime::now() >= self.0 }
+
+	/// Counts the days from the Unix epoch to a date of the proleptic
+	/// Gregorian calendar.
+	fn Days(Year:i64, Month:i64, Day:i64) -> i64 {
+		let Year = if Month <= 2 { Year - 1 } else { Year };
+
+		let Era = Year.div_euclid(400);
+
+		let Of = Year - Era * 400;
+
+		let Within = (153 * (Month + if Month > 2 { -3 } else { 9 }) + 2) / 5 + Day - 1;
+
+		Era * 146_097 + Of * 365 + Of / 4 - Of / 100 + Within - 719_468
+	}
+
+	/// Finds the date of the proleptic Gregorian calendar a number of days
+	/// after the Unix epoch falls on.
+	fn Date(Days:i64) -> (i64, i64, i64) {
+		let Days = Days + 719_468;
+
+		let Era = Days.div_euclid(146_097);
+
+		let Of = Days - Era * 146_097;
+
+		let Year = (Of - Of / 1460 + Of / 36_524 - Of / 146_096) / 365;
+
+		let Within = Of - (365 * Year + Year / 4 - Year / 100);
+
+		let Shifted = (5 * Within + 2) / 153;
+
+		let Day = Within - (153 * Shifted + 2) / 5 + 1;
+
+		let Month = if Shifted < 10 { Shifted + 3 } else { Shifted - 9 };
+
+		(Year + Era * 400 + i64::from(Month <= 2), Month, Day)
+	}
+
+	/// Returns the number of days in a month.
+	fn Length(Year:i64, Month:i64) -> i64 {
+		match Month {
+			2 if Year % 4 == 0 && (Year % 100 != 0 || Year % 400 == 0) => 29,
+			2 => 28,
+			4 | 6 | 9 | 11 => 30,
+			_ => 31,
+		}
+	}
+}
+
+impl FromStr for Struct {
+	type Err = String;
+
+	fn from_str(Text:&str) -> Result<Self, String> {
+		let Invalid = |Reason:&str| format!("Invalid RFC 3339 timestamp {:?}: {}", Text, Reason);
+
+		let Byte = Text.as_bytes();
+
+		// Reads the decimal digits in a range of the text
+		let Number = |From:usize, To:usize| -> Result<i64, String> {
+			Byte.get(From..To)
+				.filter(|Digit| Digit.iter().all(u8::is_ascii_digit))
+				.map(|Digit| Digit.iter().fold(0, |Sum, Digit| Sum * 10 + i64::from(Digit - b'0')))
+				.ok_or_else(|| Invalid("expected YYYY-MM-DDTHH:MM:SS"))
+		};
+
+		let Separated =
+			|At:usize, Expected:&[u8]| Byte.get(At).is_some_and(|Byte| Expected.contains(Byte));
+
+		if !(Separated(4, b"-")
+			&& Separated(7, b"-")
+			&& Separated(10, b"Tt ")
+			&& Separated(13, b":")
+			&& Separated(16, b":"))
+		{
+			return Err(Invalid("expected YYYY-MM-DDTHH:MM:SS"));
+		}
+
+		let (Year, Month, Day) = (Number(0, 4)?, Number(5, 7)?, Number(8, 10)?);
+
+		let (Hour, Minute, Second) = (Number(11, 13)?, Number(14, 16)?, Number(17, 19)?);
+
+		if !(1..=12).contains(&Month) || !(1..=Self::Length(Year, Month)).contains(&Day) {
+			return Err(Invalid("no such date"));
+		}
+
+		if Hour > 23 || Minute > 59 {
+			return Err(Invalid("no such time"));
+		}
+
+		if Second > 59 {
+			return Err(Invalid("leap seconds are not supported"));
+		}
+
+		let mut At = 19;
+
+		let mut Nanos = 0;
+
+		if Separated(At, b".") {
+			let Digits = Byte[At + 1..].iter().take_while(|Byte| Byte.is_ascii_digit()).count();
+
+			if Digits == 0 {
+				return Err(Invalid("expected digits after the decimal point"));
+			}
+
+			// Digits past nanosecond precision are dropped
+			for Place in 0..9 {
+				let Digit =
+					if Place < Digits { Number(At + 1 + Place, At + 2 + Place)? } else { 0 };
+
+				Nanos = Nanos * 10 + Digit;
+			}
+
+			At += 1 + Digits;
+		}
+
+		let Offset = match Byte.get(At..) {
+			Some(b"Z" | b"z") => 0,
+			Some([Sign @ (b'+' | b'-'), _, _, b':', _, _]) => {
+				let (Hours, Minutes) = (Number(At + 1, At + 3)?, Number(At + 4, At + 6)?);
+
+				if Hours > 23 || Minutes > 59 {
+					return Err(Invalid("no such offset"));
+				}
+
+				let Offset = Hours * 3600 + Minutes * 60;
+
+				if *Sign == b'-' { -Offset } else { Offset }
+			},
+			_ => return Err(Invalid("expected Z or an offset such as +02:00")),
+		};
+
+		let Seconds =
+			Self::Days(Year, Month, Day) * 86_400 + Hour * 3600 + Minute * 60 + Second - Offset;
+
+		let Seconds = u64::try_from(Seconds).map_err(|_| Invalid("lies before the Unix epoch"))?;
+
+		if Seconds >= LAST {
+			return Err(Invalid("lies after the year 9999"));
+		}
+
+		Ok(Struct(UNIX_EPOCH + Duration::new(Seconds, Nanos as u32)))
+	}
+}
+
+impl Display for Struct {
+	/// Writes the timestamp in UTC, with as many groups of three fractional
+	/// digits as it needs, e.g. `2026-10-16T04:22:15.250Z`.
+	fn fmt(&self, Formatter:&mut fmt::Formatter<'_>) -> fmt::Result {
+		let Since = self.0.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+		let Seconds = Since.as_secs() as i64;
+
+		let (Year, Month, Day) = Self::Date(Seconds.div_euclid(86_400));
+
+		let Within = Seconds.rem_euclid(86_400);
+
+		write!(
+			Formatter,
+			"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+			Year,
+			Month,
+			Day,
+			Within / 3600,
+			Within / 60 % 60,
+			Within % 60
+		)?;
+
+		match Since.subsec_nanos() {
+			0 => {},
+			Nanos if Nanos.is_multiple_of(1_000_000) => write!(Formatter, ".{:03}", Nanos / 1_000_000)?,
+			Nanos if Nanos.is_multiple_of(1_000) => write!(Formatter, ".{:06}", Nanos / 1_000)?,
+			Nanos => write!(Formatter, ".{:09}", Nanos)?,
+		}
+
+		Formatter.write_str("Z")
+	}
+}
+
+impl From<Struct> for SystemTime {
+	fn from(Timestamp:Struct) -> Self { Timestamp.0 }
+}
+
+impl Serialize for Struct {
+	fn serialize<S>(&self, Serializer:S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer, {
+		Serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for Struct {
+	fn deserialize<D>(Deserializer:D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>, {
+		Deserializer.deserialize_any(Visitor)
+	}
+}
+
+/// Reads a `Struct` from a string or a number.
+struct Visitor;
+
+impl de::Visitor<'_> for Visitor {
+	type Value = Struct;
+
+	fn expecting(&self, Formatter:&mut fmt::Formatter<'_>) -> fmt::Result {
+		Formatter.write_str("an RFC 3339 timestamp or milliseconds since the Unix epoch")
+	}
+
+	fn visit_u64<E:de::Error>(self, Millis:u64) -> Result<Struct, E> {
+		Struct::UnixMillis(Millis).map_err(E::custom)
+	}
+
+	fn visit_i64<E:de::Error>(self, Millis:i64) -> Result<Struct, E> {
+		u64::try_from(Millis)
+			.map_err(|_| E::custom(format!("Timestamp {} lies before the Unix epoch", Millis)))
+			.and_then(|Millis| self.visit_u64(Millis))
+	}
+
+	fn visit_str<E:de::Error>(self, Text:&str) -> Result<Struct, E> {
+		Text.parse().map_err(E::custom)
+	}
+}
+
+#[cfg(feature = "Schema")]
+impl schemars::JsonSchema for Struct {
+	fn schema_name() -> String { "Timestamp".to_string() }
+
+	fn json_schema(Generator:&mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		use schemars::schema::{Metadata, SchemaObject, SubschemaValidation};
+
+		let mut Text = Generator.subschema_for::<String>().into_object();
+
+		Text.format = Some("date-time".to_string());
+
+		SchemaObject {
+			metadata:Some(Box::new(Metadata {
+				description:Some(
+					"An RFC 3339 timestamp, or milliseconds since the Unix epoch".to_string(),
+				),
+				..Default::default()
+			})),
+			subschemas:Some(Box::new(SubschemaValidation {
+				any_of:Some(vec![Text.into(), Generator.subschema_for::<u64>()]),
+				..Default::default()
+			})),
+			..Default::default()
+		}
+		.into()
+	}
+}
+
+use std::{
+	fmt::{self, Display},
+	str::FromStr,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
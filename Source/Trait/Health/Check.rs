@@ -0,0 +1,29 @@
+/// A health check evaluated by `Life::Health`.
+///
+/// Each check runs under its own timeout, so a stuck check reports
+/// `Unhealthy` instead of hanging the whole probe.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Returns the name under which the check is reported.
+	fn Name(&self) -> String;
+
+	/// Returns how long the check may run.
+	fn Timeout(&self) -> Duration { Duration::from_secs(1) }
+
+	/// Evaluates the check.
+	///
+	/// # Arguments
+	///
+	/// * `Life` - The context being checked.
+	///
+	/// # Returns
+	///
+	/// The status of the check with a human-readable message.
+	async fn Check(&self, Life:&Life) -> Outcome;
+}
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::Struct::{Health::Outcome::Struct as Outcome, Sequence::Life::Struct as Life};
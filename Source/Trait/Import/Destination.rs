@@ -0,0 +1,43 @@
+/// Where `Import::Run` submits the lines it imports.
+///
+/// `Import::Local` delivers them into a `Life` or production line of this
+/// process, and a `Client` submits them to a server.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Submits one line.
+	///
+	/// # Arguments
+	///
+	/// * `Line` - The submission, carrying its `IdempotencyKey` metadata.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the line is accepted, or the reason it was refused.
+	async fn Submit(&self, Line:&Line) -> Result<(), String>;
+
+	/// Reports how many actions wait in the queue a line would go to, so the
+	/// import holds off while it is full.
+	///
+	/// # Arguments
+	///
+	/// * `Line` - The submission about to be made.
+	///
+	/// # Returns
+	///
+	/// The depth of the queue, or `None` if it is unknown.
+	async fn Depth(&self, _Line:&Line) -> Option<usize> { None }
+
+	/// Returns the runtime the import paces and polls with; tokio's unless
+	/// the destination has one of its own.
+	fn Runtime(&self) -> Arc<dyn Runtime> { Arc::new(Tokio::Struct) }
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+	Import::Line::Struct as Line,
+	Struct::Runtime::Tokio,
+	Trait::Runtime::Trait as Runtime,
+};
@@ -0,0 +1,19 @@
+/// A destination for named events, such as a Tauri `AppHandle`.
+///
+/// `ResultBridge` emits through this trait only, so it can be driven without
+/// a running webview. The Tauri implementation calls `AppHandle::emit_all`.
+pub trait Trait: Send + Sync {
+	/// Emits an event to every listener.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the event, e.g. `ActionResult`.
+	/// * `Payload` - The payload of the event.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the event was emitted, or a description of the failure.
+	fn Emit(&self, Name:&str, Payload:Value) -> Result<(), String>;
+}
+
+use serde_json::Value;
@@ -0,0 +1,114 @@
+/// The primitives through which a `Life` spawns its tasks, waits and reads
+/// the time, so that it can run on an executor other than tokio's.
+///
+/// Every internal task of a `Life`, such as the timer service, the registry
+/// sweeps, the Karma consumers and the sources started on it, is spawned
+/// through its runtime, set with `Life::Builder::WithRuntime` and
+/// `Struct::Runtime::Tokio` unless set, and every timeout and interval is
+/// measured with its `Sleep`; see `Timeout` and `Interval`. Tokio's channels
+/// and locks do not depend on its executor and are used on any runtime as
+/// they are.
+pub trait Trait: Send + Sync {
+	/// Spawns a task.
+	///
+	/// An executor that runs detached futures only can wrap the task with
+	/// `Spawned::Struct::Detached` and run the future it returns.
+	///
+	/// # Arguments
+	///
+	/// * `Task` - The task to run.
+	///
+	/// # Returns
+	///
+	/// The handle of the task.
+	fn Spawn(&self, Task:BoxFuture<'static, ()>) -> Spawned::Struct;
+
+	/// Spawns a task that blocks its thread, such as a blocking function or
+	/// file I/O, away from the threads running the other tasks; by default
+	/// as any other task, for executors without such threads.
+	///
+	/// # Arguments
+	///
+	/// * `Task` - The task to run.
+	///
+	/// # Returns
+	///
+	/// The handle of the task.
+	fn SpawnBlocking(&self, Task:BoxFuture<'static, ()>) -> Spawned::Struct { self.Spawn(Task) }
+
+	/// Waits for a while.
+	///
+	/// # Arguments
+	///
+	/// * `Length` - How long to wait.
+	///
+	/// # Returns
+	///
+	/// A future resolving once `Length` elapsed.
+	fn Sleep(&self, Length:Duration) -> BoxFuture<'static, ()>;
+
+	/// Waits until a point in time, by default for as long as it is away
+	/// from `Now`.
+	///
+	/// # Arguments
+	///
+	/// * `Deadline` - When to resolve.
+	///
+	/// # Returns
+	///
+	/// A future resolving once `Deadline` passed.
+	fn SleepUntil(&self, Deadline:Instant) -> BoxFuture<'static, ()> {
+		self.Sleep(Deadline.saturating_duration_since(self.Now()))
+	}
+
+	/// Returns the current point in time, which `Sleep` and `SleepUntil`
+	/// measure against.
+	///
+	/// It is compared with instants the crate takes from
+	/// `tokio::time::Instant::now`, e.g. when actions are enqueued, so it
+	/// reads the same clock, paused and advanced as tokio's test clock is.
+	fn Now(&self) -> Instant;
+}
+
+impl dyn Trait {
+	/// Runs a future for at most a while, measured with `Sleep`.
+	///
+	/// # Arguments
+	///
+	/// * `Length` - How long the future may run.
+	/// * `Task` - The future to run.
+	///
+	/// # Returns
+	///
+	/// The output of the future, or `None` if `Length` elapsed first.
+	pub async fn Timeout<F:Future>(&self, Length:Duration, Task:F) -> Option<F::Output> {
+		self.TimeoutAt(self.Now() + Length, Task).await
+	}
+
+	/// Runs a future until at most a point in time, measured with
+	/// `SleepUntil`.
+	///
+	/// # Arguments
+	///
+	/// * `Deadline` - When to give up on the future.
+	/// * `Task` - The future to run.
+	///
+	/// # Returns
+	///
+	/// The output of the future, or `None` if `Deadline` passed first.
+	pub async fn TimeoutAt<F:Future>(&self, Deadline:Instant, Task:F) -> Option<F::Output> {
+		select! {
+			biased;
+
+			Output = Task => Some(Output),
+			_ = self.SleepUntil(Deadline) => None,
+		}
+	}
+}
+
+use std::{future::Future, time::Duration};
+
+use futures::future::BoxFuture;
+use tokio::{select, time::Instant};
+
+use crate::Struct::Runtime::Spawned;
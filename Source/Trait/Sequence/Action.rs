@@ -1,8 +1,9 @@
-/// Trait for asynchronous actions that can be executed and cloned.
+/// Trait for asynchronous actions that can be executed and shared.
 ///
 /// This trait is intended for types that represent actions which can be
-/// executed asynchronously and need to be cloneable for use in concurrent
-/// contexts.
+/// executed asynchronously. Actions are shared through an `Arc` for retries,
+/// so implementors are not required to be cloneable; features that genuinely
+/// need a copy go through `Duplicate` or the `Cloneable` sub-trait.
 #[async_trait]
 pub trait Trait: Send + Sync {
 	/// Executes the action asynchronously.
@@ -18,35 +19,232 @@ pub trait Trait: Send + Sync {
 	/// successfully, or an `Error` if the execution failed.
 	async fn Execute(&self, Context:&Life) -> Result<(), Error>;
 
-	/// Creates a clone of the action as a trait object.
+	/// Creates an independent copy of the action as a trait object.
 	///
-	/// This method is used to create a boxed clone of the action, allowing
-	/// for dynamic dispatch and use in heterogeneous collections.
+	/// Only features that need a real copy (such as persistence snapshots)
+	/// call this. Actions that cannot be copied keep the default, which
+	/// reports `Error::NonCloneable` instead of failing to compile.
 	///
 	/// # Returns
 	///
-	/// Returns a `Box<dyn Trait>` containing a clone of the action.
-	fn Clone(&self) -> Box<dyn Trait>;
+	/// Returns a `Box<dyn Trait>` containing a copy of the action, or
+	/// `Error::NonCloneable` if the action does not support copying.
+	fn Duplicate(&self) -> Result<Box<dyn Trait>, Error> {
+		Err(Error::NonCloneable("Action does not support duplication".to_string()))
+	}
+
+	/// Looks up a metadata entry of the action.
+	///
+	/// Layers that only see trait objects, such as queue routing, read
+	/// metadata through this. Actions without metadata keep the default.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key to look up.
+	///
+	/// # Returns
+	///
+	/// The value stored under `Key`, or `None` if there is none.
+	async fn Metadata(&self, _Key:&str) -> Option<serde_json::Value> { None }
+
+	/// Writes an annotation into the metadata of the action while it is
+	/// shared, e.g. once an attempt recorded some with
+	/// `Invocation::Annotate`.
+	///
+	/// Actions without writable metadata keep the default, which drops it.
+	///
+	/// # Arguments
+	///
+	/// * `Key` - The metadata key.
+	/// * `Value` - The value stored under `Key`.
+	fn Annotate(&self, _Key:&str, _Value:serde_json::Value) {}
+
+	/// Returns the size of the action's arguments, e.g. for cost samples.
+	///
+	/// The default measures the JSON encoding of the `Argument` metadata.
+	/// Actions holding payloads as `Arg` measure them in place instead, so
+	/// they are not converted to be measured.
+	///
+	/// # Returns
+	///
+	/// The size in bytes, zero without arguments.
+	async fn ArgumentSize(&self) -> u64 {
+		self.Metadata("Argument").await.map_or(0, |Argument| Arg::Json(Argument).Size())
+	}
+
+	/// Returns the priority of the action in its production line: actions
+	/// of a higher one are dequeued first, those of the same in the order
+	/// they were enqueued.
+	///
+	/// The default reads the integer `Priority` metadata.
+	///
+	/// # Returns
+	///
+	/// The priority, 0 without a valid one.
+	async fn Priority(&self) -> i64 {
+		self.Metadata("Priority").await.and_then(|Priority| Priority.as_i64()).unwrap_or(0)
+	}
+
+	/// Opens a stream of output back to whoever submitted the action, for
+	/// the `Partial` destination of `OutputTo`.
+	///
+	/// Only actions submitted over a streaming connection have one; the
+	/// others keep the default.
+	///
+	/// # Returns
+	///
+	/// A writer delivering the output to the submitter, or `None`.
+	fn Partial(&self) -> Option<Writer> { None }
+
+	/// Runs the hooks the action attaches to a stage it reaches outside
+	/// `Execute`: `Accepted` when it is submitted, `BeforeDelay` when a
+	/// worker starts holding it for its `Delay`.
+	///
+	/// Actions without hooks keep the default, which runs none.
+	///
+	/// # Arguments
+	///
+	/// * `Context` - The context whose hooks are run.
+	/// * `Phase` - The stage reached.
+	///
+	/// # Returns
+	///
+	/// The error of the first hook failing, if any.
+	async fn Stage(&self, _Context:&Life, _Phase:Phase) -> Result<(), Error> { Ok(()) }
+
+	/// Returns the token cancelling the action, if it can be cancelled.
+	///
+	/// A sequence stops retrying an action once its token is cancelled, and
+	/// does not execute an action cancelled while it waited.
+	///
+	/// # Returns
+	///
+	/// The token, or `None` for actions that cannot be cancelled.
+	fn Cancellation(&self) -> Option<Cancellation> { None }
+
+	/// Tells the action it failed for good, after its last attempt and before
+	/// it may be handed to a dead-letter queue.
+	///
+	/// Actions answering a submitter, such as the jobs of a transport, answer
+	/// here rather than once dropped, since a dead-letter queue keeps them.
+	/// Other actions keep the default, which does nothing.
+	///
+	/// # Arguments
+	///
+	/// * `Error` - The error the action completed with.
+	fn Fail(&self, _Error:&Error) {}
+
+	/// Describes the action to admission policies.
+	///
+	/// The default reads the `Action`, `Argument` and `Queue` metadata.
+	/// Actions able to list their metadata describe all of it instead.
+	///
+	/// # Returns
+	///
+	/// The view of the action, with an empty `Action` if it names none.
+	async fn View(&self) -> View {
+		let Action = match self.Metadata("Action").await {
+			Some(serde_json::Value::String(Action)) => Action,
+			_ => String::new(),
+		};
+
+		let Argument = match self.Metadata("Argument").await {
+			Some(serde_json::Value::Array(Argument)) => Argument,
+			_ => Vec::new(),
+		};
+
+		let Metadata = self
+			.Metadata("Queue")
+			.await
+			.map(|Queue| ("Queue".to_string(), Queue))
+			.into_iter()
+			.collect();
+
+		View::New(Action, Argument, Metadata)
+	}
 }
 
 /// Implementation of the `Trait` for
 /// `crate::Struct::Sequence::Action::Struct<T>`.
 ///
-/// This implementation allows any `Struct<T>` that satisfies the bounds
-/// to be used as a `Trait` object.
+/// This implementation allows any `Struct<T>` to be used as a `Trait`
+/// object, whether or not its content is `Clone`.
 #[async_trait]
-impl<T:Send + Sync + Clone + 'static> Trait for crate::Struct::Sequence::Action::Struct<T> {
+impl<T:Send + Sync + 'static> Trait for crate::Struct::Sequence::Action::Struct<T> {
 	async fn Execute(&self, Context:&Life) -> Result<(), Error> {
 		// Delegates to the struct's own `Execute` method
-		self.Execute(Context).await
+		crate::Struct::Sequence::Action::Struct::Execute(self, Context).await
+	}
+
+	fn Duplicate(&self) -> Result<Box<dyn Trait>, Error> {
+		// Delegates to the copy function recorded by `Struct::Cloneable`
+		crate::Struct::Sequence::Action::Struct::Duplicate(self)
+			.map(|Action| Box::new(Action) as Box<dyn Trait>)
+	}
+
+	async fn Metadata(&self, Key:&str) -> Option<serde_json::Value> { self.Lookup(Key).await }
+
+	fn Annotate(&self, Key:&str, Value:serde_json::Value) {
+		self.Metadata.Insert(Key.to_string(), Value);
 	}
 
-	fn Clone(&self) -> Box<dyn Trait> {
-		// Creates a new boxed trait object containing a clone of self
-		Box::new(self.clone())
+	async fn Stage(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		crate::Struct::Sequence::Action::Struct::Stage(self, Context, Phase).await
 	}
+
+	fn Cancellation(&self) -> Option<Cancellation> {
+		Some(crate::Struct::Sequence::Action::Struct::Cancellation(self))
+	}
+
+	async fn View(&self) -> View { crate::Struct::Sequence::Action::Struct::View(self).await }
+}
+
+/// Implementation of the `Trait` for shared actions.
+///
+/// Lets an action that is already shared across attempts be enqueued again,
+/// e.g. into a dead-letter queue, without copying it.
+#[async_trait]
+impl Trait for Arc<dyn Trait> {
+	async fn Execute(&self, Context:&Life) -> Result<(), Error> { (**self).Execute(Context).await }
+
+	fn Duplicate(&self) -> Result<Box<dyn Trait>, Error> { (**self).Duplicate() }
+
+	async fn Metadata(&self, Key:&str) -> Option<serde_json::Value> { (**self).Metadata(Key).await }
+
+	fn Annotate(&self, Key:&str, Value:serde_json::Value) { (**self).Annotate(Key, Value) }
+
+	async fn ArgumentSize(&self) -> u64 { (**self).ArgumentSize().await }
+
+	async fn Priority(&self) -> i64 { (**self).Priority().await }
+
+	fn Partial(&self) -> Option<Writer> { (**self).Partial() }
+
+	async fn Stage(&self, Context:&Life, Phase:Phase) -> Result<(), Error> {
+		(**self).Stage(Context, Phase).await
+	}
+
+	fn Cancellation(&self) -> Option<Cancellation> { (**self).Cancellation() }
+
+	fn Fail(&self, Error:&Error) { (**self).Fail(Error) }
+
+	async fn View(&self) -> View { (**self).View().await }
 }
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
-use crate::{Enum::Sequence::Action::Error::Enum as Error, Struct::Sequence::Life::Struct as Life};
+use crate::{
+	Enum::Sequence::{
+		Action::{Arg::Enum as Arg, Error::Enum as Error},
+		Phase::Enum as Phase,
+	},
+	Struct::Sequence::{
+		Admission::View::Struct as View,
+		Cancellation::Struct as Cancellation,
+		Life::Struct as Life,
+	},
+	Type::Sequence::Action::Writer::Type as Writer,
+};
+
+pub mod Cloneable;
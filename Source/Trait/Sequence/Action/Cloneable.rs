@@ -0,0 +1,22 @@
+/// Trait for actions that can be copied into a new trait object.
+///
+/// This is an optional sub-trait of `super::Trait`. The execution path never
+/// requires it; it exists for code that statically needs copies of an action
+/// and wants a compile-time guarantee that one can be made.
+pub trait Trait: super::Trait {
+	/// Creates a clone of the action as a trait object.
+	///
+	/// # Returns
+	///
+	/// Returns a `Box<dyn super::Trait>` containing a clone of the action.
+	fn Clone(&self) -> Box<dyn super::Trait>;
+}
+
+/// Implementation of the `Trait` for
+/// `crate::Struct::Sequence::Action::Struct<T>` whose content is `Clone`.
+impl<T:Send + Sync + Clone + 'static> Trait for crate::Struct::Sequence::Action::Struct<T> {
+	fn Clone(&self) -> Box<dyn super::Trait> {
+		// Creates a new boxed trait object containing a clone of self
+		Box::new(self.clone())
+	}
+}
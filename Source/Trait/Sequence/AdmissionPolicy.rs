@@ -0,0 +1,34 @@
+/// Decides whether a submission is admitted before it is enqueued.
+///
+/// A `Life` built `WithAdmissionPolicy` evaluates its policies in the order
+/// they were added, for every action submitted with `Life::Submit` or
+/// delivered by a source, and for every job a transport submits to it. The
+/// first `Deny` refuses the submission; a `Modify` patches the view the
+/// policies after it see, and the action once admitted.
+/// `Admission::Roles` and `Admission::PathPrefix` are built in.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Returns the name of the policy, as reasons and audit events name it.
+	fn Name(&self) -> String;
+
+	/// Decides about a submission.
+	///
+	/// # Arguments
+	///
+	/// * `Identity` - Who submits the action.
+	/// * `Action` - What is submitted, with the patches of the policies
+	///   evaluated before.
+	///
+	/// # Returns
+	///
+	/// `Allow`, `Deny` with the reason and code of the refusal, or `Modify`
+	/// with the metadata to set.
+	async fn Admit(&self, Identity:&Identity, Action:&View) -> Decision;
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Decision::Enum as Decision,
+	Struct::Sequence::Admission::{Identity::Struct as Identity, View::Struct as View},
+};
@@ -0,0 +1,38 @@
+/// Trait for typed action handlers that can be registered in a plan.
+///
+/// A handler receives a typed request decoded from the action's arguments and
+/// returns a typed response, which is encoded back into a
+/// `serde_json::Value`. The `Kind` names the action the handler answers to.
+///
+/// Handlers are usually generated with the `Echo::Macro::Handler` attribute
+/// rather than implemented by hand.
+pub trait Trait: Send + Sync + 'static {
+	/// The typed request decoded from the action's arguments.
+	type Request: DeserializeOwned + Send;
+
+	/// The typed response encoded into the action's result.
+	type Response: Serialize + Send;
+
+	/// Returns the name of the action this handler answers to.
+	fn Kind() -> &'static str;
+
+	/// Runs the handler for a decoded request.
+	///
+	/// # Arguments
+	///
+	/// * `Request` - The typed request decoded from the action's arguments.
+	///
+	/// # Returns
+	///
+	/// Returns a future resolving to the typed response, or an `Error` if the
+	/// handler failed.
+	fn Run(
+		&self,
+		Request:Self::Request,
+	) -> impl Future<Output = Result<Self::Response, Error>> + Send;
+}
+
+use futures::Future;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
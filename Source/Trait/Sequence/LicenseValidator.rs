@@ -0,0 +1,27 @@
+/// Decides whether an action is licensed to run.
+///
+/// A `Life` built `WithLicenseValidator` asks it before every action runs,
+/// instead of reading the action's `License` signal. `License::AlwaysValid`
+/// accepts every action and `License::Expiry` refuses one past the `Expires`
+/// timestamp of its metadata.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Validates the license of an action.
+	///
+	/// # Arguments
+	///
+	/// * `Metadata` - The metadata of the action.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the action may run, or `Error::InvalidLicense` saying why
+	/// not.
+	async fn Validate(&self, Metadata:&Vector) -> Result<(), Error>;
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Vector::Struct as Vector,
+};
@@ -0,0 +1,32 @@
+/// Trait for plugins that provide action functions from outside the host.
+///
+/// A plugin describes the actions it exports and runs them by name with JSON
+/// arguments, so the host only marshals `serde_json::Value`s across the
+/// boundary. Engines such as a WebAssembly runtime implement this trait and
+/// are loaded through `Struct::Sequence::Plugin::Struct`.
+#[async_trait::async_trait]
+pub trait Trait: Send + Sync {
+	/// Describes the actions exported by the plugin.
+	///
+	/// # Returns
+	///
+	/// The signatures of every action the plugin can run.
+	fn Describe(&self) -> Vec<crate::Struct::Sequence::Action::Signature::Struct>;
+
+	/// Runs one of the plugin's actions.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action to run.
+	/// * `Argument` - The JSON arguments of the action.
+	///
+	/// # Returns
+	///
+	/// Returns the JSON result of the action, or an error if the plugin failed
+	/// or exceeded its resource limits.
+	async fn Run(
+		&self,
+		Name:&str,
+		Argument:Vec<serde_json::Value>,
+	) -> Result<serde_json::Value, crate::Enum::Sequence::Action::Error::Enum>;
+}
@@ -0,0 +1,58 @@
+/// Trait for queues of actions waiting to be executed.
+///
+/// Helpers that only move actions in and out, such as sources and worker
+/// loops, take a `dyn Trait` so they work against any queue. `Enqueue` and
+/// `Dequeue` are the canonical verbs; `Assign`, `Take`, `Execute` and `Do`
+/// remain as deprecated aliases of them.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Adds an action to the end of the queue.
+	///
+	/// # Arguments
+	///
+	/// * `Action` - The action to be added to the queue.
+	///
+	/// # Returns
+	///
+	/// The `Stamp` assigned to the action, or the error that kept it out of
+	/// the queue.
+	async fn Enqueue(&self, Action:Box<dyn Action>) -> Result<Stamp, Error>;
+
+	/// Retrieves and removes the first action of the queue.
+	///
+	/// # Returns
+	///
+	/// The first action in the queue, or `None` if the queue is empty.
+	async fn Dequeue(&self) -> Option<Box<dyn Action>>;
+
+	/// Returns the number of actions waiting in the queue.
+	fn Depth(&self) -> u64;
+
+	/// Adds an action to the end of the queue; see `Enqueue`.
+	#[deprecated(note = "use `Enqueue`")]
+	async fn Assign(&self, Action:Box<dyn Action>) -> Result<Stamp, Error> {
+		self.Enqueue(Action).await
+	}
+
+	/// Adds an action to the end of the queue; see `Enqueue`.
+	#[deprecated(note = "use `Enqueue`")]
+	async fn Take(&self, Action:Box<dyn Action>) -> Result<Stamp, Error> {
+		self.Enqueue(Action).await
+	}
+
+	/// Retrieves and removes the first action of the queue; see `Dequeue`.
+	#[deprecated(note = "use `Dequeue`")]
+	async fn Execute(&self) -> Option<Box<dyn Action>> { self.Dequeue().await }
+
+	/// Retrieves and removes the first action of the queue; see `Dequeue`.
+	#[deprecated(note = "use `Dequeue`")]
+	async fn Do(&self) -> Option<Box<dyn Action>> { self.Dequeue().await }
+}
+
+use async_trait::async_trait;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Production::Stamp::Struct as Stamp,
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,20 @@
+/// Looks secrets up by name for a `Secret::Resolver`.
+///
+/// `Secret::Environment` reads environment variables and `Secret::File`
+/// files of a directory.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Reads a secret.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the secret, as referenced by `$secret`.
+	///
+	/// # Returns
+	///
+	/// The value of the secret, or a description of why it cannot be read
+	/// that does not contain it.
+	async fn Secret(&self, Name:&str) -> Result<String, String>;
+}
+
+use async_trait::async_trait;
@@ -0,0 +1,45 @@
+/// Trait for enums that describe a complete, compile-time set of actions.
+///
+/// Each variant is one action, named after the variant and carrying its typed
+/// request. `H` is the handler answering every action of the set; it is the
+/// `<Enum>Handler` trait generated alongside this implementation by
+/// `#[derive(Echo::Macro::Set)]`, so forgetting to handle a variant is a
+/// compile error rather than a runtime miss.
+pub trait Trait<H:?Sized>: Sized + Send + 'static {
+	/// Returns the names of all actions in the set.
+	fn Names() -> &'static [&'static str];
+
+	/// Returns the name of this action.
+	fn Name(&self) -> &'static str;
+
+	/// Decodes an action of the set from its name and argument vector.
+	///
+	/// # Arguments
+	///
+	/// * `Name` - The name of the action.
+	/// * `Argument` - The argument vector, decoded per variant with
+	///   `Fn::Argument::Decode`.
+	///
+	/// # Returns
+	///
+	/// The decoded action, an `Error::Execution` if the name is not part of
+	/// the set, or an `Error::InvalidRequest` if the arguments do not match
+	/// the variant.
+	fn Decode(Name:&str, Argument:Vec<Value>) -> Result<Self, Error>;
+
+	/// Dispatches the action to the matching handler method.
+	///
+	/// # Arguments
+	///
+	/// * `Handler` - The handler answering the actions of the set.
+	///
+	/// # Returns
+	///
+	/// Returns a future resolving to the handler's result.
+	fn Dispatch(self, Handler:&H) -> impl Future<Output = Result<Value, Error>> + Send + '_;
+}
+
+use futures::Future;
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
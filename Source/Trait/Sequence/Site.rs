@@ -12,8 +12,9 @@ pub trait Trait: Send + Sync {
 	///
 	/// # Arguments
 	///
-	/// * `Action` - A boxed trait object representing the action to be
-	///   processed. It must implement the `super::Action::Trait`.
+	/// * `Action` - A shared trait object representing the action to be
+	///   processed. It must implement the `super::Action::Trait`. The same
+	///   action is handed over again on every retry.
 	/// * `Context` - A reference to the `Life` context in which the action is
 	///   executed.
 	///
@@ -29,7 +30,7 @@ pub trait Trait: Send + Sync {
 	/// `crate::Enum::Sequence::Action::Error::Enum` enum.
 	async fn Receive(
 		&self,
-		Action:Box<dyn super::Action::Trait>,
+		Action:crate::Struct::Sequence::Arc<dyn super::Action::Trait>,
 		Context:&crate::Struct::Sequence::Life::Struct,
 	) -> Result<(), crate::Enum::Sequence::Action::Error::Enum>;
 }
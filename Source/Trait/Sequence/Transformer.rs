@@ -0,0 +1,48 @@
+/// Transforms the arguments of actions as they cross into the functions of a
+/// plan, e.g. to resolve secret references; see
+/// `Formality::WithArgumentTransformer`.
+///
+/// Only the function sees the transformed arguments: the metadata of the
+/// action, the submission history and everything derived from them keep the
+/// arguments as submitted. `Mask` reverses the transformation on what the
+/// function returns, so a value it resolved does not leak into results or
+/// attempt history.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Transforms the arguments of a call.
+	///
+	/// # Arguments
+	///
+	/// * `Signature` - The signature of the function called.
+	/// * `Argument` - The arguments as submitted.
+	///
+	/// # Returns
+	///
+	/// The arguments passed to the function, or the error the call fails
+	/// with instead.
+	async fn Transform(
+		&self,
+		Signature:&Signature,
+		Argument:Vec<Value>,
+	) -> Result<Vec<Value>, Error>;
+
+	/// Masks what `Transform` put in the arguments out of a result; the
+	/// result is left as is by default.
+	///
+	/// # Arguments
+	///
+	/// * `Result` - The value or error the function returned.
+	///
+	/// # Returns
+	///
+	/// The result recorded and delivered in its place.
+	fn Mask(&self, Result:Result<Value, Error>) -> Result<Value, Error> { Result }
+}
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::Action::Signature::Struct as Signature,
+};
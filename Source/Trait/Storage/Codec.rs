@@ -0,0 +1,32 @@
+/// Encodes and decodes the records of storage files.
+///
+/// The identifier of the codec is written into the header of every file, so
+/// a file is only read back with the codec it was written with.
+pub trait Trait: Send + Sync {
+	/// Returns the identifier written into file headers.
+	fn Id(&self) -> u8;
+
+	/// Encodes one record.
+	///
+	/// # Arguments
+	///
+	/// * `Record` - The record to encode.
+	///
+	/// # Returns
+	///
+	/// The encoded record, or a description of why encoding failed.
+	fn Encode(&self, Record:&Value) -> Result<Vec<u8>, String>;
+
+	/// Decodes one record.
+	///
+	/// # Arguments
+	///
+	/// * `Record` - The encoded record.
+	///
+	/// # Returns
+	///
+	/// The decoded record, or a description of why it is malformed.
+	fn Decode(&self, Record:&[u8]) -> Result<Value, String>;
+}
+
+use serde_json::Value;
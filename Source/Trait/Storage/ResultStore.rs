@@ -0,0 +1,70 @@
+/// Keeps action results too large to travel inline.
+///
+/// A store hands out a `Stored` reference for every body it keeps; the
+/// reference is all a reader needs to get the body back or delete it.
+/// `Memory` is the default, bounded store.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Stores a body.
+	///
+	/// # Arguments
+	///
+	/// * `Id` - The identifier of the submission the result belongs to.
+	/// * `Body` - The encoded result.
+	/// * `ContentType` - The media type of the body.
+	///
+	/// # Returns
+	///
+	/// The reference to the stored body, or a description of why it was not
+	/// stored.
+	async fn Put(&self, Id:&str, Body:Vec<u8>, ContentType:&str) -> Result<Stored, String>;
+
+	/// Reads a stored body.
+	///
+	/// # Arguments
+	///
+	/// * `Stored` - The reference handed out by `Put`.
+	///
+	/// # Returns
+	///
+	/// The body, or a description of why it cannot be read.
+	async fn Get(&self, Stored:&Stored) -> Result<Vec<u8>, String>;
+
+	/// Reads part of a stored body, so a large one can be sent in chunks
+	/// without being held whole. The default reads the whole body and cuts
+	/// the part out; stores able to read a range override it.
+	///
+	/// # Arguments
+	///
+	/// * `Stored` - The reference handed out by `Put`.
+	/// * `Offset` - The position of the first byte to read.
+	/// * `Length` - The most bytes to read.
+	///
+	/// # Returns
+	///
+	/// The bytes, fewer than `Length` at the end of the body and none past
+	/// it, or a description of why they cannot be read.
+	async fn GetRange(&self, Stored:&Stored, Offset:u64, Length:usize) -> Result<Vec<u8>, String> {
+		let Body = self.Get(Stored).await?;
+
+		Ok(Range(&Body, Offset, Length).to_vec())
+	}
+
+	/// Deletes a stored body; deleting one already gone succeeds.
+	///
+	/// # Arguments
+	///
+	/// * `Stored` - The reference handed out by `Put`.
+	async fn Delete(&self, Stored:&Stored) -> Result<(), String>;
+}
+
+/// Cuts the part of a body starting at `Offset`, at most `Length` bytes long.
+pub fn Range(Body:&[u8], Offset:u64, Length:usize) -> &[u8] {
+	let Start = usize::try_from(Offset).unwrap_or(usize::MAX).min(Body.len());
+
+	&Body[Start..Start + Length.min(Body.len() - Start)]
+}
+
+use async_trait::async_trait;
+
+use crate::Struct::Storage::Stored::Struct as Stored;
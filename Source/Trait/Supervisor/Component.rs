@@ -0,0 +1,31 @@
+/// A part of an application started and stopped by a `Supervisor`.
+///
+/// The supervisor starts its components in registration order and shuts them
+/// down in reverse, so a component registered after another may rely on it
+/// for as long as it runs.
+#[async_trait]
+pub trait Trait: Send + Sync {
+	/// Starts the component.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the component runs, or why it could not start.
+	async fn Start(&mut self) -> Result<(), String>;
+
+	/// Stops the component, finishing its work by the deadline if it can.
+	///
+	/// The supervisor stops waiting once the deadline passes, so a component
+	/// should give up on its remaining work by then.
+	///
+	/// # Arguments
+	///
+	/// * `Deadline` - When the component has to be stopped.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the component stopped, or why it did not stop cleanly.
+	async fn Shutdown(&mut self, Deadline:Instant) -> Result<(), String>;
+}
+
+use async_trait::async_trait;
+use tokio::time::Instant;
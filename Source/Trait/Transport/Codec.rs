@@ -0,0 +1,65 @@
+/// Encodes replies and decodes messages for a transport.
+///
+/// The message pump is independent of the encoding; every transport sharing
+/// a pump shares its codec. Clients use the same codec in the opposite
+/// direction.
+pub trait Trait: Send + Sync {
+	/// Decodes one frame into a message.
+	///
+	/// # Arguments
+	///
+	/// * `Frame` - The frame read from the stream.
+	///
+	/// # Returns
+	///
+	/// The decoded message, or a description of why the frame is malformed.
+	fn Decode(&self, Frame:&[u8]) -> Result<Message, String>;
+
+	/// Decodes one frame the codec may keep parts of, such as the payload of
+	/// a binary frame, without copying them; decodes it with `Decode` by
+	/// default.
+	///
+	/// # Arguments
+	///
+	/// * `Frame` - The frame read from the stream.
+	///
+	/// # Returns
+	///
+	/// The decoded message, or a description of why the frame is malformed.
+	fn DecodeFrame(&self, Frame:Vec<u8>) -> Result<Message, String> { self.Decode(&Frame) }
+
+	/// Encodes one reply into a frame.
+	///
+	/// # Arguments
+	///
+	/// * `Reply` - The reply to encode.
+	///
+	/// # Returns
+	///
+	/// The encoded frame, or a description of why encoding failed.
+	fn Encode(&self, Reply:&Reply) -> Result<Vec<u8>, String>;
+
+	/// Encodes one message into a frame, on the client side.
+	///
+	/// # Arguments
+	///
+	/// * `Message` - The message to encode.
+	///
+	/// # Returns
+	///
+	/// The encoded frame, or a description of why encoding failed.
+	fn EncodeMessage(&self, Message:&Message) -> Result<Vec<u8>, String>;
+
+	/// Decodes one frame into a reply, on the client side.
+	///
+	/// # Arguments
+	///
+	/// * `Frame` - The frame read from the stream.
+	///
+	/// # Returns
+	///
+	/// The decoded reply, or a description of why the frame is malformed.
+	fn DecodeReply(&self, Frame:&[u8]) -> Result<Reply, String>;
+}
+
+use crate::Enum::Transport::{Message::Enum as Message, Reply::Enum as Reply};
@@ -0,0 +1,18 @@
+/// The reading half of a framed transport stream.
+///
+/// Implementors split a byte stream into frames, each holding one encoded
+/// message, so the message pump is independent of the framing in use.
+#[async_trait]
+pub trait Trait: Send {
+	/// Reads the next frame.
+	///
+	/// # Returns
+	///
+	/// The next frame, `None` at the end of the stream, or the I/O error
+	/// that interrupted reading.
+	async fn Read(&mut self) -> io::Result<Option<Vec<u8>>>;
+}
+
+use std::io;
+
+use async_trait::async_trait;
@@ -0,0 +1,26 @@
+/// The writing half of a framed transport stream.
+#[async_trait]
+pub trait Trait: Send {
+	/// Writes one frame and flushes it.
+	///
+	/// # Arguments
+	///
+	/// * `Frame` - The encoded message to write.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the frame is written, or the I/O error that prevented it.
+	async fn Write(&mut self, Frame:&[u8]) -> io::Result<()>;
+
+	/// Ends the stream once every frame was written, e.g. by completing a
+	/// closing handshake; does nothing by default.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` once the stream is ended, or the I/O error that prevented it.
+	async fn Close(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+use std::io;
+
+use async_trait::async_trait;
@@ -1,6 +1,55 @@
+pub mod Health {
+	pub mod Check;
+}
+
+pub mod Import {
+	pub mod Destination;
+}
+
+#[cfg(feature = "Tauri")]
+pub mod Integration {
+	pub mod EventSink;
+}
+
+pub mod Runtime;
+
 pub mod Sequence {
 
 	pub mod Action;
 
+	pub mod AdmissionPolicy;
+
+	pub mod Handler;
+
+	pub mod LicenseValidator;
+
+	pub mod Plugin;
+
+	pub mod Queue;
+
+	pub mod Secret;
+
+	pub mod Set;
+
 	pub mod Site;
+
+	pub mod Transformer;
+}
+
+pub mod Storage {
+	pub mod Codec;
+
+	pub mod ResultStore;
+}
+
+pub mod Supervisor {
+	pub mod Component;
+}
+
+pub mod Transport {
+	pub mod Codec;
+
+	pub mod Reader;
+
+	pub mod Writer;
 }
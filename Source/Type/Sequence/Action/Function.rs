@@ -0,0 +1,19 @@
+/// Represents a shared, thread-safe function registered in a plan.
+///
+/// This type alias defines a function that:
+/// - Takes a vector of `Arg` as its arguments, so large payloads reach it
+///   without being copied; functions taking JSON values are wrapped to
+///   convert them
+/// - Returns a pinned, boxed future (see `Type::Sequence::Action::Future`)
+/// - Is wrapped in an `Arc`, so executions keep the version they looked up even
+///   if the function is replaced meanwhile
+/// - Implements `Send` and `Sync` traits, making it safe to share between
+///   threads
+pub type Type = Arc<dyn Fn(Vec<Arg>) -> Future + Send + Sync>;
+
+use std::sync::Arc;
+
+use crate::{
+	Enum::Sequence::Action::Arg::Enum as Arg,
+	Type::Sequence::Action::Future::Type as Future,
+};
@@ -0,0 +1,14 @@
+/// Represents the pinned, boxed future returned by a plan function.
+///
+/// This type alias defines a future that:
+/// - Resolves to a `Result` where:
+///   - The success case is a `serde_json::Value`
+///   - The error case is `crate::Enum::Sequence::Action::Error::Enum`
+/// - Implements `Send`, so it can be awaited on any worker thread
+pub type Type = Pin<Box<dyn futures::Future<Output = Result<Value, Error>> + Send>>;
+
+use std::pin::Pin;
+
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
@@ -0,0 +1,9 @@
+/// Represents a hook registered under a name pattern with
+/// `Life::AddHookPattern`.
+///
+/// The function receives the name it was matched against, so one
+/// registration such as `metrics.*` can tell `metrics.read` from
+/// `metrics.write`.
+pub type Type = crate::Struct::Sequence::Arc<
+	dyn Fn(&str) -> Result<(), crate::Enum::Sequence::Action::Error::Enum> + Send + Sync,
+>;
@@ -0,0 +1,4 @@
+/// A byte stream an action writes its output into.
+pub type Type = Box<dyn AsyncWrite + Send + Unpin>;
+
+use tokio::io::AsyncWrite;
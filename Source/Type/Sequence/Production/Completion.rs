@@ -0,0 +1,9 @@
+/// Represents the receiver of a submitted action's outcome.
+///
+/// A production line calls it once, with the action's sequence number and
+/// either the value the action recorded or the error of its last attempt.
+pub type Type = Box<dyn FnOnce(u64, Result<Value, Error>) + Send + Sync>;
+
+use serde_json::Value;
+
+use crate::Enum::Sequence::Action::Error::Enum as Error;
@@ -0,0 +1,9 @@
+/// Represents a single queued item of a production line.
+///
+/// This type alias pairs:
+/// - The `Stamp` assigned when the action was enqueued
+/// - The boxed action itself
+pub type Type = (
+	crate::Struct::Sequence::Production::Stamp::Struct,
+	Box<dyn crate::Trait::Sequence::Action::Trait>,
+);
@@ -0,0 +1,9 @@
+/// Represents a function creating a Karma queue on demand.
+///
+/// The argument is the name an action was routed to; the function returns
+/// the production line of the new queue together with how it is consumed.
+pub type Type = Arc<dyn Fn(&str) -> (Arc<Production>, Settings) + Send + Sync>;
+
+use std::sync::Arc;
+
+use crate::Struct::Sequence::Production::{Settings::Struct as Settings, Struct as Production};
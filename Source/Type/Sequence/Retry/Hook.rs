@@ -0,0 +1,9 @@
+/// A hook of a retry policy, called before each backoff.
+///
+/// It receives the history of the attempts so far, whose last attempt
+/// carries the backoff, and the error of that attempt.
+pub type Type<E> = Arc<dyn Fn(&History, &E) + Send + Sync>;
+
+use std::sync::Arc;
+
+use crate::Struct::Sequence::Attempt::History::Struct as History;
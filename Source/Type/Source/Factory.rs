@@ -0,0 +1,9 @@
+/// Represents a function producing the action a source emits.
+///
+/// The argument is the tick or item number, so factories can stamp it onto
+/// the action they build.
+pub type Type = Arc<dyn Fn(u64) -> Box<dyn Action> + Send + Sync>;
+
+use std::sync::Arc;
+
+use crate::Trait::Sequence::Action::Trait as Action;
@@ -1,5 +1,29 @@
 pub mod Sequence {
 	pub mod Action {
 		pub mod Cycle;
+
+		pub mod Function;
+
+		pub mod Future;
+
+		pub mod Pattern;
+
+		pub mod Writer;
 	}
+
+	pub mod Production {
+		pub mod Completion;
+
+		pub mod Entry;
+
+		pub mod Factory;
+	}
+
+	pub mod Retry {
+		pub mod Hook;
+	}
+}
+
+pub mod Source {
+	pub mod Factory;
 }
@@ -0,0 +1,74 @@
+/// The version of the wire representation, sent in `Authenticated` and
+/// `Description` replies.
+///
+/// It changes whenever a message or reply changes in a way an existing
+/// client cannot read: a field renamed, removed or made required, or a
+/// variant renamed. Added optional fields and added variants keep it.
+/// Replies from servers predating it carry version 0.
+pub const WIRE_VERSION:u32 = 1;
+
+/// Describes the wire types as JSON Schema, so clients written in other
+/// languages can validate their payloads.
+///
+/// # Returns
+///
+/// An object with the `Version` of the representation and the schemas of
+/// `Message`, what clients send, and `Reply`, what servers send back; every
+/// type they contain is among the definitions of each schema.
+#[cfg(feature = "Schema")]
+pub fn Schema() -> serde_json::Value {
+	serde_json::json!({
+		"Version": WIRE_VERSION,
+		"Message": schemars::schema_for!(Message),
+		"Reply": schemars::schema_for!(Reply),
+	})
+}
+
+pub use crate::{
+	Enum::{
+		Event::Enum as Event,
+		Health::Status::Enum as Status,
+		Sequence::{
+			Attempt::Outcome::Enum as Outcome,
+			DeadLetter::Requeue::Enum as Requeue,
+			Decision::Enum as Decision,
+			Graph::State::Enum as NodeState,
+		},
+		Transport::{
+			Admission::Enum as Admission,
+			Code::Enum as Code,
+			Control::Enum as Control,
+			Delivery::Enum as Delivery,
+			Message::Enum as Message,
+			Reply::Enum as Reply,
+			Role::Enum as Role,
+			State::Enum as State,
+		},
+	},
+	Struct::{
+		Health::{Outcome::Struct as Check, Report::Struct as Report},
+		Sequence::{
+			Attempt::{History::Struct as History, Struct as Attempt},
+			DeadLetter::{Entry::Struct as DeadEntry, Filter::Struct as DeadFilter},
+			Watchdog::InFlight::Struct as InFlight,
+		},
+		Stats::{
+			Activity::Count::Struct as Count,
+			Cost::{Report::Struct as Cost, Total::Struct as Total},
+			Queue::Struct as Queue,
+			Snapshot::Struct as Snapshot,
+			Summary::{Age::Struct as Age, Oldest::Struct as Oldest, Struct as Summary},
+		},
+		Storage::Stored::Struct as Stored,
+		Transport::{
+			Catalogue::Struct as Catalogue,
+			Drain::Struct as Drain,
+			Handoff::{Struct as Handoff, Transfer::Struct as Transfer},
+			History::{
+				Page::Struct as SubmissionPage,
+				Query::Struct as SubmissionQuery,
+				Record::Struct as Submission,
+			},
+		},
+	},
+};
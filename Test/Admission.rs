@@ -0,0 +1,415 @@
+#![allow(non_snake_case)]
+
+//! Checks admission policies: the chain of a `Life` allows, denies or
+//! patches every submission, in the order the policies were added, whether
+//! submitted in process or over the wire; decisions are published as
+//! `Admission` events and `DryRun` previews them. Also checks the built-in
+//! `Roles` and `PathPrefix` policies.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Patches the metadata of every submission.
+struct Tag(Map<String, Value>);
+
+#[async_trait]
+impl AdmissionPolicy for Tag {
+	fn Name(&self) -> String { "Tag".to_string() }
+
+	async fn Admit(&self, _Identity:&Identity, _Action:&View) -> Decision {
+		Decision::Modify { Patch:self.0.clone() }
+	}
+}
+
+/// Refuses the submissions of the `blocked` tenant.
+struct Tenant;
+
+#[async_trait]
+impl AdmissionPolicy for Tenant {
+	fn Name(&self) -> String { "Tenant".to_string() }
+
+	async fn Admit(&self, _Identity:&Identity, Action:&View) -> Decision {
+		match Action.Get("Tenant") {
+			Some(Name) if Name == "blocked" => Decision::Forbid("Tenant is blocked"),
+			_ => Decision::Allow,
+		}
+	}
+}
+
+/// A plan whose `Echo` function returns its first argument, counting its
+/// calls.
+fn Plan() -> (Arc<Plan>, Arc<AtomicUsize>) {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Echo"))
+		.WithFunction("Echo", {
+			let Ran = Ran.clone();
+
+			move |Argument:Vec<Value>| {
+				Ran.fetch_add(1, Ordering::SeqCst);
+
+				async move { Ok(Argument.into_iter().next().unwrap_or(Value::Null)) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	(Arc::new(Plan), Ran)
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life` evaluating
+/// the given policies.
+fn Start(Policy:Vec<Arc<dyn AdmissionPolicy>>) -> Life {
+	let Life = Policy
+		.into_iter()
+		.fold(Life::Builder().WithFate(Arc::new(Config::default())), |Builder, Policy| {
+			Builder.WithAdmissionPolicy(Policy)
+		})
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Life
+}
+
+/// An `Echo` action of the `main` queue.
+fn Call(Plan:&Arc<Plan>, Argument:Value) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New("Echo", Argument, Plan.clone()).WithQueue("main")
+}
+
+/// Submits an `Echo` action in process and waits for its result.
+async fn Submit(Life:&Life, Plan:&Arc<Plan>, Argument:Value) -> Result<Value, Error> {
+	timeout(Duration::from_secs(5), Life.Submit(Box::new(Call(Plan, Argument))).await)
+		.await
+		.unwrap()
+}
+
+/// A patch of the given entries.
+fn Patch(Entry:Value) -> Map<String, Value> { Entry.as_object().unwrap().clone() }
+
+#[tokio::test]
+async fn Allow() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Start(vec![Arc::new(Roles::New().Allow(Role::Client, "Ech?"))]);
+
+	let mut Subscription = Life.Subscribe();
+
+	assert_eq!(Submit(&Life, &Plan, json!(["hello"])).await, Ok(json!("hello")));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	let Event = timeout(Duration::from_secs(5), Subscription.Recv())
+		.await
+		.unwrap()
+		.unwrap();
+
+	assert!(matches!(
+		Event,
+		Event::Admission { Identity, Decision:Decision::Allow, Policy, DryRun:false, .. }
+			if Identity == "local" && Policy.is_empty()
+	));
+}
+
+#[tokio::test]
+async fn Deny() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Start(vec![Arc::new(Roles::New().Deny(Role::Client, "Echo"))]);
+
+	// A programmatic submission goes through the chain too
+	let Denied = Submit(&Life, &Plan, json!(["hello"])).await;
+
+	assert!(matches!(
+		&Denied,
+		Err(Error::Admission { Reason, Code:Code::Forbidden })
+			if Reason == "Roles: Client local may not submit Echo"
+	));
+
+	assert_eq!(Code::from(&Denied.unwrap_err()).Name(), "ECHO_FORBIDDEN");
+
+	// So does an action a source delivers
+	let Delivered = Target::Life(Life.clone()).Deliver(Box::new(Call(&Plan, json!([])))).await;
+
+	assert!(matches!(Delivered, Err(Error::Admission { .. })));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn Modify() {
+	let (Plan, Ran) = Plan();
+
+	let Set = Patch(json!({ "Argument": ["patched"], "Tenant": "acme" }));
+
+	let Life = Start(vec![Arc::new(Tag(Set.clone()))]);
+
+	let mut Subscription = Life.Subscribe();
+
+	// A dry run shows the patches without submitting
+	let Previewed = Life.DryRun(&Identity::Local(), &Call(&Plan, json!(["hello"]))).await;
+
+	assert_eq!(Previewed, Decision::Modify { Patch:Set.clone() });
+
+	assert!(matches!(
+		Subscription.Recv().await,
+		Some(Event::Admission { Decision:Decision::Modify { .. }, Policy, DryRun:true, .. })
+			if Policy == ["Tag"]
+	));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+
+	// The patched arguments reach the function, an option under its key
+	let Action:Arc<dyn Action> = Arc::new(Call(&Plan, json!(["hello"])));
+
+	assert_eq!(
+		timeout(Duration::from_secs(5), Life.Submit(Box::new(Action.clone())).await)
+			.await
+			.unwrap(),
+		Ok(json!("patched"))
+	);
+
+	assert_eq!(Action.Metadata("Tenant").await, Some(json!("acme")));
+
+	assert_eq!(Action.Metadata(&Reserved::Argument.Key()).await, Some(json!(["patched"])));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn Order() {
+	let (Plan, Ran) = Plan();
+
+	let Blocked = || Arc::new(Tag(Patch(json!({ "Tenant": "blocked" }))));
+
+	// A policy sees the patches of those before it
+	let Life = Start(vec![Blocked(), Arc::new(Tenant)]);
+
+	let Decided = Life.DryRun(&Identity::Local(), &Call(&Plan, json!([]))).await;
+
+	assert_eq!(Decided, Decision::Forbid("Tenant: Tenant is blocked"));
+
+	assert!(Submit(&Life, &Plan, json!(["hello"])).await.is_err());
+
+	// But not those after it
+	let Life = Start(vec![Arc::new(Tenant), Blocked()]);
+
+	assert_eq!(Submit(&Life, &Plan, json!(["hello"])).await, Ok(json!("hello")));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	// The first denial ends the chain
+	let Life = Start(vec![
+		Arc::new(Roles::New().Deny(Role::Client, "*")),
+		Arc::new(Tag(Patch(json!({ "Tenant": "acme" })))),
+	]);
+
+	let mut Subscription = Life.Subscribe();
+
+	assert!(Submit(&Life, &Plan, json!([])).await.is_err());
+
+	assert!(matches!(
+		Subscription.Recv().await,
+		Some(Event::Admission { Decision:Decision::Deny { .. }, Policy, .. })
+			if Policy == ["Roles"]
+	));
+}
+
+#[tokio::test]
+async fn RolePolicy() {
+	let Policy = Roles::New()
+		.Allow(Role::Client, "Read")
+		.Allow(Role::Client, "Report.*")
+		.Deny(Role::Client, "Report.Payroll")
+		.Deny(Role::Admin, "Delete");
+
+	let Admit = |Role:Role, Action:&str| {
+		let Action = View::New(Action.to_string(), Vec::new(), Map::new());
+
+		let Policy = Policy.clone();
+
+		async move { Policy.Admit(&Identity::New("someone", Role), &Action).await }
+	};
+
+	assert_eq!(Admit(Role::Client, "Read").await, Decision::Allow);
+
+	assert_eq!(Admit(Role::Client, "Report.Sales").await, Decision::Allow);
+
+	assert!(matches!(Admit(Role::Client, "Report.Payroll").await, Decision::Deny { .. }));
+
+	assert!(matches!(Admit(Role::Client, "Write").await, Decision::Deny { .. }));
+
+	// A role allowed nothing in particular may submit anything not denied
+	assert_eq!(Admit(Role::Admin, "Write").await, Decision::Allow);
+
+	assert_eq!(
+		Admit(Role::Admin, "Delete").await,
+		Decision::Deny {
+			Reason:"Admin someone may not submit Delete".to_string(),
+			Code:Code::Forbidden
+		}
+	);
+}
+
+#[tokio::test]
+async fn PathPolicy() {
+	let Guard = PathPrefix::New().Deny("/secret").Allow("public").Allow("secret");
+
+	let Admit = |Action:&str, Argument:Value| {
+		let Argument = serde_json::from_value(Argument).unwrap();
+
+		let Action = View::New(Action.to_string(), Argument, Map::new());
+
+		let Guard = Guard.clone();
+
+		async move { Guard.Admit(&Identity::Local(), &Action).await.Name() }
+	};
+
+	assert_eq!(Admit("Read", json!(["public/a.txt"])).await, "Allow");
+
+	assert_eq!(Admit("Write", json!(["./public/b.txt", "content"])).await, "Allow");
+
+	// Denied prefixes win over allowed ones, whatever the spelling
+	assert_eq!(Admit("Read", json!(["secret/key"])).await, "Deny");
+
+	assert_eq!(Admit("Delete", json!(["/public/../secret/key"])).await, "Deny");
+
+	// Every path of a copy is checked
+	assert_eq!(Admit("Copy", json!(["public/a.txt", "secret/a.txt"])).await, "Deny");
+
+	assert_eq!(Admit("Move", json!(["public/a.txt", "public/b.txt"])).await, "Allow");
+
+	// Paths under no allowed prefix, or escaping the root, are refused
+	assert_eq!(Admit("Exists", json!(["private/a.txt"])).await, "Deny");
+
+	assert_eq!(Admit("Read", json!(["../public/a.txt"])).await, "Deny");
+
+	// A glob reaching under a denied prefix is refused
+	assert_eq!(Admit("List", json!(["public/*.txt"])).await, "Allow");
+
+	assert_eq!(Admit("List", json!(["*/key"])).await, "Deny");
+
+	// Other actions are not file operations
+	assert_eq!(Admit("Echo", json!(["secret/key"])).await, "Allow");
+}
+
+#[tokio::test]
+async fn Wire() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Start(vec![
+		Arc::new(Tag(Patch(json!({ "Tenant": "blocked" })))),
+		Arc::new(Tenant),
+	]);
+
+	let History = Life.History.clone();
+
+	let Pump = Pump::New(Life, Plan);
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output)).await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let Submit = json!({ "Type": "Submit", "Id": "1", "Action": "Echo", "Argument": ["hello"] });
+
+	Writer.write_all(format!("{}\n", Submit).as_bytes()).await.unwrap();
+
+	let Reply = loop {
+		let Line = timeout(Duration::from_secs(10), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+		if Reply["Type"] == "Error" {
+			break Reply;
+		}
+	};
+
+	assert_eq!(Reply["Id"], "1");
+
+	assert_eq!(Reply["Code"], "ECHO_FORBIDDEN");
+
+	assert_eq!(Reply["Message"], "Tenant: Tenant is blocked");
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+
+	// The refusal is recorded with its reason
+	let Page = History.QuerySubmissions(&Query::New()).await;
+
+	assert_eq!(Page.Record.len(), 1);
+
+	assert_eq!(Page.Record[0].Admission, Admission::Rejected);
+
+	assert_eq!(Page.Record[0].Reason.as_deref(), Some("Tenant: Tenant is blocked"));
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Map, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::timeout,
+};
+use Echo::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{
+			Action::Error::Enum as Error,
+			Decision::Enum as Decision,
+			Reserved::Enum as Reserved,
+		},
+		Source::Target::Enum as Target,
+		Transport::{Admission::Enum as Admission, Code::Enum as Code, Role::Enum as Role},
+	},
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Admission::{
+				Identity::Struct as Identity,
+				PathPrefix::Struct as PathPrefix,
+				Roles::Struct as Roles,
+				View::Struct as View,
+			},
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, History::Query::Struct as Query, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{
+		Action::Trait as Action,
+		AdmissionPolicy::Trait as AdmissionPolicy,
+		Site::Trait as Site,
+	},
+};
@@ -0,0 +1,151 @@
+#![allow(non_snake_case)]
+
+//! Checks annotating an executing action: a handler, the tasks it spawns and
+//! its hooks all write concurrently through the invocation, every write and
+//! increment lands in the `Completed` event and the action's metadata, and
+//! the reserved keys stay untouched.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// How many times each writer increments the shared counter.
+const STEPS:i64 = 500;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn Annotated() {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", |_| async {
+				let Invocation = Invocation::Current().unwrap();
+
+				// Two workers writing in parallel with the handler itself
+				let Worker = ["Left", "Right"].map(|Name| {
+					let Invocation = Invocation.clone();
+
+					tokio::spawn(async move {
+						for _ in 0..STEPS {
+							Invocation.IncrementCounter("Steps", 1).unwrap();
+
+							yield_now().await;
+						}
+
+						Invocation.Annotate(Name, json!("done")).unwrap();
+					})
+				});
+
+				for _ in 0..STEPS {
+					Invocation.IncrementCounter("Steps", 1).unwrap();
+
+					yield_now().await;
+				}
+
+				for Worker in Worker {
+					Worker.await.unwrap();
+				}
+
+				assert!(Invocation.Annotate("Id", json!("forged")).is_err());
+
+				Invocation.Annotate("Progress", json!(100))?;
+
+				Ok(json!("worked"))
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	// Hooks annotate through the same invocation
+	for Name in ["audit", "metrics"] {
+		Life.Span.insert(
+			Name.to_string(),
+			Arc::new(move || {
+				let Invocation = Invocation::Current().unwrap();
+
+				Invocation.Annotate(&format!("Hook.{}", Name), json!(true))?;
+
+				Invocation.IncrementCounter("Hooks", 1)?;
+
+				assert!(Invocation.Annotate("ActionType", json!("forged")).is_err());
+
+				assert!(Invocation.IncrementCounter("Action", 1).is_err());
+
+				Ok(())
+			}),
+		);
+	}
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let mut Subscription = Life.Subscribe();
+
+	let Action:Arc<dyn Action> = Arc::new(
+		Echo::Struct::Sequence::Action::Struct::New("Work", Value::Null, Plan)
+			.WithMetadata("Queue", json!("main"))
+			.WithMetadata("Hooks", json!(["audit", "metrics"])),
+	);
+
+	assert_eq!(
+		timeout(Duration::from_secs(10), Life.Submit(Box::new(Action.clone())).await)
+			.await
+			.unwrap(),
+		Ok(json!("worked"))
+	);
+
+	let Annotation = loop {
+		if let Some(Event::Completed { Annotation, .. }) =
+			timeout(Duration::from_secs(10), Subscription.Recv()).await.unwrap()
+		{
+			break Annotation;
+		}
+	};
+
+	let Expected = json!({
+		"Steps": 3 * STEPS,
+		"Hooks": 2,
+		"Left": "done",
+		"Right": "done",
+		"Hook.audit": true,
+		"Hook.metrics": true,
+		"Progress": 100,
+	});
+
+	assert_eq!(Value::Object(Annotation), Expected);
+
+	for (Key, Value) in Expected.as_object().unwrap() {
+		assert_eq!(Action.Metadata(Key).await.as_ref(), Some(Value), "metadata {}", Key);
+	}
+
+	assert_eq!(Action.Metadata("Action").await, Some(json!("Work")));
+
+	assert_eq!(Action.Metadata("Id").await, None);
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{task::yield_now, time::timeout};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Invocation::Struct as Invocation,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
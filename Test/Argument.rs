@@ -0,0 +1,129 @@
+#![allow(non_snake_case)]
+
+//! Checks where a function's arguments come from: the `Argument` metadata if
+//! set, else the action's content, spread when it is an array, and that a
+//! function missing its arguments fails with `Error::Execution`.
+
+/// A plan with the file actions jailed under `Root` and a `Capture` action
+/// recording the arguments it is called with.
+fn Plan(Root:&Path) -> (Arc<Plan>, Arc<Mutex<Vec<Value>>>) {
+	let Captured = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Echo::Builtin::Fs::Struct::New(Root)
+		.Register(Echo::Struct::Sequence::Plan::Struct::New())
+		.unwrap()
+		.WithSignature(Signature::New("Capture"))
+		.WithFunction("Capture", {
+			let Captured = Captured.clone();
+
+			move |Argument:Vec<Value>| {
+				*Captured.lock().unwrap() = Argument;
+
+				async { Ok(Value::Null) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	(Arc::new(Plan), Captured)
+}
+
+/// A fresh directory for the file actions of one test.
+async fn Root(Name:&str) -> PathBuf {
+	let Root = std::env::temp_dir().join(format!("Echo-{}-{}", Name, std::process::id()));
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	Root
+}
+
+#[tokio::test]
+async fn Array() {
+	let Root = Root("ArgumentArray").await;
+
+	let (Plan, Captured) = Plan(&Root);
+
+	let Life = Life::Builder().Build();
+
+	Action::New("Capture", json!(["input.txt", 1]), Plan.clone())
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(*Captured.lock().unwrap(), [json!("input.txt"), json!(1)]);
+
+	Action::New("Write", json!(["input.txt", "Hello"]), Plan.clone())
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(tokio::fs::read_to_string(Root.join("input.txt")).await.unwrap(), "Hello");
+
+	Action::New("Read", json!(["input.txt"]), Plan).Execute(&Life).await.unwrap();
+}
+
+#[tokio::test]
+async fn Scalar() {
+	let (Plan, Captured) = Plan(&Root("ArgumentScalar").await);
+
+	let Life = Life::Builder().Build();
+
+	Action::New("Capture", "input.txt".to_string(), Plan.clone())
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(*Captured.lock().unwrap(), [json!("input.txt")]);
+
+	Action::New("Capture", json!({ "Path": "input.txt" }), Plan.clone())
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(*Captured.lock().unwrap(), [json!({ "Path": "input.txt" })]);
+
+	// The metadata wins over the content
+	Action::New("Capture", json!("ignored"), Plan.clone())
+		.WithMetadata("Argument", json!(["input.txt"]))
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(*Captured.lock().unwrap(), [json!("input.txt")]);
+
+	Action::New("Capture", Value::Null, Plan).Execute(&Life).await.unwrap();
+
+	assert!(Captured.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn Missing() {
+	let (Plan, _) = Plan(&Root("ArgumentMissing").await);
+
+	let Life = Life::Builder().Build();
+
+	let Error = Action::New("Read", Value::Null, Plan).Execute(&Life).await.unwrap_err();
+
+	let Error::InvalidRequest { Kind, Path, .. } = &Error else {
+		panic!("{:?}", Error);
+	};
+
+	assert_eq!((Kind.as_str(), Path.as_str()), ("Read", "."));
+
+	assert!(Error.to_string().starts_with("Invalid request for Read"), "{}", Error);
+}
+
+use std::{
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex},
+};
+
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::{Signature::Struct as Signature, Struct as Action},
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+	},
+};
@@ -0,0 +1,259 @@
+#![allow(non_snake_case)]
+
+//! Checks backpressure from result consumers: the actions of an identity
+//! whose client reads its replies slowly run at the pace of that client,
+//! while another identity's run at full speed, and closing the slow
+//! connection releases every action held for it.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// How many actions ran, by the name they were given.
+type Executed = Arc<Mutex<HashMap<String, usize>>>;
+
+/// The client end of one connection to a pump.
+struct Client {
+	/// Where messages are written.
+	Writer:WriteHalf<DuplexStream>,
+
+	/// Where replies are read.
+	Reader:Lines<BufReader<ReadHalf<DuplexStream>>>,
+}
+
+impl Client {
+	/// Opens a connection served by `Pump` and authenticates with a token.
+	async fn Connect(Pump:&Pump, Token:&str) -> Self {
+		let (Client, Server) = duplex(512);
+
+		let Pump = Pump.clone();
+
+		tokio::spawn(async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		});
+
+		let (Reader, Writer) = split(Client);
+
+		let mut Client = Client { Writer, Reader:BufReader::new(Reader).lines() };
+
+		Client.Send(json!({ "Type": "Auth", "Token": Token })).await;
+
+		assert_eq!(Client.Reply().await["Type"], "Authenticated");
+
+		Client
+	}
+
+	/// Sends one message.
+	async fn Send(&mut self, Message:Value) {
+		self.Writer
+			.write_all(format!("{}\n", Message).as_bytes())
+			.await
+			.unwrap();
+
+		self.Writer.flush().await.unwrap();
+	}
+
+	/// Submits `Count` actions counting under `Name`.
+	async fn Submit(&mut self, Name:&str, Count:usize) {
+		for Id in 0..Count {
+			self.Send(json!({
+				"Type": "Submit",
+				"Id": Id.to_string(),
+				"Action": "Count",
+				"Argument": [Name],
+				"Metadata": { "Queue": "main" },
+			}))
+			.await;
+		}
+	}
+
+	/// Reads the next reply.
+	async fn Reply(&mut self) -> Value {
+		let Line = timeout(Duration::from_secs(10), self.Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		serde_json::from_str(&Line).unwrap()
+	}
+}
+
+/// A pump over a running `Life` whose `main` queue runs four `Count`
+/// actions of 5ms at once, pausing an identity beyond 8 waiting replies
+/// until 2 remain, and accepting the tokens of the `slow` and `fast`
+/// identities.
+fn Start() -> (Pump, Life, Executed) {
+	let Executed = Executed::default();
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Count"))
+			.WithFunction("Count", {
+				let Executed = Executed.clone();
+
+				move |Argument:Vec<Value>| {
+					let Executed = Executed.clone();
+
+					async move {
+						let Name = Argument[0].as_str().unwrap_or_default().to_string();
+
+						*Executed.lock().unwrap().entry(Name).or_default() += 1;
+
+						sleep(Duration::from_millis(5)).await;
+
+						Ok(json!("counted"))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New().WithConcurrency(4))
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Pump = Pump::New(Life.clone(), Plan)
+		.WithIdentity("slow", "slow-token")
+		.WithIdentity("fast", "fast-token")
+		.WithBackpressure(8, 2);
+
+	(Pump, Life, Executed)
+}
+
+/// Returns how many actions ran under a name.
+fn Count(Executed:&Executed, Name:&str) -> usize {
+	Executed.lock().unwrap().get(Name).copied().unwrap_or(0)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn Tracked() {
+	let (Pump, Life, Executed) = Start();
+
+	let mut Slow = Client::Connect(&Pump, "slow-token").await;
+
+	let mut Fast = Client::Connect(&Pump, "fast-token").await;
+
+	Slow.Submit("slow", 100).await;
+
+	Fast.Submit("fast", 100).await;
+
+	let mut Completed = 0;
+
+	while Completed < 100 {
+		if Fast.Reply().await["Type"] == "Result" {
+			Completed += 1;
+		}
+	}
+
+	// The fast identity finished while the slow one waits on its client
+	assert!(Count(&Executed, "slow") < 50, "{} slow actions ran", Count(&Executed, "slow"));
+
+	assert!(Life.Backpressure.Pressed("slow"));
+
+	assert!(!Life.Backpressure.Pressed("fast"));
+
+	let mut Read = 0;
+
+	while Read < 100 {
+		sleep(Duration::from_millis(5)).await;
+
+		if Slow.Reply().await["Type"] == "Result" {
+			Read += 1;
+		}
+
+		// What ran is what was read, the replies waiting up to the
+		// high-water mark or in the stream, what was running and what
+		// completed before a stalled write noticed
+		let Ran = Count(&Executed, "slow");
+
+		assert!(Ran <= Read + 8 + 12 + 4 + 8, "{} slow actions ran with {} read", Ran, Read);
+	}
+
+	assert_eq!(Count(&Executed, "slow"), 100);
+
+	assert!(!Life.Backpressure.Pressed("slow"));
+
+	assert_eq!(Life.Backpressure.Held(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn Closed() {
+	let (Pump, Life, Executed) = Start();
+
+	let mut Slow = Client::Connect(&Pump, "slow-token").await;
+
+	Slow.Submit("slow", 50).await;
+
+	timeout(Duration::from_secs(10), async {
+		while Life.Backpressure.Held() == 0 {
+			sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("no action held");
+
+	assert!(Count(&Executed, "slow") < 50);
+
+	drop(Slow);
+
+	timeout(Duration::from_secs(10), async {
+		while Count(&Executed, "slow") < 50 {
+			sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("held actions never ran");
+
+	assert!(!Life.Backpressure.Pressed("slow"));
+}
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex,
+		split,
+		AsyncBufReadExt,
+		AsyncWriteExt,
+		BufReader,
+		DuplexStream,
+		Lines,
+		ReadHalf,
+		WriteHalf,
+	},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,115 @@
+#![allow(non_snake_case)]
+
+//! Checks batch submission: the completions of a batch stream in the order
+//! the actions finish, waiting for all of them reports the outcomes in
+//! submission order with the actions left at the deadline still pending,
+//! and the progress counters follow the completions.
+
+/// Submits a batch of actions named by their indices.
+async fn Submit(Production:&Production, Count:usize) -> Batch {
+	let Plan = Arc::new(Plan::New().Build());
+
+	Production
+		.SubmitBatch(
+			(0..Count)
+				.map(|Index| {
+					Box::new(Echo::Struct::Sequence::Action::Struct::New(
+						&Index.to_string(),
+						Value::Null,
+						Plan.clone(),
+					)) as Box<dyn Action>
+				})
+				.collect(),
+		)
+		.await
+}
+
+/// Takes the queued actions and returns their sequence numbers.
+async fn Drain(Production:&Production) -> Vec<u64> {
+	let mut Sequence = Vec::new();
+
+	while let Some((Stamp, _)) = Production.DequeueStamped().await {
+		Sequence.push(Stamp.Sequence);
+	}
+
+	Sequence
+}
+
+#[tokio::test]
+async fn Streamed() {
+	let Production = Production::New();
+
+	let Batch = Submit(&Production, 3).await;
+
+	let Id = Batch.Id().to_vec();
+
+	assert_eq!(Drain(&Production).await, Id);
+
+	let Progress = Batch.Progress();
+
+	assert_eq!((Progress.Completed(), Progress.Total()), (0, 3));
+
+	// Finished out of order
+	Production.Complete(Id[2], Ok(json!("third")));
+
+	Production.Complete(Id[0], Err(Error::Execution("first".to_string())));
+
+	assert_eq!(Progress.Completed(), 2);
+
+	Production.Complete(Id[1], Ok(json!("second")));
+
+	assert_eq!(Progress.Completed(), 3);
+
+	let Outcome = timeout(Duration::from_secs(10), Batch.Results().collect::<Vec<_>>())
+		.await
+		.unwrap();
+
+	assert_eq!(
+		Outcome,
+		[
+			(Id[2], Ok(json!("third"))),
+			(Id[0], Err(Error::Execution("first".to_string()))),
+			(Id[1], Ok(json!("second"))),
+		]
+	);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Deadline() {
+	let Production = Production::New();
+
+	let Batch = Submit(&Production, 3).await;
+
+	let Id = Batch.Id().to_vec();
+
+	let Progress = Batch.Progress();
+
+	Drain(&Production).await;
+
+	Production.Complete(Id[2], Ok(json!(2)));
+
+	Production.Complete(Id[0], Ok(json!(0)));
+
+	let Outcome = Batch.WaitAll(Instant::now() + Duration::from_secs(1)).await;
+
+	assert_eq!(Outcome, [(Id[0], Some(Ok(json!(0)))), (Id[1], None), (Id[2], Some(Ok(json!(2))))]);
+
+	// The straggler still counts once it finishes
+	Production.Complete(Id[1], Ok(json!(1)));
+
+	assert_eq!((Progress.Completed(), Progress.Total()), (3, 3));
+}
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use serde_json::{json, Value};
+use tokio::time::{timeout, Duration, Instant};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Plan::Struct as Plan,
+		Production::{Batch::Struct as Batch, Struct as Production},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,100 @@
+#![allow(non_snake_case)]
+
+//! Checks the base64 convention for binary results: bytes wrapped by
+//! `Fn::Binary::Encode` come back whole from `Fn::Binary::Decode`, also as
+//! the value of an action run through a sequence, values outside the
+//! convention are left alone, and malformed ones are refused.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+#[test]
+fn RoundTrip() {
+	let Every = (0..=255).collect::<Vec<u8>>();
+
+	for Data in [&[][..], b"Echo", &Every] {
+		let Value = Encode::Fn(Data);
+
+		assert_eq!(Value["encoding"], "base64");
+
+		assert_eq!(Decode::Fn(&Value).unwrap().as_deref(), Some(Data));
+	}
+
+	assert_eq!(Encode::Fn(b"Echo"), json!({ "encoding": "base64", "data": "RWNobw==" }));
+}
+
+#[test]
+fn Unwrapped() {
+	for Value in [
+		json!("RWNobw=="),
+		json!({ "data": "RWNobw==" }),
+		json!({ "encoding": "hex", "data": "4563686f" }),
+		json!([1, 2, 3]),
+		Value::Null,
+	] {
+		assert_eq!(Decode::Fn(&Value).unwrap(), None, "{} was decoded", Value);
+	}
+}
+
+#[test]
+fn Malformed() {
+	for Value in [
+		json!({ "encoding": "base64" }),
+		json!({ "encoding": "base64", "data": 7 }),
+		json!({ "encoding": "base64", "data": "not base64!" }),
+	] {
+		assert!(
+			matches!(Decode::Fn(&Value), Err(Error::Execution(_))),
+			"{} was not refused",
+			Value
+		);
+	}
+}
+
+#[tokio::test]
+async fn Sequenced() {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Read"))
+			.WithFunction("Read", |_| async { Ok(Encode::Fn(&[0, 159, 146, 150, 255])) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Read", (), Plan).WithQueue("main");
+
+	let Value = Life.Submit(Box::new(Action)).await.await.unwrap();
+
+	assert_eq!(Decode::Fn(&Value).unwrap(), Some(vec![0, 159, 146, 150, 255]));
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Fn::Binary::{Decode, Encode},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
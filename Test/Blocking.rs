@@ -0,0 +1,147 @@
+#![allow(non_snake_case)]
+
+//! Checks the ways CPU-heavy plan functions leave the runtime responsive: a
+//! `Blocking` function spins on its own thread while other actions keep
+//! running, and a function calling `Invocation::Checkpoint` in its loop ends
+//! promptly once its attempt is cancelled or expires.
+
+/// Keeps the current thread busy for a while without yielding.
+fn Spin(For:Duration) {
+	let Started = Instant::now();
+
+	while Started.elapsed() < For {
+		std::hint::spin_loop();
+	}
+}
+
+/// A plan with a `Spin` function spinning for a second on the blocking pool,
+/// a `Loop` function spinning until its attempt ends, `Blocking` or not, and
+/// a trivial `Trivial` function.
+fn Plan() -> Arc<Plan> {
+	let Loop = |_:Vec<Value>| async {
+		let Invocation =
+			Invocation::Current().ok_or_else(|| Error::Execution("No invocation".to_string()))?;
+
+		loop {
+			Invocation.Checkpoint().await?;
+
+			Spin(Duration::from_millis(1));
+		}
+	};
+
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Spin").WithBlocking(true))
+			.WithFunction("Spin", |_| async {
+				Spin(Duration::from_secs(1));
+
+				Ok(json!("spun"))
+			})
+			.unwrap()
+			.WithSignature(Signature::New("Loop"))
+			.WithFunction("Loop", Loop)
+			.unwrap()
+			.WithSignature(Signature::New("BlockingLoop").WithBlocking(true))
+			.WithFunction("BlockingLoop", Loop)
+			.unwrap()
+			.WithSignature(Signature::New("Trivial"))
+			.WithFunction("Trivial", |_| async { Ok(json!("done")) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates an action of the given type.
+fn Action(Plan:&Arc<Plan>, Name:&str) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New(Name, Value::Null, Plan.clone())
+}
+
+#[tokio::test]
+async fn Offloaded() {
+	let (Plan, Life) = (Plan(), Life::Builder().Build());
+
+	let Spinning = tokio::spawn({
+		let (Action, Life) = (Action(&Plan, "Spin"), Life.clone());
+
+		async move { Action.Execute(&Life).await }
+	});
+
+	let Started = Instant::now();
+
+	// Let the spin start; a spin on this worker would hold up the sleep too
+	sleep(Duration::from_millis(50)).await;
+
+	Action(&Plan, "Trivial").Execute(&Life).await.unwrap();
+
+	assert!(Started.elapsed() < Duration::from_millis(250), "delayed {:?}", Started.elapsed());
+
+	assert!(Latency::Probe(&*Life.Runtime()).await < Duration::from_millis(200));
+
+	assert!(!Spinning.is_finished());
+
+	assert!(matches!(Spinning.await, Ok(Ok(()))));
+}
+
+#[tokio::test]
+async fn Cancelled() {
+	let (Plan, Life) = (Plan(), Life::Builder().Build());
+
+	for Name in ["Loop", "BlockingLoop"] {
+		let Invocation = Invocation::New(0, 1);
+
+		let Looping = tokio::spawn({
+			let (Action, Life) = (Action(&Plan, Name), Life.clone());
+
+			Invocation.clone().Scope(async move { Action.Execute(&Life).await })
+		});
+
+		sleep(Duration::from_millis(50)).await;
+
+		assert!(!Looping.is_finished(), "{} ended on its own", Name);
+
+		Invocation.Cancel();
+
+		assert!(matches!(
+			timeout(Duration::from_millis(500), Looping).await,
+			Ok(Ok(Err(Error::Cancellation(_))))
+		));
+	}
+}
+
+#[tokio::test]
+async fn Expired() {
+	let (Plan, Life) = (Plan(), Life::Builder().Build());
+
+	let Invocation =
+		Invocation::New(0, 1).WithDeadline(tokio::time::Instant::now() + Duration::from_millis(50));
+
+	let Action = Action(&Plan, "Loop");
+
+	let Outcome = timeout(
+		Duration::from_millis(500),
+		Invocation.Scope(async move { Action.Execute(&Life).await }),
+	)
+	.await;
+
+	assert!(matches!(Outcome, Ok(Err(Error::DeadlineExceeded(_)))));
+}
+
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Health::Latency::Struct as Latency,
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Invocation::Struct as Invocation,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+		},
+	},
+};
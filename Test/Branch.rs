@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+
+//! Checks that `OnSuccess` follow-ups run after a success, `OnFailure` ones
+//! after a failure, which they absorb when `AbsorbFailure` is set, and that
+//! both combine with `NextAction`, whose keys may be namespaced.
+
+/// A plan whose `Succeed` and `Fail` functions record their first argument,
+/// or their name without one, in order.
+fn Plan(Log:&Arc<Mutex<Vec<Value>>>) -> Arc<Plan> {
+	let Record = |Name:&'static str, Outcome:Result<Value, Error>| {
+		let Log = Log.clone();
+
+		move |Argument:Vec<Value>| {
+			Log.lock().unwrap().push(Argument.into_iter().next().unwrap_or(json!(Name)));
+
+			let Outcome = Outcome.clone();
+
+			async move { Outcome }
+		}
+	};
+
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Succeed"))
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Succeed", Record("Succeed", Ok(json!("done"))))
+			.unwrap()
+			.WithFunction("Fail", Record("Fail", Err(Error::Execution("broken".to_string()))))
+			.unwrap()
+			.Build(),
+	)
+}
+
+#[tokio::test]
+async fn Success() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let Life = Life::Builder().Build();
+
+	Action::New("Succeed", Value::Null, Plan(&Log))
+		.WithMetadata(
+			"OnSuccess",
+			json!({
+				"Action": "Succeed",
+				"Argument": ["{{metadata:Previous}}"],
+				"Template": true,
+			}),
+		)
+		.WithMetadata("OnFailure", json!({ "Action": "Succeed", "Argument": ["compensated"] }))
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(*Log.lock().unwrap(), vec![json!("Succeed"), json!("done")]);
+}
+
+#[tokio::test]
+async fn Failure() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let Life = Life::Builder().Build();
+
+	let Failing = || {
+		Action::New("Fail", Value::Null, Plan(&Log))
+			.WithMetadata("OnSuccess", json!({ "Action": "Succeed", "Argument": ["succeeded"] }))
+			.WithMetadata("NextAction", json!({ "Action": "Succeed", "Argument": ["next"] }))
+			.WithMetadata(
+				"OnFailure",
+				json!({
+					"Action": "Succeed",
+					"Argument": ["{{metadata:Previous}}"],
+					"Template": true,
+				}),
+			)
+	};
+
+	// The failure is returned once its compensation ran
+	assert_eq!(
+		Failing().Execute(&Life).await,
+		Err(Error::Execution("broken".to_string()))
+	);
+
+	assert_eq!(
+		*Log.lock().unwrap(),
+		vec![json!("Fail"), json!("Execution Error: broken")]
+	);
+
+	Log.lock().unwrap().clear();
+
+	assert_eq!(Failing().WithMetadata("AbsorbFailure", json!(true)).Execute(&Life).await, Ok(()));
+
+	assert_eq!(
+		*Log.lock().unwrap(),
+		vec![json!("Fail"), json!("Execution Error: broken")]
+	);
+
+	// Without an `OnFailure` there is nothing to absorb the failure
+	assert!(Action::New("Fail", Value::Null, Plan(&Log))
+		.WithMetadata("AbsorbFailure", json!(true))
+		.Execute(&Life)
+		.await
+		.is_err());
+}
+
+#[tokio::test]
+async fn Next() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let Life = Life::Builder().Build();
+
+	// `OnSuccess` and everything after it runs before `NextAction`, and a
+	// follow-up branches on its own outcome
+	Action::New("Succeed", Value::Null, Plan(&Log))
+		.WithMetadata(
+			"OnSuccess",
+			json!({
+				"Action": "Fail",
+				"Argument": ["success"],
+				"AbsorbFailure": true,
+				"NextAction": { "Action": "Succeed", "Argument": ["skipped"] },
+				"OnFailure": { "Action": "Succeed", "Argument": ["compensation"] },
+			}),
+		)
+		.WithMetadata("NextAction", json!({ "Action": "Succeed", "Argument": ["next"] }))
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(
+		*Log.lock().unwrap(),
+		vec![json!("Succeed"), json!("success"), json!("compensation"), json!("next")]
+	);
+
+	Log.lock().unwrap().clear();
+
+	// An unabsorbed failure down the chain ends it
+	let Error = Action::New("Succeed", Value::Null, Plan(&Log))
+		.WithMetadata(
+			"NextAction",
+			json!([
+				{
+					"Action": "Fail",
+					"Argument": ["failed"],
+					"OnFailure": { "Action": "Succeed", "Argument": ["compensation"] },
+				},
+				{ "Action": "Succeed", "Argument": ["skipped"] },
+			]),
+		)
+		.Execute(&Life)
+		.await
+		.unwrap_err();
+
+	assert_eq!(Error, Error::Execution("broken".to_string()));
+
+	assert_eq!(
+		*Log.lock().unwrap(),
+		vec![json!("Succeed"), json!("failed"), json!("compensation")]
+	);
+}
+
+#[tokio::test]
+async fn Namespaced() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let Life = Life::Builder().Build();
+
+	Action::New("Succeed", Value::Null, Plan(&Log))
+		.WithMetadata(
+			"__echo.NextAction",
+			json!({
+				"__echo.Action": "Succeed",
+				"__echo.Argument": ["next"],
+				"__echo.NextAction": { "__echo.Action": "Succeed", "Argument": ["last"] },
+			}),
+		)
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(*Log.lock().unwrap(), vec![json!("Succeed"), json!("next"), json!("last")]);
+}
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::{Signature::Struct as Signature, Struct as Action},
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+	},
+};
@@ -0,0 +1,192 @@
+#![allow(non_snake_case)]
+
+//! Checks cancelling actions: one cancelled while it waits out its `Delay`
+//! never runs its function, a running one fails with `Cancellation` without
+//! running its hooks or `NextAction`, and `Sequence::ShutdownNow` cancels
+//! what its sequence executes instead of waiting for it.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan with a `Count` function counting its calls and a `Hang` function
+/// that never resolves, and the count.
+fn Plan() -> (Arc<Plan>, Arc<AtomicUsize>) {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Count"))
+		.WithFunction("Count", {
+			let Ran = Ran.clone();
+
+			move |_| {
+				Ran.fetch_add(1, Ordering::SeqCst);
+
+				async { Ok(json!("counted")) }
+			}
+		})
+		.unwrap()
+		.WithSignature(Signature::New("Hang"))
+		.WithFunction("Hang", |_| pending())
+		.unwrap()
+		.Build();
+
+	(Arc::new(Plan), Ran)
+}
+
+/// Creates an action of the given type in the `main` queue.
+fn Action(Plan:&Arc<Plan>, Name:&str) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New(Name, Value::Null, Plan.clone())
+		.WithMetadata("Queue", json!("main"))
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life`.
+fn Start() -> (Life, Sequence) {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	(Life, Sequence)
+}
+
+#[tokio::test]
+async fn Delayed() {
+	let ((Plan, Ran), (Life, Sequence)) = (Plan(), Start());
+
+	let Action = Action(&Plan, "Count").WithMetadata("Delay", json!(300));
+
+	let Cancellation = Action.Cancellation();
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	sleep(Duration::from_millis(100)).await;
+
+	Cancellation.Cancel();
+
+	let Outcome = timeout(Duration::from_secs(2), Pending).await.unwrap();
+
+	assert!(matches!(Outcome, Err(Error::Cancellation(_))), "{:?}", Outcome);
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Running() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Life::Builder().Build();
+
+	let Hook = Arc::new(AtomicUsize::new(0));
+
+	Life.Span.insert("audit".to_string(), {
+		let Hook = Hook.clone();
+
+		Arc::new(move || {
+			Hook.fetch_add(1, Ordering::SeqCst);
+
+			Ok(())
+		})
+	});
+
+	// Cancelled before it runs: neither its hooks nor its function run
+	let Cancelled = Action(&Plan, "Count").WithMetadata("Hooks", json!(["audit"]));
+
+	Cancelled.Cancel();
+
+	assert!(matches!(Cancelled.Execute(&Life).await, Err(Error::Cancellation(_))));
+
+	assert_eq!((Hook.load(Ordering::SeqCst), Ran.load(Ordering::SeqCst)), (0, 0));
+
+	// A copy is not cancelled along
+	let Copy = Action(&Plan, "Count").Cloneable();
+
+	let Duplicate = Copy.Duplicate().unwrap();
+
+	Copy.Cancel();
+
+	assert_eq!(Duplicate.Execute(&Life).await, Ok(()));
+
+	// Cancelled while its function hangs: its follow-up does not run
+	let Hanging = Action(&Plan, "Hang").WithMetadata("NextAction", json!({ "Action": "Count" }));
+
+	let Cancellation = Hanging.Cancellation();
+
+	let Running = tokio::spawn({
+		let Life = Life.clone();
+
+		async move { Hanging.Execute(&Life).await }
+	});
+
+	sleep(Duration::from_millis(50)).await;
+
+	Cancellation.Cancel();
+
+	let Outcome = timeout(Duration::from_secs(1), Running).await.unwrap().unwrap();
+
+	assert!(matches!(Outcome, Err(Error::Cancellation(_))), "{:?}", Outcome);
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn Shutdown() {
+	let ((Plan, _), (Life, Sequence)) = (Plan(), Start());
+
+	let mut Subscription = Life.Subscribe();
+
+	let Pending = Life.Submit(Box::new(Action(&Plan, "Hang"))).await;
+
+	// Wait until the action runs
+	while !matches!(Subscription.Recv().await, Some(Event::Started { .. })) {}
+
+	let Started = Instant::now();
+
+	Sequence.ShutdownNow().await;
+
+	let Outcome = timeout(Duration::from_secs(2), Pending).await.unwrap();
+
+	assert!(matches!(Outcome, Err(Error::Cancellation(_))), "{:?}", Outcome);
+
+	// Without a retry and its backoff of at least a second
+	assert!(Started.elapsed() < Duration::from_millis(900), "took {:?}", Started.elapsed());
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::future::pending;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout, Instant};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,147 @@
+#![allow(non_snake_case)]
+
+//! Checks that a long `NextAction` chain runs without the stack growing
+//! with it: each follow-up runs in the frame of the one before, and that a
+//! chain one follow-up past the limit of its line is refused before anything
+//! is enqueued.
+
+/// Returns the address of a local of a fresh frame, an approximation of the
+/// depth of the stack.
+#[inline(never)]
+fn Depth() -> usize {
+	let Marker = 0u8;
+
+	black_box(&Marker) as *const u8 as usize
+}
+
+/// Runs a test on a thread with a stack large enough for copying and
+/// dropping a chain of 10,000 follow-ups, which recurses within
+/// `serde_json`, unlike running it.
+fn Deep(Test:fn() -> BoxFuture<'static, ()>) {
+	std::thread::Builder::new()
+		.stack_size(256 << 20)
+		.spawn(move || Builder::new_current_thread().enable_all().build().unwrap().block_on(Test()))
+		.unwrap()
+		.join()
+		.unwrap();
+}
+
+/// Creates a `NextAction` chain of the given number of `Print` follow-ups.
+fn Linked(Length:usize) -> Value {
+	// Nested by moving each link into the next, as `json!` would copy the
+	// chain built so far into every one
+	let mut Chain = json!({ "Action": "Print" });
+
+	for _ in 1..Length {
+		let mut Link = Map::new();
+
+		Link.insert("Action".to_string(), json!("Print"));
+		Link.insert("NextAction".to_string(), Chain);
+
+		Chain = Value::Object(Link);
+	}
+
+	Chain
+}
+
+#[test]
+fn Long() { Deep(|| Box::pin(Run())) }
+
+#[test]
+fn Refused() { Deep(|| Box::pin(Refuse())) }
+
+/// Runs a chain of 10,000 `Print` actions, recording the depth of the
+/// stack in each.
+async fn Run() {
+	let Stack = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Print"))
+		.WithFunction("Print", {
+			let Stack = Stack.clone();
+
+			move |_| {
+				Stack.lock().unwrap().push(Depth());
+
+				async { Ok(Value::Null) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	let Chain = Linked(9_999);
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(
+			Config::builder().set_override("chain.depth", 20_000).unwrap().build().unwrap(),
+		))
+		.Build();
+
+	let Action = Action::New("Print", Value::Null, Arc::new(Plan)).WithMetadata("NextAction", Chain);
+
+	assert_eq!(Action.Execute(&Life).await, Ok(()));
+
+	let Stack = Stack.lock().unwrap();
+
+	assert_eq!(Stack.len(), 10_000);
+
+	// The first follow-up runs a frame deeper than the action, and every
+	// later one as deep as the first
+	let Spread = Stack[1..].iter().max().unwrap() - Stack[1..].iter().min().unwrap();
+
+	assert_eq!(Spread, 0, "the stack grew by {} bytes along the chain", Spread);
+}
+
+/// Submits chains of 10,000 and 10,001 follow-ups to a line limited to
+/// 10,000, only the first of which is enqueued.
+async fn Refuse() {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Print"))
+			.WithFunction("Print", |_| async { Ok(Value::Null) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Production = Production::New();
+
+	// Only the number of follow-ups is limited
+	Production.Restrict(Chain::New().WithDepth(None).WithBytes(None).WithNodes(Some(10_000)));
+
+	let Chained = |Length| {
+		Box::new(
+			Action::New("Print", Value::Null, Plan.clone())
+				.WithMetadata("NextAction", Linked(Length)),
+		)
+	};
+
+	let Limit = Error::ChainLimit { Limit:"nodes".to_string(), Maximum:10_000 };
+
+	assert_eq!(Production.Enqueue(Chained(10_001)).await.err(), Some(Limit.clone()));
+
+	assert_eq!(Production.Submit(Chained(10_001)).await.await, Err(Limit));
+
+	assert_eq!(Production.Stats().Depth, 0);
+
+	assert!(Production.Enqueue(Chained(10_000)).await.is_ok());
+
+	assert_eq!(Production.Stats().Depth, 1);
+}
+
+use std::{
+	hint::black_box,
+	sync::{Arc, Mutex},
+};
+
+use config::Config;
+use futures::future::BoxFuture;
+use serde_json::{json, Map, Value};
+use tokio::runtime::Builder;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::{Signature::Struct as Signature, Struct as Action},
+		Life::Struct as Life,
+		Production::{Chain::Struct as Chain, Struct as Production},
+	},
+};
@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+//! Checks the channel source: a thousand actions sent through a small
+//! bounded channel reach the production line in the order they were sent,
+//! the sender never runs more than the channel's capacity ahead of the line,
+//! and the source ends once the sender is dropped.
+
+/// The capacity of the channel.
+const CAPACITY:usize = 4;
+
+/// Creates an action identified by its index.
+fn Numbered(Index:usize) -> Box<dyn Action> {
+	Box::new(Echo::Struct::Sequence::Action::Struct::New(
+		&Index.to_string(),
+		Value::Null,
+		Arc::new(Plan::New().Build()),
+	))
+}
+
+#[tokio::test]
+async fn Ordered() {
+	let Production = Arc::new(Production::New());
+
+	let (Sender, Receiver) = channel(CAPACITY);
+
+	let Handle = Channel::Attach(Receiver, Production.clone());
+
+	for Index in 0..1000 {
+		Sender.send(Numbered(Index)).await.unwrap();
+
+		// The channel holds at most its capacity, and the source one more
+		let Ahead = Index as u64 + 1 - Production.Stats().Enqueued;
+
+		assert!(Ahead <= CAPACITY as u64 + 1, "{} actions ahead after {}", Ahead, Index);
+	}
+
+	drop(Sender);
+
+	timeout(Duration::from_secs(10), Handle.Join()).await.unwrap().unwrap();
+
+	let mut Order = Vec::new();
+
+	while let Some(Action) = Production.Dequeue().await {
+		let Name = Action.Metadata("Action").await.unwrap();
+
+		Order.push(Name.as_str().unwrap().parse::<usize>().unwrap());
+	}
+
+	assert_eq!(Order, (0..1000).collect::<Vec<_>>());
+}
+
+use std::{sync::Arc, time::Duration};
+
+use serde_json::Value;
+use tokio::{sync::mpsc::channel, time::timeout};
+use Echo::{
+	Struct::{
+		Sequence::{Plan::Struct as Plan, Production::Struct as Production},
+		Source::Channel::Struct as Channel,
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
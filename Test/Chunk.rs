@@ -0,0 +1,366 @@
+#![allow(non_snake_case)]
+
+//! Checks results sent in chunks: the chunks of two results on one
+//! connection interleave, each in order and matching its digest; a client
+//! aborting a transfer is sent no more of it; a 50 MB result reaches a
+//! client whole; and a client rejects a chunk out of order and a digest
+//! that does not match, aborting the transfer on the server.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A result of `Size` bytes of JSON.
+fn Large(Size:usize) -> Value { Value::String("x".repeat(Size - 2)) }
+
+/// A pump over a running `Life` sending results above 1 KiB in chunks of
+/// `Chunk` bytes, whose `Large` action returns `Size` bytes of JSON and
+/// whose `Small` one returns a short string.
+fn Start(Size:usize, Chunk:usize) -> Pump {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Large"))
+			.WithFunction("Large", move |_| async move { Ok(Large(Size)) })
+			.unwrap()
+			.WithSignature(Signature::New("Small"))
+			.WithFunction("Small", |_| async { Ok(json!("small")) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Pump::New(Life, Plan)
+		.WithOffload(Arc::new(Offload::New(Arc::new(Memory::default()), 1024)))
+		.WithChunking(Chunk)
+}
+
+/// A line-framed connection served by a pump.
+struct Connection {
+	/// The replies, one JSON object per line.
+	Reader:Lines<BufReader<ReadHalf<DuplexStream>>>,
+
+	/// Where messages are written.
+	Writer:WriteHalf<DuplexStream>,
+}
+
+impl Connection {
+	/// Serves a new connection with `Pump`.
+	fn Open(Pump:Pump) -> Self {
+		let (Client, Server) = duplex(1 << 16);
+
+		tokio::spawn(async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		});
+
+		let (Reader, Writer) = split(Client);
+
+		Connection { Reader:BufReader::new(Reader).lines(), Writer }
+	}
+
+	/// Sends one message.
+	async fn Send(&mut self, Message:Value) {
+		self.Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+	}
+
+	/// Reads the next reply that is not an `Ack`.
+	async fn Next(&mut self) -> Value {
+		loop {
+			let Line = timeout(Duration::from_secs(10), self.Reader.next_line())
+				.await
+				.expect("no reply in time")
+				.unwrap()
+				.expect("stream closed");
+
+			let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+			if Reply["Type"] != "Ack" {
+				return Reply;
+			}
+		}
+	}
+}
+
+/// Returns the submission of an action to the `main` queue.
+fn Submit(Id:&str, Action:&str) -> Value {
+	json!({ "Type": "Submit", "Id": Id, "Action": Action, "Metadata": { "Queue": "main" } })
+}
+
+#[tokio::test]
+async fn Interleaved() {
+	let mut Connection = Connection::Open(Start(64 * 1024, 4096));
+
+	Connection.Send(Submit("1", "Large")).await;
+
+	Connection.Send(Submit("2", "Large")).await;
+
+	let (mut Data, mut Order, mut Ended) = (HashMap::new(), Vec::new(), 0);
+
+	while Ended < 2 {
+		let Reply = Connection.Next().await;
+
+		let Id = Reply["Id"].as_str().unwrap().to_string();
+
+		match Reply["Type"].as_str().unwrap() {
+			"ResultChunk" => {
+				let Chunk = Data.entry(Id.clone()).or_insert_with(Vec::new);
+
+				// Each result comes in order, 16 chunks of 4 KiB
+				assert_eq!(Reply["Index"], json!(Chunk.len()));
+
+				assert_eq!(Reply["Total"], 16);
+
+				Chunk.push(STANDARD.decode(Reply["Data"].as_str().unwrap()).unwrap());
+
+				Order.push(Id);
+			},
+			"ResultEnd" => {
+				let Body = Data[&Id].concat();
+
+				assert_eq!(Reply["Digest"], Hex(&Digest::Raw(&Body)));
+
+				assert_eq!(serde_json::from_slice::<Value>(&Body).unwrap(), Large(64 * 1024));
+
+				Ended += 1;
+			},
+			Type => panic!("unexpected {} reply", Type),
+		}
+	}
+
+	// The second result started before the first one ended
+	let Switches = Order.windows(2).filter(|Pair| Pair[0] != Pair[1]).count();
+
+	assert!(Switches > 2, "{:?}", Order);
+}
+
+#[tokio::test]
+async fn Aborted() {
+	let mut Connection = Connection::Open(Start(1024 * 1024, 4096));
+
+	Connection.Send(Submit("1", "Large")).await;
+
+	let First = Connection.Next().await;
+
+	assert_eq!((&First["Type"], &First["Index"]), (&json!("ResultChunk"), &json!(0)));
+
+	Connection
+		.Send(json!({
+			"Type": "ResultAbort",
+			"Id": "1",
+			"Code": "ECHO_DIGEST_MISMATCH",
+			"Message": "Given up",
+		}))
+		.await;
+
+	Connection.Send(Submit("2", "Small")).await;
+
+	// Chunks already on their way may arrive, but the result never ends
+	loop {
+		let Reply = Connection.Next().await;
+
+		assert_ne!(Reply["Type"], "ResultEnd");
+
+		if Reply["Type"] == "Result" {
+			assert_eq!((&Reply["Id"], &Reply["Value"]), (&json!("2"), &json!("small")));
+
+			break;
+		}
+	}
+
+	Connection.Send(json!({ "Type": "Ping" })).await;
+
+	let mut Late = 0;
+
+	loop {
+		match Connection.Next().await["Type"].as_str().unwrap() {
+			"Pong" => break,
+			"ResultChunk" => Late += 1,
+			Type => panic!("unexpected {} reply", Type),
+		}
+	}
+
+	assert!(Late <= 1, "{} chunks sent after the abort was read", Late);
+}
+
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+#[tokio::test]
+async fn Large50() {
+	const SIZE:usize = 50 * 1024 * 1024;
+
+	let (_Handle, Address) =
+		Echo::Struct::Transport::Tcp::Struct::New(Start(SIZE, 1024 * 1024), "127.0.0.1:0")
+			.Start()
+			.await
+			.unwrap();
+
+	let Client = Client::Connect(&Address.to_string(), Config::New()).await.unwrap();
+
+	let Result = Client
+		.Submit(Submission::New("Large").WithMetadata("Queue", json!("main")))
+		.await
+		.unwrap()
+		.Wait()
+		.await
+		.unwrap();
+
+	// Fetched whole, the result would exceed the frame limit of the client
+	assert_eq!(Result.as_str().map(str::len), Some(SIZE - 2));
+}
+
+/// Serves one client connection with `Reply` for its first submission,
+/// then returns the message the client sends next.
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+async fn Scripted(Reply:fn(&str) -> Vec<Value>) -> (String, JoinHandle<Value>) {
+	use Echo::{
+		Struct::Transport::Frame::Length,
+		Trait::Transport::{Reader::Trait as _, Writer::Trait as _},
+	};
+
+	let Listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+	let Address = Listener.local_addr().unwrap().to_string();
+
+	let Server = tokio::spawn(async move {
+		let (Stream, _) = Listener.accept().await.unwrap();
+
+		let (Input, Output) = Stream.into_split();
+
+		let (mut Reader, mut Writer) =
+			(Length::Reader::Struct::New(Input, 1 << 20), Length::Writer::Struct::New(Output));
+
+		let Submit:Value = serde_json::from_slice(&Reader.Read().await.unwrap().unwrap()).unwrap();
+
+		for Reply in Reply(Submit["Id"].as_str().unwrap()) {
+			Writer.Write(Reply.to_string().as_bytes()).await.unwrap();
+		}
+
+		serde_json::from_slice(&Reader.Read().await.unwrap().unwrap()).unwrap()
+	});
+
+	(Address, Server)
+}
+
+/// A chunk of a two-chunk result.
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+fn Chunk(Id:&str, Index:u64, Data:&[u8]) -> Value {
+	json!({
+		"Type": "ResultChunk",
+		"Id": Id,
+		"Index": Index,
+		"Total": 2,
+		"Data": STANDARD.encode(Data),
+	})
+}
+
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+#[tokio::test]
+async fn Rejected() {
+	// The second chunk comes first
+	let (Address, Server) =
+		Scripted(|Id| vec![Chunk(Id, 1, b"tent\""), Chunk(Id, 0, b"\"con")]).await;
+
+	let Client = Client::Connect(&Address, Config::New()).await.unwrap();
+
+	let Failed = Client.Submit(Submission::New("Read")).await.unwrap().Wait().await;
+
+	assert!(
+		matches!(Failed, Err(ClientError::Transfer(Code::ChunkOrder, _))),
+		"{:?}",
+		Failed
+	);
+
+	let Abort = Server.await.unwrap();
+
+	assert_eq!(
+		(&Abort["Type"], &Abort["Code"]),
+		(&json!("ResultAbort"), &json!("ECHO_CHUNK_ORDER"))
+	);
+
+	// The digest is that of other bytes
+	let (Address, Server) = Scripted(|Id| {
+		vec![
+			Chunk(Id, 0, b"\"con"),
+			Chunk(Id, 1, b"tent\""),
+			json!({ "Type": "ResultEnd", "Id": Id, "Digest": Hex(&Digest::Raw(b"\"other\"")) }),
+		]
+	})
+	.await;
+
+	let Client = Client::Connect(&Address, Config::New()).await.unwrap();
+
+	let Failed = Client.Submit(Submission::New("Read")).await.unwrap().Wait().await;
+
+	assert!(
+		matches!(Failed, Err(ClientError::Transfer(Code::DigestMismatch, _))),
+		"{:?}",
+		Failed
+	);
+
+	let Abort = Server.await.unwrap();
+
+	assert_eq!(
+		(&Abort["Type"], &Abort["Code"]),
+		(&json!("ResultAbort"), &json!("ECHO_DIGEST_MISMATCH"))
+	);
+}
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+use tokio::{net::TcpListener, task::JoinHandle};
+use tokio::{
+	io::{
+		duplex,
+		split,
+		AsyncBufReadExt,
+		AsyncWriteExt,
+		BufReader,
+		DuplexStream,
+		Lines,
+		ReadHalf,
+		WriteHalf,
+	},
+	time::timeout,
+};
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+use Echo::{
+	Enum::{Client::Error::Enum as ClientError, Transport::Code::Enum as Code},
+	Struct::Client::{
+		Config::Struct as Config,
+		Struct as Client,
+		Submission::Struct as Submission,
+	},
+};
+use Echo::{
+	Digest::{self, Hex},
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Storage::{Memory::Struct as Memory, Offload::Struct as Offload},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
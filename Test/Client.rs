@@ -0,0 +1,156 @@
+#![allow(non_snake_case)]
+
+//! Checks the client against an in-process server: many submissions in
+//! flight at once over one connection each resolve to their own result, and
+//! a submission cancelled while queued never runs and fails its wait.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A client of a `Tcp` server over a running `Life` with a `main` queue,
+/// whose `Work` function records its argument and returns it doubled after
+/// a nap. The server runs as long as the returned handle lives.
+async fn Start() -> (Client, Life, Arc<Mutex<Vec<i64>>>, Handle) {
+	let Ran = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", {
+				let Ran = Ran.clone();
+
+				move |Argument:Vec<Value>| {
+					let Number = Argument[0].as_i64().unwrap();
+
+					Ran.lock().unwrap().push(Number);
+
+					async move {
+						sleep(Duration::from_millis(10)).await;
+
+						Ok(json!(Number * 2))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New().WithConcurrency(8))
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let (Handle, Address) = Tcp::New(Pump::New(Life.clone(), Plan), "127.0.0.1:0")
+		.Start()
+		.await
+		.unwrap();
+
+	let Client = Client::Connect(&Address.to_string(), Config::New()).await.unwrap();
+
+	(Client, Life, Ran, Handle)
+}
+
+/// Returns a `Work` submission to the `main` queue.
+fn Work(Number:i64) -> Submission {
+	Submission::New("Work")
+		.WithArgument(json!(Number))
+		.WithMetadata("Queue", json!("main"))
+}
+
+#[tokio::test]
+async fn Concurrent() {
+	let (Client, _Life, Ran, _Handle) = Start().await;
+
+	let Submitted =
+		join_all((0..64).map(|Number| Client.Submit(Work(Number)))).await.into_iter();
+
+	let Submitted:Vec<_> = Submitted.map(Result::unwrap).collect();
+
+	let mut Id:Vec<_> = Submitted.iter().map(|Submitted| Submitted.Id().to_string()).collect();
+
+	Id.sort();
+
+	Id.dedup();
+
+	assert_eq!(Id.len(), 64);
+
+	let Result = timeout(
+		Duration::from_secs(10),
+		join_all(Submitted.into_iter().map(|Submitted| Submitted.Wait())),
+	)
+	.await
+	.unwrap();
+
+	// Each result is correlated with its own submission
+	for (Number, Result) in Result.into_iter().enumerate() {
+		assert_eq!(Result, Ok(json!(Number as i64 * 2)));
+	}
+
+	assert_eq!(Ran.lock().unwrap().len(), 64);
+}
+
+#[tokio::test]
+async fn Cancelled() {
+	let (Client, Life, Ran, _Handle) = Start().await;
+
+	Life.PauseQueue("main");
+
+	let Submitted = Client.Submit(Work(1)).await.unwrap();
+
+	let Id = Submitted.Id().to_string();
+
+	assert_eq!(Client.Cancel(&Id).await, Ok(true));
+
+	let Waited = timeout(Duration::from_secs(5), Submitted.Wait()).await.unwrap();
+
+	assert!(matches!(Waited, Err(ClientError::Failed(_))), "{:?}", Waited);
+
+	// Too late to withdraw it again
+	assert_eq!(Client.Cancel(&Id).await, Ok(false));
+
+	Life.ResumeQueue("main");
+
+	let Next = Client.Submit(Work(2)).await.unwrap().Wait().await;
+
+	assert_eq!(Next, Ok(json!(4)));
+
+	sleep(Duration::from_millis(100)).await;
+
+	// Cancelled while queued, so it never ran
+	assert_eq!(*Ran.lock().unwrap(), [2]);
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::{Client::Error::Enum as ClientError, Sequence::Action::Error::Enum as Error},
+	Struct::{
+		Client::{Config::Struct as Config, Struct as Client, Submission::Struct as Submission},
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Source::Handle::Struct as Handle,
+		Transport::{Pump::Struct as Pump, Tcp::Struct as Tcp},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
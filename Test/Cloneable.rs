@@ -0,0 +1,77 @@
+#![allow(non_snake_case)]
+
+//! Checks that an action whose content cannot be cloned is enqueued,
+//! dequeued and executed like any other, while `Duplicate` refuses to copy
+//! it, and that one marked `Cloneable` is copied.
+
+/// Content that cannot be cloned, passing no arguments.
+#[derive(Serialize)]
+struct Handle;
+
+#[tokio::test]
+async fn Unclonable() {
+	let Calls = Arc::new(AtomicUsize::new(0));
+
+	let Counted = Calls.clone();
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Count"))
+			.WithFunction("Count", move |_| {
+				Counted.fetch_add(1, Ordering::SeqCst);
+
+				async move { Ok(json!("counted")) }
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Count", Handle, Plan);
+
+	assert!(matches!(Action.Duplicate(), Err(Error::NonCloneable(_))));
+
+	let Production = Production::New();
+
+	Production.Enqueue(Box::new(Action)).await.unwrap();
+
+	let Dequeued = Production.Dequeue().await.unwrap();
+
+	Dequeued.Execute(&Life::Builder().Build()).await.unwrap();
+
+	assert_eq!(Calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn Marked() {
+	let Action = Echo::Struct::Sequence::Action::Struct::New(
+		"Count",
+		json!(["content"]),
+		Arc::new(Plan::New().Build()),
+	);
+
+	assert!(matches!(Action.Duplicate(), Err(Error::NonCloneable(_))));
+
+	let Copy = Action.Cloneable().Duplicate().unwrap();
+
+	assert_eq!(Copy.Content, json!(["content"]));
+
+	assert_eq!(Copy.Metadata("Action").await, Some(json!("Count")));
+}
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+use serde::Serialize;
+use serde_json::json;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Struct as Plan,
+		Production::Struct as Production,
+	},
+	Trait::Sequence::Action::Trait as _,
+};
@@ -0,0 +1,177 @@
+#![allow(non_snake_case)]
+
+//! Checks the error code catalogue: every action error maps to a code it
+//! documents, codes are stable strings on the wire, `Describe` carries the
+//! whole catalogue and `Error` replies carry their code.
+
+/// One error of every variant.
+fn Errors() -> Vec<Error> {
+	vec![
+		Error::License("expired".to_string()),
+		Error::InvalidLicense("expired".to_string()),
+		Error::Execution("failed".to_string()),
+		Error::Routing("Unknown queue: main".to_string()),
+		Error::Cancellation("stopped".to_string()),
+		Error::NonCloneable("Read".to_string()),
+		Error::DeadlineExceeded("1s".to_string()),
+		Error::Timeout("1s".to_string()),
+		Error::ResultEvicted("7".to_string()),
+		Error::Template("{cache:x}".to_string()),
+		Error::Abandoned("7".to_string()),
+		Error::UnknownAction { Action:"Thumbnail".to_string(), Known:vec!["Read".to_string()] },
+		Error::ChainLimit { Limit:"depth".to_string(), Maximum:8 },
+		Error::UnknownHook("gone".to_string()),
+		Error::HookLimit { Maximum:16 },
+		Error::Admission { Reason:"Roles denies Delete".to_string(), Code:Code::Forbidden },
+	]
+}
+
+#[test]
+fn Documented() {
+	let Errors = Errors();
+
+	// The samples cover every variant
+	let Class:HashSet<_> = Errors.iter().map(Error::Class).collect();
+
+	assert_eq!(Class.len(), Errors.len());
+
+	let Catalogue = Code::Catalogue();
+
+	for Error in &Errors {
+		let Code = Code::from(Error);
+
+		let Entry = Catalogue
+			.iter()
+			.find(|Entry| Entry.Code == Code)
+			.unwrap_or_else(|| panic!("{} is not in the catalogue", Code.Name()));
+
+		assert!(!Entry.Description.is_empty());
+	}
+
+	assert!(Code::from(&Error::DeadlineExceeded(String::new())).Retryable());
+
+	assert!(
+		!Code::from(&Error::UnknownAction { Action:String::new(), Known:Vec::new() }).Retryable()
+	);
+
+	// Codes are unique and spelled on the wire as named
+	let Name:HashSet<_> = Code::ALL.iter().map(Code::Name).collect();
+
+	assert_eq!(Name.len(), Code::ALL.len());
+
+	for Code in Code::ALL {
+		assert_eq!(serde_json::to_value(Code).unwrap(), json!(Code.Name()));
+
+		assert!(Code.Name().starts_with("ECHO_"));
+	}
+}
+
+/// A pump serving a `Life` with a `Read` action, and the client end of a
+/// connection to it.
+fn Connect() -> (WriteHalf<DuplexStream>, Lines<BufReader<ReadHalf<DuplexStream>>>) {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Read"))
+			.WithFunction("Read", |_| async { Ok(json!("read")) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Pump = Pump::New(
+		Life::Builder()
+			.WithQueue("main", Arc::new(Production::New()), Settings::New())
+			.Build(),
+		Plan,
+	);
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, Writer) = split(Client);
+
+	(Writer, BufReader::new(Reader).lines())
+}
+
+/// Sends a message and reads the next reply.
+async fn Exchange(
+	(Writer, Reader):&mut (WriteHalf<DuplexStream>, Lines<BufReader<ReadHalf<DuplexStream>>>),
+	Message:&str,
+) -> Value {
+	Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+
+	let Line = timeout(Duration::from_secs(10), Reader.next_line())
+		.await
+		.expect("no reply in time")
+		.unwrap()
+		.expect("stream closed");
+
+	serde_json::from_str(&Line).unwrap()
+}
+
+#[tokio::test]
+async fn Described() {
+	let mut Connection = Connect();
+
+	let Description = Exchange(&mut Connection, r#"{"Type":"Describe"}"#).await;
+
+	let Catalogue:Vec<Catalogue> =
+		serde_json::from_value(Description["Catalogue"].clone()).unwrap();
+
+	assert_eq!(Catalogue, Code::Catalogue());
+}
+
+#[tokio::test]
+async fn Carried() {
+	let mut Connection = Connect();
+
+	let Malformed = Exchange(&mut Connection, "{").await;
+
+	assert_eq!(Malformed["Code"], "ECHO_MALFORMED");
+
+	let Unknown = Exchange(
+		&mut Connection,
+		r#"{"Type":"Submit","Id":"1","Action":"Thumbnail","Metadata":{"Queue":"main"}}"#,
+	)
+	.await;
+
+	assert_eq!((&Unknown["Id"], &Unknown["Code"]), (&json!("1"), &json!("ECHO_UNKNOWN_ACTION")));
+
+	let Unrouted = Exchange(&mut Connection, r#"{"Type":"Submit","Id":"2","Action":"Read"}"#).await;
+
+	assert_eq!(Unrouted["Code"], "ECHO_ROUTING");
+}
+
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex,
+		split,
+		AsyncBufReadExt,
+		AsyncWriteExt,
+		BufReader,
+		DuplexStream,
+		Lines,
+		ReadHalf,
+		WriteHalf,
+	},
+	time::timeout,
+};
+use Echo::{
+	Enum::{Sequence::Action::Error::Enum as Error, Transport::Code::Enum as Code},
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+		},
+		Transport::{Catalogue::Struct as Catalogue, Frame::Line, Pump::Struct as Pump},
+	},
+};
@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+
+//! Checks that `RunKarma` honors the concurrency limit of each queue: with
+//! an `interactive` queue consuming eight actions at once and a `bulk` queue
+//! reloaded from `[queues.bulk]` to consume two, the most actions observed
+//! executing at the same time in each queue is its own limit.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// How many actions of one queue execute now, and the most that ever did.
+#[derive(Default)]
+struct Mark {
+	/// The actions executing now.
+	Running:usize,
+
+	/// The high-water mark of `Running`.
+	Peak:usize,
+}
+
+/// A plan whose `Work` function naps while counted in the `Mark` of the
+/// queue named by its argument.
+fn Plan(Marks:Arc<Mutex<HashMap<String, Mark>>>) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", move |Argument:Vec<Value>| {
+				let Marks = Marks.clone();
+
+				async move {
+					let Queue = Argument[0].as_str().unwrap().to_string();
+
+					{
+						let mut Marks = Marks.lock().unwrap();
+
+						let Mark = Marks.entry(Queue.clone()).or_default();
+
+						Mark.Running += 1;
+
+						Mark.Peak = Mark.Peak.max(Mark.Running);
+					}
+
+					sleep(Duration::from_millis(50)).await;
+
+					Marks.lock().unwrap().get_mut(&Queue).unwrap().Running -= 1;
+
+					Ok(Value::Null)
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates a `Work` action in the given queue.
+fn Work(Plan:&Arc<Plan>, Queue:&str) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New("Work", Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!(Queue))
+			.WithMetadata("Argument", json!([Queue])),
+	)
+}
+
+#[tokio::test]
+async fn HighWater() {
+	let Marks = Arc::new(Mutex::new(HashMap::new()));
+
+	let Plan = Plan(Marks.clone());
+
+	let Life = Life::Builder()
+		.WithQueue("interactive", Arc::new(Production::New()), Settings::New().WithConcurrency(8))
+		.WithQueue("bulk", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Fate = Config::builder()
+		.set_override("queues.bulk.concurrency", 2)
+		.unwrap()
+		.set_override("queues.bulk.batch", 50)
+		.unwrap()
+		.build()
+		.unwrap();
+
+	Life.Reload(&Fate);
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let mut Pending = Vec::new();
+
+	for _ in 0..24 {
+		Pending.push(Life.Submit(Work(&Plan, "interactive")).await);
+
+		Pending.push(Life.Submit(Work(&Plan, "bulk")).await);
+	}
+
+	for Outcome in timeout(Duration::from_secs(10), join_all(Pending)).await.unwrap() {
+		assert_eq!(Outcome, Ok(Value::Null));
+	}
+
+	{
+		let Marks = Marks.lock().unwrap();
+
+		assert_eq!(Marks["interactive"].Peak, 8);
+
+		assert_eq!(Marks["bulk"].Peak, 2);
+
+		assert!(Marks.values().all(|Mark| Mark.Running == 0));
+	}
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use futures::future::join_all;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
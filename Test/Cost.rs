@@ -0,0 +1,198 @@
+#![allow(non_snake_case)]
+
+//! Checks the cost accounting by action type: its totals, the cutoff that
+//! bounds it and its reports.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Measures an action of a type taking some milliseconds.
+fn Sample(Type:&str, Milliseconds:u64, Retries:u64) -> Sample {
+	Sample {
+		Type:Type.to_string(),
+		Execution:Duration::from_millis(Milliseconds),
+		Wait:Duration::from_millis(1),
+		Retries,
+		Argument:10,
+		Result:100,
+		Failed:Retries > 1,
+	}
+}
+
+#[test]
+fn Mix() {
+	let Cost = Arc::new(Cost::default());
+
+	Cost.Configure(2, None);
+
+	for (Type, Milliseconds, Retries) in [
+		("Heavy", 10, 0),
+		("Medium", 5, 1),
+		("Light", 1, 0),
+		("Heavy", 10, 2),
+		("Medium", 5, 0),
+		("Heavy", 10, 0),
+	] {
+		Cost.Record(&Sample(Type, Milliseconds, Retries));
+	}
+
+	let Report = Cost.Report();
+
+	// Light pushed Medium out, then the returning Medium pushed Light out
+	assert_eq!(Report.Type.keys().collect::<Vec<_>>(), ["Heavy", "Medium"]);
+
+	assert_eq!(Report.Folded, 2);
+
+	assert_eq!(
+		Report.Type["Heavy"],
+		Total {
+			Count:3,
+			Failed:1,
+			Execution:30_000,
+			Average:10_000,
+			Maximum:10_000,
+			Wait:3_000,
+			Retries:2,
+			Argument:30,
+			Result:300,
+		}
+	);
+
+	assert_eq!((Report.Type["Medium"].Count, Report.Type["Medium"].Execution), (1, 5_000));
+
+	assert_eq!((Report.Other.Count, Report.Other.Execution, Report.Other.Retries), (2, 6_000, 1));
+
+	assert_eq!(Report.Other.Maximum, 5_000);
+
+	let Sum = Report.Sum();
+
+	assert_eq!((Sum.Count, Sum.Execution, Sum.Average, Sum.Retries), (6, 41_000, 6_833, 3));
+
+	// Lowering the limit folds the cheapest types right away
+	Cost.Configure(1, None);
+
+	let Report = Cost.Report();
+
+	assert_eq!(Report.Type.keys().collect::<Vec<_>>(), ["Heavy"]);
+
+	assert_eq!((Report.Other.Count, Report.Folded), (3, 3));
+
+	assert_eq!(Report.Sum(), Sum);
+}
+
+#[tokio::test]
+async fn Sequence() {
+	let Root = std::env::temp_dir().join(format!("Echo-Cost-{}", std::process::id()));
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	let Dump = Root.join("cost.log");
+
+	let Fate = Config::builder()
+		.set_override("End", 1)
+		.unwrap()
+		.set_override("cost.dump", Dump.to_string_lossy().to_string())
+		.unwrap()
+		.set_override("cost.interval", "50ms")
+		.unwrap()
+		.build()
+		.unwrap();
+
+	let Plan = Arc::new(
+		Echo::Builtin::Fs::Struct::New(&Root)
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())
+			.unwrap()
+			.Build(),
+	);
+
+	let Sequence = Sequence::New(
+		Arc::new(Direct),
+		Arc::new(Production::New()),
+		Life::Builder().WithFate(Arc::new(Fate)).Build(),
+	);
+
+	for Argument in [json!(["first.txt", "written"]), json!(["second.txt", "written"])] {
+		Sequence
+			.Queue()
+			.Enqueue(Box::new(
+				Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Plan.clone())
+					.WithMetadata("Argument", Argument),
+			))
+			.await
+			.unwrap();
+	}
+
+	Sequence
+		.Queue()
+		.Enqueue(Box::new(
+			Echo::Struct::Sequence::Action::Struct::New("Read", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!(["missing.txt"])),
+		))
+		.await
+		.unwrap();
+
+	while Sequence.ConsumeOne().await.is_some() {}
+
+	let Report = Sequence.Context().CostReport();
+
+	assert_eq!((Report.Type["Write"].Count, Report.Type["Write"].Failed), (2, 0));
+
+	assert_eq!(
+		Report.Type["Write"].Argument,
+		(json!(["first.txt", "written"]).to_string().len()
+			+ json!(["second.txt", "written"]).to_string().len()) as u64
+	);
+
+	assert_eq!((Report.Type["Read"].Count, Report.Type["Read"].Failed), (1, 1));
+
+	assert_eq!(Sequence.Context().Stats().Cost, Report);
+
+	// The report is dumped to the storage log every interval
+	let Storage = Storage::New(Arc::new(Json));
+
+	let Dumped = timeout(Duration::from_secs(10), async {
+		loop {
+			if let Ok(Recovered) = Storage.Read(&Dump).await {
+				if let Some(Record) = Recovered.Record.last() {
+					return serde_json::from_value::<Report>(Record.clone()).unwrap();
+				}
+			}
+
+			sleep(Duration::from_millis(20)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	assert_eq!(Dumped, Report);
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{Life::Struct as Life, Production::Struct as Production, Struct as Sequence},
+		Stats::Cost::{
+			Report::Struct as Report,
+			Sample::Struct as Sample,
+			Struct as Cost,
+			Total::Struct as Total,
+		},
+		Storage::{Codec::Json::Struct as Json, Struct as Storage},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
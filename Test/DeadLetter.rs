@@ -0,0 +1,262 @@
+#![allow(non_snake_case)]
+
+//! Checks the dead-letter tooling of a `Life`: filtered listing, requeueing
+//! for another try and purging, each audited on the event bus.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A `Life` with a `main` queue dead-lettering into `dead`, over a plan of
+/// the file system builtins rooted in a fresh directory.
+struct Harness {
+	Root:PathBuf,
+
+	Plan:Arc<Plan>,
+
+	Life:Life,
+}
+
+impl Harness {
+	async fn Start(Name:&str) -> Self {
+		let Root =
+			std::env::temp_dir().join(format!("Echo-DeadLetter-{}-{}", Name, std::process::id()));
+
+		tokio::fs::create_dir_all(&Root).await.unwrap();
+
+		let Plan = Arc::new(
+			Echo::Builtin::Fs::Struct::New(&Root)
+				.Register(Echo::Struct::Sequence::Plan::Struct::New())
+				.unwrap()
+				.Build(),
+		);
+
+		let Life = Life::Builder()
+			.WithQueue("main", Arc::new(Production::New()), Settings::New().WithDeadLetter("dead"))
+			.WithQueue("dead", Arc::new(Production::New()), Settings::New())
+			.Build();
+
+		Harness { Root, Plan, Life }
+	}
+
+	/// Dead-letters an action the way `Sequence` does after three failed
+	/// attempts.
+	async fn Dead(&self, Action:&str, Argument:Value, Class:&str) -> u64 {
+		self.Queue("dead")
+			.Enqueue(Box::new(
+				Echo::Struct::Sequence::Action::Struct::New(Action, Value::Null, self.Plan.clone())
+					.WithMetadata("Argument", Argument)
+					.WithMetadata("Reason", json!("failed"))
+					.WithMetadata("Error", json!("Failed"))
+					.WithMetadata("ErrorClass", json!(Class))
+					.WithMetadata("Attempts", json!(3)),
+			))
+			.await
+			.unwrap()
+			.Sequence
+	}
+
+	fn Queue(&self, Name:&str) -> Arc<Production> { self.Life.Karma.get(Name).unwrap().clone() }
+
+	async fn Stop(self) { let _ = tokio::fs::remove_dir_all(&self.Root).await; }
+}
+
+/// Returns the sequence numbers of entries.
+fn Sequence(Entry:&[Entry]) -> Vec<u64> { Entry.iter().map(|Entry| Entry.Sequence).collect() }
+
+#[tokio::test]
+async fn List() {
+	let Harness = Harness::Start("List").await;
+
+	let mut Subscription = Harness.Life.Subscribe();
+
+	let Read = Harness.Dead("Read", json!(["missing.txt"]), "Execution").await;
+
+	let Write = Harness
+		.Dead("Write", json!(["out.txt", "late"]), "DeadlineExceeded")
+		.await;
+
+	let Again = Harness.Dead("Read", json!(["other.txt"]), "DeadlineExceeded").await;
+
+	let DeadLetter = &Harness.Life.DeadLetter;
+
+	let Every = DeadLetter.List("dead", &Filter::New()).await.unwrap();
+
+	assert_eq!(Sequence(&Every), [Read, Write, Again]);
+
+	assert_eq!(Every[0].Action.as_deref(), Some("Read"));
+
+	assert_eq!(
+		(Every[0].Reason.as_deref(), Every[0].Class.as_deref(), Every[0].Attempts),
+		(Some("failed"), Some("Execution"), Some(3))
+	);
+
+	let Listed = DeadLetter
+		.List("dead", &Filter::New().WithAction("Read"))
+		.await
+		.unwrap();
+
+	assert_eq!(Sequence(&Listed), [Read, Again]);
+
+	let Listed = DeadLetter
+		.List("dead", &Filter::New().WithAction("Read").WithClass("DeadlineExceeded"))
+		.await
+		.unwrap();
+
+	assert_eq!(Sequence(&Listed), [Again]);
+
+	let Listed = DeadLetter
+		.List("dead", &Filter::New().WithNewerThan(Duration::from_secs(3600)))
+		.await
+		.unwrap();
+
+	assert_eq!(Listed.len(), 3);
+
+	let Listed = DeadLetter
+		.List("dead", &Filter::New().WithOlderThan(Duration::from_secs(3600)))
+		.await
+		.unwrap();
+
+	assert!(Listed.is_empty());
+
+	// Listing leaves the queue as it was
+	assert_eq!(Harness.Queue("dead").Stats().Depth, 3);
+
+	assert!(matches!(DeadLetter.List("none", &Filter::New()).await, Err(Error::Routing(_))));
+
+	// Every listing is audited
+	let Audit = timeout(Duration::from_secs(5), async {
+		loop {
+			if let Some(Event::Audit { Operation, Queue, Sequence, Target }) =
+				Subscription.Recv().await
+			{
+				return (Operation, Queue, Sequence, Target);
+			}
+		}
+	})
+	.await
+	.unwrap();
+
+	assert_eq!(
+		Audit,
+		("DeadLetter.List".to_string(), "dead".to_string(), vec![Read, Write, Again], None)
+	);
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Requeue() {
+	let Harness = Harness::Start("Requeue").await;
+
+	let Write = Harness
+		.Dead("Write", json!(["out.txt", "retried"]), "Execution")
+		.await;
+
+	let DeadLetter = &Harness.Life.DeadLetter;
+
+	let Outcome = DeadLetter.Requeue("dead", &[Write, 99], "main").await.unwrap();
+
+	let Requeued = match &Outcome[..] {
+		[Requeue::Requeued { Sequence, Target, Requeued }, Requeue::Missing { Sequence: 99 }] => {
+			assert_eq!((*Sequence, Target.as_str()), (Write, "main"));
+
+			*Requeued
+		},
+		Outcome => panic!("unexpected outcome {:?}", Outcome),
+	};
+
+	assert_eq!(Harness.Queue("dead").Stats().Depth, 0);
+
+	// Requeueing again is a no-op reporting the first requeue
+	assert_eq!(
+		DeadLetter.Requeue("dead", &[Write], "main").await.unwrap(),
+		[Requeue::AlreadyRequeued { Sequence:Write, Target:"main".to_string(), Requeued }]
+	);
+
+	assert_eq!(Harness.Queue("main").Stats().Depth, 1);
+
+	// The failure metadata is gone, the rest of the action is not
+	let Inspected = Harness
+		.Queue("main")
+		.Inspect(&["Action", "Reason", "Error", "ErrorClass", "Attempts"])
+		.await;
+
+	assert_eq!(Inspected.len(), 1);
+
+	assert_eq!(Inspected[0].0.Sequence, Requeued);
+
+	assert_eq!(Value::Object(Inspected[0].1.clone()), json!({ "Action": "Write" }));
+
+	let Sequence = Sequence::New(Arc::new(Direct), Harness.Queue("main"), Harness.Life.clone());
+
+	assert!(matches!(Sequence.ConsumeOne().await, Some(Ok(()))));
+
+	assert_eq!(tokio::fs::read_to_string(Harness.Root.join("out.txt")).await.unwrap(), "retried");
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Purge() {
+	let Harness = Harness::Start("Purge").await;
+
+	let Read = Harness.Dead("Read", json!(["missing.txt"]), "Execution").await;
+
+	let Write = Harness
+		.Dead("Write", json!(["out.txt", "late"]), "DeadlineExceeded")
+		.await;
+
+	let DeadLetter = &Harness.Life.DeadLetter;
+
+	let Selected = Filter::New().WithClass("Execution");
+
+	// A dry run reports what would go without removing it
+	let Preview = DeadLetter.Purge("dead", &Selected, true).await.unwrap();
+
+	assert_eq!(Sequence(&Preview), [Read]);
+
+	assert_eq!(Harness.Queue("dead").Stats().Depth, 2);
+
+	let Purged = DeadLetter.Purge("dead", &Selected, false).await.unwrap();
+
+	assert_eq!(Purged, Preview);
+
+	let Left = DeadLetter.List("dead", &Filter::New()).await.unwrap();
+
+	assert_eq!(Sequence(&Left), [Write]);
+
+	// Purged entries can no longer be requeued
+	assert_eq!(
+		DeadLetter.Requeue("dead", &[Read], "main").await.unwrap(),
+		[Requeue::Missing { Sequence:Read }]
+	);
+
+	Harness.Stop().await;
+}
+
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{Action::Error::Enum as Error, DeadLetter::Requeue::Enum as Requeue},
+	},
+	Struct::Sequence::{
+		DeadLetter::{Entry::Struct as Entry, Filter::Struct as Filter},
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
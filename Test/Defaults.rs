@@ -0,0 +1,249 @@
+#![allow(non_snake_case)]
+
+//! Checks the default metadata a plan holds per action type: actions carry
+//! it unless they set a key themselves, whether built locally or submitted
+//! over the wire, a change reaches only actions executed afterwards,
+//! reserved keys cannot default, and manifests show and compare defaults.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Returns a metadata object.
+fn Map(Value:Value) -> Map<String, Value> { Value.as_object().unwrap().clone() }
+
+/// A plan whose `Write` action defaults to the `audit.write` hook and a
+/// five-second timeout, and returns the milliseconds left of its deadline.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Write"))
+			.WithFunction("Write", |_| async {
+				Ok(json!(Invocation::Current()
+					.and_then(|Invocation| Invocation.RemainingTime())
+					.map(|Left| Left.as_millis() as u64)))
+			})
+			.unwrap()
+			.WithDefaults("Write", Map(json!({ "Hooks": ["audit.write"], "Timeout": 5000 })))
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// A running `Life` with a `main` queue and an `audit.write` hook counting
+/// its calls.
+fn Start() -> (Life, Arc<AtomicUsize>) {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Audited = Arc::new(AtomicUsize::new(0));
+
+	Life.Span.insert(
+		"audit.write".to_string(),
+		Arc::new({
+			let Audited = Audited.clone();
+
+			move || {
+				Audited.fetch_add(1, Ordering::SeqCst);
+
+				Ok(())
+			}
+		}),
+	);
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	(Life, Audited)
+}
+
+/// Runs an action to completion, returning its value.
+async fn Run(Life:&Life, Action:Box<dyn Action>) -> Value {
+	timeout(Duration::from_secs(10), Life.Submit(Action).await)
+		.await
+		.unwrap()
+		.unwrap()
+}
+
+/// A `Write` action to the `main` queue.
+fn Write(Plan:&Arc<Plan>) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Plan.clone())
+		.WithMetadata("Queue", json!("main"))
+}
+
+#[tokio::test]
+async fn Precedence() {
+	let (Life, Audited) = Start();
+
+	let Plan = Plan();
+
+	let Defaulted = Write(&Plan);
+
+	assert_eq!(Defaulted.Lookup("Timeout").await, Some(json!(5000)));
+
+	assert_eq!(Defaulted.Lookup("Queue").await, Some(json!("main")));
+
+	let Left = Run(&Life, Box::new(Defaulted)).await;
+
+	assert!((1000..=5000).contains(&Left.as_u64().unwrap()), "{} left", Left);
+
+	assert_eq!(Audited.load(Ordering::SeqCst), 1);
+
+	// Values the action sets win, even empty ones
+	let Explicit = Write(&Plan)
+		.WithMetadata("Timeout", json!(500))
+		.WithMetadata("Hooks", json!([]));
+
+	let Left = Run(&Life, Box::new(Explicit)).await;
+
+	assert!(Left.as_u64().unwrap() <= 500, "{} left", Left);
+
+	assert_eq!(Audited.load(Ordering::SeqCst), 1);
+
+	// Changed defaults reach the actions executed afterwards
+	let Queued = Write(&Plan);
+
+	Plan.WithDefaults("Write", Map(json!({ "Timeout": 200 }))).unwrap();
+
+	let Left = Run(&Life, Box::new(Queued)).await;
+
+	assert!(Left.as_u64().unwrap() <= 200, "{} left", Left);
+
+	assert_eq!(Audited.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn Wire() {
+	let (Life, _) = Start();
+
+	let Pump = Pump::New(Life, Plan());
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	for (Id, Metadata) in [
+		("defaulted", json!({ "Queue": "main" })),
+		("explicit", json!({ "Queue": "main", "Timeout": 100 })),
+	] {
+		let Message =
+			json!({ "Type": "Submit", "Id": Id, "Action": "Write", "Metadata": Metadata });
+
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+	}
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let mut Left = HashMap::new();
+
+	while Left.len() < 2 {
+		let Line = timeout(Duration::from_secs(10), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+		if Reply["Type"] == "Result" {
+			Left.insert(
+				Reply["Id"].as_str().unwrap().to_string(),
+				Reply["Value"].as_u64().unwrap(),
+			);
+		}
+	}
+
+	assert!((1000..=5000).contains(&Left["defaulted"]), "{:?}", Left);
+
+	assert!(Left["explicit"] <= 100, "{:?}", Left);
+}
+
+#[test]
+fn Reserved() {
+	let Plan = Plan();
+
+	for Key in ["Id", "ActionType", "Action", "Queue", "SubmittedBy"] {
+		let Refused = Plan.WithDefaults("Write", Map(json!({ "Timeout": 1, Key: "forged" })));
+
+		assert!(Refused.unwrap_err().contains(Key));
+	}
+
+	assert!(Plan.WithDefaults("Missing", Map(json!({ "Timeout": 1 }))).is_err());
+
+	// Refusals leave the defaults as they were
+	assert_eq!(Plan.Default("Write", "Timeout"), Some(json!(5000)));
+
+	assert_eq!(Plan.Default("Write", "Queue"), None);
+}
+
+#[test]
+fn Manifest() {
+	let Plan = Plan();
+
+	let Manifest = Plan.ExportManifest();
+
+	assert_eq!(Manifest.Action[0].Defaults["Timeout"], json!(5000));
+
+	assert!(Plan.ValidateAgainstManifest(&Manifest).Compatible());
+
+	Plan.WithDefaults("Write", Map(json!({ "Timeout": 200 }))).unwrap();
+
+	let Diff = Plan.ValidateAgainstManifest(&Manifest);
+
+	assert_eq!(Diff.Changed.len(), 1);
+
+	assert_eq!(Diff.Changed[0].Field, "Defaults");
+
+	// A manifest without defaults matches any
+	let mut Bare = Manifest.clone();
+
+	Bare.Action[0].Defaults.clear();
+
+	assert!(Plan.ValidateAgainstManifest(&Bare).Compatible());
+}
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::timeout,
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Invocation::Struct as Invocation,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
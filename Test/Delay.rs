@@ -0,0 +1,205 @@
+#![allow(non_snake_case)]
+
+//! Checks `Delay` on the paused clock: a number of milliseconds, fractional
+//! or not, and a string with units stagger actions below a second, while a
+//! malformed delay fails the action with `Error::Execution` instead of
+//! letting it run at once, that a delayed action waits in the timer rather
+//! than in the only worker, so actions enqueued after it run first, and that
+//! an action executed directly, outside a queue, runs at once whatever its
+//! delay.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// When the `Work` function ran, in microseconds since `Start`, by argument.
+type Record = Arc<Mutex<Vec<(String, u128)>>>;
+
+/// Starts a sequence consuming the `main` queue of a new `Life`, and a plan
+/// whose `Work` function records when it runs.
+fn Start() -> (Life, Sequence, Arc<Plan>, Record) {
+	let (Record, Start) = (Record::default(), Instant::now());
+
+	// A single attempt, so a failure is final
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Work"))
+		.WithFunction("Work", {
+			let Record = Record.clone();
+
+			move |Argument:Vec<Value>| {
+				let Name = Argument.first().and_then(Value::as_str).unwrap_or_default().to_string();
+
+				Record.lock().unwrap().push((Name, Start.elapsed().as_micros()));
+
+				async move { Ok(Value::Null) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	(Life, Sequence, Arc::new(Plan), Record)
+}
+
+/// Creates a `Work` action in the `main` queue with the given delay.
+fn Delayed(Plan:&Arc<Plan>, Name:&str, Delay:Value) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New("Work", Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!("main"))
+			.WithMetadata("Argument", json!([Name]))
+			.WithMetadata("Delay", Delay),
+	)
+}
+
+#[tokio::test(start_paused = true)]
+async fn Staggered() {
+	let (Life, Sequence, Plan, Record) = Start();
+
+	let mut Pending = Vec::new();
+
+	for (Name, Delay) in [
+		("string", json!("1s 500ms")),
+		("fractional", json!(150.5)),
+		("numeric", json!(50)),
+		("text", json!("200ms")),
+	] {
+		Pending.push(Life.Submit(Delayed(&Plan, Name, Delay)).await);
+	}
+
+	for Pending in Pending {
+		timeout(Duration::from_secs(5), Pending).await.unwrap().unwrap();
+	}
+
+	let Record = Record.lock().unwrap().clone();
+
+	assert_eq!(
+		Record.iter().map(|(Name, _)| Name.as_str()).collect::<Vec<_>>(),
+		["numeric", "fractional", "text", "string"]
+	);
+
+	// Each ran once its delay elapsed, within a poll of the sequence
+	for ((Name, Ran), Delay) in Record.iter().zip([50_000, 150_500, 200_000, 1_500_000]) {
+		assert!((Delay..Delay + 20_000).contains(Ran), "{} ran at {}us", Name, Ran);
+	}
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn Malformed() {
+	let (Life, Sequence, Plan, Record) = Start();
+
+	for Delay in [json!("soon"), json!(-50), json!("1s 500"), json!(true)] {
+		let Pending = Life.Submit(Delayed(&Plan, "malformed", Delay.clone())).await;
+
+		let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+		assert!(
+			matches!(Outcome, Err(Error::Execution(ref Message)) if Message.contains("Delay")),
+			"{}: {:?}",
+			Delay,
+			Outcome
+		);
+	}
+
+	assert!(Record.lock().unwrap().is_empty());
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn Overtaken() {
+	let (Life, Sequence, Plan, Record) = Start();
+
+	let Late = Life.Submit(Delayed(&Plan, "late", json!("500ms"))).await;
+
+	let mut Pending = Vec::new();
+
+	for Name in ["first", "second", "third", "fourth", "fifth"] {
+		Pending.push(Life.Submit(Delayed(&Plan, Name, json!("0ms"))).await);
+	}
+
+	for Pending in Pending {
+		timeout(Duration::from_secs(5), Pending).await.unwrap().unwrap();
+	}
+
+	// The only worker is free again while the delayed action waits
+	assert_eq!(Life.Timer.Pending(), 1);
+
+	timeout(Duration::from_secs(5), Late).await.unwrap().unwrap();
+
+	assert_eq!(Life.Timer.Pending(), 0);
+
+	let Record = Record.lock().unwrap().clone();
+
+	assert_eq!(
+		Record.iter().map(|(Name, _)| Name.as_str()).collect::<Vec<_>>(),
+		["first", "second", "third", "fourth", "fifth", "late"]
+	);
+
+	assert!(Record[..5].iter().all(|(_, Ran)| *Ran < 20_000), "{:?}", Record);
+
+	assert!((500_000..520_000).contains(&Record[5].1), "{:?}", Record);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn Unqueued() {
+	let (Life, Sequence, Plan, Record) = Start();
+
+	let Start = Instant::now();
+
+	Delayed(&Plan, "direct", json!("1m")).Execute(&Life).await.unwrap();
+
+	assert_eq!(Start.elapsed(), Duration::ZERO);
+
+	assert_eq!(Record.lock().unwrap().len(), 1);
+
+	let Malformed = Delayed(&Plan, "malformed", json!("soon")).Execute(&Life).await;
+
+	assert!(matches!(Malformed, Err(Error::Execution(Message)) if Message.contains("Delay")));
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::{timeout, Instant};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,81 @@
+#![allow(non_snake_case)]
+
+//! Checks the canonical digests against the vectors in
+//! `Test/Digest/Vector.json`, whose expected hashes were computed by an
+//! independent SHA-256, and the digest of actions built on them.
+
+/// One vector: JSON text, its canonical form and the SHA-256 of that form.
+#[derive(Deserialize)]
+struct Vector {
+	/// What the vector exercises.
+	Name:String,
+
+	/// The JSON text parsed into the value digested.
+	Input:String,
+
+	/// The expected canonical form.
+	Canonical:String,
+
+	/// The expected digest in hex.
+	Sha256:String,
+}
+
+#[test]
+fn Vectors() {
+	let Vector:Vec<Vector> = serde_json::from_str(include_str!("Digest/Vector.json")).unwrap();
+
+	for Vector in Vector {
+		let Value:Value = serde_json::from_str(&Vector.Input).unwrap();
+
+		assert_eq!(Digest::Encode(&Value), Vector.Canonical, "{}", Vector.Name);
+
+		assert_eq!(Digest::Hex(&Digest::Canonical(&Value)), Vector.Sha256, "{}", Vector.Name);
+	}
+}
+
+#[test]
+fn Normalized() {
+	// Key order, float spelling and the sign of zero do not matter
+	assert_eq!(
+		Digest::Canonical(&json!({ "b": [1.0, -0.0], "a": "x" })),
+		Digest::Canonical(&json!({ "a": "x", "b": [1, 0] }))
+	);
+
+	// NaN cannot enter a value; it is null
+	assert_eq!(Digest::Encode(&json!([f64::NAN, f64::INFINITY])), "[null,null]");
+}
+
+#[test]
+fn Action() {
+	let Metadata = json!({ "Queue": "main" }).as_object().unwrap().clone();
+
+	let Resized = Digest::ActionDigest("Resize", &[json!({ "w": 1, "h": 2 })], &Metadata);
+
+	assert_eq!(
+		Digest::Hex(&Resized),
+		"55af12f9e30c050105fe818d84a02ecafe93c0dcef2aefd88a1c3d5450a9ba8d"
+	);
+
+	assert_ne!(Resized, Digest::ActionDigest("Resize", &[json!({ "w": 1, "h": 2 })], &Map::new()));
+
+	// The replay and submission digests agree with it
+	assert_eq!(
+		Replay::Digest("Resize", &[json!({ "h": 2, "w": 1 })]),
+		Digest::Hex(&Digest::ActionDigest("Resize", &[json!({ "w": 1, "h": 2 })], &Map::new()))
+	);
+
+	assert_eq!(
+		Submission::Digest(&[json!({ "h": 2, "w": 1 }).into()]),
+		Digest::Hex(&Digest::Canonical(&json!([{ "w": 1, "h": 2 }])))
+	);
+}
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use Echo::{
+	Digest,
+	Struct::{
+		Sequence::Replay::Record::Struct as Replay,
+		Transport::History::Record::Struct as Submission,
+	},
+};
@@ -0,0 +1,196 @@
+#![allow(non_snake_case)]
+
+//! Checks that a request is answered once: when a client retries a slow
+//! submission and both attempts succeed, the retry's result is delivered,
+//! the slow one is suppressed and counted, and the delivered history
+//! records it as a duplicate; a pump delivering duplicates sends both.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A pump over a running `Life` running two actions at a time, whose `Race`
+/// action is slow on its first call and fast on the next.
+fn Start() -> Pump {
+	let Calls = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Race"))
+			.WithFunction("Race", move |_| {
+				let Call = Calls.fetch_add(1, Ordering::SeqCst);
+
+				async move {
+					if Call == 0 {
+						sleep(Duration::from_millis(300)).await;
+
+						Ok(json!("A"))
+					} else {
+						Ok(json!("B"))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New().WithConcurrency(2))
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Pump::New(Life, Plan)
+}
+
+/// Serves a line-framed connection with `Pump`, submits `Race` under one
+/// identifier twice and returns every reply up to the `Pong` answering a
+/// `Ping` sent once both attempts are over.
+async fn Race(Pump:Pump) -> Vec<Value> {
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output)).await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let Submit =
+		json!({ "Type": "Submit", "Id": "1", "Action": "Race", "Metadata": { "Queue": "main" } });
+
+	// The retry goes out while the first attempt is still running
+	for _ in 0..2 {
+		Writer.write_all(format!("{}\n", Submit).as_bytes()).await.unwrap();
+
+		sleep(Duration::from_millis(50)).await;
+	}
+
+	sleep(Duration::from_millis(500)).await;
+
+	Writer.write_all(b"{\"Type\":\"Ping\"}\n").await.unwrap();
+
+	let mut Replies = Vec::new();
+
+	loop {
+		let Line = timeout(Duration::from_secs(10), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+		if Reply["Type"] == "Pong" {
+			return Replies;
+		}
+
+		if Reply["Type"] != "Ack" {
+			Replies.push(Reply);
+		}
+	}
+}
+
+#[tokio::test]
+async fn Suppressed() {
+	let Pump = Start();
+
+	let Replies = Race(Pump.clone()).await;
+
+	// The retry finished first and is the only result
+	assert_eq!(Replies.len(), 1, "{:?}", Replies);
+
+	assert_eq!((&Replies[0]["Type"], &Replies[0]["Value"]), (&json!("Result"), &json!("B")));
+
+	assert_eq!(Pump.Duplicates(), 1);
+}
+
+#[tokio::test]
+async fn Delivered() {
+	let Pump = Start().WithSuppressDuplicates(false);
+
+	let Value = Race(Pump.clone())
+		.await
+		.iter()
+		.map(|Reply| Reply["Value"].clone())
+		.collect::<Vec<_>>();
+
+	assert_eq!(Value, vec![json!("B"), json!("A")]);
+
+	assert_eq!(Pump.Duplicates(), 1);
+}
+
+#[test]
+fn Recorded() {
+	let Terminal = Terminal::New(true, Arc::new(AtomicU64::new(0)));
+
+	let Succeeded = |Number:u32| {
+		let mut History = History::default();
+
+		History.Push(Attempt::New(Number, Attempt::Now(), &Ok::<_, String>(())));
+
+		History
+	};
+
+	assert!(Terminal.Settle("1", &Succeeded(2)));
+
+	assert!(!Terminal.Settle("1", &Succeeded(1)));
+
+	let History = Terminal.History().unwrap();
+
+	let Recorded = History.Attempts.iter().map(|Attempt| (Attempt.Attempt, Attempt.Outcome));
+
+	assert_eq!(
+		Recorded.collect::<Vec<_>>(),
+		vec![(2, Outcome::Succeeded), (1, Outcome::Duplicate)]
+	);
+
+	// A cancelled request delivers nothing and counts nothing
+	let Cancelled = Terminal::default();
+
+	assert!(Cancelled.Close());
+
+	assert!(!Cancelled.Settle("2", &Succeeded(1)));
+
+	assert_eq!(Cancelled.History(), None);
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicU64, AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::{Action::Error::Enum as Error, Attempt::Outcome::Enum as Outcome},
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Attempt::{History::Struct as History, Struct as Attempt},
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump, Terminal::Struct as Terminal},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
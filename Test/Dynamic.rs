@@ -0,0 +1,168 @@
+#![allow(non_snake_case)]
+
+//! Checks the Karma queues a `Life` creates on demand: concurrent routes to
+//! a missing queue create it once and are all consumed, and the cap evicts
+//! the least recently routed empty queue, or refuses creation when every
+//! created queue holds work.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan with a `Noop` function.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Noop"))
+			.WithFunction("Noop", |_| async { Ok(json!("done")) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates a `Noop` action routed to a queue.
+fn Routed(Plan:&Arc<Plan>, Queue:&str) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New("Noop", Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!(Queue)),
+	)
+}
+
+/// A `Life` with a static `main` queue, creating at most `Limit` queues and
+/// counting the calls to its factory.
+fn Start(Limit:usize) -> (Life, Arc<AtomicUsize>) {
+	let Created = Arc::new(AtomicUsize::new(0));
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.WithQueueFactory(Limit, {
+			let Created = Created.clone();
+
+			move |_:&str| {
+				Created.fetch_add(1, Ordering::SeqCst);
+
+				(Arc::new(Production::New()), Settings::New().WithConcurrency(4))
+			}
+		})
+		.Build();
+
+	(Life, Created)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn Concurrent() {
+	let (Life, Created) = Start(8);
+
+	let Plan = Plan();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Routes = (0..100).map(|_| {
+		let (Life, Action) = (Life.clone(), Routed(&Plan, "customer-1"));
+
+		tokio::spawn(async move { Life.Submit(Action).await.await })
+	});
+
+	for Outcome in timeout(Duration::from_secs(10), join_all(Routes)).await.unwrap() {
+		assert_eq!(Outcome.unwrap().unwrap(), json!("done"));
+	}
+
+	assert_eq!(Created.load(Ordering::SeqCst), 1);
+
+	assert_eq!(Life.Dynamic.Queues(), ["customer-1"]);
+
+	assert_eq!(Life.Settings("customer-1").Concurrency, 4);
+
+	assert_eq!(Life.Karma.get("customer-1").unwrap().Stats().Enqueued, 100);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn Evicted() {
+	let (Life, Created) = Start(2);
+
+	let Plan = Plan();
+
+	// `a` holds an action, `b` is created and left empty
+	drop(Life.Submit(Routed(&Plan, "a")).await);
+
+	sleep(Duration::from_secs(1)).await;
+
+	assert!(Echo::Fn::Route::Fn(&Life, Routed(&Plan, "b").as_ref()).await.is_ok());
+
+	sleep(Duration::from_secs(1)).await;
+
+	// Routing to `a` again makes it the most recently used
+	drop(Life.Submit(Routed(&Plan, "a")).await);
+
+	assert_eq!(Life.Dynamic.Queues(), ["b", "a"]);
+
+	sleep(Duration::from_secs(1)).await;
+
+	drop(Life.Submit(Routed(&Plan, "c")).await);
+
+	assert_eq!(Created.load(Ordering::SeqCst), 3);
+
+	assert!(Life.Karma.get("b").is_none());
+
+	assert_eq!(Life.Dynamic.Queues(), ["a", "c"]);
+
+	// Every created queue holds work, so none makes room
+	assert!(matches!(Life.Submit(Routed(&Plan, "d")).await.await, Err(Error::Routing(_))));
+
+	assert!(Life.Karma.get("d").is_none());
+
+	assert_eq!(Created.load(Ordering::SeqCst), 3);
+
+	// Static queues neither count nor are evicted
+	assert!(Life.Karma.get("main").is_some());
+
+	assert_eq!(Life.Karma.get("a").unwrap().Stats().Depth, 2);
+}
+
+#[tokio::test]
+async fn Unset() {
+	let Life = Life::Builder().Build();
+
+	assert!(matches!(
+		Life.Submit(Routed(&Plan(), "missing")).await.await,
+		Err(Error::Routing(Message)) if Message == "Unknown queue: missing"
+	));
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
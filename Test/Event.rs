@@ -0,0 +1,165 @@
+#![allow(non_snake_case)]
+
+//! Checks the event bus of a `Life`: a subscriber sees the whole lifecycle
+//! of an action retried once, in order, and a subscriber lagging more than
+//! the capacity of the bus behind skips to the oldest buffered event while
+//! the lost ones are counted.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Flaky` function fails its first call and succeeds after.
+fn Plan() -> Arc<Plan> {
+	let Calls = Arc::new(AtomicUsize::new(0));
+
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Flaky"))
+			.WithFunction("Flaky", move |_| {
+				let First = Calls.fetch_add(1, Ordering::SeqCst) == 0;
+
+				async move {
+					if First {
+						Err(Error::Execution("not yet".to_string()))
+					} else {
+						Ok(json!("done"))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates a `Flaky` action in the `main` queue.
+fn Flaky(Plan:&Arc<Plan>) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New("Flaky", Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!("main")),
+	)
+}
+
+/// Creates a `Life` with a `main` queue making up to two attempts.
+fn Start() -> Life {
+	let Fate = Config::builder().set_override("End", 2).unwrap().build().unwrap();
+
+	Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build()
+}
+
+// Paused, so the backoff before the retry passes at once
+#[tokio::test(start_paused = true)]
+async fn Lifecycle() {
+	let (Life, Plan) = (Start(), Plan());
+
+	let mut Subscription = Life.Subscribe();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Pending = Life.Submit(Flaky(&Plan)).await;
+
+	assert_eq!(timeout(Duration::from_secs(30), Pending).await.unwrap(), Ok(json!("done")));
+
+	let mut Seen = Vec::new();
+
+	// The future may resolve before the last event is published
+	while !matches!(Seen.last(), Some(Event::Completed { .. } | Event::Failed { .. })) {
+		Seen.push(timeout(Duration::from_secs(5), Subscription.Recv()).await.unwrap().unwrap());
+	}
+
+	let Type:Vec<_> = Seen.iter().map(Event::Type).collect();
+
+	assert_eq!(Type, ["Enqueued", "Started", "Retried", "Completed"], "{:?}", Seen);
+
+	assert!(matches!(
+		&Seen[0],
+		Event::Enqueued { Sequence:0, Action:Some(Action) } if Action == "Flaky"
+	));
+
+	assert!(matches!(
+		&Seen[2],
+		Event::Retried { Sequence:0, Attempt:1, Error } if Error.contains("not yet")
+	));
+
+	match &Seen[3] {
+		Event::Completed { Sequence, History, .. } => {
+			assert_eq!(*Sequence, 0);
+
+			assert_eq!(History.Attempts.len(), 2);
+		},
+		Other => panic!("unexpected event {:?}", Other),
+	}
+
+	assert_eq!(Life.Bus.Dropped(), 0);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Lagged() {
+	let (Life, Plan) = (Start(), Plan());
+
+	// Subscribed, but reading nothing until every action is enqueued
+	let mut Slow = Life.Subscribe();
+
+	for _ in 0..1100 {
+		drop(Life.Submit(Flaky(&Plan)).await);
+	}
+
+	// The bus buffers 1024 events, so the first 76 are lost
+	match Slow.Recv().await {
+		Some(Event::Enqueued { Sequence, .. }) => assert_eq!(Sequence, 76),
+		Other => panic!("unexpected event {:?}", Other),
+	}
+
+	assert_eq!(Life.Bus.Dropped(), 76);
+
+	let mut Received = 1;
+
+	while Slow.TryRecv().is_some() {
+		Received += 1;
+	}
+
+	assert_eq!(Received, 1024);
+
+	assert_eq!(Life.Bus.Dropped(), 76);
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
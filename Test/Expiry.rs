@@ -0,0 +1,148 @@
+#![allow(non_snake_case)]
+
+//! Checks `Ttl` on the paused clock: an action enqueued with a 100ms time to
+//! live that waits 200ms before a worker picks it up never executes, and
+//! completes with `Error::DeadlineExceeded` while the `Expired` event fires
+//! and `echo_actions_expired_total` counts it.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Records the counters incremented while it is the local recorder.
+#[derive(Default)]
+struct Tally {
+	/// Each counter by name.
+	Counter:Mutex<HashMap<String, Arc<AtomicU64>>>,
+}
+
+impl Tally {
+	/// Returns the value of a counter, zero if it was never registered.
+	fn Get(&self, Name:&str) -> u64 {
+		self.Counter.lock().unwrap().get(Name).map_or(0, |Count| Count.load(Ordering::SeqCst))
+	}
+}
+
+impl Recorder for Tally {
+	fn describe_counter(&self, _:KeyName, _:Option<Unit>, _:SharedString) {}
+
+	fn describe_gauge(&self, _:KeyName, _:Option<Unit>, _:SharedString) {}
+
+	fn describe_histogram(&self, _:KeyName, _:Option<Unit>, _:SharedString) {}
+
+	fn register_counter(&self, Key:&Key, _:&Metadata<'_>) -> Counter {
+		Counter::from_arc(
+			self.Counter.lock().unwrap().entry(Key.name().to_string()).or_default().clone(),
+		)
+	}
+
+	fn register_gauge(&self, _:&Key, _:&Metadata<'_>) -> Gauge { Gauge::noop() }
+
+	fn register_histogram(&self, _:&Key, _:&Metadata<'_>) -> Histogram { Histogram::noop() }
+}
+
+#[tokio::test(start_paused = true)]
+async fn Expired() {
+	let Tally = Tally::default();
+
+	// The test and every task it spawns run on this thread
+	let _Recorder = metrics::set_default_local_recorder(&Tally);
+
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Preview"))
+			.WithFunction("Preview", {
+				let Ran = Ran.clone();
+
+				move |_| {
+					Ran.fetch_add(1, Ordering::SeqCst);
+
+					async { Ok(json!("rendered")) }
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let mut Subscription = Life.Subscribe();
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Preview", Value::Null, Plan)
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Ttl", json!("100ms"));
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	// No worker runs yet, so the action outlives its time to live queued
+	advance(Duration::from_millis(200)).await;
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert!(matches!(Outcome, Err(Error::DeadlineExceeded(_))), "{:?}", Outcome);
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+
+	let Expired = timeout(Duration::from_secs(1), async {
+		loop {
+			match Subscription.Recv().await {
+				Some(Event::Expired { Sequence }) => break Sequence,
+				Some(Event::Started { .. }) => panic!("the expired action was started"),
+				Some(_) => {},
+				None => panic!("the event bus closed"),
+			}
+		}
+	})
+	.await
+	.unwrap();
+
+	assert_eq!(Expired, 0);
+
+	assert_eq!(Tally.Get("echo_actions_expired_total"), 1);
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU64, AtomicUsize, Ordering},
+		Arc, Mutex,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use metrics::{
+	Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+};
+use serde_json::{json, Value};
+use tokio::time::{advance, timeout};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,170 @@
+#![allow(non_snake_case)]
+
+//! Checks fair dequeuing: a light identity queued behind a heavy one's
+//! backlog is served within a bounded number of dequeues, each identity keeps
+//! its submission order, the stats report the depth of every bucket, and
+//! identities beyond the bucket limit share one bucket, and a higher priority
+//! is served before any bucket's lower one.
+
+/// Creates an action of an identity, identified by its name.
+fn Submitted(Name:&str, Identity:&str) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New(
+			Name,
+			Value::Null,
+			Arc::new(Plan::New().Build()),
+		)
+		.WithMetadata("SubmittedBy", json!(Identity)),
+	)
+}
+
+/// Returns the name of an action.
+async fn Name(Action:Option<Box<dyn Action>>) -> Option<String> {
+	Some(Action?.Metadata("Action").await?.as_str()?.to_string())
+}
+
+#[tokio::test]
+async fn Interleaved() {
+	let Production = Production::New();
+
+	Production.Fair(Some(Fairness::New(8)));
+
+	for Index in 0..1000 {
+		Production
+			.Enqueue(Submitted(&format!("Heavy{}", Index), "heavy"))
+			.await
+			.unwrap();
+	}
+
+	for Index in 0..3 {
+		Production
+			.Enqueue(Submitted(&format!("Light{}", Index), "light"))
+			.await
+			.unwrap();
+	}
+
+	assert_eq!(
+		Production.Stats().Buckets,
+		BTreeMap::from([("heavy".to_string(), 1000), ("light".to_string(), 3)])
+	);
+
+	let mut Order = Vec::new();
+
+	while let Some(Name) = Name(Production.Dequeue().await).await {
+		Order.push(Name);
+	}
+
+	assert_eq!(Order.len(), 1003);
+
+	// Every light action waits behind at most one heavy action
+	for Index in 0..3 {
+		let Position = Order
+			.iter()
+			.position(|Name| *Name == format!("Light{}", Index))
+			.unwrap();
+
+		assert!(Position <= 2 * Index + 1, "Light{} dequeued at {}", Index, Position);
+	}
+
+	let Heavy = Order
+		.iter()
+		.filter(|Name| Name.starts_with("Heavy"))
+		.cloned()
+		.collect::<Vec<_>>();
+
+	assert_eq!(Heavy, (0..1000).map(|Index| format!("Heavy{}", Index)).collect::<Vec<_>>());
+
+	assert!(Production.Stats().Buckets.is_empty());
+}
+
+#[tokio::test]
+async fn Shared() {
+	let Production = Production::New();
+
+	Production.Fair(Some(Fairness::New(2).WithKey("Tenant")));
+
+	for Identity in ["a", "b", "c", "d"] {
+		for Index in 0..2 {
+			Production
+				.Enqueue(Box::new(
+					Echo::Struct::Sequence::Action::Struct::New(
+						&format!("{}{}", Identity, Index),
+						Value::Null,
+						Arc::new(Plan::New().Build()),
+					)
+					.WithMetadata("Tenant", json!(Identity)),
+				))
+				.await
+				.unwrap();
+		}
+	}
+
+	Production.Enqueue(Submitted("Anonymous", "e")).await.unwrap();
+
+	assert_eq!(
+		Production.Stats().Buckets,
+		BTreeMap::from([("*".to_string(), 5), ("a".to_string(), 2), ("b".to_string(), 2)])
+	);
+
+	let mut Order = Vec::new();
+
+	while let Some(Name) = Name(Production.Dequeue().await).await {
+		Order.push(Name);
+	}
+
+	assert_eq!(Order, ["a0", "b0", "c0", "a1", "b1", "c1", "d0", "d1", "Anonymous"]);
+
+	Production.Fair(None);
+
+	Production.Enqueue(Submitted("First", "a")).await.unwrap();
+
+	Production.Enqueue(Submitted("Second", "b")).await.unwrap();
+
+	assert!(Production.Stats().Buckets.is_empty());
+
+	assert_eq!(Name(Production.Dequeue().await).await.as_deref(), Some("First"));
+}
+
+#[tokio::test]
+async fn Prioritized() {
+	let Production = Production::New();
+
+	Production.Fair(Some(Fairness::New(8)));
+
+	for (Name, Identity, Priority) in
+		[("a0", "a", 0), ("a1", "a", 0), ("b0", "b", 0), ("c0", "c", 5), ("a2", "a", 5)]
+	{
+		Production
+			.Enqueue(Box::new(
+				Echo::Struct::Sequence::Action::Struct::New(
+					Name,
+					Value::Null,
+					Arc::new(Plan::New().Build()),
+				)
+				.WithMetadata("SubmittedBy", json!(Identity))
+				.WithPriority(Priority),
+			))
+			.await
+			.unwrap();
+	}
+
+	let mut Order = Vec::new();
+
+	while let Some(Name) = Name(Production.Dequeue().await).await {
+		Order.push(Name);
+	}
+
+	// The urgent band round-robins a before c, then b keeps its turn
+	assert_eq!(Order, ["a2", "c0", "b0", "a0", "a1"]);
+}
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde_json::{json, Value};
+use Echo::{
+	Struct::Sequence::{
+		Plan::Struct as Plan,
+		Production::{Fairness::Struct as Fairness, Struct as Production},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
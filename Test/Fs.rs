@@ -0,0 +1,200 @@
+#![allow(non_snake_case)]
+
+//! Checks every built-in file operation of `Builtin::Fs`, that neither `..`
+//! nor a symbolic link escapes the root, that `Delete` and `Move` act on a
+//! link rather than on its target, and that `Read` returns a file that is
+//! not UTF-8 as base64.
+
+/// A fresh, empty directory for the file actions of one test.
+async fn Root(Name:&str) -> PathBuf {
+	let Root = std::env::temp_dir().join(format!("Echo-{}-{}", Name, std::process::id()));
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+
+	tokio::fs::create_dir_all(Root.join("Jail")).await.unwrap();
+
+	Root
+}
+
+/// Runs one file operation with the given configuration.
+///
+/// # Returns
+///
+/// The result of the operation.
+async fn Run(Fs:&Fs, Name:&str, Argument:Value) -> Result<Value, Error> {
+	let Plan = Fs.clone().Register(Echo::Struct::Sequence::Plan::Struct::New()).unwrap().Build();
+
+	let Action = Action::New(Name, Argument, Arc::new(Plan));
+
+	Action.Execute(&Life::Builder().Build()).await?;
+
+	Ok(Action.Metadata.Get("Result").await.unwrap())
+}
+
+/// The message of an execution error.
+fn Message(Result:Result<Value, Error>) -> String {
+	match Result {
+		Err(Error::Execution(Message)) => Message,
+		Other => panic!("{:?}", Other),
+	}
+}
+
+#[tokio::test]
+async fn Operations() {
+	let Root = Root("FsOperations").await;
+
+	let Fs = Fs::New(Root.join("Jail"));
+
+	let Jail = Root.join("Jail");
+
+	let Written = Run(&Fs, "Write", json!(["note.txt", "Hello"])).await.unwrap();
+
+	assert_eq!(Written, json!({ "path": "note.txt", "bytes": 5 }));
+
+	Run(&Fs, "Append", json!(["note.txt", ", world"])).await.unwrap();
+
+	assert_eq!(
+		Run(&Fs, "Read", json!(["note.txt"])).await.unwrap(),
+		json!({ "path": "note.txt", "content": "Hello, world", "bytes": 12 })
+	);
+
+	tokio::fs::create_dir(Jail.join("Copies")).await.unwrap();
+
+	let Copied = Run(&Fs, "Copy", json!(["note.txt", "Copies/note.txt"])).await.unwrap();
+
+	assert_eq!(Copied["bytes"], 12);
+
+	Run(&Fs, "Move", json!(["Copies/note.txt", "Copies/moved.txt"])).await.unwrap();
+
+	assert_eq!(Run(&Fs, "List", json!(["*.txt"])).await.unwrap()["entries"], json!(["note.txt"]));
+
+	assert_eq!(
+		Run(&Fs, "List", json!(["Copies/*"])).await.unwrap()["entries"],
+		json!(["Copies/moved.txt"])
+	);
+
+	assert_eq!(
+		Run(&Fs, "Exists", json!(["Copies"])).await.unwrap(),
+		json!({ "path": "Copies", "exists": true, "kind": "directory" })
+	);
+
+	assert_eq!(Run(&Fs, "Exists", json!(["Copies/note.txt"])).await.unwrap()["exists"], false);
+
+	assert_eq!(Run(&Fs, "Delete", json!(["Copies"])).await.unwrap()["deleted"], true);
+
+	assert_eq!(Run(&Fs, "Delete", json!(["Copies"])).await.unwrap()["deleted"], false);
+
+	assert!(!Jail.join("Copies").exists());
+
+	let Capped = Fs { Read:4, ..Fs::New(&Jail) };
+
+	assert!(Message(Run(&Capped, "Read", json!(["note.txt"])).await).contains("read limit"));
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+}
+
+#[tokio::test]
+async fn Escaped() {
+	let Root = Root("FsEscaped").await;
+
+	let Fs = Fs::New(Root.join("Jail"));
+
+	tokio::fs::write(Root.join("secret.txt"), "Secret").await.unwrap();
+
+	for (Name, Argument) in [
+		("Read", json!(["../secret.txt"])),
+		("Write", json!(["Nested/../../secret.txt", "Overwritten"])),
+		("Copy", json!(["../secret.txt", "stolen.txt"])),
+		("Move", json!(["../secret.txt", "stolen.txt"])),
+		("Delete", json!(["../secret.txt"])),
+	] {
+		assert!(Message(Run(&Fs, Name, Argument).await).starts_with("Path escapes the root"));
+	}
+
+	for Name in ["Delete", "Move"] {
+		let Argument = if Name == "Move" { json!([".", "elsewhere"]) } else { json!(["."]) };
+
+		assert!(Message(Run(&Fs, Name, Argument).await).starts_with("Path refers to the root"));
+	}
+
+	assert_eq!(tokio::fs::read_to_string(Root.join("secret.txt")).await.unwrap(), "Secret");
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn Linked() {
+	let Root = Root("FsLinked").await;
+
+	let Fs = Fs::New(Root.join("Jail"));
+
+	let Jail = Root.join("Jail");
+
+	tokio::fs::create_dir(Root.join("Outside")).await.unwrap();
+
+	tokio::fs::write(Root.join("Outside/secret.txt"), "Secret").await.unwrap();
+
+	tokio::fs::symlink(Root.join("Outside"), Jail.join("Escape")).await.unwrap();
+
+	// A link leading out of the root is refused when followed
+	assert!(
+		Message(Run(&Fs, "Read", json!(["Escape/secret.txt"])).await)
+			.starts_with("Path escapes the root")
+	);
+
+	// But is removed itself, never the directory it points to
+	assert_eq!(Run(&Fs, "Delete", json!(["Escape"])).await.unwrap()["deleted"], true);
+
+	assert!(tokio::fs::symlink_metadata(Jail.join("Escape")).await.is_err());
+
+	assert!(Root.join("Outside/secret.txt").exists());
+
+	tokio::fs::create_dir(Jail.join("Kept")).await.unwrap();
+
+	tokio::fs::write(Jail.join("Kept/note.txt"), "Kept").await.unwrap();
+
+	tokio::fs::symlink(Jail.join("Kept"), Jail.join("Alias")).await.unwrap();
+
+	Run(&Fs, "Move", json!(["Alias", "Renamed"])).await.unwrap();
+
+	assert!(tokio::fs::symlink_metadata(Jail.join("Renamed")).await.unwrap().is_symlink());
+
+	assert_eq!(tokio::fs::read_to_string(Jail.join("Kept/note.txt")).await.unwrap(), "Kept");
+
+	Run(&Fs, "Delete", json!(["Renamed"])).await.unwrap();
+
+	assert!(Jail.join("Kept/note.txt").exists());
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+}
+
+#[tokio::test]
+async fn Binary() {
+	let Root = Root("FsBinary").await;
+
+	let Fs = Fs::New(Root.join("Jail"));
+
+	let Bytes = vec![0xff, 0xfe, 0x00, 0x80];
+
+	tokio::fs::write(Root.join("Jail/image.bin"), &Bytes).await.unwrap();
+
+	let Read = Run(&Fs, "Read", json!(["image.bin"])).await.unwrap();
+
+	assert_eq!(Read["content"]["encoding"], "base64");
+
+	assert_eq!(Echo::Fn::Binary::Decode::Fn(&Read["content"]).unwrap(), Some(Bytes));
+
+	assert_eq!(Read["bytes"], 4);
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+}
+
+use std::{path::PathBuf, sync::Arc};
+
+use serde_json::{json, Value};
+use Echo::{
+	Builtin::Fs::Struct as Fs,
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{Action::Struct as Action, Life::Struct as Life},
+};
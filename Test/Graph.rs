@@ -0,0 +1,227 @@
+#![allow(non_snake_case)]
+
+//! Checks graph exports: a diamond exported while one branch still runs
+//! shows every node in its state, renders the same DOT every time and never
+//! an edge to a missing node; and the chain of an action is described as it
+//! executes, the follow-ups it does not take skipped.
+
+/// A plan whose `Work` function returns at once, `Slow` after 5ms and
+/// `Wait` once `Release` is notified, telling `Started` first.
+fn Plan(Started:&Arc<Notify>, Release:&Arc<Notify>) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithSignature(Signature::New("Slow"))
+			.WithSignature(Signature::New("Wait"))
+			.WithFunction("Work", |_:Vec<Value>| async move { Ok(json!(null)) })
+			.unwrap()
+			.WithFunction("Slow", |_:Vec<Value>| {
+				async move {
+					sleep(Duration::from_millis(5)).await;
+
+					Ok(json!(null))
+				}
+			})
+			.unwrap()
+			.WithFunction("Wait", {
+				let (Started, Release) = (Started.clone(), Release.clone());
+
+				move |_:Vec<Value>| {
+					let (Started, Release) = (Started.clone(), Release.clone());
+
+					async move {
+						Started.notify_one();
+
+						Release.notified().await;
+
+						Ok(json!(null))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// An action of the `Diamond` graph.
+fn Node(Plan:&Arc<Plan>, Name:&str, Id:&str) -> Action<()> {
+	Action::New(Name, (), Plan.clone())
+		.WithMetadata("Graph", json!("Diamond"))
+		.WithMetadata("Node", json!(Id))
+}
+
+/// The state and duration of every node, in order.
+fn States(Graph:&Graph) -> Vec<(&str, State, Option<u64>)> {
+	Graph.Node.iter().map(|Node| (Node.Id.as_str(), Node.State, Node.DurationMs)).collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn Diamond() {
+	let (Started, Release) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+
+	let Plan = Plan(&Started, &Release);
+
+	let Life = Life::Builder().Build();
+
+	let Dag = Arc::new(Dag::New(
+		"Diamond",
+		Graph::New()
+			.WithNode("A", "Work")
+			.WithNode("B", "Wait")
+			.WithNode("C", "Slow")
+			.WithNode("D", "Work")
+			.WithEdge("A", "B")
+			.unwrap()
+			.WithEdge("A", "C")
+			.unwrap()
+			.WithEdge("B", "D")
+			.unwrap()
+			.WithEdge("C", "D")
+			.unwrap(),
+		&Life,
+	));
+
+	assert!(Graph::New().WithNode("A", "Work").WithEdge("A", "E").is_err());
+
+	assert!(States(&Dag.Export()).iter().all(|(_, State, _)| *State == State::Pending));
+
+	Node(&Plan, "Work", "A").Execute(&Life).await.unwrap();
+
+	let B = tokio::spawn({
+		let (Plan, Life) = (Plan.clone(), Life.clone());
+
+		async move { Node(&Plan, "Wait", "B").Execute(&Life).await }
+	});
+
+	Node(&Plan, "Slow", "C").Execute(&Life).await.unwrap();
+
+	Started.notified().await;
+
+	// B still runs: exports agree with each other and with the DOT
+	let Export = Dag.Export();
+
+	assert_eq!(
+		States(&Export),
+		[
+			("A", State::Done, Some(0)),
+			("B", State::Running, None),
+			("C", State::Done, Some(5)),
+			("D", State::Pending, None),
+		]
+	);
+
+	assert_eq!(Dag.Export(), Export);
+
+	assert_eq!(
+		Export.ToDot(),
+		"digraph {\n\tnode [shape=box];\n\t\"A\" [label=\"Work\\nDone 0ms\"];\n\t\"B\" \
+		 [label=\"Wait\\nRunning\"];\n\t\"C\" [label=\"Slow\\nDone 5ms\"];\n\t\"D\" \
+		 [label=\"Work\\nPending\"];\n\t\"A\" -> \"B\";\n\t\"A\" -> \"C\";\n\t\"B\" -> \
+		 \"D\";\n\t\"C\" -> \"D\";\n}"
+	);
+
+	assert_eq!(Export.ToDot(), Dag.Export().ToDot());
+
+	// Exports taken while the graph runs never hold an edge to a missing node
+	let Exporting = tokio::spawn({
+		let Dag = Dag.clone();
+
+		async move {
+			for _ in 0..100 {
+				let Export = Dag.Export();
+
+				for Edge in &Export.Edge {
+					assert!(Export.Get(&Edge.From).is_some() && Export.Get(&Edge.To).is_some());
+				}
+
+				tokio::task::yield_now().await;
+			}
+		}
+	});
+
+	Release.notify_one();
+
+	B.await.unwrap().unwrap();
+
+	Node(&Plan, "Work", "D").Execute(&Life).await.unwrap();
+
+	Exporting.await.unwrap();
+
+	assert!(States(&Dag.Export()).iter().all(|(_, State, _)| *State == State::Done));
+
+	let Serialized = serde_json::to_value(Dag.Export()).unwrap();
+
+	// B started as C did, and ran until C ended
+	assert_eq!(
+		Serialized["Node"][1],
+		json!({ "Id": "B", "Action": "Wait", "State": "Done", "DurationMs": 5 })
+	);
+
+	assert_eq!(Serialized["Edge"][0], json!({ "From": "A", "To": "B" }));
+}
+
+#[tokio::test(start_paused = true)]
+async fn Chain() {
+	let (Started, Release) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+
+	let Plan = Plan(&Started, &Release);
+
+	let Life = Life::Builder().Build();
+
+	let Action = Action::New("Slow", (), Plan.clone())
+		.WithMetadata("Graph", json!("Chain"))
+		.WithMetadata(
+			"OnSuccess",
+			json!([
+				{ "Action": "Work", "NextAction": { "Action": "Work" } },
+				{ "Action": "Missing", "Node": "Last" },
+			]),
+		)
+		.WithMetadata("OnFailure", json!({ "Action": "Work" }));
+
+	let Graph = Graph::Chain(&Action).unwrap();
+
+	assert_eq!(
+		Graph.Edge.iter().map(|Edge| (Edge.From.as_str(), Edge.To.as_str())).collect::<Vec<_>>(),
+		[
+			("0", "0/OnSuccess/0"),
+			("0/OnSuccess/0", "0/OnSuccess/0/NextAction/0"),
+			("0", "Last"),
+			("0", "0/OnFailure/0"),
+		]
+	);
+
+	let Dag = Dag::New("Chain", Graph, &Life);
+
+	// The second follow-up names no function, which fails the chain
+	assert!(Action.Execute(&Life).await.is_err());
+
+	assert_eq!(
+		States(&Dag.Export()),
+		[
+			("0", State::Done, Some(5)),
+			("0/OnSuccess/0", State::Done, Some(0)),
+			("0/OnSuccess/0/NextAction/0", State::Done, Some(0)),
+			("Last", State::Failed, Some(0)),
+			("0/OnFailure/0", State::Skipped, None),
+		]
+	);
+
+	assert!(Dag.Export().ToDot().contains("\t\"0\" -> \"0/OnFailure/0\" [label=\"OnFailure\"];\n"));
+
+	assert!(Graph::Chain(&Action.WithMetadata("NextAction", json!("Work"))).is_err());
+}
+
+use std::{sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+use tokio::{sync::Notify, time::sleep};
+use Echo::{
+	Enum::Sequence::Graph::State::Enum as State,
+	Struct::Sequence::{
+		Action::{Signature::Struct as Signature, Struct as Action},
+		Graph::{Dag::Struct as Dag, Struct as Graph},
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+	},
+};
@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+
+//! Checks the `Handler` attribute: two handlers it derives are registered
+//! with `Plan::WithHandler` and answer under their kinds with typed requests
+//! and responses, a request of the wrong shape fails with the kind and the
+//! path to the offending value instead of panicking, and misshapen handlers
+//! are refused at compile time.
+
+/// Doubles a number.
+struct Double;
+
+#[Handler]
+impl Double {
+	async fn Run(&self, Request:u64) -> Result<u64, Error> { Ok(Request * 2) }
+}
+
+/// The request of `Greet`.
+#[derive(Deserialize)]
+struct Person {
+	Name:String,
+}
+
+/// Greets a person.
+struct Greet {
+	Greeting:String,
+}
+
+#[Handler(Kind = "Hello")]
+impl Greet {
+	async fn Run(&self, Request:Person) -> Result<String, Error> {
+		Ok(format!("{}, {}", self.Greeting, Request.Name))
+	}
+}
+
+/// Calls the function of an action directly.
+async fn Call(Plan:&Plan, Kind:&str, Argument:Value) -> Result<Value, Error> {
+	Plan.Get(Kind).unwrap()(vec![Arg::Json(Argument)]).await
+}
+
+#[tokio::test]
+async fn Derived() {
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithHandler(Double)
+		.unwrap()
+		.WithHandler(Greet { Greeting:"Hello".to_string() })
+		.unwrap()
+		.Build();
+
+	assert_eq!(Plan.Names(), ["Double", "Hello"]);
+
+	assert_eq!(Call(&Plan, "Double", json!(21)).await, Ok(json!(42)));
+
+	assert_eq!(
+		Call(&Plan, "Hello", json!({ "Name": "Echo" })).await,
+		Ok(json!("Hello, Echo"))
+	);
+
+	for (Argument, Expected, Reason) in [
+		(json!({ "Nom": "Echo" }), ".", "missing field `Name`"),
+		(json!({ "Name": 7 }), ".Name", "invalid type: integer `7`"),
+	] {
+		let Outcome = Call(&Plan, "Hello", Argument).await;
+
+		assert!(
+			matches!(
+				&Outcome,
+				Err(Error::InvalidRequest { Kind, Path, Message })
+					if Kind == "Hello" && Path == Expected && Message.starts_with(Reason)
+			),
+			"{:?}",
+			Outcome
+		);
+	}
+
+	let Outcome = Call(&Plan, "Double", json!([1, "two"])).await;
+
+	assert_eq!(
+		Outcome.unwrap_err().to_string(),
+		"Invalid request for Double at .: invalid type: sequence, expected u64"
+	);
+}
+
+#[test]
+fn Misshapen() { trybuild::TestCases::new().compile_fail("Test/Handler/*.rs"); }
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Macro::Handler,
+	Struct::Sequence::Plan::Formality::Struct as Plan,
+};
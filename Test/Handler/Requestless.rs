@@ -0,0 +1,12 @@
+#![allow(non_snake_case)]
+
+//! A handler whose `Run` takes no request.
+
+struct Ping;
+
+#[Echo::Macro::Handler]
+impl Ping {
+	async fn Run(&self) -> Result<(), Echo::Enum::Sequence::Action::Error::Enum> { Ok(()) }
+}
+
+fn main() {}
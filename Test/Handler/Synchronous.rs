@@ -0,0 +1,14 @@
+#![allow(non_snake_case)]
+
+//! A handler whose `Run` is not async.
+
+struct Double;
+
+#[Echo::Macro::Handler]
+impl Double {
+	fn Run(&self, Request:u64) -> Result<u64, Echo::Enum::Sequence::Action::Error::Enum> {
+		Ok(Request * 2)
+	}
+}
+
+fn main() {}
@@ -0,0 +1,185 @@
+#![allow(non_snake_case)]
+
+//! Checks the warm handoff of queued actions between two processes: the
+//! actions of an old `Life` move chunk by chunk into a new one over an admin
+//! connection, a connection lost mid-way puts the chunk not acknowledged back
+//! and resumes the old consumers, and running again finishes the handoff
+//! with every action moved once, in order.
+
+/// A writer failing once it was asked to send a number of acknowledgements.
+struct Flaky<W> {
+	Writer:W,
+
+	/// The acknowledgements left to send.
+	Left:usize,
+}
+
+#[async_trait]
+impl<W:Writer> Writer for Flaky<W> {
+	async fn Write(&mut self, Frame:&[u8]) -> io::Result<()> {
+		if String::from_utf8_lossy(Frame).contains("HandoffAck") {
+			if self.Left == 0 {
+				return Err(io::Error::new(io::ErrorKind::BrokenPipe, "Connection lost"));
+			}
+
+			self.Left -= 1;
+		}
+
+		self.Writer.Write(Frame).await
+	}
+}
+
+/// A plan whose `Work` function returns its argument.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", |Argument| async move { Ok(json!(Argument)) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Returns the argument of every action queued in `main`, in dequeue order.
+async fn Queued(Life:&Life) -> Vec<u64> {
+	let Line = Life.Karma.get("main").unwrap().clone();
+
+	Line.Inspect(&["Argument"])
+		.await
+		.into_iter()
+		.map(|(_, Metadata)| Metadata["Argument"][0].as_u64().unwrap())
+		.collect()
+}
+
+/// Runs the receiver over a new connection to the old pump.
+async fn Connect(
+	Pump:&Pump,
+	Receiver:&mut Receiver,
+	Acks:usize,
+) -> io::Result<Echo::Struct::Transport::Handoff::Struct> {
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn({
+		let Pump = Pump.clone();
+
+		async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		}
+	});
+
+	let (Input, Output) = split(Client);
+
+	timeout(
+		Duration::from_secs(10),
+		Receiver.Run(
+			Line::Reader::Struct::New(Input),
+			Flaky { Writer:Line::Writer::Struct::New(Output), Left:Acks },
+		),
+	)
+	.await
+	.expect("the handoff hung")
+}
+
+#[tokio::test]
+async fn Handed() {
+	let Plan = Plan();
+
+	let Old = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let New = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Line = Old.Karma.get("main").unwrap().clone();
+
+	for Index in 0..1000 {
+		Line.Enqueue(Box::new(
+			Action::New("Work", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!([Index]))
+				.WithMetadata("Queue", json!("main")),
+		))
+		.await
+		.unwrap();
+	}
+
+	let Pump = Pump::New(Old.clone(), Plan.clone()).WithAdmin("admin");
+
+	let mut Receiver = Receiver::New(New.clone(), Plan).WithToken("admin").WithChunk(64);
+
+	// The third chunk is enqueued but its acknowledgement is lost
+	assert!(Connect(&Pump, &mut Receiver, 2).await.is_err());
+
+	timeout(Duration::from_secs(10), async {
+		while Old.Handoff.Paused() {
+			sleep(Duration::from_millis(5)).await;
+		}
+	})
+	.await
+	.expect("the old consumers never resumed");
+
+	// Only the acknowledged chunks left; the third is back at the front
+	assert_eq!(Queued(&Old).await, (128..1000).collect::<Vec<_>>());
+
+	assert_eq!(Queued(&New).await, (0..192).collect::<Vec<_>>());
+
+	let Summary = Connect(&Pump, &mut Receiver, usize::MAX).await.unwrap();
+
+	assert_eq!(Summary.Transferred, 1000);
+
+	assert_eq!(Summary.Duplicate, 64);
+
+	assert_eq!((Summary.Kept, Summary.Refused), (0, 0));
+
+	// Every action moved once, in order, and the old consumers stay paused
+	assert_eq!(Queued(&New).await, (0..1000).collect::<Vec<_>>());
+
+	assert!(Queued(&Old).await.is_empty());
+
+	assert!(Old.Handoff.Paused());
+}
+
+#[tokio::test]
+async fn Denied() {
+	let Plan = Plan();
+
+	let Old = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Pump = Pump::New(Old.clone(), Plan.clone()).WithAdmin("admin");
+
+	// Without the admin token the handoff is refused and nothing pauses
+	let mut Receiver = Receiver::New(Life::Builder().Build(), Plan);
+
+	let _Error = Connect(&Pump, &mut Receiver, usize::MAX).await.unwrap_err();
+
+	assert_eq!(_Error.kind(), io::ErrorKind::PermissionDenied);
+
+	assert!(!Old.Handoff.Paused());
+}
+
+use std::{io, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Struct::{
+		Sequence::{
+			Action::{Signature::Struct as Signature, Struct as Action},
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+		},
+		Transport::{Frame::Line, Handoff::Receiver::Struct as Receiver, Pump::Struct as Pump},
+	},
+	Trait::Transport::Writer::Trait as Writer,
+};
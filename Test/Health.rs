@@ -0,0 +1,227 @@
+#![allow(non_snake_case)]
+
+//! Checks each built-in health check at its trip condition and the report
+//! aggregating them: `Depth` from its thresholds or on a missing queue,
+//! `Recent` once work waits past the window of the last success, `Latency`
+//! behind a task holding the worker, and a report whose status is the worst
+//! of its checks, counting a check past its timeout as `Unhealthy`.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A check reporting a fixed status, or never finishing without one.
+struct Fixed(&'static str, Option<Status>);
+
+#[async_trait]
+impl Check for Fixed {
+	fn Name(&self) -> String { self.0.to_string() }
+
+	fn Timeout(&self) -> Duration { Duration::from_millis(50) }
+
+	async fn Check(&self, _:&Life) -> Outcome {
+		match self.1 {
+			Some(Status) => Outcome::New(Status, "fixed"),
+			None => pending().await,
+		}
+	}
+}
+
+/// A plan whose `Work` function succeeds at once.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", |_| async { Ok(Value::Null) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates a `Work` action in the `main` queue.
+fn Work(Plan:&Arc<Plan>) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New("Work", Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!("main")),
+	)
+}
+
+/// Creates a `Life` with a `main` queue and the given check.
+fn Start(Check:Arc<dyn Check>) -> Life {
+	Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.WithCheck(Check)
+		.Build()
+}
+
+/// Evaluates the only check of a `Life`.
+async fn Evaluate(Life:&Life) -> Status {
+	let Report = Life.Health().await;
+
+	assert_eq!(Report.Check.len(), 1);
+
+	Report.Status
+}
+
+#[tokio::test]
+async fn Depth() {
+	let Plan = Plan();
+
+	let Life = Start(Arc::new(Depth::New("main", 2, 4)));
+
+	assert_eq!(Evaluate(&Life).await, Status::Healthy);
+
+	for (Depth, Expected) in [
+		(1, Status::Healthy),
+		(2, Status::Degraded),
+		(3, Status::Degraded),
+		(4, Status::Unhealthy),
+	] {
+		drop(Life.Submit(Work(&Plan)).await);
+
+		let Report = Life.Health().await;
+
+		assert_eq!(Report.Status, Expected, "at depth {}", Depth);
+
+		assert!(Report.Check["Depth:main"].Message.contains(&format!("holds {}", Depth)));
+	}
+
+	let Missing = Start(Arc::new(Depth::New("absent", 2, 4)));
+
+	let Report = Missing.Health().await;
+
+	assert_eq!(Report.Status, Status::Unhealthy);
+
+	assert!(Report.Check["Depth:absent"].Message.contains("does not exist"));
+}
+
+#[tokio::test]
+async fn Recent() {
+	let Plan = Plan();
+
+	let Life = Start(Arc::new(Recent::New(Duration::from_millis(200))));
+
+	// Idle, so healthy though nothing ever succeeded
+	assert_eq!(Evaluate(&Life).await, Status::Healthy);
+
+	Life.PauseQueue("main");
+
+	drop(Life.Submit(Work(&Plan)).await);
+
+	// Waiting without any success
+	assert_eq!(Evaluate(&Life).await, Status::Unhealthy);
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	Life.ResumeQueue("main");
+
+	timeout(Duration::from_secs(5), Life.Submit(Work(&Plan)).await).await.unwrap().unwrap();
+
+	Life.PauseQueue("main");
+
+	drop(Life.Submit(Work(&Plan)).await);
+
+	// Waiting, but within the window of the last success
+	assert_eq!(Evaluate(&Life).await, Status::Healthy);
+
+	sleep(Duration::from_millis(300)).await;
+
+	let Report = Life.Health().await;
+
+	assert_eq!(Report.Status, Status::Unhealthy);
+
+	assert!(Report.Check["Recent"].Message.contains("1 actions waiting"), "{:?}", Report);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Latency() {
+	let Life = Start(Arc::new(Latency::New(Duration::from_millis(20), Duration::from_secs(5))));
+
+	assert_eq!(Evaluate(&Life).await, Status::Healthy);
+
+	// Holds the only worker ahead of the probe
+	tokio::spawn(async { std::thread::sleep(Duration::from_millis(60)) });
+
+	assert_eq!(Evaluate(&Life).await, Status::Degraded);
+
+	let Life = Start(Arc::new(Latency::New(Duration::ZERO, Duration::from_millis(20))));
+
+	tokio::spawn(async { std::thread::sleep(Duration::from_millis(60)) });
+
+	assert_eq!(Evaluate(&Life).await, Status::Unhealthy);
+}
+
+#[tokio::test]
+async fn Aggregated() {
+	let Life = Life::Builder().Build();
+
+	let Report = Life.Health().await;
+
+	assert_eq!((Report.Status, Report.Check.len(), Report.Ready()), (Status::Healthy, 0, true));
+
+	Life.Register(Arc::new(Fixed("Good", Some(Status::Healthy))));
+
+	Life.Register(Arc::new(Fixed("Slow", Some(Status::Degraded))));
+
+	let Report = Life.Health().await;
+
+	// Degraded is still ready
+	assert_eq!((Report.Status, Report.Live(), Report.Ready()), (Status::Degraded, true, true));
+
+	Life.Register(Arc::new(Fixed("Stuck", None)));
+
+	let Report = timeout(Duration::from_secs(5), Life.Health()).await.unwrap();
+
+	assert_eq!((Report.Status, Report.Live(), Report.Ready()), (Status::Unhealthy, true, false));
+
+	assert_eq!(Report.Check["Good"].Status, Status::Healthy);
+
+	assert_eq!(Report.Check["Slow"].Status, Status::Degraded);
+
+	assert_eq!(Report.Check["Stuck"].Status, Status::Unhealthy);
+
+	assert!(Report.Check["Stuck"].Message.contains("timed out"));
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use futures::future::pending;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::{Health::Status::Enum as Status, Sequence::Action::Error::Enum as Error},
+	Struct::{
+		Health::{
+			Depth::Struct as Depth,
+			Latency::Struct as Latency,
+			Outcome::Struct as Outcome,
+			Recent::Struct as Recent,
+		},
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+	},
+	Trait::{
+		Health::Check::Trait as Check,
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+	},
+};
@@ -0,0 +1,129 @@
+#![allow(non_snake_case)]
+
+//! Checks the attempt history of an action failing twice before it
+//! succeeds: the `Completed` event carries three attempts numbered in order,
+//! the first two failed with their error and a backoff, the last succeeded
+//! without either, and their timestamps never go back.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+// Paused, so the backoffs before the retries pass at once
+#[tokio::test(start_paused = true)]
+async fn Recorded() {
+	let Calls = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Flaky"))
+			.WithFunction("Flaky", move |_| {
+				let Call = Calls.fetch_add(1, Ordering::SeqCst) + 1;
+
+				async move {
+					if Call < 3 {
+						Err(Error::Execution(format!("failure {}", Call)))
+					} else {
+						Ok(json!("done"))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Fate = Config::builder().set_override("End", 3).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let mut Subscription = Life.Subscribe();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Flaky", Value::Null, Plan)
+		.WithMetadata("Queue", json!("main"));
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	assert_eq!(timeout(Duration::from_secs(30), Pending).await.unwrap(), Ok(json!("done")));
+
+	let History = loop {
+		match timeout(Duration::from_secs(5), Subscription.Recv()).await.unwrap() {
+			Some(Event::Completed { History, .. }) => break History,
+			Some(_) => continue,
+			None => panic!("the bus closed"),
+		}
+	};
+
+	assert_eq!(History.Omitted, 0);
+
+	let Recorded = History
+		.Attempts
+		.iter()
+		.map(|Attempt| (Attempt.Attempt, Attempt.Outcome, Attempt.Error.as_deref()))
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		Recorded,
+		[
+			(1, Outcome::Failed, Some("Execution Error: failure 1")),
+			(2, Outcome::Failed, Some("Execution Error: failure 2")),
+			(3, Outcome::Succeeded, None),
+		]
+	);
+
+	assert!(History.Attempts[..2].iter().all(|Attempt| Attempt.Backoff.is_some()));
+
+	assert_eq!(History.Attempts[2].Backoff, None);
+
+	for Attempt in &History.Attempts {
+		assert!(Attempt.Started <= Attempt.Ended, "{:?}", Attempt);
+	}
+
+	for Pair in History.Attempts.windows(2) {
+		assert!(Pair[0].Ended <= Pair[1].Started, "{:?}", Pair);
+	}
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{Action::Error::Enum as Error, Attempt::Outcome::Enum as Outcome},
+	},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,411 @@
+#![allow(non_snake_case)]
+
+//! Checks how the hooks an action references by name or pattern are
+//! resolved: their order, references matching nothing under each policy
+//! and the cap, and when each phase of the object form runs.
+
+/// Creates a lifecycle with overridden configuration keys.
+fn Life(Override:&[(&str, i64)]) -> Life {
+	let mut Fate = Config::builder();
+
+	for (Key, Value) in Override {
+		Fate = Fate.set_override(*Key, *Value).unwrap();
+	}
+
+	Life::Builder().WithFate(Arc::new(Fate.build().unwrap())).Build()
+}
+
+/// Registers hooks that record their calls, returning the record.
+fn Record(Life:&Life, Exact:&[&str], Pattern:&[&str]) -> Arc<Mutex<Vec<String>>> {
+	let Called = Arc::new(Mutex::new(Vec::new()));
+
+	for Name in Exact {
+		let (Called, Name) = (Called.clone(), Name.to_string());
+
+		Life.Span.insert(
+			Name.clone(),
+			Arc::new(move || {
+				Called.lock().unwrap().push(Name.clone());
+
+				Ok(())
+			}),
+		);
+	}
+
+	for Pattern in Pattern {
+		let (Called, Source) = (Called.clone(), Pattern.to_string());
+
+		Life.AddHookPattern(Pattern, move |Name| {
+			Called.lock().unwrap().push(format!("{} <- {}", Source, Name));
+
+			Ok(())
+		});
+	}
+
+	Called
+}
+
+#[tokio::test]
+async fn Order() {
+	let Life = Life(&[]);
+
+	let Called = Record(
+		&Life,
+		&["audit.write", "audit.read", "notify.slack"],
+		&["audit.*", "*.read", "metrics.*"],
+	);
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New(
+		"Exists",
+		Value::Null,
+		Arc::new(
+			Echo::Builtin::Fs::Struct::New(std::env::temp_dir())
+				.Register(Echo::Struct::Sequence::Plan::Struct::New())
+				.unwrap()
+				.Build(),
+		),
+	)
+	.WithMetadata("Argument", json!(["nothing"]))
+	.WithMetadata("Hooks", json!(["notify.slack", "audit.*", "metrics.cpu", "audit.read"]));
+
+	let _ = Action.Execute(&Life).await;
+
+	assert_eq!(
+		*Called.lock().unwrap(),
+		[
+			// Exact hooks in reference order, a pattern's names sorted
+			"notify.slack",
+			"audit.read",
+			"audit.write",
+			// Then registered patterns sorted, each over the names it matches
+			"*.read <- audit.read",
+			"audit.* <- audit.read",
+			"audit.* <- audit.write",
+			"metrics.* <- metrics.cpu",
+		]
+	);
+}
+
+#[tokio::test]
+async fn Unmatched() {
+	let Lenient = Life(&[]);
+
+	Record(&Lenient, &["audit.read"], &[]);
+
+	assert_eq!(Lenient.Expand(&["missing.*", "missing"]).unwrap().len(), 0);
+
+	let Strict = Life(&[("hooks.strict", 1)]);
+
+	Record(&Strict, &["audit.read"], &[]);
+
+	assert_eq!(
+		Strict.Expand(&["audit.read", "missing.*"]).unwrap_err(),
+		Error::UnknownHook("missing.*".to_string())
+	);
+
+	assert_eq!(Strict.Expand(&["audit.?ead"]).unwrap()[0].Name(), "audit.read");
+}
+
+#[tokio::test]
+async fn Cap() {
+	let Life = Life(&[("hooks.expansion", 3)]);
+
+	let Called = Record(&Life, &["a.1", "a.2", "a.3", "a.4"], &["b.*"]);
+
+	assert_eq!(Life.Expand(&["a.*"]).unwrap_err(), Error::HookLimit { Maximum:3 });
+
+	assert_eq!(
+		Life.Expand(&["a.1", "a.2", "b.1", "b.2"]).unwrap_err(),
+		Error::HookLimit { Maximum:3 }
+	);
+
+	assert_eq!(Life.Expand(&["a.1", "a.2", "a.3"]).unwrap().len(), 3);
+
+	// Nothing ran for the expansions refused
+	assert!(Called.lock().unwrap().is_empty());
+}
+
+/// An action running `Count` with the given `Hooks` and `MissingHooks`
+/// metadata, and the number of times its function ran.
+fn Counted(Hooks:Value, Missing:Option<&str>) -> (Action<Value>, Arc<AtomicUsize>) {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Count"))
+		.WithFunction("Count", {
+			let Ran = Ran.clone();
+
+			move |_| {
+				Ran.fetch_add(1, Ordering::SeqCst);
+
+				async { Ok(Value::Null) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	let mut Action = Action::New("Count", Value::Null, Arc::new(Plan))
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Hooks", Hooks);
+
+	if let Some(Missing) = Missing {
+		Action = Action.WithMetadata("MissingHooks", json!(Missing));
+	}
+
+	(Action, Ran)
+}
+
+/// Creates a lifecycle with a `main` queue and `hooks.missing` set.
+fn Missing(Policy:&str) -> Life {
+	let Fate = Config::builder().set_override("hooks.missing", Policy).unwrap().build().unwrap();
+
+	Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build()
+}
+
+#[tokio::test]
+async fn Ignore() {
+	let Life = Life(&[]);
+
+	Record(&Life, &["audit.read"], &[]);
+
+	let mut Subscription = Life.Subscribe();
+
+	let (Action, Ran) = Counted(json!(["audit.read", "audit.wirte"]), None);
+
+	assert_eq!(Life.MissingHook(), MissingHook::Ignore);
+
+	assert_eq!(Action.Execute(&Life).await, Ok(()));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	assert!(timeout(Duration::from_millis(50), Subscription.Recv()).await.is_err());
+}
+
+#[tokio::test]
+async fn Warn() {
+	let Life = Missing("warn");
+
+	let mut Subscription = Life.Subscribe();
+
+	let (Action, Ran) = Counted(json!(["audit.wirte"]), None);
+
+	assert_eq!(Action.Execute(&Life).await, Ok(()));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	let Event = timeout(Duration::from_secs(1), Subscription.Recv()).await.unwrap();
+
+	assert!(matches!(
+		Event,
+		Some(Event::MissingHook { Sequence:None, Hook }) if Hook == "audit.wirte"
+	));
+}
+
+#[tokio::test]
+async fn Fail() {
+	let Life = Life::Builder().WithMissingHook(MissingHook::Fail).Build();
+
+	let (Action, Ran) = Counted(json!(["audit.wirte"]), None);
+
+	assert_eq!(Action.Execute(&Life).await, Err(Error::UnknownHook("audit.wirte".to_string())));
+
+	// The function never ran
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn Override() {
+	// The builder setting beats the configuration
+	let Built = Life::Builder()
+		.WithFate(Arc::new(
+			Config::builder().set_override("hooks.missing", "fail").unwrap().build().unwrap(),
+		))
+		.WithMissingHook(MissingHook::Warn)
+		.Build();
+
+	assert_eq!(Built.MissingHook(), MissingHook::Warn);
+
+	// The action's metadata beats both
+	let Strict = Missing("fail");
+
+	let (Action, Ran) = Counted(json!(["gone"]), Some("ignore"));
+
+	assert_eq!(Action.Execute(&Strict).await, Ok(()));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	let Lenient = Missing("ignore");
+
+	let (Action, Ran) = Counted(json!(["gone"]), Some("FAIL"));
+
+	assert_eq!(Action.Execute(&Lenient).await, Err(Error::UnknownHook("gone".to_string())));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+
+	let (Action, _) = Counted(json!(["gone"]), Some("sometimes"));
+
+	assert!(matches!(Action.Execute(&Lenient).await, Err(Error::Execution(_))));
+
+	// The older switch still means `Fail`
+	assert_eq!(Life(&[("hooks.strict", 1)]).MissingHook(), MissingHook::Fail);
+}
+
+#[tokio::test]
+async fn Reconciled() {
+	let Life = Missing("ignore");
+
+	let (Action, _) = Counted(json!(["gone"]), None);
+
+	let Plan = Action.Plan.clone();
+
+	drop(Life.Submit(Box::new(Action)).await);
+
+	// Validation reports the missing hook whatever the runtime policy
+	let Report = Life
+		.ReconcileWith(&Plan, &Reconcile::New().WithPolicy(Class::MissingHook, Policy::Keep))
+		.await;
+
+	assert_eq!(Report.MissingHook, 1);
+
+	assert_eq!(Report.Kept, 1);
+}
+
+/// An action running `Work` with the given `Hooks` metadata, whose function
+/// records its call next to the hooks and fails if `Fail` is set.
+fn Phased(Called:&Arc<Mutex<Vec<String>>>, Hooks:Value, Fail:bool) -> Action<Value> {
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Work"))
+		.WithFunction("Work", {
+			let Called = Called.clone();
+
+			move |_| {
+				Called.lock().unwrap().push("Work".to_string());
+
+				async move {
+					if Fail {
+						Err(Error::Execution("worn out".to_string()))
+					} else {
+						Ok(Value::Null)
+					}
+				}
+			}
+		})
+		.unwrap()
+		.Build();
+
+	Action::New("Work", Value::Null, Arc::new(Plan)).WithMetadata("Hooks", Hooks)
+}
+
+#[tokio::test]
+async fn Phases() {
+	let Life = Life(&[]);
+
+	let Called = Record(&Life, &["start", "end", "alert"], &[]);
+
+	let Hooks = json!({ "Before": ["start"], "After": ["end"], "OnError": ["alert"] });
+
+	assert_eq!(Phased(&Called, Hooks.clone(), false).Execute(&Life).await, Ok(()));
+
+	// OnError hooks do not run on success
+	assert_eq!(*Called.lock().unwrap(), ["start", "Work", "end"]);
+
+	Called.lock().unwrap().clear();
+
+	assert_eq!(
+		Phased(&Called, Hooks, true).Execute(&Life).await,
+		Err(Error::Execution("worn out".to_string()))
+	);
+
+	// After hooks do not run on failure
+	assert_eq!(*Called.lock().unwrap(), ["start", "Work", "alert"]);
+}
+
+#[tokio::test]
+async fn Failed() {
+	let Life = Life(&[]);
+
+	let Called = Record(&Life, &["alert"], &[]);
+
+	Life.Span
+		.insert("broken".to_string(), Arc::new(|| Err(Error::Execution("broken".to_string()))));
+
+	// A failing OnError hook keeps the original error
+	let Hooks = json!({ "OnError": ["broken", "alert"] });
+
+	assert_eq!(
+		Phased(&Called, Hooks, true).Execute(&Life).await,
+		Err(Error::Execution("worn out".to_string()))
+	);
+
+	// A failing Before hook fails the action before its function runs
+	Called.lock().unwrap().clear();
+
+	let Hooks = json!({ "Before": ["broken"], "OnError": ["alert"] });
+
+	assert_eq!(
+		Phased(&Called, Hooks, false).Execute(&Life).await,
+		Err(Error::Execution("broken".to_string()))
+	);
+
+	assert_eq!(*Called.lock().unwrap(), ["alert"]);
+
+	// So does an unknown phase
+	let Outcome = Phased(&Called, json!({ "Afterwards": ["alert"] }), false).Execute(&Life).await;
+
+	assert!(matches!(Outcome, Err(Error::Execution(_))), "{:?}", Outcome);
+}
+
+#[tokio::test]
+async fn Array() {
+	let Life = Life(&[]);
+
+	let Called = Record(&Life, &["start", "end"], &[]);
+
+	// The array form is run before the function
+	assert_eq!(Phased(&Called, json!(["start", "end"]), false).Execute(&Life).await, Ok(()));
+
+	assert_eq!(*Called.lock().unwrap(), ["start", "end", "Work"]);
+
+	// Reconciling reads every phase
+	let Lenient = Missing("ignore");
+
+	let (Action, _) = Counted(json!({ "After": ["gone"] }), None);
+
+	let Plan = Action.Plan.clone();
+
+	drop(Lenient.Submit(Box::new(Action)).await);
+
+	assert_eq!(Lenient.Reconcile(&Plan).await.MissingHook, 1);
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+		Mutex,
+	},
+	time::Duration,
+};
+
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{
+			Action::Error::Enum as Error,
+			MissingHook::Enum as MissingHook,
+			Reconcile::{Class::Enum as Class, Policy::Enum as Policy},
+		},
+	},
+	Struct::Sequence::{
+		Action::{Signature::Struct as Signature, Struct as Action},
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Reconcile::Struct as Reconcile,
+	},
+};
@@ -0,0 +1,201 @@
+#![allow(non_snake_case)]
+
+//! Checks the built-in `HttpRequest` action against a local server: a
+//! response comes back with its status, its allowed headers and its body up
+//! to the cap, a JSON body is sent as JSON, a 500 fails the action unless
+//! it is wanted as data, and timeouts, URLs outside the allow-list and
+//! redirects past the limit fail it.
+
+/// Serves one canned response per path, echoing what a `POST` sent.
+async fn Serve() -> String {
+	let Listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+	let Address = Listener.local_addr().unwrap();
+
+	tokio::spawn(async move {
+		while let Ok((Stream, _)) = Listener.accept().await {
+			tokio::spawn(Answer(Stream));
+		}
+	});
+
+	format!("http://{}", Address)
+}
+
+/// Answers a single request.
+async fn Answer(Stream:TcpStream) {
+	let mut Stream = BufReader::new(Stream);
+
+	let mut Line = String::new();
+
+	Stream.read_line(&mut Line).await.unwrap();
+
+	let (Method, Path) = {
+		let mut Part = Line.split_whitespace();
+
+		(Part.next().unwrap().to_string(), Part.next().unwrap().to_string())
+	};
+
+	let (mut Length, mut Type) = (0, String::new());
+
+	loop {
+		let mut Header = String::new();
+
+		Stream.read_line(&mut Header).await.unwrap();
+
+		if Header.trim().is_empty() {
+			break;
+		}
+
+		let (Name, Value) = Header.split_once(':').unwrap();
+
+		match Name.to_ascii_lowercase().as_str() {
+			"content-length" => Length = Value.trim().parse().unwrap(),
+			"content-type" => Type = Value.trim().to_string(),
+			_ => {},
+		}
+	}
+
+	let mut Body = vec![0; Length];
+
+	Stream.read_exact(&mut Body).await.unwrap();
+
+	let (Status, Extra, Body) = match Path.as_str() {
+		"/ok" => ("200 OK", "X-Secret: hidden\r\n".to_string(), "hello".to_string()),
+		"/echo" => (
+			"200 OK",
+			String::new(),
+			format!("{} {} {}", Method, Type, String::from_utf8_lossy(&Body)),
+		),
+		"/fail" => ("500 Internal Server Error", String::new(), "broken".to_string()),
+		"/slow" => {
+			tokio::time::sleep(Duration::from_secs(10)).await;
+
+			("200 OK", String::new(), String::new())
+		},
+		"/moved" => ("302 Found", "Location: /ok\r\n".to_string(), String::new()),
+		"/loop" => ("302 Found", "Location: /loop\r\n".to_string(), String::new()),
+		_ => ("404 Not Found", String::new(), String::new()),
+	};
+
+	let Response = format!(
+		"HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\n{}Connection: \
+		 close\r\n\r\n{}",
+		Status,
+		Body.len(),
+		Extra,
+		Body
+	);
+
+	let _ = Stream.get_mut().write_all(Response.as_bytes()).await;
+}
+
+/// Registers `HttpRequest` with a configuration.
+fn Plan(Config:Http) -> Formality { Config.Register(Plan::New()).unwrap().Build() }
+
+/// Runs an `HttpRequest` action.
+async fn Run(Plan:&Formality, Request:Value) -> Result<Value, Error> {
+	Plan.Get("HttpRequest").unwrap()(vec![Arg::Json(Request)]).await
+}
+
+/// Whether a result is an execution error mentioning `Text`.
+fn Failed(Result:&Result<Value, Error>, Text:&str) -> bool {
+	matches!(Result, Err(Error::Execution(Message)) if Message.contains(Text))
+}
+
+#[tokio::test]
+async fn Answered() {
+	let Server = Serve().await;
+
+	let Plan = Plan(Http::default());
+
+	let Value = Run(&Plan, json!({ "url": format!("{}/ok", Server) })).await.unwrap();
+
+	assert_eq!(
+		Value,
+		json!({
+			"status": 200,
+			"headers": { "content-type": "text/plain", "content-length": "5" },
+			"body": "hello",
+			"truncated": false,
+		})
+	);
+
+	let Value = Run(&Plan, json!({ "url": format!("{}/moved", Server) })).await.unwrap();
+
+	assert_eq!(Value["body"], "hello");
+
+	let Value = Run(
+		&Plan,
+		json!({ "method": "post", "url": format!("{}/echo", Server), "body": { "Echo": 1 } }),
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(Value["body"], r#"POST application/json {"Echo":1}"#);
+
+	let Plan = self::Plan(Http { Capture:2, ..Http::default() });
+
+	let Value = Run(&Plan, json!({ "url": format!("{}/ok", Server) })).await.unwrap();
+
+	assert_eq!((&Value["body"], &Value["truncated"]), (&json!("he"), &json!(true)));
+}
+
+#[tokio::test]
+async fn Rejected() {
+	let Server = Serve().await;
+
+	let Url = json!({ "url": format!("{}/fail", Server) });
+
+	let Result = Run(&Plan(Http::default()), Url.clone()).await;
+
+	assert!(Failed(&Result, "failed with 500"), "{:?}", Result);
+
+	let Value = Run(&Plan(Http { Reject:false, ..Http::default() }), Url).await.unwrap();
+
+	assert_eq!((&Value["status"], &Value["body"]), (&json!(500), &json!("broken")));
+}
+
+#[tokio::test]
+async fn Refused() {
+	let Server = Serve().await;
+
+	let Start = Instant::now();
+
+	let Result =
+		Run(&Plan(Http::default()), json!({ "url": format!("{}/slow", Server), "timeout_ms": 200 }))
+			.await;
+
+	assert!(Failed(&Result, "timed out"), "{:?}", Result);
+
+	assert!(Start.elapsed() < Duration::from_secs(5));
+
+	let Plan =
+		self::Plan(Http { Allow:Some(vec![format!("{}/moved", Server)]), ..Http::default() });
+
+	let Result = Run(&Plan, json!({ "url": format!("{}/fail", Server) })).await;
+
+	assert!(Failed(&Result, "URL is not allowed"), "{:?}", Result);
+
+	// A redirect is checked against the allow-list as well
+	let Result = Run(&Plan, json!({ "url": format!("{}/moved", Server) })).await;
+
+	assert!(Failed(&Result, &format!("URL is not allowed: {}/ok", Server)), "{:?}", Result);
+
+	let Plan = self::Plan(Http { Redirect:2, ..Http::default() });
+
+	let Result = Run(&Plan, json!({ "url": format!("{}/loop", Server) })).await;
+
+	assert!(Failed(&Result, "Too many redirects, the limit is 2"), "{:?}", Result);
+}
+
+use serde_json::{json, Value};
+use tokio::{
+	io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+	net::{TcpListener, TcpStream},
+	time::{Duration, Instant},
+};
+use Echo::{
+	Builtin::Http::Struct as Http,
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::Plan::{Formality::Struct as Formality, Struct as Plan},
+};
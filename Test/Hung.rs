@@ -0,0 +1,128 @@
+#![allow(non_snake_case)]
+
+//! Checks the `Timeout` of an action: a function that never resolves fails
+//! with `Error::Timeout` once it ran for that long, while the `Delay` the
+//! action waits out beforehand does not count against it.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan with a `Hang` function that never resolves and a `Nap` function
+/// sleeping for 100 milliseconds.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Hang"))
+			.WithFunction("Hang", |_| pending())
+			.unwrap()
+			.WithSignature(Signature::New("Nap"))
+			.WithFunction("Nap", |_| {
+				async {
+					sleep(Duration::from_millis(100)).await;
+
+					Ok(json!("rested"))
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates an action of the given type with a `Timeout` in the `main`
+/// queue.
+fn Action(
+	Plan:&Arc<Plan>,
+	Name:&str,
+	Timeout:Value,
+) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New(Name, Value::Null, Plan.clone())
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Timeout", Timeout)
+}
+
+#[tokio::test]
+async fn Hung() {
+	let (Plan, Life) = (Plan(), Life::Builder().Build());
+
+	let Started = Instant::now();
+
+	let Outcome = timeout(
+		Duration::from_secs(5),
+		Action(&Plan, "Hang", json!("200ms")).Execute(&Life),
+	)
+	.await
+	.expect("the timeout did not apply");
+
+	let Elapsed = Started.elapsed();
+
+	assert!(matches!(Outcome, Err(Error::Timeout(_))), "{:?}", Outcome);
+
+	assert!(Elapsed >= Duration::from_millis(200), "ended after {:?}", Elapsed);
+
+	assert!(Elapsed < Duration::from_secs(1), "ended after {:?}", Elapsed);
+
+	// A timed-out attempt may be retried
+	assert!(Code::from(&Outcome.unwrap_err()).Retryable());
+
+	// Milliseconds are read as numbers
+	let Outcome = Action(&Plan, "Hang", json!(50)).Execute(&Life).await;
+
+	assert!(matches!(Outcome, Err(Error::Timeout(_))));
+}
+
+#[tokio::test]
+async fn Delayed() {
+	let Plan = Plan();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Config::builder().set_override("End", 1).unwrap().build().unwrap()))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	// The delay is longer than the timeout, the function well within it
+	let Napped = Life
+		.Submit(Box::new(Action(&Plan, "Nap", json!(250)).WithMetadata("Delay", json!(400))))
+		.await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Napped).await.unwrap(), Ok(json!("rested")));
+
+	let Hung = Life.Submit(Box::new(Action(&Plan, "Hang", json!(100)))).await;
+
+	assert!(matches!(timeout(Duration::from_secs(5), Hung).await, Ok(Err(Error::Timeout(_)))));
+
+	Sequence.Shutdown().await;
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use futures::future::pending;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout, Instant};
+use Echo::{
+	Enum::{Sequence::Action::Error::Enum as Error, Transport::Code::Enum as Code},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,200 @@
+#![allow(non_snake_case)]
+
+//! Checks idempotency keys: an action submitted again under the key of one
+//! that completed is not executed but ends with the remembered result,
+//! locally and over the wire, until `Life::ForgetIdempotency` clears the key
+//! or the `[idempotency] window` passes.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Write` function returns how many times it ran, and the
+/// count.
+fn Plan() -> (Arc<Plan>, Arc<AtomicUsize>) {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Write"))
+		.WithFunction("Write", {
+			let Ran = Ran.clone();
+
+			move |_| {
+				let Count = Ran.fetch_add(1, Ordering::SeqCst) + 1;
+
+				async move { Ok(json!(Count)) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	(Arc::new(Plan), Ran)
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life` with the
+/// given configuration.
+fn Start(Fate:Config) -> Life {
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Life
+}
+
+/// Submits a `Write` action in the `main` queue under an idempotency key
+/// and waits for its result.
+async fn Write(Life:&Life, Plan:&Arc<Plan>, Key:&str) -> Result<Value, Error> {
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Plan.clone())
+		.WithQueue("main")
+		.WithIdempotencyKey(Key);
+
+	timeout(Duration::from_secs(5), Life.Submit(Box::new(Action)).await)
+		.await
+		.unwrap()
+}
+
+#[tokio::test]
+async fn Once() {
+	let ((Plan, Ran), Life) = (Plan(), Start(Config::default()));
+
+	assert_eq!(Write(&Life, &Plan, "write-1").await, Ok(json!(1)));
+
+	assert_eq!(Write(&Life, &Plan, "write-1").await, Ok(json!(1)));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	// Another key runs
+	assert_eq!(Write(&Life, &Plan, "write-2").await, Ok(json!(2)));
+
+	assert_eq!(Life.Idempotent("write-1"), Some(json!(1)));
+}
+
+#[tokio::test]
+async fn Forgotten() {
+	let ((Plan, Ran), Life) = (Plan(), Start(Config::default()));
+
+	assert_eq!(Write(&Life, &Plan, "write").await, Ok(json!(1)));
+
+	assert!(Life.ForgetIdempotency("write"));
+
+	assert!(!Life.ForgetIdempotency("write"));
+
+	assert_eq!(Write(&Life, &Plan, "write").await, Ok(json!(2)));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn Window() {
+	let Fate = Config::builder()
+		.set_override("idempotency.window", 100)
+		.unwrap()
+		.build()
+		.unwrap();
+
+	let ((Plan, Ran), Life) = (Plan(), Start(Fate));
+
+	assert_eq!(Write(&Life, &Plan, "write").await, Ok(json!(1)));
+
+	assert_eq!(Write(&Life, &Plan, "write").await, Ok(json!(1)));
+
+	sleep(Duration::from_millis(200)).await;
+
+	assert_eq!(Write(&Life, &Plan, "write").await, Ok(json!(2)));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn Wire() {
+	let ((Plan, Ran), Life) = (Plan(), Start(Config::default()));
+
+	let Pump = Pump::New(Life, Plan);
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output)).await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let mut Value = Vec::new();
+
+	// A reconnected client sends the same write under a new identifier
+	for Id in ["1", "2"] {
+		let Submit = json!({
+			"Type": "Submit",
+			"Id": Id,
+			"Action": "Write",
+			"Options": { "Queue": "main", "IdempotencyKey": "write" },
+		});
+
+		Writer.write_all(format!("{}\n", Submit).as_bytes()).await.unwrap();
+
+		loop {
+			let Line = timeout(Duration::from_secs(10), Reader.next_line())
+				.await
+				.expect("no reply in time")
+				.unwrap()
+				.expect("stream closed");
+
+			let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+			if Reply["Type"] == "Result" {
+				Value.push((Reply["Id"].clone(), Reply["Value"].clone()));
+
+				break;
+			}
+		}
+	}
+
+	assert_eq!(Value, vec![(json!("1"), json!(1)), (json!("2"), json!(1))]);
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
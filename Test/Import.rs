@@ -0,0 +1,305 @@
+#![allow(non_snake_case)]
+
+//! Checks bulk imports: a file with invalid lines is imported across an
+//! interruption exactly once, with every refused line reported; CSV columns
+//! become arguments and metadata; and submitting waits out a full queue and
+//! keeps to its rate.
+
+/// Records what reaches the destination it wraps, and cancels the import
+/// once `Stop` lines reached it.
+struct Recording {
+	/// The destination the lines go on to.
+	Inner:Local,
+
+	/// Every line submitted, in order.
+	Seen:Arc<Mutex<Vec<Line>>>,
+
+	/// How many lines are submitted before the token is cancelled.
+	Stop:Option<(usize, Cancellation)>,
+}
+
+#[async_trait]
+impl Destination for Recording {
+	async fn Submit(&self, Line:&Line) -> Result<(), String> {
+		let Count = {
+			let mut Seen = self.Seen.lock().unwrap();
+
+			Seen.push(Line.clone());
+
+			Seen.len()
+		};
+
+		if let Some((Stop, Cancellation)) = &self.Stop {
+			if Count >= *Stop {
+				Cancellation.Cancel();
+			}
+		}
+
+		self.Inner.Submit(Line).await
+	}
+
+	async fn Depth(&self, Line:&Line) -> Option<usize> { self.Inner.Depth(Line).await }
+}
+
+/// A plan whose `Work` function returns its argument.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", |Argument:Vec<Value>| async move { Ok(Argument[0].clone()) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Copies a fixture into a fresh directory, so its checkpoint lands there.
+fn Fixture(Name:&str, Content:&str) -> PathBuf {
+	let Root = std::env::temp_dir().join(format!("Echo-Import-{}-{}", Name, std::process::id()));
+
+	let _ = std::fs::remove_dir_all(&Root);
+
+	std::fs::create_dir_all(&Root).unwrap();
+
+	let Path = Root.join(Name);
+
+	std::fs::write(&Path, Content).unwrap();
+
+	Path
+}
+
+/// The first argument of every action waiting in a line, in order.
+async fn Drain(Production:&Production) -> Vec<Value> {
+	let mut Argument = Vec::new();
+
+	while let Some(Action) = Production.Dequeue().await {
+		Argument.push(Action.Metadata("Argument").await.unwrap()[0].clone());
+	}
+
+	Argument
+}
+
+#[tokio::test]
+async fn Resumed() {
+	let Path = Fixture("Backlog.jsonl", include_str!("Import/Backlog.jsonl"));
+
+	let Plan = Plan();
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let (Seen, Cancellation) = (Arc::new(Mutex::new(Vec::new())), Cancellation::New());
+
+	let Options = Options::New()
+		.WithBatch(3)
+		.WithConcurrency(2)
+		.WithManifest(Plan.ExportManifest());
+
+	// Interrupted once the first batch submitted two lines
+	let Interrupted = Recording {
+		Inner:Local::New(Life.clone(), Plan.clone()),
+		Seen:Seen.clone(),
+		Stop:Some((2, Cancellation.clone())),
+	};
+
+	let Report =
+		Import::Run(&Path, &Interrupted, &Options.clone().WithCancellation(Cancellation))
+			.await
+			.unwrap();
+
+	assert!(!Report.Complete);
+
+	assert_eq!(Report.Accepted, 2);
+
+	assert_eq!(
+		Report.Rejected,
+		[Rejection { Line:3, Reason:"Unknown action Missing".to_string() }]
+	);
+
+	assert!(Checkpoint::Sidecar(&Path).exists());
+
+	let Resumed =
+		Recording { Inner:Local::New(Life.clone(), Plan.clone()), Seen:Seen.clone(), Stop:None };
+
+	let Report = Import::Run(&Path, &Resumed, &Options).await.unwrap();
+
+	assert!(Report.Complete);
+
+	assert_eq!(Report.Accepted, 5);
+
+	assert_eq!(
+		Report.Rejected.iter().map(|Rejection| Rejection.Line).collect::<Vec<_>>(),
+		[3, 4, 7, 9]
+	);
+
+	assert!(Report.Rejected[1].Reason.starts_with("Invalid submission"));
+
+	assert!(Report.Rejected[2].Reason.starts_with("Invalid submission"));
+
+	assert!(Report.Rejected[3].Reason.contains("absent"), "{}", Report.Rejected[3].Reason);
+
+	// Every line reached the destination once, the interruption included
+	let Key = Seen
+		.lock()
+		.unwrap()
+		.iter()
+		.map(|Line| Line.Metadata["IdempotencyKey"].as_str().unwrap().to_string())
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		Key.iter().map(|Key| Key.split('-').next().unwrap()).collect::<Vec<_>>(),
+		["1", "2", "5", "8", "9", "10"]
+	);
+
+	assert_eq!(
+		Drain(&Life.Karma.get("main").unwrap()).await,
+		[json!(1), json!(2), json!(5), json!(8), json!(10)]
+	);
+
+	// A finished import submits nothing more, and the keys do not change
+	let Report = Import::Run(&Path, &Resumed, &Options).await.unwrap();
+
+	assert_eq!(Report.Accepted, 5);
+
+	assert_eq!(Seen.lock().unwrap().len(), 6);
+
+	let Eighth = include_str!("Import/Backlog.jsonl").lines().nth(7).unwrap();
+
+	let Again = Line::Json(8, Eighth).unwrap();
+
+	assert_eq!(Again.Metadata["IdempotencyKey"].as_str().unwrap(), Key[3]);
+}
+
+#[tokio::test]
+async fn Csv() {
+	let Path = Fixture("Backlog.csv", include_str!("Import/Backlog.csv"));
+
+	let Production = Arc::new(Production::New());
+
+	let Seen = Arc::new(Mutex::new(Vec::new()));
+
+	let Destination =
+		Recording { Inner:Local::New(Production.clone(), Plan()), Seen:Seen.clone(), Stop:None };
+
+	let Report = Import::Run(&Path, &Destination, &Options::New()).await.unwrap();
+
+	assert_eq!(Report.Accepted, 3);
+
+	assert_eq!(
+		Report.Rejected,
+		[Rejection { Line:4, Reason:"Expected 4 columns, found 2".to_string() }]
+	);
+
+	let Seen = Seen.lock().unwrap().clone();
+
+	assert_eq!(Seen[0].Argument, [json!(1)]);
+
+	assert_eq!(Seen[1].Argument, [json!("a,b")]);
+
+	assert_eq!(Seen[1].Metadata["Queue"], json!("main"));
+
+	assert_eq!(Seen[1].Metadata["Priority"], json!(2));
+
+	assert!(Seen[1].Metadata["IdempotencyKey"].as_str().unwrap().starts_with("3-"));
+
+	assert!(Seen[2].Argument.is_empty());
+
+	assert_eq!(Production.Stats().Depth, 3);
+}
+
+/// Writes a JSONL fixture of `Count` `Work` lines, numbered from 1.
+fn Numbered(Name:&str, Count:u64) -> PathBuf {
+	let Content = (1..=Count)
+		.map(|Number| json!({ "Action": "Work", "Argument": [Number] }).to_string())
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	Fixture(Name, &Content)
+}
+
+#[tokio::test(start_paused = true)]
+async fn Paced() {
+	let Path = Numbered("Paced.jsonl", 6);
+
+	let Production = Arc::new(Production::New());
+
+	let Start = Instant::now();
+
+	let Report = Import::Run(
+		&Path,
+		&Local::New(Production.clone(), Plan()),
+		&Options::New().WithBatch(4).WithRate(10.0),
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(Report.Accepted, 6);
+
+	// Ten lines per second: the first at once, the sixth half a second later
+	assert!((500..600).contains(&Start.elapsed().as_millis()), "{:?}", Start.elapsed());
+}
+
+#[tokio::test(start_paused = true)]
+async fn Pressure() {
+	let Path = Numbered("Pressure.jsonl", 6);
+
+	let Production = Arc::new(Production::New());
+
+	let Destination = Local::New(Production.clone(), Plan());
+
+	let Options = Options::New().WithBatch(2).WithDepth(2, Duration::from_millis(10));
+
+	let Import = tokio::spawn(async move { Import::Run(&Path, &Destination, &Options).await });
+
+	sleep(Duration::from_secs(1)).await;
+
+	// The queue filled up to its depth, and the import waits for it
+	assert_eq!(Production.Stats().Depth, 2);
+
+	assert!(!Import.is_finished());
+
+	let mut Taken = Vec::new();
+
+	while Taken.len() < 6 {
+		Taken.extend(Drain(&Production).await);
+
+		sleep(Duration::from_millis(100)).await;
+
+		assert!(Production.Stats().Depth <= 2);
+	}
+
+	assert_eq!(Import.await.unwrap().unwrap().Accepted, 6);
+
+	// Lines submitting at the same time may land in either order
+	Taken.sort_by_key(|Number| Number.as_u64());
+
+	assert_eq!(Taken, (1..=6).map(|Number| json!(Number)).collect::<Vec<_>>());
+}
+
+use std::{
+	path::PathBuf,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::time::{sleep, Instant};
+use Echo::{
+	Import::{
+		self,
+		Checkpoint::Struct as Checkpoint,
+		Line::Struct as Line,
+		Local::Struct as Local,
+		Options::Struct as Options,
+		Report::Rejection::Struct as Rejection,
+	},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Cancellation::Struct as Cancellation,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+	},
+	Trait::Import::Destination::Trait as Destination,
+};
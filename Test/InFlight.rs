@@ -0,0 +1,214 @@
+#![allow(non_snake_case)]
+
+//! Checks the listing of the actions executing right now: a slow action is
+//! listed with its worker, attempt and deadline while it runs and gone once
+//! it completes, both from its sequence and over a pump, and a drain whose
+//! deadline expires reports it as abandoned.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Slow` function waits until `Gate` is notified.
+fn Plan(Gate:Arc<Notify>) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Slow"))
+			.WithFunction("Slow", move |_| {
+				let Gate = Gate.clone();
+
+				async move {
+					Gate.notified().await;
+
+					Ok(json!("done"))
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Waits until an action is in flight.
+async fn Listed(Sequence:&Sequence) -> Vec<InFlight> {
+	timeout(Duration::from_secs(10), async {
+		loop {
+			let InFlight = Sequence.InFlight();
+
+			if !InFlight.is_empty() {
+				return InFlight;
+			}
+
+			sleep(Duration::from_millis(5)).await;
+		}
+	})
+	.await
+	.expect("nothing in flight")
+}
+
+#[tokio::test]
+async fn Executing() {
+	let Gate = Arc::new(Notify::new());
+
+	let Sequence =
+		Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life::Builder().Build());
+
+	Sequence
+		.Queue()
+		.Enqueue(Box::new(
+			Echo::Struct::Sequence::Action::Struct::New("Slow", Value::Null, Plan(Gate.clone()))
+				.WithMetadata("Timeout", json!(60_000)),
+		))
+		.await
+		.unwrap();
+
+	assert!(Sequence.InFlight().is_empty());
+
+	let Task = tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.ConsumeOne().await }
+	});
+
+	let InFlight = Listed(&Sequence).await;
+
+	assert_eq!(InFlight.len(), 1);
+
+	let Entry = &InFlight[0];
+
+	assert_eq!((Entry.Sequence, Entry.Action.as_str(), Entry.Worker), (0, "Slow", 0));
+
+	assert_eq!(Entry.Attempt, 1);
+
+	assert!(!Entry.Stuck);
+
+	// The deadline is the timeout past the start of the attempt
+	let Deadline = Entry.Deadline.expect("no deadline");
+
+	assert!((Entry.Started + 59_000..=Entry.Started + 61_000).contains(&Deadline));
+
+	// The listing is what the wire carries
+	assert_eq!(serde_json::to_value(Entry).unwrap()["Action"], "Slow");
+
+	Gate.notify_one();
+
+	assert!(matches!(Task.await.unwrap(), Some(Ok(()))));
+
+	assert!(Sequence.InFlight().is_empty());
+}
+
+#[tokio::test]
+async fn Queried() {
+	let Gate = Arc::new(Notify::new());
+
+	let Plan = Plan(Gate.clone());
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let (Client, Server) = duplex(1 << 16);
+
+	let Pump = Pump::New(Life, Plan).WithAdmin("admin");
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	for Message in [
+		json!({ "Type": "Auth", "Token": "admin" }),
+		json!({ "Type": "Submit", "Id": "1", "Action": "Slow", "Metadata": { "Queue": "main" } }),
+	] {
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+	}
+
+	Listed(&Sequence).await;
+
+	let mut Reply = async |Message:Value| {
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+
+		loop {
+			let Line = timeout(Duration::from_secs(10), Reader.next_line())
+				.await
+				.expect("no reply in time")
+				.unwrap()
+				.expect("stream closed");
+
+			let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+			if Reply["Type"] == Message["Type"] {
+				return Reply;
+			}
+		}
+	};
+
+	let InFlight = Reply(json!({ "Type": "InFlight" })).await;
+
+	assert_eq!(InFlight["Entry"][0]["Action"], "Slow");
+
+	assert_eq!(InFlight["Entry"][0]["Attempt"], 1);
+
+	// The action outlives the drain deadline and is reported abandoned
+	let Control = Reply(json!({ "Type": "Control", "Control": "Drain", "DeadlineMs": 50 })).await;
+
+	assert_eq!(Control["Drain"]["Drained"], false);
+
+	assert_eq!(Control["Drain"]["Remaining"], 1);
+
+	assert_eq!(Control["Drain"]["Abandoned"][0]["Action"], "Slow");
+
+	Gate.notify_one();
+
+	timeout(Duration::from_secs(10), async {
+		while !Sequence.InFlight().is_empty() {
+			sleep(Duration::from_millis(5)).await;
+		}
+	})
+	.await
+	.expect("the action never completed");
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	sync::Notify,
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+			Watchdog::InFlight::Struct as InFlight,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
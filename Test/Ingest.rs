@@ -0,0 +1,222 @@
+#![allow(non_snake_case)]
+
+//! Checks the shared ingestion stage: bursts pushed by two transports are
+//! held to each transport's quota, every job learns its verdict and replies
+//! its acknowledgement or refusal, and a pump pushing through the stage
+//! still acknowledges a submission before its result.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Work` function returns its argument.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", |Argument:Vec<Value>| async move { Ok(Argument[0].clone()) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// A `Life` with a `main` queue.
+fn Life() -> Life {
+	Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build()
+}
+
+/// A `Work` job for a queue, replying on `Reply`.
+fn Job(Id:&str, Queue:&str, Reply:&UnboundedSender<Reply>) -> Job {
+	let mut Metadata = Map::new();
+
+	Metadata.insert("Queue".to_string(), json!(Queue));
+
+	Job::New(
+		Id.to_string(),
+		"Work".to_string(),
+		vec![json!(Id).into()],
+		Metadata,
+		Plan(),
+		Reply.clone(),
+	)
+}
+
+#[tokio::test]
+async fn Quota() {
+	let Life = Life();
+
+	let Ingest = Ingest::New(Life.clone(), 64)
+		.WithQuota("tcp", 3)
+		.WithQuota("stdio", 2);
+
+	let (Sender, mut Receiver) = unbounded_channel();
+
+	// Both bursts reach the channel before the routing task runs
+	let Verdict = join_all((0..5).flat_map(|Index| {
+		[("tcp", format!("tcp-{}", Index)), ("stdio", format!("stdio-{}", Index))].map(
+			|(Transport, Id)| {
+				let (Ingest, Job) = (&Ingest, Job(&Id, "main", &Sender));
+
+				async move { (Id, Ingest.Push(Transport, "client", Job).await) }
+			},
+		)
+	}))
+	.await;
+
+	let Accepted = Verdict
+		.iter()
+		.filter(|(_, Verdict)| Verdict.is_ok())
+		.map(|(Id, _)| Id.as_str())
+		.collect::<Vec<_>>();
+
+	assert_eq!(Accepted, ["tcp-0", "stdio-0", "tcp-1", "stdio-1", "tcp-2"]);
+
+	for (Id, Verdict) in &Verdict {
+		if let Err((Code, Message)) = Verdict {
+			assert_eq!(*Code, Code::NotAccepting, "{} refused: {}", Id, Message);
+		}
+	}
+
+	assert_eq!(Life.Stats().Queue["main"].Depth, 5);
+
+	assert_eq!((Ingest.Waiting("tcp"), Ingest.Waiting("stdio")), (0, 0));
+
+	drop(Sender);
+
+	let mut Ack = Vec::new();
+
+	let mut Refused = Vec::new();
+
+	while let Ok(Reply) = Receiver.try_recv() {
+		match Reply {
+			Reply::Ack { Id } => Ack.push(Id),
+			Reply::Error { Id: Some(Id), Code: Some(Code::NotAccepting), .. } => Refused.push(Id),
+			Other => panic!("unexpected reply {:?}", Other),
+		}
+	}
+
+	Ack.sort();
+
+	Refused.sort();
+
+	assert_eq!(Ack, ["stdio-0", "stdio-1", "tcp-0", "tcp-1", "tcp-2"]);
+
+	assert_eq!(Refused, ["stdio-2", "stdio-3", "stdio-4", "tcp-3", "tcp-4"]);
+
+	// Once routed, the transport's jobs are admitted again
+	let (Sender, mut Receiver) = unbounded_channel();
+
+	assert_eq!(Ingest.Push("tcp", "client", Job("tcp-5", "main", &Sender)).await, Ok(()));
+
+	let Verdict = Ingest
+		.Push("stdio", "client", Job("stdio-5", "missing", &Sender))
+		.await;
+
+	assert!(matches!(Verdict, Err((Code::Routing, _))), "{:?}", Verdict);
+
+	assert!(matches!(Receiver.recv().await, Some(Reply::Ack { Id }) if Id == "tcp-5"));
+
+	assert!(matches!(
+		Receiver.recv().await,
+		Some(Reply::Error { Id: Some(Id), Code: Some(Code::Routing), .. }) if Id == "stdio-5"
+	));
+}
+
+#[tokio::test]
+async fn Pumped() {
+	let Life = Life();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Ingest = Arc::new(Ingest::New(Life.clone(), 16));
+
+	let Pump = Pump::New(Life.clone(), Plan()).WithIngest(Ingest, "memory");
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	for (Id, Queue) in [("1", "main"), ("2", "missing")] {
+		let Message = json!({
+			"Type": "Submit",
+			"Id": Id,
+			"Action": "Work",
+			"Argument": [Id],
+			"Metadata": { "Queue": Queue },
+		});
+
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+	}
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let mut Reply = Vec::new();
+
+	while Reply.len() < 3 {
+		let Line = timeout(Duration::from_secs(10), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Value:Value = serde_json::from_str(&Line).unwrap();
+
+		Reply.push((Value["Type"].as_str().unwrap().to_string(), Value["Id"].clone()));
+	}
+
+	let First = Reply.iter().filter(|(_, Id)| *Id == "1").cloned().collect::<Vec<_>>();
+
+	assert_eq!(First, [("Ack".to_string(), json!("1")), ("Result".to_string(), json!("1"))]);
+
+	assert!(Reply.contains(&("Error".to_string(), json!("2"))));
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde_json::{json, Map, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	sync::mpsc::{unbounded_channel, UnboundedSender},
+	time::timeout,
+};
+use Echo::{
+	Enum::{
+		Sequence::Action::Error::Enum as Error,
+		Transport::{Code::Enum as Code, Reply::Enum as Reply},
+	},
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{
+			Frame::Line,
+			Ingest::Struct as Ingest,
+			Job::Struct as Job,
+			Pump::Struct as Pump,
+		},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,133 @@
+#![allow(non_snake_case)]
+
+//! Checks the invocation a plan function sees: under a 200ms action timeout
+//! it has a deadline at most 200ms away, and an action failing its first
+//! attempt sees the attempt number increase on the retry while its id stays
+//! the same.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// What a function saw of its invocation.
+#[derive(Debug)]
+struct Seen {
+	/// The `ActionId` of the invocation.
+	Id:u64,
+
+	/// The `Attempt` of the invocation.
+	Attempt:u32,
+
+	/// The `RemainingTime` of the invocation.
+	Remaining:Option<Duration>,
+
+	/// Whether the invocation was cancelled.
+	Cancelled:bool,
+}
+
+// Paused, so the backoff before the retry passes at once
+#[tokio::test(start_paused = true)]
+async fn Attempt() {
+	let Record = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Bounded"))
+			.WithFunction("Bounded", {
+				let Record = Record.clone();
+
+				move |_| {
+					let Invocation = Invocation::Current().unwrap();
+
+					let mut Record = Record.lock().unwrap();
+
+					Record.push(Seen {
+						Id:Invocation.ActionId(),
+						Attempt:Invocation.Attempt(),
+						Remaining:Invocation.RemainingTime(),
+						Cancelled:Invocation.IsCancelled(),
+					});
+
+					let First = Record.len() == 1;
+
+					async move {
+						if First {
+							Err(Error::Execution("not yet".to_string()))
+						} else {
+							Ok(json!("done"))
+						}
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Fate = Config::builder().set_override("End", 2).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Bounded", Value::Null, Plan)
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Timeout", json!("200ms"));
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	assert_eq!(timeout(Duration::from_secs(30), Pending).await.unwrap(), Ok(json!("done")));
+
+	let Record = Record.lock().unwrap();
+
+	assert_eq!(Record.len(), 2, "{:?}", Record);
+
+	assert_eq!(Record.iter().map(|Seen| Seen.Attempt).collect::<Vec<_>>(), [1, 2]);
+
+	assert_eq!(Record[0].Id, Record[1].Id);
+
+	for Seen in Record.iter() {
+		let Remaining = Seen.Remaining.expect("no deadline under a timeout");
+
+		assert!(Remaining > Duration::ZERO, "{:?}", Seen);
+
+		assert!(Remaining <= Duration::from_millis(200), "{:?}", Seen);
+
+		assert!(!Seen.Cancelled);
+	}
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Invocation::Struct as Invocation,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
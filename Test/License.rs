@@ -0,0 +1,190 @@
+#![allow(non_snake_case)]
+
+//! Checks the license of actions: a `Life` built `WithLicenseValidator`
+//! refuses an action past its `Expires` timestamp with
+//! `Error::InvalidLicense` before its function runs, and one without a
+//! validator still reads the `License` signal of the action.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan with a `Count` function counting its calls, and the count.
+fn Plan() -> (Arc<Plan>, Arc<AtomicUsize>) {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Count"))
+		.WithFunction("Count", {
+			let Ran = Ran.clone();
+
+			move |_| {
+				Ran.fetch_add(1, Ordering::SeqCst);
+
+				async { Ok(json!("counted")) }
+			}
+		})
+		.unwrap()
+		.Build();
+
+	(Arc::new(Plan), Ran)
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life`, with the
+/// given validator if any.
+fn Start(Validator:Option<Arc<dyn LicenseValidator>>) -> Life {
+	// A single attempt, so a failure is final
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let mut Builder = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New());
+
+	if let Some(Validator) = Validator {
+		Builder = Builder.WithLicenseValidator(Validator);
+	}
+
+	let Life = Builder.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Life
+}
+
+/// Creates a `Count` action in the `main` queue.
+fn Count(Plan:&Arc<Plan>) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New("Count", Value::Null, Plan.clone())
+		.WithMetadata("Queue", json!("main"))
+}
+
+/// The Unix milliseconds of now, shifted by the given offset.
+fn Millis(Offset:i64) -> i64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64 + Offset
+}
+
+#[tokio::test]
+async fn Expired() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Start(Some(Arc::new(Expiry::New())));
+
+	let Pending = Life
+		.Submit(Box::new(Count(&Plan).WithMetadata("Expires", json!(Millis(-60_000)))))
+		.await;
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert!(matches!(Outcome, Err(Error::InvalidLicense(_))), "{:?}", Outcome);
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn Unexpired() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Start(Some(Arc::new(Expiry::New())));
+
+	let Pending = Life
+		.Submit(Box::new(Count(&Plan).WithMetadata("Expires", json!(Millis(60_000)))))
+		.await;
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert_eq!(Outcome, Ok(json!("counted")));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn Missing() {
+	let (Plan, Ran) = Plan();
+
+	let Life = Start(Some(Arc::new(Expiry::New())));
+
+	for Expires in [None, Some(json!("tomorrow"))] {
+		let mut Action = Count(&Plan);
+
+		if let Some(Expires) = Expires {
+			Action = Action.WithMetadata("Expires", Expires);
+		}
+
+		let Outcome = timeout(Duration::from_secs(5), Life.Submit(Box::new(Action)).await)
+			.await
+			.unwrap();
+
+		assert!(matches!(Outcome, Err(Error::InvalidLicense(_))), "{:?}", Outcome);
+	}
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn Signal() {
+	let (Plan, Ran) = Plan();
+
+	// The validator decides, whatever the signal says
+	let Life = Start(Some(Arc::new(AlwaysValid)));
+
+	let Action = Count(&Plan);
+
+	Action.License.Set(false).await;
+
+	let Outcome = timeout(Duration::from_secs(5), Life.Submit(Box::new(Action)).await)
+		.await
+		.unwrap();
+
+	assert_eq!(Outcome, Ok(json!("counted")));
+
+	// Without one, the signal does
+	let Life = Start(None);
+
+	let Action = Count(&Plan);
+
+	Action.License.Set(false).await;
+
+	let Outcome = timeout(Duration::from_secs(5), Life.Submit(Box::new(Action)).await)
+		.await
+		.unwrap();
+
+	assert!(matches!(Outcome, Err(Error::License(_))), "{:?}", Outcome);
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		License::{AlwaysValid::Struct as AlwaysValid, Expiry::Struct as Expiry},
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{
+		Action::Trait as Action,
+		LicenseValidator::Trait as LicenseValidator,
+		Site::Trait as Site,
+	},
+};
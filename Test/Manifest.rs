@@ -0,0 +1,132 @@
+#![allow(non_snake_case)]
+
+//! Checks plan manifests: a built plan exports its signatures without their
+//! functions, the export survives serialization, and validating a plan
+//! against a manifest pinpoints every action missing, added or changed.
+
+/// Doubles a number.
+struct Double;
+
+impl Handler for Double {
+	type Request = u64;
+
+	type Response = u64;
+
+	fn Kind() -> &'static str { "Double" }
+
+	async fn Run(&self, Request:u64) -> Result<u64, Error> { Ok(Request * 2) }
+}
+
+/// A plan with a typed `Double`, a blocking `Spin` and an untyped `Echo`.
+fn Plan() -> Plan {
+	Echo::Struct::Sequence::Plan::Struct::New()
+		.WithHandler(Double)
+		.unwrap()
+		.WithSignature(Signature::New("Spin").WithBlocking(true))
+		.WithFunction("Spin", |_| async { Ok(Value::Null) })
+		.unwrap()
+		.WithSignature(Signature::New("Echo"))
+		.WithFunction("Echo", |Argument:Vec<Value>| async move { Ok(json!(Argument)) })
+		.unwrap()
+		.Build()
+}
+
+#[test]
+fn Export() {
+	let Manifest = Plan().ExportManifest();
+
+	assert_eq!(
+		serde_json::to_value(&Manifest).unwrap(),
+		json!({
+			"Action": [
+				{ "Name": "Double", "Input": "u64", "Output": "u64" },
+				{ "Name": "Echo" },
+				{ "Name": "Spin", "Blocking": true },
+			],
+		})
+	);
+
+	let Read:Manifest = serde_json::from_str(&serde_json::to_string(&Manifest).unwrap()).unwrap();
+
+	assert_eq!(Read, Manifest);
+
+	// A signature without a function is not provided
+	let Plan = Plan();
+
+	Plan.Extend(Signature::New("Later"), |_| async { Ok(Value::Null) });
+
+	Plan.Remove("Later");
+
+	assert_eq!(Plan.ExportManifest(), Manifest);
+
+	assert!(Plan.ValidateAgainstManifest(&Manifest).Empty());
+}
+
+#[test]
+fn Changed() {
+	let mut Manifest = Plan().ExportManifest();
+
+	Manifest.Action[0].Output = Some("i64".to_string());
+
+	let Diff = Plan().ValidateAgainstManifest(&Manifest);
+
+	assert!(!Diff.Compatible());
+
+	assert!(Diff.Missing.is_empty() && Diff.Extra.is_empty());
+
+	assert_eq!(
+		Diff.Changed,
+		[Change {
+			Action:"Double".to_string(),
+			Field:"Output".to_string(),
+			Expected:json!("i64"),
+			Found:json!("u64"),
+		}]
+	);
+
+	assert_eq!(Diff.to_string(), "~ Double.Output: \"i64\" -> \"u64\"\n");
+}
+
+#[test]
+fn Missing() {
+	let mut Manifest = Plan().ExportManifest();
+
+	// The manifest expects an action the plan lacks and not one it has
+	Manifest.Action.retain(|Signature| Signature.Name != "Echo");
+
+	Manifest.Action.push(Signature::New("Resize"));
+
+	// A manifest leaving a type unknown accepts any
+	Manifest.Action[0].Input = None;
+
+	let Diff = Plan().ValidateAgainstManifest(&Manifest);
+
+	assert_eq!(
+		(Diff.Missing.as_slice(), Diff.Extra.as_slice()),
+		(&["Resize".to_string()][..], &["Echo".to_string()][..])
+	);
+
+	assert!(Diff.Changed.is_empty());
+
+	assert!(!Diff.Compatible());
+
+	assert_eq!(Diff.to_string(), "- Resize\n+ Echo\n");
+
+	// Extra actions alone keep the plan compatible
+	Manifest.Action.retain(|Signature| Signature.Name != "Resize");
+
+	assert!(Plan().ValidateAgainstManifest(&Manifest).Compatible());
+}
+
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Plan::{
+			Formality::Struct as Plan,
+			Manifest::{Change::Struct as Change, Struct as Manifest},
+		},
+	},
+	Trait::Sequence::Handler::Trait as Handler,
+};
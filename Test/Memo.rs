@@ -0,0 +1,175 @@
+#![allow(non_snake_case)]
+
+//! Checks the results of `Cacheable` actions: served as they are within
+//! their soft TTL, served while a single background execution refreshes
+//! them up to their hard TTL, and waited for past it, with a failed refresh
+//! keeping the stale result.
+
+/// A plan with a `Dashboard` function returning how often it ran, after
+/// sleeping for `Pause` and failing while `Failing` is set, and the count.
+fn Plan(Pause:Duration, Failing:Arc<AtomicBool>) -> (Arc<Plan>, Arc<AtomicUsize>) {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(
+			Signature::New("Dashboard")
+				.WithCacheable(Duration::from_secs(1), Duration::from_secs(5)),
+		)
+		.WithFunction("Dashboard", {
+			let Ran = Ran.clone();
+
+			move |_| {
+				let (Ran, Failing) = (Ran.clone(), Failing.clone());
+
+				async move {
+					sleep(Pause).await;
+
+					if Failing.load(Ordering::SeqCst) {
+						return Err(Error::Execution("Dashboard is down".to_string()));
+					}
+
+					Ok(json!(Ran.fetch_add(1, Ordering::SeqCst) + 1))
+				}
+			}
+		})
+		.unwrap()
+		.Build();
+
+	(Arc::new(Plan), Ran)
+}
+
+/// Executes a `Dashboard` action with an argument and returns its result.
+async fn Dashboard(Plan:&Arc<Plan>, Life:&Life, Argument:Value) -> Value {
+	let Action = Action::New("Dashboard", Argument, Plan.clone());
+
+	Action.Execute(Life).await.unwrap();
+
+	Action.Metadata.Get("Result").await.unwrap()
+}
+
+/// Lets the background refreshes run.
+async fn Settle() { sleep(Duration::from_millis(1)).await; }
+
+#[tokio::test(start_paused = true)]
+async fn Windows() {
+	let (Plan, Ran) = Plan(Duration::ZERO, Arc::default());
+
+	let Life = Life::Builder().Build();
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	// Within the soft TTL the result is served as it is
+	advance(Duration::from_millis(500)).await;
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	Settle().await;
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	// Other arguments are kept apart
+	assert_eq!(Dashboard(&Plan, &Life, json!("other")).await, json!(2));
+
+	// Past it the stale result is served while it is refreshed
+	advance(Duration::from_secs(2)).await;
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	Settle().await;
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 3);
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(3));
+
+	// Past the hard TTL the caller waits for a fresh result
+	advance(Duration::from_secs(6)).await;
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(4));
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn SingleFlight() {
+	let (Plan, Ran) = Plan(Duration::from_millis(100), Arc::default());
+
+	let Life = Life::Builder().Build();
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	advance(Duration::from_secs(2)).await;
+
+	// Concurrent stale hits are all served at once, and refresh once
+	let Started = Instant::now();
+
+	let Served = join_all((0..8).map(|_| Dashboard(&Plan, &Life, json!("main")))).await;
+
+	assert_eq!(Started.elapsed(), Duration::ZERO);
+
+	assert!(Served.iter().all(|Served| *Served == json!(1)), "{:?}", Served);
+
+	sleep(Duration::from_millis(200)).await;
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 2);
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn Failed() {
+	let Failing = Arc::new(AtomicBool::new(false));
+
+	let (Plan, Ran) = Plan(Duration::ZERO, Failing.clone());
+
+	let Life = Life::Builder().Build();
+
+	let mut Subscription = Life.Subscribe();
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	advance(Duration::from_secs(2)).await;
+
+	Failing.store(true, Ordering::SeqCst);
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	match timeout(Duration::from_secs(1), Subscription.Recv()).await.unwrap() {
+		Some(Event::RefreshFailed { Action, Error }) => {
+			assert_eq!(Action, "Dashboard");
+
+			assert!(Error.contains("Dashboard is down"), "{}", Error);
+		},
+		Other => panic!("expected RefreshFailed, got {:?}", Other),
+	}
+
+	// The stale result is kept, and the next stale hit tries again
+	Failing.store(false, Ordering::SeqCst);
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(1));
+
+	Settle().await;
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 2);
+
+	assert_eq!(Dashboard(&Plan, &Life, json!("main")).await, json!(2));
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicBool, AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use futures::future::join_all;
+use serde_json::{json, Value};
+use tokio::time::{advance, sleep, timeout, Instant};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::Sequence::{
+		Action::{Signature::Struct as Signature, Struct as Action},
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+	},
+};
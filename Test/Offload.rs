@@ -0,0 +1,237 @@
+#![allow(non_snake_case)]
+
+//! Checks the offloading of large results into a `ResultStore`: results
+//! above the threshold reach the client as `$stored` markers it resolves
+//! with `Fetch`, small ones and those the store refuses travel inline, and
+//! stored results are deleted once past their retention.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Refuses every body.
+struct Refusing;
+
+#[async_trait]
+impl ResultStore for Refusing {
+	async fn Put(&self, _Id:&str, _Body:Vec<u8>, _ContentType:&str) -> Result<Stored, String> {
+		Err("Bucket unreachable".to_string())
+	}
+
+	async fn Get(&self, Stored:&Stored) -> Result<Vec<u8>, String> {
+		Err(format!("No stored result {}", Stored.Ref))
+	}
+
+	async fn Delete(&self, _Stored:&Stored) -> Result<(), String> { Ok(()) }
+}
+
+/// The result of a `Large` action.
+fn Large() -> Value { json!({ "Data": "x".repeat(4096) }) }
+
+/// A pump over a running `Life` offloading results above 1 KiB into `Store`,
+/// with a `Large` action and a `Small` one.
+fn Start(Store:Arc<dyn ResultStore>) -> Pump {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Large"))
+			.WithFunction("Large", |_| async { Ok(Large()) })
+			.unwrap()
+			.WithSignature(Signature::New("Small"))
+			.WithFunction("Small", |_| async { Ok(json!("small")) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Pump::New(Life, Plan).WithOffload(Arc::new(Offload::New(Store, 1024)))
+}
+
+/// Serves a connection with `Pump`, sends `Message` over it and returns the
+/// replies up to the `Count`th that is not an `Ack`.
+async fn Exchange(Pump:Pump, Message:Vec<Value>, Count:usize) -> Vec<Value> {
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	for Message in Message {
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+	}
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let mut Reply = Vec::new();
+
+	while Reply.len() < Count {
+		let Line = timeout(Duration::from_secs(10), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Value:Value = serde_json::from_str(&Line).unwrap();
+
+		if Value["Type"] != "Ack" {
+			Reply.push(Value);
+		}
+	}
+
+	Reply
+}
+
+/// Returns the submission of an action to the `main` queue.
+fn Submit(Id:&str, Action:&str) -> Value {
+	json!({ "Type": "Submit", "Id": Id, "Action": Action, "Metadata": { "Queue": "main" } })
+}
+
+#[tokio::test]
+async fn Offloaded() {
+	let Memory = Arc::new(Memory::default());
+
+	let Pump = Start(Memory.clone());
+
+	let Reply = Exchange(Pump.clone(), vec![Submit("1", "Large"), Submit("2", "Small")], 2).await;
+
+	let Result = |Id:&str| Reply.iter().find(|Reply| Reply["Id"] == Id).unwrap()["Value"].clone();
+
+	assert_eq!(Result("2"), "small");
+
+	let Stored = Stored::Parse(&Result("1")).expect("the large result was not offloaded");
+
+	assert_eq!(Stored.Size, serde_json::to_vec(&Large()).unwrap().len() as u64);
+
+	assert_eq!(Stored.ContentType, "application/json");
+
+	assert_eq!(Memory.Len(), 1);
+
+	// Any connection resolves the marker
+	let Fetched =
+		Exchange(Pump, vec![json!({ "Type": "Fetch", "Stored": Result("1")["$stored"] })], 1).await;
+
+	assert_eq!(Fetched[0], json!({ "Type": "Fetched", "Value": Large() }));
+}
+
+#[tokio::test]
+async fn Refused() {
+	let Reply = Exchange(Start(Arc::new(Refusing)), vec![Submit("1", "Large")], 1).await;
+
+	assert_eq!(Reply[0]["Value"], Large());
+}
+
+#[tokio::test(start_paused = true)]
+async fn Retention() {
+	let Memory = Arc::new(Memory::default());
+
+	let Offload = Offload::New(Memory.clone(), 16).WithRetention(Some(Duration::from_secs(60)));
+
+	let Marker = Offload.Offload("1", Large()).await;
+
+	let Stored = Stored::Parse(&Marker).unwrap();
+
+	assert_eq!(Offload.Resolve(&Stored).await.unwrap(), Large());
+
+	assert_eq!(Offload.Offload("2", json!("inline")).await, "inline");
+
+	sleep(Duration::from_secs(30)).await;
+
+	assert_eq!(Offload.Sweep().await, 0);
+
+	sleep(Duration::from_secs(31)).await;
+
+	assert_eq!(Offload.Sweep().await, 1);
+
+	assert!(Offload.IsEmpty());
+
+	assert!(Memory.IsEmpty());
+
+	assert!(Offload.Resolve(&Stored).await.is_err());
+}
+
+#[cfg(all(feature = "Client", feature = "Tcp"))]
+#[tokio::test]
+async fn Resolved() {
+	let (_Handle, Address) = Echo::Struct::Transport::Tcp::Struct::New(
+		Start(Arc::new(Memory::default())),
+		"127.0.0.1:0",
+	)
+	.Start()
+	.await
+	.unwrap();
+
+	let Client = Echo::Struct::Client::Struct::Connect(
+		&Address.to_string(),
+		Echo::Struct::Client::Config::Struct::New(),
+	)
+	.await
+	.unwrap();
+
+	let Submission = |Action:&str| {
+		Echo::Struct::Client::Submission::Struct::New(Action).WithMetadata("Queue", json!("main"))
+	};
+
+	// The marker never reaches the caller
+	let Large = Client
+		.Submit(Submission("Large"))
+		.await
+		.unwrap()
+		.Wait()
+		.await
+		.unwrap();
+
+	assert_eq!(Large, self::Large());
+
+	let Small = Client
+		.Submit(Submission("Small"))
+		.await
+		.unwrap()
+		.Wait()
+		.await
+		.unwrap();
+
+	assert_eq!(Small, "small");
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Storage::{Memory::Struct as Memory, Offload::Struct as Offload, Stored::Struct as Stored},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::{
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+		Storage::ResultStore::Trait as ResultStore,
+	},
+};
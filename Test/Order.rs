@@ -0,0 +1,207 @@
+#![allow(non_snake_case)]
+
+//! Checks the delivery modes of a connection: submitting A, B and C where B
+//! finishes first and C last, an `Unordered` connection receives B, A, C, a
+//! `SubmissionOrder` connection receives A, B, C, and one whose reordering
+//! buffer overflows receives the held results at once, flagged
+//! `OutOfOrder`.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// The client end of one connection to a pump.
+struct Client {
+	/// Where messages are written.
+	Writer:WriteHalf<DuplexStream>,
+
+	/// Where replies are read.
+	Reader:Lines<BufReader<ReadHalf<DuplexStream>>>,
+}
+
+impl Client {
+	/// Opens a connection served by `Pump` and authenticates it, asking
+	/// for results in the given delivery mode.
+	async fn Connect(Pump:&Pump, Delivery:&str) -> Self {
+		let (Client, Server) = duplex(1 << 16);
+
+		let Pump = Pump.clone();
+
+		tokio::spawn(async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		});
+
+		let (Reader, Writer) = split(Client);
+
+		let mut Client = Client { Writer, Reader:BufReader::new(Reader).lines() };
+
+		Client.Send(json!({ "Type": "Auth", "Token": "client", "Delivery": Delivery })).await;
+
+		let Reply = Client.Reply().await;
+
+		assert_eq!(Reply["Type"], "Authenticated");
+
+		assert_eq!(Reply["Delivery"], Delivery);
+
+		Client
+	}
+
+	/// Sends one message.
+	async fn Send(&mut self, Message:Value) {
+		self.Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+
+		self.Writer.flush().await.unwrap();
+	}
+
+	/// Reads the next reply.
+	async fn Reply(&mut self) -> Value {
+		let Line = timeout(Duration::from_secs(10), self.Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		serde_json::from_str(&Line).unwrap()
+	}
+
+	/// Submits A, B and C, napping for the given milliseconds each, and
+	/// returns the identifiers and `OutOfOrder` flags of their results in
+	/// the order they were delivered.
+	async fn Race(&mut self, Nap:[u64; 3]) -> Vec<(String, bool)> {
+		for (Id, Nap) in ["A", "B", "C"].into_iter().zip(Nap) {
+			self.Send(json!({
+				"Type": "Submit",
+				"Id": Id,
+				"Action": "Nap",
+				"Argument": [Nap],
+				"Metadata": { "Queue": "main" },
+			}))
+			.await;
+		}
+
+		let mut Delivered = Vec::new();
+
+		while Delivered.len() < 3 {
+			let Reply = self.Reply().await;
+
+			if Reply["Type"] == "Result" {
+				Delivered.push((
+					Reply["Id"].as_str().unwrap().to_string(),
+					Reply["OutOfOrder"].as_bool().unwrap_or(false),
+				));
+			}
+		}
+
+		Delivered
+	}
+}
+
+/// Starts a pump over a running `Life` whose `main` queue executes three
+/// actions at once, with a plan whose `Nap` function sleeps for its argument
+/// in milliseconds.
+fn Start() -> (Pump, Sequence) {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Nap"))
+			.WithFunction("Nap", |Argument:Vec<Value>| async move {
+				sleep(Duration::from_millis(Argument[0].as_u64().unwrap())).await;
+
+				Ok(Value::Null)
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New().WithConcurrency(3))
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	(Pump::New(Life, Plan).WithToken("client"), Sequence)
+}
+
+/// Returns owned identifiers and flags for comparison.
+fn Expect(Delivered:&[(&str, bool)]) -> Vec<(String, bool)> {
+	Delivered.iter().map(|(Id, Flag)| (Id.to_string(), *Flag)).collect()
+}
+
+#[tokio::test]
+async fn Unordered() {
+	let (Pump, Sequence) = Start();
+
+	let mut Client = Client::Connect(&Pump, "Unordered").await;
+
+	let Delivered = Client.Race([100, 10, 200]).await;
+
+	assert_eq!(Delivered, Expect(&[("B", false), ("A", false), ("C", false)]));
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn SubmissionOrder() {
+	let (Pump, Sequence) = Start();
+
+	let mut Client = Client::Connect(&Pump, "SubmissionOrder").await;
+
+	let Delivered = Client.Race([100, 10, 200]).await;
+
+	assert_eq!(Delivered, Expect(&[("A", false), ("B", false), ("C", false)]));
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Overflow() {
+	let (Pump, Sequence) = Start();
+
+	// Holding one result at most, B and C overflow while A still runs
+	let mut Client = Client::Connect(&Pump.WithReorder(1), "SubmissionOrder").await;
+
+	let Delivered = Client.Race([300, 10, 100]).await;
+
+	assert_eq!(Delivered, Expect(&[("B", true), ("C", true), ("A", false)]));
+
+	Sequence.Shutdown().await;
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines, ReadHalf,
+		WriteHalf,
+	},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
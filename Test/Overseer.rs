@@ -0,0 +1,144 @@
+#![allow(non_snake_case)]
+
+//! Checks the overseer of internal tasks: a task panicking again and again
+//! is restarted after its backoff each time, counted in `Tasks` and
+//! reported as `TaskFailed` on the bus, until its `Limited` policy runs out
+//! and it stays down; a task that returns is not restarted.
+
+/// The backoff before every restart.
+const BACKOFF:Duration = Duration::from_millis(250);
+
+/// Starts a task recording the time of every run, panicking on its first
+/// `Panics` runs and pending forever after.
+fn Spawn(
+	Overseer:&Overseer,
+	Restart:Restart,
+	Panics:usize,
+) -> (Spawned, Arc<Mutex<Vec<Instant>>>) {
+	let Started = Arc::new(Mutex::new(Vec::new()));
+
+	let Recorded = Started.clone();
+
+	let Spawned = Overseer.Spawn("flaky", Restart, move || {
+		let Started = Recorded.clone();
+
+		async move {
+			let Run = {
+				let mut Started = Started.lock().unwrap();
+
+				Started.push(Instant::now());
+
+				Started.len()
+			};
+
+			if Run <= Panics {
+				panic!("run {} failed", Run);
+			}
+
+			pending::<()>().await;
+		}
+	});
+
+	(Spawned, Started)
+}
+
+/// Asserts that every run started at least `BACKOFF` after the one before.
+fn BackedOff(Started:&[Instant]) {
+	for Pair in Started.windows(2) {
+		assert!(Pair[1] - Pair[0] >= BACKOFF, "restarted after {:?}", Pair[1] - Pair[0]);
+	}
+}
+
+#[tokio::test(start_paused = true)]
+async fn Restarted() {
+	let Bus = Bus::New(64);
+
+	let mut Subscription = Bus.Subscribe();
+
+	let Overseer = Overseer::New(Bus);
+
+	let (Spawned, Started) = Spawn(&Overseer, Restart::Always { Backoff:BACKOFF }, 3);
+
+	sleep(BACKOFF * 10).await;
+
+	assert_eq!(Started.lock().unwrap().len(), 4);
+
+	BackedOff(&Started.lock().unwrap());
+
+	assert_eq!(Overseer.Tasks(), [Task { Name:"flaky".to_string(), Restarts:3 }]);
+
+	for Run in 1..=3 {
+		match Subscription.TryRecv() {
+			Some(Event::TaskFailed { Name, Error }) => {
+				assert_eq!(Name, "flaky");
+
+				assert!(Error.contains(&format!("run {} failed", Run)), "{}", Error);
+			},
+			Other => panic!("expected the failure of run {}, got {:?}", Run, Other),
+		}
+	}
+
+	Spawned.Abort();
+
+	sleep(BACKOFF).await;
+
+	assert!(Overseer.Tasks().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn Exhausted() {
+	let Overseer = Overseer::default();
+
+	let (Spawned, Started) =
+		Spawn(&Overseer, Restart::Limited { Restarts:2, Backoff:BACKOFF }, usize::MAX);
+
+	timeout(BACKOFF * 10, Spawned).await.unwrap().unwrap();
+
+	// The first run and two restarts
+	assert_eq!(Started.lock().unwrap().len(), 3);
+
+	BackedOff(&Started.lock().unwrap());
+
+	assert!(Overseer.Tasks().is_empty());
+
+	let (Spawned, Started) = Spawn(&Overseer, Restart::Never, usize::MAX);
+
+	timeout(BACKOFF, Spawned).await.unwrap().unwrap();
+
+	assert_eq!(Started.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn Returned() {
+	let Overseer = Overseer::default();
+
+	let Runs = Arc::new(Mutex::new(0));
+
+	let Counted = Runs.clone();
+
+	let Spawned = Overseer.Spawn("once", Restart::Always { Backoff:BACKOFF }, move || {
+		let Runs = Counted.clone();
+
+		async move { *Runs.lock().unwrap() += 1 }
+	});
+
+	timeout(BACKOFF, Spawned).await.unwrap().unwrap();
+
+	assert_eq!(*Runs.lock().unwrap(), 1);
+}
+
+use std::{
+	future::pending,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use tokio::time::{sleep, timeout, Instant};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Restart::Enum as Restart},
+	Struct::{
+		Event::Bus::Struct as Bus,
+		Runtime::Spawned::Struct as Spawned,
+		Sequence::Overseer::{Struct as Overseer, Task::Struct as Task},
+	},
+};
@@ -0,0 +1,225 @@
+#![allow(non_snake_case)]
+
+//! Checks the pausing of single Karma queues: a paused queue accepts actions
+//! but runs none while the other queues flow, reports the flag in its stats,
+//! and drains its backlog once resumed, whether through `Life`, a `Control`
+//! message or a reloaded configuration.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A running `Life` with a `reports` and an `interactive` queue, whose
+/// `Work` function records the queue named by its argument.
+fn Start() -> (Life, Arc<Plan>, Arc<Mutex<Vec<String>>>) {
+	let Ran = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", {
+				let Ran = Ran.clone();
+
+				move |Argument:Vec<Value>| {
+					Ran.lock().unwrap().push(Argument[0].as_str().unwrap().to_string());
+
+					async { Ok(Value::Null) }
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("reports", Arc::new(Production::New()), Settings::New())
+		.WithQueue("interactive", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	(Life, Plan, Ran)
+}
+
+/// Submits three `Work` actions to a queue.
+async fn Submit(Life:&Life, Plan:&Arc<Plan>, Queue:&str) -> Vec<Pending> {
+	let mut Pending = Vec::new();
+
+	for _ in 0..3 {
+		Pending.push(
+			Life.Submit(Box::new(
+				Echo::Struct::Sequence::Action::Struct::New("Work", Value::Null, Plan.clone())
+					.WithMetadata("Argument", json!([Queue]))
+					.WithMetadata("Queue", json!(Queue)),
+			))
+			.await,
+		);
+	}
+
+	Pending
+}
+
+/// Waits for every pending action to complete.
+async fn Completed(Pending:Vec<Pending>) {
+	for Pending in Pending {
+		timeout(Duration::from_secs(10), Pending)
+			.await
+			.expect("not completed")
+			.unwrap();
+	}
+}
+
+/// Counts the actions of a queue that ran.
+fn Count(Ran:&Mutex<Vec<String>>, Queue:&str) -> usize {
+	Ran.lock().unwrap().iter().filter(|Ran| *Ran == Queue).count()
+}
+
+#[tokio::test]
+async fn Paused() {
+	let (Life, Plan, Ran) = Start();
+
+	Life.PauseQueue("Reports");
+
+	let Reports = Submit(&Life, &Plan, "reports").await;
+
+	Completed(Submit(&Life, &Plan, "interactive").await).await;
+
+	sleep(Duration::from_millis(300)).await;
+
+	assert_eq!((Count(&Ran, "reports"), Count(&Ran, "interactive")), (0, 3));
+
+	assert_eq!(Life.PausedQueues(), vec!["reports".to_string()]);
+
+	let Stats = Life.Stats();
+
+	assert!(Stats.Queue["reports"].Paused);
+
+	assert_eq!(Stats.Queue["reports"].Depth, 3);
+
+	assert!(!Stats.Queue["interactive"].Paused);
+
+	// The backlog drains once resumed
+	Life.ResumeQueue("reports");
+
+	Completed(Reports).await;
+
+	assert_eq!(Count(&Ran, "reports"), 3);
+
+	assert!(Life.PausedQueues().is_empty());
+}
+
+#[tokio::test]
+async fn Controlled() {
+	let (Life, Plan, Ran) = Start();
+
+	let Pump = Pump::New(Life.clone(), Plan.clone()).WithAdmin("admin");
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let mut Reply = async |Message:Value, Type:&str| {
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+
+		loop {
+			let Line = timeout(Duration::from_secs(10), Reader.next_line())
+				.await
+				.expect("no reply in time")
+				.unwrap()
+				.expect("stream closed");
+
+			let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+			if Reply["Type"] == Type {
+				return Reply;
+			}
+		}
+	};
+
+	Reply(json!({ "Type": "Auth", "Token": "admin" }), "Authenticated").await;
+
+	let Control =
+		Reply(json!({ "Type": "Control", "Control": "Pause", "Queue": "reports" }), "Control")
+			.await;
+
+	// The pump itself keeps accepting submissions
+	assert_eq!(Control["State"], "Open");
+
+	assert_eq!(Control["Paused"], json!(["reports"]));
+
+	let Reports = Submit(&Life, &Plan, "reports").await;
+
+	Completed(Submit(&Life, &Plan, "interactive").await).await;
+
+	sleep(Duration::from_millis(300)).await;
+
+	assert_eq!(Count(&Ran, "reports"), 0);
+
+	let Stats = Reply(json!({ "Type": "Stats" }), "Stats").await;
+
+	assert_eq!(Stats["Stats"]["Queue"]["reports"]["Paused"], true);
+
+	// A reloaded configuration sets the flag from its section
+	Life.Reload(
+		&Config::builder()
+			.set_override("queues.reports.paused", false)
+			.unwrap()
+			.build()
+			.unwrap(),
+	);
+
+	Completed(Reports).await;
+
+	assert_eq!(Count(&Ran, "reports"), 3);
+
+	let Control = Reply(json!({ "Type": "Control", "Control": "Status" }), "Control").await;
+
+	assert!(Control.get("Paused").is_none());
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{
+				Pending::Struct as Pending,
+				Settings::Struct as Settings,
+				Struct as Production,
+			},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,286 @@
+#![allow(non_snake_case)]
+
+//! Checks that a large payload reaches a function without being copied: a
+//! binary frame carries it from the transport to the file system built-in
+//! as shared bytes, while the same payload sent as JSON text is copied on
+//! the way, and both write the same file.
+
+/// The size from which an allocation counts as a copy of the payload.
+const LARGE:usize = 1 << 20;
+
+/// How many allocations of at least `LARGE` bytes were made.
+static COUNT:AtomicUsize = AtomicUsize::new(0);
+
+/// Keeps the tests from counting each other's allocations.
+static SERIAL:Mutex<()> = Mutex::const_new(());
+
+/// The system allocator, counting large allocations.
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, Layout:Layout) -> *mut u8 {
+		if Layout.size() >= LARGE {
+			COUNT.fetch_add(1, Ordering::SeqCst);
+		}
+
+		unsafe { System.alloc(Layout) }
+	}
+
+	unsafe fn dealloc(&self, Pointer:*mut u8, Layout:Layout) {
+		unsafe { System.dealloc(Pointer, Layout) }
+	}
+
+	unsafe fn realloc(&self, Pointer:*mut u8, Layout:Layout, Size:usize) -> *mut u8 {
+		if Size >= LARGE {
+			COUNT.fetch_add(1, Ordering::SeqCst);
+		}
+
+		unsafe { System.realloc(Pointer, Layout, Size) }
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR:Counting = Counting;
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Reads the frames sent over a channel.
+struct Inbound(UnboundedReceiver<Vec<u8>>);
+
+#[async_trait]
+impl Reader for Inbound {
+	async fn Read(&mut self) -> io::Result<Option<Vec<u8>>> { Ok(self.0.recv().await) }
+}
+
+/// Writes frames into a channel.
+struct Outbound(UnboundedSender<Vec<u8>>);
+
+#[async_trait]
+impl Writer for Outbound {
+	async fn Write(&mut self, Frame:&[u8]) -> io::Result<()> {
+		self.0
+			.send(Frame.to_vec())
+			.map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))
+	}
+}
+
+/// A pump serving the file system built-ins and the client end of its
+/// channels.
+struct Harness {
+	/// The directory the built-ins work in.
+	Root:PathBuf,
+
+	/// The sequence consuming the `main` queue.
+	Sequence:Sequence,
+
+	/// Where frames are sent.
+	Sender:UnboundedSender<Vec<u8>>,
+
+	/// Where replies are received.
+	Receiver:UnboundedReceiver<Vec<u8>>,
+}
+
+impl Harness {
+	/// Starts a pump whose `main` queue is consumed by a running sequence.
+	async fn Start(Name:&str) -> Self {
+		let Root = std::env::temp_dir().join(format!("Echo-{}-{}", Name, std::process::id()));
+
+		let _ = tokio::fs::remove_dir_all(&Root).await;
+
+		tokio::fs::create_dir_all(&Root).await.unwrap();
+
+		let Plan = Arc::new(
+			Echo::Builtin::Fs::Struct::New(&Root)
+				.Register(Echo::Struct::Sequence::Plan::Struct::New())
+				.unwrap()
+				.Build(),
+		);
+
+		let Life = Life::Builder()
+			.WithQueue("main", Arc::new(Production::New()), Settings::New())
+			.Build();
+
+		let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+		tokio::spawn({
+			let Sequence = Sequence.clone();
+
+			async move { Sequence.RunKarma().await }
+		});
+
+		let (Sender, Inbox) = unbounded_channel();
+
+		let (Outbox, Receiver) = unbounded_channel();
+
+		let Pump = Pump::New(Life, Plan);
+
+		tokio::spawn(async move { Pump.Run(Inbound(Inbox), Outbound(Outbox)).await });
+
+		Harness { Root, Sequence, Sender, Receiver }
+	}
+
+	/// Sends a frame and waits for the result of the action it submits,
+	/// returning it with the number of large allocations made meanwhile.
+	async fn Submit(&mut self, Frame:Vec<u8>) -> (Value, usize) {
+		let Before = COUNT.load(Ordering::SeqCst);
+
+		self.Sender.send(Frame).unwrap();
+
+		loop {
+			let Reply = timeout(Duration::from_secs(10), self.Receiver.recv())
+				.await
+				.expect("no reply in time")
+				.expect("pump stopped");
+
+			let Reply:Value = serde_json::from_slice(&Reply).unwrap();
+
+			if Reply["Type"] == "Result" || Reply["Type"] == "Error" {
+				return (Reply, COUNT.load(Ordering::SeqCst) - Before);
+			}
+		}
+	}
+
+	/// Stops the sequence and removes the directory.
+	async fn Stop(self) {
+		self.Sequence.Shutdown().await;
+
+		let _ = tokio::fs::remove_dir_all(&self.Root).await;
+	}
+}
+
+/// Returns a `Write` submission to the `main` queue.
+fn Write(Id:&str, Argument:Vec<Value>) -> Message {
+	Message::Submit {
+		Id:Id.to_string(),
+		Action:"Write".to_string(),
+		Argument:Argument.into_iter().map(Arg::Json).collect(),
+		Metadata:Map::new(),
+		Options:Map::from_iter([("Queue".to_string(), json!("main"))]),
+	}
+}
+
+/// Returns four mebibytes of text.
+fn Content() -> String { "0123456789abcdef".repeat(1 << 18) }
+
+#[tokio::test]
+async fn Binary() {
+	let _Serial = SERIAL.lock().await;
+
+	let mut Harness = Harness::Start("Payload-Binary").await;
+
+	let Content = Content();
+
+	let Frame = Json::Struct
+		.EncodeBinary(&Write("1", vec![json!("out.txt")]), Content.as_bytes())
+		.unwrap();
+
+	let (Result, Large) = Harness.Submit(Frame).await;
+
+	assert_eq!(Result["Value"], json!({ "path": "out.txt", "bytes": Content.len() }));
+
+	// The frame's buffer is the only copy of the payload on the way
+	assert_eq!(Large, 0, "the payload was copied {} times", Large);
+
+	assert_eq!(tokio::fs::read_to_string(Harness.Root.join("out.txt")).await.unwrap(), Content);
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Text() {
+	let _Serial = SERIAL.lock().await;
+
+	let mut Harness = Harness::Start("Payload-Text").await;
+
+	let Content = Content();
+
+	let Frame = Json::Struct
+		.EncodeMessage(&Write("1", vec![json!("out.txt"), json!(Content)]))
+		.unwrap();
+
+	let (Result, Large) = Harness.Submit(Frame).await;
+
+	assert_eq!(Result["Value"], json!({ "path": "out.txt", "bytes": Content.len() }));
+
+	// JSON text still works, parsing the payload into a string of its own
+	assert!(Large > 0);
+
+	assert_eq!(tokio::fs::read_to_string(Harness.Root.join("out.txt")).await.unwrap(), Content);
+
+	Harness.Stop().await;
+}
+
+#[test]
+fn Conversions() {
+	let Data = Bytes::from_static(b"Hello");
+
+	assert_eq!(Arg::Bytes(Data.clone()).Value(), json!("Hello"));
+
+	assert_eq!(Arg::Bytes(Data.clone()).Str(), Some("Hello"));
+
+	assert_eq!(Arg::Bytes(Data.clone()).Size(), 5);
+
+	assert_eq!(Arg::Json(json!("Hello")).Size(), 7);
+
+	assert_eq!(Arg::Json(json!("Hello")).IntoBytes(), Ok(Data.clone()));
+
+	assert_eq!(Arg::Str(Arc::from("Hello")).IntoBytes(), Ok(Data));
+
+	assert_eq!(Arg::Json(json!(1)).IntoBytes(), Err(Arg::Json(json!(1))));
+
+	// Bytes that are not text keep the binary convention of JSON values
+	assert_eq!(
+		Arg::Bytes(Bytes::from_static(&[0xff, 0])).Value(),
+		Echo::Fn::Binary::Encode::Fn(&[0xff, 0])
+	);
+
+	assert_eq!(serde_json::to_value(Arg::Bytes(Bytes::from_static(b"Hi"))).unwrap(), json!("Hi"));
+}
+
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	io,
+	path::PathBuf,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde_json::{json, Map, Value};
+use tokio::{
+	sync::{
+		mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+		Mutex,
+	},
+	time::timeout,
+};
+use Echo::{
+	Enum::{
+		Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+		Transport::Message::Enum as Message,
+	},
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Codec::Json, Pump::Struct as Pump},
+	},
+	Trait::{
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+		Transport::{Codec::Trait as _, Reader::Trait as Reader, Writer::Trait as Writer},
+	},
+};
@@ -0,0 +1,162 @@
+#![allow(non_snake_case)]
+
+//! Checks the completion futures `Life::Submit` returns: awaiting one yields
+//! the value of its action, or the error of its last attempt once the
+//! action failed, and dropping one leaves its action to run regardless,
+//! unless `CancelOnDrop` was requested.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan with an `Echo` function returning its arguments, a `Fail`
+/// function that always fails, and a `Count` function counting its calls
+/// after a short nap.
+fn Plan(Ran:Arc<AtomicUsize>) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Echo"))
+			.WithFunction("Echo", |Argument:Vec<Value>| async move { Ok(json!(Argument)) })
+			.unwrap()
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Fail", |_| async { Err(Error::Execution("broken".to_string())) })
+			.unwrap()
+			.WithSignature(Signature::New("Count"))
+			.WithFunction("Count", move |_| {
+				let Ran = Ran.clone();
+
+				async move {
+					sleep(Duration::from_millis(50)).await;
+
+					Ran.fetch_add(1, Ordering::SeqCst);
+
+					Ok(Value::Null)
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life` that makes
+/// a single attempt at each action.
+fn Start() -> (Life, Sequence) {
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	(Life, Sequence)
+}
+
+/// Creates an action of the given type in the `main` queue.
+fn Action(Plan:&Arc<Plan>, Name:&str, Argument:Value) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New(Name, Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!("main"))
+			.WithMetadata("Argument", Argument),
+	)
+}
+
+#[tokio::test]
+async fn Resolved() {
+	let ((Life, Sequence), Plan) = (Start(), Plan(Arc::default()));
+
+	let Pending = Life.Submit(Action(&Plan, "Echo", json!(["hello"]))).await;
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert_eq!(Outcome, Ok(json!(["hello"])));
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Failed() {
+	let ((Life, Sequence), Plan) = (Start(), Plan(Arc::default()));
+
+	let Pending = Life.Submit(Action(&Plan, "Fail", json!([]))).await;
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert!(
+		matches!(&Outcome, Err(Error::Execution(Message)) if Message.contains("broken")),
+		"{:?}",
+		Outcome
+	);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Dropped() {
+	let (Ran, (Life, Sequence)) = (Arc::new(AtomicUsize::new(0)), Start());
+
+	let Plan = Plan(Ran.clone());
+
+	drop(Life.Submit(Action(&Plan, "Count", json!([]))).await);
+
+	timeout(Duration::from_secs(5), async {
+		while Ran.load(Ordering::SeqCst) == 0 {
+			sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	// Opting in cancels the action along with its future
+	let Cancelled = Life.Submit(Action(&Plan, "Count", json!([]))).await.CancelOnDrop();
+
+	drop(Cancelled);
+
+	let Pending = Life.Submit(Action(&Plan, "Echo", json!([]))).await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap(), Ok(json!([])));
+
+	// Long enough for the cancelled action to have finished had it run
+	sleep(Duration::from_millis(200)).await;
+
+	assert_eq!(Ran.load(Ordering::SeqCst), 1);
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
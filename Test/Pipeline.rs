@@ -0,0 +1,474 @@
+#![allow(non_snake_case)]
+
+//! Drives the whole pipeline the way a client does: messages go over a
+//! newline-delimited JSON stream into a `Pump` serving a `Life`, whose Karma
+//! queues are consumed by a running `Sequence` executing the file system
+//! built-ins inside a temporary directory.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A running pipeline and the client end of its stream.
+struct Harness {
+	/// The directory the built-ins work in.
+	Root:PathBuf,
+
+	/// The lifecycle served by the pump.
+	Life:Life,
+
+	/// The plan of the built-ins.
+	Plan:Arc<Formality>,
+
+	/// The sequence consuming the Karma queues.
+	Sequence:Sequence,
+
+	/// Where messages are written.
+	Writer:WriteHalf<DuplexStream>,
+
+	/// Where replies are read.
+	Reader:Lines<BufReader<ReadHalf<DuplexStream>>>,
+
+	/// How long a reply is awaited.
+	Patience:Duration,
+}
+
+impl Harness {
+	/// Starts a pipeline whose `main` queue is consumed under `Settings` and
+	/// makes at most `End` attempts per action.
+	async fn Start(Name:&str, Settings:Settings, End:i64) -> Self {
+		let Root = std::env::temp_dir().join(format!("Echo-{}-{}", Name, std::process::id()));
+
+		let _ = tokio::fs::remove_dir_all(&Root).await;
+
+		tokio::fs::create_dir_all(&Root).await.unwrap();
+
+		let Plan = Arc::new(
+			Echo::Builtin::Fs::Struct::New(&Root)
+				.Register(Echo::Struct::Sequence::Plan::Struct::New())
+				.unwrap()
+				.Build(),
+		);
+
+		let Fate = Config::builder().set_override("End", End).unwrap().build().unwrap();
+
+		let Life = Life::Builder()
+			.WithFate(Arc::new(Fate))
+			.WithQueue("main", Arc::new(Production::New()), Settings)
+			.WithQueue("dlq", Arc::new(Production::New()), Settings::New())
+			.Build();
+
+		let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+		tokio::spawn({
+			let Sequence = Sequence.clone();
+
+			async move { Sequence.RunKarma().await }
+		});
+
+		let (Client, Server) = duplex(1 << 20);
+
+		let Pump = Pump::New(Life.clone(), Plan.clone()).WithAdmin("admin");
+
+		tokio::spawn(async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		});
+
+		let (Reader, Writer) = split(Client);
+
+		Harness {
+			Root,
+			Life,
+			Plan,
+			Sequence,
+			Writer,
+			Reader:BufReader::new(Reader).lines(),
+			Patience:Duration::from_secs(10),
+		}
+	}
+
+	/// Sends one message.
+	async fn Send(&mut self, Message:Value) {
+		self.Writer
+			.write_all(format!("{}\n", Message).as_bytes())
+			.await
+			.unwrap();
+
+		self.Writer.flush().await.unwrap();
+	}
+
+	/// Submits an action to the `main` queue.
+	async fn Submit(&mut self, Id:&str, Action:&str, Argument:Value, mut Metadata:Value) {
+		Metadata["Queue"] = json!("main");
+
+		self.Send(json!({
+			"Type": "Submit",
+			"Id": Id,
+			"Action": Action,
+			"Argument": Argument,
+			"Metadata": Metadata,
+		}))
+		.await;
+	}
+
+	/// Reads the next reply.
+	async fn Reply(&mut self) -> Value {
+		let Line = timeout(self.Patience, self.Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		serde_json::from_str(&Line).unwrap()
+	}
+
+	/// Reads replies until one of `Type`, returning it with the ones before.
+	async fn Until(&mut self, Type:&str) -> (Value, Vec<Value>) {
+		let mut Before = Vec::new();
+
+		loop {
+			let Reply = self.Reply().await;
+
+			if Reply["Type"] == Type {
+				return (Reply, Before);
+			}
+
+			Before.push(Reply);
+		}
+	}
+
+	/// Stops the sequence and removes the directory.
+	async fn Stop(self) {
+		self.Sequence.Shutdown().await;
+
+		let _ = tokio::fs::remove_dir_all(&self.Root).await;
+	}
+}
+
+#[tokio::test]
+async fn Success() {
+	let mut Harness = Harness::Start("Success", Settings::New(), 3).await;
+
+	Harness
+		.Submit("1", "Write", json!(["out.txt", "Hello, World!"]), json!({}))
+		.await;
+
+	assert_eq!(Harness.Reply().await, json!({ "Type": "Ack", "Id": "1" }));
+
+	let Result = Harness.Reply().await;
+
+	assert_eq!(Result["Type"], "Result");
+
+	assert_eq!(Result["Id"], "1");
+
+	assert_eq!(Result["Value"], json!({ "path": "out.txt", "bytes": 13 }));
+
+	Harness.Submit("2", "Read", json!(["out.txt"]), json!({})).await;
+
+	assert_eq!(Harness.Reply().await["Type"], "Ack");
+
+	let Result = Harness.Reply().await;
+
+	assert_eq!(Result["Value"]["content"], "Hello, World!");
+
+	assert_eq!(
+		tokio::fs::read_to_string(Harness.Root.join("out.txt")).await.unwrap(),
+		"Hello, World!"
+	);
+
+	Harness.Stop().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn Retry() {
+	let mut Harness = Harness::Start("Retry", Settings::New(), 3).await;
+
+	// The backoff runs in paused time, so it may take long
+	Harness.Patience = Duration::from_secs(24 * 60 * 60);
+
+	Harness
+		.Send(json!({ "Type": "Subscribe", "Filter": ["Retried"] }))
+		.await;
+
+	assert_eq!(Harness.Reply().await, json!({ "Type": "Subscribed" }));
+
+	Harness.Submit("1", "Read", json!(["missing.txt"]), json!({})).await;
+
+	let (Error, Before) = Harness.Until("Error").await;
+
+	assert_eq!(Error["Id"], "1");
+
+	assert_eq!(Before[0], json!({ "Type": "Ack", "Id": "1" }));
+
+	let Retried:Vec<_> = Before.iter().filter(|Reply| Reply["Type"] == "Event").collect();
+
+	assert_eq!(Retried.len(), 2);
+
+	assert_eq!(Retried[0]["Event"]["Type"], "Retried");
+
+	assert_eq!(Retried[1]["Event"]["Attempt"], 2);
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Cancel() {
+	let mut Harness = Harness::Start("Cancel", Settings::New(), 3).await;
+
+	Harness
+		.Submit("1", "Write", json!(["out.txt", "late"]), json!({ "Delay": 500 }))
+		.await;
+
+	assert_eq!(Harness.Reply().await["Type"], "Ack");
+
+	Harness.Send(json!({ "Type": "Cancel", "Id": "1" })).await;
+
+	let (Cancelled, Before) = Harness.Until("Cancelled").await;
+
+	assert_eq!(Cancelled, json!({ "Type": "Cancelled", "Id": "1", "Cancelled": true }));
+
+	assert_eq!(Before[0]["Message"], "Action was cancelled");
+
+	sleep(Duration::from_secs(1)).await;
+
+	assert!(!Harness.Root.join("out.txt").exists());
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Timeout() {
+	let mut Harness = Harness::Start("Timeout", Settings::New(), 3).await;
+
+	Harness
+		.Submit("1", "Write", json!(["out.txt", "late"]), json!({ "Delay": 300, "Ttl": 100 }))
+		.await;
+
+	assert_eq!(Harness.Reply().await["Type"], "Ack");
+
+	let Error = Harness.Reply().await;
+
+	assert_eq!((&Error["Type"], &Error["Id"]), (&json!("Error"), &json!("1")));
+
+	// The expiry itself, not a generic drop
+	assert_eq!(Error["Code"], "ECHO_TIMEOUT");
+
+	assert!(Error["Message"].as_str().unwrap().contains("expired after waiting"), "{}", Error);
+
+	assert!(!Harness.Root.join("out.txt").exists());
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn DeadLetter() {
+	let mut Harness = Harness::Start("DeadLetter", Settings::New().WithDeadLetter("dlq"), 1).await;
+
+	Harness.Submit("1", "Read", json!(["missing.txt"]), json!({})).await;
+
+	assert_eq!(Harness.Reply().await["Type"], "Ack");
+
+	// The dead-lettered job fails again in `dlq`, which has no dead-letter
+	// queue of its own, and only then replies
+	let (Error, _) = Harness.Until("Error").await;
+
+	assert_eq!(Error["Id"], "1");
+
+	let Dead = Harness.Life.Karma.get("dlq").unwrap().Stats();
+
+	assert_eq!(Dead.Enqueued, 1);
+
+	assert_eq!(Harness.Life.Karma.get("main").unwrap().Stats().Enqueued, 1);
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Chain() {
+	let Harness = Harness::Start("Chain", Settings::New(), 1).await;
+
+	let Action =
+		Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Harness.Plan.clone())
+			.WithMetadata("Queue", json!("main"))
+			.WithMetadata("Argument", json!(["first.txt", "hello"]))
+			.WithMetadata(
+				"NextAction",
+				json!({
+					"Action": "Read",
+					"Argument": ["first.txt"],
+					"NextAction": {
+						"Action": "Write",
+						"Argument": ["second.txt", "{{metadata:Previous}}"],
+						"Template": true,
+					},
+				}),
+			);
+
+	Harness.Life.Submit(Box::new(Action)).await.await.unwrap();
+
+	let Second = tokio::fs::read_to_string(Harness.Root.join("second.txt"))
+		.await
+		.unwrap();
+
+	let Previous:Value = serde_json::from_str(&Second).unwrap();
+
+	assert_eq!(Previous["content"], "hello");
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Drain() {
+	let mut Harness = Harness::Start("Drain", Settings::New(), 3).await;
+
+	Harness.Send(json!({ "Type": "Auth", "Token": "admin" })).await;
+
+	assert_eq!(Harness.Reply().await["Role"], "Admin");
+
+	Harness
+		.Submit("1", "Write", json!(["out.txt", "drained"]), json!({}))
+		.await;
+
+	Harness.Send(json!({ "Type": "Control", "Control": "Drain" })).await;
+
+	let (Control, Before) = Harness.Until("Control").await;
+
+	assert_eq!(Control["State"], "Drained");
+
+	assert_eq!(Control["Drain"]["Drained"], true);
+
+	assert!(Before
+		.iter()
+		.any(|Reply| Reply["Type"] == "Result" && Reply["Id"] == "1"));
+
+	assert_eq!(tokio::fs::read_to_string(Harness.Root.join("out.txt")).await.unwrap(), "drained");
+
+	Harness
+		.Submit("2", "Write", json!(["late.txt", "late"]), json!({}))
+		.await;
+
+	let Error = Harness.Reply().await;
+
+	assert_eq!(Error["Type"], "Error");
+
+	assert_eq!(Error["Id"], "2");
+
+	assert!(!Harness.Root.join("late.txt").exists());
+
+	Harness.Stop().await;
+}
+
+#[tokio::test]
+async fn Report() {
+	let mut Harness = Harness::Start("Report", Settings::New(), 1).await;
+
+	Harness.Send(json!({ "Type": "Auth", "Token": "admin" })).await;
+
+	assert_eq!(Harness.Reply().await["Role"], "Admin");
+
+	// Held in the queue until the drain began
+	Harness.Life.PauseQueue("main");
+
+	for Id in 1..=4 {
+		Harness
+			.Submit(&Id.to_string(), "Write", json!([format!("{}.txt", Id), "queued"]), json!({}))
+			.await;
+	}
+
+	Harness.Submit("5", "Read", json!(["missing.txt"]), json!({})).await;
+
+	let mut Acked = 0;
+
+	while Acked < 5 {
+		assert_eq!(Harness.Reply().await["Type"], "Ack");
+
+		Acked += 1;
+	}
+
+	assert_eq!(Harness.Life.Karma.get("main").unwrap().Stats().Depth, 5);
+
+	Harness.Send(json!({ "Type": "Control", "Control": "Drain" })).await;
+
+	sleep(Duration::from_millis(100)).await;
+
+	Harness.Life.ResumeQueue("main");
+
+	let (Control, Before) = Harness.Until("Control").await;
+
+	assert_eq!(Control["State"], "Drained");
+
+	assert_eq!(
+		(
+			&Control["Drain"]["Drained"],
+			&Control["Drain"]["Completed"],
+			&Control["Drain"]["Failed"],
+			&Control["Drain"]["Remaining"],
+		),
+		(&json!(true), &json!(4), &json!(1), &json!(0)),
+		"{}",
+		Control
+	);
+
+	assert_eq!(Before.iter().filter(|Reply| Reply["Type"] == "Result").count(), 4);
+
+	assert_eq!(Before.iter().filter(|Reply| Reply["Type"] == "Error").count(), 1);
+
+	// Nothing is accepted once drained
+	Harness
+		.Submit("6", "Write", json!(["late.txt", "late"]), json!({}))
+		.await;
+
+	let Error = Harness.Reply().await;
+
+	assert_eq!((&Error["Type"], &Error["Id"]), (&json!("Error"), &json!("6")));
+
+	assert_eq!(Error["Code"], "ECHO_NOT_ACCEPTING");
+
+	assert_eq!(Harness.Life.Karma.get("main").unwrap().Stats().Depth, 0);
+
+	assert!(!Harness.Root.join("late.txt").exists());
+
+	Harness.Stop().await;
+}
+
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex,
+		split,
+		AsyncBufReadExt,
+		AsyncWriteExt,
+		BufReader,
+		DuplexStream,
+		Lines,
+		ReadHalf,
+		WriteHalf,
+	},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Plan::Formality::Struct as Formality,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
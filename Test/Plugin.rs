@@ -0,0 +1,113 @@
+#![allow(non_snake_case)]
+
+//! Checks the plugin slot: the actions a plugin describes are registered
+//! into a formality and answered with JSON marshalled across the boundary,
+//! its failures and overruns surface as action errors, and a reloaded
+//! version answers the calls made afterwards while a call in progress
+//! finishes on the version it started with.
+
+/// A plugin adding its arguments to a base, which tells versions apart.
+struct Adder {
+	/// The number the sum starts from.
+	Base:i64,
+
+	/// Held by a `Wait` call until released.
+	Gate:Arc<Notify>,
+}
+
+#[async_trait]
+impl Plugin for Adder {
+	fn Describe(&self) -> Vec<Signature> {
+		["Add", "Trap", "Spin", "Wait"].into_iter().map(Signature::New).collect()
+	}
+
+	async fn Run(&self, Name:&str, Argument:Vec<Value>) -> Result<Value, Error> {
+		match Name {
+			"Add" => Ok(json!(self.Base + Argument.iter().filter_map(Value::as_i64).sum::<i64>())),
+			"Trap" => Err(Error::Execution("unreachable executed".to_string())),
+			"Spin" => Err(Error::Timeout("fuel exhausted".to_string())),
+			"Wait" => {
+				self.Gate.notified().await;
+
+				Ok(json!(self.Base))
+			},
+			_ => Err(Error::Execution(format!("No export {}", Name))),
+		}
+	}
+}
+
+/// A slot hosting the first version, registered into a formality.
+fn Load(Gate:&Arc<Notify>) -> (Slot, Formality) {
+	let Slot = Slot::New(Arc::new(Adder { Base:0, Gate:Gate.clone() }));
+
+	let mut Formality = Formality::New();
+
+	Slot.Register(&mut Formality).unwrap();
+
+	(Slot, Formality)
+}
+
+/// Calls an action of the formality with JSON arguments.
+async fn Call(Formality:&Formality, Name:&str, Argument:Value) -> Result<Value, Error> {
+	let Argument = Argument.as_array().unwrap().iter().cloned().map(Arg::Json).collect();
+
+	Formality.Get(Name).unwrap()(Argument).await
+}
+
+#[tokio::test]
+async fn Registered() {
+	let (_Slot, Formality) = Load(&Arc::new(Notify::new()));
+
+	for Name in ["Add", "Trap", "Spin", "Wait"] {
+		assert!(Formality.Get(Name).is_some(), "{} is not registered", Name);
+	}
+
+	assert_eq!(Call(&Formality, "Add", json!([2, 3])).await, Ok(json!(5)));
+
+	assert!(matches!(Call(&Formality, "Trap", json!([])).await, Err(Error::Execution(_))));
+
+	assert!(matches!(Call(&Formality, "Spin", json!([])).await, Err(Error::Timeout(_))));
+}
+
+#[tokio::test]
+async fn Reloaded() {
+	let Gate = Arc::new(Notify::new());
+
+	let (Slot, Formality) = Load(&Gate);
+
+	let Formality = Arc::new(Formality);
+
+	let Waiting = tokio::spawn({
+		let Formality = Formality.clone();
+
+		async move { Call(&Formality, "Wait", json!([])).await }
+	});
+
+	tokio::task::yield_now().await;
+
+	Slot.Reload(Arc::new(Adder { Base:100, Gate:Gate.clone() }));
+
+	assert_eq!(Call(&Formality, "Add", json!([2, 3])).await, Ok(json!(105)));
+
+	assert_eq!(Slot.Plugin().Describe().len(), 4);
+
+	// The call in progress started on the first version
+	Gate.notify_one();
+
+	assert_eq!(Waiting.await.unwrap(), Ok(json!(0)));
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::sync::Notify;
+use Echo::{
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Plan::Formality::Struct as Formality,
+		Plugin::Struct as Slot,
+	},
+	Trait::Sequence::Plugin::Trait as Plugin,
+};
@@ -0,0 +1,135 @@
+#![allow(non_snake_case)]
+
+//! Checks the built-in `Process` action: a program's output, status and
+//! duration come back as its value, a nonzero exit is data rather than an
+//! error, output past the cap is cut, a timeout kills the whole process
+//! tree, and programs, shells and environment variables outside what is
+//! configured are refused.
+
+/// Registers `Process` with a configuration.
+fn Plan(Config:Process) -> Formality {
+	Config.Register(Plan::New()).unwrap().Build()
+}
+
+/// Runs a `Process` action.
+async fn Run(Plan:&Formality, Request:Value) -> Result<Value, Error> {
+	Plan.Get("Process").unwrap()(vec![Arg::Json(Request)]).await
+}
+
+#[tokio::test]
+async fn Captured() {
+	let Plan = Plan(Process::default());
+
+	let Value = Run(&Plan, json!({ "program": "cat", "stdin": "Echo" })).await.unwrap();
+
+	assert_eq!(
+		[&Value["status"], &Value["stdout"], &Value["stderr"]],
+		[&json!(0), &json!("Echo"), &json!("")]
+	);
+
+	assert!(Value["duration_ms"].is_u64());
+
+	let Value =
+		Run(&Plan, json!({ "program": "ls", "args": ["Missing"], "cwd": "/" })).await.unwrap();
+
+	assert_ne!(Value["status"], json!(0));
+
+	assert!(Value["stderr"].as_str().unwrap().contains("Missing"));
+}
+
+#[tokio::test]
+async fn Capped() {
+	let Plan = Plan(Process { Capture:4, ..Process::default() });
+
+	let Value = Run(&Plan, json!({ "program": "echo", "args": ["Echo", "Echo"] })).await.unwrap();
+
+	assert_eq!((&Value["status"], &Value["stdout"]), (&json!(0), &json!("Echo")));
+}
+
+#[tokio::test]
+async fn Killed() {
+	let Plan = Plan(Process { Shell:true, ..Process::default() });
+
+	let File = std::env::temp_dir().join(format!("Echo-Process-{}", std::process::id()));
+
+	// The shell records the child it waits for, which the timeout must kill too
+	let Command = format!("sleep 30 & echo $! > {}; wait", File.display());
+
+	let Start = Instant::now();
+
+	let Result = Run(&Plan, json!({ "program": Command, "shell": true, "timeout_ms": 500 })).await;
+
+	assert!(
+		matches!(&Result, Err(Error::Execution(Message)) if Message.contains("timed out")),
+		"{:?}",
+		Result
+	);
+
+	assert!(Start.elapsed() < Duration::from_secs(5));
+
+	let Child = std::fs::read_to_string(&File).unwrap();
+
+	let _ = std::fs::remove_file(&File);
+
+	// A killed child is gone, or a zombie until whoever inherited it reaps it
+	let mut Alive = true;
+
+	for _ in 0..50 {
+		let State = std::process::Command::new("ps")
+			.args(["-o", "stat=", "-p", Child.trim()])
+			.output()
+			.unwrap();
+
+		Alive = !matches!(State.stdout.trim_ascii().first(), None | Some(b'Z'));
+
+		if !Alive {
+			break;
+		}
+
+		tokio::time::sleep(Duration::from_millis(20)).await;
+	}
+
+	assert!(!Alive, "The child {} outlived the timeout", Child.trim());
+}
+
+#[tokio::test]
+async fn Refused() {
+	let Plan = Plan(Process { Program:Some(vec!["echo".to_string()]), ..Process::default() });
+
+	assert!(Run(&Plan, json!({ "program": "echo", "args": ["Echo"] })).await.is_ok());
+
+	for (Request, Reason) in [
+		(json!({ "program": "cat" }), "Program is not allowed: cat"),
+		(json!({ "program": "echo", "shell": true }), "Program is not allowed: sh"),
+		(
+			json!({ "program": "echo", "env": { "PATH": "/tmp" } }),
+			"Environment variable is not allowed: PATH",
+		),
+	] {
+		assert_eq!(Run(&Plan, Request).await, Err(Error::Execution(Reason.to_string())));
+	}
+
+	let Plan = self::Plan(Process::default());
+
+	assert_eq!(
+		Run(&Plan, json!({ "program": "echo $HOME", "shell": true })).await,
+		Err(Error::Execution("Shell interpretation is not enabled".to_string()))
+	);
+
+	let Plan = self::Plan(Process { Environment:vec!["Echo".to_string()], ..Process::default() });
+
+	let Value =
+		Run(&Plan, json!({ "program": "printenv", "args": ["Echo"], "env": { "Echo": "Set" } }))
+			.await
+			.unwrap();
+
+	assert_eq!(Value["stdout"], json!("Set\n"));
+}
+
+use serde_json::{json, Value};
+use tokio::time::{Duration, Instant};
+use Echo::{
+	Builtin::Process::Struct as Process,
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::Plan::{Formality::Struct as Formality, Struct as Plan},
+};
@@ -0,0 +1,119 @@
+#![allow(non_snake_case)]
+
+//! Checks progress reporting: a function added with `AddWithProgress`
+//! reports into the `Progress` signal of its action, which a caller holding
+//! a clone sees halfway through and at 1.0 once the action succeeded, and
+//! such a function still runs for an action exposing no progress.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Copy` function reports 0.5 halfway through, then waits for
+/// `Resume` before it finishes.
+fn Plan(Resume:&Arc<Notify>) -> Arc<Plan> {
+	let Resume = Resume.clone();
+
+	let mut Plan = Plan::New();
+
+	Plan.Sign(Signature::New("Copy"));
+
+	Plan.AddWithProgress("Copy", move |_, Progress| {
+		let Resume = Resume.clone();
+
+		async move {
+			Progress.Set(0.5).await;
+
+			Resume.notified().await;
+
+			Ok(json!("copied"))
+		}
+	})
+	.unwrap();
+
+	Arc::new(Plan)
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life`.
+fn Start() -> Life {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Life
+}
+
+#[tokio::test]
+async fn Observed() {
+	let (Resume, Life) = (Arc::new(Notify::new()), Start());
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Copy", Value::Null, Plan(&Resume))
+		.WithMetadata("Queue", json!("main"))
+		.WithProgress(Signal::New(0.0));
+
+	let Progress = Action.Progress.clone().unwrap();
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	// The observer sees the function halfway through
+	timeout(Duration::from_secs(5), async {
+		while Progress.Get().await < 0.5 {
+			sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("no progress reported");
+
+	assert_eq!(Progress.Get().await, 0.5);
+
+	Resume.notify_one();
+
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap().unwrap(), json!("copied"));
+
+	assert_eq!(Progress.Get().await, 1.0);
+}
+
+#[tokio::test]
+async fn Unobserved() {
+	let (Resume, Life) = (Arc::new(Notify::new()), Start());
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Copy", Value::Null, Plan(&Resume))
+		.WithMetadata("Queue", json!("main"));
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	Resume.notify_one();
+
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap().unwrap(), json!("copied"));
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	sync::Notify,
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Signal::Struct as Signal,
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,264 @@
+#![allow(non_snake_case)]
+
+//! Checks that the deprecated queue verbs behave like `Enqueue` and
+//! `Dequeue`, that a production line serves through the common queue
+//! trait, and that actions are dequeued by `Priority`, in the order they
+//! were enqueued among equals, refusing one that is not an integer.
+
+/// Creates an action identified by its name.
+fn Named(Name:&str) -> Box<dyn Action> {
+	Box::new(Echo::Struct::Sequence::Action::Struct::New(
+		Name,
+		Value::Null,
+		Arc::new(Plan::New().Build()),
+	))
+}
+
+/// Returns the name of an action.
+async fn Name(Action:Option<Box<dyn Action>>) -> Option<String> {
+	Some(Action?.Metadata("Action").await?.as_str()?.to_string())
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn Alias() {
+	let Production = Production::New();
+
+	let First = Production.Enqueue(Named("First")).await.unwrap();
+
+	let Second = Production.Assign(Named("Second")).await.unwrap();
+
+	let Third = Production.Take(Named("Third")).await.unwrap();
+
+	Production.Enqueue(Named("Fourth")).await.unwrap();
+
+	assert_eq!([First.Sequence, Second.Sequence, Third.Sequence], [0, 1, 2]);
+
+	assert_eq!(Name(Production.Dequeue().await).await.as_deref(), Some("First"));
+
+	assert_eq!(Name(Production.Do().await).await.as_deref(), Some("Second"));
+
+	assert_eq!(Name(Production.Execute().await).await.as_deref(), Some("Third"));
+
+	let (Stamp, Action) = Production.DoStamped().await.unwrap();
+
+	assert_eq!(Stamp.Sequence, 3);
+
+	assert_eq!(Name(Some(Action)).await.as_deref(), Some("Fourth"));
+
+	assert!(Production.Dequeue().await.is_none());
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn Priority() {
+	let Production = Production::New();
+
+	let Prioritized = |Name:&str, Priority:Value| {
+		Box::new(
+			Echo::Struct::Sequence::Action::Struct::New(
+				Name,
+				Value::Null,
+				Arc::new(Plan::New().Build()),
+			)
+			.WithMetadata("Priority", Priority),
+		) as Box<dyn Action>
+	};
+
+	Production.Take(Named("Low")).await.unwrap();
+
+	Production.Take(Prioritized("High", json!(5))).await.unwrap();
+
+	Production.Take(Prioritized("Later", json!(0))).await.unwrap();
+
+	Production.Take(Prioritized("Urgent", json!(9))).await.unwrap();
+
+	Production.Take(Prioritized("Background", json!(-1))).await.unwrap();
+
+	// Not an integer, so refused rather than of the default priority
+	for Priority in [json!("high"), json!(2.5), json!({ "Level": 1 })] {
+		let Refused = Production.Take(Prioritized("Invalid", Priority.clone())).await;
+
+		assert!(
+			matches!(&Refused, Err(Error::Execution(Message)) if Message.contains("Priority")),
+			"{} gave {:?}",
+			Priority,
+			Refused
+		);
+	}
+
+	assert_eq!(Production.Depth(), 5);
+
+	let mut Order = Vec::new();
+
+	while let Some((Stamp, Action)) = Production.DequeueStamped().await {
+		Order.push((Name(Some(Action)).await.unwrap(), Stamp.Sequence, Stamp.Priority));
+	}
+
+	assert_eq!(
+		Order,
+		[
+			("Urgent".to_string(), 3, 9),
+			("High".to_string(), 1, 5),
+			("Low".to_string(), 0, 0),
+			("Later".to_string(), 2, 0),
+			("Background".to_string(), 4, -1),
+		]
+	);
+
+	// An action put back goes behind those of its priority
+	Production.Enqueue(Prioritized("High", json!(5))).await.unwrap();
+
+	Production.Enqueue(Named("Low")).await.unwrap();
+
+	let (Stamp, Action) = Production.DequeueStamped().await.unwrap();
+
+	Production.Enqueue(Prioritized("Higher", json!(5))).await.unwrap();
+
+	Production.Reinject(Stamp, Action).await;
+
+	assert_eq!(Name(Production.Do().await).await.as_deref(), Some("Higher"));
+
+	assert_eq!(Name(Production.Do().await).await.as_deref(), Some("High"));
+
+	assert_eq!(Name(Production.Do().await).await.as_deref(), Some("Low"));
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn Object() {
+	let Queue:Arc<dyn Queue> = Arc::new(Production::New());
+
+	Queue.Enqueue(Named("First")).await.unwrap();
+
+	Queue.Assign(Named("Second")).await.unwrap();
+
+	Queue.Take(Named("Third")).await.unwrap();
+
+	assert_eq!(Queue.Depth(), 3);
+
+	assert_eq!(Name(Queue.Do().await).await.as_deref(), Some("First"));
+
+	assert_eq!(Name(Queue.Execute().await).await.as_deref(), Some("Second"));
+
+	assert_eq!(Name(Queue.Dequeue().await).await.as_deref(), Some("Third"));
+
+	assert_eq!(Queue.Depth(), 0);
+}
+
+#[tokio::test]
+async fn Refused() {
+	let Production = Production::New();
+
+	Production.Restrict(Chain::New().WithDepth(Some(1)));
+
+	let Queue:&dyn Queue = &Production;
+
+	let Chained = Echo::Struct::Sequence::Action::Struct::New(
+		"First",
+		Value::Null,
+		Arc::new(Plan::New().Build()),
+	)
+	.WithMetadata("NextAction", json!({ "Action": "Second", "NextAction": { "Action": "Third" } }));
+
+	assert!(Queue.Enqueue(Box::new(Chained)).await.is_err());
+
+	assert_eq!(Queue.Depth(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Redelivery() {
+	let Production = Arc::new(Production::New());
+
+	Production.Lease(Some(Duration::from_secs(5)));
+
+	Production.Enqueue(Named("First")).await.unwrap();
+
+	let Reservation = Production.DoReserve().await.unwrap();
+
+	assert_eq!(Reservation.Action().Metadata("DeliveryCount").await, Some(json!(1)));
+
+	// The consumer dies without acknowledging
+	drop(Reservation);
+
+	assert!(Production.DoReserve().await.is_none());
+
+	sleep(Duration::from_secs(6)).await;
+
+	let Reservation = Production.DoReserve().await.unwrap();
+
+	assert_eq!(Reservation.Deliveries(), 2);
+
+	assert_eq!(Reservation.Action().Metadata("DeliveryCount").await, Some(json!(2)));
+
+	assert_eq!(Name(Some(Box::new(Reservation.Action()))).await.as_deref(), Some("First"));
+
+	assert!(Reservation.Ack());
+
+	sleep(Duration::from_secs(6)).await;
+
+	assert_eq!(Production.Reap().await, 0);
+
+	assert!(Production.DoReserve().await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn Expired() {
+	let Production = Arc::new(Production::New());
+
+	Production.Lease(Some(Duration::from_secs(5)));
+
+	Production.Enqueue(Named("First")).await.unwrap();
+
+	let Stale = Production.DoReserve().await.unwrap();
+
+	sleep(Duration::from_secs(6)).await;
+
+	let Fresh = Production.DoReserve().await.unwrap();
+
+	// The expired lease no longer settles the action
+	assert!(!Stale.Ack());
+
+	assert!(Fresh.Ack());
+}
+
+#[tokio::test]
+async fn Nack() {
+	let Production = Arc::new(Production::New());
+
+	Production.Enqueue(Named("First")).await.unwrap();
+
+	let Reservation = Production.DoReserve().await.unwrap();
+
+	assert!(Reservation.Nack(true).await.is_none());
+
+	let Reservation = Production.DoReserve().await.unwrap();
+
+	assert_eq!(Reservation.Deliveries(), 2);
+
+	let Dead = Reservation.Nack(false).await;
+
+	assert_eq!(
+		Name(Dead.map(|Action| Box::new(Action) as Box<dyn Action>))
+			.await
+			.as_deref(),
+		Some("First")
+	);
+
+	assert!(Production.DoReserve().await.is_none());
+
+	assert_eq!(Production.Stats().Depth, 0);
+}
+
+use std::{sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+use tokio::time::sleep;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Plan::Struct as Plan,
+		Production::{Chain::Struct as Chain, Struct as Production},
+	},
+	Trait::Sequence::{Action::Trait as Action, Queue::Trait as Queue},
+};
@@ -0,0 +1,162 @@
+#![allow(non_snake_case)]
+
+//! Checks `Life::Reconcile` over actions left in the Karma queues, as after
+//! a restore: actions the plan can no longer run are classified and
+//! dead-lettered, dropped or kept as the policy says, and the runnable ones
+//! stay queued.
+
+/// A plan with the `Resize` and `Encode` functions, but no `Thumbnail`.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Resize"))
+			.WithFunction("Resize", |_| async { Ok(json!("resized")) })
+			.unwrap()
+			.WithSignature(Signature::New("Encode"))
+			.WithFunction("Encode", |_| async { Ok(json!("encoded")) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Creates an action of the given type routed to the `main` queue.
+fn Action(Plan:&Arc<Plan>, Name:&str) -> Echo::Struct::Sequence::Action::Struct<Value> {
+	Echo::Struct::Sequence::Action::Struct::New(Name, Value::Null, Plan.clone())
+		.WithMetadata("Queue", json!("main"))
+}
+
+/// A `Life` whose `main` queue dead-letters into `dead`.
+fn Start() -> Life {
+	Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New().WithDeadLetter("dead"))
+		.WithQueue("dead", Arc::new(Production::New()), Settings::New())
+		.Build()
+}
+
+#[tokio::test]
+async fn Orphaned() {
+	let (Plan, Life) = (Plan(), Start());
+
+	// Queued before the plan lost its `Thumbnail` function
+	let Resize = Life.Submit(Box::new(Action(&Plan, "Resize"))).await;
+
+	let Thumbnail = Life.Submit(Box::new(Action(&Plan, "Thumbnail"))).await;
+
+	let Encode = Life.Submit(Box::new(Action(&Plan, "Encode"))).await;
+
+	let Report = Life.Reconcile(&Plan).await;
+
+	assert_eq!(
+		Report,
+		Report { Runnable:2, MissingFunction:1, DeadLettered:1, ..Report::default() }
+	);
+
+	assert_eq!(Life.Karma.get("main").unwrap().Stats().Depth, 2);
+
+	let Dead = Life.DeadLetter.List("dead", &Filter::New()).await.unwrap();
+
+	assert_eq!(Dead.len(), 1);
+
+	assert_eq!(Dead[0].Action.as_deref(), Some("Thumbnail"));
+
+	assert_eq!(Dead[0].Reason.as_deref(), Some("unknown"));
+
+	assert_eq!(Dead[0].Class.as_deref(), Some("UnknownAction"));
+
+	assert!(
+		matches!(Thumbnail.await, Err(Error::UnknownAction { Action, .. }) if Action == "Thumbnail")
+	);
+
+	// The runnable actions still wait for a sequence
+	assert!(timeout(Duration::from_millis(50), Resize).await.is_err());
+
+	assert!(timeout(Duration::from_millis(50), Encode).await.is_err());
+
+	// Reconciling again finds nothing more to do
+	assert_eq!(Life.Reconcile(&Plan).await.Scanned(), 2);
+}
+
+#[tokio::test]
+async fn Policies() {
+	let (Plan, Life) = (Plan(), Start());
+
+	let mut Subscription = Life.Subscribe();
+
+	let Hooked = Life
+		.Submit(Box::new(Action(&Plan, "Resize").WithMetadata("Hooks", json!(["gone"]))))
+		.await;
+
+	drop(
+		Life.Submit(Box::new(Action(&Plan, "Encode").WithMetadata("Argument", json!("raw"))))
+			.await,
+	);
+
+	drop(Life.Submit(Box::new(Action(&Plan, "Thumbnail"))).await);
+
+	let Report = Life
+		.ReconcileWith(
+			&Plan,
+			&Reconcile::New()
+				.WithPolicy(Class::MissingHook, Policy::Drop)
+				.WithPolicy(Class::Undeserializable, Policy::Keep),
+		)
+		.await;
+
+	assert_eq!(
+		Report,
+		Report {
+			MissingFunction:1,
+			MissingHook:1,
+			Undeserializable:1,
+			Kept:1,
+			DeadLettered:1,
+			Dropped:1,
+			..Report::default()
+		}
+	);
+
+	assert!(matches!(Hooked.await, Err(Error::UnknownHook(Hook)) if Hook == "gone"));
+
+	assert_eq!(Life.Karma.get("main").unwrap().Stats().Depth, 1);
+
+	// Both the move and the removal are audited
+	let mut Audit = Vec::new();
+
+	while Audit.len() < 2 {
+		if let Some(Event::Audit { Operation, Target, .. }) =
+			timeout(Duration::from_secs(5), Subscription.Recv()).await.unwrap()
+		{
+			Audit.push((Operation, Target));
+		}
+	}
+
+	assert_eq!(
+		Audit,
+		[
+			("Reconcile.DeadLetter".to_string(), Some("dead".to_string())),
+			("Reconcile.Drop".to_string(), None),
+		]
+	);
+}
+
+use std::{sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::{
+		Event::Enum as Event,
+		Sequence::{
+			Action::Error::Enum as Error,
+			Reconcile::{Class::Enum as Class, Policy::Enum as Policy},
+		},
+	},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		DeadLetter::Filter::Struct as Filter,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Reconcile::{Report::Struct as Report, Struct as Reconcile},
+	},
+};
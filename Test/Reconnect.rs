@@ -0,0 +1,159 @@
+#![allow(non_snake_case)]
+
+//! Checks that a client with a `Backoff` outlives its server: killed while a
+//! submission waits, the wait fails with `ConnectionLost`, and once a server
+//! listens on the same address again the same client submits successfully
+//! and its event subscription resumes.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Runs a `Tcp` server over a fresh `Life` on a runtime of its own, so
+/// dropping the runtime kills the server and every connection it serves.
+/// Its plan has a `Work` function returning its argument and a `Hang`
+/// function that never resolves.
+fn Serve(Address:&str) -> (Runtime, SocketAddr) {
+	let Address = Address.to_string();
+
+	// Started from a thread of its own, outside the runtime of the test
+	std::thread::spawn(move || Start(&Address)).join().unwrap()
+}
+
+/// Builds the runtime of a server and starts the server on it.
+fn Start(Address:&str) -> (Runtime, SocketAddr) {
+	let Server = Builder::new_multi_thread().worker_threads(2).enable_all().build().unwrap();
+
+	let Address = Server.block_on(async {
+		let Plan = Arc::new(
+			Echo::Struct::Sequence::Plan::Struct::New()
+				.WithSignature(Signature::New("Work"))
+				.WithFunction("Work", |Argument:Vec<Value>| async move { Ok(json!(Argument)) })
+				.unwrap()
+				.WithSignature(Signature::New("Hang"))
+				.WithFunction("Hang", |_| pending())
+				.unwrap()
+				.Build(),
+		);
+
+		let Life = Life::Builder()
+			.WithQueue("main", Arc::new(Production::New()), Settings::New())
+			.Build();
+
+		let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+		tokio::spawn(async move { Sequence.RunKarma().await });
+
+		let (Handle, Address) = Tcp::New(Pump::New(Life, Plan), Address).Start().await.unwrap();
+
+		// Lives as long as the runtime
+		tokio::spawn(async move { Handle.Join().await });
+
+		Address
+	});
+
+	(Server, Address)
+}
+
+/// Returns a submission of an action to the `main` queue.
+fn Submit(Action:&str, Argument:Value) -> Submission {
+	Submission::New(Action).WithArgument(Argument).WithMetadata("Queue", json!("main"))
+}
+
+#[tokio::test]
+async fn Restarted() {
+	let (Server, Address) = Serve("127.0.0.1:0");
+
+	let Config = Config::New()
+		.WithBackoff(Backoff::New(Duration::from_millis(20), Duration::from_millis(100)))
+		.WithTimeout(Duration::from_secs(10));
+
+	let Client = Client::Connect(&Address.to_string(), Config).await.unwrap();
+
+	let mut State = Client.State();
+
+	let mut Completed = Box::pin(Client.Subscribe(vec!["Completed".to_string()]).await.unwrap());
+
+	let First = Client.Submit(Submit("Work", json!("first"))).await.unwrap();
+
+	assert_eq!(First.Wait().await, Ok(json!(["first"])));
+
+	assert!(matches!(
+		timeout(Duration::from_secs(5), Completed.next()).await.unwrap(),
+		Some(Event::Completed { .. })
+	));
+
+	let Hanging = Client.Submit(Submit("Hang", json!(null))).await.unwrap();
+
+	let Waiting = tokio::spawn(Hanging.Wait());
+
+	// Killed mid-wait, connections included
+	sleep(Duration::from_millis(100)).await;
+
+	Server.shutdown_background();
+
+	assert_eq!(
+		timeout(Duration::from_secs(5), Waiting).await.unwrap().unwrap(),
+		Err(ClientError::ConnectionLost)
+	);
+
+	let (Server, _) = Serve(&Address.to_string());
+
+	timeout(Duration::from_secs(5), State.wait_for(|State| *State == ClientState::Connected))
+		.await
+		.unwrap()
+		.unwrap();
+
+	let Second = Client.Submit(Submit("Work", json!("second"))).await.unwrap();
+
+	assert_eq!(Second.Wait().await, Ok(json!(["second"])));
+
+	// The subscription was restored on the new connection
+	assert!(matches!(
+		timeout(Duration::from_secs(5), Completed.next()).await.unwrap(),
+		Some(Event::Completed { .. })
+	));
+
+	drop(Client);
+
+	Server.shutdown_background();
+}
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use futures::{future::pending, StreamExt};
+use serde_json::{json, Value};
+use tokio::{
+	runtime::{Builder, Runtime},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::{
+		Client::{Error::Enum as ClientError, State::Enum as ClientState},
+		Event::Enum as Event,
+		Sequence::Action::Error::Enum as Error,
+	},
+	Struct::{
+		Client::{
+			Backoff::Struct as Backoff,
+			Config::Struct as Config,
+			Struct as Client,
+			Submission::Struct as Submission,
+		},
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Pump::Struct as Pump, Tcp::Struct as Tcp},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,95 @@
+#![allow(non_snake_case)]
+
+//! Checks the registration records: records defined next to their feature
+//! code in separate modules are collected into one plan by
+//! `Plan::FromRecords`, records compiled out with `#[cfg]` are left out, and
+//! two records of the same name are refused with both definition sites.
+
+/// The actions of the editor.
+mod Editor {
+	/// Opens a file.
+	async fn Open(Argument:Vec<Value>) -> Result<Value, Error> {
+		Ok(json!(format!("Opened {}", Argument[0].as_str().unwrap_or_default())))
+	}
+
+	/// Closes every file, compiled out.
+	#[cfg(any())]
+	async fn Close(_Argument:Vec<Value>) -> Result<Value, Error> { Ok(Value::Null) }
+
+	pub const RECORDS:&[Record] = &[
+		Echo::Record!("Open", Open),
+		#[cfg(any())]
+		Echo::Record!("Close", Close),
+	];
+
+	use serde_json::{json, Value};
+	use Echo::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::Plan::Record::Struct as Record,
+	};
+}
+
+/// The actions of the terminal.
+mod Terminal {
+	/// Runs a command.
+	async fn Run(Argument:Vec<Value>) -> Result<Value, Error> {
+		Ok(json!(format!("Ran {}", Argument[0].as_str().unwrap_or_default())))
+	}
+
+	/// Opens a terminal, under the name the editor uses already.
+	async fn Open(_Argument:Vec<Value>) -> Result<Value, Error> { Ok(Value::Null) }
+
+	pub const RECORDS:&[Record] = &[Echo::Record!("Run", Run)];
+
+	pub const CONFLICTING:&[Record] = &[Echo::Record!("Open", Open)];
+
+	use serde_json::{json, Value};
+	use Echo::{
+		Enum::Sequence::Action::Error::Enum as Error,
+		Struct::Sequence::Plan::Record::Struct as Record,
+	};
+}
+
+/// Calls the function of an action directly.
+async fn Call(Plan:&Formality, Name:&str, Argument:&str) -> Result<Value, Error> {
+	Plan.Get(Name).unwrap()(vec![Arg::Json(json!(Argument))]).await
+}
+
+#[tokio::test]
+async fn Collected() {
+	let Plan = Plan::FromRecords(Editor::RECORDS.iter().chain(Terminal::RECORDS))
+		.unwrap()
+		.Build();
+
+	assert_eq!(Call(&Plan, "Open", "Echo.rs").await, Ok(json!("Opened Echo.rs")));
+
+	assert_eq!(Call(&Plan, "Run", "ls").await, Ok(json!("Ran ls")));
+
+	assert!(Plan.Get("Close").is_none());
+
+	assert_eq!(Editor::RECORDS.len(), 1);
+
+	assert_eq!(Editor::RECORDS[0].Module, "Record::Editor");
+
+	assert_eq!(Editor::RECORDS[0].File, file!());
+}
+
+#[test]
+fn Duplicate() {
+	let Error =
+		Plan::FromRecords(Editor::RECORDS.iter().chain(Terminal::CONFLICTING)).err().unwrap();
+
+	assert!(Error.starts_with("Duplicate action Open"), "{}", Error);
+
+	assert!(Error.contains(&Editor::RECORDS[0].Site()), "{}", Error);
+
+	assert!(Error.contains(&Terminal::CONFLICTING[0].Site()), "{}", Error);
+
+	assert!(Error.contains("Record::Editor") && Error.contains("Record::Terminal"), "{}", Error);
+}
+
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::{Arg::Enum as Arg, Error::Enum as Error},
+	Struct::Sequence::Plan::{Formality::Struct as Formality, Struct as Plan},
+};
@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+
+//! Checks the action registry of a `Life` on a paused clock: actions are
+//! tracked from enqueue to completion, the periodic sweep abandons the
+//! actions drained from their queue, resolving what awaits them with
+//! `Error::Abandoned`, and the registry is empty again once the retention
+//! of the terminal entries passed.
+
+#[tokio::test(start_paused = true)]
+async fn Swept() {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Production = Life.Karma.get("main").unwrap().clone();
+
+	let Plan = Arc::new(Plan::New().Build());
+
+	let Action = || {
+		Box::new(Echo::Struct::Sequence::Action::Struct::New("Idle", Value::Null, Plan.clone()))
+			as Box<dyn Action>
+	};
+
+	let Done = Production.Submit(Action()).await;
+
+	let (Stamp, _) = Production.DequeueStamped().await.unwrap();
+
+	assert_eq!(Life.Actions.State(&Production, Stamp.Sequence), Some(Lifecycle::Running));
+
+	Production.Complete(Stamp.Sequence, Ok(json!("done")));
+
+	assert_eq!(Done.await, Ok(json!("done")));
+
+	let mut Pending = Vec::new();
+
+	for _ in 0..3 {
+		Pending.push(Production.Submit(Action()).await);
+	}
+
+	assert_eq!(Life.Actions.Len(), 4);
+
+	assert_eq!(Life.Actions.State(&Production, Stamp.Sequence), Some(Lifecycle::Completed));
+
+	assert_eq!(Production.Clear().await, 3);
+
+	// The next periodic sweep finds the drained actions
+	sleep(Duration::from_secs(6)).await;
+
+	for Pending in Pending {
+		let Result = Pending.await;
+
+		assert!(matches!(Result, Err(Error::Abandoned(_))), "{:?}", Result);
+	}
+
+	assert_eq!(Life.Actions.Len(), 4);
+
+	assert_eq!(Life.Actions.State(&Production, Stamp.Sequence + 1), Some(Lifecycle::Abandoned));
+
+	// Terminal entries go once their retention passed
+	sleep(Duration::from_secs(60)).await;
+
+	assert!(Life.Actions.IsEmpty());
+
+	assert_eq!(Life.Actions.Sweep().await, 0);
+}
+
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use tokio::time::{sleep, Duration};
+use Echo::{
+	Enum::Sequence::{Action::Error::Enum as Error, Lifecycle::Enum as Lifecycle},
+	Struct::Sequence::{
+		Life::Struct as Life,
+		Plan::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
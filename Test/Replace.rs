@@ -0,0 +1,99 @@
+#![allow(non_snake_case)]
+
+//! Checks that plan functions stay registered and can be replaced while the
+//! plan is shared: an action type runs as often as it is submitted,
+//! `Formality::Replace` answers every action executed afterwards with the
+//! replacement, a function looked up before it keeps running the previous
+//! version, and an unknown name cannot be replaced.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Version` answers `1`.
+fn Plan() -> Arc<Formality> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Version"))
+			.WithFunction("Version", |_| async { Ok(json!(1)) })
+			.unwrap()
+			.Build(),
+	)
+}
+
+#[tokio::test]
+async fn Replaced() {
+	let Plan = Plan();
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Submit = |Plan:&Arc<Formality>| {
+		let Action = Echo::Struct::Sequence::Action::Struct::New("Version", (), Plan.clone())
+			.WithQueue("main");
+
+		let Life = Life.clone();
+
+		async move { Life.Submit(Box::new(Action)).await.await }
+	};
+
+	// Running an action leaves its function registered
+	for _ in 0..3 {
+		assert_eq!(Submit(&Plan).await, Ok(json!(1)));
+	}
+
+	let Previous = Plan.Replace("Version", |_| async { Ok(json!(2)) }).unwrap();
+
+	for _ in 0..3 {
+		assert_eq!(Submit(&Plan).await, Ok(json!(2)));
+	}
+
+	assert_eq!(Previous(vec![]).await, Ok(json!(1)));
+}
+
+#[tokio::test]
+async fn Held() {
+	let Plan = Plan();
+
+	let Held = Plan.Get("Version").unwrap();
+
+	Plan.Replace("Version", |_| async { Ok(json!(2)) }).unwrap();
+
+	assert_eq!(Held(vec![]).await, Ok(json!(1)));
+
+	assert_eq!(Plan.Get("Version").unwrap()(vec![]).await, Ok(json!(2)));
+}
+
+#[test]
+fn Unknown() {
+	let Error = Plan().Replace("Missing", |_| async { Ok(Value::Null) }).err().unwrap();
+
+	assert_eq!(Error, "No function found to replace: Missing");
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Formality,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+
+//! Checks a record/replay round trip: actions run through a `Recorder` are
+//! logged with their outcomes, and the same actions answered by a
+//! `Replayer` standing in for the site, or replayed against the real plan,
+//! end exactly as they did when recorded.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Add` sums its arguments and whose `Fail` fails, counting
+/// the calls of both.
+fn Plan(Calls:&Arc<AtomicUsize>) -> Arc<Plan> {
+	let (Added, Failed) = (Calls.clone(), Calls.clone());
+
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Add"))
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Add", move |Argument:Vec<Value>| {
+				Added.fetch_add(1, Ordering::SeqCst);
+
+				async move { Ok(json!(Argument.iter().filter_map(Value::as_i64).sum::<i64>())) }
+			})
+			.unwrap()
+			.WithFunction("Fail", move |_| {
+				Failed.fetch_add(1, Ordering::SeqCst);
+
+				async move { Err(Error::Execution("broken".to_string())) }
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// A running `Life` with a `main` queue served through a site.
+fn Start(Site:Arc<dyn Site>) -> Life {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Site, Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Life
+}
+
+/// Submits the same three actions and returns how each ended.
+async fn Run(Life:&Life, Plan:&Arc<Plan>) -> Vec<Result<Value, Error>> {
+	let mut Outcome = Vec::new();
+
+	for (Name, Argument) in [("Add", json!([1, 2])), ("Add", json!([3, 4])), ("Fail", json!([]))] {
+		let Action = Echo::Struct::Sequence::Action::Struct::New(Name, (), Plan.clone())
+			.WithOption(Reserved::Argument, Argument)
+			.WithQueue("main");
+
+		Outcome.push(Life.Submit(Box::new(Action)).await.await);
+	}
+
+	Outcome
+}
+
+#[tokio::test(start_paused = true)]
+async fn RoundTrip() {
+	let Path = std::env::temp_dir().join(format!("Echo-Replay-{}.jsonl", std::process::id()));
+
+	let _ = tokio::fs::remove_file(&Path).await;
+
+	let Calls = Arc::new(AtomicUsize::new(0));
+
+	let Recorder = Recorder::Create(&Path, Arc::new(Direct)).await.unwrap();
+
+	let Recorded = Run(&Start(Arc::new(Recorder)), &Plan(&Calls)).await;
+
+	assert_eq!(
+		Recorded,
+		[Ok(json!(3)), Ok(json!(7)), Err(Error::Execution("broken".to_string()))]
+	);
+
+	// Every attempt of the retried failure is a record of its own
+	let Called = Calls.load(Ordering::SeqCst);
+
+	let Replayer = Arc::new(Replayer::Open(&Path).await.unwrap());
+
+	assert_eq!(Replayer.Record().len(), Called);
+
+	assert_eq!(
+		Replayer.Record().iter().map(|Record| Record.Action.as_str()).collect::<Vec<_>>(),
+		["Add", "Add", "Fail", "Fail", "Fail"]
+	);
+
+	// Stubbed: the recordings answer, the functions are not called
+	let Stubbed = Run(&Start(Replayer.clone()), &Plan(&Calls)).await;
+
+	assert_eq!(Stubbed, Recorded);
+
+	assert_eq!(Calls.load(Ordering::SeqCst), Called);
+
+	// Replayed against the real plan
+	let Life = Start(Arc::new(Direct));
+
+	let mut Replayed = Vec::new();
+
+	for Pending in Replayer.Replay(&Life.Karma.get("main").unwrap(), Plan(&Calls)).await {
+		Replayed.push(Pending.await);
+	}
+
+	let Expected = Replayer
+		.Record()
+		.iter()
+		.map(|Record| match &Record.Error {
+			Some(_Error) => Err(_Error.clone()),
+			None => Ok(Record.Value.clone().unwrap_or_default()),
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(Replayed, Expected);
+}
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::{Action::Error::Enum as Error, Reserved::Enum as Reserved},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Replay::{Recorder::Struct as Recorder, Replayer::Struct as Replayer},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,201 @@
+#![allow(non_snake_case)]
+
+//! Checks the reserved metadata keys: a user field named `Delay` delays
+//! nothing once a plan drops the legacy shim, the shim still honors a flat
+//! `Delay` and writes options in both forms, and a wire submission's
+//! `options` route it while an unknown option is refused.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Echo` function returns its argument.
+fn Plan(Legacy:bool) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Echo"))
+			.WithFunction("Echo", |Argument:Vec<Value>| {
+				async move { Ok(Argument.into_iter().next().unwrap_or(Value::Null)) }
+			})
+			.unwrap()
+			.WithLegacyKeys(Legacy)
+			.Build(),
+	)
+}
+
+/// Starts a sequence consuming the `main` queue of a new `Life`.
+fn Start() -> Life {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Life
+}
+
+#[tokio::test]
+async fn Collision() {
+	let Life = Start();
+
+	let Action = Struct::New("Echo", json!(["done"]), Plan(false))
+		.WithQueue("main")
+		.WithMetadata("Delay", json!("1h"));
+
+	let Start = Instant::now();
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	// The business field is kept but does not delay the action
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap().unwrap(), json!("done"));
+
+	assert!(Start.elapsed() < Duration::from_secs(5));
+
+	let Action = Struct::New("Echo", Value::Null, Plan(false)).WithMetadata("Delay", json!("1h"));
+
+	assert_eq!(Action.Lookup("Delay").await, None);
+
+	assert_eq!(Action.Metadata.Get("Delay").await, Some(json!("1h")));
+
+	// Options are written under their reserved key only
+	let Action = Action.WithDelay(Duration::from_millis(10));
+
+	assert_eq!(Action.Metadata.Get("Delay").await, Some(json!("1h")));
+
+	assert_eq!(Action.Lookup("Delay").await, Some(json!("10ms")));
+}
+
+#[tokio::test]
+async fn Shim() {
+	let Life = Start();
+
+	let Action = Struct::New("Echo", json!(["late"]), Plan(true))
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Delay", json!(300));
+
+	// The legacy flat keys are read
+	assert_eq!(Action.ReadOption(Reserved::Delay).await, Some(json!(300)));
+
+	let Start = Instant::now();
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap().unwrap(), json!("late"));
+
+	assert!(Start.elapsed() >= Duration::from_millis(300));
+
+	// Options are written in both forms, the reserved one read first
+	let Action = Struct::New("Echo", Value::Null, Plan(true))
+		.WithMetadata("Priority", json!(1))
+		.WithPriority(5);
+
+	for Key in ["Priority", "__echo.Priority"] {
+		assert_eq!(Action.Metadata.Get(Key).await, Some(json!(5)));
+	}
+
+	assert_eq!(Action.Lookup("Priority").await, Some(json!(5)));
+}
+
+/// Serves a line-framed connection over a running `Life` with a plan
+/// without the legacy shim, sends `Messages` and returns a reply but `Ack`
+/// for each.
+async fn Exchange(Messages:Vec<Value>) -> Vec<Value> {
+	let Pump = Pump::New(Start(), Plan(false));
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output)).await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	for Message in &Messages {
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+	}
+
+	let mut Replies = Vec::new();
+
+	while Replies.len() < Messages.len() {
+		let Line = timeout(Duration::from_secs(5), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+		if Reply["Type"] != "Ack" {
+			Replies.push(Reply);
+		}
+	}
+
+	Replies
+}
+
+#[tokio::test]
+async fn Options() {
+	let Replies = Exchange(vec![
+		json!({
+			"Type": "Submit",
+			"Id": "1",
+			"Action": "Echo",
+			"Argument": ["routed"],
+			"Metadata": { "Delay": "1h" },
+			"options": { "Queue": "main" },
+		}),
+		json!({
+			"Type": "Submit",
+			"Id": "2",
+			"Action": "Echo",
+			"options": { "Queue": "main", "Colour": "red" },
+		}),
+	])
+	.await;
+
+	let Reply = |Id:&str| {
+		Replies.iter().find(|Reply| Reply["Id"] == Id).cloned().expect("no reply")
+	};
+
+	assert_eq!((&Reply("1")["Type"], &Reply("1")["Value"]), (&json!("Result"), &json!("routed")));
+
+	assert_eq!(
+		(&Reply("2")["Type"], &Reply("2")["Code"]),
+		(&json!("Error"), &json!("ECHO_MALFORMED"))
+	);
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{timeout, Instant},
+};
+use Echo::{
+	Enum::Sequence::{Action::Error::Enum as Error, Reserved::Enum as Reserved},
+	Struct::{
+		Sequence::{
+			Action::{Signature::Struct as Signature, Struct},
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
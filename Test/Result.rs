@@ -0,0 +1,111 @@
+#![allow(non_snake_case)]
+
+//! Checks that the value a function returns is kept as the action's `Result`
+//! metadata and in the `Life` cache under its `Id`, and that a follow-up in
+//! `NextAction` receives it, so a `Read` chained into a `Write` copies a file.
+
+/// A fresh directory for the file actions of one test.
+async fn Root(Name:&str) -> PathBuf {
+	let Root = std::env::temp_dir().join(format!("Echo-{}-{}", Name, std::process::id()));
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	Root
+}
+
+/// A plan with the file actions jailed under `Root`.
+fn Plan(Root:&Path) -> Arc<Plan> {
+	Arc::new(
+		Echo::Builtin::Fs::Struct::New(Root)
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())
+			.unwrap()
+			.Build(),
+	)
+}
+
+#[tokio::test]
+async fn Kept() {
+	let Root = Root("ResultKept").await;
+
+	tokio::fs::write(Root.join("input.txt"), "Hello").await.unwrap();
+
+	let Life = Life::Builder().Build();
+
+	let Action =
+		Action::New("Read", json!(["input.txt"]), Plan(&Root)).WithMetadata("Id", json!(7));
+
+	Action.Execute(&Life).await.unwrap();
+
+	let Result = Action.Metadata.Get("Result").await.unwrap();
+
+	assert_eq!(Result["content"], "Hello");
+
+	assert_eq!(Life.Cache.Get(&"Result:7".to_string()), Some(Result));
+
+	// Without an `Id` the result stays on the action alone
+	let Anonymous = Action::New("Read", json!(["input.txt"]), Plan(&Root));
+
+	Anonymous.Execute(&Life).await.unwrap();
+
+	assert!(Anonymous.Metadata.Get("Result").await.is_some());
+
+	assert_eq!(Life.Cache.Len(), 1);
+}
+
+#[tokio::test]
+async fn Chain() {
+	let Root = Root("ResultChain").await;
+
+	tokio::fs::write(Root.join("input.txt"), "Hello, chain").await.unwrap();
+
+	let Life = Life::Builder().Build();
+
+	Action::New("Read", json!(["input.txt"]), Plan(&Root))
+		.WithMetadata(
+			"NextAction",
+			json!({
+				"Action": "Write",
+				"Argument": ["output.txt", "{{metadata:Previous/content}}"],
+				"Template": true,
+			}),
+		)
+		.Execute(&Life)
+		.await
+		.unwrap();
+
+	assert_eq!(
+		tokio::fs::read_to_string(Root.join("output.txt")).await.unwrap(),
+		"Hello, chain"
+	);
+
+	// A pointer into a result that lacks the field is unresolved
+	let Error = Action::New("Read", json!(["input.txt"]), Plan(&Root))
+		.WithMetadata(
+			"NextAction",
+			json!({
+				"Action": "Write",
+				"Argument": ["output.txt", "{{metadata:Previous/missing}}"],
+				"Template": true,
+			}),
+		)
+		.Execute(&Life)
+		.await
+		.unwrap_err();
+
+	assert!(matches!(Error, Error::Template(_)), "{:?}", Error);
+}
+
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+use serde_json::json;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Struct as Action,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+	},
+};
@@ -0,0 +1,160 @@
+#![allow(non_snake_case)]
+
+//! Checks the result bridge against a fake sink on a paused clock: a burst
+//! of progress events per action reaches the sink at the configured rate
+//! with the latest state last, a burst of results beyond the batch size is
+//! held and emitted as one `ActionResults` event when the window ends, and
+//! whatever is held is emitted once the bus is gone.
+
+/// Records what the bridge emits, standing in for a webview.
+#[derive(Clone, Default)]
+struct Webview(Arc<Mutex<Vec<(String, Value)>>>);
+
+impl EventSink for Webview {
+	fn Emit(&self, Name:&str, Payload:Value) -> Result<(), String> {
+		self.0.lock().unwrap().push((Name.to_string(), Payload));
+
+		Ok(())
+	}
+}
+
+impl Webview {
+	/// Takes what was emitted so far.
+	fn Take(&self) -> Vec<(String, Value)> { std::mem::take(&mut self.0.lock().unwrap()) }
+}
+
+/// The name of every emitted event with one field of its payload.
+fn Field<'a>(Emitted:&'a [(String, Value)], Field:&str) -> Vec<(&'a str, Value)> {
+	Emitted.iter().map(|(Name, Payload)| (Name.as_str(), Payload[Field].clone())).collect()
+}
+
+/// Starts a bridge on a bus emitting 10 progress events per action per
+/// second and at most 3 results per 100 milliseconds one by one.
+fn Start() -> (Bus, Webview, JoinHandle<()>) {
+	let (Bus, Sink) = (Bus::default(), Webview::default());
+
+	let Bridge = Bridge::New(Sink.clone())
+		.WithRate(10)
+		.WithBatch(3)
+		.WithWindow(Duration::from_millis(100));
+
+	let Running = tokio::spawn(Bridge.Run(Bus.Subscribe()));
+
+	(Bus, Sink, Running)
+}
+
+/// A failed attempt of an action.
+fn Retried(Sequence:u64, Attempt:u32) -> Event {
+	Event::Retried { Sequence, Attempt, Error:"broken".to_string() }
+}
+
+#[tokio::test(start_paused = true)]
+async fn Coalesced() {
+	let (Bus, Sink, _Running) = Start();
+
+	Bus.Emit(|| Event::Enqueued { Sequence:1, Action:Some("Read".to_string()) });
+
+	for Attempt in 1..=5 {
+		Bus.Emit(|| Retried(1, Attempt));
+	}
+
+	Bus.Emit(|| Event::Started { Sequence:2 });
+
+	sleep(Duration::from_millis(10)).await;
+
+	// The first event of every action passes, the rest of the burst is held
+	assert_eq!(
+		Field(&Sink.Take(), "Type"),
+		[("ActionProgress", json!("Enqueued")), ("ActionProgress", json!("Started"))]
+	);
+
+	sleep(Duration::from_millis(100)).await;
+
+	let Emitted = Sink.Take();
+
+	assert_eq!(Field(&Emitted, "Type"), [("ActionProgress", json!("Retried"))]);
+
+	assert_eq!(Emitted[0].1["Attempt"], 5);
+
+	// A result drops what is held for its action
+	Bus.Emit(|| Retried(2, 1));
+
+	Bus.Emit(|| Retried(2, 2));
+
+	Bus.Emit(|| Event::Expired { Sequence:2 });
+
+	sleep(Duration::from_millis(200)).await;
+
+	assert_eq!(
+		Field(&Sink.Take(), "Type"),
+		[("ActionProgress", json!("Retried")), ("ActionResult", json!("Expired"))]
+	);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Batched() {
+	let (Bus, Sink, Running) = Start();
+
+	for Sequence in 0..10 {
+		Bus.Emit(|| Event::Expired { Sequence });
+	}
+
+	sleep(Duration::from_millis(10)).await;
+
+	let Emitted = Sink.Take();
+
+	assert_eq!(
+		Field(&Emitted, "Sequence"),
+		[("ActionResult", json!(0)), ("ActionResult", json!(1)), ("ActionResult", json!(2))]
+	);
+
+	sleep(Duration::from_millis(100)).await;
+
+	let Emitted = Sink.Take();
+
+	assert_eq!(Emitted.len(), 1);
+
+	assert_eq!(Emitted[0].0, "ActionResults");
+
+	let Sequence = Emitted[0].1.as_array().unwrap().iter().map(|Event| Event["Sequence"].clone());
+
+	assert_eq!(Sequence.collect::<Vec<_>>(), (3..10).map(Value::from).collect::<Vec<_>>());
+
+	// A new window emits one by one again
+	Bus.Emit(|| Event::Expired { Sequence:10 });
+
+	sleep(Duration::from_millis(10)).await;
+
+	assert_eq!(Sink.Take().len(), 1);
+
+	// Results held when the bus goes away are still emitted
+	for Sequence in 11..15 {
+		Bus.Emit(|| Event::Expired { Sequence });
+	}
+
+	drop(Bus);
+
+	Running.await.unwrap();
+
+	let Emitted = Sink.Take();
+
+	assert_eq!(
+		Emitted.iter().map(|(Name, _)| Name.as_str()).collect::<Vec<_>>(),
+		["ActionResult", "ActionResult", "ActionResults"]
+	);
+
+	assert_eq!(Emitted[2].1.as_array().unwrap().len(), 2);
+}
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::{json, Value};
+use tokio::{
+	task::JoinHandle,
+	time::{sleep, Duration},
+};
+use Echo::{
+	Enum::Event::Enum as Event,
+	Struct::{Event::Bus::Struct as Bus, Integration::Tauri::ResultBridge::Struct as Bridge},
+	Trait::Integration::EventSink::Trait as EventSink,
+};
@@ -0,0 +1,142 @@
+#![allow(non_snake_case)]
+
+//! Checks `Fn::Retry` on a paused clock: a failing operation is retried
+//! after the backoff of the policy until it succeeds, an error the
+//! classifier refuses ends it at once, the last error is returned once the
+//! attempts are exhausted, and cancelling the token ends it mid-backoff.
+
+/// An operation failing with `failure <n>` until attempt `Succeed`.
+fn Operation(Succeed:u32) -> (Arc<AtomicU32>, impl FnMut() -> Ready<Result<u32, String>>) {
+	let Calls = Arc::new(AtomicU32::new(0));
+
+	let Counted = Calls.clone();
+
+	let Operation = move || {
+		let Attempt = Counted.fetch_add(1, Ordering::SeqCst) + 1;
+
+		ready(if Attempt >= Succeed { Ok(Attempt) } else { Err(format!("failure {}", Attempt)) })
+	};
+
+	(Calls, Operation)
+}
+
+/// The backoff recorded for every attempt, in milliseconds.
+fn Backoff(History:&History) -> Vec<Option<u64>> {
+	History.Attempts.iter().map(|Attempt| Attempt.Backoff).collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn Recovered() {
+	let Hooked = Arc::new(AtomicU32::new(0));
+
+	let Retry = Retry::New().WithBackoff(Backoff::Fixed(Duration::from_secs(1))).WithHook({
+		let Hooked = Hooked.clone();
+
+		move |History:&History, _Error:&String| {
+			assert_eq!(History.Last().unwrap().Error.as_ref(), Some(_Error));
+
+			Hooked.fetch_add(1, Ordering::SeqCst);
+		}
+	});
+
+	let (Calls, Operation) = Operation(3);
+
+	let Start = Instant::now();
+
+	let (Result, History) = Fn(&Retry, Operation).await;
+
+	assert_eq!(Result, Ok(3));
+
+	assert_eq!(Start.elapsed(), Duration::from_secs(2));
+
+	assert_eq!(Calls.load(Ordering::SeqCst), 3);
+
+	assert_eq!(Hooked.load(Ordering::SeqCst), 2);
+
+	assert_eq!(Backoff(&History), [Some(1000), Some(1000), None]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Classified() {
+	let Retry = Retry::New().WithClassify(|_Error:&String| !_Error.ends_with('1'));
+
+	let (Calls, Operation) = Operation(3);
+
+	let Start = Instant::now();
+
+	let (Result, History) = Fn(&Retry, Operation).await;
+
+	assert_eq!(Result, Err("failure 1".to_string()));
+
+	assert_eq!(Start.elapsed(), Duration::ZERO);
+
+	assert_eq!((Calls.load(Ordering::SeqCst), History.Attempts.len()), (1, 1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn Exhausted() {
+	let Retry = Retry::New().WithAttempts(4).WithBackoff(Backoff::Exponential {
+		Base:Duration::from_secs(1),
+		Jitter:Duration::ZERO,
+	});
+
+	let (Calls, Operation) = Operation(u32::MAX);
+
+	let Start = Instant::now();
+
+	let (Result, History) = Fn(&Retry, Operation).await;
+
+	assert_eq!(Result, Err("failure 4".to_string()));
+
+	assert_eq!(Calls.load(Ordering::SeqCst), 4);
+
+	assert_eq!(Backoff(&History), [Some(2000), Some(4000), Some(8000), None]);
+
+	assert_eq!(Start.elapsed(), Duration::from_secs(14));
+}
+
+#[tokio::test(start_paused = true)]
+async fn Cancelled() {
+	let Cancellation = Cancellation::New();
+
+	let Retry = Retry::New()
+		.WithBackoff(Backoff::Fixed(Duration::from_secs(10)))
+		.WithCancellation(Cancellation.clone());
+
+	tokio::spawn(async move {
+		sleep(Duration::from_secs(3)).await;
+
+		Cancellation.Cancel();
+	});
+
+	let (Calls, Operation) = Operation(u32::MAX);
+
+	let Start = Instant::now();
+
+	let (Result, History) = Fn(&Retry, Operation).await;
+
+	assert_eq!(Result, Err("failure 1".to_string()));
+
+	assert_eq!(Start.elapsed(), Duration::from_secs(3));
+
+	assert_eq!((Calls.load(Ordering::SeqCst), History.Attempts.len()), (1, 1));
+}
+
+use std::{
+	future::{ready, Ready},
+	sync::{
+		atomic::{AtomicU32, Ordering},
+		Arc,
+	},
+};
+
+use tokio::time::{sleep, Duration, Instant};
+use Echo::{
+	Enum::Sequence::Backoff::Enum as Backoff,
+	Fn::Retry::Fn,
+	Struct::Sequence::{
+		Attempt::History::Struct as History,
+		Cancellation::Struct as Cancellation,
+		Retry::Struct as Retry,
+	},
+};
@@ -0,0 +1,231 @@
+#![allow(non_snake_case)]
+
+//! Checks running a `Life` on a runtime of its own: a runtime counting its
+//! spawns and sleeps, handing detached futures to tokio as an executor that
+//! knows nothing of join handles would, carries a delayed action from its
+//! submission to its completion, and its handles report panics and aborts. A
+//! blocking action timing out spawns nothing on tokio behind its back.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Runs detached futures on tokio, counting what it is asked to do.
+#[derive(Default)]
+struct Counting {
+	/// How many tasks were spawned.
+	Spawned:AtomicUsize,
+
+	/// How many sleeps were started.
+	Slept:AtomicUsize,
+
+	/// How many blocking tasks were spawned.
+	Blocked:AtomicUsize,
+
+	/// How many of the spawned tasks are still running on tokio.
+	Alive:Arc<AtomicUsize>,
+}
+
+impl Runtime for Counting {
+	fn Spawn(&self, Task:BoxFuture<'static, ()>) -> Spawned::Struct {
+		self.Spawned.fetch_add(1, Ordering::SeqCst);
+
+		let (Detached, Handle) = Spawned::Struct::Detached(Task);
+
+		let Alive = self.Alive.clone();
+
+		Alive.fetch_add(1, Ordering::SeqCst);
+
+		drop(tokio::spawn(async move {
+			Detached.await;
+
+			Alive.fetch_sub(1, Ordering::SeqCst);
+		}));
+
+		Handle
+	}
+
+	fn SpawnBlocking(&self, Task:BoxFuture<'static, ()>) -> Spawned::Struct {
+		self.Blocked.fetch_add(1, Ordering::SeqCst);
+
+		let (Detached, Handle) = Spawned::Struct::Detached(Task);
+
+		let Current = tokio::runtime::Handle::current();
+
+		drop(tokio::task::spawn_blocking(move || Current.block_on(Detached)));
+
+		Handle
+	}
+
+	fn Sleep(&self, Length:Duration) -> BoxFuture<'static, ()> {
+		self.Slept.fetch_add(1, Ordering::SeqCst);
+
+		Box::pin(sleep(Length))
+	}
+
+	fn Now(&self) -> Instant { Instant::now() }
+}
+
+#[tokio::test]
+async fn Cycle() {
+	let Runtime = Arc::new(Counting::default());
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Echo"))
+			.WithFunction("Echo", |Argument:Vec<Value>| async move { Ok(json!(Argument)) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.WithRuntime(Runtime.clone())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	let Running = Runtime.Spawn(Box::pin({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	}));
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Echo", json!(["hello"]), Plan)
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Delay", json!(50));
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap(), Ok(json!(["hello"])));
+
+	// The consumer, the timer service and the worker all went through it
+	assert!(Runtime.Spawned.load(Ordering::SeqCst) >= 6, "{:?}", Runtime.Spawned);
+
+	assert!(Runtime.Slept.load(Ordering::SeqCst) >= 1);
+
+	assert!(Life.Tasks().iter().any(|Task| Task.Name == "timer"));
+
+	assert!(Life.Tasks().iter().any(|Task| Task.Name == "consumer.main"));
+
+	Sequence.Shutdown().await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Running).await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn Contained() {
+	let Runtime = Arc::new(Counting::default());
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Stall").WithBlocking(true))
+			.WithFunction("Stall", |_| async {
+				std::thread::sleep(Duration::from_millis(300));
+
+				Ok(Value::Null)
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	// A single attempt, so the timeout is final
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.WithRuntime(Runtime.clone())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	let Running = Runtime.Spawn(Box::pin({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	}));
+
+	let Action = Echo::Struct::Sequence::Action::Struct::New("Stall", Value::Null, Plan)
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Timeout", json!("50ms"));
+
+	let Pending = Life.Submit(Box::new(Action)).await;
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert!(matches!(Outcome, Err(Error::Timeout(_))), "{:?}", Outcome);
+
+	assert_eq!(Runtime.Blocked.load(Ordering::SeqCst), 1);
+
+	// The timeout slept through the runtime too
+	assert!(Runtime.Slept.load(Ordering::SeqCst) >= 1);
+
+	// Every task alive on tokio is one the runtime spawned
+	assert_eq!(
+		tokio::runtime::Handle::current().metrics().num_alive_tasks(),
+		Runtime.Alive.load(Ordering::SeqCst)
+	);
+
+	Sequence.Shutdown().await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Running).await.unwrap(), Ok(()));
+}
+
+#[tokio::test]
+async fn Handles() {
+	let Runtime = Counting::default();
+
+	let Panicking = Runtime.Spawn(Box::pin(async { panic!("boom") }));
+
+	assert_eq!(Panicking.await, Err(Exit::Panicked("boom".to_string())));
+
+	let Hanging = Runtime.Spawn(Box::pin(pending()));
+
+	assert!(!Hanging.IsFinished());
+
+	Hanging.Abort();
+
+	assert_eq!(Hanging.await, Err(Exit::Cancelled));
+
+	// The default runtime reports the same
+	let Panicking = Tokio::Struct.Spawn(Box::pin(async { panic!("boom") }));
+
+	assert_eq!(Panicking.await, Err(Exit::Panicked("boom".to_string())));
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use futures::future::{pending, BoxFuture};
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout, Instant};
+use Echo::{
+	Enum::{Runtime::Exit::Enum as Exit, Sequence::Action::Error::Enum as Error},
+	Struct::{
+		Runtime::{Spawned, Tokio},
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+	},
+	Trait::{
+		Runtime::Trait as Runtime,
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+	},
+};
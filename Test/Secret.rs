@@ -0,0 +1,244 @@
+#![allow(non_snake_case)]
+
+//! Checks the resolution of secret references at the plan boundary: the
+//! function receives the secret while the queued action, the submission
+//! history, the result and the offloaded copy of the result only ever show
+//! the reference, and a reference that cannot be resolved fails the call.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// The value of the `db_password` secret.
+const SECRET:&str = "hunter2-correct-horse";
+
+/// Writes the `db_password` secret into a directory of its own.
+fn Directory(Name:&str) -> PathBuf {
+	let Directory =
+		std::env::temp_dir().join(format!("Echo-Secret-{}-{}", Name, std::process::id()));
+
+	std::fs::create_dir_all(&Directory).unwrap();
+
+	std::fs::write(Directory.join("db_password"), format!("{}\n", SECRET)).unwrap();
+
+	Directory
+}
+
+/// A plan resolving secrets from `Directory`, whose `Connect` function
+/// records what it receives and echoes its first argument, and whose `Fail`
+/// function quotes its first argument in its error.
+fn Plan(Directory:PathBuf, Received:Arc<Mutex<Vec<Value>>>) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithArgumentTransformer(Arc::new(Resolver::New(Arc::new(File::New(Directory)))))
+			.WithSignature(Signature::New("Connect"))
+			.WithFunction("Connect", move |Argument:Vec<Value>| {
+				Received.lock().unwrap().extend(Argument.clone());
+
+				async move {
+					let Password = Argument[0].as_str().unwrap_or_default().to_string();
+
+					Ok(json!({
+						"Password": Password,
+						"Dsn": format!("postgres://app:{}@db", Password),
+						"Padding": "x".repeat(2048),
+					}))
+				}
+			})
+			.unwrap()
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Fail", |Argument:Vec<Value>| async move {
+				Err(Error::Execution(format!(
+					"Rejected {}",
+					Argument[0].as_str().unwrap_or_default()
+				)))
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// The reference to the `db_password` secret.
+fn Reference() -> Value { json!({ "$secret": "db_password" }) }
+
+#[tokio::test]
+async fn Resolved() {
+	let Received = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Plan(Directory("Resolved"), Received.clone());
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Memory = Arc::new(Memory::default());
+
+	let Pump = Pump::New(Life, Plan)
+		.WithAdmin("admin")
+		.WithOffload(Arc::new(Offload::New(Memory.clone(), 1024)));
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let mut Seen = Vec::new();
+
+	let mut Reply = async |Message:Value, Type:&str| {
+		Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+
+		loop {
+			let Line = timeout(Duration::from_secs(10), Reader.next_line())
+				.await
+				.expect("no reply in time")
+				.unwrap()
+				.expect("stream closed");
+
+			Seen.push(Line.clone());
+
+			let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+			if Reply["Type"] == Type {
+				return Reply;
+			}
+		}
+	};
+
+	Reply(json!({ "Type": "Auth", "Token": "admin" }), "Authenticated").await;
+
+	let Result = Reply(
+		json!({
+			"Type": "Submit",
+			"Id": "1",
+			"Action": "Connect",
+			"Argument": [Reference()],
+			"Metadata": { "Queue": "main" },
+		}),
+		"Result",
+	)
+	.await;
+
+	// The function got the secret itself
+	assert_eq!(*Received.lock().unwrap(), vec![json!(SECRET)]);
+
+	// The result cache holds the masked result
+	assert_eq!(Memory.Len(), 1);
+
+	let Fetched =
+		Reply(json!({ "Type": "Fetch", "Stored": Result["Value"]["$stored"] }), "Fetched").await;
+
+	assert_eq!(Fetched["Value"]["Password"], Reference());
+
+	assert_eq!(Fetched["Value"]["Dsn"], "postgres://app:***@db");
+
+	// The submission history only saw the reference
+	let Submissions = Reply(json!({ "Type": "QuerySubmissions" }), "Submissions").await;
+
+	assert_eq!(
+		Submissions["Page"]["Record"][0]["Digest"],
+		json!(Submission::Digest(&[Reference().into()]))
+	);
+
+	for Line in Seen {
+		assert!(!Line.contains(SECRET), "the secret leaked: {}", Line);
+	}
+}
+
+// Failed calls are retried after a backoff
+#[tokio::test(start_paused = true)]
+async fn Masked() {
+	let Received = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Plan(Directory("Masked"), Received.clone());
+
+	let Life = Life::Builder().Build();
+
+	let Line = Arc::new(Production::New());
+
+	let Sequence = Sequence::New(Arc::new(Direct), Line.clone(), Life);
+
+	let Submit = |Action:&str, Argument:Value| {
+		Line.Submit(Box::new(
+			Echo::Struct::Sequence::Action::Struct::New(Action, Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!([Argument])),
+		))
+	};
+
+	let Connect = Submit("Connect", Reference()).await;
+
+	let Fail = Submit("Fail", Reference()).await;
+
+	let Missing = Submit("Connect", json!({ "$secret": "missing" })).await;
+
+	// The queued actions keep the reference
+	for (_, Metadata) in Line.Inspect(&["Argument"]).await {
+		assert!(!Metadata["Argument"].to_string().contains(SECRET));
+	}
+
+	while Sequence.ConsumeOne().await.is_some() {}
+
+	let Connected = Connect.await.unwrap();
+
+	assert_eq!(Connected["Password"], Reference());
+
+	assert_eq!(Connected["Dsn"], "postgres://app:***@db");
+
+	// Errors quoting the secret are masked as well
+	assert_eq!(Fail.await.unwrap_err(), Error::Execution("Rejected ***".to_string()));
+
+	// A reference that cannot be resolved fails before the function runs
+	let Unresolved = Missing.await.unwrap_err();
+
+	assert!(
+		matches!(&Unresolved, Error::Execution(Message) if Message.contains("Cannot resolve secret missing"))
+	);
+
+	assert_eq!(*Received.lock().unwrap(), vec![json!(SECRET)]);
+}
+
+use std::{
+	path::PathBuf,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::timeout,
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Secret::{File::Struct as File, Resolver::Struct as Resolver},
+			Struct as Sequence,
+		},
+		Storage::{Memory::Struct as Memory, Offload::Struct as Offload},
+		Transport::{Frame::Line, History::Record::Struct as Submission, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,91 @@
+#![allow(non_snake_case)]
+
+//! Checks that a seeded `Life` schedules retries the same way on every run:
+//! failing actions retried under the same seed are attempted at identical
+//! times, and under another seed at different ones.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Runs failing actions under a seed.
+///
+/// # Returns
+///
+/// The time of every attempt, from the start of the run.
+async fn Schedule(Seed:u64) -> Vec<Duration> {
+	let Start = Instant::now();
+
+	let Attempt = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Fail", {
+				let Attempt = Attempt.clone();
+
+				move |_| {
+					Attempt.lock().unwrap().push(Start.elapsed());
+
+					async move { Err(Error::Execution("broken".to_string())) }
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.WithRngSeed(Seed)
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	let Running = tokio::spawn(async move { Sequence.RunKarma().await });
+
+	for _ in 0..3 {
+		let Action = Echo::Struct::Sequence::Action::Struct::New("Fail", (), Plan.clone())
+			.WithQueue("main");
+
+		assert!(Life.Submit(Box::new(Action)).await.await.is_err());
+	}
+
+	Running.abort();
+
+	let Attempt = Attempt.lock().unwrap().clone();
+
+	Attempt
+}
+
+#[tokio::test(start_paused = true)]
+async fn Reproduced() {
+	let First = Schedule(7).await;
+
+	// Three actions of three attempts each
+	assert_eq!(First.len(), 9);
+
+	assert_eq!(Schedule(7).await, First);
+
+	assert_ne!(Schedule(8).await, First);
+}
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use tokio::time::{Duration, Instant};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
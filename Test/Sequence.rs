@@ -0,0 +1,218 @@
+#![allow(non_snake_case)]
+
+//! Drives a `Sequence` from external worker loops through its public
+//! accessors, the way the Tauri example spawns its workers, and checks that
+//! `ConsumeOne` dead-letters under the settings of its queue.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+#[tokio::test]
+async fn ConsumeOne() {
+	let Root = std::env::temp_dir().join(format!("Echo-ConsumeOne-{}", std::process::id()));
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	let Plan = Arc::new(
+		Echo::Builtin::Fs::Struct::New(&Root)
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())
+			.unwrap()
+			.Build(),
+	);
+
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Sequence = Arc::new(Sequence::New(
+		Arc::new(Direct),
+		Arc::new(Production::New()),
+		Life::Builder().WithFate(Arc::new(Fate)).Build(),
+	));
+
+	let mut Completed = Sequence.Context().Subscribe();
+
+	for Number in 0..8 {
+		Sequence
+			.Queue()
+			.Enqueue(Box::new(
+				Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Plan.clone())
+					.WithMetadata("Argument", json!([format!("{}.txt", Number), "written"])),
+			))
+			.await
+			.unwrap();
+	}
+
+	Sequence
+		.Queue()
+		.Enqueue(Box::new(
+			Echo::Struct::Sequence::Action::Struct::New("Read", Value::Null, Plan.clone())
+				.WithMetadata("Argument", json!(["missing.txt"])),
+		))
+		.await
+		.unwrap();
+
+	// The worker spawning of the Tauri example
+	let mut Force = JoinSet::new();
+
+	for _ in 0..4 {
+		let Sequence = Sequence.clone();
+
+		Force.spawn(async move {
+			let Shutdown = Sequence.ShutdownToken();
+
+			let (mut Succeeded, mut Failed) = (0, 0);
+
+			while !Shutdown.Get().await {
+				match Sequence.ConsumeOne().await {
+					Some(Ok(())) => Succeeded += 1,
+					Some(Err(_)) => Failed += 1,
+					None => sleep(Duration::from_millis(10)).await,
+				}
+			}
+
+			(Succeeded, Failed)
+		});
+	}
+
+	let mut Finished = 0;
+
+	while Finished < 9 {
+		match timeout(Duration::from_secs(10), Completed.Recv()).await.unwrap() {
+			Some(Event::Completed { .. } | Event::Failed { .. }) => Finished += 1,
+			Some(_) => {},
+			None => panic!("event bus closed"),
+		}
+	}
+
+	Sequence.Shutdown().await;
+
+	let (mut Succeeded, mut Failed) = (0, 0);
+
+	while let Some(Outcome) = Force.join_next().await {
+		let (Success, Failure) = Outcome.unwrap();
+
+		Succeeded += Success;
+
+		Failed += Failure;
+	}
+
+	assert_eq!((Succeeded, Failed), (8, 1));
+
+	assert!(Sequence.Queue().Dequeue().await.is_none());
+
+	for Number in 0..8 {
+		assert_eq!(
+			tokio::fs::read_to_string(Root.join(format!("{}.txt", Number)))
+				.await
+				.unwrap(),
+			"written"
+		);
+	}
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+}
+
+#[tokio::test]
+async fn Empty() {
+	let Sequence =
+		Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life::Builder().Build());
+
+	assert!(Sequence.ConsumeOne().await.is_none());
+
+	assert!(!Sequence.ShutdownToken().Get().await);
+
+	Sequence.Shutdown().await;
+
+	assert!(Sequence.ShutdownToken().Get().await);
+
+	assert!(Arc::ptr_eq(&Sequence.Queue(), &Sequence.Production));
+}
+
+#[tokio::test(start_paused = true)]
+async fn Leased() {
+	let Production = Arc::new(Production::New());
+
+	Production.Lease(Some(Duration::from_secs(5)));
+
+	let Sequence = Sequence::New(Arc::new(Direct), Production.clone(), Life::Builder().Build());
+
+	Production
+		.Enqueue(Box::new(Echo::Struct::Sequence::Action::Struct::New(
+			"Missing",
+			Value::Null,
+			Arc::new(Echo::Struct::Sequence::Plan::Struct::New().Build()),
+		)))
+		.await
+		.unwrap();
+
+	assert!(matches!(Sequence.ConsumeOne().await, Some(Err(Error::UnknownAction { .. }))));
+
+	// Processed actions are acknowledged, so nothing is handed out again
+	sleep(Duration::from_secs(6)).await;
+
+	assert!(Sequence.ConsumeOne().await.is_none());
+
+	assert_eq!(Production.Reap().await, 0);
+}
+
+#[tokio::test]
+async fn DeadLettered() {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Fail", |_:Vec<Value>| {
+				async { Err(Error::Execution("Failed".to_string())) }
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Main = Arc::new(Production::New());
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Main.clone(), Settings::New().WithDeadLetter("dead"))
+		.WithQueue("dead", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Main.clone(), Life.clone());
+
+	Main.Enqueue(Box::new(Echo::Struct::Sequence::Action::Struct::New("Fail", Value::Null, Plan)))
+		.await
+		.unwrap();
+
+	// The dead-letter queue of the settings of `main` receives the failure
+	assert!(matches!(Sequence.ConsumeOne().await, Some(Err(Error::Execution(_)))));
+
+	let Dead = Life.Karma.get("dead").unwrap().clone();
+
+	assert_eq!((Dead.Stats().Depth, Main.Stats().Depth), (1, 0));
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::{
+	task::JoinSet,
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
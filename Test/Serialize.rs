@@ -0,0 +1,90 @@
+#![allow(non_snake_case)]
+
+//! Checks that actions survive serialization: metadata, content and license
+//! round-trip, and a deserialized action runs its `NextAction` chain once its
+//! plan is attached again.
+
+/// A plan whose `First` and `Second` actions count their calls.
+fn Plan() -> (Arc<Plan>, Arc<AtomicUsize>) {
+	let Called = Arc::new(AtomicUsize::new(0));
+
+	let mut Plan = Plan::New();
+
+	for Name in ["First", "Second"] {
+		let Called = Called.clone();
+
+		Plan.Sign(Signature::New(Name));
+
+		Plan.Add(Name, move |_| {
+			let Called = Called.clone();
+
+			async move { Ok(json!(Called.fetch_add(1, Ordering::SeqCst) + 1)) }
+		})
+		.unwrap();
+	}
+
+	(Arc::new(Plan), Called)
+}
+
+#[tokio::test]
+async fn RoundTrip() {
+	let (Plan, _) = Plan();
+
+	let Action = Action::New("First", json!({ "Path": "/tmp" }), Plan)
+		.WithMetadata("Queue", json!("main"))
+		.WithMetadata("Argument", json!([1, "two"]))
+		.WithMetadata("NextAction", json!({ "Action": "Second" }));
+
+	Action.License.Set(false).await;
+
+	let Value = serde_json::to_value(&Action).unwrap();
+
+	let Restored:Action<Value> = serde_json::from_value(Value.clone()).unwrap();
+
+	assert_eq!(Restored.Metadata.Snapshot(), Action.Metadata.Snapshot());
+
+	assert_eq!(Restored.Content, json!({ "Path": "/tmp" }));
+
+	assert!(!Restored.License.Get().await);
+
+	// Serializing again yields the same value
+	assert_eq!(serde_json::to_value(&Restored).unwrap(), Value);
+}
+
+#[tokio::test]
+async fn Chain() {
+	let (Plan, Called) = Plan();
+
+	let Life = Life::Builder().Build();
+
+	let Value = serde_json::to_value(
+		Action::New("First", Value::Null, Plan.clone())
+			.WithMetadata("NextAction", json!({ "Action": "Second" })),
+	)
+	.unwrap();
+
+	// Without its plan the action names a function it cannot find
+	let Detached:Action<Value> = serde_json::from_value(Value.clone()).unwrap();
+
+	assert!(Detached.Execute(&Life).await.is_err());
+
+	assert_eq!(Called.load(Ordering::SeqCst), 0);
+
+	let Attached = serde_json::from_value::<Action<Value>>(Value).unwrap().Attach(Plan);
+
+	Attached.Execute(&Life).await.unwrap();
+
+	assert_eq!(Called.load(Ordering::SeqCst), 2);
+}
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+use serde_json::{json, Value};
+use Echo::Struct::Sequence::{
+	Action::{Signature::Struct as Signature, Struct as Action},
+	Life::Struct as Life,
+	Plan::Formality::Struct as Plan,
+};
@@ -0,0 +1,148 @@
+#![allow(non_snake_case)]
+
+//! Checks the argument and result types of signatures: a call with too few
+//! or too many arguments, an argument of the wrong JSON type, a result of
+//! the wrong type and a signature naming an unknown type each fail with an
+//! `Error::Execution` saying why, before the function runs where it can.
+
+/// A plan whose `Copy` function takes a path and a byte count and returns
+/// its second argument, counting its calls in `Ran`.
+fn Plan(Output:&str, Ran:&Arc<AtomicUsize>) -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(
+				Signature::New("Copy")
+					.WithInputTypes(&["string", "integer"])
+					.WithOutputType(Output),
+			)
+			.WithFunction("Copy", {
+				let Ran = Ran.clone();
+
+				move |Argument:Vec<Value>| {
+					Ran.fetch_add(1, Ordering::Relaxed);
+
+					async move { Ok(Argument[1].clone()) }
+				}
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Executes a `Copy` action with the given arguments.
+async fn Copy(Plan:&Arc<Plan>, Argument:Value) -> Result<(), Error> {
+	Echo::Struct::Sequence::Action::Struct::New("Copy", (), Plan.clone())
+		.WithMetadata("Argument", Argument)
+		.Execute(&Life::Builder().Build())
+		.await
+}
+
+#[tokio::test]
+async fn Accepted() {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	assert_eq!(Copy(&Plan("integer", &Ran), json!(["a.txt", 3])).await, Ok(()));
+
+	assert_eq!(Copy(&Plan("any", &Ran), json!(["a.txt", 3])).await, Ok(()));
+
+	assert_eq!(Ran.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn Arity() {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Plan("integer", &Ran);
+
+	assert_eq!(
+		Copy(&Plan, json!(["a.txt"])).await,
+		Err(Error::Execution(
+			"Action Copy expects 2 arguments (string, integer), got 1".to_string()
+		))
+	);
+
+	assert_eq!(
+		Copy(&Plan, json!(["a.txt", 3, true])).await,
+		Err(Error::Execution(
+			"Action Copy expects 2 arguments (string, integer), got 3".to_string()
+		))
+	);
+
+	assert_eq!(Ran.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn Mismatch() {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	let Plan = Plan("integer", &Ran);
+
+	assert_eq!(
+		Copy(&Plan, json!([7, 3])).await,
+		Err(Error::Execution(
+			"Argument 0 of action Copy must be string, got integer".to_string()
+		))
+	);
+
+	assert_eq!(
+		Copy(&Plan, json!(["a.txt", 1.5])).await,
+		Err(Error::Execution(
+			"Argument 1 of action Copy must be integer, got number".to_string()
+		))
+	);
+
+	assert_eq!(Ran.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn Output() {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	// The function ran, but its result is not what the signature promised
+	assert_eq!(
+		Copy(&Plan("string", &Ran), json!(["a.txt", 3])).await,
+		Err(Error::Execution("Action Copy must return string, got integer".to_string()))
+	);
+
+	assert_eq!(Ran.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn Unknown() {
+	let Ran = Arc::new(AtomicUsize::new(0));
+
+	assert_eq!(
+		Copy(&Plan("text", &Ran), json!(["a.txt", 3])).await,
+		Err(Error::Execution(
+			"Signature of action Copy names an unknown type: text".to_string()
+		))
+	);
+
+	// The manifest carries the types, so deployments compare them
+	let Manifest = Plan("integer", &Ran).ExportManifest();
+
+	assert_eq!(Manifest.Action[0].InputTypes, ["string", "integer"]);
+
+	assert_eq!(Manifest.Action[0].OutputType.as_deref(), Some("integer"));
+
+	let Diff = Manifest.Diff(&Plan("string", &Ran).ExportManifest());
+
+	assert_eq!(Diff.Changed.len(), 1);
+
+	assert_eq!(Diff.Changed[0].Field, "OutputType");
+}
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+	},
+};
@@ -0,0 +1,182 @@
+#![allow(non_snake_case)]
+
+//! Checks the pump serving a WebSocket over an in-memory pipe: text and
+//! binary messages are submissions, pings are answered and pongs ignored, a
+//! malformed message gets an error reply, a reply that cannot be encoded is
+//! replaced by an error about the same submission instead of being dropped,
+//! and a close message drains the connection and completes the closing
+//! handshake.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// The JSON codec, failing to encode results whose value is `"Unencodable"`.
+struct Brittle;
+
+impl Codec for Brittle {
+	fn Decode(&self, Frame:&[u8]) -> Result<Message, String> { Json.Decode(Frame) }
+
+	fn Encode(&self, Reply:&Reply) -> Result<Vec<u8>, String> {
+		match Reply {
+			Reply::Result { Value, .. } if Value == "Unencodable" => {
+				Err("the value cannot be encoded".to_string())
+			},
+			_ => Json.Encode(Reply),
+		}
+	}
+
+	fn EncodeMessage(&self, Message:&Message) -> Result<Vec<u8>, String> {
+		Json.EncodeMessage(Message)
+	}
+
+	fn DecodeReply(&self, Frame:&[u8]) -> Result<Reply, String> { Json.DecodeReply(Frame) }
+}
+
+/// A submission of `Echo` to the `main` queue.
+fn Submit(Id:&str, Argument:&str) -> String {
+	json!({
+		"Type": "Submit",
+		"Id": Id,
+		"Action": "Echo",
+		"Argument": [Argument],
+		"Metadata": { "Queue": "main" },
+	})
+	.to_string()
+}
+
+#[tokio::test]
+async fn Framed() {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Echo"))
+		.WithFunction("Echo", |Argument:Vec<Value>| async move { Ok(Argument[0].clone()) })
+		.unwrap()
+		.Build();
+
+	let Pump = Pump::New(Life, Arc::new(Plan)).WithCodec(Arc::new(Brittle));
+
+	let (Client, Server) = duplex(1 << 16);
+
+	let Serving = tokio::spawn(async move {
+		let (Sink, Stream) =
+			WebSocketStream::from_raw_socket(Server, Role::Server, None).await.split();
+
+		Pump.Run(Reader::New(Stream), Writer::New(Sink)).await
+	});
+
+	let mut Client = WebSocketStream::from_raw_socket(Client, Role::Client, None).await;
+
+	for Frame in [
+		WsMessage::Ping(b"Echo".to_vec()),
+		WsMessage::Pong(b"unsolicited".to_vec()),
+		WsMessage::Text(Submit("text", "Hello")),
+		WsMessage::Binary(Submit("binary", "World").into_bytes()),
+		WsMessage::Text("{ not json".to_string()),
+		WsMessage::Text(Submit("brittle", "Unencodable")),
+	] {
+		Client.send(Frame).await.unwrap();
+	}
+
+	let mut Pong = Vec::new();
+
+	let mut Reply = HashMap::new();
+
+	let mut Malformed = 0;
+
+	while Reply.len() < 3 {
+		match timeout(Duration::from_secs(10), Client.next()).await.unwrap().unwrap().unwrap() {
+			WsMessage::Pong(Payload) => Pong.push(Payload),
+			WsMessage::Text(Text) => {
+				let Value:Value = serde_json::from_str(&Text).unwrap();
+
+				match (Value["Type"].as_str().unwrap(), Value["Id"].as_str()) {
+					("Ack", _) => {},
+					("Error", None) => Malformed += 1,
+					(_, Some(Id)) => {
+						Reply.insert(Id.to_string(), Value);
+					},
+					_ => panic!("Unexpected reply {}", Value),
+				}
+			},
+			Other => panic!("Unexpected message {:?}", Other),
+		}
+	}
+
+	assert_eq!(Pong, [b"Echo".to_vec()]);
+
+	assert_eq!(Malformed, 1);
+
+	for (Id, Value) in [("text", "Hello"), ("binary", "World")] {
+		assert_eq!((&Reply[Id]["Type"], &Reply[Id]["Value"]), (&json!("Result"), &json!(Value)));
+	}
+
+	assert_eq!(Reply["brittle"]["Type"], "Error");
+
+	assert!(Reply["brittle"]["Message"].as_str().unwrap().contains("cannot be encoded"));
+
+	// Closing drains the connection and the server answers the close
+	Client.close(None).await.unwrap();
+
+	let Closed = timeout(Duration::from_secs(10), async {
+		while let Some(Message) = Client.next().await {
+			if matches!(Message, Ok(WsMessage::Close(_)) | Err(_)) {
+				break;
+			}
+		}
+	});
+
+	Closed.await.unwrap();
+
+	timeout(Duration::from_secs(10), Serving).await.unwrap().unwrap().unwrap();
+}
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::{
+	io::duplex,
+	time::{timeout, Duration},
+};
+use tokio_tungstenite::{
+	tungstenite::{protocol::Role, Message as WsMessage},
+	WebSocketStream,
+};
+use Echo::{
+	Enum::{
+		Sequence::Action::Error::Enum as Error,
+		Transport::{Message::Enum as Message, Reply::Enum as Reply},
+	},
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{
+			Codec::Json::Struct as Json,
+			Frame::Socket::{Reader::Struct as Reader, Writer::Struct as Writer},
+			Pump::Struct as Pump,
+		},
+	},
+	Trait::{
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+		Transport::Codec::Trait as Codec,
+	},
+};
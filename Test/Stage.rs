@@ -0,0 +1,208 @@
+#![allow(non_snake_case)]
+
+//! Checks the order of the hook stages against a `Delay` on the paused
+//! clock: `Accepted` when the action is submitted, `BeforeDelay` when a
+//! worker holds it, `BeforeFunction` once it is due, then `AfterFunction` or
+//! `Failed`, and that the earlier phase keys still attach to their stages.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// When each hook or the function ran, in milliseconds since `Start`.
+type Record = Arc<Mutex<Vec<(String, u128)>>>;
+
+/// Starts a sequence consuming the `main` queue of a new `Life` whose hooks
+/// record when they run, and a plan whose `Work` function does too and
+/// fails when its argument is `true`.
+fn Start(Hook:&[&str]) -> (Life, Sequence, Arc<Plan>, Record) {
+	let (Record, Start) = (Record::default(), Instant::now());
+
+	// A single attempt, so a failure is final
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	for Name in Hook {
+		let (Record, Name) = (Record.clone(), Name.to_string());
+
+		Life.Span.insert(
+			Name.clone(),
+			Arc::new(move || {
+				Record.lock().unwrap().push((Name.clone(), Start.elapsed().as_millis()));
+
+				Ok(())
+			}),
+		);
+	}
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Work"))
+		.WithFunction("Work", {
+			let Record = Record.clone();
+
+			move |Argument:Vec<Value>| {
+				Record.lock().unwrap().push(("Work".to_string(), Start.elapsed().as_millis()));
+
+				async move {
+					match Argument.first() {
+						Some(Value::Bool(true)) => Err(Error::Execution("worn out".to_string())),
+						_ => Ok(Value::Null),
+					}
+				}
+			}
+		})
+		.unwrap()
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	(Life, Sequence, Arc::new(Plan), Record)
+}
+
+/// Creates a `Work` action in the `main` queue delayed by 300ms.
+fn Delayed(Plan:&Arc<Plan>, Fail:bool, Hooks:Value) -> Box<dyn Action> {
+	Box::new(
+		Echo::Struct::Sequence::Action::Struct::New("Work", Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!("main"))
+			.WithMetadata("Argument", json!([Fail]))
+			.WithMetadata("Delay", json!("300ms"))
+			.WithMetadata("Hooks", Hooks),
+	)
+}
+
+#[tokio::test(start_paused = true)]
+async fn Ordered() {
+	let (Life, Sequence, Plan, Record) =
+		Start(&["accepted", "delaying", "starting", "ending", "alerting"]);
+
+	let Hooks = json!({
+		"Accepted": ["accepted"],
+		"BeforeDelay": ["delaying"],
+		"BeforeFunction": ["starting"],
+		"AfterFunction": ["ending"],
+		"Failed": ["alerting"],
+	});
+
+	let Pending = Life.Submit(Delayed(&Plan, false, Hooks.clone())).await;
+
+	// Accepted before the action reached its queue
+	assert_eq!(*Record.lock().unwrap(), [("accepted".to_string(), 0)]);
+
+	timeout(Duration::from_secs(2), Pending).await.unwrap().unwrap();
+
+	assert_eq!(
+		*Record.lock().unwrap(),
+		[
+			("accepted".to_string(), 0),
+			("delaying".to_string(), 0),
+			("starting".to_string(), 300),
+			("Work".to_string(), 300),
+			("ending".to_string(), 300),
+		]
+	);
+
+	Record.lock().unwrap().clear();
+
+	let Pending = Life.Submit(Delayed(&Plan, true, Hooks)).await;
+
+	assert!(timeout(Duration::from_secs(2), Pending).await.unwrap().is_err());
+
+	let Record = Record.lock().unwrap().clone();
+
+	assert_eq!(
+		Record.iter().map(|(Name, _)| Name.as_str()).collect::<Vec<_>>(),
+		["accepted", "delaying", "starting", "Work", "alerting"]
+	);
+
+	// The delay counts from the submission, however soon a worker held it
+	assert_eq!(Record[0].1 + 300, Record[2].1);
+
+	assert!(Record[0].1 <= Record[1].1 && Record[1].1 < Record[2].1);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn Legacy() {
+	let (Life, Sequence, Plan, Record) = Start(&["starting", "ending"]);
+
+	// The array form and the earlier keys run once the delay elapsed
+	let Pending = Life.Submit(Delayed(&Plan, false, json!(["starting"]))).await;
+
+	timeout(Duration::from_secs(2), Pending).await.unwrap().unwrap();
+
+	let Pending = Life
+		.Submit(Delayed(&Plan, false, json!({ "AfterDelay": ["starting"], "After": ["ending"] })))
+		.await;
+
+	timeout(Duration::from_secs(2), Pending).await.unwrap().unwrap();
+
+	assert_eq!(
+		*Record.lock().unwrap(),
+		[
+			("starting".to_string(), 300),
+			("Work".to_string(), 300),
+			("starting".to_string(), 600),
+			("Work".to_string(), 600),
+			("ending".to_string(), 600),
+		]
+	);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn Refused() {
+	let (Life, Sequence, Plan, Record) = Start(&[]);
+
+	Life.Span
+		.insert("gate".to_string(), Arc::new(|| Err(Error::Execution("closed".to_string()))));
+
+	// A failing Accepted hook refuses the action before it is enqueued
+	let Outcome = Life.Submit(Delayed(&Plan, false, json!({ "Accepted": ["gate"] }))).await.await;
+
+	assert_eq!(Outcome, Err(Error::Execution("closed".to_string())));
+
+	assert_eq!(Life.Karma.get("main").unwrap().Stats().Enqueued, 0);
+
+	assert!(Record.lock().unwrap().is_empty());
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::{timeout, Instant};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Plan::Formality::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
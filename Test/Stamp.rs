@@ -0,0 +1,168 @@
+#![allow(non_snake_case)]
+
+//! Checks the stamps of a production line: producers enqueuing at once on
+//! several threads each receive unique sequence numbers, increasing in the
+//! order of their own calls and dequeued in that order, and the wait of an
+//! action measured on the paused clock is exactly the time it spent queued,
+//! as recorded in `echo_queue_wait_seconds`.
+
+/// Records the histogram samples taken while it is the local recorder.
+#[derive(Default)]
+struct Tally {
+	/// The samples of each histogram by name.
+	Histogram:Mutex<HashMap<String, Arc<Samples>>>,
+}
+
+/// The samples of one histogram.
+#[derive(Default)]
+struct Samples(Mutex<Vec<f64>>);
+
+impl HistogramFn for Samples {
+	fn record(&self, Value:f64) { self.0.lock().unwrap().push(Value); }
+}
+
+impl Tally {
+	/// Returns the samples of a histogram, none if it was never registered.
+	fn Get(&self, Name:&str) -> Vec<f64> {
+		self.Histogram
+			.lock()
+			.unwrap()
+			.get(Name)
+			.map_or_else(Vec::new, |Sampled| Sampled.0.lock().unwrap().clone())
+	}
+}
+
+impl Recorder for Tally {
+	fn describe_counter(&self, _:KeyName, _:Option<Unit>, _:SharedString) {}
+
+	fn describe_gauge(&self, _:KeyName, _:Option<Unit>, _:SharedString) {}
+
+	fn describe_histogram(&self, _:KeyName, _:Option<Unit>, _:SharedString) {}
+
+	fn register_counter(&self, _:&Key, _:&Metadata<'_>) -> Counter { Counter::noop() }
+
+	fn register_gauge(&self, _:&Key, _:&Metadata<'_>) -> Gauge { Gauge::noop() }
+
+	fn register_histogram(&self, Key:&Key, _:&Metadata<'_>) -> Histogram {
+		Histogram::from_arc(
+			self.Histogram.lock().unwrap().entry(Key.name().to_string()).or_default().clone(),
+		)
+	}
+}
+
+/// Creates an action identified by its name.
+fn Named(Name:&str) -> Box<dyn Action> {
+	Box::new(Echo::Struct::Sequence::Action::Struct::New(
+		Name,
+		Value::Null,
+		Arc::new(Plan::New().Build()),
+	))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn Monotonic() {
+	let Production = Arc::new(Production::New());
+
+	let Producer = (0..8).map(|Producer| {
+		let Production = Production.clone();
+
+		tokio::spawn(async move {
+			let mut Sequence = Vec::new();
+
+			for Index in 0..100 {
+				let Stamp =
+					Production.Enqueue(Named(&format!("{}-{}", Producer, Index))).await.unwrap();
+
+				Sequence.push(Stamp.Sequence);
+
+				tokio::task::yield_now().await;
+			}
+
+			(Producer, Sequence)
+		})
+	});
+
+	let mut Assigned = HashMap::new();
+
+	for (Producer, Sequence) in join_all(Producer).await.into_iter().map(Result::unwrap) {
+		// Increasing in the order each producer enqueued
+		assert!(Sequence.windows(2).all(|Pair| Pair[0] < Pair[1]), "{:?}", Sequence);
+
+		for (Index, Sequence) in Sequence.into_iter().enumerate() {
+			assert!(Assigned.insert(Sequence, format!("{}-{}", Producer, Index)).is_none());
+		}
+	}
+
+	// Unique, and none skipped
+	assert_eq!(Assigned.keys().copied().collect::<BTreeSet<_>>(), (0..800).collect());
+
+	let mut Previous:Option<Stamp> = None;
+
+	while let Some((Stamp, Action)) = Production.DequeueStamped().await {
+		let Name = Action.Metadata("Action").await.unwrap();
+
+		assert_eq!(Name.as_str(), Some(Assigned[&Stamp.Sequence].as_str()));
+
+		if let Some(Previous) = Previous {
+			assert!(Previous.Sequence < Stamp.Sequence);
+
+			assert!(Previous.Instant <= Stamp.Instant);
+		}
+
+		Previous = Some(Stamp);
+	}
+
+	assert_eq!(Previous.map(|Stamp| Stamp.Sequence), Some(799));
+}
+
+#[tokio::test(start_paused = true)]
+async fn Wait() {
+	let Tally = Tally::default();
+
+	// The test runs on this thread alone
+	let _Recorder = metrics::set_default_local_recorder(&Tally);
+
+	let Production = Production::New();
+
+	let First = Production.Enqueue(Named("First")).await.unwrap();
+
+	advance(Duration::from_millis(150)).await;
+
+	let Second = Production.Enqueue(Named("Second")).await.unwrap();
+
+	advance(Duration::from_millis(250)).await;
+
+	assert_eq!(Second.Instant - First.Instant, Duration::from_millis(150));
+
+	let (Stamp, _) = Production.DequeueStamped().await.unwrap();
+
+	assert_eq!((Stamp.Sequence, Stamp.Wait()), (0, Duration::from_millis(400)));
+
+	advance(Duration::from_millis(100)).await;
+
+	let (Stamp, _) = Production.DequeueStamped().await.unwrap();
+
+	assert_eq!((Stamp.Sequence, Stamp.Wait()), (1, Duration::from_millis(350)));
+
+	assert_eq!(Tally.Get("echo_queue_wait_seconds"), [0.4, 0.35]);
+}
+
+use std::{
+	collections::{BTreeSet, HashMap},
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use futures::future::join_all;
+use metrics::{
+	Counter, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+};
+use serde_json::Value;
+use tokio::time::advance;
+use Echo::{
+	Struct::Sequence::{
+		Plan::Struct as Plan,
+		Production::{Stamp::Struct as Stamp, Struct as Production},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,135 @@
+#![allow(non_snake_case)]
+
+//! Checks `Life::Stats` with two queues and a running sequence: a `main`
+//! queue whose failures go to a paused `dead` queue. Once every action was
+//! handled, each queue holds what it was given minus what it handed out,
+//! the sequence processed exactly what the queues handed out, the
+//! dead-letter depth counts the failures, and the snapshot survives a JSON
+//! round trip.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+#[tokio::test]
+async fn Consistent() {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", |_| async { Ok(json!("done")) })
+			.unwrap()
+			.WithSignature(Signature::New("Fail"))
+			.WithFunction("Fail", |_| async { Err(Error::Execution("broken".to_string())) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue(
+			"main",
+			Arc::new(Production::New()),
+			Settings::New().WithConcurrency(2).WithDeadLetter("dead"),
+		)
+		.WithQueue("dead", Arc::new(Production::New()), Settings::New().WithPaused(true))
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let mut Pending = Vec::new();
+
+	for Name in ["Work", "Fail", "Work", "Work", "Fail", "Work", "Work", "Work"] {
+		let Action = Echo::Struct::Sequence::Action::Struct::New(Name, Value::Null, Plan.clone())
+			.WithMetadata("Queue", json!("main"));
+
+		Pending.push(Life.Submit(Box::new(Action)).await);
+	}
+
+	timeout(Duration::from_secs(10), join_all(Pending)).await.unwrap();
+
+	// Settled once every attempt was counted and every failure dead-lettered
+	let Stats = timeout(Duration::from_secs(5), async {
+		loop {
+			let Stats = Life.Stats();
+
+			if Stats.DeadLetter == 2 && Stats.Sequence.iter().all(|Count| Count.Active == 0) {
+				break Stats;
+			}
+
+			sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	let (Main, Dead) = (&Stats.Queue["main"], &Stats.Queue["dead"]);
+
+	assert_eq!((Main.Enqueued, Main.Dequeued, Main.Depth, Main.Paused), (8, 8, 0, false));
+
+	assert_eq!((Dead.Enqueued, Dead.Dequeued, Dead.Depth, Dead.Paused), (2, 0, 2, true));
+
+	for Queue in Stats.Queue.values() {
+		assert_eq!(Queue.Enqueued - Queue.Dequeued, Queue.Depth);
+	}
+
+	assert_eq!(Stats.Sequence.len(), 1);
+
+	let Processed = Stats.Sequence[0];
+
+	assert_eq!((Processed.Active, Processed.Total, Processed.Failed), (0, 8, 2));
+
+	assert!(Processed.Last.is_some());
+
+	let Dequeued:u64 = Stats.Queue.values().map(|Queue| Queue.Dequeued).sum();
+
+	assert_eq!(Dequeued, Processed.Total + Processed.Active);
+
+	assert_eq!(Stats.DeadLetter, Dead.Depth);
+
+	assert_eq!(Stats.Connection, Count::default());
+
+	assert!(Stats.Cache.is_some());
+
+	let Restored:Snapshot = serde_json::from_str(&serde_json::to_string(&Stats).unwrap()).unwrap();
+
+	assert_eq!(Restored.Queue, Stats.Queue);
+
+	assert_eq!(Restored.Sequence, Stats.Sequence);
+
+	Sequence.Shutdown().await;
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use futures::future::join_all;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Stats::{Activity::Count::Struct as Count, Snapshot::Struct as Snapshot},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
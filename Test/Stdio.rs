@@ -0,0 +1,154 @@
+#![allow(non_snake_case)]
+
+//! Checks the stdio transport end to end over in-memory pipes: submissions
+//! written as JSON lines are acknowledged and answered on the output, a
+//! malformed line gets an error reply without ending the stream, and the
+//! end of input drains the jobs still running before the transport returns
+//! and closes its output.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A plan whose `Add` sums its arguments and whose `Sleep` waits before
+/// answering.
+fn Plan() -> Arc<Plan> {
+	Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Add"))
+			.WithSignature(Signature::New("Sleep"))
+			.WithFunction("Add", |Argument:Vec<Value>| async move {
+				Ok(json!(Argument.iter().filter_map(Value::as_i64).sum::<i64>()))
+			})
+			.unwrap()
+			.WithFunction("Sleep", |_| async {
+				sleep(Duration::from_millis(200)).await;
+
+				Ok(json!("Slept"))
+			})
+			.unwrap()
+			.Build(),
+	)
+}
+
+/// Serves the lines on a stdio transport until they end.
+///
+/// # Returns
+///
+/// Every reply the transport wrote.
+async fn Serve(Lines:&[&str]) -> Vec<Value> {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Stdio = Stdio::New(Pump::New(Life, Plan()));
+
+	let (mut Stdin, Input) = duplex(1 << 16);
+
+	let (Output, Stdout) = duplex(1 << 16);
+
+	let Serving = tokio::spawn(async move { Stdio.Serve(Input, Output).await });
+
+	for Line in Lines {
+		Stdin.write_all(format!("{}\n", Line).as_bytes()).await.unwrap();
+	}
+
+	// The end of input
+	drop(Stdin);
+
+	let mut Reply = Vec::new();
+
+	let mut Stdout = BufReader::new(Stdout).lines();
+
+	while let Some(Line) =
+		timeout(Duration::from_secs(10), Stdout.next_line()).await.unwrap().unwrap()
+	{
+		Reply.push(serde_json::from_str(&Line).unwrap());
+	}
+
+	timeout(Duration::from_secs(10), Serving).await.unwrap().unwrap().unwrap();
+
+	Reply
+}
+
+/// A submission of an action to the `main` queue.
+fn Submit(Id:&str, Action:&str, Argument:Value) -> String {
+	json!({
+		"Type": "Submit",
+		"Id": Id,
+		"Action": Action,
+		"Argument": Argument,
+		"Metadata": { "Queue": "main" },
+	})
+	.to_string()
+}
+
+/// The replies of a type, by identifier.
+fn Of<'a>(Reply:&'a [Value], Type:&str) -> BTreeMap<&'a str, &'a Value> {
+	Reply
+		.iter()
+		.filter(|Reply| Reply["Type"] == Type)
+		.map(|Reply| (Reply["Id"].as_str().unwrap_or_default(), Reply))
+		.collect()
+}
+
+#[tokio::test]
+async fn Answered() {
+	let Reply = Serve(&[
+		&Submit("1", "Add", json!([1, 2])),
+		"{ not json",
+		r#"{"Type":"Ping"}"#,
+		&Submit("2", "Add", json!([3, 4])),
+	])
+	.await;
+
+	assert_eq!(Of(&Reply, "Ack").into_keys().collect::<Vec<_>>(), ["1", "2"]);
+
+	let Result = Of(&Reply, "Result");
+
+	assert_eq!((&Result["1"]["Value"], &Result["2"]["Value"]), (&json!(3), &json!(7)));
+
+	assert_eq!(Reply.iter().filter(|Reply| Reply["Type"] == "Pong").count(), 1);
+
+	assert_eq!(Of(&Reply, "Error").len(), 1, "{:?}", Reply);
+}
+
+#[tokio::test]
+async fn Drained() {
+	let Reply = Serve(&[&Submit("slow", "Sleep", json!([]))]).await;
+
+	assert_eq!(Of(&Reply, "Result")["slow"]["Value"], "Slept");
+}
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{sleep, timeout, Duration},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Pump::Struct as Pump, Stdio::Struct as Stdio},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
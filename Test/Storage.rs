@@ -0,0 +1,160 @@
+#![allow(non_snake_case)]
+
+//! Checks that the storage reader survives damaged files: a file cut at
+//! every offset yields the records before the cut, a flipped bit in the
+//! header is refused, and one in a frame's length, checksum or payload
+//! skips frames without panicking or returning a damaged record. Concurrent
+//! writes of one file never collide on their temporary files.
+
+/// A fresh, empty directory for one test.
+async fn Root(Name:&str) -> PathBuf {
+	let Root = std::env::temp_dir().join(format!("Echo-Storage-{}-{}", Name, std::process::id()));
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	Root
+}
+
+/// The records written by every test.
+fn Records() -> Vec<Value> {
+	vec![json!({ "Id": 1, "Name": "first" }), json!([2, "second"]), json!("third")]
+}
+
+/// Writes the records and returns the bytes of the file.
+async fn Written(Storage:&Storage, Path:&Path) -> Vec<u8> {
+	Storage.Write(Path, &Records()).await.unwrap();
+
+	tokio::fs::read(Path).await.unwrap()
+}
+
+/// Reads a damaged copy of a file.
+async fn Damaged(Storage:&Storage, Path:&Path, Content:&[u8]) -> io::Result<Recovered> {
+	tokio::fs::write(Path, Content).await.unwrap();
+
+	Storage.Read(Path).await
+}
+
+#[tokio::test]
+async fn Truncated() {
+	let Root = Root("Truncated").await;
+
+	let Storage = Storage::default();
+
+	let Content = Written(&Storage, &Root.join("intact")).await;
+
+	// Where every frame ends
+	let mut End = vec![6];
+
+	for Record in Records() {
+		let Length = serde_json::to_vec(&Record).unwrap().len();
+
+		End.push(End.last().unwrap() + 8 + Length);
+	}
+
+	assert_eq!(*End.last().unwrap(), Content.len());
+
+	for Cut in 0..=Content.len() {
+		let Read = Damaged(&Storage, &Root.join("cut"), &Content[..Cut]).await;
+
+		if Cut < 6 {
+			assert!(Read.is_err(), "cut at {}", Cut);
+
+			continue;
+		}
+
+		let Recovered = Read.unwrap();
+
+		let Whole = End.iter().filter(|End| **End <= Cut).count() - 1;
+
+		assert_eq!(Recovered.Record, Records()[..Whole], "cut at {}", Cut);
+
+		assert_eq!(Recovered.Skipped.len(), usize::from(!End.contains(&Cut)), "cut at {}", Cut);
+	}
+}
+
+#[tokio::test]
+async fn Flipped() {
+	let Root = Root("Flipped").await;
+
+	let Storage = Storage::default();
+
+	let Content = Written(&Storage, &Root.join("intact")).await;
+
+	for Byte in 0..Content.len() {
+		for Bit in 0..8 {
+			let mut Flipped = Content.clone();
+
+			Flipped[Byte] ^= 1 << Bit;
+
+			let Read = Damaged(&Storage, &Root.join("flipped"), &Flipped).await;
+
+			if Byte < 6 {
+				assert!(Read.is_err(), "bit {} of byte {}", Bit, Byte);
+
+				continue;
+			}
+
+			let Recovered = Read.unwrap();
+
+			// A damaged frame is skipped, never returned
+			assert!(
+				Recovered.Record.iter().all(|Record| Records().contains(Record)),
+				"bit {} of byte {}",
+				Bit,
+				Byte
+			);
+
+			assert!(!Recovered.Skipped.is_empty(), "bit {} of byte {}", Bit, Byte);
+
+			assert!(Recovered.Record.len() < Records().len(), "bit {} of byte {}", Bit, Byte);
+		}
+	}
+}
+
+#[tokio::test]
+async fn Concurrent() {
+	let Root = Root("Concurrent").await;
+
+	let Storage = Storage::default();
+
+	let Path = Root.join("shared");
+
+	let Writes = (0..16)
+		.map(|Index| {
+			let (Storage, Path) = (Storage.clone(), Path.clone());
+
+			tokio::spawn(async move { Storage.Write(&Path, &[json!(Index)]).await })
+		})
+		.collect::<Vec<_>>();
+
+	for Write in Writes {
+		Write.await.unwrap().unwrap();
+	}
+
+	let Recovered = Storage.Read(&Path).await.unwrap();
+
+	assert_eq!(Recovered.Record.len(), 1);
+
+	assert!(Recovered.Skipped.is_empty());
+
+	// No temporary file is left behind
+	let mut Entry = tokio::fs::read_dir(&Root).await.unwrap();
+
+	let mut Name = Vec::new();
+
+	while let Some(Entry) = Entry.next_entry().await.unwrap() {
+		Name.push(Entry.file_name());
+	}
+
+	assert_eq!(Name, ["shared"]);
+}
+
+use std::{
+	io,
+	path::{Path, PathBuf},
+};
+
+use serde_json::{json, Value};
+use Echo::Struct::Storage::{Recovered::Struct as Recovered, Struct as Storage};
@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+
+//! Checks the LRU store: inserting past the entry or byte limit evicts the
+//! least recently used entries and hands them back, an entry read recently
+//! survives, and a smaller limit evicts what no longer fits.
+
+#[test]
+fn Entries() {
+	let Store = Store::New("test", Limit::New().WithEntries(Some(3)).WithBytes(None));
+
+	for Key in ["a", "b", "c"] {
+		assert!(Store.Insert(Key, Key.to_uppercase(), 1).is_empty());
+	}
+
+	// Reading `a` makes `b` the least recently used
+	assert_eq!(Store.Get(&"a").as_deref(), Some("A"));
+
+	assert_eq!(Store.Insert("d", "D".to_string(), 1), [("b", "B".to_string())]);
+
+	assert_eq!(Store.Get(&"b"), None);
+
+	assert_eq!(Store.Get(&"a").as_deref(), Some("A"));
+
+	assert_eq!(Store.Insert("e", "E".to_string(), 1), [("c", "C".to_string())]);
+
+	assert_eq!((Store.Len(), Store.Evicted()), (3, 2));
+
+	for Key in ["a", "d", "e"] {
+		assert!(Store.Get(&Key).is_some(), "{} was evicted", Key);
+	}
+}
+
+#[test]
+fn Bytes() {
+	let Store = Store::New("test", Limit::New().WithEntries(None).WithBytes(Some(100)));
+
+	for Key in 0..4 {
+		Store.Insert(Key, Key, 25);
+	}
+
+	assert_eq!(Store.Bytes(), 100);
+
+	Store.Get(&0);
+
+	// Room for a large entry is made from the least recently used up
+	assert_eq!(Store.Insert(4, 4, 50), [(1, 1), (2, 2)]);
+
+	assert_eq!(Store.Bytes(), 100);
+
+	// A smaller limit evicts down to it
+	assert_eq!(Store.Resize(Limit::New().WithEntries(Some(1)).WithBytes(None)), [(3, 3), (0, 0)]);
+
+	assert_eq!(Store.Get(&4), Some(4));
+
+	assert_eq!((Store.Len(), Store.Evicted()), (1, 4));
+}
+
+use Echo::Struct::Store::{Limit::Struct as Limit, Struct as Store};
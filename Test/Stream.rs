@@ -0,0 +1,172 @@
+#![allow(non_snake_case)]
+
+//! Checks streaming output over a connection: 10 MiB written by a handler
+//! into its output sink arrive as `Partial` replies of 64 KiB each, intact
+//! and in order, before the `Result` counting the bytes, and a client
+//! reading slowly holds the handler back to a bounded lead instead of the
+//! output piling up in memory.
+
+/// The size of the streamed output.
+const SIZE:u64 = 10 * 1024 * 1024;
+
+/// The size of one `Partial` reply.
+const CHUNK:u64 = 64 * 1024;
+
+/// How far the handler may run ahead of the client: the partial channel of
+/// the connection, the chunk being written and the framing buffers.
+const LEAD:u64 = 32 * CHUNK;
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// The byte at an offset of the streamed output.
+fn Byte(Offset:u64) -> u8 { (Offset % 251) as u8 }
+
+/// A pump over a running `Life` whose `Produce` action streams `SIZE` bytes
+/// into its output sink, counting them in `Written`.
+fn Start(Written:Arc<AtomicU64>) -> Pump {
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Produce"))
+			.WithFunction("Produce", move |_| {
+				let Written = Written.clone();
+
+				async move {
+					let Failure = |_Error:std::io::Error| Error::Execution(_Error.to_string());
+
+					let mut Sink = Invocation::Current()
+						.and_then(|Invocation| Invocation.OutputSink())
+						.ok_or_else(|| Error::Execution("No output sink".to_string()))?;
+
+					for Start in (0..SIZE).step_by(CHUNK as usize) {
+						let Chunk = (Start..Start + CHUNK).map(Byte).collect::<Vec<_>>();
+
+						Sink.write_all(&Chunk).await.map_err(Failure)?;
+
+						Written.store(Start + CHUNK, Ordering::SeqCst);
+					}
+
+					Sink.shutdown().await.map_err(Failure)?;
+
+					Ok(Value::Null)
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	Pump::New(Life, Plan)
+}
+
+#[tokio::test]
+async fn Bounded() {
+	let Written = Arc::new(AtomicU64::new(0));
+
+	let Pump = Start(Written.clone());
+
+	let (Client, Server) = duplex(1 << 16);
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output)).await
+	});
+
+	let (Reader, mut Writer) = split(Client);
+
+	let mut Reader = BufReader::new(Reader).lines();
+
+	let Submit = json!({
+		"Type": "Submit",
+		"Id": "1",
+		"Action": "Produce",
+		"Metadata": { "Queue": "main" },
+		"Options": { "OutputTo": "Partial" },
+	});
+
+	Writer.write_all(format!("{}\n", Submit).as_bytes()).await.unwrap();
+
+	let (mut Received, mut Chunk) = (0u64, 0u64);
+
+	let Result = loop {
+		let Line = timeout(Duration::from_secs(30), Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		let Reply:Value = serde_json::from_str(&Line).unwrap();
+
+		match Reply["Type"].as_str() {
+			Some("Partial") => {
+				let Data = STANDARD.decode(Reply["Data"].as_str().unwrap()).unwrap();
+
+				assert_eq!(Data.len() as u64, CHUNK);
+
+				assert!(Data.iter().zip(Received..).all(|(Data, Offset)| *Data == Byte(Offset)));
+
+				Received += CHUNK;
+
+				Chunk += 1;
+
+				let Lead = Written.load(Ordering::SeqCst) - Received.min(SIZE);
+
+				assert!(Lead <= LEAD, "the handler ran {} bytes ahead", Lead);
+
+				// Read slowly, so the handler has to wait for room
+				sleep(Duration::from_millis(2)).await;
+			},
+			Some("Result") => break Reply,
+			_ => {},
+		}
+	};
+
+	assert_eq!((Received, Chunk), (SIZE, SIZE / CHUNK));
+
+	assert_eq!(Result["Value"], json!({ "OutputTo": "Partial", "Bytes": SIZE }));
+}
+
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use tokio::{
+	io::{duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Invocation::Struct as Invocation,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,300 @@
+#![allow(non_snake_case)]
+
+//! Checks the submission history of a `Pump` serving a `Life`: every
+//! submission and malformed message is recorded with the identity of its
+//! connection, whether accepted or not, and `QuerySubmissions` selects them
+//! by identity, admission and time, a page at a time.
+
+/// The client end of one connection to a pump.
+struct Client {
+	/// Where messages are written.
+	Writer:WriteHalf<DuplexStream>,
+
+	/// Where replies are read.
+	Reader:Lines<BufReader<ReadHalf<DuplexStream>>>,
+}
+
+impl Client {
+	/// Opens a connection served by `Pump`.
+	fn Connect(Pump:&Pump) -> Self {
+		let (Client, Server) = duplex(1 << 16);
+
+		let Pump = Pump.clone();
+
+		tokio::spawn(async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		});
+
+		let (Reader, Writer) = split(Client);
+
+		Client { Writer, Reader:BufReader::new(Reader).lines() }
+	}
+
+	/// Sends one line as is.
+	async fn Raw(&mut self, Line:&str) {
+		self.Writer.write_all(format!("{}\n", Line).as_bytes()).await.unwrap();
+
+		self.Writer.flush().await.unwrap();
+	}
+
+	/// Sends one message.
+	async fn Send(&mut self, Message:Value) { self.Raw(&Message.to_string()).await; }
+
+	/// Authenticates with a token, returning the reply.
+	async fn Auth(&mut self, Token:&str) -> Value {
+		self.Send(json!({ "Type": "Auth", "Token": Token })).await;
+
+		self.Reply().await
+	}
+
+	/// Submits an action to the `main` queue, returning the first reply.
+	async fn Submit(&mut self, Id:&str, Action:&str, Argument:Value) -> Value {
+		self.Send(json!({
+			"Type": "Submit",
+			"Id": Id,
+			"Action": Action,
+			"Argument": Argument,
+			"Metadata": { "Queue": "main" },
+		}))
+		.await;
+
+		self.Reply().await
+	}
+
+	/// Selects a page of submissions.
+	async fn Query(&mut self, Query:&Query) -> Page {
+		self.Send(json!({ "Type": "QuerySubmissions", "Query": Query })).await;
+
+		let Reply = self.Reply().await;
+
+		assert_eq!(Reply["Type"], "Submissions", "unexpected reply {}", Reply);
+
+		serde_json::from_value(Reply["Page"].clone()).unwrap()
+	}
+
+	/// Reads the next reply.
+	async fn Reply(&mut self) -> Value {
+		let Line = timeout(Duration::from_secs(10), self.Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		serde_json::from_str(&Line).unwrap()
+	}
+}
+
+/// A pump over a `Life` with a `main` queue, accepting the shared token
+/// `client`, the admin token `admin` and the token of the `batch` identity.
+fn Start() -> Pump {
+	let Plan = Arc::new(
+		Echo::Builtin::Fs::Struct::New(std::env::temp_dir())
+			.Register(Echo::Struct::Sequence::Plan::Struct::New())
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	Pump::New(Life, Plan)
+		.WithToken("client")
+		.WithAdmin("admin")
+		.WithIdentity("batch", "batch-token")
+}
+
+/// Returns the submission identifiers of records, `-` for none.
+fn Id(Page:&Page) -> Vec<&str> {
+	Page.Record
+		.iter()
+		.map(|Record| Record.Id.as_deref().unwrap_or("-"))
+		.collect()
+}
+
+#[tokio::test]
+async fn Recorded() {
+	let Pump = Start();
+
+	let mut Batch = Client::Connect(&Pump);
+
+	assert_eq!(Batch.Auth("batch-token").await["Role"], "Client");
+
+	assert_eq!(Batch.Submit("1", "Read", json!(["in.txt"])).await["Type"], "Ack");
+
+	assert_eq!(Batch.Submit("2", "Reed", json!(["in.txt"])).await["Type"], "Error");
+
+	Batch.Raw("{ not json").await;
+
+	assert_eq!(Batch.Reply().await["Type"], "Error");
+
+	// Rejected before authenticating, and the connection is dropped
+	let mut Stranger = Client::Connect(&Pump);
+
+	assert_eq!(Stranger.Submit("3", "Read", json!(["in.txt"])).await["Type"], "Error");
+
+	let mut Admin = Client::Connect(&Pump);
+
+	assert_eq!(Admin.Auth("admin").await["Role"], "Admin");
+
+	Admin.Send(json!({ "Type": "Control", "Control": "Pause" })).await;
+
+	assert_eq!(Admin.Reply().await["State"], "Paused");
+
+	assert_eq!(Admin.Submit("4", "Read", json!(["in.txt"])).await["Type"], "Error");
+
+	let Every = Admin.Query(&Query::New()).await;
+
+	assert_eq!(Id(&Every), ["1", "2", "-", "3", "4"]);
+
+	assert_eq!(Every.Next, None);
+
+	let Record = &Every.Record;
+
+	assert_eq!(
+		Record
+			.iter()
+			.map(|Record| Record.Identity.as_str())
+			.collect::<Vec<_>>(),
+		["batch", "batch", "batch", "anonymous", "admin"]
+	);
+
+	assert_eq!(Record[0].Admission, Admission::Accepted);
+
+	assert_eq!(Record[0].Reason, None);
+
+	assert!(Record[1..]
+		.iter()
+		.all(|Record| Record.Admission == Admission::Rejected));
+
+	assert!(Record[2]
+		.Reason
+		.as_deref()
+		.is_some_and(|Reason| Reason.starts_with("Malformed")));
+
+	assert_eq!(Record[3].Reason.as_deref(), Some("Not authenticated"));
+
+	assert!(Record[4]
+		.Reason
+		.as_deref()
+		.is_some_and(|Reason| Reason.contains("Paused")));
+
+	// Submissions of one connection share its identifier
+	assert_eq!(Record[0].Connection, Record[2].Connection);
+
+	assert_ne!(Record[0].Connection, Record[3].Connection);
+
+	// The digest identifies the argument without exposing it
+	assert_eq!(Record[0].Digest, Record[1].Digest);
+
+	assert_eq!(Record[0].Digest, Some(Submission::Digest(&[json!("in.txt").into()])));
+
+	assert_eq!(Record[2].Digest, None);
+
+	let Listed = Admin.Query(&Query::New().WithIdentity("batch")).await;
+
+	assert_eq!(Id(&Listed), ["1", "2", "-"]);
+
+	let Listed = Admin
+		.Query(&Query::New().WithIdentity("batch").WithAdmission(Admission::Rejected))
+		.await;
+
+	assert_eq!(Id(&Listed), ["2", "-"]);
+
+	// Only admins may read the history
+	Batch.Send(json!({ "Type": "QuerySubmissions" })).await;
+
+	assert_eq!(Batch.Reply().await["Type"], "Denied");
+}
+
+#[tokio::test]
+async fn Paged() {
+	let History = Arc::new(History::default());
+
+	for (Time, Identity) in [(1000, "a"), (2000, "b"), (3000, "a"), (4000, "a"), (5000, "b")] {
+		History.Record(Submission {
+			Sequence:0,
+			Time,
+			Identity:Identity.to_string(),
+			Connection:0,
+			Id:Some(Time.to_string()),
+			Action:Some("Read".to_string()),
+			Digest:None,
+			Admission:Admission::Accepted,
+			Reason:None,
+		});
+	}
+
+	// From is inclusive, To exclusive
+	let Ranged = History.QuerySubmissions(&Query::New().WithRange(2000, 4000)).await;
+
+	assert_eq!(Id(&Ranged), ["2000", "3000"]);
+
+	let First = History
+		.QuerySubmissions(&Query::New().WithIdentity("a").WithLimit(2))
+		.await;
+
+	assert_eq!(Id(&First), ["1000", "3000"]);
+
+	let Next = First.Next.expect("a second page");
+
+	let Second = History
+		.QuerySubmissions(&Query::New().WithIdentity("a").WithLimit(2).WithAfter(Next))
+		.await;
+
+	assert_eq!(Id(&Second), ["4000"]);
+
+	assert_eq!(Second.Next, None);
+
+	// A page ending exactly on the last selected record has no successor
+	let Exact = History
+		.QuerySubmissions(&Query::New().WithIdentity("b").WithLimit(2))
+		.await;
+
+	assert_eq!((Id(&Exact), Exact.Next), (vec!["2000", "5000"], None));
+
+	// The oldest records beyond the limit are dropped
+	History.Configure(2, None);
+
+	assert_eq!(Id(&History.QuerySubmissions(&Query::New()).await), ["4000", "5000"]);
+}
+
+use std::{sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex,
+		split,
+		AsyncBufReadExt,
+		AsyncWriteExt,
+		BufReader,
+		DuplexStream,
+		Lines,
+		ReadHalf,
+		WriteHalf,
+	},
+	time::timeout,
+};
+use Echo::{
+	Enum::Transport::Admission::Enum as Admission,
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+		},
+		Transport::{
+			Frame::Line,
+			History::{
+				Page::Struct as Page,
+				Query::Struct as Query,
+				Record::Struct as Submission,
+				Struct as History,
+			},
+			Pump::Struct as Pump,
+		},
+	},
+};
@@ -0,0 +1,118 @@
+#![allow(non_snake_case)]
+
+//! Checks that a summary of a production line groups its actions by type,
+//! age bucket and priority, sums their argument sizes and names the one
+//! waiting longest, and that `Life::Summary` summarizes every Karma queue.
+
+/// Creates an action of a type with arguments and, if given, a priority.
+fn Action(Name:&str, Argument:Value, Priority:Option<i64>) -> Box<dyn Action> {
+	let Action = Echo::Struct::Sequence::Action::Struct::New(
+		Name,
+		Value::Null,
+		Arc::new(Plan::New().Build()),
+	)
+	.WithMetadata("Argument", Argument);
+
+	Box::new(match Priority {
+		Some(Priority) => Action.WithMetadata("Priority", json!(Priority)),
+		None => Action,
+	})
+}
+
+#[tokio::test(start_paused = true)]
+async fn Grouped() {
+	let Production = Production::New();
+
+	assert_eq!(Production.Summary().await, Summary::default());
+
+	// Waits of 11 minutes, 5 minutes, 30 seconds, 5 seconds and none
+	let Queued = [
+		("Read", json!(["a.txt"]), Some(1), 360),
+		("Write", json!(["b.txt", "hello"]), None, 270),
+		("Read", json!(["c.txt"]), Some(1), 25),
+		("Read", json!([]), Some(-2), 5),
+		("Delete", json!(["d.txt"]), None, 0),
+	];
+
+	for (Name, Argument, Priority, Later) in Queued {
+		Production.Enqueue(Action(Name, Argument, Priority)).await.unwrap();
+
+		advance(Duration::from_secs(Later)).await;
+	}
+
+	let Summary = Production.Summary().await;
+
+	assert_eq!(Summary.Count, 5);
+
+	assert_eq!(
+		Summary.Action,
+		BTreeMap::from([
+			("Delete".to_string(), 1),
+			("Read".to_string(), 3),
+			("Write".to_string(), 1),
+		])
+	);
+
+	assert_eq!(Summary.Age, Age { Second:1, TenSeconds:1, Minute:1, TenMinutes:1, Older:1 });
+
+	assert_eq!(
+		Summary.Priority,
+		BTreeMap::from([("-2".to_string(), 1), ("0".to_string(), 2), ("1".to_string(), 2)])
+	);
+
+	// The JSON of the arguments: `["a.txt"]` is 9 bytes
+	assert_eq!(Summary.Bytes, 9 + 17 + 9 + 2 + 9);
+
+	assert_eq!(
+		Summary.Oldest,
+		Some(Oldest { Action:Some("Read".to_string()), AgeMs:660_000 })
+	);
+
+	// Nothing was dequeued to summarize
+	assert_eq!(Production.Stats().Depth, 5);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Karma() {
+	let Life = Life::Builder()
+		.WithQueue("reports", Arc::new(Production::New()), Settings::New())
+		.WithQueue("interactive", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	for _ in 0..2 {
+		Life.Karma
+			.get("reports")
+			.unwrap()
+			.Enqueue(Action("Report", json!([]), None))
+			.await
+			.unwrap();
+	}
+
+	advance(Duration::from_secs(2)).await;
+
+	let Summary = Life.Summary().await;
+
+	assert_eq!(Summary.keys().collect::<Vec<_>>(), ["interactive", "reports"]);
+
+	assert_eq!(Summary["interactive"], Echo::Wire::Summary::default());
+
+	assert_eq!(Summary["reports"].Count, 2);
+
+	assert_eq!(Summary["reports"].Age.TenSeconds, 2);
+}
+
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+use tokio::time::advance;
+use Echo::{
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Plan::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+		},
+		Stats::Summary::{Age::Struct as Age, Oldest::Struct as Oldest, Struct as Summary},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+
+//! Checks that a `Supervisor` starts its components in registration order
+//! and stops them in reverse, holds each to its shutdown deadline, rolls back
+//! the started ones when a start fails, and runs the provided handles.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(
+		&self,
+		Action:Arc<dyn Action>,
+		Context:&Life,
+	) -> Result<(), Echo::Enum::Sequence::Action::Error::Enum> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A component recording its calls into a shared log.
+struct Fake {
+	/// The name written into the log.
+	Name:&'static str,
+
+	/// The shared log of calls.
+	Log:Arc<Mutex<Vec<String>>>,
+
+	/// Whether `Start` fails.
+	Refuse:bool,
+
+	/// How long `Shutdown` takes.
+	Linger:Duration,
+}
+
+#[async_trait]
+impl Component for Fake {
+	async fn Start(&mut self) -> Result<(), String> {
+		self.Log.lock().unwrap().push(format!("start {}", self.Name));
+
+		if self.Refuse {
+			return Err("refused".to_string());
+		}
+
+		Ok(())
+	}
+
+	async fn Shutdown(&mut self, _Deadline:Instant) -> Result<(), String> {
+		self.Log.lock().unwrap().push(format!("stop {}", self.Name));
+
+		sleep(self.Linger).await;
+
+		Ok(())
+	}
+}
+
+/// A component named `Name` writing into `Log`.
+fn Fake(Name:&'static str, Log:&Arc<Mutex<Vec<String>>>) -> Fake {
+	Fake { Name, Log:Log.clone(), Refuse:false, Linger:Duration::ZERO }
+}
+
+/// The log of calls so far.
+fn Calls(Log:&Arc<Mutex<Vec<String>>>) -> Vec<String> { Log.lock().unwrap().clone() }
+
+#[tokio::test]
+async fn Order() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let mut Supervisor = Supervisor::New()
+		.Register("server", Fake("server", &Log), Duration::from_secs(1))
+		.Register("sequence", Fake("sequence", &Log), Duration::from_secs(1))
+		.Register("source", Fake("source", &Log), Duration::from_secs(1));
+
+	Supervisor.Start().await.unwrap();
+
+	assert_eq!(Calls(&Log), ["start server", "start sequence", "start source"]);
+
+	let Report = Supervisor.Shutdown().await;
+
+	assert!(Report.Clean());
+
+	assert_eq!(
+		Report.Component.iter().map(|Stop| Stop.Name.as_str()).collect::<Vec<_>>(),
+		["source", "sequence", "server"]
+	);
+
+	assert_eq!(Calls(&Log)[3..], ["stop source", "stop sequence", "stop server"]);
+
+	// Nothing is left to stop
+	assert!(Supervisor.Shutdown().await.Component.is_empty());
+}
+
+#[tokio::test]
+async fn Deadline() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let mut Supervisor = Supervisor::New()
+		.Register("first", Fake("first", &Log), Duration::from_secs(1))
+		.Register(
+			"stuck",
+			Fake { Linger:Duration::from_secs(60), ..Fake("stuck", &Log) },
+			Duration::from_millis(100),
+		);
+
+	Supervisor.Start().await.unwrap();
+
+	let Begin = Instant::now();
+
+	let Report = Supervisor.Shutdown().await;
+
+	assert!(Begin.elapsed() < Duration::from_secs(5));
+
+	assert_eq!(Report.Component[0].Outcome, Outcome::Expired);
+
+	assert!(Report.Component[0].Elapsed >= Duration::from_millis(100));
+
+	// The next component is stopped regardless
+	assert_eq!(Report.Component[1].Outcome, Outcome::Stopped);
+
+	assert_eq!(Report.Unclean().len(), 1);
+}
+
+#[tokio::test]
+async fn Rollback() {
+	let Log = Arc::new(Mutex::new(Vec::new()));
+
+	let mut Supervisor = Supervisor::New()
+		.Register("first", Fake("first", &Log), Duration::from_secs(1))
+		.Register("second", Fake("second", &Log), Duration::from_secs(1))
+		.Register("failing", Fake { Refuse:true, ..Fake("failing", &Log) }, Duration::from_secs(1))
+		.Register("never", Fake("never", &Log), Duration::from_secs(1));
+
+	let Error::Start { Component, Reason, Rollback } = Supervisor.Start().await.unwrap_err();
+
+	assert_eq!((Component.as_str(), Reason.as_str()), ("failing", "refused"));
+
+	assert!(Rollback.Clean());
+
+	assert_eq!(
+		Calls(&Log),
+		["start first", "start second", "start failing", "stop second", "stop first"]
+	);
+}
+
+#[tokio::test]
+async fn Handles() {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence =
+		Arc::new(Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone()));
+
+	let Source = Handle::Spawn(|mut Stop| async move { Stopped(&mut Stop).await });
+
+	let mut Supervisor = Supervisor::New()
+		.Register("timer", Life.Timer.clone(), Duration::from_secs(1))
+		.Register("sequence", SequenceHandle::New(Sequence.clone()), Duration::from_secs(5))
+		.Register("source", Source, Duration::from_secs(1));
+
+	Supervisor.Start().await.unwrap();
+
+	let Report = Supervisor.Shutdown().await;
+
+	assert!(Report.Clean(), "{:?}", Report);
+
+	assert!(Sequence.ShutdownToken().Get().await);
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use tokio::time::{sleep, Instant};
+use Echo::{
+	Enum::Supervisor::{Error::Enum as Error, Outcome::Enum as Outcome},
+	Struct::{
+		Sequence::{
+			Handle::Struct as SequenceHandle,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Source::Handle::{Stopped, Struct as Handle},
+		Supervisor::Struct as Supervisor,
+	},
+	Trait::{
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+		Supervisor::Component::Trait as Component,
+	},
+};
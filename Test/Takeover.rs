@@ -0,0 +1,238 @@
+#![allow(non_snake_case)]
+
+//! Checks what a pump does with a second connection of an identity: under
+//! `TakeOver` the first connection is closed with `Superseded` and the
+//! second receives what the first still owed, under `Reject` the second is
+//! refused, and otherwise both are served.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// The client end of a connection.
+type Client = (WriteHalf<DuplexStream>, Lines<BufReader<ReadHalf<DuplexStream>>>);
+
+/// A pump serving a `Life` whose `main` queue is consumed, with a `Gate`
+/// action that completes once the returned semaphore hands it a permit.
+fn Serve() -> (Pump, Arc<Semaphore>) {
+	let Gate = Arc::new(Semaphore::new(0));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Gate"))
+			.WithFunction("Gate", {
+				let Gate = Gate.clone();
+
+				move |_| {
+					let Gate = Gate.clone();
+
+					async move {
+						Gate.acquire().await.unwrap().forget();
+
+						Ok(json!("passed"))
+					}
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	(Pump::New(Life, Plan), Gate)
+}
+
+/// Opens a connection to the pump.
+fn Connect(Pump:&Pump) -> Client {
+	let (Client, Server) = duplex(1 << 16);
+
+	let Pump = Pump.clone();
+
+	tokio::spawn(async move {
+		let (Input, Output) = split(Server);
+
+		Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+			.await
+	});
+
+	let (Reader, Writer) = split(Client);
+
+	(Writer, BufReader::new(Reader).lines())
+}
+
+/// Sends a message.
+async fn Send((Writer, _):&mut Client, Message:Value) {
+	Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+}
+
+/// Reads the next reply, or `None` once the pump closed the stream.
+async fn Next((_, Reader):&mut Client) -> Option<Value> {
+	timeout(Duration::from_secs(5), Reader.next_line())
+		.await
+		.expect("no reply in time")
+		.unwrap()
+		.map(|Line| serde_json::from_str(&Line).unwrap())
+}
+
+/// Sends a message and reads the next reply.
+async fn Exchange(Client:&mut Client, Message:Value) -> Value {
+	Send(Client, Message).await;
+
+	Next(Client).await.expect("stream closed")
+}
+
+/// Submits a `Gate` action to the `main` queue.
+fn Gate(Id:&str) -> Value {
+	json!({ "Type": "Submit", "Id": Id, "Action": "Gate", "Metadata": { "Queue": "main" } })
+}
+
+#[tokio::test]
+async fn TakenOver() {
+	let (Pump, Gate) = Serve();
+
+	let Pump = Pump
+		.WithIdentity("desktop-1", "secret")
+		.WithTakeover("desktop-*", Takeover::TakeOver);
+
+	let mut First = Connect(&Pump);
+
+	let Auth = json!({ "Type": "Auth", "Token": "secret" });
+
+	assert_eq!(Exchange(&mut First, Auth.clone()).await["Type"], "Authenticated");
+
+	let Subscribe = json!({ "Type": "Subscribe", "Filter": ["Completed"] });
+
+	assert_eq!(Exchange(&mut First, Subscribe).await["Type"], "Subscribed");
+
+	assert_eq!(Exchange(&mut First, self::Gate("1")).await["Type"], "Ack");
+
+	assert_eq!(Exchange(&mut First, self::Gate("2")).await["Type"], "Ack");
+
+	// The network blips: the desktop reconnects while the first stream lingers
+	let mut Second = Connect(&Pump);
+
+	assert_eq!(Exchange(&mut Second, Auth).await["Type"], "Authenticated");
+
+	assert_eq!(Next(&mut First).await, Some(json!({ "Type": "Superseded", "Connection": 1 })));
+
+	assert_eq!(Next(&mut First).await, None);
+
+	// The submissions of the first may be cancelled from the second
+	Send(&mut Second, json!({ "Type": "Cancel", "Id": "2" })).await;
+
+	assert_eq!(Next(&mut Second).await.unwrap()["Code"], "ECHO_CANCELLED");
+
+	assert_eq!(Next(&mut Second).await.unwrap()["Cancelled"], true);
+
+	// Results completing after the switch reach the second, with the events
+	// the first subscribed to
+	Gate.add_permits(2);
+
+	let (mut Result, mut Completed) = (None, false);
+
+	while Result.is_none() || !Completed {
+		let Reply = Next(&mut Second).await.expect("stream closed");
+
+		match Reply["Type"].as_str() {
+			Some("Result") => Result = Some(Reply),
+			Some("Event") => Completed |= Reply["Event"]["Type"] == "Completed",
+			_ => panic!("unexpected reply {}", Reply),
+		}
+	}
+
+	let Result = Result.unwrap();
+
+	assert_eq!((&Result["Id"], &Result["Value"]), (&json!("1"), &json!("passed")));
+}
+
+#[tokio::test]
+async fn Rejected() {
+	let (Pump, _) = Serve();
+
+	let Pump = Pump
+		.WithToken("shared")
+		.WithIdentity("kiosk", "kiosk")
+		.WithTakeover("kiosk", Takeover::Reject);
+
+	let mut First = Connect(&Pump);
+
+	let Auth = json!({ "Type": "Auth", "Token": "kiosk" });
+
+	assert_eq!(Exchange(&mut First, Auth.clone()).await["Type"], "Authenticated");
+
+	let mut Second = Connect(&Pump);
+
+	assert_eq!(Exchange(&mut Second, Auth.clone()).await["Code"], "ECHO_DUPLICATE_CONNECTION");
+
+	assert_eq!(Next(&mut Second).await, None);
+
+	// The first is left alone
+	assert_eq!(Exchange(&mut First, json!({ "Type": "Ping" })).await["Type"], "Pong");
+
+	// Once it is gone, the identity may connect again
+	First.0.shutdown().await.unwrap();
+
+	assert_eq!(Next(&mut First).await, None);
+
+	sleep(Duration::from_millis(100)).await;
+
+	let mut Third = Connect(&Pump);
+
+	assert_eq!(Exchange(&mut Third, Auth).await["Type"], "Authenticated");
+
+	// Identities of no class keep every connection
+	let Shared = json!({ "Type": "Auth", "Token": "shared" });
+
+	let (mut Left, mut Right) = (Connect(&Pump), Connect(&Pump));
+
+	assert_eq!(Exchange(&mut Left, Shared.clone()).await["Type"], "Authenticated");
+
+	assert_eq!(Exchange(&mut Right, Shared).await["Type"], "Authenticated");
+
+	assert_eq!(Exchange(&mut Left, json!({ "Type": "Ping" })).await["Type"], "Pong");
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex,
+		split,
+		AsyncBufReadExt,
+		AsyncWriteExt,
+		BufReader,
+		DuplexStream,
+		Lines,
+		ReadHalf,
+		WriteHalf,
+	},
+	sync::Semaphore,
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::{Sequence::Action::Error::Enum as Error, Transport::Takeover::Enum as Takeover},
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
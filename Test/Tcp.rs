@@ -0,0 +1,147 @@
+#![allow(non_snake_case)]
+
+//! Checks the TCP transport from a client built on nothing but a raw
+//! `TcpStream` and the JSON codec: framed by hand as a big-endian `u32`
+//! length and the encoded message, it authenticates, submits a `Read`
+//! action, is acknowledged, receives the content of the file, and closes
+//! the connection gracefully.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Encodes a message with the codec and writes it in one frame.
+async fn Send(Stream:&mut TcpStream, Message:Value) {
+	let Message:Message = serde_json::from_value(Message).unwrap();
+
+	let Frame = Json.EncodeMessage(&Message).unwrap();
+
+	Stream.write_all(&(Frame.len() as u32).to_be_bytes()).await.unwrap();
+
+	Stream.write_all(&Frame).await.unwrap();
+}
+
+/// Reads one frame and decodes the reply in it.
+async fn Receive(Stream:&mut TcpStream) -> Reply {
+	timeout(Duration::from_secs(10), async {
+		let mut Prefix = [0; 4];
+
+		Stream.read_exact(&mut Prefix).await.unwrap();
+
+		let mut Frame = vec![0; u32::from_be_bytes(Prefix) as usize];
+
+		Stream.read_exact(&mut Frame).await.unwrap();
+
+		Json.DecodeReply(&Frame).unwrap()
+	})
+	.await
+	.expect("no reply in time")
+}
+
+#[tokio::test]
+async fn Read() {
+	let Root = std::env::temp_dir().join(format!("Echo-Tcp-{}", std::process::id()));
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	tokio::fs::write(Root.join("note.txt"), "Hello over TCP").await.unwrap();
+
+	let Plan = Fs::New(&Root).Register(Echo::Struct::Sequence::Plan::Struct::New()).unwrap();
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Pump = Pump::New(Life, Arc::new(Plan.Build())).WithToken("embedded");
+
+	let (Handle, Address) = Tcp::New(Pump, "127.0.0.1:0").Start().await.unwrap();
+
+	let mut Stream = TcpStream::connect(Address).await.unwrap();
+
+	Send(&mut Stream, json!({ "Type": "Auth", "Token": "embedded" })).await;
+
+	assert!(matches!(Receive(&mut Stream).await, Reply::Authenticated { .. }));
+
+	Send(
+		&mut Stream,
+		json!({
+			"Type": "Submit",
+			"Id": "1",
+			"Action": "Read",
+			"Argument": ["note.txt"],
+			"Metadata": { "Queue": "main" },
+		}),
+	)
+	.await;
+
+	assert!(matches!(Receive(&mut Stream).await, Reply::Ack { Id } if Id == "1"));
+
+	match Receive(&mut Stream).await {
+		Reply::Result { Id, Value, .. } => {
+			assert_eq!(Id, "1");
+
+			assert_eq!(Value["content"], "Hello over TCP");
+
+			assert_eq!(Value["bytes"], 14);
+		},
+		Other => panic!("unexpected reply {:?}", Other),
+	}
+
+	Send(&mut Stream, json!({ "Type": "Close" })).await;
+
+	// Nothing was pending, so the server ends the connection at once
+	let mut Rest = Vec::new();
+
+	timeout(Duration::from_secs(5), Stream.read_to_end(&mut Rest)).await.unwrap().unwrap();
+
+	assert!(Rest.is_empty());
+
+	Handle.Shutdown().await.unwrap();
+
+	Sequence.Shutdown().await;
+
+	tokio::fs::remove_dir_all(&Root).await.unwrap();
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	io::{AsyncReadExt, AsyncWriteExt},
+	net::TcpStream,
+	time::timeout,
+};
+use Echo::{
+	Builtin::Fs::Struct as Fs,
+	Enum::{
+		Sequence::Action::Error::Enum as Error,
+		Transport::{Message::Enum as Message, Reply::Enum as Reply},
+	},
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Codec::Json::Struct as Json, Pump::Struct as Pump, Tcp::Struct as Tcp},
+	},
+	Trait::{
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+		Transport::Codec::Trait as _,
+	},
+};
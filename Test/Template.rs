@@ -0,0 +1,221 @@
+#![allow(non_snake_case)]
+
+//! Checks template placeholders: each provider resolves its tokens in
+//! strings at any depth of the arguments, `\{\{` stays a literal `{{`, and a
+//! token that cannot be resolved fails with `Error::Template` naming it,
+//! also when an action with `Template` metadata runs.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// Creates a `Life` making a single attempt at each action of its `main`
+/// queue, configured with an `output_dir` and caching a `last_id`.
+fn Start() -> Life {
+	let Fate = Config::builder()
+		.set_override("End", 1)
+		.unwrap()
+		.set_override("output_dir", "/var/out")
+		.unwrap()
+		.build()
+		.unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	Life.Cache.Put("last_id".to_string(), json!(42));
+
+	Life
+}
+
+/// Resolves the placeholders of a value against `Life` and metadata
+/// holding a `Name` and a `Previous` result.
+async fn Render(Life:&Life, Value:Value) -> Result<Value, Error> {
+	let Metadata = Vector::New();
+
+	Metadata.Insert("Name".to_string(), json!("report"));
+
+	Metadata.Insert("Previous".to_string(), json!({ "content": "hello" }));
+
+	Template::Fn(Life, &Metadata, Value).await
+}
+
+#[tokio::test]
+async fn Provider() {
+	let Life = Start();
+
+	assert_eq!(
+		Render(&Life, json!("{{config:output_dir}}/{{metadata:Name}}.txt")).await,
+		Ok(json!("/var/out/report.txt"))
+	);
+
+	// Values other than strings are inserted as JSON
+	assert_eq!(Render(&Life, json!("after {{cache:last_id}}")).await, Ok(json!("after 42")));
+
+	assert_eq!(
+		Render(&Life, json!("{{metadata:Previous}} {{metadata:Previous/content}}")).await,
+		Ok(json!(r#"{"content":"hello"} hello"#))
+	);
+
+	let Before = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+	let Rendered = Render(&Life, json!(["{{now:%s}}", "{{date}}", "{{now:%Y-%m-%d}}", "{{now}}"]))
+		.await
+		.unwrap();
+
+	let After = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+	let Second:u64 = Rendered[0].as_str().unwrap().parse().unwrap();
+
+	assert!((Before..=After).contains(&Second), "{}", Second);
+
+	// The default of `date` is the date part of the default of `now`
+	let (Date, Now) = (Rendered[1].as_str().unwrap(), Rendered[3].as_str().unwrap());
+
+	assert_eq!(Date.len(), 10, "{}", Date);
+
+	assert_eq!(Rendered[2], Rendered[1]);
+
+	assert!(Now.starts_with(&format!("{}T", Date)) && Now.ends_with('Z'), "{}", Now);
+}
+
+#[tokio::test]
+async fn Nested() {
+	let Life = Start();
+
+	let Rendered = Render(
+		&Life,
+		json!({
+			"Path": "{{config:output_dir}}",
+			"Count": 3,
+			"Step": [
+				{ "Name": "{{metadata:Name}}", "Flag": true },
+				["{{cache:last_id}}", null],
+			],
+		}),
+	)
+	.await;
+
+	assert_eq!(
+		Rendered,
+		Ok(json!({
+			"Path": "/var/out",
+			"Count": 3,
+			"Step": [
+				{ "Name": "report", "Flag": true },
+				["42", null],
+			],
+		}))
+	);
+}
+
+#[tokio::test]
+async fn Escape() {
+	let Life = Start();
+
+	assert_eq!(
+		Render(&Life, json!(r"\{\{config:output_dir}} is {{config:output_dir}}")).await,
+		Ok(json!("{{config:output_dir}} is /var/out"))
+	);
+
+	// A lone brace or backslash is left alone
+	assert_eq!(Render(&Life, json!(r"{ \ }")).await, Ok(json!(r"{ \ }")));
+}
+
+#[tokio::test]
+async fn Unresolved() {
+	let Life = Start();
+
+	for (Text, Named) in [
+		("{{cache:missing}}", "{{cache:missing}}"),
+		("a {{config:absent}} b", "{{config:absent}}"),
+		("{{metadata:Previous/missing}}", "{{metadata:Previous/missing}}"),
+		("{{unknown}}", "{{unknown}}"),
+		("{{config:output_dir", "{{config:output_dir"),
+	] {
+		let Rendered = Render(&Life, json!({ "Deep": [Text] })).await;
+
+		assert!(
+			matches!(&Rendered, Err(Error::Template(Message)) if Message.contains(Named)),
+			"{} gave {:?}",
+			Text,
+			Rendered
+		);
+	}
+}
+
+#[tokio::test]
+async fn Executed() {
+	let Life = Start();
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Write"))
+			.WithFunction("Write", |Argument:Vec<Value>| async move { Ok(json!(Argument)) })
+			.unwrap()
+			.Build(),
+	);
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.RunKarma().await }
+	});
+
+	let Write = |Argument:Value| {
+		Box::new(
+			Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Plan.clone())
+				.WithMetadata("Queue", json!("main"))
+				.WithMetadata("Template", json!(true))
+				.WithMetadata("Argument", Argument),
+		)
+	};
+
+	let Pending = Life.Submit(Write(json!(["{{config:output_dir}}/{{cache:last_id}}"]))).await;
+
+	assert_eq!(timeout(Duration::from_secs(5), Pending).await.unwrap(), Ok(json!(["/var/out/42"])));
+
+	let Pending = Life.Submit(Write(json!(["{{cache:missing}}"]))).await;
+
+	let Outcome = timeout(Duration::from_secs(5), Pending).await.unwrap();
+
+	assert!(
+		matches!(&Outcome, Err(Error::Template(Message)) if Message.contains("{{cache:missing}}")),
+		"{:?}",
+		Outcome
+	);
+
+	Sequence.Shutdown().await;
+}
+
+use std::{
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::time::timeout;
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Fn::Template,
+	Struct::Sequence::{
+		Action::Signature::Struct as Signature,
+		Life::Struct as Life,
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+		Vector::Struct as Vector,
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+
+//! Checks the ticker source on a paused clock: it enqueues one numbered
+//! action per interval, skips ticks while the previous action is still
+//! pending if asked to, and stops ticking once it is shut down.
+
+/// The time between ticks.
+const INTERVAL:Duration = Duration::from_secs(30);
+
+/// A lifecycle with a `main` queue nothing consumes.
+fn Start() -> (Life, Arc<Production>) {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Production = Life.Karma.get("main").unwrap().clone();
+
+	(Life, Production)
+}
+
+/// Takes the queued actions and returns their tick numbers.
+async fn Drain(Production:&Production) -> Vec<u64> {
+	let mut Number = Vec::new();
+
+	while let Some(Action) = Production.Dequeue().await {
+		Number.push(Action.Metadata("TickNumber").await.unwrap().as_u64().unwrap());
+	}
+
+	Number
+}
+
+#[tokio::test(start_paused = true)]
+async fn Counted() {
+	let (Life, Production) = Start();
+
+	let Template = Template::New("Beat", Value::Null, Arc::new(Plan::New().Build()));
+
+	let _Handle = Ticker::New(INTERVAL, Template, "main").Start(Life);
+
+	sleep(INTERVAL / 2).await;
+
+	assert_eq!(Production.Stats().Enqueued, 0);
+
+	sleep(INTERVAL * 3).await;
+
+	assert_eq!(Drain(&Production).await, [1, 2, 3]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Skipped() {
+	let (Life, Production) = Start();
+
+	let Plan = Arc::new(Plan::New().Build());
+
+	let Ticker = Ticker::WithFactory(
+		INTERVAL,
+		move |Tick| {
+			Box::new(
+				Echo::Struct::Sequence::Action::Struct::New("Beat", (), Plan.clone())
+					.WithMetadata("TickNumber", Tick.into()),
+			) as Box<dyn Action>
+		},
+		"main",
+	)
+	.WithSkipIfPrevPending(true);
+
+	let _Handle = Ticker.Start(Life);
+
+	// The first action stays queued, so the next two ticks are skipped
+	sleep(INTERVAL * 3 + INTERVAL / 2).await;
+
+	assert_eq!(Drain(&Production).await, [1]);
+
+	sleep(INTERVAL).await;
+
+	assert_eq!(Drain(&Production).await, [4]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn Stopped() {
+	let (Life, Production) = Start();
+
+	let Template = Template::New("Beat", Value::Null, Arc::new(Plan::New().Build()));
+
+	let Handle = Ticker::New(INTERVAL, Template, "main").Start(Life);
+
+	sleep(INTERVAL * 2 + INTERVAL / 2).await;
+
+	timeout(INTERVAL / 10, Handle.Shutdown()).await.unwrap().unwrap();
+
+	sleep(INTERVAL * 10).await;
+
+	assert_eq!(Drain(&Production).await, [1, 2]);
+}
+
+use std::sync::Arc;
+
+use serde_json::Value;
+use tokio::time::{sleep, timeout, Duration};
+use Echo::{
+	Struct::{
+		Sequence::{
+			Life::Struct as Life,
+			Plan::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+		},
+		Source::{Template::Struct as Template, Ticker::Struct as Ticker},
+	},
+	Trait::Sequence::Action::Trait as Action,
+};
@@ -0,0 +1,298 @@
+#![allow(non_snake_case)]
+
+//! Checks how durations and timestamps are parsed and written: numbers of
+//! milliseconds, strings with units and RFC 3339 timestamps are read alike
+//! from JSON and configuration, negative and absurd values are refused, and
+//! the reserved metadata reads them the same way.
+
+/// Parses a duration from JSON.
+fn Length(Value:Value) -> Result<Duration, String> {
+	serde_json::from_value::<Span>(Value)
+		.map(Span::Get)
+		.map_err(|_Error| _Error.to_string())
+}
+
+/// Parses a timestamp from JSON.
+fn Moment(Value:Value) -> Result<SystemTime, String> {
+	serde_json::from_value::<Timestamp>(Value)
+		.map(SystemTime::from)
+		.map_err(|_Error| _Error.to_string())
+}
+
+#[test]
+fn Numbers() {
+	assert_eq!(Length(json!(0)), Ok(Duration::ZERO));
+
+	assert_eq!(Length(json!(250)), Ok(Duration::from_millis(250)));
+
+	assert_eq!(Length(json!(1.5)), Ok(Duration::from_micros(1500)));
+
+	assert_eq!(Length(json!(2000.0)), Ok(Duration::from_secs(2)));
+
+	assert_eq!(Length(json!("250")), Ok(Duration::from_millis(250)));
+
+	assert_eq!(Length(json!(" 0.25 ")), Ok(Duration::from_micros(250)));
+}
+
+#[test]
+fn Units() {
+	for (Text, Expected) in [
+		("250ms", Duration::from_millis(250)),
+		("2s", Duration::from_secs(2)),
+		("5m", Duration::from_secs(300)),
+		("1h", Duration::from_secs(3600)),
+		("1d", Duration::from_secs(86_400)),
+		("10us", Duration::from_micros(10)),
+		("10µs", Duration::from_micros(10)),
+		("7ns", Duration::from_nanos(7)),
+		("1.5s", Duration::from_millis(1500)),
+		(".5s", Duration::from_millis(500)),
+		("2.s", Duration::from_secs(2)),
+		("1h30m", Duration::from_secs(5400)),
+		("1h 30m 15s", Duration::from_secs(5415)),
+		("2 s", Duration::from_secs(2)),
+		("0.123456789123s", Duration::from_nanos(123_456_789)),
+	] {
+		assert_eq!(Length(json!(Text)), Ok(Expected), "{}", Text);
+	}
+}
+
+#[test]
+fn Rejected() {
+	for Value in [
+		json!(-1),
+		json!(-0.5),
+		json!("-1"),
+		json!("-2s"),
+		json!(""),
+		json!("   "),
+		json!("s"),
+		json!("."),
+		json!(".s"),
+		json!("2x"),
+		json!("2 seconds"),
+		json!("2s5"),
+		json!("1..5s"),
+		json!("NaN"),
+		json!("inf"),
+		json!(true),
+		json!(null),
+		json!([1]),
+		json!({ "Secs": 1 }),
+	] {
+		assert!(Length(Value.clone()).is_err(), "{} was accepted", Value);
+	}
+
+	// Absurd values exceed the maximum instead of wrapping
+	for Value in [
+		json!(u64::MAX),
+		json!(1e30),
+		json!("36501d"),
+		json!("99999999999999999999999999999999999999999h"),
+		json!("1000000000000000000000000000000000000000000s"),
+	] {
+		assert!(Length(Value.clone()).unwrap_err().contains("maximum"), "{}", Value);
+	}
+
+	assert_eq!(Length(json!("36500d")), Ok(MAXIMUM));
+}
+
+#[test]
+fn Format() {
+	for (Duration, Text) in [
+		(Duration::ZERO, "0ms"),
+		(Duration::from_millis(250), "250ms"),
+		(Duration::from_secs(2), "2s"),
+		(Duration::from_secs(300), "5m"),
+		(Duration::from_secs(5415), "1h30m15s"),
+		(Duration::from_secs(90_061), "1d1h1m1s"),
+		(Duration::from_micros(1500), "1ms500us"),
+		(Duration::from_nanos(1_000_000_001), "1s1ns"),
+	] {
+		assert_eq!(Span::from(Duration).to_string(), Text);
+
+		assert_eq!(Text.parse::<Span>().unwrap().Get(), Duration);
+	}
+
+	// Whole milliseconds are written as a number, for existing readers
+	assert_eq!(serde_json::to_value(Span::from(Duration::from_secs(2))).unwrap(), json!(2000));
+
+	assert_eq!(
+		serde_json::to_value(Span::from(Duration::from_micros(1500))).unwrap(),
+		json!("1ms500us")
+	);
+
+	for Millis in [0, 1, 999, 86_400_000] {
+		let Written = Span::from(Duration::from_millis(Millis));
+
+		let Read:Span = serde_json::from_value(serde_json::to_value(Written).unwrap()).unwrap();
+
+		assert_eq!(Read, Written);
+	}
+}
+
+#[test]
+fn Timestamps() {
+	let At = |Seconds:u64, Nanos:u32| Ok(UNIX_EPOCH + Duration::new(Seconds, Nanos));
+
+	assert_eq!(Moment(json!("1970-01-01T00:00:00Z")), At(0, 0));
+
+	assert_eq!(Moment(json!("2026-10-16T04:22:15Z")), At(1_792_124_535, 0));
+
+	assert_eq!(Moment(json!("2026-10-16t04:22:15z")), At(1_792_124_535, 0));
+
+	assert_eq!(Moment(json!("2026-10-16 04:22:15Z")), At(1_792_124_535, 0));
+
+	assert_eq!(Moment(json!("2026-10-16T06:22:15+02:00")), At(1_792_124_535, 0));
+
+	assert_eq!(Moment(json!("2026-10-15T23:52:15-04:30")), At(1_792_124_535, 0));
+
+	assert_eq!(Moment(json!("2026-10-16T04:22:15.25Z")), At(1_792_124_535, 250_000_000));
+
+	assert_eq!(
+		Moment(json!("2026-10-16T04:22:15.123456789999Z")),
+		At(1_792_124_535, 123_456_789)
+	);
+
+	assert_eq!(Moment(json!("2024-02-29T00:00:00Z")), At(1_709_164_800, 0));
+
+	assert_eq!(Moment(json!("9999-12-31T23:59:59Z")), At(253_402_300_799, 0));
+
+	// Unix milliseconds, as clients have always sent
+	assert_eq!(Moment(json!(1_792_124_535_250u64)), At(1_792_124_535, 250_000_000));
+
+	for Value in [
+		json!("2026-10-16"),
+		json!("2026-10-16T04:22:15"),
+		json!("2026-10-16T04:22Z"),
+		json!("2026-13-01T00:00:00Z"),
+		json!("2026-00-01T00:00:00Z"),
+		json!("2026-02-29T00:00:00Z"),
+		json!("2026-04-31T00:00:00Z"),
+		json!("2026-10-16T24:00:00Z"),
+		json!("2026-10-16T23:60:00Z"),
+		json!("2016-12-31T23:59:60Z"),
+		json!("2026-10-16T04:22:15.Z"),
+		json!("2026-10-16T04:22:15+2:00"),
+		json!("2026-10-16T04:22:15+24:00"),
+		json!("2026-10-16T04:22:15Z "),
+		json!("1969-12-31T23:59:59Z"),
+		json!("1970-01-01T00:00:00+00:01"),
+		json!("+2026-10-16T04:22:15Z"),
+		json!("２０２６-10-16T04:22:15Z"),
+		json!(-1),
+		json!(u64::MAX),
+		json!(253_402_300_800_000u64),
+		json!(true),
+	] {
+		assert!(Moment(Value.clone()).is_err(), "{} was accepted", Value);
+	}
+
+	for Text in [
+		"1970-01-01T00:00:00Z",
+		"2026-10-16T04:22:15Z",
+		"2026-10-16T04:22:15.250Z",
+		"2026-10-16T04:22:15.000250Z",
+		"2026-10-16T04:22:15.000000250Z",
+		"2000-02-29T12:00:00Z",
+		"9999-12-31T23:59:59.999Z",
+	] {
+		let Parsed = Text.parse::<Timestamp>().unwrap();
+
+		assert_eq!(Parsed.to_string(), Text);
+
+		assert_eq!(serde_json::to_value(Parsed).unwrap(), json!(Text));
+	}
+
+	assert_eq!(
+		"2026-10-16T06:22:15+02:00".parse::<Timestamp>().unwrap().to_string(),
+		"2026-10-16T04:22:15Z"
+	);
+
+	assert_eq!(Timestamp::UnixMillis(1_792_124_535_250).unwrap().Millis(), 1_792_124_535_250);
+}
+
+#[test]
+fn Configuration() {
+	let Fate = Config::builder()
+		.set_override("integer", 250)
+		.unwrap()
+		.set_override("float", 0.5)
+		.unwrap()
+		.set_override("text", "5m")
+		.unwrap()
+		.set_override("negative", -3)
+		.unwrap()
+		.build()
+		.unwrap();
+
+	let Read = |Key:&str| Fate.get::<Span>(Key).map(Span::Get);
+
+	assert_eq!(Read("integer").unwrap(), Duration::from_millis(250));
+
+	assert_eq!(Read("float").unwrap(), Duration::from_micros(500));
+
+	assert_eq!(Read("text").unwrap(), Duration::from_secs(300));
+
+	assert!(Read("negative").is_err());
+}
+
+#[tokio::test]
+async fn Metadata() {
+	let Plan = Arc::new(Echo::Struct::Sequence::Plan::Formality::Struct::New());
+
+	let Action = |Key:&str, Value:Value| {
+		Echo::Struct::Sequence::Action::Struct::New("Write", Value::Null, Plan.clone())
+			.WithMetadata(Key, Value)
+	};
+
+	let Enqueued = Stamp::New(0);
+
+	// `Delay` counts milliseconds like every other duration
+	let Due = Enqueued.Due(&Action("Delay", json!(1500))).await.unwrap();
+
+	assert_eq!(Due - Enqueued.Instant, Duration::from_millis(1500));
+
+	let Due = Enqueued.Due(&Action("Delay", json!("2s"))).await.unwrap();
+
+	assert_eq!(Due - Enqueued.Instant, Duration::from_secs(2));
+
+	assert!(Enqueued.Due(&Action("Delay", json!("-2s"))).await.is_none());
+
+	assert!(Enqueued.Expired(&Action("Ttl", json!("0ms"))).await);
+
+	assert!(!Enqueued.Expired(&Action("Ttl", json!("1h"))).await);
+
+	assert!(Enqueued.Expired(&Action("ExpiresAt", json!("2020-01-01T00:00:00Z"))).await);
+
+	assert!(!Enqueued.Expired(&Action("ExpiresAt", json!("9999-01-01T00:00:00Z"))).await);
+
+	assert!(Enqueued.Expired(&Action("ExpiresAt", json!(1))).await);
+
+	// An invalid timestamp is ignored, as if it were absent
+	assert!(!Enqueued.Expired(&Action("ExpiresAt", json!("yesterday"))).await);
+
+	let Left = |Invocation:Invocation| Invocation.RemainingTime().unwrap();
+
+	let Timed = Invocation::New(1, 1).WithMetadata(&Action("Timeout", json!("5s"))).await;
+
+	assert!(Left(Timed) <= Duration::from_secs(5));
+
+	let Deadline = Timestamp(SystemTime::now() + Duration::from_secs(60)).to_string();
+
+	let Bounded = Invocation::New(1, 1).WithMetadata(&Action("Deadline", json!(Deadline))).await;
+
+	assert!((Duration::from_secs(50)..=Duration::from_secs(60)).contains(&Left(Bounded)));
+}
+
+use std::{
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use config::Config;
+use serde_json::{json, Value};
+use Echo::{
+	Struct::Sequence::{Invocation::Struct as Invocation, Production::Stamp::Struct as Stamp},
+	Time::{Duration::Struct as Span, Timestamp::Struct as Timestamp, MAXIMUM},
+};
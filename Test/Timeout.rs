@@ -0,0 +1,149 @@
+#![allow(non_snake_case)]
+
+//! Checks the timeouts of client submissions against an in-process server:
+//! a wait running past its own timeout fails with `ClientTimeout`, cancels
+//! the action on the server only if asked to, and otherwise leaves it to
+//! run while its late result is dropped.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A client of a `Tcp` server over a running `Life` with a paused `main`
+/// queue, whose `Work` function records its argument. The server runs as
+/// long as the returned handle lives.
+async fn Start() -> (Client, Life, Arc<Mutex<Vec<String>>>, Handle) {
+	let Ran = Arc::new(Mutex::new(Vec::new()));
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Work"))
+			.WithFunction("Work", {
+				let Ran = Ran.clone();
+
+				move |Argument:Vec<Value>| {
+					Ran.lock().unwrap().push(Argument[0].as_str().unwrap().to_string());
+
+					async { Ok(Value::Null) }
+				}
+			})
+			.unwrap()
+			.Build(),
+	);
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	Life.PauseQueue("main");
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let (Handle, Address) = Tcp::New(Pump::New(Life.clone(), Plan), "127.0.0.1:0")
+		.Start()
+		.await
+		.unwrap();
+
+	let Client = Client::Connect(&Address.to_string(), Config::New()).await.unwrap();
+
+	(Client, Life, Ran, Handle)
+}
+
+/// Returns a `Work` submission to the `main` queue.
+fn Work(Name:&str) -> Submission {
+	Submission::New("Work")
+		.WithArgument(json!(Name))
+		.WithMetadata("Queue", json!("main"))
+}
+
+#[tokio::test]
+async fn Cancelled() {
+	let (Client, Life, Ran, _Handle) = Start().await;
+
+	let Waited = Client
+		.Submit(
+			Work("Late")
+				.WithTimeout(Duration::from_millis(200))
+				.WithCancelOnTimeout(true),
+		)
+		.await
+		.unwrap()
+		.Wait()
+		.await;
+
+	assert_eq!(Waited, Err(ClientError::ClientTimeout));
+
+	Life.ResumeQueue("main");
+
+	let Next = Client.Submit(Work("Next")).await.unwrap().Wait().await;
+
+	assert_eq!(Next, Ok(Value::Null));
+
+	sleep(Duration::from_millis(100)).await;
+
+	// Cancelled while queued, so it never ran
+	assert_eq!(*Ran.lock().unwrap(), ["Next"]);
+}
+
+#[tokio::test]
+async fn Abandoned() {
+	let (Client, Life, Ran, _Handle) = Start().await;
+
+	let Waited = Client
+		.Submit(Work("Late").WithDeadline(Instant::now() + Duration::from_millis(200)))
+		.await
+		.unwrap()
+		.Wait()
+		.await;
+
+	assert_eq!(Waited, Err(ClientError::ClientTimeout));
+
+	// A late result of the forgotten submission is dropped
+	Life.ResumeQueue("main");
+
+	let Next = Client.Submit(Work("Next")).await.unwrap().Wait().await;
+
+	assert_eq!(Next, Ok(Value::Null));
+
+	timeout(Duration::from_secs(10), async {
+		while Ran.lock().unwrap().len() < 2 {
+			sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	assert!(Ran.lock().unwrap().contains(&"Late".to_string()));
+}
+
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::time::{sleep, timeout, Instant};
+use Echo::{
+	Enum::{Client::Error::Enum as ClientError, Sequence::Action::Error::Enum as Error},
+	Struct::{
+		Client::{Config::Struct as Config, Struct as Client, Submission::Struct as Submission},
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Source::Handle::Struct as Handle,
+		Transport::{Pump::Struct as Pump, Tcp::Struct as Tcp},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,140 @@
+#![allow(non_snake_case)]
+
+//! Checks the typed plan: actions of a derived set are dispatched to their
+//! handler methods with decoded requests, named actions with an unknown
+//! name or the wrong arguments fail, and the plan converted into a
+//! `Formality` serves the same actions behind a sequence.
+
+/// The request of `Add`.
+#[derive(Deserialize)]
+struct Pair {
+	Left:i64,
+
+	Right:i64,
+}
+
+/// The actions of the calculator.
+#[derive(Set)]
+enum Calculator {
+	Zero,
+
+	Add(Pair),
+
+	Negate(i64),
+}
+
+/// Answers the actions of the calculator.
+struct Desk;
+
+impl CalculatorHandler for Desk {
+	async fn Zero(&self) -> Result<Value, Error> {
+		Ok(json!(0))
+	}
+
+	async fn Add(&self, Request:Pair) -> Result<Value, Error> {
+		Ok(json!(Request.Left + Request.Right))
+	}
+
+	async fn Negate(&self, Request:i64) -> Result<Value, Error> {
+		Ok(json!(-Request))
+	}
+}
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+#[tokio::test]
+async fn Dispatched() {
+	let Plan = Typed::<Calculator, Desk>::New(Desk);
+
+	assert_eq!(<Calculator as Set<Desk>>::Names(), ["Zero", "Add", "Negate"]);
+
+	assert_eq!(Set::<Desk>::Name(&Calculator::Negate(1)), "Negate");
+
+	assert_eq!(Plan.Execute(Calculator::Zero).await, Ok(json!(0)));
+
+	assert_eq!(Plan.Execute(Calculator::Add(Pair { Left:2, Right:3 })).await, Ok(json!(5)));
+
+	assert_eq!(Plan.Call("Add", vec![json!({ "Left":4, "Right":5 })]).await, Ok(json!(9)));
+
+	assert_eq!(Plan.Call("Negate", vec![json!(7)]).await, Ok(json!(-7)));
+
+	assert_eq!(Plan.Call("Zero", vec![]).await, Ok(json!(0)));
+}
+
+#[tokio::test]
+async fn Refused() {
+	let Plan = Typed::<Calculator, Desk>::New(Desk);
+
+	assert!(matches!(
+		Plan.Call("Multiply", vec![json!(1)]).await,
+		Err(Error::Execution(Message)) if Message.contains("No action Multiply")
+	));
+
+	for (Name, Argument, Expected, Reason) in [
+		("Add", vec![json!({ "Left":1 })], ".", "missing field `Right`"),
+		("Add", vec![json!(1), json!("two")], "[1]", "invalid type: string \"two\""),
+		("Negate", vec![json!("seven")], ".", "invalid type: string \"seven\""),
+	] {
+		let Outcome = Plan.Call(Name, Argument).await;
+
+		assert!(
+			matches!(
+				&Outcome,
+				Err(Error::InvalidRequest { Kind, Path, Message })
+					if Kind == Name && Path == Expected && Message.starts_with(Reason)
+			),
+			"{:?}",
+			Outcome
+		);
+	}
+}
+
+#[tokio::test]
+async fn Sequenced() {
+	let Plan = Arc::new(Formality::from(Typed::<Calculator, Desk>::New(Desk)));
+
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	for (Name, Argument, Expected) in [
+		("Add", json!([{ "Left":20, "Right":22 }]), json!(42)),
+		("Negate", json!([3]), json!(-3)),
+		("Zero", json!([]), json!(0)),
+	] {
+		let Action = Echo::Struct::Sequence::Action::Struct::New(Name, (), Plan.clone())
+			.WithOption(Reserved::Argument, Argument)
+			.WithQueue("main");
+
+		assert_eq!(Life.Submit(Box::new(Action)).await.await, Ok(Expected), "{}", Name);
+	}
+}
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use Echo::{
+	Enum::Sequence::{Action::Error::Enum as Error, Reserved::Enum as Reserved},
+	Macro::Set,
+	Struct::Sequence::{
+		Life::Struct as Life,
+		Plan::{Formality::Struct as Formality, Typed::Struct as Typed},
+		Production::{Settings::Struct as Settings, Struct as Production},
+		Struct as Sequence,
+	},
+	Trait::Sequence::{Action::Trait as Action, Set::Trait as Set, Site::Trait as Site},
+};
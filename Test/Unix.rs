@@ -0,0 +1,151 @@
+#![allow(non_snake_case)]
+#![cfg(unix)]
+
+//! Checks the Unix socket transport: a submission sent over the socket in a
+//! length-prefixed frame comes back as its result, the socket file is made
+//! private and removed on shutdown, a stale socket file is replaced, and a
+//! path served by a live socket or holding another file is refused.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// A path for a socket of this process.
+fn Socket(Name:&str) -> PathBuf {
+	let Path = std::env::temp_dir().join(format!("Echo-Unix-{}-{}.sock", Name, std::process::id()));
+
+	let _ = std::fs::remove_file(&Path);
+
+	Path
+}
+
+/// A transport on a path, serving a plan whose `Add` sums its arguments.
+fn Transport(Path:&Path) -> Unix {
+	let Life = Life::Builder()
+		.WithQueue("main", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Arc::new(Production::New()), Life.clone());
+
+	tokio::spawn(async move { Sequence.RunKarma().await });
+
+	let Plan = Echo::Struct::Sequence::Plan::Struct::New()
+		.WithSignature(Signature::New("Add"))
+		.WithFunction("Add", |Argument:Vec<Value>| async move {
+			Ok(json!(Argument.iter().filter_map(Value::as_i64).sum::<i64>()))
+		})
+		.unwrap()
+		.Build();
+
+	Unix::New(Pump::New(Life, Arc::new(Plan)), Path)
+}
+
+#[tokio::test]
+async fn RoundTrip() {
+	let Path = Socket("RoundTrip");
+
+	let Handle = Transport(&Path).Start().await.unwrap();
+
+	assert_eq!(std::fs::metadata(&Path).unwrap().permissions().mode() & 0o777, 0o600);
+
+	let (Input, Output) = UnixStream::connect(&Path).await.unwrap().into_split();
+
+	let (mut Reader, mut Writer) = (Reader::New(Input, 1 << 20), Writer::New(Output));
+
+	let Message = json!({
+		"Type": "Submit",
+		"Id": "1",
+		"Action": "Add",
+		"Argument": [20, 22],
+		"Metadata": { "Queue": "main" },
+	});
+
+	Writer.Write(Message.to_string().as_bytes()).await.unwrap();
+
+	let Result = loop {
+		let Frame =
+			timeout(Duration::from_secs(10), Reader.Read()).await.unwrap().unwrap().unwrap();
+
+		let Reply:Value = serde_json::from_slice(&Frame).unwrap();
+
+		if Reply["Type"] == "Result" {
+			break Reply;
+		}
+	};
+
+	assert_eq!((&Result["Id"], &Result["Value"]), (&json!("1"), &json!(42)));
+
+	Handle.Shutdown().await.unwrap();
+
+	assert!(!Path.exists());
+}
+
+#[tokio::test]
+async fn Stale() {
+	let Path = Socket("Stale");
+
+	// A socket nothing listens on any more
+	drop(std::os::unix::net::UnixListener::bind(&Path).unwrap());
+
+	assert!(Path.exists());
+
+	let Handle = Transport(&Path).Start().await.unwrap();
+
+	assert!(UnixStream::connect(&Path).await.is_ok());
+
+	let Refused = Transport(&Path).Start().await.err().unwrap();
+
+	assert_eq!(Refused.kind(), ErrorKind::AddrInUse);
+
+	Handle.Shutdown().await.unwrap();
+
+	std::fs::write(&Path, "not a socket").unwrap();
+
+	let Refused = Transport(&Path).Start().await.err().unwrap();
+
+	assert_eq!(Refused.kind(), ErrorKind::AlreadyExists);
+
+	assert_eq!(std::fs::read_to_string(&Path).unwrap(), "not a socket");
+
+	std::fs::remove_file(&Path).unwrap();
+}
+
+use std::{
+	io::ErrorKind,
+	os::unix::fs::PermissionsExt,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use tokio::{
+	net::UnixStream,
+	time::{timeout, Duration},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			Life::Struct as Life,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{
+			Frame::Length::{Reader::Struct as Reader, Writer::Struct as Writer},
+			Pump::Struct as Pump,
+			Unix::Struct as Unix,
+		},
+	},
+	Trait::{
+		Sequence::{Action::Trait as Action, Site::Trait as Site},
+		Transport::{Reader::Trait as _, Writer::Trait as _},
+	},
+};
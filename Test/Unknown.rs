@@ -0,0 +1,235 @@
+#![allow(non_snake_case)]
+
+//! Checks submissions naming an action type the plan does not handle: the
+//! pump refuses them before acknowledging with `ECHO_UNKNOWN_ACTION`, unless
+//! it accepts unknown types, in which case a function registered later runs
+//! them, and one still missing when the action runs dead-letters it with
+//! the reason `unknown`, without a retry.
+
+/// Executes every action it receives.
+struct Direct;
+
+#[async_trait]
+impl Site for Direct {
+	async fn Receive(&self, Action:Arc<dyn Action>, Context:&Life) -> Result<(), Error> {
+		Action.Execute(Context).await
+	}
+}
+
+/// The client end of one connection to a pump.
+struct Client {
+	/// Where messages are written.
+	Writer:WriteHalf<DuplexStream>,
+
+	/// Where replies are read.
+	Reader:Lines<BufReader<ReadHalf<DuplexStream>>>,
+}
+
+impl Client {
+	/// Opens a connection served by `Pump` and authenticates it.
+	async fn Connect(Pump:&Pump) -> Self {
+		let (Client, Server) = duplex(1 << 16);
+
+		let Pump = Pump.clone();
+
+		tokio::spawn(async move {
+			let (Input, Output) = split(Server);
+
+			Pump.Run(Line::Reader::Struct::New(Input), Line::Writer::Struct::New(Output))
+				.await
+		});
+
+		let (Reader, Writer) = split(Client);
+
+		let mut Client = Client { Writer, Reader:BufReader::new(Reader).lines() };
+
+		Client.Send(json!({ "Type": "Auth", "Token": "client" })).await;
+
+		assert_eq!(Client.Reply().await["Type"], "Authenticated");
+
+		Client
+	}
+
+	/// Sends one message.
+	async fn Send(&mut self, Message:Value) {
+		self.Writer.write_all(format!("{}\n", Message).as_bytes()).await.unwrap();
+
+		self.Writer.flush().await.unwrap();
+	}
+
+	/// Submits an action without arguments to the `main` queue, returning
+	/// the first reply.
+	async fn Submit(&mut self, Id:&str, Action:&str) -> Value {
+		self.Send(json!({
+			"Type": "Submit",
+			"Id": Id,
+			"Action": Action,
+			"Argument": [],
+			"Metadata": { "Queue": "main" },
+		}))
+		.await;
+
+		self.Reply().await
+	}
+
+	/// Reads the next reply.
+	async fn Reply(&mut self) -> Value {
+		let Line = timeout(Duration::from_secs(10), self.Reader.next_line())
+			.await
+			.expect("no reply in time")
+			.unwrap()
+			.expect("stream closed");
+
+		serde_json::from_str(&Line).unwrap()
+	}
+}
+
+/// A `Life` making a single attempt at each action of its `main` queue,
+/// which dead-letters into `dead`, and a plan handling only `Known`.
+fn Start() -> (Life, Arc<Plan>) {
+	let Fate = Config::builder().set_override("End", 1).unwrap().build().unwrap();
+
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Fate))
+		.WithQueue("main", Arc::new(Production::New()), Settings::New().WithDeadLetter("dead"))
+		.WithQueue("dead", Arc::new(Production::New()), Settings::New())
+		.Build();
+
+	let Plan = Arc::new(
+		Echo::Struct::Sequence::Plan::Struct::New()
+			.WithSignature(Signature::New("Known"))
+			.WithFunction("Known", |_| async { Ok(json!("known")) })
+			.unwrap()
+			.Build(),
+	);
+
+	(Life, Plan)
+}
+
+/// Starts a sequence consuming the `main` queue only, so dead letters stay
+/// where they are.
+fn Consume(Life:&Life) -> Sequence {
+	let Main = Life.Karma.get("main").unwrap().clone();
+
+	let Sequence = Sequence::New(Arc::new(Direct), Main, Life.clone());
+
+	tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move {
+			while !Sequence.ShutdownToken().Get().await {
+				if Sequence.ConsumeOne().await.is_none() {
+					sleep(Duration::from_millis(10)).await;
+				}
+			}
+		}
+	});
+
+	Sequence
+}
+
+#[tokio::test]
+async fn Rejected() {
+	let (Life, Plan) = Start();
+
+	let Pump = Pump::New(Life.clone(), Plan).WithToken("client");
+
+	let mut Client = Client::Connect(&Pump).await;
+
+	let Reply = Client.Submit("1", "Unheard").await;
+
+	assert_eq!(Reply["Type"], "Error", "unexpected reply {}", Reply);
+
+	assert_eq!(Reply["Code"], "ECHO_UNKNOWN_ACTION");
+
+	let Message = Reply["Message"].as_str().unwrap();
+
+	assert!(Message.contains("Unheard") && Message.contains("Known"), "{}", Message);
+
+	// Refused before anything was enqueued
+	assert_eq!(Life.Karma.get("main").unwrap().Stats().Depth, 0);
+
+	assert_eq!(Client.Submit("2", "Known").await["Type"], "Ack");
+}
+
+#[tokio::test]
+async fn Late() {
+	let (Life, Plan) = Start();
+
+	let Pump = Pump::New(Life.clone(), Plan.clone())
+		.WithToken("client")
+		.WithAcceptUnknown(true);
+
+	let mut Client = Client::Connect(&Pump).await;
+
+	assert_eq!(Client.Submit("1", "Later").await["Type"], "Ack");
+
+	assert_eq!(Life.Karma.get("main").unwrap().Stats().Depth, 1);
+
+	// Registered after the submission was accepted, before it runs
+	Plan.Extend(Signature::New("Later"), |_| async { Ok(json!("late")) });
+
+	let Sequence = Consume(&Life);
+
+	let Reply = Client.Reply().await;
+
+	assert_eq!((&Reply["Type"], &Reply["Value"]), (&json!("Result"), &json!("late")), "{}", Reply);
+
+	Sequence.Shutdown().await;
+}
+
+#[tokio::test]
+async fn DeadLettered() {
+	let (Life, Plan) = Start();
+
+	let Pump = Pump::New(Life.clone(), Plan).WithToken("client").WithAcceptUnknown(true);
+
+	let mut Client = Client::Connect(&Pump).await;
+
+	assert_eq!(Client.Submit("1", "Missing").await["Type"], "Ack");
+
+	let Sequence = Consume(&Life);
+
+	let Reply = Client.Reply().await;
+
+	assert_eq!((&Reply["Type"], &Reply["Code"]), (&json!("Error"), &json!("ECHO_UNKNOWN_ACTION")));
+
+	let Entry = Life.DeadLetter.List("dead", &Filter::New()).await.unwrap();
+
+	assert_eq!(Entry.len(), 1);
+
+	assert_eq!(
+		(Entry[0].Action.as_deref(), Entry[0].Reason.as_deref(), Entry[0].Attempts),
+		(Some("Missing"), Some("unknown"), Some(1))
+	);
+
+	Sequence.Shutdown().await;
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::{json, Value};
+use tokio::{
+	io::{
+		duplex, split, AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines, ReadHalf,
+		WriteHalf,
+	},
+	time::{sleep, timeout},
+};
+use Echo::{
+	Enum::Sequence::Action::Error::Enum as Error,
+	Struct::{
+		Sequence::{
+			Action::Signature::Struct as Signature,
+			DeadLetter::Filter::Struct as Filter,
+			Life::Struct as Life,
+			Plan::Formality::Struct as Plan,
+			Production::{Settings::Struct as Settings, Struct as Production},
+			Struct as Sequence,
+		},
+		Transport::{Frame::Line, Pump::Struct as Pump},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,147 @@
+#![allow(non_snake_case)]
+
+//! Checks the directory watcher on a temporary directory: a created file is
+//! emitted with its path, a burst of writes within the debounce window is
+//! coalesced into one action, and files the glob does not match are ignored.
+
+/// A fresh, empty directory for one test.
+async fn Root(Name:&str) -> PathBuf {
+	let Root = std::env::temp_dir().join(format!("Echo-Watch-{}-{}", Name, std::process::id()));
+
+	let _ = tokio::fs::remove_dir_all(&Root).await;
+
+	tokio::fs::create_dir_all(&Root).await.unwrap();
+
+	Root
+}
+
+/// Starts a watcher of one location feeding the `main` queue of a `Life`.
+fn Start(Root:&Path, Glob:&str, Debounce:Duration) -> (Handle, Arc<Production>) {
+	let Production = Arc::new(Production::New());
+
+	let Life = Life::Builder().WithQueue("main", Production.clone(), Settings::New()).Build();
+
+	let Template = Template::New("Touched", json!(["{Path}"]), Arc::new(Plan::New().Build()));
+
+	let Handle = Watch::New()
+		.WithInterval(Duration::from_millis(20))
+		.WithEntry(Entry::New(Root, Glob, Template, "main").WithDebounce(Debounce))
+		.Start(Life);
+
+	(Handle, Production)
+}
+
+/// Takes the paths of the emitted actions out of the queue.
+async fn Emitted(Production:&Production) -> Vec<String> {
+	let mut Emitted = Vec::new();
+
+	while let Some(Action) = Production.Dequeue().await {
+		let View = Action.View().await;
+
+		Emitted.push(View.Argument[0].as_str().unwrap_or_default().to_string());
+	}
+
+	Emitted
+}
+
+/// Waits until the queue holds an action, for at most five seconds.
+async fn Settled(Production:&Production) {
+	timeout(Duration::from_secs(5), async {
+		while Production.Stats().Depth == 0 {
+			sleep(Duration::from_millis(20)).await;
+		}
+	})
+	.await
+	.unwrap();
+}
+
+#[tokio::test]
+async fn Created() {
+	let Root = Root("Created").await;
+
+	let (Handle, Production) = Start(&Root, "**", Duration::from_millis(50));
+
+	// The first scan sees an empty directory
+	sleep(Duration::from_millis(100)).await;
+
+	tokio::fs::write(Root.join("new.txt"), "Hello").await.unwrap();
+
+	Settled(&Production).await;
+
+	assert_eq!(Emitted(&Production).await, [Root.join("new.txt").to_string_lossy()]);
+
+	Handle.Shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn Debounced() {
+	let Root = Root("Debounced").await;
+
+	let (Handle, Production) = Start(&Root, "**", Duration::from_millis(400));
+
+	sleep(Duration::from_millis(100)).await;
+
+	let Path = Root.join("burst.log");
+
+	// Every write grows the file within the debounce window of the last
+	for Size in 1..=5 {
+		tokio::fs::write(&Path, "x".repeat(Size)).await.unwrap();
+
+		sleep(Duration::from_millis(60)).await;
+	}
+
+	Settled(&Production).await;
+
+	// Nothing else is emitted once the file stays unchanged
+	sleep(Duration::from_millis(600)).await;
+
+	assert_eq!(Emitted(&Production).await, [Path.to_string_lossy()]);
+
+	Handle.Shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn Filtered() {
+	let Root = Root("Filtered").await;
+
+	let (Handle, Production) = Start(&Root, "*.txt", Duration::from_millis(50));
+
+	sleep(Duration::from_millis(100)).await;
+
+	tokio::fs::write(Root.join("skipped.log"), "Hello").await.unwrap();
+
+	tokio::fs::create_dir_all(Root.join("nested")).await.unwrap();
+
+	tokio::fs::write(Root.join("nested/deep.txt"), "Hello").await.unwrap();
+
+	tokio::fs::write(Root.join("kept.txt"), "Hello").await.unwrap();
+
+	Settled(&Production).await;
+
+	sleep(Duration::from_millis(300)).await;
+
+	assert_eq!(Emitted(&Production).await, [Root.join("kept.txt").to_string_lossy()]);
+
+	Handle.Shutdown().await.unwrap();
+}
+
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
+
+use serde_json::json;
+use tokio::time::{sleep, timeout};
+use Echo::Struct::{
+	Sequence::{
+		Life::Struct as Life,
+		Plan::Struct as Plan,
+		Production::{Settings::Struct as Settings, Struct as Production},
+	},
+	Source::{
+		Handle::Struct as Handle,
+		Template::Struct as Template,
+		Watch::{Entry::Struct as Entry, Struct as Watch},
+	},
+};
@@ -0,0 +1,177 @@
+#![allow(non_snake_case)]
+
+//! Checks the watchdog of a `Life` on a paused clock: executions running
+//! past the stuck threshold are flagged once, and cancelled if configured,
+//! while executions that end in time never are.
+
+/// Never finishes a receive.
+struct Hang;
+
+#[async_trait]
+impl Site for Hang {
+	async fn Receive(&self, _Action:Arc<dyn Action>, _Context:&Life) -> Result<(), Error> {
+		std::future::pending().await
+	}
+}
+
+/// Finishes a receive only once its attempt is cancelled.
+struct Patient;
+
+#[async_trait]
+impl Site for Patient {
+	async fn Receive(&self, _Action:Arc<dyn Action>, _Context:&Life) -> Result<(), Error> {
+		let Invocation = Invocation::Current().unwrap();
+
+		while !Invocation.IsCancelled() {
+			sleep(Duration::from_millis(100)).await;
+		}
+
+		Err(Error::Cancellation("Stuck".to_string()))
+	}
+}
+
+/// Finishes a receive after a second.
+struct Quick;
+
+#[async_trait]
+impl Site for Quick {
+	async fn Receive(&self, _Action:Arc<dyn Action>, _Context:&Life) -> Result<(), Error> {
+		sleep(Duration::from_secs(1)).await;
+
+		Ok(())
+	}
+}
+
+/// A sequence over a site with one `Read` action queued, whose watchdog
+/// flags executions after five seconds.
+async fn Start(Site:Arc<dyn Site>, Cancel:bool) -> Sequence {
+	let Life = Life::Builder()
+		.WithFate(Arc::new(Config::builder().set_override("End", 1).unwrap().build().unwrap()))
+		.Build();
+
+	Life.Watchdog
+		.Configure(Some(Duration::from_secs(5)), Duration::from_millis(500), Cancel);
+
+	let Sequence = Sequence::New(Site, Arc::new(Production::New()), Life);
+
+	Sequence
+		.Queue()
+		.Enqueue(Box::new(Echo::Struct::Sequence::Action::Struct::New(
+			"Read",
+			Value::Null,
+			Arc::new(Echo::Struct::Sequence::Plan::Struct::New().Build()),
+		)))
+		.await
+		.unwrap();
+
+	Sequence
+}
+
+/// Waits for the next `StuckAction` event.
+async fn Stuck(Subscription:&mut Subscription) -> Event {
+	loop {
+		if let Some(Event @ Event::StuckAction { .. }) = Subscription.Recv().await {
+			return Event;
+		}
+	}
+}
+
+#[tokio::test(start_paused = true)]
+async fn Flagged() {
+	let Sequence = Start(Arc::new(Hang), false).await;
+
+	let mut Subscription = Sequence.Context().Subscribe();
+
+	let Started = Instant::now();
+
+	let Task = tokio::spawn({
+		let Sequence = Sequence.clone();
+
+		async move { Sequence.ConsumeOne().await }
+	});
+
+	let Event = timeout(Duration::from_secs(60), Stuck(&mut Subscription))
+		.await
+		.unwrap();
+
+	assert!(Started.elapsed() >= Duration::from_secs(5));
+
+	let Event::StuckAction { Sequence: Number, Action, Worker, ElapsedMs, Attempt, Cancelled } =
+		Event
+	else {
+		panic!("unexpected event {:?}", Event)
+	};
+
+	assert_eq!((Number, Action.as_str(), Worker, Attempt, Cancelled), (0, "Read", 0, 1, false));
+
+	assert!((5_000..6_000).contains(&ElapsedMs));
+
+	let Running = Sequence.Context().Watchdog.Running();
+
+	assert_eq!(Running.len(), 1);
+
+	assert!(Running[0].Stuck);
+
+	// An execution is reported once however long it stays stuck
+	assert!(timeout(Duration::from_secs(60), Stuck(&mut Subscription))
+		.await
+		.is_err());
+
+	// Ending the execution, here by aborting it, clears the table
+	Task.abort();
+
+	let _ = Task.await;
+
+	assert!(Sequence.Context().Watchdog.Running().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn Cancelled() {
+	let Sequence = Start(Arc::new(Patient), true).await;
+
+	let mut Subscription = Sequence.Context().Subscribe();
+
+	assert!(matches!(Sequence.ConsumeOne().await, Some(Err(Error::Cancellation(_)))));
+
+	assert!(matches!(
+		timeout(Duration::from_secs(1), Stuck(&mut Subscription)).await,
+		Ok(Event::StuckAction { Cancelled:true, .. })
+	));
+
+	assert!(Sequence.Context().Watchdog.Running().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn Normal() {
+	let Sequence = Start(Arc::new(Quick), false).await;
+
+	let mut Subscription = Sequence.Context().Subscribe();
+
+	assert!(matches!(Sequence.ConsumeOne().await, Some(Ok(()))));
+
+	assert!(Sequence.Context().Watchdog.Running().is_empty());
+
+	assert!(timeout(Duration::from_secs(60), Stuck(&mut Subscription))
+		.await
+		.is_err());
+}
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use config::Config;
+use serde_json::Value;
+use tokio::time::{sleep, timeout, Instant};
+use Echo::{
+	Enum::{Event::Enum as Event, Sequence::Action::Error::Enum as Error},
+	Struct::{
+		Event::Subscription::Struct as Subscription,
+		Sequence::{
+			Invocation::Struct as Invocation,
+			Life::Struct as Life,
+			Production::Struct as Production,
+			Struct as Sequence,
+		},
+	},
+	Trait::Sequence::{Action::Trait as Action, Site::Trait as Site},
+};
@@ -0,0 +1,550 @@
+#![allow(non_snake_case)]
+
+//! Pins the wire representation to the golden files in `Test/Wire`: every
+//! message, reply and event must serialize to its fixture and read back
+//! unchanged. A failure means the representation drifted; if the change is
+//! intended, update the fixture and, for a change existing clients cannot
+//! read, `WIRE_VERSION` with it.
+
+/// Returns the variant name of a message; adding a variant fails to compile
+/// here until it gets a sample and a fixture.
+fn MessageName(Message:&Message) -> &'static str {
+	match Message {
+		Message::Submit { .. } => "Submit",
+		Message::Auth { .. } => "Auth",
+		Message::Cancel { .. } => "Cancel",
+		Message::Describe => "Describe",
+		Message::Subscribe { .. } => "Subscribe",
+		Message::Ping => "Ping",
+		Message::Control { .. } => "Control",
+		Message::Stats => "Stats",
+		Message::Summary => "Summary",
+		Message::Health => "Health",
+		Message::InFlight => "InFlight",
+		Message::Fetch { .. } => "Fetch",
+		Message::DeadLetterList { .. } => "DeadLetterList",
+		Message::DeadLetterRequeue { .. } => "DeadLetterRequeue",
+		Message::DeadLetterPurge { .. } => "DeadLetterPurge",
+		Message::QuerySubmissions { .. } => "QuerySubmissions",
+		Message::Handoff { .. } => "Handoff",
+		Message::HandoffAck { .. } => "HandoffAck",
+		Message::ResultAbort { .. } => "ResultAbort",
+		Message::Close => "Close",
+	}
+}
+
+/// Returns the variant name of a reply, like `MessageName`.
+fn ReplyName(Reply:&Reply) -> &'static str {
+	match Reply {
+		Reply::Ack { .. } => "Ack",
+		Reply::Result { .. } => "Result",
+		Reply::Partial { .. } => "Partial",
+		Reply::ResultChunk { .. } => "ResultChunk",
+		Reply::ResultEnd { .. } => "ResultEnd",
+		Reply::ResultAbort { .. } => "ResultAbort",
+		Reply::Error { .. } => "Error",
+		Reply::Cancelled { .. } => "Cancelled",
+		Reply::Description { .. } => "Description",
+		Reply::Subscribed => "Subscribed",
+		Reply::Event { .. } => "Event",
+		Reply::Authenticated { .. } => "Authenticated",
+		Reply::Control { .. } => "Control",
+		Reply::Denied { .. } => "Denied",
+		Reply::Pong => "Pong",
+		Reply::Stats { .. } => "Stats",
+		Reply::Summary { .. } => "Summary",
+		Reply::Health { .. } => "Health",
+		Reply::InFlight { .. } => "InFlight",
+		Reply::Fetched { .. } => "Fetched",
+		Reply::DeadLetterListed { .. } => "DeadLetterListed",
+		Reply::DeadLetterRequeued { .. } => "DeadLetterRequeued",
+		Reply::DeadLetterPurged { .. } => "DeadLetterPurged",
+		Reply::Submissions { .. } => "Submissions",
+		Reply::HandoffChunk { .. } => "HandoffChunk",
+		Reply::HandoffDone { .. } => "HandoffDone",
+		Reply::Superseded { .. } => "Superseded",
+	}
+}
+
+/// A history of one failed and one successful attempt.
+fn History() -> History {
+	let mut History = History::New(10);
+
+	History.Push(Attempt {
+		Attempt:1,
+		Started:1_700_000_000_000,
+		Ended:1_700_000_000_250,
+		Outcome:Outcome::Failed,
+		Error:Some("Timed out".to_string()),
+		Backoff:Some(1000),
+	});
+
+	History.Push(Attempt {
+		Attempt:2,
+		Started:1_700_000_001_250,
+		Ended:1_700_000_001_300,
+		Outcome:Outcome::Succeeded,
+		Error:None,
+		Backoff:None,
+	});
+
+	History
+}
+
+/// A dead-lettered action that failed three times.
+fn DeadEntry() -> DeadEntry {
+	DeadEntry {
+		Sequence:3,
+		Action:Some("Read".to_string()),
+		Reason:Some("failed".to_string()),
+		Class:Some("Execution".to_string()),
+		Error:Some("Failed".to_string()),
+		Attempts:Some(3),
+		DeadLettered:1_700_000_000_000,
+		AgeMs:60_000,
+	}
+}
+
+/// One sample of every message.
+fn Messages() -> Vec<Message> {
+	vec![
+		Message::Submit {
+			Id:"1".to_string(),
+			Action:"Read".to_string(),
+			Argument:vec![json!("a.txt").into()],
+			Metadata:json!({ "Tenant": "acme" }).as_object().unwrap().clone(),
+			Options:json!({ "Queue": "main" }).as_object().unwrap().clone(),
+		},
+		Message::Auth { Token:"secret".to_string(), Delivery:Delivery::SubmissionOrder },
+		Message::Cancel { Id:"1".to_string() },
+		Message::Describe,
+		Message::Subscribe { Filter:vec!["Completed".to_string()] },
+		Message::Ping,
+		Message::Control {
+			Control:Control::Pause,
+			DeadlineMs:None,
+			Queue:Some("reports".to_string()),
+		},
+		Message::Stats,
+		Message::Summary,
+		Message::Health,
+		Message::InFlight,
+		Message::Fetch { Stored:Stored::New("memory:7", 2_097_152, "application/json") },
+		Message::DeadLetterList {
+			Queue:"dead".to_string(),
+			Filter:DeadFilter::New()
+				.WithClass("Execution")
+				.WithOlderThan(Duration::from_secs(60)),
+		},
+		Message::DeadLetterRequeue {
+			Queue:"dead".to_string(),
+			Sequence:vec![3],
+			Target:"main".to_string(),
+		},
+		Message::DeadLetterPurge {
+			Queue:"dead".to_string(),
+			Filter:DeadFilter::New().WithAction("Read"),
+			DryRun:true,
+		},
+		Message::QuerySubmissions {
+			Query:SubmissionQuery::New()
+				.WithIdentity("batch")
+				.WithAdmission(Admission::Rejected)
+				.WithAfter(7)
+				.WithLimit(10),
+		},
+		Message::Handoff { Chunk:Some(100) },
+		Message::HandoffAck { Chunk:1 },
+		Message::ResultAbort {
+			Id:"1".to_string(),
+			Code:Code::DigestMismatch,
+			Message:"The chunks hash to 00, not ff".to_string(),
+		},
+		Message::Close,
+	]
+}
+
+/// One sample of every reply.
+fn Replies() -> Vec<Reply> {
+	let mut Cost = Cost::default();
+
+	Cost.Type.insert(
+		"Read".to_string(),
+		Total {
+			Count:2,
+			Failed:1,
+			Execution:3000,
+			Average:1500,
+			Maximum:2000,
+			Wait:400,
+			Retries:1,
+			Argument:22,
+			Result:40,
+		},
+	);
+
+	let mut Queue = BTreeMap::new();
+
+	Queue.insert(
+		"main".to_string(),
+		Queue {
+			Depth:1,
+			Enqueued:3,
+			Dequeued:2,
+			Paused:true,
+			Buckets:BTreeMap::from([("alice".to_string(), 1)]),
+		},
+	);
+
+	vec![
+		Reply::Ack { Id:"1".to_string() },
+		Reply::Result {
+			Id:"1".to_string(),
+			Value:json!("content"),
+			History:History(),
+			Annotation:[("Progress".to_string(), json!(100))].into_iter().collect(),
+			OutOfOrder:false,
+		},
+		Reply::Partial { Id:"1".to_string(), Data:"Y2h1bms=".to_string() },
+		Reply::ResultChunk {
+			Id:"1".to_string(),
+			Index:0,
+			Total:2,
+			Data:"ImNvbnRl".to_string(),
+		},
+		Reply::ResultEnd {
+			Id:"1".to_string(),
+			Digest:"2a2b3f4e0c5b0c2bd2ee8bff1da73b9ea8c76e29ea71df0bc1a2ae7ba5e7a4d5".to_string(),
+			History:History(),
+			Annotation:Map::new(),
+		},
+		Reply::ResultAbort {
+			Id:"1".to_string(),
+			Code:Code::TransferAborted,
+			Message:"Cannot read chunk 1 of the stored result memory:1".to_string(),
+		},
+		Reply::Error {
+			Id:Some("1".to_string()),
+			Message:"Failed".to_string(),
+			Code:Some(Code::Execution),
+			OutOfOrder:true,
+		},
+		Reply::Cancelled { Id:"1".to_string(), Cancelled:true },
+		Reply::Description {
+			Action:vec!["Read".to_string()],
+			Version:WIRE_VERSION,
+			Catalogue:vec![Catalogue {
+				Code:Code::Timeout,
+				Retryable:true,
+				Description:"The action ran out of time.".to_string(),
+			}],
+		},
+		Reply::Subscribed,
+		Reply::Event { Event:Event::Enqueued { Sequence:0, Action:Some("Read".to_string()) } },
+		Reply::Authenticated {
+			Role:Role::Admin,
+			Delivery:Delivery::Unordered,
+			Version:WIRE_VERSION,
+		},
+		Reply::Control {
+			Control:Control::Drain,
+			State:State::Drained,
+			Drain:Some(Drain {
+				Drained:true,
+				Completed:4,
+				Failed:1,
+				Remaining:0,
+				Elapsed:120,
+				Abandoned:Vec::new(),
+			}),
+			Paused:vec!["reports".to_string()],
+		},
+		Reply::Denied { Message:"Control requires the Admin role".to_string() },
+		Reply::Pong,
+		Reply::Stats {
+			Stats:Snapshot {
+				Queue,
+				Sequence:vec![Count { Active:1, Total:5, Failed:1, Last:Some(1_700_000_000_000) }],
+				Connection:Count { Active:1, Total:1, Failed:0, Last:None },
+				Cache:Some(2),
+				DeadLetter:0,
+				Cost,
+			},
+		},
+		Reply::Summary {
+			Queue:BTreeMap::from([(
+				"main".to_string(),
+				Summary {
+					Count:3,
+					Action:BTreeMap::from([("Read".to_string(), 2), ("Write".to_string(), 1)]),
+					Age:Age { Second:1, TenSeconds:0, Minute:2, TenMinutes:0, Older:0 },
+					Priority:BTreeMap::from([("0".to_string(), 2), ("5".to_string(), 1)]),
+					Bytes:48,
+					Oldest:Some(Oldest { Action:Some("Read".to_string()), AgeMs:42_000 }),
+				},
+			)]),
+		},
+		Reply::Health {
+			Health:Report::New(BTreeMap::from([(
+				"queue".to_string(),
+				Check::New(Status::Degraded, "Depth 900 of 1000"),
+			)])),
+		},
+		Reply::InFlight {
+			Entry:vec![InFlight {
+				Sequence:0,
+				Action:"Read".to_string(),
+				Worker:1,
+				Started:1_700_000_000_000,
+				Attempt:2,
+				ElapsedMs:1_500,
+				Deadline:Some(1_700_000_005_000),
+				Stuck:false,
+			}],
+		},
+		Reply::Fetched { Value:json!({ "rows": [1, 2, 3] }) },
+		Reply::DeadLetterListed { Queue:"dead".to_string(), Entry:vec![DeadEntry()] },
+		Reply::DeadLetterRequeued {
+			Queue:"dead".to_string(),
+			Outcome:vec![
+				Requeue::Requeued { Sequence:3, Target:"main".to_string(), Requeued:7 },
+				Requeue::AlreadyRequeued { Sequence:3, Target:"main".to_string(), Requeued:7 },
+				Requeue::Missing { Sequence:4 },
+				Requeue::Refused { Sequence:5, Error:"Queue is full".to_string() },
+			],
+		},
+		Reply::DeadLetterPurged { Queue:"dead".to_string(), Entry:vec![DeadEntry()], DryRun:true },
+		Reply::Submissions {
+			Page:SubmissionPage {
+				Record:vec![
+					Submission {
+						Sequence:8,
+						Time:1_700_000_000_000,
+						Identity:"batch".to_string(),
+						Connection:2,
+						Id:Some("1".to_string()),
+						Action:Some("Read".to_string()),
+						Digest:Some("a1b2c3d4e5f60718".to_string()),
+						Admission:Admission::Rejected,
+						Reason:Some("Unknown action Reed".to_string()),
+					},
+					Submission {
+						Sequence:9,
+						Time:1_700_000_000_050,
+						Identity:"batch".to_string(),
+						Connection:2,
+						Id:None,
+						Action:None,
+						Digest:None,
+						Admission:Admission::Rejected,
+						Reason:Some("Malformed message".to_string()),
+					},
+				],
+				Next:Some(9),
+			},
+		},
+		Reply::HandoffChunk {
+			Chunk:1,
+			Action:vec![Transfer {
+				Key:"main#42".to_string(),
+				Action:"Read".to_string(),
+				Argument:vec![json!("a.txt")],
+				Metadata:[("Queue".to_string(), json!("main"))].into_iter().collect(),
+			}],
+		},
+		Reply::HandoffDone {
+			Handoff:Handoff { Chunks:10, Transferred:1000, Kept:2, Duplicate:0, Refused:0 },
+		},
+		Reply::Superseded { Connection:7 },
+	]
+}
+
+/// One sample of every event.
+fn Events() -> Vec<Event> {
+	vec![
+		Event::Enqueued { Sequence:0, Action:Some("Read".to_string()) },
+		Event::Started { Sequence:0 },
+		Event::Retried { Sequence:0, Attempt:1, Error:"Timed out".to_string() },
+		Event::Completed {
+			Sequence:0,
+			History:History(),
+			Annotation:[("Progress".to_string(), json!(100))].into_iter().collect(),
+		},
+		Event::Failed { Sequence:1, Error:"Failed".to_string(), History:History() },
+		Event::Expired { Sequence:2 },
+		Event::TaskFailed { Name:"registry".to_string(), Error:"panicked".to_string() },
+		Event::Opened { Connection:1 },
+		Event::Closed { Connection:1 },
+		Event::Audit {
+			Operation:"DeadLetter.Requeue".to_string(),
+			Queue:"dead".to_string(),
+			Sequence:vec![3],
+			Target:Some("main".to_string()),
+		},
+		Event::StuckAction {
+			Sequence:0,
+			Action:"Read".to_string(),
+			Worker:1,
+			ElapsedMs:300_000,
+			Attempt:2,
+			Cancelled:false,
+		},
+		Event::MissingHook { Sequence:Some(0), Hook:"audit.*".to_string() },
+		Event::RefreshFailed { Action:"Dashboard".to_string(), Error:"Timed out".to_string() },
+		Event::Node {
+			Graph:"Diamond".to_string(),
+			Node:"B".to_string(),
+			State:NodeState::Done,
+			DurationMs:Some(120),
+		},
+		Event::Admission {
+			Identity:"client".to_string(),
+			Action:"Delete".to_string(),
+			Decision:Decision::Deny {
+				Reason:"Roles: Client client may not submit Delete".to_string(),
+				Code:Code::Forbidden,
+			},
+			Policy:vec!["Roles".to_string()],
+			DryRun:false,
+		},
+	]
+}
+
+/// Checks samples against a golden file: every sample serializes to the
+/// fixture under its name and reads back to it, and every fixture has a
+/// sample.
+fn Golden<T:Serialize + DeserializeOwned>(
+	Fixture:&str,
+	Sample:Vec<T>,
+	Name:fn(&T) -> &'static str,
+) {
+	let Fixture:Value = serde_json::from_str(Fixture).unwrap();
+
+	assert_eq!(Fixture["Version"], json!(WIRE_VERSION), "fixture of another wire version");
+
+	let Fixture = Fixture["Type"].as_object().unwrap();
+
+	for Sample in &Sample {
+		let Name = Name(Sample);
+
+		let Expected = Fixture.get(Name).unwrap_or_else(|| panic!("no fixture for {}", Name));
+
+		assert_eq!(&serde_json::to_value(Sample).unwrap(), Expected, "{} drifted", Name);
+
+		let Read:T = serde_json::from_value(Expected.clone()).unwrap();
+
+		assert_eq!(&serde_json::to_value(&Read).unwrap(), Expected, "{} does not round-trip", Name);
+	}
+
+	let Named:Vec<_> = Sample.iter().map(Name).collect();
+
+	for Name in Fixture.keys() {
+		assert!(Named.contains(&Name.as_str()), "fixture {} has no sample", Name);
+	}
+}
+
+#[test]
+fn MessageGolden() { Golden(include_str!("Wire/Message.json"), Messages(), MessageName); }
+
+#[test]
+fn ReplyGolden() { Golden(include_str!("Wire/Reply.json"), Replies(), ReplyName); }
+
+#[test]
+fn EventGolden() { Golden(include_str!("Wire/Event.json"), Events(), Event::Type); }
+
+#[test]
+fn Defaults() {
+	// Optional fields may be left out and unknown fields are ignored
+	let Submit:Message = serde_json::from_value(
+		json!({ "Type": "Submit", "Id": "1", "Action": "Read", "Later": 1 }),
+	)
+	.unwrap();
+
+	assert!(matches!(
+		Submit,
+		Message::Submit { Argument, Metadata, .. } if Argument.is_empty() && Metadata.is_empty()
+	));
+
+	let Auth:Message =
+		serde_json::from_value(json!({ "Type": "Auth", "Token": "secret" })).unwrap();
+
+	assert!(matches!(Auth, Message::Auth { Delivery:Delivery::Unordered, .. }));
+
+	// Replies of servers predating the version read as version 0
+	let Authenticated:Reply =
+		serde_json::from_value(json!({ "Type": "Authenticated", "Role": "Client" })).unwrap();
+
+	assert!(matches!(Authenticated, Reply::Authenticated { Version:0, .. }));
+
+	let Result:Reply =
+		serde_json::from_value(json!({ "Type": "Result", "Id": "1", "Value": null })).unwrap();
+
+	assert_eq!(
+		serde_json::to_value(Result).unwrap(),
+		json!({ "Type": "Result", "Id": "1", "Value": null, "History": { "Attempts": [], "Omitted": 0 } })
+	);
+
+	// The tag is required
+	assert!(serde_json::from_value::<Message>(json!({ "Ping": null })).is_err());
+}
+
+#[cfg(feature = "Schema")]
+#[test]
+fn Schema() {
+	let Schema = Echo::Wire::Schema();
+
+	assert_eq!(Schema["Version"], json!(WIRE_VERSION));
+
+	for Type in ["Message", "Reply"] {
+		assert!(Schema[Type]["oneOf"]
+			.as_array()
+			.is_some_and(|Variant| !Variant.is_empty()));
+	}
+
+	assert!(Schema["Reply"]["definitions"].get("Snapshot").is_some());
+}
+
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+use Echo::Wire::{
+	Admission,
+	Age,
+	Attempt,
+	Catalogue,
+	Check,
+	Code,
+	Control,
+	Cost,
+	Count,
+	DeadEntry,
+	DeadFilter,
+	Decision,
+	Delivery,
+	Drain,
+	Event,
+	Handoff,
+	History,
+	InFlight,
+	Message,
+	NodeState,
+	Oldest,
+	Outcome,
+	Queue,
+	Reply,
+	Report,
+	Requeue,
+	Role,
+	Snapshot,
+	State,
+	Status,
+	Stored,
+	Submission,
+	SubmissionPage,
+	SubmissionQuery,
+	Summary,
+	Total,
+	Transfer,
+	WIRE_VERSION,
+};